@@ -0,0 +1,142 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The broker interface used by the A-share execution client.
+//!
+//! A-share retail and institutional access is brokered, with each broker exposing its own
+//! counter API. The execution client is written against the [`AShareBrokerApi`] trait so it can
+//! be backed by a broker binding or by the bundled [`sim::SimulatedBroker`].
+
+pub mod sim;
+
+use std::fmt::Debug;
+
+use ant_core::UnixNanos;
+use ant_model::{
+    enums::{OrderSide, OrderType, TimeInForce},
+    identifiers::{ClientOrderId, InstrumentId},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// An order request sent to the broker.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AShareOrderRequest {
+    /// The client order ID, echoed on every event for the order.
+    pub client_order_id: ClientOrderId,
+    /// The instrument ID, e.g. `600000.SSE`.
+    pub instrument_id: InstrumentId,
+    /// The order side.
+    pub side: OrderSide,
+    /// The order type (`Market` or `Limit`).
+    pub order_type: OrderType,
+    /// The limit price (`None` for market orders).
+    pub price: Option<f64>,
+    /// The order quantity in shares.
+    pub quantity: u64,
+    /// The time in force.
+    pub time_in_force: TimeInForce,
+}
+
+/// A position held at the broker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AShareBrokerPosition {
+    /// The instrument ID.
+    pub instrument_id: InstrumentId,
+    /// The total quantity held in shares.
+    pub quantity: u64,
+    /// The quantity which can be sold today (T+1 settlement excludes shares bought today).
+    pub sellable: u64,
+}
+
+/// An event reported by the broker for an order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum AShareBrokerEvent {
+    /// The order was accepted by the exchange.
+    Accepted {
+        client_order_id: ClientOrderId,
+        broker_order_id: String,
+        ts_event: UnixNanos,
+    },
+    /// The order was rejected by the broker or exchange.
+    Rejected {
+        client_order_id: ClientOrderId,
+        reason: String,
+        ts_event: UnixNanos,
+    },
+    /// The order was (partially) filled.
+    Filled {
+        client_order_id: ClientOrderId,
+        broker_order_id: String,
+        trade_id: String,
+        price: f64,
+        quantity: u64,
+        commission: f64,
+        ts_event: UnixNanos,
+    },
+    /// The order was canceled, either on request or on expiry of its time in force.
+    Canceled {
+        client_order_id: ClientOrderId,
+        broker_order_id: String,
+        ts_event: UnixNanos,
+    },
+    /// A cancel request for the order was rejected.
+    CancelRejected {
+        client_order_id: ClientOrderId,
+        reason: String,
+        ts_event: UnixNanos,
+    },
+}
+
+/// The interface to an A-share broker counter.
+///
+/// Order events are reported asynchronously through the channel given on connect.
+pub trait AShareBrokerApi: Send + Sync + Debug {
+    /// Connects to the broker, reporting order events to `event_tx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection cannot be established.
+    fn connect(&self, event_tx: UnboundedSender<AShareBrokerEvent>) -> anyhow::Result<()>;
+
+    /// Disconnects from the broker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the disconnection fails.
+    fn disconnect(&self) -> anyhow::Result<()>;
+
+    /// Sends an order to the broker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent.
+    fn submit_order(&self, request: &AShareOrderRequest) -> anyhow::Result<()>;
+
+    /// Requests cancellation of an order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be sent.
+    fn cancel_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()>;
+
+    /// Returns the positions held at the broker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the positions cannot be queried.
+    fn query_positions(&self) -> anyhow::Result<Vec<AShareBrokerPosition>>;
+}
@@ -0,0 +1,435 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A local broker simulator for the A-share execution client.
+//!
+//! The simulator matches orders against the latest quote of each instrument, filling the full
+//! quantity of marketable orders at the touch. It enforces T+1 settlement: shares bought today
+//! only become sellable after [`SimulatedBroker::settle`] rolls the trading day.
+
+use std::sync::{Mutex, MutexGuard};
+
+use ahash::AHashMap;
+use ant_core::UnixNanos;
+use ant_model::{
+    data::QuoteTick,
+    enums::{OrderSide, OrderType, TimeInForce},
+    identifiers::{ClientOrderId, InstrumentId},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::{AShareBrokerApi, AShareBrokerEvent, AShareBrokerPosition, AShareOrderRequest};
+
+#[derive(Debug)]
+struct SimulatedOrder {
+    request: AShareOrderRequest,
+    broker_order_id: String,
+}
+
+#[derive(Debug, Default)]
+struct SimulatedBrokerState {
+    event_tx: Option<UnboundedSender<AShareBrokerEvent>>,
+    positions: AHashMap<InstrumentId, AShareBrokerPosition>,
+    quotes: AHashMap<InstrumentId, QuoteTick>,
+    open_orders: Vec<SimulatedOrder>,
+    next_order_id: u64,
+    next_trade_id: u64,
+    ts_now: UnixNanos,
+}
+
+impl SimulatedBrokerState {
+    fn send(&self, event: AShareBrokerEvent) {
+        if let Some(tx) = &self.event_tx
+            && let Err(e) = tx.send(event)
+        {
+            log::error!("Failed to send simulated broker event: {e}");
+        }
+    }
+
+    fn position_mut(&mut self, instrument_id: InstrumentId) -> &mut AShareBrokerPosition {
+        self.positions
+            .entry(instrument_id)
+            .or_insert(AShareBrokerPosition {
+                instrument_id,
+                quantity: 0,
+                sellable: 0,
+            })
+    }
+
+    /// Returns the fill price for an order against the latest quote (if marketable).
+    fn match_price(&self, request: &AShareOrderRequest) -> Option<f64> {
+        let quote = self.quotes.get(&request.instrument_id)?;
+        let touch = match request.side {
+            OrderSide::Buy => quote.ask_price.as_f64(),
+            _ => quote.bid_price.as_f64(),
+        };
+        let is_marketable = match (request.order_type, request.price) {
+            (OrderType::Market, _) => true,
+            (_, Some(price)) if request.side == OrderSide::Buy => price >= touch,
+            (_, Some(price)) => price <= touch,
+            _ => false,
+        };
+        is_marketable.then_some(touch)
+    }
+
+    fn fill(&mut self, order: &SimulatedOrder, price: f64) {
+        let request = &order.request;
+        let position = self.position_mut(request.instrument_id);
+        match request.side {
+            // Shares bought today settle on T+1 so are not yet sellable
+            OrderSide::Buy => position.quantity += request.quantity,
+            // Sellable shares were reserved on submission
+            _ => position.quantity -= request.quantity,
+        }
+
+        self.next_trade_id += 1;
+        self.send(AShareBrokerEvent::Filled {
+            client_order_id: request.client_order_id,
+            broker_order_id: order.broker_order_id.clone(),
+            trade_id: format!("T-{}", self.next_trade_id),
+            price,
+            quantity: request.quantity,
+            commission: 0.0,
+            ts_event: self.ts_now,
+        });
+    }
+
+    fn cancel(&mut self, order: &SimulatedOrder) {
+        let request = &order.request;
+        if request.side == OrderSide::Sell {
+            self.position_mut(request.instrument_id).sellable += request.quantity;
+        }
+        self.send(AShareBrokerEvent::Canceled {
+            client_order_id: request.client_order_id,
+            broker_order_id: order.broker_order_id.clone(),
+            ts_event: self.ts_now,
+        });
+    }
+}
+
+/// A simulated A-share broker which fills orders against the latest quotes.
+#[derive(Debug, Default)]
+pub struct SimulatedBroker {
+    state: Mutex<SimulatedBrokerState>,
+}
+
+impl SimulatedBroker {
+    /// Creates a new [`SimulatedBroker`] instance with no positions.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a settled (sellable) position, as held from a previous trading day.
+    pub fn add_position(&self, instrument_id: InstrumentId, quantity: u64) {
+        let mut state = self.state();
+        let position = state.position_mut(instrument_id);
+        position.quantity += quantity;
+        position.sellable += quantity;
+    }
+
+    /// Updates the latest quote for an instrument, filling any resting orders it crosses.
+    pub fn update_quote(&self, quote: &QuoteTick) {
+        let mut state = self.state();
+        state.ts_now = quote.ts_event;
+        state.quotes.insert(quote.instrument_id, *quote);
+
+        let open_orders = std::mem::take(&mut state.open_orders);
+        for order in open_orders {
+            if order.request.instrument_id == quote.instrument_id
+                && let Some(price) = state.match_price(&order.request)
+            {
+                state.fill(&order, price);
+            } else {
+                state.open_orders.push(order);
+            }
+        }
+    }
+
+    /// Closes the trading day, expiring all open orders and settling today's purchases.
+    pub fn settle(&self) {
+        let mut state = self.state();
+        let open_orders = std::mem::take(&mut state.open_orders);
+        for order in &open_orders {
+            state.cancel(order);
+        }
+        for position in state.positions.values_mut() {
+            position.sellable = position.quantity;
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, SimulatedBrokerState> {
+        self.state.lock().expect("Simulated broker state poisoned")
+    }
+}
+
+impl AShareBrokerApi for SimulatedBroker {
+    fn connect(&self, event_tx: UnboundedSender<AShareBrokerEvent>) -> anyhow::Result<()> {
+        self.state().event_tx = Some(event_tx);
+        Ok(())
+    }
+
+    fn disconnect(&self) -> anyhow::Result<()> {
+        self.state().event_tx = None;
+        Ok(())
+    }
+
+    fn submit_order(&self, request: &AShareOrderRequest) -> anyhow::Result<()> {
+        let mut state = self.state();
+        let ts_event = state.ts_now;
+
+        if request.side == OrderSide::Sell {
+            let position = state.position_mut(request.instrument_id);
+            if request.quantity > position.sellable {
+                let reason = format!(
+                    "Insufficient sellable shares: {} requested, {} sellable (T+1)",
+                    request.quantity, position.sellable
+                );
+                state.send(AShareBrokerEvent::Rejected {
+                    client_order_id: request.client_order_id,
+                    reason,
+                    ts_event,
+                });
+                return Ok(());
+            }
+            position.sellable -= request.quantity;
+        }
+
+        state.next_order_id += 1;
+        let order = SimulatedOrder {
+            request: request.clone(),
+            broker_order_id: format!("SIM-{}", state.next_order_id),
+        };
+        state.send(AShareBrokerEvent::Accepted {
+            client_order_id: request.client_order_id,
+            broker_order_id: order.broker_order_id.clone(),
+            ts_event,
+        });
+
+        match state.match_price(request) {
+            Some(price) => state.fill(&order, price),
+            None if request.time_in_force == TimeInForce::Day => state.open_orders.push(order),
+            None => state.cancel(&order),
+        }
+
+        Ok(())
+    }
+
+    fn cancel_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
+        let mut state = self.state();
+        let index = state
+            .open_orders
+            .iter()
+            .position(|order| order.request.client_order_id == *client_order_id);
+
+        match index {
+            Some(index) => {
+                let order = state.open_orders.remove(index);
+                state.cancel(&order);
+            }
+            None => {
+                let ts_event = state.ts_now;
+                state.send(AShareBrokerEvent::CancelRejected {
+                    client_order_id: *client_order_id,
+                    reason: "Order not open".to_string(),
+                    ts_event,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn query_positions(&self) -> anyhow::Result<Vec<AShareBrokerPosition>> {
+        Ok(self
+            .state()
+            .positions
+            .values()
+            .filter(|position| position.quantity > 0)
+            .copied()
+            .collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ant_model::types::{Price, Quantity};
+    use rstest::{fixture, rstest};
+    use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+    use super::*;
+
+    #[fixture]
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("600000.SSE")
+    }
+
+    fn quote(instrument_id: InstrumentId, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(10_000),
+            Quantity::from(10_000),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        )
+    }
+
+    fn request(
+        instrument_id: InstrumentId,
+        client_order_id: &str,
+        side: OrderSide,
+        price: &str,
+        quantity: u64,
+    ) -> AShareOrderRequest {
+        AShareOrderRequest {
+            client_order_id: ClientOrderId::from(client_order_id),
+            instrument_id,
+            side,
+            order_type: OrderType::Limit,
+            price: Some(price.parse().unwrap()),
+            quantity,
+            time_in_force: TimeInForce::Day,
+        }
+    }
+
+    fn connect(broker: &SimulatedBroker) -> UnboundedReceiver<AShareBrokerEvent> {
+        let (tx, rx) = unbounded_channel();
+        broker.connect(tx).unwrap();
+        rx
+    }
+
+    fn drain(rx: &mut UnboundedReceiver<AShareBrokerEvent>) -> Vec<AShareBrokerEvent> {
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+
+    #[rstest]
+    fn test_marketable_limit_order_fills_at_touch(instrument_id: InstrumentId) {
+        let broker = SimulatedBroker::new();
+        let mut rx = connect(&broker);
+        broker.update_quote(&quote(instrument_id, "10.25", "10.26"));
+
+        broker
+            .submit_order(&request(instrument_id, "O-1", OrderSide::Buy, "10.30", 200))
+            .unwrap();
+
+        let events = drain(&mut rx);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], AShareBrokerEvent::Accepted { .. }));
+        assert!(matches!(
+            events[1],
+            AShareBrokerEvent::Filled { price, quantity: 200, .. } if (price - 10.26).abs() < 1e-9
+        ));
+    }
+
+    #[rstest]
+    fn test_resting_order_fills_on_quote_update(instrument_id: InstrumentId) {
+        let broker = SimulatedBroker::new();
+        let mut rx = connect(&broker);
+        broker.update_quote(&quote(instrument_id, "10.25", "10.26"));
+        broker
+            .submit_order(&request(instrument_id, "O-1", OrderSide::Buy, "10.20", 100))
+            .unwrap();
+        assert_eq!(drain(&mut rx).len(), 1);
+
+        broker.update_quote(&quote(instrument_id, "10.18", "10.19"));
+
+        let events = drain(&mut rx);
+        assert!(matches!(events[0], AShareBrokerEvent::Filled { .. }));
+    }
+
+    #[rstest]
+    fn test_shares_bought_today_are_not_sellable(instrument_id: InstrumentId) {
+        let broker = SimulatedBroker::new();
+        let mut rx = connect(&broker);
+        broker.update_quote(&quote(instrument_id, "10.25", "10.26"));
+        broker
+            .submit_order(&request(instrument_id, "O-1", OrderSide::Buy, "10.26", 100))
+            .unwrap();
+
+        broker
+            .submit_order(&request(
+                instrument_id,
+                "O-2",
+                OrderSide::Sell,
+                "10.25",
+                100,
+            ))
+            .unwrap();
+
+        let events = drain(&mut rx);
+        assert!(matches!(events[2], AShareBrokerEvent::Rejected { .. }));
+
+        broker.settle();
+        broker
+            .submit_order(&request(
+                instrument_id,
+                "O-3",
+                OrderSide::Sell,
+                "10.25",
+                100,
+            ))
+            .unwrap();
+
+        let events = drain(&mut rx);
+        assert!(matches!(events[1], AShareBrokerEvent::Filled { .. }));
+        assert!(broker.query_positions().unwrap().is_empty());
+    }
+
+    #[rstest]
+    fn test_settle_expires_open_orders_and_releases_shares(instrument_id: InstrumentId) {
+        let broker = SimulatedBroker::new();
+        broker.add_position(instrument_id, 300);
+        let mut rx = connect(&broker);
+        broker
+            .submit_order(&request(
+                instrument_id,
+                "O-1",
+                OrderSide::Sell,
+                "11.00",
+                300,
+            ))
+            .unwrap();
+
+        broker.settle();
+
+        let events = drain(&mut rx);
+        assert!(matches!(events[1], AShareBrokerEvent::Canceled { .. }));
+        let positions = broker.query_positions().unwrap();
+        assert_eq!(positions[0].sellable, 300);
+    }
+
+    #[rstest]
+    fn test_cancel_unknown_order_rejects() {
+        let broker = SimulatedBroker::new();
+        let mut rx = connect(&broker);
+
+        broker.cancel_order(&ClientOrderId::from("O-9")).unwrap();
+
+        let events = drain(&mut rx);
+        assert!(matches!(
+            events[0],
+            AShareBrokerEvent::CancelRejected { .. }
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::sync::LazyLock;
+
+use ant_model::{
+    enums::{OrderType, TimeInForce},
+    identifiers::Venue,
+};
+use ustr::Ustr;
+
+pub const ASHARE: &str = "ASHARE";
+
+pub const SSE: &str = "SSE";
+pub const SZSE: &str = "SZSE";
+pub const BSE: &str = "BSE";
+
+pub static SSE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(SSE)));
+pub static SZSE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(SZSE)));
+pub static BSE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(BSE)));
+
+/// The China stock exchanges supported by the adapter.
+pub const ASHARE_SUPPORTED_EXCHANGES: &[&str] = &[SSE, SZSE, BSE];
+
+/// A-share supported order time in force.
+///
+/// # Notes
+///
+/// - Limit orders rest until the close of the trading day, so `Day` is the only resting option.
+/// - Market orders must be immediate, with the unfilled remainder canceled (`Ioc`) or the
+///   whole order filled or canceled (`Fok`).
+pub const ASHARE_SUPPORTED_TIME_IN_FORCE: &[TimeInForce] =
+    &[TimeInForce::Day, TimeInForce::Ioc, TimeInForce::Fok];
+
+/// A-share supported order types.
+pub const ASHARE_SUPPORTED_ORDER_TYPES: &[OrderType] = &[OrderType::Market, OrderType::Limit];
+
+/// The UTC offset of exchange local time (China Standard Time has no daylight saving).
+pub const ASHARE_UTC_OFFSET_SECS: i32 = 8 * 3600;
+
+/// The standard board lot (一手) in shares.
+pub const ASHARE_BOARD_LOT: u64 = 100;
+
+/// The minimum order quantity for STAR Market stocks, above which any share increment is allowed.
+pub const STAR_MIN_QUANTITY: u64 = 200;
+
+/// The price precision of stocks (0.01 CNY ticks).
+pub const STOCK_PRICE_PRECISION: u8 = 2;
+
+/// The price precision of exchange traded funds (0.001 CNY ticks).
+pub const FUND_PRICE_PRECISION: u8 = 3;
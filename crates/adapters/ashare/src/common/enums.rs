@@ -0,0 +1,242 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Enumerations for the A-share exchanges, boards and security types.
+
+use std::str::FromStr;
+
+use ant_model::{enums::AggressorSide, identifiers::Venue};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, EnumString};
+
+use super::consts::{BSE_VENUE, SSE_VENUE, SZSE_VENUE};
+
+/// Represents the China stock exchanges listing A-shares.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum AShareExchange {
+    /// Shanghai Stock Exchange.
+    Sse,
+    /// Shenzhen Stock Exchange.
+    Szse,
+    /// Beijing Stock Exchange.
+    Bse,
+}
+
+impl AShareExchange {
+    /// Returns the ant [`Venue`] for the exchange.
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        match self {
+            Self::Sse => *SSE_VENUE,
+            Self::Szse => *SZSE_VENUE,
+            Self::Bse => *BSE_VENUE,
+        }
+    }
+}
+
+impl TryFrom<Venue> for AShareExchange {
+    type Error = anyhow::Error;
+
+    fn try_from(venue: Venue) -> Result<Self, Self::Error> {
+        Self::from_str(venue.as_str())
+            .map_err(|_| anyhow::anyhow!("Venue {venue} is not an A-share exchange"))
+    }
+}
+
+/// Represents the type of a listed security.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum AShareSecurityType {
+    /// An ordinary share.
+    Stock,
+    /// An exchange traded fund.
+    Etf,
+}
+
+/// Represents the listing board of a stock, which determines its trading rules.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum AShareBoard {
+    /// The SSE or SZSE Main Board (主板).
+    Main,
+    /// The SSE Science and Technology Innovation Board (科创板).
+    Star,
+    /// The SZSE Growth Enterprise Market (创业板).
+    ChiNext,
+    /// The Beijing Stock Exchange (北交所).
+    Bse,
+}
+
+impl AShareBoard {
+    /// Classifies the board of a security from its exchange and six digit code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code does not belong to a stock or ETF range of the exchange.
+    pub fn from_code(exchange: AShareExchange, code: &str) -> anyhow::Result<Self> {
+        if code.len() != 6 || !code.bytes().all(|b| b.is_ascii_digit()) {
+            anyhow::bail!("Invalid A-share code '{code}'");
+        }
+
+        let board = match (exchange, &code[..3]) {
+            (AShareExchange::Sse, "688" | "689") => Self::Star,
+            (AShareExchange::Sse, "600" | "601" | "603" | "605") => Self::Main,
+            (AShareExchange::Sse, prefix) if prefix.starts_with('5') => Self::Main,
+            (AShareExchange::Szse, "300" | "301") => Self::ChiNext,
+            (AShareExchange::Szse, "000" | "001" | "002" | "003") => Self::Main,
+            (AShareExchange::Szse, prefix)
+                if prefix.starts_with("15") || prefix.starts_with("16") =>
+            {
+                Self::Main
+            }
+            (AShareExchange::Bse, prefix)
+                if prefix.starts_with('4') || prefix.starts_with('8') || prefix == "920" =>
+            {
+                Self::Bse
+            }
+            _ => anyhow::bail!("Code '{code}' is not an A-share stock or ETF on {exchange}"),
+        };
+
+        Ok(board)
+    }
+}
+
+/// Represents the buy/sell flag (`BSFlag`) of a tick-by-tick trade.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum AShareTradeSide {
+    /// The trade was initiated by the buyer.
+    #[serde(rename = "B")]
+    #[strum(serialize = "B")]
+    Buy,
+    /// The trade was initiated by the seller.
+    #[serde(rename = "S")]
+    #[strum(serialize = "S")]
+    Sell,
+    /// No initiating side, e.g. trades from the call auctions.
+    #[serde(rename = "N")]
+    #[strum(serialize = "N")]
+    None,
+}
+
+impl From<AShareTradeSide> for AggressorSide {
+    fn from(value: AShareTradeSide) -> Self {
+        match value {
+            AShareTradeSide::Buy => Self::Buyer,
+            AShareTradeSide::Sell => Self::Seller,
+            AShareTradeSide::None => Self::NoAggressor,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(AShareExchange::Sse, "600000", AShareBoard::Main)]
+    #[case(AShareExchange::Sse, "688981", AShareBoard::Star)]
+    #[case(AShareExchange::Sse, "510300", AShareBoard::Main)]
+    #[case(AShareExchange::Szse, "000001", AShareBoard::Main)]
+    #[case(AShareExchange::Szse, "300750", AShareBoard::ChiNext)]
+    #[case(AShareExchange::Szse, "159915", AShareBoard::Main)]
+    #[case(AShareExchange::Bse, "830799", AShareBoard::Bse)]
+    #[case(AShareExchange::Bse, "920002", AShareBoard::Bse)]
+    fn test_board_from_code(
+        #[case] exchange: AShareExchange,
+        #[case] code: &str,
+        #[case] expected: AShareBoard,
+    ) {
+        assert_eq!(AShareBoard::from_code(exchange, code).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(AShareExchange::Sse, "000001")]
+    #[case(AShareExchange::Szse, "688981")]
+    #[case(AShareExchange::Sse, "60000")]
+    #[case(AShareExchange::Bse, "60000A")]
+    fn test_board_from_code_invalid(#[case] exchange: AShareExchange, #[case] code: &str) {
+        assert!(AShareBoard::from_code(exchange, code).is_err());
+    }
+
+    #[rstest]
+    fn test_exchange_from_venue() {
+        assert_eq!(
+            AShareExchange::try_from(Venue::from("SZSE")).unwrap(),
+            AShareExchange::Szse
+        );
+        assert!(AShareExchange::try_from(Venue::from("SHFE")).is_err());
+    }
+}
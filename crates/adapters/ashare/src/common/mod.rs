@@ -0,0 +1,32 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Common functionality shared across the A-share adapter.
+//!
+//! This module provides core utilities, constants, and data structures used throughout
+//! the A-share integration, including:
+//!
+//! - Venue constants for the Shanghai, Shenzhen and Beijing stock exchanges.
+//! - Board classification and the lot and tick size rules of each board.
+//! - Parsing utilities for converting instrument definitions and market data to ant types.
+
+pub mod consts;
+pub mod enums;
+pub mod models;
+pub mod parse;
+pub mod rules;
+
+#[cfg(test)]
+pub(crate) mod testing;
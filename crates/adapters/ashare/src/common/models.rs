@@ -0,0 +1,189 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data models for A-share instrument definitions and market data feeds.
+//!
+//! Snapshots follow the layout common to exchange Level-1 (5 levels) and Level-2 (10 levels)
+//! feeds, with a flat CSV form where each level is a separate column (`bid_price_1`,
+//! `bid_volume_1`, ...) and a JSON form where each side is an array of levels.
+
+use ahash::AHashMap;
+use csv::StringRecord;
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use super::enums::{AShareExchange, AShareSecurityType, AShareTradeSide};
+
+/// The maximum number of book levels carried by a snapshot.
+pub const SNAPSHOT_MAX_LEVELS: usize = 10;
+
+/// An A-share instrument definition.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AShareInstrumentRecord {
+    /// The six digit security code, e.g. `600000`.
+    pub symbol: Ustr,
+    /// The short name of the security.
+    pub name: String,
+    /// The listing exchange.
+    pub exchange: AShareExchange,
+    /// The security type.
+    pub security_type: AShareSecurityType,
+    /// The ISIN of the security (if known).
+    #[serde(default)]
+    pub isin: Option<Ustr>,
+    /// The previous close price, used to derive the daily price limits (if known).
+    #[serde(default)]
+    pub pre_close: Option<f64>,
+}
+
+/// A Level-1 or Level-2 market data snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AShareSnapshot {
+    /// The six digit security code.
+    pub symbol: Ustr,
+    /// The listing exchange.
+    pub exchange: AShareExchange,
+    /// The trading date (YYYYMMDD).
+    pub trading_date: String,
+    /// The exchange local time (HH:MM:SS or HH:MM:SS.fff).
+    pub time: String,
+    /// The last traded price (zero before the first trade).
+    pub last_price: f64,
+    /// The previous close price.
+    pub pre_close: f64,
+    /// The cumulative traded volume in shares.
+    pub volume: u64,
+    /// The cumulative traded value in CNY.
+    pub turnover: f64,
+    /// The bid prices from best to worst.
+    pub bid_prices: Vec<f64>,
+    /// The bid volumes in shares.
+    pub bid_volumes: Vec<u64>,
+    /// The number of bid orders per level (Level-2 only).
+    #[serde(default)]
+    pub bid_counts: Vec<u32>,
+    /// The ask prices from best to worst.
+    pub ask_prices: Vec<f64>,
+    /// The ask volumes in shares.
+    pub ask_volumes: Vec<u64>,
+    /// The number of ask orders per level (Level-2 only).
+    #[serde(default)]
+    pub ask_counts: Vec<u32>,
+}
+
+impl AShareSnapshot {
+    /// Parses a snapshot from a CSV `record` with the given `headers`.
+    ///
+    /// Book levels are read from `bid_price_{n}`, `bid_volume_{n}` and (optionally)
+    /// `bid_count_{n}` columns, and likewise for asks, stopping at the first missing column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required column is missing or a value cannot be parsed.
+    pub fn from_csv_record(headers: &StringRecord, record: &StringRecord) -> anyhow::Result<Self> {
+        let columns: AHashMap<&str, &str> = headers.iter().zip(record.iter()).collect();
+        let get = |name: &str| -> anyhow::Result<&str> {
+            columns
+                .get(name)
+                .copied()
+                .ok_or_else(|| anyhow::anyhow!("Missing snapshot column '{name}'"))
+        };
+        let parse_f64 = |name: &str| -> anyhow::Result<f64> {
+            let value = get(name)?;
+            value
+                .trim()
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid '{name}' value '{value}': {e}"))
+        };
+        let parse_u64 = |name: &str| -> anyhow::Result<u64> {
+            let value = get(name)?;
+            value
+                .trim()
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid '{name}' value '{value}': {e}"))
+        };
+
+        let mut snapshot = Self {
+            symbol: Ustr::from(get("symbol")?.trim()),
+            exchange: get("exchange")?.trim().parse()?,
+            trading_date: get("trading_date")?.trim().to_string(),
+            time: get("time")?.trim().to_string(),
+            last_price: parse_f64("last_price")?,
+            pre_close: parse_f64("pre_close")?,
+            volume: parse_u64("volume")?,
+            turnover: parse_f64("turnover")?,
+            bid_prices: Vec::new(),
+            bid_volumes: Vec::new(),
+            bid_counts: Vec::new(),
+            ask_prices: Vec::new(),
+            ask_volumes: Vec::new(),
+            ask_counts: Vec::new(),
+        };
+
+        for level in 1..=SNAPSHOT_MAX_LEVELS {
+            let bid_price = format!("bid_price_{level}");
+            if !columns.contains_key(bid_price.as_str()) {
+                break;
+            }
+            snapshot.bid_prices.push(parse_f64(&bid_price)?);
+            snapshot
+                .bid_volumes
+                .push(parse_u64(&format!("bid_volume_{level}"))?);
+            snapshot
+                .ask_prices
+                .push(parse_f64(&format!("ask_price_{level}"))?);
+            snapshot
+                .ask_volumes
+                .push(parse_u64(&format!("ask_volume_{level}"))?);
+
+            let bid_count = format!("bid_count_{level}");
+            if columns.contains_key(bid_count.as_str()) {
+                snapshot.bid_counts.push(parse_u64(&bid_count)? as u32);
+                snapshot
+                    .ask_counts
+                    .push(parse_u64(&format!("ask_count_{level}"))? as u32);
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Returns the number of book levels in the snapshot.
+    #[must_use]
+    pub fn levels(&self) -> usize {
+        self.bid_prices.len().min(self.ask_prices.len())
+    }
+}
+
+/// A Level-2 tick-by-tick trade (逐笔成交).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AShareTradeRecord {
+    /// The six digit security code.
+    pub symbol: Ustr,
+    /// The listing exchange.
+    pub exchange: AShareExchange,
+    /// The trading date (YYYYMMDD).
+    pub trading_date: String,
+    /// The exchange local time (HH:MM:SS or HH:MM:SS.fff).
+    pub time: String,
+    /// The exchange trade sequence number, unique per channel and trading date.
+    pub trade_id: u64,
+    /// The trade price.
+    pub price: f64,
+    /// The trade volume in shares.
+    pub volume: u64,
+    /// The initiating side of the trade.
+    pub side: AShareTradeSide,
+}
@@ -0,0 +1,289 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing functions for converting A-share definitions and market data to ant types.
+
+use ant_core::UnixNanos;
+use ant_model::{
+    data::{BookOrder, DEPTH10_LEN, NULL_ORDER, OrderBookDepth10, QuoteTick, TradeTick},
+    enums::{AggressorSide, OrderSide, RecordFlag},
+    identifiers::{InstrumentId, Symbol, TradeId},
+    instruments::{Equity, Instrument, InstrumentEnum},
+    types::{Currency, Price, Quantity},
+};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use ustr::Ustr;
+
+use super::{
+    consts::ASHARE_UTC_OFFSET_SECS,
+    enums::{AShareBoard, AShareExchange},
+    models::{AShareInstrumentRecord, AShareSnapshot, AShareTradeRecord},
+    rules::{AShareLotRule, price_precision},
+};
+
+/// Returns the ant [`InstrumentId`] for the given security code and exchange, e.g. `600000.SSE`.
+#[must_use]
+pub fn parse_instrument_id(symbol: Ustr, exchange: AShareExchange) -> InstrumentId {
+    InstrumentId::new(Symbol::from_ustr_unchecked(symbol), exchange.venue())
+}
+
+/// Parses an exchange local date (YYYYMMDD) and time (HH:MM:SS with optional fraction) into
+/// UNIX nanoseconds.
+///
+/// # Errors
+///
+/// Returns an error if the date or time cannot be parsed.
+pub fn parse_ashare_timestamp(date: &str, time: &str) -> anyhow::Result<UnixNanos> {
+    let date = NaiveDate::parse_from_str(date.trim(), "%Y%m%d")
+        .map_err(|e| anyhow::anyhow!("Invalid A-share date '{date}': {e}"))?;
+    let time = NaiveTime::parse_from_str(time.trim(), "%H:%M:%S%.f")
+        .map_err(|e| anyhow::anyhow!("Invalid A-share time '{time}': {e}"))?;
+    let utc = NaiveDateTime::new(date, time) - Duration::seconds(i64::from(ASHARE_UTC_OFFSET_SECS));
+    let nanos = utc
+        .and_utc()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow::anyhow!("A-share timestamp out of range"))?;
+    Ok(UnixNanos::from(nanos as u64))
+}
+
+/// Returns the lot rule for an instrument definition.
+///
+/// # Errors
+///
+/// Returns an error if the board cannot be classified from the security code.
+pub fn parse_lot_rule(record: &AShareInstrumentRecord) -> anyhow::Result<AShareLotRule> {
+    let board = AShareBoard::from_code(record.exchange, &record.symbol)?;
+    Ok(AShareLotRule::new(board, record.security_type))
+}
+
+/// Parses an A-share instrument definition into an [`Equity`].
+///
+/// The lot size is the quantity increment of the board (100 shares on the Main Board and
+/// ChiNext, 1 share on the STAR Market and BSE) with the board minimum as `min_quantity`.
+///
+/// # Errors
+///
+/// Returns an error if the definition is invalid.
+pub fn parse_instrument(
+    record: &AShareInstrumentRecord,
+    ts_init: UnixNanos,
+) -> anyhow::Result<InstrumentEnum> {
+    let lot_rule = parse_lot_rule(record)?;
+    let price_precision = price_precision(record.security_type);
+    let price_increment =
+        Price::new_checked(10f64.powi(-i32::from(price_precision)), price_precision)?;
+
+    let equity = Equity::new_checked(
+        parse_instrument_id(record.symbol, record.exchange),
+        Symbol::from_ustr_unchecked(record.symbol),
+        record.isin,
+        Currency::CNY(),
+        price_precision,
+        price_increment,
+        Some(Quantity::from(lot_rule.increment)),
+        Some(Quantity::from(lot_rule.max_quantity)),
+        Some(Quantity::from(lot_rule.min_quantity)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        ts_init,
+        ts_init,
+    )?;
+
+    Ok(InstrumentEnum::Equity(equity))
+}
+
+/// Returns whether a book level holds a price (feeds report empty levels as zero).
+fn is_valid_level(price: f64, volume: u64) -> bool {
+    price.is_finite() && price > 0.0 && volume > 0
+}
+
+/// Parses the top of book of a snapshot into a [`QuoteTick`].
+///
+/// Returns `Ok(None)` when either side of the book is empty, as happens when a stock is
+/// trading at its daily price limit or during the call auctions.
+///
+/// # Errors
+///
+/// Returns an error if the snapshot cannot be parsed.
+pub fn parse_quote_tick(
+    snapshot: &AShareSnapshot,
+    instrument: &InstrumentEnum,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<QuoteTick>> {
+    let (Some(&bid_price), Some(&bid_volume), Some(&ask_price), Some(&ask_volume)) = (
+        snapshot.bid_prices.first(),
+        snapshot.bid_volumes.first(),
+        snapshot.ask_prices.first(),
+        snapshot.ask_volumes.first(),
+    ) else {
+        return Ok(None);
+    };
+
+    if !is_valid_level(bid_price, bid_volume) || !is_valid_level(ask_price, ask_volume) {
+        return Ok(None);
+    }
+
+    let ts_event = parse_ashare_timestamp(&snapshot.trading_date, &snapshot.time)?;
+    let quote = QuoteTick::new_checked(
+        instrument.id(),
+        instrument.make_price(bid_price),
+        instrument.make_price(ask_price),
+        Quantity::new(bid_volume as f64, instrument.size_precision()),
+        Quantity::new(ask_volume as f64, instrument.size_precision()),
+        ts_event,
+        ts_init,
+    )?;
+
+    Ok(Some(quote))
+}
+
+fn parse_book_level(
+    side: OrderSide,
+    prices: &[f64],
+    volumes: &[u64],
+    counts: &[u32],
+    level: usize,
+    instrument: &InstrumentEnum,
+) -> (BookOrder, u32) {
+    match (prices.get(level), volumes.get(level)) {
+        (Some(&price), Some(&volume)) if is_valid_level(price, volume) => (
+            BookOrder::new(
+                side,
+                instrument.make_price(price),
+                Quantity::new(volume as f64, instrument.size_precision()),
+                0,
+            ),
+            counts.get(level).copied().unwrap_or(1),
+        ),
+        _ => (NULL_ORDER, 0),
+    }
+}
+
+/// Parses a snapshot into an [`OrderBookDepth10`].
+///
+/// Level-1 snapshots fill the first five levels, with the remaining levels left empty. Where
+/// the feed does not carry order counts each populated level has a count of one.
+///
+/// # Errors
+///
+/// Returns an error if the snapshot cannot be parsed.
+pub fn parse_order_book_depth10(
+    snapshot: &AShareSnapshot,
+    instrument: &InstrumentEnum,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderBookDepth10> {
+    let ts_event = parse_ashare_timestamp(&snapshot.trading_date, &snapshot.time)?;
+
+    let mut bids = [NULL_ORDER; DEPTH10_LEN];
+    let mut asks = [NULL_ORDER; DEPTH10_LEN];
+    let mut bid_counts = [0u32; DEPTH10_LEN];
+    let mut ask_counts = [0u32; DEPTH10_LEN];
+
+    for level in 0..DEPTH10_LEN {
+        (bids[level], bid_counts[level]) = parse_book_level(
+            OrderSide::Buy,
+            &snapshot.bid_prices,
+            &snapshot.bid_volumes,
+            &snapshot.bid_counts,
+            level,
+            instrument,
+        );
+        (asks[level], ask_counts[level]) = parse_book_level(
+            OrderSide::Sell,
+            &snapshot.ask_prices,
+            &snapshot.ask_volumes,
+            &snapshot.ask_counts,
+            level,
+            instrument,
+        );
+    }
+
+    Ok(OrderBookDepth10::new(
+        instrument.id(),
+        bids,
+        asks,
+        bid_counts,
+        ask_counts,
+        RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8,
+        0, // Sequence not available
+        ts_event,
+        ts_init,
+    ))
+}
+
+/// Derives a [`TradeTick`] from the change in cumulative volume between snapshots.
+///
+/// Level-1 feeds do not publish individual trades, so the volume traded since the previous
+/// snapshot is reported as a single trade at the last price with no aggressor side. Returns
+/// `None` for the first snapshot of an instrument (no `last_volume`) or when no volume traded.
+///
+/// # Errors
+///
+/// Returns an error if the snapshot cannot be parsed.
+pub fn parse_snapshot_trade_tick(
+    snapshot: &AShareSnapshot,
+    instrument: &InstrumentEnum,
+    last_volume: Option<u64>,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<TradeTick>> {
+    let Some(last_volume) = last_volume else {
+        return Ok(None);
+    };
+    if snapshot.volume <= last_volume || snapshot.last_price <= 0.0 {
+        return Ok(None);
+    }
+
+    let ts_event = parse_ashare_timestamp(&snapshot.trading_date, &snapshot.time)?;
+    let trade = TradeTick::new_checked(
+        instrument.id(),
+        instrument.make_price(snapshot.last_price),
+        Quantity::new(
+            (snapshot.volume - last_volume) as f64,
+            instrument.size_precision(),
+        ),
+        AggressorSide::NoAggressor,
+        TradeId::new(format!("{}-{}", snapshot.trading_date, snapshot.volume)),
+        ts_event,
+        ts_init,
+    )?;
+
+    Ok(Some(trade))
+}
+
+/// Parses a Level-2 tick-by-tick trade into a [`TradeTick`].
+///
+/// # Errors
+///
+/// Returns an error if the trade cannot be parsed.
+pub fn parse_trade_tick(
+    record: &AShareTradeRecord,
+    instrument: &InstrumentEnum,
+    ts_init: UnixNanos,
+) -> anyhow::Result<TradeTick> {
+    let ts_event = parse_ashare_timestamp(&record.trading_date, &record.time)?;
+    TradeTick::new_checked(
+        instrument.id(),
+        instrument.make_price(record.price),
+        Quantity::new(record.volume as f64, instrument.size_precision()),
+        record.side.into(),
+        TradeId::new(format!("{}-{}", record.trading_date, record.trade_id)),
+        ts_event,
+        ts_init,
+    )
+}
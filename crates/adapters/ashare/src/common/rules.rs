@@ -0,0 +1,219 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Board lot and tick size rules for A-share orders.
+//!
+//! Main Board and ChiNext stocks trade in board lots of 100 shares. STAR Market stocks require
+//! at least 200 shares and then any increment of 1 share, while BSE stocks require at least
+//! 100 shares and then any increment of 1 share. Odd lots can only arise from corporate actions
+//! and may only be sold, all at once.
+
+use ant_model::{
+    enums::OrderSide,
+    instruments::{Instrument, InstrumentEnum},
+};
+
+use super::{
+    consts::{ASHARE_BOARD_LOT, FUND_PRICE_PRECISION, STAR_MIN_QUANTITY, STOCK_PRICE_PRECISION},
+    enums::{AShareBoard, AShareSecurityType},
+};
+
+/// The order quantity rules of a security.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AShareLotRule {
+    /// The minimum quantity of an order.
+    pub min_quantity: u64,
+    /// The quantity increment above the minimum.
+    pub increment: u64,
+    /// The maximum quantity of a single limit order.
+    pub max_quantity: u64,
+}
+
+impl AShareLotRule {
+    /// Returns the lot rule for a security listed on `board`.
+    #[must_use]
+    pub const fn new(board: AShareBoard, security_type: AShareSecurityType) -> Self {
+        match (security_type, board) {
+            (AShareSecurityType::Etf, _) | (AShareSecurityType::Stock, AShareBoard::Main) => Self {
+                min_quantity: ASHARE_BOARD_LOT,
+                increment: ASHARE_BOARD_LOT,
+                max_quantity: 1_000_000,
+            },
+            (AShareSecurityType::Stock, AShareBoard::ChiNext) => Self {
+                min_quantity: ASHARE_BOARD_LOT,
+                increment: ASHARE_BOARD_LOT,
+                max_quantity: 300_000,
+            },
+            (AShareSecurityType::Stock, AShareBoard::Star) => Self {
+                min_quantity: STAR_MIN_QUANTITY,
+                increment: 1,
+                max_quantity: 100_000,
+            },
+            (AShareSecurityType::Stock, AShareBoard::Bse) => Self {
+                min_quantity: ASHARE_BOARD_LOT,
+                increment: 1,
+                max_quantity: 1_000_000,
+            },
+        }
+    }
+
+    /// Returns the lot rule carried by an instrument parsed by this adapter (if any).
+    #[must_use]
+    pub fn from_instrument(instrument: &InstrumentEnum) -> Option<Self> {
+        Some(Self {
+            min_quantity: instrument.min_quantity()?.as_f64() as u64,
+            increment: instrument.lot_size()?.as_f64() as u64,
+            max_quantity: instrument.max_quantity()?.as_f64() as u64,
+        })
+    }
+
+    /// Returns whether `quantity` is a valid order quantity ignoring odd lot sales.
+    #[must_use]
+    pub const fn is_valid_quantity(&self, quantity: u64) -> bool {
+        quantity >= self.min_quantity
+            && quantity <= self.max_quantity
+            && (quantity - self.min_quantity) % self.increment == 0
+    }
+
+    /// Rounds `quantity` down to the nearest valid order quantity (zero if below the minimum).
+    #[must_use]
+    pub const fn round_down(&self, quantity: u64) -> u64 {
+        if quantity < self.min_quantity {
+            return 0;
+        }
+        let quantity = if quantity > self.max_quantity {
+            self.max_quantity
+        } else {
+            quantity
+        };
+        quantity - (quantity - self.min_quantity) % self.increment
+    }
+
+    /// Validates an order quantity against the rule.
+    ///
+    /// A sell order may also dispose of the whole `position`, or a valid quantity plus the odd
+    /// lot remainder of the position, as odd lots must be sold in a single order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the quantity is not valid for the order side.
+    pub fn validate(&self, side: OrderSide, quantity: u64, position: u64) -> anyhow::Result<()> {
+        if self.is_valid_quantity(quantity) {
+            return Ok(());
+        }
+
+        if side == OrderSide::Sell && quantity <= position && quantity <= self.max_quantity {
+            // Boards without lots only allow the remainder below the minimum to be sold in full
+            let odd_lot = if self.increment > 1 {
+                position % self.increment
+            } else {
+                0
+            };
+            if quantity == position
+                || (odd_lot > 0 && quantity > odd_lot && self.is_valid_quantity(quantity - odd_lot))
+            {
+                return Ok(());
+            }
+        }
+
+        anyhow::bail!(
+            "Invalid {side} quantity {quantity}: must be at least {} and in increments of {} up to {}",
+            self.min_quantity,
+            self.increment,
+            self.max_quantity
+        )
+    }
+}
+
+/// Returns the price precision for a security type.
+#[must_use]
+pub const fn price_precision(security_type: AShareSecurityType) -> u8 {
+    match security_type {
+        AShareSecurityType::Stock => STOCK_PRICE_PRECISION,
+        AShareSecurityType::Etf => FUND_PRICE_PRECISION,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(AShareBoard::Main, 100, true)]
+    #[case(AShareBoard::Main, 150, false)]
+    #[case(AShareBoard::Main, 1_000_100, false)]
+    #[case(AShareBoard::ChiNext, 300_000, true)]
+    #[case(AShareBoard::ChiNext, 300_100, false)]
+    #[case(AShareBoard::Star, 100, false)]
+    #[case(AShareBoard::Star, 200, true)]
+    #[case(AShareBoard::Star, 201, true)]
+    #[case(AShareBoard::Bse, 101, true)]
+    #[case(AShareBoard::Bse, 99, false)]
+    fn test_is_valid_quantity(
+        #[case] board: AShareBoard,
+        #[case] quantity: u64,
+        #[case] expected: bool,
+    ) {
+        let rule = AShareLotRule::new(board, AShareSecurityType::Stock);
+        assert_eq!(rule.is_valid_quantity(quantity), expected);
+    }
+
+    #[rstest]
+    #[case(AShareBoard::Main, 250, 200)]
+    #[case(AShareBoard::Main, 99, 0)]
+    #[case(AShareBoard::Star, 199, 0)]
+    #[case(AShareBoard::Star, 257, 257)]
+    fn test_round_down(#[case] board: AShareBoard, #[case] quantity: u64, #[case] expected: u64) {
+        let rule = AShareLotRule::new(board, AShareSecurityType::Stock);
+        assert_eq!(rule.round_down(quantity), expected);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, 150, 0, false)]
+    #[case(OrderSide::Sell, 150, 150, true)] // Whole position with odd lot
+    #[case(OrderSide::Sell, 50, 150, false)] // Odd lot must be sold with the whole remainder
+    #[case(OrderSide::Sell, 150, 250, true)] // Lot plus odd lot remainder
+    #[case(OrderSide::Sell, 200, 250, true)]
+    #[case(OrderSide::Sell, 150, 100, false)] // Exceeds position
+    fn test_validate_main_board(
+        #[case] side: OrderSide,
+        #[case] quantity: u64,
+        #[case] position: u64,
+        #[case] expected: bool,
+    ) {
+        let rule = AShareLotRule::new(AShareBoard::Main, AShareSecurityType::Stock);
+        assert_eq!(rule.validate(side, quantity, position).is_ok(), expected);
+    }
+
+    #[rstest]
+    fn test_validate_star_remaining_shares_sold_at_once() {
+        let rule = AShareLotRule::new(AShareBoard::Star, AShareSecurityType::Stock);
+
+        assert!(rule.validate(OrderSide::Sell, 150, 150).is_ok());
+        assert!(rule.validate(OrderSide::Sell, 100, 150).is_err());
+        assert!(rule.validate(OrderSide::Buy, 150, 0).is_err());
+    }
+
+    #[rstest]
+    fn test_price_precision() {
+        assert_eq!(price_precision(AShareSecurityType::Stock), 2);
+        assert_eq!(price_precision(AShareSecurityType::Etf), 3);
+    }
+}
@@ -0,0 +1,51 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration structures for the A-share adapter.
+
+use std::sync::Arc;
+
+use ant_model::identifiers::{AccountId, TraderId};
+
+use crate::broker::AShareBrokerApi;
+
+/// Configuration for the A-share execution client.
+#[derive(Debug, Clone)]
+pub struct AShareExecClientConfig {
+    /// The trader ID for the client.
+    pub trader_id: TraderId,
+    /// The brokerage account (资金账号).
+    pub account: String,
+    /// The broker implementation.
+    pub broker: Arc<dyn AShareBrokerApi>,
+}
+
+impl AShareExecClientConfig {
+    /// Creates a new [`AShareExecClientConfig`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, account: String, broker: Arc<dyn AShareBrokerApi>) -> Self {
+        Self {
+            trader_id,
+            account,
+            broker,
+        }
+    }
+
+    /// Returns the account ID for the brokerage account, e.g. `ASHARE-880001`.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        AccountId::from(format!("ASHARE-{}", self.account).as_str())
+    }
+}
@@ -0,0 +1,931 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the A-share execution client.
+//!
+//! Orders are validated against the board lot rules of the instrument before being sent to the
+//! broker, which reports order events asynchronously. Events are queued to the client and must
+//! be drained from the engine thread with [`AShareExecutionClient::drain_events`] so that order
+//! events are published on the thread owning the message bus.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use ahash::AHashMap;
+use ant_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+        GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount, QueryOrder,
+        SubmitOrder, SubmitOrderList,
+    },
+};
+use ant_core::{UUID4, UnixNanos};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient, base::BaseExecutionClient};
+use ant_model::{
+    accounts::AccountAny,
+    enums::{
+        AccountType, LiquiditySide, OmsType, OrderSide, OrderType, PositionSideSpecified,
+        TimeInForce,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, Venue,
+        VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, Currency, MarginBalance, Money, Price, Quantity},
+};
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+use crate::{
+    broker::{AShareBrokerEvent, AShareOrderRequest},
+    common::{
+        consts::{ASHARE, ASHARE_SUPPORTED_EXCHANGES, ASHARE_SUPPORTED_TIME_IN_FORCE},
+        enums::AShareExchange,
+        rules::AShareLotRule,
+    },
+    config::AShareExecClientConfig,
+};
+
+/// The state of an order sent to the broker.
+#[derive(Clone, Copy, Debug)]
+struct AShareOrderState {
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: Option<VenueOrderId>,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quote_currency: Currency,
+    price_precision: u8,
+    size_precision: u8,
+    is_closed: bool,
+}
+
+/// An A-share execution client trading stocks and ETFs through a broker.
+///
+/// The client uses [`OmsType::Netting`] and a cash account, and reports the `ASHARE` venue, so
+/// routing must be registered with the execution engine for each exchange in
+/// [`Self::routing_venues`].
+pub struct AShareExecutionClient {
+    core: BaseExecutionClient,
+    config: AShareExecClientConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    is_connected: Cell<bool>,
+    event_rx: RefCell<Option<UnboundedReceiver<AShareBrokerEvent>>>,
+    orders: RefCell<AHashMap<ClientOrderId, AShareOrderState>>,
+    fills: RefCell<Vec<FillReport>>,
+}
+
+impl std::fmt::Debug for AShareExecutionClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(AShareExecutionClient))
+            .field("client_id", &self.core.client_id)
+            .field("account_id", &self.core.account_id)
+            .field("broker", &self.config.broker)
+            .finish()
+    }
+}
+
+impl AShareExecutionClient {
+    /// Creates a new [`AShareExecutionClient`] instance.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        config: AShareExecClientConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            client_id,
+            Venue::from(ASHARE),
+            OmsType::Netting,
+            config.account_id(),
+            AccountType::Cash,
+            Some(Currency::CNY()),
+            clock.clone(),
+            cache.clone(),
+        );
+
+        Self {
+            core,
+            config,
+            clock,
+            cache,
+            is_connected: Cell::new(false),
+            event_rx: RefCell::new(None),
+            orders: RefCell::new(AHashMap::new()),
+            fills: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the exchange venues which should be routed to this client.
+    #[must_use]
+    pub fn routing_venues() -> Vec<Venue> {
+        ASHARE_SUPPORTED_EXCHANGES
+            .iter()
+            .map(|exchange| Venue::from(*exchange))
+            .collect()
+    }
+
+    /// Processes all queued events from the broker, returning the number processed.
+    ///
+    /// Must be called from the thread owning the message bus, typically on each engine tick.
+    pub fn drain_events(&self) -> usize {
+        let mut count = 0;
+        loop {
+            // Release the receiver borrow before handling, as strategies may react to an order
+            // event by sending further commands to the client
+            let event = match self.event_rx.borrow_mut().as_mut() {
+                Some(rx) => match rx.try_recv() {
+                    Ok(event) => event,
+                    Err(_) => break,
+                },
+                None => break,
+            };
+            self.handle_event(event);
+            count += 1;
+        }
+        count
+    }
+
+    fn handle_event(&self, event: AShareBrokerEvent) {
+        match event {
+            AShareBrokerEvent::Accepted {
+                client_order_id,
+                broker_order_id,
+                ts_event,
+            } => {
+                let venue_order_id = VenueOrderId::new(&broker_order_id);
+                let Some(order) = self.update_order(&client_order_id, |order| {
+                    order.venue_order_id = Some(venue_order_id);
+                }) else {
+                    return;
+                };
+                self.core.generate_order_accepted(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            AShareBrokerEvent::Rejected {
+                client_order_id,
+                reason,
+                ts_event,
+            } => {
+                let Some(order) = self.update_order(&client_order_id, |order| {
+                    order.is_closed = true;
+                }) else {
+                    return;
+                };
+                self.core.generate_order_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    &reason,
+                    ts_event,
+                    false,
+                );
+            }
+            AShareBrokerEvent::Filled {
+                client_order_id,
+                broker_order_id,
+                trade_id,
+                price,
+                quantity,
+                commission,
+                ts_event,
+            } => {
+                let Some(order) = self.update_order(&client_order_id, |_| {}) else {
+                    return;
+                };
+                let venue_order_id = VenueOrderId::new(&broker_order_id);
+                let trade_id = TradeId::new(&trade_id);
+                let last_qty = Quantity::new(quantity as f64, order.size_precision);
+                let last_px = Price::new(price, order.price_precision);
+                let commission = Money::new(commission, order.quote_currency);
+
+                self.fills.borrow_mut().push(FillReport::new(
+                    self.core.account_id,
+                    order.instrument_id,
+                    venue_order_id,
+                    trade_id,
+                    order.order_side,
+                    last_qty,
+                    last_px,
+                    commission,
+                    LiquiditySide::NoLiquiditySide,
+                    Some(order.client_order_id),
+                    None,
+                    ts_event,
+                    self.clock.borrow().timestamp_ns(),
+                    None,
+                ));
+
+                self.core.generate_order_filled(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    venue_order_id,
+                    PositionId::new(format!("{}-{}", order.instrument_id, order.strategy_id)),
+                    trade_id,
+                    order.order_side,
+                    order.order_type,
+                    last_qty,
+                    last_px,
+                    order.quote_currency,
+                    commission,
+                    LiquiditySide::NoLiquiditySide,
+                    ts_event,
+                );
+            }
+            AShareBrokerEvent::Canceled {
+                client_order_id,
+                broker_order_id,
+                ts_event,
+            } => {
+                let Some(order) = self.update_order(&client_order_id, |order| {
+                    order.is_closed = true;
+                }) else {
+                    return;
+                };
+                self.core.generate_order_canceled(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    VenueOrderId::new(&broker_order_id),
+                    ts_event,
+                );
+            }
+            AShareBrokerEvent::CancelRejected {
+                client_order_id,
+                reason,
+                ts_event,
+            } => {
+                let Some(order) = self.update_order(&client_order_id, |_| {}) else {
+                    return;
+                };
+                self.core.generate_order_cancel_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order
+                        .venue_order_id
+                        .unwrap_or_else(|| VenueOrderId::from("NONE")),
+                    &reason,
+                    ts_event,
+                );
+            }
+        }
+    }
+
+    /// Applies `update` to the order state, returning a copy with the borrow released.
+    fn update_order(
+        &self,
+        client_order_id: &ClientOrderId,
+        update: impl FnOnce(&mut AShareOrderState),
+    ) -> Option<AShareOrderState> {
+        let mut orders = self.orders.borrow_mut();
+        let Some(order) = orders.get_mut(client_order_id) else {
+            log::warn!("Received broker event for unknown order {client_order_id}");
+            return None;
+        };
+        update(order);
+        Some(*order)
+    }
+
+    fn reject(&self, cmd: &SubmitOrder, reason: &str) {
+        log::error!("Rejecting order {}: {reason}", cmd.client_order_id);
+        self.core.generate_order_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            reason,
+            self.clock.borrow().timestamp_ns(),
+            false,
+        );
+    }
+
+    fn sellable_quantity(&self, instrument_id: &InstrumentId) -> anyhow::Result<u64> {
+        Ok(self
+            .config
+            .broker
+            .query_positions()?
+            .iter()
+            .find(|position| position.instrument_id == *instrument_id)
+            .map_or(0, |position| position.sellable))
+    }
+
+    fn validate(&self, order: &OrderAny, instrument: &InstrumentEnum) -> anyhow::Result<()> {
+        AShareExchange::try_from(order.instrument_id().venue)?;
+
+        match order.order_type() {
+            OrderType::Limit => {}
+            OrderType::Market if order.time_in_force() != TimeInForce::Day => {}
+            OrderType::Market => anyhow::bail!("Market orders must be IOC or FOK"),
+            order_type => anyhow::bail!("Unsupported order type {order_type}"),
+        }
+        if !ASHARE_SUPPORTED_TIME_IN_FORCE.contains(&order.time_in_force()) {
+            anyhow::bail!("Unsupported time in force {}", order.time_in_force());
+        }
+
+        let rule = AShareLotRule::from_instrument(instrument)
+            .ok_or_else(|| anyhow::anyhow!("No lot rule for {}", instrument.id()))?;
+        let quantity = order.quantity().as_f64() as u64;
+        let position = match order.order_side() {
+            OrderSide::Sell => self.sellable_quantity(&instrument.id())?,
+            _ => 0,
+        };
+        rule.validate(order.order_side(), quantity, position)?;
+
+        // Shares bought today only become sellable on the next trading day (T+1)
+        if order.order_side() == OrderSide::Sell && quantity > position {
+            anyhow::bail!("Sell quantity {quantity} exceeds sellable position {position}");
+        }
+        Ok(())
+    }
+
+    fn submit(&self, cmd: &SubmitOrder, order: &OrderAny) {
+        if !self.is_connected.get() {
+            self.reject(cmd, "Broker not connected");
+            return;
+        }
+
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id())
+            .cloned();
+        let Some(instrument) = instrument else {
+            return self.reject(
+                cmd,
+                &format!("Instrument {} not found", order.instrument_id()),
+            );
+        };
+
+        if let Err(e) = self.validate(order, &instrument) {
+            return self.reject(cmd, &e.to_string());
+        }
+
+        self.core.generate_order_submitted(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            self.clock.borrow().timestamp_ns(),
+        );
+
+        self.orders.borrow_mut().insert(
+            cmd.client_order_id,
+            AShareOrderState {
+                strategy_id: cmd.strategy_id,
+                instrument_id: cmd.instrument_id,
+                client_order_id: cmd.client_order_id,
+                venue_order_id: None,
+                order_side: order.order_side(),
+                order_type: order.order_type(),
+                quote_currency: instrument.quote_currency(),
+                price_precision: instrument.price_precision(),
+                size_precision: instrument.size_precision(),
+                is_closed: false,
+            },
+        );
+
+        let request = AShareOrderRequest {
+            client_order_id: cmd.client_order_id,
+            instrument_id: cmd.instrument_id,
+            side: order.order_side(),
+            order_type: order.order_type(),
+            price: order.price().map(|price| price.as_f64()),
+            quantity: order.quantity().as_f64() as u64,
+            time_in_force: order.time_in_force(),
+        };
+
+        if let Err(e) = self.config.broker.submit_order(&request) {
+            self.update_order(&cmd.client_order_id, |order| order.is_closed = true);
+            self.reject(cmd, &format!("Failed to send order: {e}"));
+            return;
+        }
+
+        self.drain_events();
+    }
+
+    fn cancel(&self, cmd: &CancelOrder) {
+        if let Err(e) = self.config.broker.cancel_order(&cmd.client_order_id) {
+            self.core.generate_order_cancel_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                &format!("Failed to send cancel: {e}"),
+                self.clock.borrow().timestamp_ns(),
+            );
+            return;
+        }
+
+        self.drain_events();
+    }
+}
+
+impl ExecutionClient for AShareExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.is_connected.get()
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        self.core.venue
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        log::info!("Started");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        log::info!("Stopped");
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(cmd, &cmd.order);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // Brokers have no native order lists, orders are submitted individually
+        for order in &cmd.order_list.orders {
+            let submit = SubmitOrder::new(
+                cmd.trader_id,
+                cmd.client_id,
+                cmd.strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                cmd.venue_order_id,
+                order.clone(),
+                cmd.exec_algorith_id,
+                cmd.position_id,
+                cmd.command_id,
+                cmd.ts_init,
+            )?;
+            self.submit(&submit, order);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        self.core.generate_order_modify_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            cmd.venue_order_id,
+            "A-share orders cannot be modified, cancel and replace instead",
+            self.clock.borrow().timestamp_ns(),
+        );
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        self.cancel(cmd);
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        let open_orders: Vec<AShareOrderState> = self
+            .orders
+            .borrow()
+            .values()
+            .filter(|order| {
+                !order.is_closed
+                    && order.instrument_id == cmd.instrument_id
+                    && (cmd.order_side == OrderSide::NoOrderSide
+                        || order.order_side == cmd.order_side)
+            })
+            .copied()
+            .collect();
+
+        for order in open_orders {
+            self.cancel(&CancelOrder {
+                trader_id: cmd.trader_id,
+                client_id: cmd.client_id,
+                strategy_id: order.strategy_id,
+                instrument_id: order.instrument_id,
+                client_order_id: order.client_order_id,
+                venue_order_id: order
+                    .venue_order_id
+                    .unwrap_or_else(|| VenueOrderId::from("NONE")),
+                command_id: UUID4::new(),
+                ts_init: cmd.ts_init,
+            });
+        }
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        for cancel in &cmd.cancels {
+            self.cancel(cancel);
+        }
+        Ok(())
+    }
+
+    fn query_account(&self, cmd: &QueryAccount) -> anyhow::Result<()> {
+        log::debug!("Account queries are not yet supported for A-share brokers: {cmd:?}");
+        Ok(())
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        log::debug!("Order queries are not yet supported for A-share brokers: {cmd:?}");
+        Ok(())
+    }
+
+    fn as_live(&self) -> Option<&dyn LiveExecutionClient> {
+        Some(self)
+    }
+}
+
+impl LiveExecutionClient for AShareExecutionClient {
+    fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected.get() {
+            return Ok(());
+        }
+
+        let (tx, rx) = unbounded_channel();
+        self.config.broker.connect(tx)?;
+        *self.event_rx.borrow_mut() = Some(rx);
+        self.is_connected.set(true);
+
+        log::info!("Connected to broker for account {}", self.core.account_id);
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> anyhow::Result<()> {
+        self.config.broker.disconnect()?;
+        self.drain_events();
+        *self.event_rx.borrow_mut() = None;
+        self.is_connected.set(false);
+        log::info!("Disconnected");
+        Ok(())
+    }
+
+    fn generate_order_status_report(
+        &self,
+        _cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        log::warn!("Order status reports are not supported by the broker API");
+        Ok(None)
+    }
+
+    fn generate_order_status_reports(
+        &self,
+        _cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        log::warn!("Order status reports are not supported by the broker API");
+        Ok(Vec::new())
+    }
+
+    fn generate_fill_reports(&self, cmd: GenerateFillReports) -> anyhow::Result<Vec<FillReport>> {
+        Ok(self
+            .fills
+            .borrow()
+            .iter()
+            .filter(|report| {
+                cmd.instrument_id()
+                    .is_none_or(|instrument_id| report.instrument_id == instrument_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        Ok(self
+            .config
+            .broker
+            .query_positions()?
+            .into_iter()
+            .filter(|position| {
+                cmd.instrument_id()
+                    .is_none_or(|instrument_id| position.instrument_id == instrument_id)
+            })
+            .map(|position| {
+                // A-share cash accounts cannot hold short positions
+                let position_side = if position.quantity > 0 {
+                    PositionSideSpecified::Long
+                } else {
+                    PositionSideSpecified::Flat
+                };
+                PositionStatusReport::new(
+                    self.core.account_id,
+                    position.instrument_id,
+                    position_side,
+                    Quantity::from(position.quantity),
+                    None,
+                    ts_init,
+                    ts_init,
+                    None,
+                )
+            })
+            .collect())
+    }
+
+    fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        log::debug!("Generating mass status (lookback_mins={lookback_mins:?})");
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            self.core.venue,
+            ts_init,
+            None,
+        );
+
+        mass_status.add_fill_reports(self.generate_fill_reports(GenerateFillReports::new(
+            UUID4::new(),
+            ts_init,
+            None,
+            None,
+            None,
+            None,
+        ))?);
+        mass_status.add_position_reports(self.generate_position_status_reports(
+            &GeneratePositionReports::new(UUID4::new(), ts_init, None, None, None),
+        )?);
+
+        Ok(Some(mass_status))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ant_common::{
+        clock::TestClock,
+        msgbus::{
+            self, get_message_saving_handler, get_saved_messages, handler::ShareableMessageHandler,
+        },
+    };
+    use ant_model::{
+        data::QuoteTick, events::OrderEventAny, identifiers::TraderId,
+        orders::builder::OrderTestBuilder,
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::*;
+    use crate::{broker::sim::SimulatedBroker, common::testing::test_data_path, loader};
+
+    #[fixture]
+    fn broker() -> Arc<SimulatedBroker> {
+        let broker = Arc::new(SimulatedBroker::new());
+        broker.update_quote(&QuoteTick::new(
+            InstrumentId::from("600000.SSE"),
+            Price::from("10.25"),
+            Price::from("10.26"),
+            Quantity::from(10_000),
+            Quantity::from(10_000),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        ));
+        broker
+    }
+
+    fn order_event_handler() -> ShareableMessageHandler {
+        let handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        msgbus::register("ExecEngine.process".into(), handler.clone());
+        handler
+    }
+
+    fn create_client(broker: Arc<SimulatedBroker>) -> AShareExecutionClient {
+        let mut cache = Cache::default();
+        for instrument in
+            loader::load_instruments(test_data_path("instruments.csv"), UnixNanos::default())
+                .unwrap()
+        {
+            cache.add_instrument(instrument).unwrap();
+        }
+
+        let config =
+            AShareExecClientConfig::new(TraderId::from("TRADER-001"), "880001".to_string(), broker);
+
+        AShareExecutionClient::new(
+            ClientId::from("ASHARE"),
+            config,
+            Rc::new(RefCell::new(TestClock::new())),
+            Rc::new(RefCell::new(cache)),
+        )
+    }
+
+    fn limit_order(instrument_id: &str, side: OrderSide, quantity: u64, price: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from(instrument_id))
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .price(Price::from(price))
+            .time_in_force(TimeInForce::Day)
+            .build()
+    }
+
+    fn submit_order(order: &OrderAny) -> SubmitOrder {
+        SubmitOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("ASHARE"),
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::from("NONE"),
+            order: order.clone(),
+            exec_algorith_id: None,
+            position_id: None,
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        }
+    }
+
+    #[rstest]
+    fn test_submit_order_when_not_connected_rejects(broker: Arc<SimulatedBroker>) {
+        let handler = order_event_handler();
+        let client = create_client(broker);
+        let order = limit_order("600000.SSE", OrderSide::Buy, 100, "10.26");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_submit_marketable_order_fills(broker: Arc<SimulatedBroker>) {
+        let handler = order_event_handler();
+        let mut client = create_client(broker);
+        client.connect().unwrap();
+        let order = limit_order("600000.SSE", OrderSide::Buy, 200, "10.30");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], OrderEventAny::Submitted(_)));
+        assert!(matches!(events[1], OrderEventAny::Accepted(_)));
+        let OrderEventAny::Filled(fill) = &events[2] else {
+            panic!("Expected fill, was {:?}", events[2]);
+        };
+        assert_eq!(fill.last_qty, Quantity::from(200));
+        assert_eq!(fill.last_px, Price::from("10.26"));
+        assert_eq!(fill.currency, Currency::CNY());
+    }
+
+    #[rstest]
+    #[case("600000.SSE", 150, "10.26")]
+    #[case("688981.SSE", 100, "50.00")]
+    fn test_submit_order_with_invalid_lot_rejects(
+        broker: Arc<SimulatedBroker>,
+        #[case] instrument_id: &str,
+        #[case] quantity: u64,
+        #[case] price: &str,
+    ) {
+        let handler = order_event_handler();
+        let mut client = create_client(broker);
+        client.connect().unwrap();
+        let order = limit_order(instrument_id, OrderSide::Buy, quantity, price);
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_sell_without_sellable_position_rejects(broker: Arc<SimulatedBroker>) {
+        let handler = order_event_handler();
+        let mut client = create_client(broker);
+        client.connect().unwrap();
+        let order = limit_order("600000.SSE", OrderSide::Sell, 100, "10.25");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    }
+
+    #[rstest]
+    fn test_sell_odd_lot_of_whole_position_is_accepted(broker: Arc<SimulatedBroker>) {
+        broker.add_position(InstrumentId::from("600000.SSE"), 150);
+        let handler = order_event_handler();
+        let mut client = create_client(broker);
+        client.connect().unwrap();
+        let order = limit_order("600000.SSE", OrderSide::Sell, 150, "10.25");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert!(matches!(events.last(), Some(OrderEventAny::Filled(_))));
+    }
+
+    #[rstest]
+    fn test_cancel_resting_order(broker: Arc<SimulatedBroker>) {
+        let handler = order_event_handler();
+        let mut client = create_client(broker);
+        client.connect().unwrap();
+        let order = limit_order("600000.SSE", OrderSide::Buy, 100, "10.00");
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        client
+            .cancel_all_orders(&CancelAllOrders {
+                trader_id: TraderId::from("TRADER-001"),
+                client_id: ClientId::from("ASHARE"),
+                strategy_id: StrategyId::from("S-001"),
+                instrument_id: order.instrument_id(),
+                order_side: OrderSide::NoOrderSide,
+                command_id: UUID4::new(),
+                ts_init: UnixNanos::default(),
+            })
+            .unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[2], OrderEventAny::Canceled(_)));
+    }
+
+    #[rstest]
+    fn test_generate_position_status_reports(broker: Arc<SimulatedBroker>) {
+        broker.add_position(InstrumentId::from("600000.SSE"), 500);
+        let mut client = create_client(broker);
+        client.connect().unwrap();
+
+        let reports = client
+            .generate_position_status_reports(&GeneratePositionReports::new(
+                UUID4::new(),
+                UnixNanos::default(),
+                None,
+                None,
+                None,
+            ))
+            .unwrap();
+
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].position_side, PositionSideSpecified::Long);
+        assert_eq!(reports[0].quantity, Quantity::from(500));
+    }
+}
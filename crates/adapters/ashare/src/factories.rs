@@ -0,0 +1,81 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating A-share clients.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use ant_common::{cache::Cache, clock::Clock};
+use ant_execution::client::ExecutionClient;
+use ant_model::identifiers::ClientId;
+use ant_system::factories::{ClientConfig, ExecutionClientFactory};
+
+use crate::{config::AShareExecClientConfig, execution::AShareExecutionClient};
+
+impl ClientConfig for AShareExecClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating A-share execution clients.
+#[derive(Debug)]
+pub struct AShareExecutionClientFactory;
+
+impl AShareExecutionClientFactory {
+    /// Creates a new [`AShareExecutionClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for AShareExecutionClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutionClientFactory for AShareExecutionClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn ExecutionClient>> {
+        let ashare_config = config
+            .as_any()
+            .downcast_ref::<AShareExecClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for AShareExecutionClientFactory. Expected AShareExecClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client =
+            AShareExecutionClient::new(ClientId::from(name), ashare_config.clone(), clock, cache);
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        "ASHARE"
+    }
+
+    fn config_type(&self) -> &'static str {
+        "AShareExecClientConfig"
+    }
+}
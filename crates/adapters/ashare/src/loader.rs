@@ -0,0 +1,319 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Loaders for A-share instrument definitions and market data stored as CSV.
+
+use std::{fs::File, path::Path};
+
+use ant_core::UnixNanos;
+use ant_model::{
+    data::{OrderBookDepth10, QuoteTick, TradeTick},
+    instruments::{Instrument, InstrumentEnum},
+};
+use csv::{Reader, StringRecord};
+
+use crate::common::{
+    models::{AShareInstrumentRecord, AShareSnapshot, AShareTradeRecord},
+    parse::{
+        parse_ashare_timestamp, parse_instrument, parse_instrument_id, parse_order_book_depth10,
+        parse_quote_tick, parse_snapshot_trade_tick, parse_trade_tick,
+    },
+};
+
+fn create_csv_reader<P: AsRef<Path>>(filepath: P) -> anyhow::Result<Reader<File>> {
+    let filepath = filepath.as_ref();
+    let file = File::open(filepath)
+        .map_err(|e| anyhow::anyhow!("Failed to open file '{}': {e}", filepath.display()))?;
+    Ok(Reader::from_reader(file))
+}
+
+/// Loads A-share instruments from a CSV of instrument definitions.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a definition is invalid.
+pub fn load_instruments<P: AsRef<Path>>(
+    filepath: P,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Vec<InstrumentEnum>> {
+    let mut reader = create_csv_reader(filepath)?;
+    reader
+        .deserialize::<AShareInstrumentRecord>()
+        .map(|record| parse_instrument(&record?, ts_init))
+        .collect()
+}
+
+/// Loads Level-1 or Level-2 snapshots from a CSV.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a snapshot is invalid.
+pub fn load_snapshots<P: AsRef<Path>>(filepath: P) -> anyhow::Result<Vec<AShareSnapshot>> {
+    let mut reader = create_csv_reader(filepath)?;
+    let headers = reader.headers()?.clone();
+    let mut record = StringRecord::new();
+    let mut snapshots = Vec::new();
+
+    while reader.read_record(&mut record)? {
+        snapshots.push(AShareSnapshot::from_csv_record(&headers, &record)?);
+    }
+
+    Ok(snapshots)
+}
+
+/// Loads Level-2 tick-by-tick trades from a CSV.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a trade is invalid.
+pub fn load_trade_records<P: AsRef<Path>>(filepath: P) -> anyhow::Result<Vec<AShareTradeRecord>> {
+    let mut reader = create_csv_reader(filepath)?;
+    reader
+        .deserialize()
+        .map(|record| record.map_err(anyhow::Error::from))
+        .collect()
+}
+
+fn load_instrument_snapshots<P: AsRef<Path>>(
+    filepath: P,
+    instrument: &InstrumentEnum,
+) -> anyhow::Result<Vec<AShareSnapshot>> {
+    let instrument_id = instrument.id();
+    Ok(load_snapshots(filepath)?
+        .into_iter()
+        .filter(|snapshot| parse_instrument_id(snapshot.symbol, snapshot.exchange) == instrument_id)
+        .collect())
+}
+
+/// Loads [`QuoteTick`]s for `instrument` from a snapshot CSV.
+///
+/// Snapshots with an empty side of the book (e.g. at a price limit) are skipped.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a snapshot is invalid.
+pub fn load_quotes<P: AsRef<Path>>(
+    filepath: P,
+    instrument: &InstrumentEnum,
+) -> anyhow::Result<Vec<QuoteTick>> {
+    let mut quotes = Vec::new();
+    for snapshot in load_instrument_snapshots(filepath, instrument)? {
+        let ts_init = parse_snapshot_ts(&snapshot)?;
+        if let Some(quote) = parse_quote_tick(&snapshot, instrument, ts_init)? {
+            quotes.push(quote);
+        }
+    }
+    Ok(quotes)
+}
+
+/// Loads [`OrderBookDepth10`]s for `instrument` from a snapshot CSV.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a snapshot is invalid.
+pub fn load_depth10<P: AsRef<Path>>(
+    filepath: P,
+    instrument: &InstrumentEnum,
+) -> anyhow::Result<Vec<OrderBookDepth10>> {
+    load_instrument_snapshots(filepath, instrument)?
+        .iter()
+        .map(|snapshot| {
+            parse_order_book_depth10(snapshot, instrument, parse_snapshot_ts(snapshot)?)
+        })
+        .collect()
+}
+
+/// Loads [`TradeTick`]s for `instrument` derived from the cumulative volume of a snapshot CSV.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a snapshot is invalid.
+pub fn load_snapshot_trades<P: AsRef<Path>>(
+    filepath: P,
+    instrument: &InstrumentEnum,
+) -> anyhow::Result<Vec<TradeTick>> {
+    let mut trades = Vec::new();
+    let mut last_volume = None;
+    for snapshot in load_instrument_snapshots(filepath, instrument)? {
+        let ts_init = parse_snapshot_ts(&snapshot)?;
+        if let Some(trade) = parse_snapshot_trade_tick(&snapshot, instrument, last_volume, ts_init)?
+        {
+            trades.push(trade);
+        }
+        last_volume = Some(snapshot.volume);
+    }
+    Ok(trades)
+}
+
+/// Loads [`TradeTick`]s for `instrument` from a Level-2 tick-by-tick trade CSV.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a trade is invalid.
+pub fn load_trades<P: AsRef<Path>>(
+    filepath: P,
+    instrument: &InstrumentEnum,
+) -> anyhow::Result<Vec<TradeTick>> {
+    let instrument_id = instrument.id();
+    let mut trades = Vec::new();
+    for record in load_trade_records(filepath)? {
+        if parse_instrument_id(record.symbol, record.exchange) != instrument_id {
+            continue;
+        }
+        let ts_init = parse_ashare_timestamp(&record.trading_date, &record.time)?;
+        trades.push(parse_trade_tick(&record, instrument, ts_init)?);
+    }
+    Ok(trades)
+}
+
+// Historical data is initialized at the event time
+fn parse_snapshot_ts(snapshot: &AShareSnapshot) -> anyhow::Result<UnixNanos> {
+    parse_ashare_timestamp(&snapshot.trading_date, &snapshot.time)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ant_model::{
+        enums::AggressorSide,
+        identifiers::{InstrumentId, TradeId},
+        instruments::Instrument,
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::common::testing::test_data_path;
+
+    #[fixture]
+    fn instruments() -> Vec<InstrumentEnum> {
+        load_instruments(test_data_path("instruments.csv"), UnixNanos::default()).unwrap()
+    }
+
+    fn instrument(instruments: &[InstrumentEnum], instrument_id: &str) -> InstrumentEnum {
+        let instrument_id = InstrumentId::from(instrument_id);
+        instruments
+            .iter()
+            .find(|instrument| instrument.id() == instrument_id)
+            .cloned()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_load_instruments(instruments: Vec<InstrumentEnum>) {
+        assert_eq!(instruments.len(), 5);
+
+        let main = instrument(&instruments, "600000.SSE");
+        assert_eq!(main.price_increment(), Price::from("0.01"));
+        assert_eq!(main.lot_size(), Some(Quantity::from(100)));
+        assert_eq!(main.min_quantity(), Some(Quantity::from(100)));
+        assert_eq!(main.quote_currency().code.as_str(), "CNY");
+
+        let star = instrument(&instruments, "688981.SSE");
+        assert_eq!(star.lot_size(), Some(Quantity::from(1)));
+        assert_eq!(star.min_quantity(), Some(Quantity::from(200)));
+        assert_eq!(star.max_quantity(), Some(Quantity::from(100_000)));
+
+        let chinext = instrument(&instruments, "300750.SZSE");
+        assert_eq!(chinext.lot_size(), Some(Quantity::from(100)));
+        assert_eq!(chinext.max_quantity(), Some(Quantity::from(300_000)));
+
+        let etf = instrument(&instruments, "510300.SSE");
+        assert_eq!(etf.price_increment(), Price::from("0.001"));
+
+        let bse = instrument(&instruments, "830799.BSE");
+        assert_eq!(bse.lot_size(), Some(Quantity::from(1)));
+        assert_eq!(bse.min_quantity(), Some(Quantity::from(100)));
+    }
+
+    #[rstest]
+    fn test_load_quotes_level1(instruments: Vec<InstrumentEnum>) {
+        let instrument = instrument(&instruments, "600000.SSE");
+
+        let quotes = load_quotes(test_data_path("snapshots_l1.csv"), &instrument).unwrap();
+
+        assert_eq!(quotes.len(), 3);
+        assert_eq!(quotes[1].bid_price, Price::from("10.25"));
+        assert_eq!(quotes[1].ask_price, Price::from("10.26"));
+        assert_eq!(quotes[1].bid_size, Quantity::from(1100));
+        // 09:30:03 China Standard Time
+        assert_eq!(quotes[1].ts_event.as_u64(), 1_736_127_003_000_000_000);
+    }
+
+    #[rstest]
+    fn test_load_quotes_skips_price_limit(instruments: Vec<InstrumentEnum>) {
+        let instrument = instrument(&instruments, "600000.SSE");
+
+        let quotes = load_quotes(test_data_path("snapshots_l2.csv"), &instrument).unwrap();
+
+        assert!(quotes.is_empty());
+    }
+
+    #[rstest]
+    fn test_load_depth10_level1_pads_empty_levels(instruments: Vec<InstrumentEnum>) {
+        let instrument = instrument(&instruments, "600000.SSE");
+
+        let depths = load_depth10(test_data_path("snapshots_l1.csv"), &instrument).unwrap();
+
+        assert_eq!(depths.len(), 3);
+        let depth = &depths[1];
+        assert_eq!(depth.bids[4].price, Price::from("10.21"));
+        assert_eq!(depth.bid_counts[4], 1);
+        assert_eq!(depth.bid_counts[5], 0);
+        assert_eq!(depth.asks[5].size, Quantity::from(0));
+    }
+
+    #[rstest]
+    fn test_load_depth10_level2(instruments: Vec<InstrumentEnum>) {
+        let instrument = instrument(&instruments, "600000.SSE");
+
+        let depths = load_depth10(test_data_path("snapshots_l2.csv"), &instrument).unwrap();
+
+        let depth = &depths[0];
+        assert_eq!(depth.bids[9].price, Price::from("11.18"));
+        assert_eq!(depth.bid_counts[9], 12);
+        // Trading limit up, so there are no offers
+        assert_eq!(depth.ask_counts, [0; 10]);
+    }
+
+    #[rstest]
+    fn test_load_snapshot_trades(instruments: Vec<InstrumentEnum>) {
+        let instrument = instrument(&instruments, "600000.SSE");
+
+        let trades = load_snapshot_trades(test_data_path("snapshots_l1.csv"), &instrument).unwrap();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].size, Quantity::from(152_300));
+        assert_eq!(trades[1].size, Quantity::from(6_500));
+        assert_eq!(trades[1].price, Price::from("10.26"));
+        assert_eq!(trades[1].aggressor_side, AggressorSide::NoAggressor);
+    }
+
+    #[rstest]
+    fn test_load_trades_level2(instruments: Vec<InstrumentEnum>) {
+        let instrument = instrument(&instruments, "600000.SSE");
+
+        let trades = load_trades(test_data_path("trades_l2.csv"), &instrument).unwrap();
+
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].aggressor_side, AggressorSide::NoAggressor);
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trades[2].aggressor_side, AggressorSide::Seller);
+        assert_eq!(trades[2].trade_id, TradeId::from("20250106-1003"));
+        assert_eq!(trades[2].size, Quantity::from(1200));
+    }
+}
@@ -13,7 +13,189 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Configuration types for the BitMEX adapter.
+//! Configuration structures for the BitMEX adapter.
 //!
-//! This module is reserved for future configuration structures for the BitMEX adapter,
-//! including settings for WebSocket connections, HTTP client parameters, and adapter behavior.
+//! Credentials which are not provided are read from the `BITMEX_API_KEY` and `BITMEX_API_SECRET`
+//! environment variables, and URLs which are not provided default to the production (or testnet)
+//! endpoints.
+
+use ant_model::identifiers::{AccountId, TraderId};
+
+use crate::common::consts::{
+    BITMEX_HTTP_TESTNET_URL, BITMEX_HTTP_URL, BITMEX_WS_TESTNET_URL, BITMEX_WS_URL,
+};
+
+/// Configuration for the BitMEX data client.
+#[derive(Debug, Clone)]
+pub struct BitmexDataClientConfig {
+    /// The API key (only required for private data).
+    pub api_key: Option<String>,
+    /// The API secret.
+    pub api_secret: Option<String>,
+    /// If only active instruments should be loaded on connect.
+    pub active_only: bool,
+    /// The HTTP base URL override.
+    pub base_url_http: Option<String>,
+    /// The WebSocket URL override.
+    pub base_url_ws: Option<String>,
+    /// If the testnet endpoints should be used.
+    pub testnet: bool,
+    /// The HTTP request timeout in seconds.
+    pub http_timeout_secs: Option<u64>,
+}
+
+impl Default for BitmexDataClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_secret: None,
+            active_only: true,
+            base_url_http: None,
+            base_url_ws: None,
+            testnet: false,
+            http_timeout_secs: Some(60),
+        }
+    }
+}
+
+impl BitmexDataClientConfig {
+    /// Returns whether both of the API credentials have been provided.
+    #[must_use]
+    pub const fn has_api_credentials(&self) -> bool {
+        self.api_key.is_some() && self.api_secret.is_some()
+    }
+
+    /// Returns the HTTP base URL.
+    #[must_use]
+    pub fn http_base_url(&self) -> String {
+        self.base_url_http
+            .clone()
+            .unwrap_or_else(|| http_base_url(self.testnet))
+    }
+
+    /// Returns the WebSocket URL.
+    #[must_use]
+    pub fn ws_url(&self) -> String {
+        self.base_url_ws
+            .clone()
+            .unwrap_or_else(|| ws_url(self.testnet))
+    }
+}
+
+/// Configuration for the BitMEX execution client.
+#[derive(Debug, Clone)]
+pub struct BitmexExecClientConfig {
+    /// The trader ID for the client.
+    pub trader_id: TraderId,
+    /// The account ID for the client.
+    pub account_id: AccountId,
+    /// The API key (read from `BITMEX_API_KEY` if `None`).
+    pub api_key: Option<String>,
+    /// The API secret (read from `BITMEX_API_SECRET` if `None`).
+    pub api_secret: Option<String>,
+    /// If only active instruments should be loaded on connect.
+    pub active_only: bool,
+    /// The dead man's switch timeout in milliseconds, after which BitMEX cancels all open
+    /// orders unless the switch is refreshed (disabled if `None`).
+    pub cancel_all_after_ms: Option<u64>,
+    /// The interval in seconds between dead man's switch refreshes.
+    pub cancel_all_after_refresh_secs: u64,
+    /// The HTTP base URL override.
+    pub base_url_http: Option<String>,
+    /// The WebSocket URL override.
+    pub base_url_ws: Option<String>,
+    /// If the testnet endpoints should be used.
+    pub testnet: bool,
+    /// The HTTP request timeout in seconds.
+    pub http_timeout_secs: Option<u64>,
+}
+
+impl BitmexExecClientConfig {
+    /// Creates a new [`BitmexExecClientConfig`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, account_id: AccountId) -> Self {
+        Self {
+            trader_id,
+            account_id,
+            api_key: None,
+            api_secret: None,
+            active_only: true,
+            cancel_all_after_ms: None,
+            cancel_all_after_refresh_secs: 15,
+            base_url_http: None,
+            base_url_ws: None,
+            testnet: false,
+            http_timeout_secs: Some(60),
+        }
+    }
+
+    /// Returns the HTTP base URL.
+    #[must_use]
+    pub fn http_base_url(&self) -> String {
+        self.base_url_http
+            .clone()
+            .unwrap_or_else(|| http_base_url(self.testnet))
+    }
+
+    /// Returns the WebSocket URL.
+    #[must_use]
+    pub fn ws_url(&self) -> String {
+        self.base_url_ws
+            .clone()
+            .unwrap_or_else(|| ws_url(self.testnet))
+    }
+}
+
+fn http_base_url(testnet: bool) -> String {
+    if testnet {
+        BITMEX_HTTP_TESTNET_URL.to_string()
+    } else {
+        BITMEX_HTTP_URL.to_string()
+    }
+}
+
+fn ws_url(testnet: bool) -> String {
+    if testnet {
+        BITMEX_WS_TESTNET_URL.to_string()
+    } else {
+        BITMEX_WS_URL.to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_data_client_config_urls() {
+        let mut config = BitmexDataClientConfig::default();
+        assert_eq!(config.http_base_url(), BITMEX_HTTP_URL);
+        assert_eq!(config.ws_url(), BITMEX_WS_URL);
+        assert!(!config.has_api_credentials());
+
+        config.testnet = true;
+        assert_eq!(config.http_base_url(), BITMEX_HTTP_TESTNET_URL);
+        assert_eq!(config.ws_url(), BITMEX_WS_TESTNET_URL);
+
+        config.base_url_ws = Some("ws://127.0.0.1:8080/realtime".to_string());
+        assert_eq!(config.ws_url(), "ws://127.0.0.1:8080/realtime");
+    }
+
+    #[rstest]
+    fn test_exec_client_config_defaults() {
+        let config = BitmexExecClientConfig::new(
+            TraderId::from("TRADER-001"),
+            AccountId::from("BITMEX-001"),
+        );
+
+        assert_eq!(config.cancel_all_after_ms, None);
+        assert_eq!(config.cancel_all_after_refresh_secs, 15);
+        assert_eq!(config.http_base_url(), BITMEX_HTTP_URL);
+    }
+}
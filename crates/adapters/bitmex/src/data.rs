@@ -0,0 +1,649 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the BitMEX live market data client.
+//!
+//! Instrument definitions are loaded over HTTP on connect, after which market data is streamed
+//! from the WebSocket. Historical trades are requested over HTTP.
+
+use std::{
+    future::Future,
+    num::NonZeroUsize,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use ahash::AHashMap;
+use ant_common::{
+    messages::{
+        DataEvent, DataResponse,
+        data::{
+            InstrumentResponse, InstrumentsResponse, RequestInstrument, RequestInstruments,
+            RequestTrades, SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10,
+            SubscribeFundingRates, SubscribeIndexPrices, SubscribeInstruments, SubscribeMarkPrices,
+            SubscribeQuotes, SubscribeTrades, TradesResponse, UnsubscribeBars,
+            UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeFundingRates,
+            UnsubscribeIndexPrices, UnsubscribeInstruments, UnsubscribeMarkPrices,
+            UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+    runtime::get_runtime,
+};
+use ant_core::{UnixNanos, time::AtomicTime};
+use ant_data::client::DataClient;
+use ant_model::{
+    data::Data,
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::{Instrument, InstrumentEnum},
+};
+use futures_util::{Stream, StreamExt};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle};
+
+use crate::{
+    common::consts::BITMEX_VENUE,
+    config::BitmexDataClientConfig,
+    http::client::BitmexHttpClient,
+    websocket::{
+        client::BitmexWebSocketClient, enums::BitmexWsTopic, error::BitmexWsError,
+        messages::AntWsMessage,
+    },
+};
+
+/// The heartbeat interval in seconds.
+const BITMEX_WS_HEARTBEAT_SECS: u64 = 5;
+
+/// The maximum time in seconds to wait for the WebSocket connection to become active.
+const BITMEX_WS_CONNECT_TIMEOUT_SECS: f64 = 10.0;
+
+/// The depth of the partial order book channel, full depth is used otherwise.
+const BITMEX_BOOK_DEPTH_25: usize = 25;
+
+/// A BitMEX data client streaming market data for spot, perpetual and futures instruments.
+#[derive(Debug)]
+pub struct BitmexDataClient {
+    client_id: ClientId,
+    config: BitmexDataClientConfig,
+    clock: &'static AtomicTime,
+    http_client: BitmexHttpClient,
+    ws_client: BitmexWebSocketClient,
+    is_connected: Arc<AtomicBool>,
+    instruments: Arc<RwLock<AHashMap<InstrumentId, InstrumentEnum>>>,
+    task_handles: Vec<JoinHandle<()>>,
+    data_sender: UnboundedSender<DataEvent>,
+}
+
+impl BitmexDataClient {
+    /// Creates a new [`BitmexDataClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP or WebSocket clients cannot be created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data event sender has not been initialized by the runner.
+    pub fn new(
+        client_id: ClientId,
+        config: BitmexDataClientConfig,
+        clock: &'static AtomicTime,
+    ) -> anyhow::Result<Self> {
+        let http_client = BitmexHttpClient::new(
+            Some(config.http_base_url()),
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.testnet,
+            config.http_timeout_secs,
+            None,
+            None,
+            None,
+        )?;
+
+        // Market data topics are public, so the WebSocket session is never authenticated
+        let ws_client = BitmexWebSocketClient::new(
+            Some(config.ws_url()),
+            None,
+            None,
+            None,
+            Some(BITMEX_WS_HEARTBEAT_SECS),
+        )?;
+
+        Ok(Self {
+            client_id,
+            config,
+            clock,
+            http_client,
+            ws_client,
+            is_connected: Arc::new(AtomicBool::new(false)),
+            instruments: Arc::new(RwLock::new(AHashMap::new())),
+            task_handles: Vec::new(),
+            data_sender: get_data_event_sender(),
+        })
+    }
+
+    /// Returns the instruments loaded from BitMEX.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instruments lock is poisoned.
+    #[must_use]
+    pub fn instruments(&self) -> Vec<InstrumentEnum> {
+        self.instruments
+            .read()
+            .expect("Instruments lock poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    async fn load_instruments(&mut self) -> anyhow::Result<Vec<InstrumentEnum>> {
+        let instruments = self
+            .http_client
+            .request_instruments(self.config.active_only)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to load instruments: {e}"))?;
+        tracing::info!("Loaded {} instruments", instruments.len());
+
+        self.http_client.add_instruments(instruments.clone());
+
+        let mut map = self.instruments.write().expect("Instruments lock poisoned");
+        for instrument in &instruments {
+            map.insert(instrument.id(), instrument.clone());
+        }
+        Ok(instruments)
+    }
+
+    fn check_instrument(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        if self
+            .instruments
+            .read()
+            .expect("Instruments lock poisoned")
+            .contains_key(instrument_id)
+        {
+            Ok(())
+        } else {
+            anyhow::bail!("Instrument {instrument_id} not found for BitMEX")
+        }
+    }
+
+    /// Spawns a WebSocket (un)subscription, which completes asynchronously.
+    fn spawn_ws<F>(&self, fut: F, context: String)
+    where
+        F: Future<Output = Result<(), BitmexWsError>> + Send + 'static,
+    {
+        get_runtime().spawn(async move {
+            if let Err(e) = fut.await {
+                tracing::error!("{context}: {e}");
+            }
+        });
+    }
+
+    fn send_response(sender: &UnboundedSender<DataEvent>, response: DataResponse) {
+        if let Err(e) = sender.send(DataEvent::Response(response)) {
+            tracing::error!("Failed to send data response: {e}");
+        }
+    }
+}
+
+/// Forwards data from the BitMEX WebSocket stream until the stream ends.
+async fn run_ws_stream(
+    stream: impl Stream<Item = AntWsMessage> + 'static,
+    data_sender: UnboundedSender<DataEvent>,
+) {
+    tokio::pin!(stream);
+
+    while let Some(msg) = stream.next().await {
+        let data: Vec<Data> = match msg {
+            AntWsMessage::Data(data) => data,
+            AntWsMessage::FundingRateUpdates(updates) => {
+                updates.into_iter().map(Data::FundingRateUpdate).collect()
+            }
+            AntWsMessage::Reconnected => {
+                tracing::info!("BitMEX WebSocket reconnected");
+                continue;
+            }
+            other => {
+                tracing::debug!("Unhandled WebSocket message: {other:?}");
+                continue;
+            }
+        };
+
+        for data in data {
+            if let Err(e) = data_sender.send(DataEvent::Data(data)) {
+                tracing::error!("Failed to send data: {e}");
+            }
+        }
+    }
+
+    tracing::debug!("WebSocket stream ended");
+}
+
+fn limit_to_u32(limit: Option<NonZeroUsize>) -> Option<u32> {
+    limit.map(|limit| u32::try_from(limit.get()).unwrap_or(u32::MAX))
+}
+
+#[async_trait::async_trait]
+impl DataClient for BitmexDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    fn venue(&self) -> Option<Venue> {
+        Some(*BITMEX_VENUE)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Starting");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Stopping");
+        for handle in self.task_handles.drain(..) {
+            handle.abort();
+        }
+        self.http_client.cancel_all_requests();
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Resetting");
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Disposing");
+        self.stop()
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+        tracing::debug!("Connecting...");
+
+        let instruments = self.load_instruments().await?;
+
+        // The instruments cache must be initialized before connecting, as it is captured by
+        // the message handler for parsing
+        self.ws_client.initialize_instruments_cache(instruments);
+        self.ws_client.connect().await?;
+        self.ws_client
+            .wait_until_active(BITMEX_WS_CONNECT_TIMEOUT_SECS)
+            .await?;
+
+        let stream = self.ws_client.stream();
+        self.task_handles
+            .push(get_runtime().spawn(run_ws_stream(stream, self.data_sender.clone())));
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Connected to {}", self.ws_client.url());
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Disconnecting...");
+
+        if let Err(e) = self.ws_client.close().await {
+            tracing::error!("Failed to close WebSocket: {e}");
+        }
+
+        for handle in self.task_handles.drain(..) {
+            handle.abort();
+            if let Err(e) = handle.await
+                && !e.is_cancelled()
+            {
+                tracing::error!("Task join error: {e}");
+            }
+        }
+
+        self.is_connected.store(false, Ordering::Relaxed);
+        tracing::info!("Disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_instruments(&mut self, cmd: &SubscribeInstruments) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe instruments: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move { ws.subscribe_instruments().await },
+            "Failed to subscribe instruments".to_string(),
+        );
+        Ok(())
+    }
+
+    fn subscribe_book_deltas(&mut self, cmd: &SubscribeBookDeltas) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe book deltas: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+        anyhow::ensure!(
+            cmd.book_type == BookType::L2_MBP,
+            "BitMEX only provides L2_MBP order book data, got {:?}",
+            cmd.book_type
+        );
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        if cmd.depth.map(NonZeroUsize::get) == Some(BITMEX_BOOK_DEPTH_25) {
+            self.spawn_ws(
+                async move { ws.subscribe_book_25(instrument_id).await },
+                format!("Failed to subscribe book deltas (depth 25) for {instrument_id}"),
+            );
+        } else {
+            self.spawn_ws(
+                async move { ws.subscribe_book(instrument_id).await },
+                format!("Failed to subscribe book deltas for {instrument_id}"),
+            );
+        }
+        Ok(())
+    }
+
+    fn subscribe_book_depth10(&mut self, cmd: &SubscribeBookDepth10) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe book depth10: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.subscribe_book_depth10(instrument_id).await },
+            format!("Failed to subscribe book depth10 for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe quotes: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.subscribe_quotes(instrument_id).await },
+            format!("Failed to subscribe quotes for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe trades: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.subscribe_trades(instrument_id).await },
+            format!("Failed to subscribe trades for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn subscribe_mark_prices(&mut self, cmd: &SubscribeMarkPrices) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe mark prices: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.subscribe_mark_prices(instrument_id).await },
+            format!("Failed to subscribe mark prices for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn subscribe_index_prices(&mut self, cmd: &SubscribeIndexPrices) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe index prices: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.subscribe_index_prices(instrument_id).await },
+            format!("Failed to subscribe index prices for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn subscribe_funding_rates(&mut self, cmd: &SubscribeFundingRates) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe funding rates: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.subscribe_funding_rates(instrument_id).await },
+            format!("Failed to subscribe funding rates for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn subscribe_bars(&mut self, cmd: &SubscribeBars) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe bars: {cmd:?}");
+        self.check_instrument(&cmd.bar_type.instrument_id())?;
+
+        let ws = self.ws_client.clone();
+        let bar_type = cmd.bar_type;
+        self.spawn_ws(
+            async move { ws.subscribe_bars(bar_type).await },
+            format!("Failed to subscribe bars for {bar_type}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_instruments(&mut self, cmd: &UnsubscribeInstruments) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe instruments: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        self.spawn_ws(
+            async move { ws.unsubscribe_instruments().await },
+            "Failed to unsubscribe instruments".to_string(),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_book_deltas(&mut self, cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe book deltas: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        let topic_25 = format!("{}:{}", BitmexWsTopic::OrderBookL2_25, instrument_id.symbol);
+        if ws.get_subscriptions(instrument_id).contains(&topic_25) {
+            self.spawn_ws(
+                async move { ws.unsubscribe_book_25(instrument_id).await },
+                format!("Failed to unsubscribe book deltas (depth 25) for {instrument_id}"),
+            );
+        } else {
+            self.spawn_ws(
+                async move { ws.unsubscribe_book(instrument_id).await },
+                format!("Failed to unsubscribe book deltas for {instrument_id}"),
+            );
+        }
+        Ok(())
+    }
+
+    fn unsubscribe_book_depth10(&mut self, cmd: &UnsubscribeBookDepth10) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe book depth10: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.unsubscribe_book_depth10(instrument_id).await },
+            format!("Failed to unsubscribe book depth10 for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_quotes(&mut self, cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe quotes: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.unsubscribe_quotes(instrument_id).await },
+            format!("Failed to unsubscribe quotes for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_trades(&mut self, cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe trades: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.unsubscribe_trades(instrument_id).await },
+            format!("Failed to unsubscribe trades for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_mark_prices(&mut self, cmd: &UnsubscribeMarkPrices) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe mark prices: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.unsubscribe_mark_prices(instrument_id).await },
+            format!("Failed to unsubscribe mark prices for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_index_prices(&mut self, cmd: &UnsubscribeIndexPrices) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe index prices: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.unsubscribe_index_prices(instrument_id).await },
+            format!("Failed to unsubscribe index prices for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_funding_rates(&mut self, cmd: &UnsubscribeFundingRates) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe funding rates: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_ws(
+            async move { ws.unsubscribe_funding_rates(instrument_id).await },
+            format!("Failed to unsubscribe funding rates for {instrument_id}"),
+        );
+        Ok(())
+    }
+
+    fn unsubscribe_bars(&mut self, cmd: &UnsubscribeBars) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe bars: {cmd:?}");
+
+        let ws = self.ws_client.clone();
+        let bar_type = cmd.bar_type;
+        self.spawn_ws(
+            async move { ws.unsubscribe_bars(bar_type).await },
+            format!("Failed to unsubscribe bars for {bar_type}"),
+        );
+        Ok(())
+    }
+
+    fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
+        tracing::debug!("Request instruments: {request:?}");
+
+        let response = DataResponse::Instruments(InstrumentsResponse::new(
+            request.request_id,
+            self.client_id,
+            *BITMEX_VENUE,
+            self.instruments(),
+            None,
+            None,
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        ));
+
+        Self::send_response(&self.data_sender, response);
+        Ok(())
+    }
+
+    fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
+        tracing::debug!("Request instrument: {request:?}");
+
+        let instrument = self
+            .instruments
+            .read()
+            .expect("Instruments lock poisoned")
+            .get(&request.instrument_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Instrument {} not found", request.instrument_id))?;
+
+        let response = DataResponse::Instrument(Box::new(InstrumentResponse::new(
+            request.request_id,
+            self.client_id,
+            request.instrument_id,
+            instrument,
+            None,
+            None,
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        )));
+
+        Self::send_response(&self.data_sender, response);
+        Ok(())
+    }
+
+    fn request_trades(&self, request: &RequestTrades) -> anyhow::Result<()> {
+        tracing::debug!("Request trades: {request:?}");
+
+        let http_client = self.http_client.clone();
+        let data_sender = self.data_sender.clone();
+        let client_id = self.client_id;
+        let clock = self.clock;
+        let request = request.clone();
+
+        get_runtime().spawn(async move {
+            match http_client
+                .request_trades(request.instrument_id, limit_to_u32(request.limit))
+                .await
+            {
+                Ok(trades) => {
+                    let response = DataResponse::Trades(TradesResponse::new(
+                        request.request_id,
+                        client_id,
+                        request.instrument_id,
+                        trades,
+                        request.start.map(UnixNanos::from),
+                        request.end.map(UnixNanos::from),
+                        clock.get_time_ns(),
+                        request.params,
+                    ));
+                    Self::send_response(&data_sender, response);
+                }
+                Err(e) => tracing::error!("Failed to request trades: {e}"),
+            }
+        });
+
+        Ok(())
+    }
+}
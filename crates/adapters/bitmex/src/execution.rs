@@ -0,0 +1,1161 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the BitMEX live execution client.
+//!
+//! Orders are placed, amended and canceled over HTTP, while order, execution and wallet updates
+//! are streamed from the authenticated WebSocket. Reports for reconciliation are requested over
+//! HTTP.
+//!
+//! WebSocket messages arrive on the shared runtime and are queued to the client, which must be
+//! drained from the engine thread with [`BitmexExecutionClient::drain_events`] so that order
+//! events are published on the thread owning the message bus.
+//!
+//! Order commands spawn their requests on the shared runtime rather than waiting for the venue,
+//! and a failed request is queued to the client as a rejected event in the same way.
+
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    rc::Rc,
+    time::Duration,
+};
+
+use ahash::AHashSet;
+use ant_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+        GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount, QueryOrder,
+        SubmitOrder, SubmitOrderList,
+    },
+    runtime::{block_on, get_runtime},
+};
+use ant_core::{UUID4, UnixNanos, env::get_env_var, time::get_atomic_clock_realtime};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient, base::BaseExecutionClient};
+use ant_model::{
+    accounts::AccountAny,
+    enums::{AccountType, OmsType, OrderSide, OrderStatus},
+    events::{
+        AccountState, OrderCancelRejected, OrderModifyRejected, OrderRejected, OrderUpdated,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, Venue,
+        VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, MarginBalance},
+};
+use futures_util::StreamExt;
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+    task::JoinHandle,
+};
+use ustr::Ustr;
+
+use crate::{
+    common::consts::BITMEX_VENUE,
+    config::BitmexExecClientConfig,
+    http::client::BitmexHttpClient,
+    websocket::{client::BitmexWebSocketClient, messages::AntWsMessage},
+};
+
+/// The heartbeat interval in seconds.
+const BITMEX_WS_HEARTBEAT_SECS: u64 = 5;
+
+/// The maximum time in seconds to wait for the WebSocket connection to become active.
+const BITMEX_WS_CONNECT_TIMEOUT_SECS: f64 = 10.0;
+
+/// A BitMEX execution client trading spot, perpetual and futures contracts.
+///
+/// BitMEX nets positions per symbol, so the client always uses [`OmsType::Netting`]. When a
+/// dead man's switch timeout is configured the switch is armed on connect, refreshed in the
+/// background, and disarmed on disconnect.
+pub struct BitmexExecutionClient {
+    core: BaseExecutionClient,
+    config: BitmexExecClientConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    http_client: BitmexHttpClient,
+    ws_client: BitmexWebSocketClient,
+    is_connected: Cell<bool>,
+    event_tx: RefCell<Option<UnboundedSender<AntWsMessage>>>,
+    event_rx: RefCell<Option<UnboundedReceiver<AntWsMessage>>>,
+    stream_handle: RefCell<Option<JoinHandle<()>>>,
+    dead_mans_switch_handle: RefCell<Option<JoinHandle<()>>>,
+    /// Orders for which an accepted event has been generated by this client.
+    accepted: RefCell<AHashSet<ClientOrderId>>,
+    /// Trades already applied, as BitMEX may resend executions on reconnect.
+    trade_ids: RefCell<AHashSet<TradeId>>,
+}
+
+impl std::fmt::Debug for BitmexExecutionClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(BitmexExecutionClient))
+            .field("client_id", &self.core.client_id)
+            .field("account_id", &self.core.account_id)
+            .field("cancel_all_after_ms", &self.config.cancel_all_after_ms)
+            .finish()
+    }
+}
+
+impl BitmexExecutionClient {
+    /// Creates a new [`BitmexExecutionClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The API credentials are not provided or found in the environment.
+    /// - The dead man's switch refresh interval is not shorter than its timeout.
+    pub fn new(
+        client_id: ClientId,
+        config: BitmexExecClientConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> anyhow::Result<Self> {
+        let api_key = config
+            .api_key
+            .clone()
+            .or_else(|| get_env_var("BITMEX_API_KEY").ok());
+        let api_secret = config
+            .api_secret
+            .clone()
+            .or_else(|| get_env_var("BITMEX_API_SECRET").ok());
+        anyhow::ensure!(
+            api_key.is_some() && api_secret.is_some(),
+            "BitMEX API credentials are required for the execution client"
+        );
+
+        if let Some(timeout_ms) = config.cancel_all_after_ms {
+            anyhow::ensure!(
+                config.cancel_all_after_refresh_secs > 0
+                    && config.cancel_all_after_refresh_secs * 1_000 < timeout_ms,
+                "Dead man's switch refresh interval ({}s) must be positive and shorter than the timeout ({timeout_ms}ms)",
+                config.cancel_all_after_refresh_secs
+            );
+        }
+
+        let http_client = BitmexHttpClient::new(
+            Some(config.http_base_url()),
+            api_key.clone(),
+            api_secret.clone(),
+            config.testnet,
+            config.http_timeout_secs,
+            None,
+            None,
+            None,
+        )?;
+        let ws_client = BitmexWebSocketClient::new(
+            Some(config.ws_url()),
+            api_key,
+            api_secret,
+            Some(config.account_id),
+            Some(BITMEX_WS_HEARTBEAT_SECS),
+        )?;
+
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            client_id,
+            *BITMEX_VENUE,
+            OmsType::Netting,
+            config.account_id,
+            AccountType::Margin,
+            None,
+            clock.clone(),
+            cache.clone(),
+        );
+
+        Ok(Self {
+            core,
+            config,
+            clock,
+            cache,
+            http_client,
+            ws_client,
+            is_connected: Cell::new(false),
+            event_tx: RefCell::new(None),
+            event_rx: RefCell::new(None),
+            stream_handle: RefCell::new(None),
+            dead_mans_switch_handle: RefCell::new(None),
+            accepted: RefCell::new(AHashSet::new()),
+            trade_ids: RefCell::new(AHashSet::new()),
+        })
+    }
+
+    /// Processes all queued WebSocket messages, returning the number processed.
+    ///
+    /// Must be called from the thread owning the message bus, typically on each engine tick.
+    pub fn drain_events(&self) -> usize {
+        let mut count = 0;
+        loop {
+            // Release the receiver borrow before handling, as strategies may react to an event
+            // by sending further commands to the client
+            let msg = match self.event_rx.borrow_mut().as_mut() {
+                Some(rx) => match rx.try_recv() {
+                    Ok(msg) => msg,
+                    Err(_) => break,
+                },
+                None => break,
+            };
+            self.handle_ws_message(msg);
+            count += 1;
+        }
+        count
+    }
+
+    fn handle_ws_message(&self, msg: AntWsMessage) {
+        match msg {
+            AntWsMessage::OrderStatusReports(reports) => {
+                for report in reports {
+                    self.handle_order_report(&report);
+                }
+            }
+            AntWsMessage::FillReports(reports) => {
+                for report in reports {
+                    self.handle_fill_report(&report);
+                }
+            }
+            AntWsMessage::OrderUpdated(event) => self.handle_order_updated(&event),
+            AntWsMessage::AccountState(state) => self.apply_account_state(state),
+            AntWsMessage::OrderRejected(event) => self.core.generate_order_rejected(
+                event.strategy_id,
+                event.instrument_id,
+                event.client_order_id,
+                &event.reason,
+                event.ts_event,
+                event.due_post_only != 0,
+            ),
+            AntWsMessage::OrderCancelRejected(event) => {
+                if let Some(venue_order_id) = event.venue_order_id {
+                    self.core.generate_order_cancel_rejected(
+                        event.strategy_id,
+                        event.instrument_id,
+                        event.client_order_id,
+                        venue_order_id,
+                        &event.reason,
+                        event.ts_event,
+                    );
+                }
+            }
+            AntWsMessage::OrderModifyRejected(event) => {
+                if let Some(venue_order_id) = event.venue_order_id {
+                    self.core.generate_order_modify_rejected(
+                        event.strategy_id,
+                        event.instrument_id,
+                        event.client_order_id,
+                        venue_order_id,
+                        &event.reason,
+                        event.ts_event,
+                    );
+                }
+            }
+            AntWsMessage::PositionStatusReport(report) => {
+                log::debug!("Position update: {report:?}");
+            }
+            AntWsMessage::Reconnected => log::info!("BitMEX WebSocket reconnected"),
+            other => log::debug!("Unhandled WebSocket message: {other:?}"),
+        }
+    }
+
+    fn apply_account_state(&self, state: AccountState) {
+        if let Err(e) = self.core.generate_account_state(
+            state.balances,
+            state.margins,
+            state.is_reported,
+            state.ts_event,
+        ) {
+            log::error!("Failed to generate account state: {e}");
+        }
+    }
+
+    /// Returns the cached order for the report, or `None` for orders not placed by ant.
+    fn report_order(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: &VenueOrderId,
+    ) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        let client_order_id = client_order_id
+            .filter(|id| cache.order(id).is_some())
+            .or_else(|| cache.client_order_id(venue_order_id).copied())?;
+        cache.order(&client_order_id).cloned()
+    }
+
+    /// Generates an accepted event if the order has not yet been accepted.
+    fn ensure_accepted(&self, order: &OrderAny, venue_order_id: VenueOrderId, ts_event: UnixNanos) {
+        if order.status() == OrderStatus::Submitted
+            && self.accepted.borrow_mut().insert(order.client_order_id())
+        {
+            self.core.generate_order_accepted(
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                venue_order_id,
+                ts_event,
+            );
+        }
+    }
+
+    fn handle_order_report(&self, report: &OrderStatusReport) {
+        let Some(order) = self.report_order(report.client_order_id, &report.venue_order_id) else {
+            log::debug!("Order report for external order {}", report.venue_order_id);
+            return;
+        };
+
+        // BitMEX publishes the same state on both the HTTP response and the order stream
+        if order.is_closed() {
+            log::debug!("Order {} already closed", order.client_order_id());
+            return;
+        }
+
+        let strategy_id = order.strategy_id();
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let venue_order_id = report.venue_order_id;
+        let ts_event = report.ts_last;
+
+        match report.order_status {
+            OrderStatus::Accepted => {
+                if order.status() == OrderStatus::Submitted {
+                    self.ensure_accepted(&order, venue_order_id, ts_event);
+                } else if report.quantity != order.quantity()
+                    || (report.price.is_some() && report.price != order.price())
+                    || (report.trigger_price.is_some()
+                        && report.trigger_price != order.trigger_price())
+                {
+                    // BitMEX republishes the full order after an amendment
+                    let Some(price) = report.price.or_else(|| order.price()) else {
+                        return;
+                    };
+                    self.core.generate_order_updated(
+                        strategy_id,
+                        instrument_id,
+                        client_order_id,
+                        venue_order_id,
+                        report.quantity,
+                        price,
+                        report.trigger_price,
+                        ts_event,
+                        false,
+                    );
+                }
+            }
+            // Fills are generated from the execution stream
+            OrderStatus::PartiallyFilled | OrderStatus::Filled => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+            }
+            OrderStatus::Canceled => {
+                // BitMEX cancels post-only orders which would cross rather than rejecting them
+                if order.status() == OrderStatus::Submitted
+                    && order.is_post_only()
+                    && let Some(reason) = &report.cancel_reason
+                {
+                    self.core.generate_order_rejected(
+                        strategy_id,
+                        instrument_id,
+                        client_order_id,
+                        reason,
+                        ts_event,
+                        true,
+                    );
+                    return;
+                }
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_canceled(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Expired => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_expired(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Rejected => self.core.generate_order_rejected(
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                report
+                    .cancel_reason
+                    .as_deref()
+                    .unwrap_or("Rejected by BitMEX"),
+                ts_event,
+                report.post_only,
+            ),
+            status => log::debug!("Order {client_order_id} status {status:?}"),
+        }
+    }
+
+    /// Handles a partial order update, which BitMEX sends when an order is amended.
+    fn handle_order_updated(&self, event: &OrderUpdated) {
+        let Some(venue_order_id) = event.venue_order_id else {
+            return;
+        };
+        let client_order_id =
+            (event.client_order_id != ClientOrderId::default()).then_some(event.client_order_id);
+        let Some(order) = self.report_order(client_order_id, &venue_order_id) else {
+            log::debug!("Order update for external order {venue_order_id}");
+            return;
+        };
+
+        let Some(price) = event.price.filter(|price| Some(*price) != order.price()) else {
+            return;
+        };
+        self.core.generate_order_updated(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            order.quantity(),
+            price,
+            order.trigger_price(),
+            event.ts_event,
+            false,
+        );
+    }
+
+    fn handle_fill_report(&self, report: &FillReport) {
+        let Some(order) = self.report_order(report.client_order_id, &report.venue_order_id) else {
+            log::debug!("Fill report for external order {}", report.venue_order_id);
+            return;
+        };
+
+        if !self.trade_ids.borrow_mut().insert(report.trade_id) {
+            log::debug!("Duplicate fill {}", report.trade_id);
+            return;
+        }
+
+        let Some(instrument) = self.instrument(&order.instrument_id()) else {
+            log::error!("Instrument {} not found in cache", order.instrument_id());
+            return;
+        };
+
+        self.ensure_accepted(&order, report.venue_order_id, report.ts_event);
+
+        self.core.generate_order_filled(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report
+                .venue_position_id
+                .unwrap_or_else(|| venue_position_id(order.instrument_id(), order.strategy_id())),
+            report.trade_id,
+            order.order_side(),
+            order.order_type(),
+            report.last_qty,
+            report.last_px,
+            instrument.quote_currency(),
+            report.commission,
+            report.liquidity_side,
+            report.ts_event,
+        );
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentEnum> {
+        self.cache.borrow().instrument(instrument_id).cloned()
+    }
+
+    fn reject(&self, cmd: &SubmitOrder, reason: &str) {
+        log::error!("Rejecting order {}: {reason}", cmd.client_order_id);
+        self.core.generate_order_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            reason,
+            self.clock.borrow().timestamp_ns(),
+            false,
+        );
+    }
+
+    /// Spawns an order `request` on the shared runtime, so the calling thread is not held for
+    /// the round trip.
+    ///
+    /// The order reports returned are queued to be handled from
+    /// [`BitmexExecutionClient::drain_events`]. If the request fails the events built by
+    /// `on_error`, from the reason and the time of the failure, are queued instead.
+    fn spawn_order_request<F, E>(&self, request: F, on_error: E)
+    where
+        F: Future<Output = anyhow::Result<Vec<OrderStatusReport>>> + Send + 'static,
+        E: FnOnce(String, UnixNanos) -> Vec<AntWsMessage> + Send + 'static,
+    {
+        let Some(tx) = self.event_tx.borrow().clone() else {
+            log::error!("Cannot send order request: BitMEX execution client not connected");
+            return;
+        };
+        get_runtime().spawn(async move {
+            let msgs = match request.await {
+                Ok(reports) => vec![AntWsMessage::OrderStatusReports(reports)],
+                Err(e) => on_error(e.to_string(), get_atomic_clock_realtime().get_time_ns()),
+            };
+            for msg in msgs {
+                if tx.send(msg).is_err() {
+                    log::error!("Order request completed after disconnect");
+                    break;
+                }
+            }
+        });
+    }
+
+    fn submit(&self, cmd: &SubmitOrder, order: &OrderAny) {
+        if !self.is_connected.get() {
+            self.reject(cmd, "BitMEX execution client not connected");
+            return;
+        }
+
+        if self.instrument(&order.instrument_id()).is_none() {
+            return self.reject(
+                cmd,
+                &format!("Instrument {} not found", order.instrument_id()),
+            );
+        }
+
+        self.core.generate_order_submitted(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            self.clock.borrow().timestamp_ns(),
+        );
+
+        let http_client = self.http_client.clone();
+        let trader_id = cmd.trader_id;
+        let strategy_id = cmd.strategy_id;
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let account_id = self.core.account_id;
+        let order = order.clone();
+
+        // Unsupported order types and time in force are rejected when building the request
+        self.spawn_order_request(
+            async move {
+                let report = http_client
+                    .submit_order(
+                        instrument_id,
+                        client_order_id,
+                        order.order_side(),
+                        order.order_type(),
+                        order.quantity(),
+                        order.time_in_force(),
+                        order.price(),
+                        order.trigger_price(),
+                        order.display_qty(),
+                        order.is_post_only(),
+                        order.is_reduce_only(),
+                    )
+                    .await?;
+                Ok(vec![report])
+            },
+            move |reason, ts_event| {
+                vec![AntWsMessage::OrderRejected(OrderRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    account_id,
+                    Ustr::from(&format!("Failed to submit order: {reason}")),
+                    UUID4::new(),
+                    ts_event,
+                    ts_event,
+                    false,
+                    false,
+                ))]
+            },
+        );
+    }
+
+    /// Sends a cancel for the order, a failure is reported as an order cancel rejected event.
+    fn cancel(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+        venue_order_id: VenueOrderId,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.is_connected.get(),
+            "BitMEX execution client not connected"
+        );
+
+        let cached_venue_order_id = self
+            .cache
+            .borrow()
+            .venue_order_id(&client_order_id)
+            .copied();
+        let http_client = self.http_client.clone();
+        let trader_id = self.core.trader_id;
+        let account_id = self.core.account_id;
+
+        self.spawn_order_request(
+            async move {
+                let report = http_client
+                    .cancel_order(instrument_id, Some(client_order_id), cached_venue_order_id)
+                    .await?;
+                Ok(vec![report])
+            },
+            move |reason, ts_event| {
+                vec![AntWsMessage::OrderCancelRejected(OrderCancelRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    Ustr::from(&reason),
+                    UUID4::new(),
+                    ts_event,
+                    ts_event,
+                    false,
+                    Some(cached_venue_order_id.unwrap_or(venue_order_id)),
+                    Some(account_id),
+                ))]
+            },
+        );
+        Ok(())
+    }
+
+    fn request_account_state(&self) -> anyhow::Result<()> {
+        let state = block_on(self.http_client.request_account_state(self.core.account_id))?;
+        self.apply_account_state(state);
+        Ok(())
+    }
+
+    fn request_order_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let reports = block_on(self.http_client.request_order_status_reports(
+            instrument_id,
+            false,
+            None,
+        ))?;
+        let start = self.lookback_start(lookback_mins);
+        Ok(reports
+            .into_iter()
+            .filter(|report| start.is_none_or(|start| report.ts_last >= start))
+            .collect())
+    }
+
+    fn request_fill_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        let reports = block_on(self.http_client.request_fill_reports(instrument_id, None))?;
+        let start = self.lookback_start(lookback_mins);
+        Ok(reports
+            .into_iter()
+            .filter(|report| start.is_none_or(|start| report.ts_event >= start))
+            .collect())
+    }
+
+    fn request_position_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let reports = block_on(self.http_client.request_position_status_reports())?;
+        Ok(reports
+            .into_iter()
+            .filter(|report| instrument_id.is_none_or(|id| report.instrument_id == id))
+            .collect())
+    }
+
+    fn lookback_start(&self, lookback_mins: Option<u64>) -> Option<UnixNanos> {
+        let now = self.clock.borrow().timestamp_ns();
+        lookback_mins.map(|mins| {
+            UnixNanos::from(
+                now.as_u64()
+                    .saturating_sub(mins.saturating_mul(60_000_000_000)),
+            )
+        })
+    }
+
+    /// Loads the instruments from BitMEX, merged with any BitMEX instruments already in the cache.
+    fn load_instruments(&mut self) -> anyhow::Result<Vec<InstrumentEnum>> {
+        let mut instruments: Vec<InstrumentEnum> = self
+            .cache
+            .borrow()
+            .instruments(&BITMEX_VENUE, None)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let loaded = block_on(
+            self.http_client
+                .request_instruments(self.config.active_only),
+        )?;
+        log::info!("Loaded {} instruments", loaded.len());
+        instruments.extend(loaded);
+
+        self.http_client.add_instruments(instruments.clone());
+        Ok(instruments)
+    }
+
+    /// Arms the dead man's switch and spawns a task refreshing it, if a timeout is configured.
+    fn start_dead_mans_switch(&self) -> anyhow::Result<()> {
+        let Some(timeout_ms) = self.config.cancel_all_after_ms else {
+            return Ok(());
+        };
+
+        // Arm once up front so a misconfigured account fails the connection
+        block_on(self.http_client.cancel_all_after(timeout_ms))?;
+        log::info!("Armed dead man's switch with {timeout_ms}ms timeout");
+
+        let http_client = self.http_client.clone();
+        let refresh_interval = Duration::from_secs(self.config.cancel_all_after_refresh_secs);
+        *self.dead_mans_switch_handle.borrow_mut() = Some(get_runtime().spawn(async move {
+            let mut interval = tokio::time::interval(refresh_interval);
+            interval.tick().await; // The first tick completes immediately
+            loop {
+                interval.tick().await;
+                if let Err(e) = http_client.cancel_all_after(timeout_ms).await {
+                    log::error!("Failed to refresh dead man's switch: {e}");
+                }
+            }
+        }));
+        Ok(())
+    }
+
+    /// Stops refreshing the dead man's switch and disarms it.
+    fn stop_dead_mans_switch(&self) {
+        let Some(handle) = self.dead_mans_switch_handle.borrow_mut().take() else {
+            return;
+        };
+        handle.abort();
+
+        match block_on(self.http_client.cancel_all_after(0)) {
+            Ok(()) => log::info!("Disarmed dead man's switch"),
+            Err(e) => log::error!("Failed to disarm dead man's switch: {e}"),
+        }
+    }
+}
+
+/// Returns the netting position ID for fills, as BitMEX has no venue position IDs.
+fn venue_position_id(instrument_id: InstrumentId, strategy_id: StrategyId) -> PositionId {
+    PositionId::new(format!("{instrument_id}-{strategy_id}"))
+}
+
+impl ExecutionClient for BitmexExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.is_connected.get()
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        self.core.venue
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        log::info!("Started");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        log::info!("Stopped");
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(cmd, &cmd.order);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // Contingent order lists are not supported, orders are submitted individually
+        for order in &cmd.order_list.orders {
+            let submit = SubmitOrder::new(
+                cmd.trader_id,
+                cmd.client_id,
+                cmd.strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                cmd.venue_order_id,
+                order.clone(),
+                cmd.exec_algorith_id,
+                cmd.position_id,
+                cmd.command_id,
+                cmd.ts_init,
+            )?;
+            self.submit(&submit, order);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        let order = self.cache.borrow().order(&cmd.client_order_id).cloned();
+        let order = match order {
+            Some(order) if self.is_connected.get() => Ok(order),
+            Some(_) => Err("BitMEX execution client not connected".to_string()),
+            None => Err(format!("Order {} not found", cmd.client_order_id)),
+        };
+        let order = match order {
+            Ok(order) => order,
+            Err(reason) => {
+                self.core.generate_order_modify_rejected(
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    cmd.client_order_id,
+                    cmd.venue_order_id,
+                    &reason,
+                    self.clock.borrow().timestamp_ns(),
+                );
+                return Ok(());
+            }
+        };
+
+        let http_client = self.http_client.clone();
+        let trader_id = cmd.trader_id;
+        let strategy_id = cmd.strategy_id;
+        let instrument_id = cmd.instrument_id;
+        let client_order_id = cmd.client_order_id;
+        let venue_order_id = order.venue_order_id();
+        let quantity = cmd.quantity;
+        let price = cmd.price;
+        let trigger_price = cmd.trigger_price;
+        let account_id = self.core.account_id;
+        let rejected_venue_order_id = venue_order_id.unwrap_or(cmd.venue_order_id);
+
+        // The amended order is republished on the order stream, and the report returned is
+        // handled as an update
+        self.spawn_order_request(
+            async move {
+                let report = http_client
+                    .modify_order(
+                        instrument_id,
+                        Some(client_order_id),
+                        venue_order_id,
+                        quantity,
+                        price,
+                        trigger_price,
+                    )
+                    .await?;
+                Ok(vec![report])
+            },
+            move |reason, ts_event| {
+                vec![AntWsMessage::OrderModifyRejected(OrderModifyRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    Ustr::from(&reason),
+                    UUID4::new(),
+                    ts_event,
+                    ts_event,
+                    false,
+                    Some(rejected_venue_order_id),
+                    Some(account_id),
+                ))]
+            },
+        );
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        if let Err(e) = self.cancel(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            cmd.venue_order_id,
+        ) {
+            self.core.generate_order_cancel_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                &e.to_string(),
+                self.clock.borrow().timestamp_ns(),
+            );
+        }
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.is_connected.get(),
+            "BitMEX execution client not connected"
+        );
+
+        let side = (cmd.order_side != OrderSide::NoOrderSide).then_some(cmd.order_side);
+        let http_client = self.http_client.clone();
+        let instrument_id = cmd.instrument_id;
+        self.spawn_order_request(
+            async move { http_client.cancel_all_orders(instrument_id, side).await },
+            move |reason, _| {
+                log::error!("Failed to cancel all orders for {instrument_id}: {reason}");
+                Vec::new()
+            },
+        );
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        if cmd.cancels.is_empty() {
+            return Ok(());
+        }
+
+        if !self.is_connected.get() {
+            let ts_event = self.clock.borrow().timestamp_ns();
+            for cancel in &cmd.cancels {
+                self.core.generate_order_cancel_rejected(
+                    cancel.strategy_id,
+                    cancel.instrument_id,
+                    cancel.client_order_id,
+                    cancel.venue_order_id,
+                    "BitMEX execution client not connected",
+                    ts_event,
+                );
+            }
+            return Ok(());
+        }
+
+        let client_order_ids: Vec<ClientOrderId> = cmd
+            .cancels
+            .iter()
+            .map(|cancel| cancel.client_order_id)
+            .collect();
+        let http_client = self.http_client.clone();
+        let instrument_id = cmd.instrument_id;
+        let trader_id = self.core.trader_id;
+        let account_id = self.core.account_id;
+        let cancels = cmd.cancels.clone();
+
+        self.spawn_order_request(
+            async move {
+                http_client
+                    .cancel_orders(instrument_id, Some(client_order_ids), None)
+                    .await
+            },
+            move |reason, ts_event| {
+                let reason = Ustr::from(&reason);
+                cancels
+                    .into_iter()
+                    .map(|cancel| {
+                        AntWsMessage::OrderCancelRejected(OrderCancelRejected::new(
+                            trader_id,
+                            cancel.strategy_id,
+                            cancel.instrument_id,
+                            cancel.client_order_id,
+                            reason,
+                            UUID4::new(),
+                            ts_event,
+                            ts_event,
+                            false,
+                            Some(cancel.venue_order_id),
+                            Some(account_id),
+                        ))
+                    })
+                    .collect()
+            },
+        );
+        Ok(())
+    }
+
+    fn query_account(&self, cmd: &QueryAccount) -> anyhow::Result<()> {
+        log::debug!("Query account: {cmd:?}");
+        self.request_account_state()
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        let report = block_on(self.http_client.query_order(
+            cmd.instrument_id,
+            Some(cmd.client_order_id),
+            None,
+        ))?;
+        match report {
+            Some(report) => self.handle_order_report(&report),
+            None => log::warn!("Order {} not found", cmd.client_order_id),
+        }
+        Ok(())
+    }
+
+    fn as_live(&self) -> Option<&dyn LiveExecutionClient> {
+        Some(self)
+    }
+}
+
+impl LiveExecutionClient for BitmexExecutionClient {
+    fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected.get() {
+            return Ok(());
+        }
+
+        let instruments = self.load_instruments()?;
+
+        // The instruments cache must be initialized before connecting, as it is captured by
+        // the message handler for parsing
+        self.ws_client.initialize_instruments_cache(instruments);
+        let ws_client = &mut self.ws_client;
+        block_on(async {
+            ws_client.connect().await?;
+            ws_client
+                .wait_until_active(BITMEX_WS_CONNECT_TIMEOUT_SECS)
+                .await?;
+            ws_client.subscribe_orders().await?;
+            ws_client.subscribe_executions().await?;
+            ws_client.subscribe_positions().await?;
+            ws_client.subscribe_wallet().await?;
+            anyhow::Ok(())
+        })?;
+
+        let stream = self.ws_client.stream();
+        let (tx, rx) = unbounded_channel();
+        *self.event_tx.borrow_mut() = Some(tx.clone());
+        *self.event_rx.borrow_mut() = Some(rx);
+        *self.stream_handle.borrow_mut() = Some(get_runtime().spawn(async move {
+            tokio::pin!(stream);
+            while let Some(msg) = stream.next().await {
+                if tx.send(msg).is_err() {
+                    break;
+                }
+            }
+            log::debug!("WebSocket stream ended");
+        }));
+
+        self.is_connected.set(true);
+        self.core.set_connected(true);
+        self.request_account_state()?;
+        self.start_dead_mans_switch()?;
+
+        log::info!("Connected to {}", self.ws_client.url());
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> anyhow::Result<()> {
+        self.stop_dead_mans_switch();
+        if let Err(e) = block_on(self.ws_client.close()) {
+            log::error!("Failed to close WebSocket: {e}");
+        }
+        if let Some(handle) = self.stream_handle.borrow_mut().take() {
+            handle.abort();
+        }
+        *self.event_tx.borrow_mut() = None;
+        self.drain_events();
+        *self.event_rx.borrow_mut() = None;
+        self.is_connected.set(false);
+        self.core.set_connected(false);
+        log::info!("Disconnected");
+        Ok(())
+    }
+
+    fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let reports = self.request_order_status_reports(cmd.instrument_id(), None)?;
+        Ok(reports.into_iter().find(|report| {
+            cmd.client_order_id()
+                .is_some_and(|id| report.client_order_id == Some(id))
+                || cmd
+                    .venue_order_id()
+                    .is_some_and(|id| report.venue_order_id.as_str() == id.as_str())
+        }))
+    }
+
+    fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        self.request_order_status_reports(cmd.instrument_id(), None)
+    }
+
+    fn generate_fill_reports(&self, cmd: GenerateFillReports) -> anyhow::Result<Vec<FillReport>> {
+        self.request_fill_reports(cmd.instrument_id(), None)
+    }
+
+    fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        self.request_position_status_reports(cmd.instrument_id())
+    }
+
+    fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        log::debug!("Generating mass status (lookback_mins={lookback_mins:?})");
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            self.core.venue,
+            ts_init,
+            None,
+        );
+
+        mass_status.add_order_reports(self.request_order_status_reports(None, lookback_mins)?);
+        mass_status.add_fill_reports(self.request_fill_reports(None, lookback_mins)?);
+        mass_status.add_position_reports(self.request_position_status_reports(None)?);
+
+        Ok(Some(mass_status))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_venue_position_id() {
+        assert_eq!(
+            venue_position_id(
+                InstrumentId::from("XBTUSD.BITMEX"),
+                StrategyId::from("S-001")
+            ),
+            PositionId::from("XBTUSD.BITMEX-S-001")
+        );
+    }
+}
@@ -0,0 +1,147 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating BitMEX clients.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use ant_common::{cache::Cache, clock::Clock};
+use ant_core::time::get_atomic_clock_realtime;
+use ant_data::client::DataClient;
+use ant_execution::client::ExecutionClient;
+use ant_model::identifiers::ClientId;
+use ant_system::factories::{ClientConfig, DataClientFactory, ExecutionClientFactory};
+
+use crate::{
+    common::consts::BITMEX,
+    config::{BitmexDataClientConfig, BitmexExecClientConfig},
+    data::BitmexDataClient,
+    execution::BitmexExecutionClient,
+};
+
+impl ClientConfig for BitmexDataClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl ClientConfig for BitmexExecClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating BitMEX data clients.
+#[derive(Debug)]
+pub struct BitmexDataClientFactory;
+
+impl BitmexDataClientFactory {
+    /// Creates a new [`BitmexDataClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BitmexDataClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataClientFactory for BitmexDataClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        _cache: Rc<RefCell<Cache>>,
+        _clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn DataClient>> {
+        let bitmex_config = config
+            .as_any()
+            .downcast_ref::<BitmexDataClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for BitmexDataClientFactory. Expected BitmexDataClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client = BitmexDataClient::new(
+            ClientId::from(name),
+            bitmex_config.clone(),
+            get_atomic_clock_realtime(),
+        )?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        BITMEX
+    }
+
+    fn config_type(&self) -> &'static str {
+        "BitmexDataClientConfig"
+    }
+}
+
+/// Factory for creating BitMEX execution clients.
+#[derive(Debug)]
+pub struct BitmexExecutionClientFactory;
+
+impl BitmexExecutionClientFactory {
+    /// Creates a new [`BitmexExecutionClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BitmexExecutionClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutionClientFactory for BitmexExecutionClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn ExecutionClient>> {
+        let bitmex_config = config
+            .as_any()
+            .downcast_ref::<BitmexExecClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for BitmexExecutionClientFactory. Expected BitmexExecClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client =
+            BitmexExecutionClient::new(ClientId::from(name), bitmex_config.clone(), clock, cache)?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        BITMEX
+    }
+
+    fn config_type(&self) -> &'static str {
+        "BitmexExecClientConfig"
+    }
+}
@@ -65,8 +65,8 @@ use super::{
     query::{
         DeleteAllOrdersParams, DeleteOrderParams, GetExecutionParams, GetExecutionParamsBuilder,
         GetOrderParams, GetPositionParams, GetPositionParamsBuilder, GetTradeParams,
-        GetTradeParamsBuilder, PostOrderBulkParams, PostOrderParams, PostPositionLeverageParams,
-        PutOrderBulkParams, PutOrderParams,
+        GetTradeParamsBuilder, PostCancelAllAfterParams, PostOrderBulkParams, PostOrderParams,
+        PostPositionLeverageParams, PutOrderBulkParams, PutOrderParams,
     },
 };
 use crate::{
@@ -430,7 +430,7 @@ impl BitmexHttpInnerClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if credentials are missing, the request fails, or the API returns an error.
+    /// Returns an error if the request fails or the API returns an error.
     ///
     /// # Panics
     ///
@@ -443,7 +443,7 @@ impl BitmexHttpInnerClient {
             BitmexHttpError::ValidationError(format!("Failed to serialize parameters: {e}"))
         })?;
         let path = format!("/trade?{query}");
-        self.send_request(Method::GET, &path, None, true).await
+        self.send_request(Method::GET, &path, None, false).await
     }
 
     /// Get user orders.
@@ -547,6 +547,26 @@ impl BitmexHttpInnerClient {
         self.send_request(Method::DELETE, &path, None, true).await
     }
 
+    /// Arm (or disarm with a zero timeout) the dead man's switch which cancels all orders.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if credentials are missing, the request fails, or the API returns an error.
+    ///
+    /// # References
+    ///
+    /// <https://www.bitmex.com/api/explorer/#!/Order/Order_cancelAllAfter>
+    pub async fn http_cancel_all_after(
+        &self,
+        params: PostCancelAllAfterParams,
+    ) -> Result<Value, BitmexHttpError> {
+        let query = serde_urlencoded::to_string(&params).map_err(|e| {
+            BitmexHttpError::ValidationError(format!("Failed to serialize parameters: {e}"))
+        })?;
+        let path = format!("/order/cancelAllAfter?{query}");
+        self.send_request(Method::POST, &path, None, true).await
+    }
+
     /// Get user executions.
     ///
     /// # Errors
@@ -811,11 +831,22 @@ impl BitmexHttpClient {
     ///
     /// Panics if the instruments cache mutex is poisoned.
     pub fn add_instrument(&mut self, instrument: InstrumentEnum) {
-        // TODO laiqb check
-        // self.instruments_cache
-        //     .lock()
-        //     .unwrap()
-        //     .insert(instrument.raw_symbol().inner(), instrument);
+        self.instruments_cache
+            .lock()
+            .unwrap()
+            .insert(instrument.raw_symbol().inner(), instrument);
+    }
+
+    /// Adds the `instruments` to the cache for precision lookups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instruments cache mutex is poisoned.
+    pub fn add_instruments(&mut self, instruments: Vec<InstrumentEnum>) {
+        let mut cache = self.instruments_cache.lock().unwrap();
+        for instrument in instruments {
+            cache.insert(instrument.raw_symbol().inner(), instrument);
+        }
     }
 
     /// Request all available instruments and parse them into ant types.
@@ -959,6 +990,22 @@ impl BitmexHttpClient {
         inner.http_cancel_all_orders(params).await
     }
 
+    /// Arms the dead man's switch to cancel all orders after `timeout_ms`, a zero timeout disarms it.
+    ///
+    /// The switch must be refreshed before the timeout elapses, BitMEX recommends re-arming
+    /// every 15 seconds with a 60 second timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if credentials are missing, the request fails, or the API returns an error.
+    pub async fn cancel_all_after(&self, timeout_ms: u64) -> anyhow::Result<()> {
+        let params = PostCancelAllAfterParams {
+            timeout: timeout_ms,
+        };
+        self.inner.http_cancel_all_after(params).await?;
+        Ok(())
+    }
+
     /// Get price precision for a symbol from the instruments cache (if found).
     ///
     /// # Errors
@@ -249,6 +249,20 @@ pub struct DeleteAllOrdersParams {
     pub text: Option<String>,
 }
 
+/// Parameters for the POST /order/cancelAllAfter endpoint.
+///
+/// # References
+///
+/// <https://www.bitmex.com/api/explorer/#!/Order/Order_cancelAllAfter>
+#[derive(Clone, Debug, Deserialize, Serialize, Default, Builder)]
+#[builder(default)]
+#[builder(setter(into, strip_option))]
+#[serde(rename_all = "camelCase")]
+pub struct PostCancelAllAfterParams {
+    /// Timeout in ms. Set to 0 to cancel this timer.
+    pub timeout: u64,
+}
+
 /// Parameters for the PUT /order endpoint.
 #[derive(Clone, Debug, Deserialize, Serialize, Default, Builder)]
 #[builder(default)]
@@ -51,9 +51,22 @@
 
 pub mod common;
 pub mod config;
+pub mod data;
 pub mod error;
+pub mod execution;
+pub mod factories;
 pub mod http;
 pub mod websocket;
 
 #[cfg(feature = "python")]
 pub mod python;
+
+// Re-exports for convenient access to primary types
+pub use crate::{
+    config::{BitmexDataClientConfig, BitmexExecClientConfig},
+    data::BitmexDataClient,
+    execution::BitmexExecutionClient,
+    factories::{BitmexDataClientFactory, BitmexExecutionClientFactory},
+    http::client::BitmexHttpClient,
+    websocket::client::BitmexWebSocketClient,
+};
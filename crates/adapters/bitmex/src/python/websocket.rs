@@ -186,6 +186,21 @@ impl BitmexWebSocketClient {
                                 call_python(py, &callback, py_obj);
                             }
                         }
+                        AntWsMessage::OrderRejected(event) => {
+                            if let Ok(py_obj) = event.into_py_any(py) {
+                                call_python(py, &callback, py_obj);
+                            }
+                        }
+                        AntWsMessage::OrderCancelRejected(event) => {
+                            if let Ok(py_obj) = event.into_py_any(py) {
+                                call_python(py, &callback, py_obj);
+                            }
+                        }
+                        AntWsMessage::OrderModifyRejected(event) => {
+                            if let Ok(py_obj) = event.into_py_any(py) {
+                                call_python(py, &callback, py_obj);
+                            }
+                        }
                         AntWsMessage::Reconnected => {} // Nothing to handle
                     });
                 }
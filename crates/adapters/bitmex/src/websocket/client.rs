@@ -181,7 +181,7 @@ impl BitmexWebSocketClient {
     pub fn initialize_instruments_cache(&mut self, instruments: Vec<InstrumentEnum>) {
         let mut instruments_cache: AHashMap<Ustr, InstrumentEnum> = AHashMap::new();
         for inst in instruments {
-            // TODO laiqb check instruments_cache.insert(inst.symbol().inner(), inst.clone());
+            instruments_cache.insert(inst.raw_symbol().inner(), inst);
         }
 
         self.instruments_cache = Arc::new(instruments_cache);
@@ -18,7 +18,9 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use ant_model::{
     data::{Data, funding::FundingRateUpdate},
-    events::{AccountState, OrderUpdated},
+    events::{
+        AccountState, OrderCancelRejected, OrderModifyRejected, OrderRejected, OrderUpdated,
+    },
     reports::{FillReport, OrderStatusReport, PositionStatusReport},
 };
 use serde::{Deserialize, Deserializer, Serialize, de};
@@ -61,6 +63,9 @@ pub enum AntWsMessage {
     PositionStatusReport(PositionStatusReport),
     FundingRateUpdates(Vec<FundingRateUpdate>),
     AccountState(AccountState),
+    OrderRejected(OrderRejected),
+    OrderCancelRejected(OrderCancelRejected),
+    OrderModifyRejected(OrderModifyRejected),
     Reconnected,
 }
 
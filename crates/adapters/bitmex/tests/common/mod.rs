@@ -0,0 +1,346 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mock BitMEX HTTP and WebSocket server replaying the fixtures under `test_data`.
+
+#![allow(dead_code)] // Not every test binary uses every helper
+
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{
+    Json, Router,
+    extract::{
+        Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+    routing::{get, post},
+};
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+/// The venue order ID assigned to orders placed on the mock server.
+pub const MOCK_ORDER_ID: &str = "550e8400-e29b-41d4-a716-446655440099";
+
+// Server state recording what the clients sent
+#[derive(Clone, Default)]
+pub struct MockServerState {
+    pub http_requests: Arc<Mutex<Vec<String>>>,
+    pub subscriptions: Arc<Mutex<Vec<String>>>,
+    pub auth_calls: Arc<Mutex<usize>>,
+}
+
+impl MockServerState {
+    /// Returns the recorded HTTP requests for the `path`, formatted as `METHOD path?query`.
+    pub async fn requests_for(&self, method: &str, path: &str) -> Vec<String> {
+        let prefix = format!("{method} {path}?");
+        self.http_requests
+            .lock()
+            .await
+            .iter()
+            .filter(|request| request.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+}
+
+// Load test data from existing files
+pub fn load_test_data(filename: &str) -> Value {
+    let path = format!("test_data/{filename}");
+    let content = std::fs::read_to_string(path).expect("Failed to read test data");
+    serde_json::from_str(&content).expect("Failed to parse test data")
+}
+
+async fn record(
+    state: &MockServerState,
+    method: &str,
+    path: &str,
+    params: &HashMap<String, String>,
+) {
+    let mut params: Vec<_> = params.iter().map(|(k, v)| format!("{k}={v}")).collect();
+    params.sort();
+    state
+        .http_requests
+        .lock()
+        .await
+        .push(format!("{method} {path}?{}", params.join("&")));
+}
+
+/// Returns a BitMEX order echoing the request `params` with the `ord_status`.
+fn mock_order(params: &HashMap<String, String>, cl_ord_id: &str, ord_status: &str) -> Value {
+    let param = |key: &str| params.get(key).cloned();
+    json!({
+        "orderID": param("orderID").unwrap_or_else(|| MOCK_ORDER_ID.to_string()),
+        "clOrdID": cl_ord_id,
+        "account": 1234567,
+        "symbol": param("symbol").unwrap_or_else(|| "XBTUSD".to_string()),
+        "side": param("side").unwrap_or_else(|| "Buy".to_string()),
+        "orderQty": param("orderQty").and_then(|q| q.parse::<i64>().ok()).unwrap_or(100),
+        "price": param("price").and_then(|p| p.parse::<f64>().ok()).unwrap_or(98000.0),
+        "ordType": param("ordType").unwrap_or_else(|| "Limit".to_string()),
+        "timeInForce": param("timeInForce").unwrap_or_else(|| "GoodTillCancel".to_string()),
+        "ordStatus": ord_status,
+        "leavesQty": 100,
+        "cumQty": 0,
+        "text": param("text"),
+        "transactTime": "2025-01-05T17:50:00.000Z",
+        "timestamp": "2025-01-05T17:50:00.000Z",
+    })
+}
+
+async fn handle_instruments(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "GET", "/instrument/active", &params).await;
+    Json(json!([load_test_data("http_get_instrument_xbtusd.json")]))
+}
+
+async fn handle_margin(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "GET", "/user/margin", &params).await;
+    // The HTTP margin response shares the fields of the margin table message
+    Json(load_test_data("ws_margin.json"))
+}
+
+async fn handle_get_orders(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "GET", "/order", &params).await;
+    Json(load_test_data("http_get_orders.json"))
+}
+
+async fn handle_post_order(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "POST", "/order", &params).await;
+    let cl_ord_id = params.get("clOrdID").cloned().unwrap_or_default();
+    Json(mock_order(&params, &cl_ord_id, "New"))
+}
+
+async fn handle_put_order(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "PUT", "/order", &params).await;
+    // Amendments addressed by venue order ID echo a placeholder client order ID
+    let cl_ord_id = params
+        .get("origClOrdID")
+        .cloned()
+        .unwrap_or_else(|| "mock_amended".to_string());
+    Json(mock_order(&params, &cl_ord_id, "New"))
+}
+
+async fn handle_delete_order(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "DELETE", "/order", &params).await;
+    // Multiple IDs are sent comma separated
+    let cl_ord_ids = params.get("clOrdID").cloned().unwrap_or_default();
+    let orders: Vec<Value> = cl_ord_ids
+        .split(',')
+        .enumerate()
+        .map(|(i, cl_ord_id)| {
+            let mut order = mock_order(&HashMap::new(), cl_ord_id, "Canceled");
+            order["orderID"] = json!(format!("550e8400-e29b-41d4-a716-4466554401{i:02}"));
+            order
+        })
+        .collect();
+    Json(json!(orders))
+}
+
+async fn handle_delete_all_orders(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "DELETE", "/order/all", &params).await;
+    Json(json!([]))
+}
+
+async fn handle_cancel_all_after(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "POST", "/order/cancelAllAfter", &params).await;
+    let timeout = params.get("timeout").cloned().unwrap_or_default();
+    Json(json!({ "now": "2025-01-05T17:50:00.000Z", "cancelTime": timeout }))
+}
+
+async fn handle_executions(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "GET", "/execution/tradeHistory", &params).await;
+    Json(load_test_data("http_get_executions.json"))
+}
+
+async fn handle_positions(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "GET", "/position", &params).await;
+    Json(load_test_data("http_get_positions.json"))
+}
+
+async fn handle_trades(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "GET", "/trade", &params).await;
+    Json(load_test_data("http_get_trades.json"))
+}
+
+// WebSocket handler for the mock server
+async fn handle_websocket(ws: WebSocketUpgrade, State(state): State<MockServerState>) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+/// Returns the table message pushed after a subscription to the `topic`.
+fn topic_fixture(topic: &str) -> Option<Value> {
+    let (table, action, fixture) = match topic.split_once(':').map_or(topic, |(table, _)| table) {
+        "trade" => ("trade", "insert", "ws_trade.json"),
+        "orderBookL2" | "orderBookL2_25" => ("orderBookL2", "partial", "ws_orderbook_l2.json"),
+        "order" => ("order", "insert", "ws_order.json"),
+        "execution" => ("execution", "insert", "ws_execution.json"),
+        "wallet" => ("wallet", "partial", "ws_wallet.json"),
+        _ => return None,
+    };
+    Some(json!({
+        "table": table,
+        "action": action,
+        "data": [load_test_data(fixture)],
+    }))
+}
+
+async fn send_json(socket: &mut WebSocket, value: &Value) -> bool {
+    socket
+        .send(Message::Text(value.to_string().into()))
+        .await
+        .is_ok()
+}
+
+async fn handle_socket(mut socket: WebSocket, state: MockServerState) {
+    let welcome = json!({
+        "info": "Welcome to the BitMEX Realtime API.",
+        "version": "2024-06-12T21:37:02.000Z",
+        "timestamp": "2025-01-05T12:00:00.000Z",
+        "docs": "https://www.bitmex.com/app/wsAPI",
+        "limit": { "remaining": 40 },
+    });
+    if !send_json(&mut socket, &welcome).await {
+        return;
+    }
+
+    while let Some(Ok(msg)) = socket.recv().await {
+        let Message::Text(text) = msg else {
+            continue;
+        };
+        let Ok(request) = serde_json::from_str::<Value>(text.as_str()) else {
+            continue;
+        };
+
+        let op = request["op"].as_str().unwrap_or_default().to_string();
+        let args = request["args"].as_array().cloned().unwrap_or_default();
+
+        match op.as_str() {
+            "authKeyExpires" => {
+                *state.auth_calls.lock().await += 1;
+                let response = json!({ "success": true, "request": request });
+                if !send_json(&mut socket, &response).await {
+                    break;
+                }
+            }
+            "subscribe" | "unsubscribe" => {
+                for topic in args.iter().filter_map(Value::as_str) {
+                    let response = json!({
+                        "success": true,
+                        op.as_str(): topic,
+                        "request": { "op": op, "args": [topic] },
+                    });
+                    if !send_json(&mut socket, &response).await {
+                        return;
+                    }
+                    if op == "subscribe" {
+                        state.subscriptions.lock().await.push(topic.to_string());
+                        if let Some(fixture) = topic_fixture(topic)
+                            && !send_json(&mut socket, &fixture).await
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn create_test_router(state: MockServerState) -> Router {
+    Router::new()
+        .route("/instrument/active", get(handle_instruments))
+        .route("/user/margin", get(handle_margin))
+        .route(
+            "/order",
+            get(handle_get_orders)
+                .post(handle_post_order)
+                .put(handle_put_order)
+                .delete(handle_delete_order),
+        )
+        .route(
+            "/order/all",
+            axum::routing::delete(handle_delete_all_orders),
+        )
+        .route("/order/cancelAllAfter", post(handle_cancel_all_after))
+        .route("/execution/tradeHistory", get(handle_executions))
+        .route("/position", get(handle_positions))
+        .route("/trade", get(handle_trades))
+        .route("/realtime", get(handle_websocket))
+        .with_state(state)
+}
+
+/// Starts the mock server on a free port, returning its address and recorded state.
+pub async fn start_mock_server() -> (SocketAddr, MockServerState) {
+    // Bind to port 0 to let the OS assign an available port
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let state = MockServerState::default();
+    let router = create_test_router(state.clone());
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    (addr, state)
+}
+
+/// Polls `condition` every 10ms until it holds or the `timeout` elapses.
+pub async fn wait_until<F>(mut condition: F, timeout: Duration) -> bool
+where
+    F: AsyncFnMut() -> bool,
+{
+    tokio::time::timeout(timeout, async {
+        while !condition().await {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .is_ok()
+}
@@ -0,0 +1,267 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests for the BitMEX data client using a mock server.
+
+mod common;
+
+use std::{net::SocketAddr, num::NonZeroUsize, time::Duration};
+
+use ant_bitmex::{BitmexDataClient, BitmexDataClientConfig};
+use ant_common::{
+    messages::{
+        DataEvent, DataResponse,
+        data::{RequestTrades, SubscribeBookDeltas, SubscribeTrades},
+    },
+    runner::set_data_event_sender,
+};
+use ant_core::{UUID4, time::get_atomic_clock_realtime};
+use ant_data::client::DataClient;
+use ant_model::{
+    data::Data,
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId},
+    instruments::Instrument,
+    types::Price,
+};
+use common::{start_mock_server, wait_until};
+use rstest::rstest;
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+fn client_id() -> ClientId {
+    ClientId::from("BITMEX")
+}
+
+fn create_client(addr: SocketAddr) -> (BitmexDataClient, UnboundedReceiver<DataEvent>) {
+    let (tx, rx) = unbounded_channel();
+    set_data_event_sender(tx);
+
+    let config = BitmexDataClientConfig {
+        base_url_http: Some(format!("http://{addr}")),
+        base_url_ws: Some(format!("ws://{addr}/realtime")),
+        ..Default::default()
+    };
+    let client = BitmexDataClient::new(client_id(), config, get_atomic_clock_realtime()).unwrap();
+    (client, rx)
+}
+
+/// Receives events until one matches `predicate`, or panics after five seconds.
+async fn recv_matching<F>(rx: &mut UnboundedReceiver<DataEvent>, mut predicate: F) -> DataEvent
+where
+    F: FnMut(&DataEvent) -> bool,
+{
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let event = rx.recv().await.expect("Data event channel closed");
+            if predicate(&event) {
+                return event;
+            }
+        }
+    })
+    .await
+    .expect("Timed out waiting for data event")
+}
+
+fn subscribe_book_deltas(instrument_id: InstrumentId, depth: Option<usize>) -> SubscribeBookDeltas {
+    SubscribeBookDeltas::new(
+        instrument_id,
+        BookType::L2_MBP,
+        Some(client_id()),
+        None,
+        UUID4::new(),
+        get_atomic_clock_realtime().get_time_ns(),
+        depth.and_then(NonZeroUsize::new),
+        false,
+        None,
+    )
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_connect_loads_instruments() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, _rx) = create_client(addr);
+
+    client.connect().await.unwrap();
+
+    assert!(client.is_connected());
+    let instrument_ids: Vec<InstrumentId> =
+        client.instruments().iter().map(Instrument::id).collect();
+    assert_eq!(instrument_ids, vec![InstrumentId::from("XBTUSD.BITMEX")]);
+    assert_eq!(
+        state.requests_for("GET", "/instrument/active").await.len(),
+        1
+    );
+
+    client.disconnect().await.unwrap();
+    assert!(client.is_disconnected());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_trades_and_book_deltas() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, mut rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let instrument_id = InstrumentId::from("XBTUSD.BITMEX");
+    client
+        .subscribe_trades(&SubscribeTrades::new(
+            instrument_id,
+            Some(client_id()),
+            None,
+            UUID4::new(),
+            get_atomic_clock_realtime().get_time_ns(),
+            None,
+        ))
+        .unwrap();
+    client
+        .subscribe_book_deltas(&subscribe_book_deltas(instrument_id, None))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| matches!(e, DataEvent::Data(Data::Trade(_)))).await;
+    let DataEvent::Data(Data::Trade(trade)) = event else {
+        unreachable!()
+    };
+    assert_eq!(trade.instrument_id, instrument_id);
+    assert_eq!(trade.price, Price::from("98570.9"));
+
+    // The fixture holds a single level, which is published as a lone delta
+    let event = recv_matching(&mut rx, |e| matches!(e, DataEvent::Data(Data::Delta(_)))).await;
+    let DataEvent::Data(Data::Delta(delta)) = event else {
+        unreachable!()
+    };
+    assert_eq!(delta.instrument_id, instrument_id);
+    assert_eq!(delta.order.price, Price::from("98459.9"));
+
+    let subscriptions = state.subscriptions.lock().await.clone();
+    assert!(subscriptions.contains(&"trade:XBTUSD".to_string()));
+    assert!(subscriptions.contains(&"orderBookL2:XBTUSD".to_string()));
+
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_book_deltas_depth_25() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, _rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    client
+        .subscribe_book_deltas(&subscribe_book_deltas(
+            InstrumentId::from("XBTUSD.BITMEX"),
+            Some(25),
+        ))
+        .unwrap();
+
+    let subscribed = wait_until(
+        async || {
+            state
+                .subscriptions
+                .lock()
+                .await
+                .contains(&"orderBookL2_25:XBTUSD".to_string())
+        },
+        Duration::from_secs(2),
+    )
+    .await;
+    assert!(subscribed);
+
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[case(BookType::L1_MBP)]
+#[case(BookType::L3_MBO)]
+#[tokio::test]
+async fn test_subscribe_book_deltas_rejects_unsupported_book_type(#[case] book_type: BookType) {
+    let (addr, _state) = start_mock_server().await;
+    let (mut client, _rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let mut cmd = subscribe_book_deltas(InstrumentId::from("XBTUSD.BITMEX"), None);
+    cmd.book_type = book_type;
+
+    assert!(client.subscribe_book_deltas(&cmd).is_err());
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_unknown_instrument_errors() {
+    let (addr, _state) = start_mock_server().await;
+    let (mut client, _rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let result = client.subscribe_trades(&SubscribeTrades::new(
+        InstrumentId::from("ETHUSD.BITMEX"),
+        Some(client_id()),
+        None,
+        UUID4::new(),
+        get_atomic_clock_realtime().get_time_ns(),
+        None,
+    ));
+
+    assert!(result.is_err());
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_request_trades() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, mut rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let instrument_id = InstrumentId::from("XBTUSD.BITMEX");
+    let request_id = UUID4::new();
+    client
+        .request_trades(&RequestTrades::new(
+            instrument_id,
+            None,
+            None,
+            NonZeroUsize::new(100),
+            Some(client_id()),
+            request_id,
+            get_atomic_clock_realtime().get_time_ns(),
+            None,
+        ))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| {
+        matches!(e, DataEvent::Response(DataResponse::Trades(_)))
+    })
+    .await;
+    let DataEvent::Response(DataResponse::Trades(response)) = event else {
+        unreachable!()
+    };
+    assert_eq!(response.correlation_id, request_id);
+    assert_eq!(response.instrument_id, instrument_id);
+    assert_eq!(response.data.len(), 3);
+    assert!(
+        response
+            .data
+            .iter()
+            .all(|t| t.instrument_id == instrument_id)
+    );
+
+    let requests = state.requests_for("GET", "/trade").await;
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].contains("count=100"));
+    assert!(requests[0].contains("symbol=XBTUSD"));
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,592 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests for the BitMEX execution client using a mock server.
+
+mod common;
+
+use std::{
+    cell::RefCell,
+    net::SocketAddr,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ant_bitmex::{BitmexExecClientConfig, BitmexExecutionClient, BitmexHttpClient};
+use ant_common::{
+    cache::Cache,
+    clock::TestClock,
+    messages::execution::{
+        BatchCancelOrders, CancelOrder, GeneratePositionReports, ModifyOrder, SubmitOrder,
+    },
+    msgbus::{
+        self, get_message_saving_handler, get_saved_messages, handler::ShareableMessageHandler,
+    },
+    runtime::get_runtime,
+};
+use ant_core::{UUID4, UnixNanos};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient};
+use ant_model::{
+    enums::{OrderSide, OrderType},
+    events::{AccountState, OrderEventAny},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
+    },
+    orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+    types::{Price, Quantity},
+};
+use common::{MOCK_ORDER_ID, MockServerState, start_mock_server};
+use rstest::rstest;
+use ustr::Ustr;
+
+fn account_id() -> AccountId {
+    AccountId::from("BITMEX-1234567")
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from("XBTUSD.BITMEX")
+}
+
+fn order_event_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+    msgbus::register("ExecEngine.process".into(), handler.clone());
+    handler
+}
+
+fn account_state_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<AccountState>(Some(Ustr::from("Portfolio.update_account")));
+    msgbus::register("Portfolio.update_account".into(), handler.clone());
+    handler
+}
+
+fn create_client(
+    addr: SocketAddr,
+    cancel_all_after_ms: Option<u64>,
+    cache: Rc<RefCell<Cache>>,
+) -> BitmexExecutionClient {
+    let mut config = BitmexExecClientConfig::new(TraderId::from("TRADER-001"), account_id());
+    config.api_key = Some("test_api_key".to_string());
+    config.api_secret = Some("test_api_secret".to_string());
+    config.cancel_all_after_ms = cancel_all_after_ms;
+    config.base_url_http = Some(format!("http://{addr}"));
+    config.base_url_ws = Some(format!("ws://{addr}/realtime"));
+
+    BitmexExecutionClient::new(
+        ClientId::from("BITMEX"),
+        config,
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+    )
+    .unwrap()
+}
+
+/// Starts the mock server and returns a cache holding the instruments it serves.
+fn setup() -> (SocketAddr, MockServerState, Rc<RefCell<Cache>>) {
+    let (addr, state) = get_runtime().block_on(start_mock_server());
+
+    let http_client = BitmexHttpClient::new(
+        Some(format!("http://{addr}")),
+        None,
+        None,
+        false,
+        Some(60),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    let instruments = get_runtime()
+        .block_on(http_client.request_instruments(true))
+        .unwrap();
+
+    let mut cache = Cache::default();
+    for instrument in instruments {
+        cache.add_instrument(instrument).unwrap();
+    }
+    state.http_requests.blocking_lock().clear();
+    (addr, state, Rc::new(RefCell::new(cache)))
+}
+
+fn limit_order(client_order_id: &str, side: OrderSide, quantity: &str, price: &str) -> OrderAny {
+    OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_id())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(side)
+        .quantity(Quantity::from(quantity))
+        .price(Price::from(price))
+        .build()
+}
+
+/// Adds the order to the cache in the submitted state, optionally accepted by the venue.
+fn add_order(cache: &Rc<RefCell<Cache>>, mut order: OrderAny, accepted: bool) -> OrderAny {
+    order
+        .apply(TestOrderEventStubs::submitted(&order, account_id()))
+        .unwrap();
+    if accepted {
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id(),
+                VenueOrderId::from(MOCK_ORDER_ID),
+            ))
+            .unwrap();
+    }
+    cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    order
+}
+
+fn submit_order(order: &OrderAny) -> SubmitOrder {
+    SubmitOrder {
+        trader_id: TraderId::from("TRADER-001"),
+        client_id: ClientId::from("BITMEX"),
+        strategy_id: StrategyId::from("S-001"),
+        instrument_id: order.instrument_id(),
+        client_order_id: order.client_order_id(),
+        venue_order_id: VenueOrderId::from("NONE"),
+        order: order.clone(),
+        exec_algorith_id: None,
+        position_id: None,
+        command_id: UUID4::new(),
+        ts_init: UnixNanos::default(),
+    }
+}
+
+fn cancel_order(order: &OrderAny) -> CancelOrder {
+    CancelOrder {
+        trader_id: TraderId::from("TRADER-001"),
+        client_id: ClientId::from("BITMEX"),
+        strategy_id: order.strategy_id(),
+        instrument_id: order.instrument_id(),
+        client_order_id: order.client_order_id(),
+        venue_order_id: VenueOrderId::from(MOCK_ORDER_ID),
+        command_id: UUID4::new(),
+        ts_init: UnixNanos::default(),
+    }
+}
+
+/// Drains the client until `condition` holds for the saved events, or panics after five seconds.
+fn drain_until<F>(client: &BitmexExecutionClient, handler: &ShareableMessageHandler, condition: F)
+where
+    F: Fn(&[OrderEventAny]) -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        client.drain_events();
+        if condition(&get_saved_messages::<OrderEventAny>(handler.clone())) {
+            return;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Timed out waiting for order events"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[rstest]
+fn test_connect_subscribes_and_requests_account_state() {
+    let _orders = order_event_handler();
+    let accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let mut client = create_client(addr, None, cache);
+
+    client.connect().unwrap();
+
+    assert!(client.is_connected());
+    get_runtime().block_on(async {
+        assert_eq!(*state.auth_calls.lock().await, 1);
+        let subscriptions = state.subscriptions.lock().await.clone();
+        for topic in ["order", "execution", "position", "wallet"] {
+            assert!(
+                subscriptions.contains(&topic.to_string()),
+                "{topic} not subscribed"
+            );
+        }
+        assert_eq!(state.requests_for("GET", "/user/margin").await.len(), 1);
+        // No dead man's switch is armed without a timeout
+        assert!(
+            state
+                .requests_for("POST", "/order/cancelAllAfter")
+                .await
+                .is_empty()
+        );
+    });
+
+    let account_states = get_saved_messages::<AccountState>(accounts);
+    assert!(!account_states.is_empty());
+    assert_eq!(account_states[0].account_id, account_id());
+
+    client.disconnect().unwrap();
+    assert!(!client.is_connected());
+}
+
+#[rstest]
+fn test_order_stream_generates_accepted() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    add_order(
+        &cache,
+        limit_order(
+            "mm_bitmex_1a/oemUeQ4CAJZgP3fjHsA",
+            OrderSide::Buy,
+            "100",
+            "98000",
+        ),
+        false,
+    );
+
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+
+    drain_until(&client, &handler, |events| {
+        events
+            .iter()
+            .any(|e| matches!(e, OrderEventAny::Accepted(_)))
+    });
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Accepted(accepted) = &events[0] else {
+        panic!("Expected accepted, was {:?}", events[0]);
+    };
+    assert_eq!(
+        accepted.venue_order_id,
+        VenueOrderId::from("550e8400-e29b-41d4-a716-446655440001")
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_execution_stream_generates_accepted_and_filled() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            "mm_bitmex_2b/oemUeQ4CAJZgP3fjHsB",
+            OrderSide::Sell,
+            "200",
+            "99000",
+        ),
+        false,
+    );
+
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+
+    drain_until(&client, &handler, |events| {
+        events.iter().any(|e| matches!(e, OrderEventAny::Filled(_)))
+    });
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+    let OrderEventAny::Filled(fill) = &events[1] else {
+        panic!("Expected fill, was {:?}", events[1]);
+    };
+    assert_eq!(fill.client_order_id, order.client_order_id());
+    assert_eq!(
+        fill.trade_id,
+        TradeId::from("00000000-006d-1000-0000-000e8737d540")
+    );
+    assert_eq!(fill.last_qty, Quantity::from("100"));
+    assert_eq!(fill.last_px, Price::from("98950.0"));
+    assert_eq!(
+        fill.position_id.map(|id| id.to_string()),
+        Some(format!("{}-{}", instrument_id(), order.strategy_id()))
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_order_sends_order_over_http() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order("O-20250105-001", OrderSide::Sell, "300", "100000"),
+        false,
+    );
+
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[0], OrderEventAny::Submitted(_)));
+    let OrderEventAny::Accepted(accepted) = &events[1] else {
+        panic!("Expected accepted, was {:?}", events[1]);
+    };
+    assert_eq!(accepted.venue_order_id, VenueOrderId::from(MOCK_ORDER_ID));
+
+    let requests = get_runtime().block_on(state.requests_for("POST", "/order"));
+    assert_eq!(requests.len(), 1);
+    for param in [
+        "clOrdID=O-20250105-001",
+        "symbol=XBTUSD",
+        "side=Sell",
+        "ordType=Limit",
+        "orderQty=300",
+        "price=100000",
+    ] {
+        assert!(
+            requests[0].contains(param),
+            "{param} not in {}",
+            requests[0]
+        );
+    }
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_order_when_not_connected_rejects() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    let order = limit_order("O-20250105-002", OrderSide::Buy, "100", "90000");
+
+    let client = create_client(addr, None, cache);
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    assert!(
+        get_runtime()
+            .block_on(state.requests_for("POST", "/order"))
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_cancel_order_generates_canceled() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order("O-20250105-003", OrderSide::Buy, "100", "90000"),
+        true,
+    );
+
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+    client.cancel_order(&cancel_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Canceled(canceled) = &events[0] else {
+        panic!("Expected canceled, was {:?}", events[0]);
+    };
+    assert_eq!(canceled.client_order_id, order.client_order_id());
+
+    let requests = get_runtime().block_on(state.requests_for("DELETE", "/order"));
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].contains("clOrdID=O-20250105-003"));
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_batch_cancel_orders_generates_canceled_for_each_order() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let orders: Vec<OrderAny> = ["O-20250105-004", "O-20250105-005"]
+        .into_iter()
+        .map(|id| {
+            add_order(
+                &cache,
+                limit_order(id, OrderSide::Buy, "100", "90000"),
+                true,
+            )
+        })
+        .collect();
+
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+    client
+        .batch_cancel_orders(&BatchCancelOrders {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("BITMEX"),
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: instrument_id(),
+            cancels: orders.iter().map(cancel_order).collect(),
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        })
+        .unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    let canceled: Vec<ClientOrderId> = events
+        .iter()
+        .filter_map(|e| match e {
+            OrderEventAny::Canceled(canceled) => Some(canceled.client_order_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        canceled,
+        orders
+            .iter()
+            .map(Order::client_order_id)
+            .collect::<Vec<_>>()
+    );
+
+    // Both orders are canceled with a single request
+    let requests = get_runtime().block_on(state.requests_for("DELETE", "/order"));
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].contains("clOrdID=O-20250105-004,O-20250105-005"));
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_modify_order_generates_updated() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order("O-20250105-006", OrderSide::Buy, "100", "90000"),
+        true,
+    );
+
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+    client
+        .modify_order(&ModifyOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("BITMEX"),
+            strategy_id: order.strategy_id(),
+            instrument_id: instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::from(MOCK_ORDER_ID),
+            quantity: Some(Quantity::from("200")),
+            price: Some(Price::from("91000")),
+            trigger_price: None,
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        })
+        .unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Updated(updated) = &events[0] else {
+        panic!("Expected updated, was {:?}", events[0]);
+    };
+    assert_eq!(updated.quantity, Quantity::from("200"));
+    assert_eq!(updated.price, Some(Price::from("91000.0")));
+
+    let requests = get_runtime().block_on(state.requests_for("PUT", "/order"));
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].contains(&format!("orderID={MOCK_ORDER_ID}")));
+    assert!(requests[0].contains("orderQty=200"));
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_dead_mans_switch_armed_on_connect_and_disarmed_on_disconnect() {
+    let _orders = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let mut client = create_client(addr, Some(60_000), cache);
+
+    client.connect().unwrap();
+    let requests = get_runtime().block_on(state.requests_for("POST", "/order/cancelAllAfter"));
+    assert_eq!(requests, vec!["POST /order/cancelAllAfter?timeout=60000"]);
+
+    client.disconnect().unwrap();
+    let requests = get_runtime().block_on(state.requests_for("POST", "/order/cancelAllAfter"));
+    assert_eq!(
+        requests,
+        vec![
+            "POST /order/cancelAllAfter?timeout=60000",
+            "POST /order/cancelAllAfter?timeout=0",
+        ]
+    );
+}
+
+#[rstest]
+#[case(10_000, 15)]
+#[case(10_000, 0)]
+fn test_invalid_dead_mans_switch_refresh_interval_errors(
+    #[case] cancel_all_after_ms: u64,
+    #[case] refresh_secs: u64,
+) {
+    let mut config = BitmexExecClientConfig::new(TraderId::from("TRADER-001"), account_id());
+    config.api_key = Some("test_api_key".to_string());
+    config.api_secret = Some("test_api_secret".to_string());
+    config.cancel_all_after_ms = Some(cancel_all_after_ms);
+    config.cancel_all_after_refresh_secs = refresh_secs;
+
+    let result = BitmexExecutionClient::new(
+        ClientId::from("BITMEX"),
+        config,
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+    );
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_generate_mass_status_from_http_fixtures() {
+    let _orders = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    let mut client = create_client(addr, None, cache);
+    client.connect().unwrap();
+
+    let mass_status = client.generate_mass_status(None).unwrap().unwrap();
+
+    assert_eq!(mass_status.account_id, account_id());
+    assert_eq!(mass_status.order_reports().len(), 2);
+    assert_eq!(
+        mass_status
+            .fill_reports()
+            .values()
+            .map(Vec::len)
+            .sum::<usize>(),
+        2
+    );
+    assert_eq!(mass_status.position_reports().len(), 1);
+
+    let cmd = GeneratePositionReports::new(
+        UUID4::new(),
+        UnixNanos::default(),
+        Some(instrument_id()),
+        None,
+        None,
+    );
+    let position_reports = client.generate_position_status_reports(&cmd).unwrap();
+    assert_eq!(position_reports.len(), 1);
+    assert_eq!(position_reports[0].instrument_id, instrument_id());
+
+    client.disconnect().unwrap();
+}
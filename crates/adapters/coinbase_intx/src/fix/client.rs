@@ -30,31 +30,30 @@ use std::{
     time::Duration,
 };
 
-use aws_lc_rs::hmac;
-use base64::prelude::*;
 use ant_common::logging::{log_task_started, log_task_stopped};
-#[cfg(feature = "python")]
-use ant_core::python::IntoPyObjectantExt;
 use ant_core::{env::get_env_var, time::get_atomic_clock_realtime};
 use ant_model::identifiers::AccountId;
-use ant_network::socket::{SocketClient, SocketConfig, WriterCommand};
-#[cfg(feature = "python")]
-use pyo3::prelude::*;
-use tokio::task::JoinHandle;
+use ant_network::socket::{SocketClient, SocketConfig, TcpMessageHandler, WriterCommand};
+use aws_lc_rs::hmac;
+use base64::prelude::*;
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+    task::JoinHandle,
+};
 use tokio_tungstenite::tungstenite::stream::Mode;
 
 use super::{
-    messages::{FIX_DELIMITER, FixMessage},
-    parse::convert_to_order_status_report,
+    messages::{ExecutionReport, FIX_DELIMITER, FixMessage},
+    parse::parse_execution_report,
 };
 use crate::{
     common::consts::COINBASE_INTX,
-    fix::{
-        messages::{fix_exec_type, fix_message_type, fix_tag},
-        parse::convert_to_fill_report,
-    },
+    fix::messages::{fix_message_type, fix_tag},
 };
 
+/// The maximum time in seconds to wait for the acceptor to confirm a logout.
+const FIX_LOGOUT_TIMEOUT_SECS: u64 = 2;
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "ant_trader.core.ant_pyo3.adapters")
@@ -74,6 +73,8 @@ pub struct CoinbaseIntxFixClient {
     seq_num: Arc<AtomicUsize>,
     received_seq_num: Arc<AtomicUsize>,
     heartbeat_secs: u64,
+    use_tls: bool,
+    message_task: Option<Arc<JoinHandle<()>>>,
     processing_task: Option<Arc<JoinHandle<()>>>,
     heartbeat_task: Option<Arc<JoinHandle<()>>>,
 }
@@ -92,10 +93,12 @@ impl CoinbaseIntxFixClient {
         portfolio_id: Option<String>,
     ) -> anyhow::Result<Self> {
         let endpoint = endpoint.unwrap_or("fix.international.coinbase.com:6130".to_string());
-        let api_key = api_key.unwrap_or(get_env_var("COINBASE_INTX_API_KEY")?);
-        let api_secret = api_secret.unwrap_or(get_env_var("COINBASE_INTX_API_SECRET")?);
-        let api_passphrase = api_passphrase.unwrap_or(get_env_var("COINBASE_INTX_API_PASSPHRASE")?);
-        let portfolio_id = portfolio_id.unwrap_or(get_env_var("COINBASE_INTX_PORTFOLIO_ID")?);
+        let api_key = api_key.map_or_else(|| get_env_var("COINBASE_INTX_API_KEY"), Ok)?;
+        let api_secret = api_secret.map_or_else(|| get_env_var("COINBASE_INTX_API_SECRET"), Ok)?;
+        let api_passphrase =
+            api_passphrase.map_or_else(|| get_env_var("COINBASE_INTX_API_PASSPHRASE"), Ok)?;
+        let portfolio_id =
+            portfolio_id.map_or_else(|| get_env_var("COINBASE_INTX_PORTFOLIO_ID"), Ok)?;
         let sender_comp_id = api_key.to_string();
         let target_comp_id = "CBINTLDC".to_string(); // Drop Copy endpoint
 
@@ -113,6 +116,8 @@ impl CoinbaseIntxFixClient {
             seq_num: Arc::new(AtomicUsize::new(1)),
             received_seq_num: Arc::new(AtomicUsize::new(0)),
             heartbeat_secs: 10, // Default (probably no need to change)
+            use_tls: true,
+            message_task: None,
             processing_task: None,
             heartbeat_task: None,
         })
@@ -128,6 +133,14 @@ impl CoinbaseIntxFixClient {
         Self::new(None, None, None, None, None)
     }
 
+    /// Sets whether the connection uses TLS, which may be disabled to connect to a local
+    /// FIX acceptor (e.g. for testing).
+    #[must_use]
+    pub const fn with_tls(mut self, use_tls: bool) -> Self {
+        self.use_tls = use_tls;
+        self
+    }
+
     /// Returns the FIX endpoint being used by the client.
     #[must_use]
     pub const fn endpoint(&self) -> &str {
@@ -172,6 +185,11 @@ impl CoinbaseIntxFixClient {
 
     /// Connects to the Coinbase International FIX Drop Copy endpoint.
     ///
+    /// Returns a receiver for the order status and fill reports parsed from the execution
+    /// reports on the drop copy session. Gaps in the inbound sequence numbers are detected
+    /// and the missed messages requested for resend, so a report may be received more than
+    /// once and consumers should deduplicate them.
+    ///
     /// # Panics
     ///
     /// Panics if time calculation or unwrap logic inside fails during logon retry setup.
@@ -179,122 +197,21 @@ impl CoinbaseIntxFixClient {
     /// # Errors
     ///
     /// Returns an error if network connection or FIX logon fails.
-    pub async fn connect(
-        &mut self,
-        #[cfg(feature = "python")] handler: PyObject,
-        #[cfg(not(feature = "python"))] _handler: (),
-    ) -> anyhow::Result<()> {
-        let logged_on = self.logged_on.clone();
+    pub async fn connect(&mut self) -> anyhow::Result<UnboundedReceiver<ExecutionReport>> {
         let seq_num = self.seq_num.clone();
-        let received_seq_num = self.received_seq_num.clone();
         let account_id = AccountId::new(format!("{COINBASE_INTX}-{}", self.portfolio_id));
 
-        let handle_message = Arc::new(move |data: &[u8]| {
-            if let Ok(message) = FixMessage::parse(data) {
-                // Update received sequence number
-                if let Some(msg_seq) = message.msg_seq_num() {
-                    received_seq_num.store(msg_seq, Ordering::SeqCst);
-                }
-
-                // Process message based on type
-                if let Some(msg_type) = message.msg_type() {
-                    match msg_type {
-                        fix_message_type::LOGON => {
-                            tracing::info!("Logon successful");
-                            logged_on.store(true, Ordering::SeqCst);
-                        }
-                        fix_message_type::LOGOUT => {
-                            tracing::info!("Received logout");
-                            logged_on.store(false, Ordering::SeqCst);
-                        }
-                        fix_message_type::EXECUTION_REPORT => {
-                            if let Some(exec_type) = message.get_field(fix_tag::EXEC_TYPE) {
-                                if matches!(
-                                    exec_type,
-                                    fix_exec_type::REJECTED
-                                        | fix_exec_type::NEW
-                                        | fix_exec_type::PENDING_NEW
-                                ) {
-                                    // These order events are already handled by the client
-                                    tracing::debug!(
-                                        "Received execution report for EXEC_TYPE {exec_type} (not handling here)"
-                                    );
-                                } else if matches!(
-                                    exec_type,
-                                    fix_exec_type::CANCELED
-                                        | fix_exec_type::EXPIRED
-                                        | fix_exec_type::REPLACED
-                                ) {
-                                    let clock = get_atomic_clock_realtime(); // TODO: Optimize
-                                    let ts_init = clock.get_time_ns();
-                                    match convert_to_order_status_report(
-                                        &message, account_id, ts_init,
-                                    ) {
-                                        #[cfg(feature = "python")]
-                                        Ok(report) => Python::with_gil(|py| {
-                                            call_python(
-                                                py,
-                                                &handler,
-                                                report.into_py_any_unwrap(py),
-                                            );
-                                        }),
-                                        #[cfg(not(feature = "python"))]
-                                        Ok(_report) => {
-                                            tracing::debug!(
-                                                "Order status report handled (Python disabled)"
-                                            );
-                                        }
-                                        Err(e) => {
-                                            tracing::error!(
-                                                "Failed to parse FIX execution report: {e}"
-                                            );
-                                        }
-                                    }
-                                } else if exec_type == fix_exec_type::PARTIAL_FILL
-                                    || exec_type == fix_exec_type::FILL
-                                {
-                                    let clock = get_atomic_clock_realtime(); // TODO: Optimize
-                                    let ts_init = clock.get_time_ns();
-                                    match convert_to_fill_report(&message, account_id, ts_init) {
-                                        #[cfg(feature = "python")]
-                                        Ok(report) => Python::with_gil(|py| {
-                                            call_python(
-                                                py,
-                                                &handler,
-                                                report.into_py_any_unwrap(py),
-                                            );
-                                        }),
-                                        #[cfg(not(feature = "python"))]
-                                        Ok(_report) => {
-                                            tracing::debug!(
-                                                "Fill report handled (Python disabled)"
-                                            );
-                                        }
-                                        Err(e) => {
-                                            tracing::error!(
-                                                "Failed to parse FIX execution report: {e}"
-                                            );
-                                        }
-                                    }
-                                } else {
-                                    tracing::warn!("Unhandled EXEC_TYPE {exec_type}: {message:?}");
-                                }
-                            }
-                        }
-                        // These can be HEARTBEAT or TEST_REQUEST messages,
-                        // ideally we'd respond to these with a heartbeat
-                        // including tag 112 TestReqID.
-                        _ => tracing::trace!("Recieved unexpected {message:?}"),
-                    }
-                }
-            } else {
-                tracing::error!("Failed to parse FIX message");
+        // Messages are handled on a task which can reply on the session once connected
+        let (raw_tx, raw_rx) = unbounded_channel::<Vec<u8>>();
+        let handle_message: TcpMessageHandler = Arc::new(move |data: &[u8]| {
+            if raw_tx.send(data.to_vec()).is_err() {
+                tracing::debug!("FIX message handler stopped");
             }
         });
 
         let config = SocketConfig {
             url: self.endpoint.clone(),
-            mode: Mode::Tls,
+            mode: if self.use_tls { Mode::Tls } else { Mode::Plain },
             suffix: vec![FIX_DELIMITER],
             message_handler: Some(handle_message),
             heartbeat: None, // Using FIX heartbeats
@@ -318,6 +235,20 @@ impl CoinbaseIntxFixClient {
         };
 
         let writer_tx = socket.writer_tx.clone();
+        self.connected.store(true, Ordering::SeqCst);
+
+        let (report_tx, report_rx) = unbounded_channel();
+        let session = FixSession {
+            sender_comp_id: self.sender_comp_id.clone(),
+            target_comp_id: self.target_comp_id.clone(),
+            seq_num: self.seq_num.clone(),
+            received_seq_num: self.received_seq_num.clone(),
+            logged_on: self.logged_on.clone(),
+            writer_tx: writer_tx.clone(),
+        };
+        self.message_task = Some(Arc::new(tokio::spawn(handle_messages(
+            raw_rx, session, account_id, report_tx,
+        ))));
 
         self.socket = Some(Arc::new(socket));
 
@@ -370,8 +301,13 @@ impl CoinbaseIntxFixClient {
                     // Create new heartbeat message
                     let seq = seq_num.fetch_add(1, Ordering::SeqCst) + 1;
                     let now = chrono::Utc::now();
-                    let msg =
-                        FixMessage::create_heartbeat(seq, &sender_comp_id, &target_comp_id, &now);
+                    let msg = FixMessage::create_heartbeat(
+                        seq,
+                        &sender_comp_id,
+                        &target_comp_id,
+                        None,
+                        &now,
+                    );
 
                     if let Err(e) = writer_tx.send(WriterCommand::Send(msg.to_bytes().into())) {
                         tracing::error!("Failed to send heartbeat: {e}");
@@ -391,20 +327,34 @@ impl CoinbaseIntxFixClient {
             log_task_stopped("heartbeat");
         })));
 
-        Ok(())
+        Ok(report_rx)
     }
 
     /// Closes the connection.
     ///
+    /// Logs out first if logged on, waiting for the acceptor to confirm the logout so that it is
+    /// not lost when the socket closes.
+    ///
     /// # Errors
     ///
     /// Returns an error if logout or socket closure fails.
     pub async fn close(&mut self) -> anyhow::Result<()> {
+        // Cancel processing task first, so the logout does not trigger a new logon
+        if let Some(task) = self.processing_task.take() {
+            task.abort();
+        }
+
+        // Cancel heartbeat task
+        if let Some(task) = self.heartbeat_task.take() {
+            task.abort();
+        }
+
         // Send logout message if connected
-        if self.is_logged_on()
-            && let Err(e) = self.send_logout("Normal logout").await
-        {
-            tracing::warn!("Failed to send logout message: {e}");
+        if self.is_logged_on() {
+            match self.send_logout("Normal logout").await {
+                Ok(()) => self.await_logout().await,
+                Err(e) => tracing::warn!("Failed to send logout message: {e}"),
+            }
         }
 
         // Close socket
@@ -412,13 +362,8 @@ impl CoinbaseIntxFixClient {
             socket.close().await;
         }
 
-        // Cancel processing task
-        if let Some(task) = self.processing_task.take() {
-            task.abort();
-        }
-
-        // Cancel heartbeat task
-        if let Some(task) = self.heartbeat_task.take() {
+        // Cancel message handling task
+        if let Some(task) = self.message_task.take() {
             task.abort();
         }
 
@@ -428,14 +373,27 @@ impl CoinbaseIntxFixClient {
         Ok(())
     }
 
+    /// Waits for the acceptor to confirm a logout, up to the logout timeout.
+    async fn await_logout(&self) {
+        let start = std::time::Instant::now();
+        while self.is_logged_on() {
+            if start.elapsed() > Duration::from_secs(FIX_LOGOUT_TIMEOUT_SECS) {
+                tracing::warn!("Logout not confirmed within {FIX_LOGOUT_TIMEOUT_SECS}s");
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
     /// Send a logon message
     async fn send_logon(&self) -> anyhow::Result<()> {
         if self.socket.is_none() {
             anyhow::bail!("Socket not connected".to_string());
         }
 
-        // Reset sequence number
+        // Reset sequence numbers, the acceptor also resets on a logon with ResetSeqNumFlag
         self.seq_num.store(1, Ordering::SeqCst);
+        self.received_seq_num.store(0, Ordering::SeqCst);
 
         let now = chrono::Utc::now();
         let timestamp = now.format("%Y%m%d-%H:%M:%S.%3f").to_string();
@@ -497,7 +455,7 @@ impl CoinbaseIntxFixClient {
             anyhow::bail!("Socket not connected".to_string());
         }
 
-        let seq_num = self.seq_num.fetch_add(1, Ordering::SeqCst);
+        let seq_num = self.seq_num.fetch_add(1, Ordering::SeqCst) + 1;
         let now = chrono::Utc::now();
 
         let logout_msg = FixMessage::create_logout(
@@ -521,10 +479,232 @@ impl CoinbaseIntxFixClient {
     }
 }
 
-// Can't be moved to core because we don't want to depend on tracing there
-#[cfg(feature = "python")]
-pub fn call_python(py: Python, callback: &PyObject, py_obj: PyObject) {
-    if let Err(e) = callback.call1(py, (py_obj,)) {
-        tracing::error!("Error calling Python: {e}");
+/// The result of checking an inbound message sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SequenceCheck {
+    /// The next expected message.
+    InOrder,
+    /// Messages were missed, starting from the contained sequence number.
+    Gap(usize),
+    /// A retransmission of an earlier message.
+    Resent,
+    /// An earlier sequence number without the possible duplicate flag.
+    TooLow,
+}
+
+/// Checks the inbound `seq` against the last received sequence number.
+const fn check_sequence(last_received: usize, seq: usize, poss_dup: bool) -> SequenceCheck {
+    let expected = last_received + 1;
+    if seq == expected {
+        SequenceCheck::InOrder
+    } else if seq > expected {
+        SequenceCheck::Gap(expected)
+    } else if poss_dup {
+        SequenceCheck::Resent
+    } else {
+        SequenceCheck::TooLow
+    }
+}
+
+/// The session state shared with the message handling task.
+#[derive(Debug)]
+struct FixSession {
+    sender_comp_id: String,
+    target_comp_id: String,
+    seq_num: Arc<AtomicUsize>,
+    received_seq_num: Arc<AtomicUsize>,
+    logged_on: Arc<AtomicBool>,
+    writer_tx: UnboundedSender<WriterCommand>,
+}
+
+impl FixSession {
+    fn next_seq_num(&self) -> usize {
+        self.seq_num.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    fn send(&self, msg: &FixMessage) {
+        if let Err(e) = self
+            .writer_tx
+            .send(WriterCommand::Send(msg.to_bytes().into()))
+        {
+            tracing::error!("Failed to send FIX message: {e}");
+        }
+    }
+
+    fn handle_message(
+        &self,
+        message: &FixMessage,
+        account_id: AccountId,
+        report_tx: &UnboundedSender<ExecutionReport>,
+    ) {
+        let msg_type = message.msg_type().unwrap_or_default();
+
+        // Sequence resets carry the next sequence number rather than being sequenced themselves
+        if msg_type == fix_message_type::SEQUENCE_RESET {
+            self.handle_sequence_reset(message);
+            return;
+        }
+
+        if let Some(seq) = message.msg_seq_num()
+            && !self.check_sequence(seq, message.is_poss_dup())
+        {
+            return;
+        }
+
+        match msg_type {
+            fix_message_type::LOGON => {
+                tracing::info!("Logon successful");
+                self.logged_on.store(true, Ordering::SeqCst);
+            }
+            fix_message_type::LOGOUT => {
+                tracing::info!("Received logout");
+                self.logged_on.store(false, Ordering::SeqCst);
+            }
+            fix_message_type::TEST_REQUEST => {
+                let test_req_id = message.get_field(fix_tag::TEST_REQ_ID);
+                let now = chrono::Utc::now();
+                self.send(&FixMessage::create_heartbeat(
+                    self.next_seq_num(),
+                    &self.sender_comp_id,
+                    &self.target_comp_id,
+                    test_req_id,
+                    &now,
+                ));
+            }
+            fix_message_type::RESEND_REQUEST => {
+                let Some(begin_seq_no) = message
+                    .get_field(fix_tag::BEGIN_SEQ_NO)
+                    .and_then(|s| s.parse::<usize>().ok())
+                else {
+                    tracing::warn!("Resend request without BeginSeqNo: {message:?}");
+                    return;
+                };
+
+                // Only session messages have been sent, so the requested range is gap filled
+                let new_seq_no = self.seq_num.load(Ordering::SeqCst) + 1;
+                tracing::info!("Gap filling resend request from {begin_seq_no} to {new_seq_no}");
+                let now = chrono::Utc::now();
+                self.send(&FixMessage::create_gap_fill(
+                    begin_seq_no,
+                    &self.sender_comp_id,
+                    &self.target_comp_id,
+                    new_seq_no,
+                    &now,
+                ));
+            }
+            fix_message_type::EXECUTION_REPORT => {
+                let ts_init = get_atomic_clock_realtime().get_time_ns();
+                match parse_execution_report(message, account_id, ts_init) {
+                    Ok(Some(report)) => {
+                        if report_tx.send(report).is_err() {
+                            tracing::debug!("Execution report receiver dropped");
+                        }
+                    }
+                    Ok(None) => tracing::debug!("Received pending execution report"),
+                    Err(e) => tracing::error!("Failed to parse FIX execution report: {e}"),
+                }
+            }
+            fix_message_type::HEARTBEAT => tracing::trace!("Received heartbeat"),
+            _ => tracing::trace!("Received unexpected {message:?}"),
+        }
+    }
+
+    /// Returns whether the message should be processed, requesting a resend of any messages
+    /// missed before it.
+    fn check_sequence(&self, seq: usize, poss_dup: bool) -> bool {
+        let last_received = self.received_seq_num.load(Ordering::SeqCst);
+        match check_sequence(last_received, seq, poss_dup) {
+            SequenceCheck::InOrder => {
+                self.received_seq_num.store(seq, Ordering::SeqCst);
+                true
+            }
+            SequenceCheck::Gap(expected) => {
+                tracing::warn!(
+                    "Sequence gap detected: expected {expected}, received {seq}, requesting resend"
+                );
+                self.received_seq_num.store(seq, Ordering::SeqCst);
+                let now = chrono::Utc::now();
+                self.send(&FixMessage::create_resend_request(
+                    self.next_seq_num(),
+                    &self.sender_comp_id,
+                    &self.target_comp_id,
+                    expected,
+                    &now,
+                ));
+                true
+            }
+            // Resent reports are deduplicated by consumers
+            SequenceCheck::Resent => true,
+            SequenceCheck::TooLow => {
+                tracing::error!(
+                    "Sequence number {seq} lower than expected {}, ignoring message",
+                    last_received + 1
+                );
+                false
+            }
+        }
+    }
+
+    fn handle_sequence_reset(&self, message: &FixMessage) {
+        let Some(new_seq_no) = message
+            .get_field(fix_tag::NEW_SEQ_NO)
+            .and_then(|s| s.parse::<usize>().ok())
+        else {
+            tracing::warn!("Sequence reset without NewSeqNo: {message:?}");
+            return;
+        };
+
+        let last_received = new_seq_no.saturating_sub(1);
+        if message.get_field(fix_tag::GAP_FILL_FLAG) == Some("Y") {
+            // A gap fill never moves the expected sequence number backwards
+            self.received_seq_num
+                .fetch_max(last_received, Ordering::SeqCst);
+        } else {
+            self.received_seq_num.store(last_received, Ordering::SeqCst);
+        }
+        tracing::debug!("Sequence reset to {new_seq_no}");
+    }
+}
+
+async fn handle_messages(
+    mut raw_rx: UnboundedReceiver<Vec<u8>>,
+    session: FixSession,
+    account_id: AccountId,
+    report_tx: UnboundedSender<ExecutionReport>,
+) {
+    log_task_started("fix-message-handler");
+
+    while let Some(data) = raw_rx.recv().await {
+        match FixMessage::parse(&data) {
+            Ok(message) => session.handle_message(&message, account_id, &report_tx),
+            Err(e) => tracing::error!("Failed to parse FIX message: {e}"),
+        }
+    }
+
+    log_task_stopped("fix-message-handler");
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, 1, false, SequenceCheck::InOrder)]
+    #[case(4, 5, true, SequenceCheck::InOrder)]
+    #[case(4, 8, false, SequenceCheck::Gap(5))]
+    #[case(4, 3, true, SequenceCheck::Resent)]
+    #[case(4, 4, false, SequenceCheck::TooLow)]
+    fn test_check_sequence(
+        #[case] last_received: usize,
+        #[case] seq: usize,
+        #[case] poss_dup: bool,
+        #[case] expected: SequenceCheck,
+    ) {
+        assert_eq!(check_sequence(last_received, seq, poss_dup), expected);
     }
 }
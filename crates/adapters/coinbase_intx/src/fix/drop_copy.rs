@@ -0,0 +1,369 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Feeds the Coinbase International FIX drop copy into the execution engine.
+//!
+//! The drop copy session streams an execution report for every order event on the portfolio,
+//! independently of the session the order was placed on, which makes it a low-latency source of
+//! order updates and fills for an execution client.
+//!
+//! Reports arrive on the shared runtime and are queued, and must be drained from the engine
+//! thread with [`CoinbaseIntxDropCopy::drain_reports`] so that order events are published on the
+//! thread owning the message bus.
+
+use std::{cell::RefCell, future::Future, rc::Rc};
+
+use ahash::AHashSet;
+use ant_common::{cache::Cache, clock::Clock, runtime::block_on};
+use ant_core::UnixNanos;
+use ant_execution::client::base::BaseExecutionClient;
+use ant_model::{
+    enums::{AccountType, OmsType, OrderStatus},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId,
+        TraderId, VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{FillReport, OrderStatusReport},
+    types::{Price, Quantity},
+};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::{client::CoinbaseIntxFixClient, messages::ExecutionReport};
+use crate::common::consts::{COINBASE_INTX, COINBASE_INTX_VENUE};
+
+/// Generates order events from the Coinbase International FIX drop copy.
+///
+/// Reports for orders not in the cache are ignored, as are repeated fills, so that
+/// retransmissions after a sequence gap do not duplicate events.
+pub struct CoinbaseIntxDropCopy {
+    core: BaseExecutionClient,
+    cache: Rc<RefCell<Cache>>,
+    fix_client: CoinbaseIntxFixClient,
+    report_rx: RefCell<Option<UnboundedReceiver<ExecutionReport>>>,
+    accepted: RefCell<AHashSet<ClientOrderId>>,
+    trade_ids: RefCell<AHashSet<TradeId>>,
+}
+
+impl std::fmt::Debug for CoinbaseIntxDropCopy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(CoinbaseIntxDropCopy))
+            .field("client_id", &self.core.client_id)
+            .field("account_id", &self.core.account_id)
+            .field("fix_client", &self.fix_client)
+            .finish()
+    }
+}
+
+impl CoinbaseIntxDropCopy {
+    /// Creates a new [`CoinbaseIntxDropCopy`] instance generating events for the portfolio of
+    /// the `fix_client` on behalf of the execution client `client_id`.
+    pub fn new(
+        trader_id: TraderId,
+        client_id: ClientId,
+        fix_client: CoinbaseIntxFixClient,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        let account_id = AccountId::new(format!("{COINBASE_INTX}-{}", fix_client.portfolio_id()));
+        let core = BaseExecutionClient::new(
+            trader_id,
+            client_id,
+            *COINBASE_INTX_VENUE,
+            OmsType::Netting,
+            account_id,
+            AccountType::Margin,
+            None,
+            clock,
+            cache.clone(),
+        );
+
+        Self {
+            core,
+            cache,
+            fix_client,
+            report_rx: RefCell::new(None),
+            accepted: RefCell::new(AHashSet::new()),
+            trade_ids: RefCell::new(AHashSet::new()),
+        }
+    }
+
+    /// Returns the account ID of the drop copy portfolio.
+    #[must_use]
+    pub const fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    /// Checks if the drop copy session is logged on.
+    #[must_use]
+    pub fn is_logged_on(&self) -> bool {
+        self.fix_client.is_logged_on()
+    }
+
+    /// Connects and logs on to the drop copy session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection or FIX logon fails.
+    pub fn connect(&mut self) -> anyhow::Result<()> {
+        let report_rx = block_on(self.fix_client.connect())?;
+        *self.report_rx.borrow_mut() = Some(report_rx);
+        self.core.set_connected(true);
+        tracing::info!("Connected drop copy for {}", self.core.account_id);
+        Ok(())
+    }
+
+    /// Processes the queued reports, then logs out and closes the drop copy session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if closing the session fails.
+    pub fn disconnect(&mut self) -> anyhow::Result<()> {
+        self.drain_reports();
+        block_on(self.fix_client.close())?;
+        *self.report_rx.borrow_mut() = None;
+        self.core.set_connected(false);
+        tracing::info!("Disconnected drop copy for {}", self.core.account_id);
+        Ok(())
+    }
+
+    /// Processes all queued drop copy reports, returning the number processed.
+    ///
+    /// Must be called from the thread owning the message bus, typically on each engine tick.
+    pub fn drain_reports(&self) -> usize {
+        let mut count = 0;
+        loop {
+            // Release the receiver borrow before handling, as strategies may react to an event
+            // by sending further commands
+            let report = match self.report_rx.borrow_mut().as_mut() {
+                Some(rx) => match rx.try_recv() {
+                    Ok(report) => report,
+                    Err(_) => break,
+                },
+                None => break,
+            };
+            match report {
+                ExecutionReport::Order(report) => self.handle_order_report(&report),
+                ExecutionReport::Fill(report) => self.handle_fill_report(&report),
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns the cached order for a report, or `None` for orders not placed by ant.
+    fn report_order(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: &VenueOrderId,
+    ) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        // Replaced orders are reported with a new client order ID
+        let client_order_id = client_order_id
+            .filter(|id| cache.order(id).is_some())
+            .or_else(|| cache.client_order_id(venue_order_id).copied())?;
+        cache.order(&client_order_id).cloned()
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentEnum> {
+        self.cache.borrow().instrument(instrument_id).cloned()
+    }
+
+    /// Generates an accepted event if the order has not yet been accepted.
+    fn ensure_accepted(&self, order: &OrderAny, venue_order_id: VenueOrderId, ts_event: UnixNanos) {
+        if order.status() == OrderStatus::Submitted
+            && self.accepted.borrow_mut().insert(order.client_order_id())
+        {
+            self.core.generate_order_accepted(
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                venue_order_id,
+                ts_event,
+            );
+        }
+    }
+
+    fn handle_order_report(&self, report: &OrderStatusReport) {
+        let Some(order) = self.report_order(report.client_order_id, &report.venue_order_id) else {
+            tracing::debug!("Order report for external order {}", report.venue_order_id);
+            return;
+        };
+
+        if order.is_closed() {
+            tracing::debug!("Order {} already closed", order.client_order_id());
+            return;
+        }
+
+        let strategy_id = order.strategy_id();
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let venue_order_id = report.venue_order_id;
+        let ts_event = report.ts_last;
+
+        match report.order_status {
+            OrderStatus::Accepted | OrderStatus::PartiallyFilled => {
+                if order.status() == OrderStatus::Submitted {
+                    self.ensure_accepted(&order, venue_order_id, ts_event);
+                } else if report.quantity != order.quantity()
+                    || (report.price.is_some() && report.price != order.price())
+                {
+                    // Replaced orders are reported with the new quantity and price
+                    self.handle_replaced(&order, report);
+                }
+            }
+            OrderStatus::Canceled => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_canceled(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Expired => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_expired(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Rejected => {
+                if order.status() == OrderStatus::Submitted {
+                    self.core.generate_order_rejected(
+                        strategy_id,
+                        instrument_id,
+                        client_order_id,
+                        report
+                            .cancel_reason
+                            .as_deref()
+                            .unwrap_or("Rejected by venue"),
+                        ts_event,
+                        false,
+                    );
+                }
+            }
+            status => tracing::debug!("Order {client_order_id} status {status:?}"),
+        }
+    }
+
+    fn handle_replaced(&self, order: &OrderAny, report: &OrderStatusReport) {
+        let Some(instrument) = self.instrument(&order.instrument_id()) else {
+            tracing::error!("Instrument {} not found in cache", order.instrument_id());
+            return;
+        };
+
+        // Drop copy reports are parsed without the instrument precisions
+        let rescale = || -> anyhow::Result<(Quantity, Option<Price>, Option<Price>)> {
+            let quantity =
+                Quantity::from_decimal(report.quantity.as_decimal(), instrument.size_precision())?;
+            let price = report
+                .price
+                .or_else(|| order.price())
+                .map(|price| Price::from_decimal(price.as_decimal(), instrument.price_precision()))
+                .transpose()?;
+            let trigger_price = report
+                .trigger_price
+                .map(|price| Price::from_decimal(price.as_decimal(), instrument.price_precision()))
+                .transpose()?;
+            Ok((quantity, price, trigger_price))
+        };
+        let (quantity, Some(price), trigger_price) = (match rescale() {
+            Ok(values) => values,
+            Err(e) => {
+                tracing::error!(
+                    "Failed to parse replaced order {}: {e}",
+                    order.client_order_id()
+                );
+                return;
+            }
+        }) else {
+            return;
+        };
+
+        self.core.generate_order_updated(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            quantity,
+            price,
+            trigger_price,
+            report.ts_last,
+            false,
+        );
+    }
+
+    fn handle_fill_report(&self, report: &FillReport) {
+        let Some(order) = self.report_order(report.client_order_id, &report.venue_order_id) else {
+            tracing::debug!("Fill report for external order {}", report.venue_order_id);
+            return;
+        };
+
+        if !self.trade_ids.borrow_mut().insert(report.trade_id) {
+            tracing::debug!("Duplicate fill {}", report.trade_id);
+            return;
+        }
+
+        let Some(instrument) = self.instrument(&order.instrument_id()) else {
+            tracing::error!("Instrument {} not found in cache", order.instrument_id());
+            return;
+        };
+
+        // Drop copy reports are parsed without the instrument precisions
+        let last_qty =
+            Quantity::from_decimal(report.last_qty.as_decimal(), instrument.size_precision());
+        let last_px =
+            Price::from_decimal(report.last_px.as_decimal(), instrument.price_precision());
+        let (last_qty, last_px) = match (last_qty, last_px) {
+            (Ok(last_qty), Ok(last_px)) => (last_qty, last_px),
+            (Err(e), _) | (_, Err(e)) => {
+                tracing::error!("Failed to parse fill {}: {e}", report.trade_id);
+                return;
+            }
+        };
+
+        self.ensure_accepted(&order, report.venue_order_id, report.ts_event);
+
+        self.core.generate_order_filled(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report
+                .venue_position_id
+                .unwrap_or_else(|| venue_position_id(order.instrument_id(), order.strategy_id())),
+            report.trade_id,
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            instrument.quote_currency(),
+            report.commission,
+            report.liquidity_side,
+            report.ts_event,
+        );
+    }
+}
+
+/// Returns the position ID for orders of the strategy, as positions are netted per instrument.
+fn venue_position_id(instrument_id: InstrumentId, strategy_id: StrategyId) -> PositionId {
+    PositionId::new(format!("{instrument_id}-{strategy_id}"))
+}
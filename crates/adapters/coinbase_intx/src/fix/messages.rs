@@ -16,9 +16,17 @@
 // Some constants not used (retained for completeness)
 #![allow(dead_code)]
 
+use ant_model::reports::{FillReport, OrderStatusReport};
 use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 
+/// Represents a report parsed from a drop copy FIX execution report.
+#[derive(Debug, Clone)]
+pub enum ExecutionReport {
+    Order(OrderStatusReport),
+    Fill(FillReport),
+}
+
 /// Common FIX tags used in this implementation.
 pub mod fix_tag {
     // Standard header fields
@@ -30,6 +38,7 @@ pub mod fix_tag {
     pub const MSG_SEQ_NUM: u32 = 34; // Message sequence number
     pub const SENDING_TIME: u32 = 52; // Message sending time
     pub const CHECKSUM: u32 = 10; // Checksum of message
+    pub const POSS_DUP_FLAG: u32 = 43; // Possible duplicate (retransmission) flag
 
     // Logon fields
     pub const ENCRYPT_METHOD: u32 = 98; // Encryption method (0 = none)
@@ -38,6 +47,13 @@ pub mod fix_tag {
     pub const USERNAME: u32 = 553; // Username for authentication
     pub const PASSWORD: u32 = 554; // Password for authentication
 
+    // Session fields
+    pub const BEGIN_SEQ_NO: u32 = 7; // First message to resend
+    pub const END_SEQ_NO: u32 = 16; // Last message to resend (0 = infinity)
+    pub const NEW_SEQ_NO: u32 = 36; // Next expected sequence number after a reset
+    pub const TEST_REQ_ID: u32 = 112; // Test request ID echoed in the heartbeat
+    pub const GAP_FILL_FLAG: u32 = 123; // Sequence reset is a gap fill
+
     // Execution report fields
     pub const CL_ORD_ID: u32 = 11; // Client order ID
     pub const ORIG_CL_ORD_ID: u32 = 41; // Original client order ID (for cancel/replace)
@@ -118,7 +134,7 @@ pub(crate) mod fix_exec_type {
     pub const PENDING_NEW: &str = "A";
     pub const EXPIRED: &str = "C";
     pub const PENDING_REPLACE: &str = "E";
-    pub const TRADE: &str = "F"; // For Trade Capture Reports
+    pub const TRADE: &str = "F"; // Trade (partial fill or fill)
     pub const STOP_TRIGGERED: &str = "L";
 }
 
@@ -285,20 +301,79 @@ impl FixMessage {
         msg
     }
 
-    /// Creates a heartbeat message.
+    /// Creates a heartbeat message, echoing the test request ID if responding to one.
     pub(crate) fn create_heartbeat(
         seq_num: usize,
         sender_comp_id: &str,
         target_comp_id: &str,
+        test_req_id: Option<&str>,
         timestamp: &DateTime<Utc>,
     ) -> Self {
-        Self::new(
+        let mut msg = Self::new(
             fix_message_type::HEARTBEAT,
             seq_num,
             sender_comp_id,
             target_comp_id,
             timestamp,
-        )
+        );
+
+        if let Some(test_req_id) = test_req_id {
+            msg.add_field(fix_tag::TEST_REQ_ID, test_req_id);
+        }
+
+        msg
+    }
+
+    /// Creates a resend request for all messages from `begin_seq_no` onwards.
+    pub(crate) fn create_resend_request(
+        seq_num: usize,
+        sender_comp_id: &str,
+        target_comp_id: &str,
+        begin_seq_no: usize,
+        timestamp: &DateTime<Utc>,
+    ) -> Self {
+        let mut msg = Self::new(
+            fix_message_type::RESEND_REQUEST,
+            seq_num,
+            sender_comp_id,
+            target_comp_id,
+            timestamp,
+        );
+
+        msg.add_field(fix_tag::BEGIN_SEQ_NO, begin_seq_no.to_string())
+            .add_field(fix_tag::END_SEQ_NO, "0"); // All messages after the gap
+
+        msg
+    }
+
+    /// Creates a sequence reset gap fill, skipping resent messages up to `new_seq_no`.
+    ///
+    /// Only session messages are sent on the drop copy, which are never resent.
+    pub(crate) fn create_gap_fill(
+        seq_num: usize,
+        sender_comp_id: &str,
+        target_comp_id: &str,
+        new_seq_no: usize,
+        timestamp: &DateTime<Utc>,
+    ) -> Self {
+        let mut msg = Self::new(
+            fix_message_type::SEQUENCE_RESET,
+            seq_num,
+            sender_comp_id,
+            target_comp_id,
+            timestamp,
+        );
+
+        msg.add_field(fix_tag::POSS_DUP_FLAG, "Y")
+            .add_field(fix_tag::GAP_FILL_FLAG, "Y")
+            .add_field(fix_tag::NEW_SEQ_NO, new_seq_no.to_string());
+
+        msg
+    }
+
+    /// Returns whether the message is flagged as a possible duplicate (retransmission).
+    pub(crate) fn is_poss_dup(&self) -> bool {
+        self.get_field(fix_tag::POSS_DUP_FLAG) == Some("Y")
     }
 
     /// Creates a logout message.
@@ -368,4 +443,40 @@ mod tests {
 
         assert_eq!(message, expected);
     }
+
+    #[rstest]
+    fn test_fix_message_to_bytes_resend_request() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 22, 12, 34, 56).unwrap();
+        let msg = FixMessage::create_resend_request(5, "SENDER", "TARGET", 3, &timestamp);
+
+        let message = String::from_utf8(msg.to_bytes()).unwrap();
+
+        assert!(message.contains("\x0135=2\x01"));
+        assert!(message.contains("\x0134=5\x01"));
+        assert!(message.contains("\x017=3\x0116=0\x01"));
+    }
+
+    #[rstest]
+    fn test_gap_fill_is_poss_dup() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 22, 12, 34, 56).unwrap();
+        let msg = FixMessage::create_gap_fill(2, "SENDER", "TARGET", 7, &timestamp);
+
+        let parsed = FixMessage::parse(&msg.to_bytes()).unwrap();
+
+        assert_eq!(parsed.msg_type(), Some(fix_message_type::SEQUENCE_RESET));
+        assert!(parsed.is_poss_dup());
+        assert_eq!(parsed.get_field(fix_tag::GAP_FILL_FLAG), Some("Y"));
+        assert_eq!(parsed.get_field(fix_tag::NEW_SEQ_NO), Some("7"));
+    }
+
+    #[rstest]
+    fn test_heartbeat_echoes_test_request_id() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 22, 12, 34, 56).unwrap();
+        let msg = FixMessage::create_heartbeat(3, "SENDER", "TARGET", Some("TEST-1"), &timestamp);
+
+        let parsed = FixMessage::parse(&msg.to_bytes()).unwrap();
+
+        assert_eq!(parsed.msg_type(), Some(fix_message_type::HEARTBEAT));
+        assert_eq!(parsed.get_field(fix_tag::TEST_REQ_ID), Some("TEST-1"));
+    }
 }
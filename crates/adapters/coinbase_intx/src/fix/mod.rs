@@ -14,8 +14,9 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod client;
+pub mod drop_copy;
 pub mod messages;
 mod parse;
 
 // Re-exports
-pub use crate::fix::client::CoinbaseIntxFixClient;
+pub use crate::fix::{client::CoinbaseIntxFixClient, drop_copy::CoinbaseIntxDropCopy};
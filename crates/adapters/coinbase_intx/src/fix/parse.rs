@@ -13,22 +13,55 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use chrono::{DateTime, Utc};
-use ant_core::{UnixNanos, time::get_atomic_clock_realtime};
+use ant_core::UnixNanos;
 use ant_model::{
     enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType},
     identifiers::{AccountId, ClientOrderId, InstrumentId, Symbol, TradeId, VenueOrderId},
     reports::{FillReport, OrderStatusReport},
     types::{Currency, Money, Price, Quantity},
 };
+use chrono::NaiveDateTime;
 use ustr::Ustr;
 
-use super::messages::{FixMessage, fix_tag};
+use super::messages::{ExecutionReport, FixMessage, fix_exec_type, fix_tag};
 use crate::common::{consts::COINBASE_INTX_VENUE, parse::parse_instrument_id};
 
 // Reasonable default precision for now, as reports will be converted in the clients.
 const DEFAULT_PRECISION: u8 = 8;
 
+/// Parses a FIX execution report into an order status or fill report.
+///
+/// Returns `None` for pending states, which are followed by a report of the outcome.
+///
+/// # Errors
+///
+/// Returns an error if the execution type is unknown, or a required FIX tag is missing or
+/// cannot be parsed.
+pub fn parse_execution_report(
+    message: &FixMessage,
+    account_id: AccountId,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<ExecutionReport>> {
+    let exec_type = message.get_field_checked(fix_tag::EXEC_TYPE)?;
+    match exec_type {
+        fix_exec_type::PARTIAL_FILL | fix_exec_type::FILL | fix_exec_type::TRADE => Ok(Some(
+            ExecutionReport::Fill(convert_to_fill_report(message, account_id, ts_init)?),
+        )),
+        fix_exec_type::NEW
+        | fix_exec_type::CANCELED
+        | fix_exec_type::REPLACED
+        | fix_exec_type::REJECTED
+        | fix_exec_type::EXPIRED
+        | fix_exec_type::STOP_TRIGGERED => Ok(Some(ExecutionReport::Order(
+            convert_to_order_status_report(message, account_id, ts_init)?,
+        ))),
+        fix_exec_type::PENDING_NEW
+        | fix_exec_type::PENDING_CANCEL
+        | fix_exec_type::PENDING_REPLACE => Ok(None),
+        _ => anyhow::bail!("Unknown exec type: {exec_type}"),
+    }
+}
+
 /// Parse a FIX execution report message to create a ant `OrderStatusReport`.
 ///
 /// # Errors
@@ -239,15 +272,9 @@ pub fn convert_to_fill_report(
         _ => LiquiditySide::NoLiquiditySide,
     };
 
-    // Parse transaction time if available
-    let ts_event = if let Some(transact_time) = message.get_field(fix_tag::TRANSACT_TIME) {
-        if let Ok(dt) = DateTime::parse_from_str(transact_time, "%Y%m%d-%H:%M:%S%.3f") {
-            UnixNanos::from(dt.with_timezone(&Utc))
-        } else {
-            ts_init
-        }
-    } else {
-        ts_init
+    let ts_event = match message.get_field(fix_tag::TRANSACT_TIME) {
+        Some(transact_time) => parse_fix_timestamp(transact_time)?,
+        None => ts_init,
     };
 
     let instrument_id = InstrumentId::new(Symbol::from_str_unchecked(symbol), *COINBASE_INTX_VENUE);
@@ -265,15 +292,150 @@ pub fn convert_to_fill_report(
         Some(client_order_id),
         None, // Position ID not applicable
         ts_event,
-        get_atomic_clock_realtime().get_time_ns(),
+        ts_init,
         None, // UUID will be generated
     );
 
     Ok(report)
 }
 
-/// Parse a FIX timestamp in format YYYYMMDDd-HH:MM:SS.sss
+/// Parse a FIX UTC timestamp in format YYYYMMDD-HH:MM:SS[.sss]
 fn parse_fix_timestamp(timestamp: &str) -> Result<UnixNanos, anyhow::Error> {
-    let dt = DateTime::parse_from_str(timestamp, "%Y%m%d-%H:%M:%S%.3f")?;
-    Ok(UnixNanos::from(dt.with_timezone(&Utc)))
+    // FIX timestamps carry no offset, so must be parsed as naive UTC times
+    let dt = NaiveDateTime::parse_from_str(timestamp, "%Y%m%d-%H:%M:%S%.f")?;
+    Ok(UnixNanos::from(dt.and_utc()))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use ant_model::enums::{LiquiditySide, OrderSide, OrderStatus};
+    use chrono::{TimeZone, Utc};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::messages::fix_message_type;
+
+    const ACCOUNT_ID: &str = "COINBASE_INTX-001";
+
+    fn execution_report(exec_type: &str, ord_status: &str) -> FixMessage {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 22, 12, 34, 56).unwrap();
+        let mut message = FixMessage::new(
+            fix_message_type::EXECUTION_REPORT,
+            2,
+            "CBINTLDC",
+            "portfolio",
+            &timestamp,
+        );
+        message
+            .add_field(fix_tag::ORDER_ID, "1-2-3")
+            .add_field(fix_tag::CL_ORD_ID, "O-001")
+            .add_field(fix_tag::EXEC_TYPE, exec_type)
+            .add_field(fix_tag::ORD_STATUS, ord_status)
+            .add_field(fix_tag::SYMBOL, "BTC-PERP")
+            .add_field(fix_tag::SIDE, "1")
+            .add_field(fix_tag::ORD_TYPE, "2")
+            .add_field(fix_tag::TIME_IN_FORCE, "1")
+            .add_field(fix_tag::ORDER_QTY, "0.01")
+            .add_field(fix_tag::PRICE, "60000.5")
+            .add_field(fix_tag::LEAVES_QTY, "0")
+            .add_field(fix_tag::CUM_QTY, "0.01")
+            .add_field(fix_tag::TRANSACT_TIME, "20250322-12:34:56.789");
+        message
+    }
+
+    #[rstest]
+    #[case(fix_exec_type::PARTIAL_FILL)]
+    #[case(fix_exec_type::FILL)]
+    #[case(fix_exec_type::TRADE)]
+    fn test_parse_execution_report_fill(#[case] exec_type: &str) {
+        let mut message = execution_report(exec_type, "2");
+        message
+            .add_field(fix_tag::TRD_MATCH_ID, "T-001")
+            .add_field(fix_tag::LAST_QTY, "0.01")
+            .add_field(fix_tag::LAST_PX, "60000.5")
+            .add_field(fix_tag::CURRENCY, "USDC")
+            .add_field(fix_tag::LAST_LIQUIDITY_IND, "1");
+
+        let report =
+            parse_execution_report(&message, AccountId::new(ACCOUNT_ID), UnixNanos::default())
+                .unwrap()
+                .unwrap();
+
+        let ExecutionReport::Fill(report) = report else {
+            panic!("Expected fill report, was {report:?}");
+        };
+        assert_eq!(
+            report.instrument_id,
+            InstrumentId::from("BTC-PERP.COINBASE_INTX")
+        );
+        assert_eq!(report.client_order_id, Some(ClientOrderId::new("O-001")));
+        assert_eq!(report.venue_order_id, VenueOrderId::new("1-2-3"));
+        assert_eq!(report.trade_id, TradeId::new("T-001"));
+        assert_eq!(report.order_side, OrderSide::Buy);
+        assert_eq!(report.last_qty.as_f64(), 0.01);
+        assert_eq!(report.last_px.as_f64(), 60_000.5);
+        assert_eq!(report.liquidity_side, LiquiditySide::Maker);
+        assert_eq!(report.ts_event, UnixNanos::from(1_742_646_896_789_000_000));
+    }
+
+    #[rstest]
+    fn test_parse_execution_report_canceled() {
+        let mut message = execution_report(fix_exec_type::CANCELED, "4");
+        message.add_field(fix_tag::TEXT, "User requested");
+
+        let report =
+            parse_execution_report(&message, AccountId::new(ACCOUNT_ID), UnixNanos::default())
+                .unwrap()
+                .unwrap();
+
+        let ExecutionReport::Order(report) = report else {
+            panic!("Expected order status report, was {report:?}");
+        };
+        assert_eq!(report.order_status, OrderStatus::Canceled);
+        assert_eq!(report.venue_order_id, VenueOrderId::new("1-2-3"));
+        assert_eq!(report.cancel_reason.as_deref(), Some("User requested"));
+        assert_eq!(report.ts_last, UnixNanos::from(1_742_646_896_789_000_000));
+    }
+
+    #[rstest]
+    #[case(fix_exec_type::PENDING_NEW, "A")]
+    #[case(fix_exec_type::PENDING_CANCEL, "6")]
+    #[case(fix_exec_type::PENDING_REPLACE, "E")]
+    fn test_parse_execution_report_pending_is_skipped(
+        #[case] exec_type: &str,
+        #[case] ord_status: &str,
+    ) {
+        let message = execution_report(exec_type, ord_status);
+
+        let report =
+            parse_execution_report(&message, AccountId::new(ACCOUNT_ID), UnixNanos::default())
+                .unwrap();
+
+        assert!(report.is_none());
+    }
+
+    #[rstest]
+    fn test_parse_execution_report_unknown_exec_type() {
+        let message = execution_report("Z", "0");
+
+        let result =
+            parse_execution_report(&message, AccountId::new(ACCOUNT_ID), UnixNanos::default());
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("20250322-12:34:56", 1_742_646_896_000_000_000)]
+    #[case("20250322-12:34:56.789", 1_742_646_896_789_000_000)]
+    #[case("20250322-12:34:56.789123", 1_742_646_896_789_123_000)]
+    fn test_parse_fix_timestamp(#[case] timestamp: &str, #[case] expected: u64) {
+        assert_eq!(
+            parse_fix_timestamp(timestamp).unwrap(),
+            UnixNanos::from(expected)
+        );
+    }
 }
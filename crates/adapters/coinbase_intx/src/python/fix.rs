@@ -15,10 +15,11 @@
 
 //! Provides `PyO3` bindings for the Coinbase International FIX client.
 
-use ant_core::python::{to_pyruntime_err, to_pyvalue_err};
+use ant_core::python::{IntoPyObjectantExt, to_pyruntime_err, to_pyvalue_err};
 use pyo3::prelude::*;
 
-use crate::fix::client::CoinbaseIntxFixClient;
+use super::websocket::call_python;
+use crate::fix::{client::CoinbaseIntxFixClient, messages::ExecutionReport};
 
 #[pymethods]
 impl CoinbaseIntxFixClient {
@@ -89,7 +90,21 @@ impl CoinbaseIntxFixClient {
         let mut client = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            client.connect(handler).await.map_err(to_pyruntime_err)
+            let mut report_rx = client.connect().await.map_err(to_pyruntime_err)?;
+
+            tokio::spawn(async move {
+                while let Some(report) = report_rx.recv().await {
+                    Python::with_gil(|py| {
+                        let py_obj = match report {
+                            ExecutionReport::Order(report) => report.into_py_any_unwrap(py),
+                            ExecutionReport::Fill(report) => report.into_py_any_unwrap(py),
+                        };
+                        call_python(py, &handler, py_obj);
+                    });
+                }
+            });
+
+            Ok(())
         })
     }
 
@@ -0,0 +1,166 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A local FIX acceptor stub standing in for the Coinbase International drop copy endpoint.
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::mpsc::{UnboundedSender, unbounded_channel},
+};
+
+/// The CompID of the drop copy acceptor.
+pub const ACCEPTOR_COMP_ID: &str = "CBINTLDC";
+
+/// The API key used by the test client, which is also its CompID.
+pub const API_KEY: &str = "test_api_key";
+
+/// The fields of a FIX message keyed by tag.
+pub type FixFields = HashMap<u32, String>;
+
+/// The state of the acceptor, recording the messages received from the client.
+#[derive(Debug, Clone, Default)]
+pub struct AcceptorState {
+    received: Arc<Mutex<Vec<FixFields>>>,
+    writer_tx: Arc<Mutex<Option<UnboundedSender<Vec<u8>>>>>,
+}
+
+impl AcceptorState {
+    /// Returns the messages of `msg_type` received from the client.
+    pub fn received(&self, msg_type: &str) -> Vec<FixFields> {
+        self.received
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|fields| fields.get(&35).map(String::as_str) == Some(msg_type))
+            .cloned()
+            .collect()
+    }
+
+    /// Sends a message to the connected client with the given sequence number.
+    pub fn send(&self, msg_type: &str, seq_num: usize, poss_dup: bool, fields: &[(u32, &str)]) {
+        let bytes = encode_fix(msg_type, seq_num, poss_dup, fields);
+        self.writer_tx
+            .lock()
+            .unwrap()
+            .as_ref()
+            .expect("Client not connected")
+            .send(bytes)
+            .unwrap();
+    }
+}
+
+/// Encodes a FIX message from the acceptor to the client, computing the body length and
+/// checksum.
+pub fn encode_fix(
+    msg_type: &str,
+    seq_num: usize,
+    poss_dup: bool,
+    fields: &[(u32, &str)],
+) -> Vec<u8> {
+    let mut body =
+        format!("35={msg_type}\x0149={ACCEPTOR_COMP_ID}\x0156={API_KEY}\x0134={seq_num}\x01");
+    if poss_dup {
+        body.push_str("43=Y\x01");
+    }
+    body.push_str("52=20250322-12:34:56.000\x01");
+    for (tag, value) in fields {
+        body.push_str(&format!("{tag}={value}\x01"));
+    }
+
+    let mut message = format!("8=FIXT.1.1\x019={}\x01{body}", body.len());
+    let checksum = message.bytes().map(u32::from).sum::<u32>() % 256;
+    message.push_str(&format!("10={checksum:03}\x01"));
+    message.into_bytes()
+}
+
+/// Parses the fields of a FIX message.
+fn decode_fix(data: &[u8]) -> FixFields {
+    String::from_utf8_lossy(data)
+        .split('\x01')
+        .filter_map(|field| field.split_once('='))
+        .filter_map(|(tag, value)| Some((tag.parse().ok()?, value.to_string())))
+        .collect()
+}
+
+/// Removes the next complete FIX message from the front of `buffer`, discarding any delimiters
+/// between messages.
+fn next_message(buffer: &mut Vec<u8>) -> Option<Vec<u8>> {
+    let start = buffer.windows(5).position(|w| w == b"8=FIX")?;
+    let checksum = start + buffer[start..].windows(4).position(|w| w == b"\x0110=")?;
+    let end = checksum + 1 + buffer[checksum + 1..].iter().position(|&b| b == b'\x01')?;
+    let message = buffer[start..=end].to_vec();
+    buffer.drain(..=end);
+    Some(message)
+}
+
+/// Starts the FIX acceptor on a local port, replying to each logon and logout from the client.
+///
+/// Messages sent by the test with [`AcceptorState::send`] should be sequenced from 2, as the
+/// logon reply is sent with sequence number 1.
+pub async fn start_fix_acceptor() -> (SocketAddr, AcceptorState) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let state = AcceptorState::default();
+
+    let acceptor_state = state.clone();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let (mut reader, mut writer) = stream.into_split();
+            let (writer_tx, mut writer_rx) = unbounded_channel::<Vec<u8>>();
+            *acceptor_state.writer_tx.lock().unwrap() = Some(writer_tx);
+
+            tokio::spawn(async move {
+                while let Some(bytes) = writer_rx.recv().await {
+                    if writer.write_all(&bytes).await.is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let state = acceptor_state.clone();
+            tokio::spawn(async move {
+                let mut buffer = Vec::new();
+                let mut chunk = [0u8; 4096];
+                while let Ok(n) = reader.read(&mut chunk).await {
+                    if n == 0 {
+                        break;
+                    }
+                    buffer.extend_from_slice(&chunk[..n]);
+
+                    while let Some(message) = next_message(&mut buffer) {
+                        let fields = decode_fix(&message);
+                        let msg_type = fields.get(&35).cloned().unwrap_or_default();
+                        state.received.lock().unwrap().push(fields);
+
+                        match msg_type.as_str() {
+                            "A" => state.send("A", 1, false, &[(98, "0"), (108, "10")]),
+                            "5" => state.send("5", 2, false, &[]),
+                            _ => {}
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    (addr, state)
+}
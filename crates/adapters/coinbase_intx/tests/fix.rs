@@ -0,0 +1,383 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests for the Coinbase International FIX drop copy using a local FIX acceptor.
+
+mod common;
+
+use std::{
+    cell::RefCell,
+    net::SocketAddr,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ant_coinbase_intx::{
+    fix::{CoinbaseIntxDropCopy, CoinbaseIntxFixClient},
+    http::{models::CoinbaseIntxInstrument, parse::parse_perp_instrument},
+};
+use ant_common::{
+    cache::Cache,
+    clock::TestClock,
+    msgbus::{
+        self, get_message_saving_handler, get_saved_messages, handler::ShareableMessageHandler,
+    },
+    runtime::get_runtime,
+};
+use ant_core::UnixNanos;
+use ant_model::{
+    enums::{LiquiditySide, OrderSide, OrderType},
+    events::OrderEventAny,
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, TradeId, TraderId, VenueOrderId,
+    },
+    orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+    types::{Money, Price, Quantity},
+};
+use common::{ACCEPTOR_COMP_ID, API_KEY, AcceptorState, start_fix_acceptor};
+use rstest::rstest;
+use ustr::Ustr;
+
+const VENUE_ORDER_ID: &str = "1-2-3";
+
+fn account_id() -> AccountId {
+    AccountId::from("COINBASE_INTX-test_portfolio")
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from("BTC-PERP.COINBASE_INTX")
+}
+
+fn order_event_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+    msgbus::register("ExecEngine.process".into(), handler.clone());
+    handler
+}
+
+/// Starts the FIX acceptor and returns a cache holding the BTC-PERP instrument.
+fn setup() -> (SocketAddr, AcceptorState, Rc<RefCell<Cache>>) {
+    let (addr, state) = get_runtime().block_on(start_fix_acceptor());
+
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/test_data/http_get_instruments_BTC-PERP.json"
+    );
+    let definition: CoinbaseIntxInstrument =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    let instrument =
+        parse_perp_instrument(&definition, None, None, None, None, UnixNanos::default()).unwrap();
+
+    let mut cache = Cache::default();
+    cache.add_instrument(instrument).unwrap();
+    (addr, state, Rc::new(RefCell::new(cache)))
+}
+
+fn create_drop_copy(addr: SocketAddr, cache: Rc<RefCell<Cache>>) -> CoinbaseIntxDropCopy {
+    let fix_client = CoinbaseIntxFixClient::new(
+        Some(addr.to_string()),
+        Some(API_KEY.to_string()),
+        Some("dGVzdF9hcGlfc2VjcmV0".to_string()),
+        Some("test_passphrase".to_string()),
+        Some("test_portfolio".to_string()),
+    )
+    .unwrap()
+    .with_tls(false);
+
+    CoinbaseIntxDropCopy::new(
+        TraderId::from("TRADER-001"),
+        ClientId::from("COINBASE_INTX"),
+        fix_client,
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+    )
+}
+
+/// Adds a limit order to buy 0.02 BTC-PERP to the cache in the submitted state, optionally
+/// accepted by the venue.
+fn add_order(cache: &Rc<RefCell<Cache>>, client_order_id: &str, accepted: bool) -> OrderAny {
+    let mut order = OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_id())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("0.0200"))
+        .price(Price::from("60000.5"))
+        .build();
+    order
+        .apply(TestOrderEventStubs::submitted(&order, account_id()))
+        .unwrap();
+    if accepted {
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id(),
+                VenueOrderId::from(VENUE_ORDER_ID),
+            ))
+            .unwrap();
+    }
+    cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    order
+}
+
+/// Returns the fields of an execution report for the order with the given status.
+fn order_fields<'a>(
+    client_order_id: &'a str,
+    exec_type: &'a str,
+    ord_status: &'a str,
+    cum_qty: &'a str,
+) -> Vec<(u32, &'a str)> {
+    vec![
+        (37, VENUE_ORDER_ID),
+        (11, client_order_id),
+        (150, exec_type),
+        (39, ord_status),
+        (55, "BTC-PERP"),
+        (54, "1"),
+        (40, "2"),
+        (59, "1"),
+        (38, "0.02"),
+        (44, "60000.5"),
+        (151, "0"),
+        (14, cum_qty),
+        (60, "20250322-12:34:56.789"),
+    ]
+}
+
+/// Returns the fields of an execution report for a 0.005 fill of the order.
+fn fill_fields<'a>(client_order_id: &'a str, trade_id: &'a str) -> Vec<(u32, &'a str)> {
+    let mut fields = order_fields(client_order_id, "F", "1", "0.005");
+    fields.extend([
+        (880, trade_id),
+        (32, "0.005"),
+        (31, "60000.5"),
+        (15, "USDC"),
+        (851, "1"),
+        (136, "1"),
+        (137, "0.06"),
+        (138, "USDC"),
+    ]);
+    fields
+}
+
+/// Waits until `condition` holds for the messages received by the acceptor, or panics after
+/// five seconds.
+fn wait_until<F>(condition: F)
+where
+    F: Fn() -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !condition() {
+        assert!(
+            Instant::now() < deadline,
+            "Timed out waiting for FIX messages"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Drains the drop copy until `condition` holds for the saved events, or panics after five
+/// seconds.
+fn drain_until<F>(drop_copy: &CoinbaseIntxDropCopy, handler: &ShareableMessageHandler, condition: F)
+where
+    F: Fn(&[OrderEventAny]) -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        drop_copy.drain_reports();
+        if condition(&get_saved_messages::<OrderEventAny>(handler.clone())) {
+            return;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Timed out waiting for order events"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[rstest]
+fn test_connect_logs_on_with_reset() {
+    let (addr, state, cache) = setup();
+    let mut drop_copy = create_drop_copy(addr, cache);
+
+    drop_copy.connect().unwrap();
+
+    assert!(drop_copy.is_logged_on());
+    assert_eq!(drop_copy.account_id(), account_id());
+    let logons = state.received("A");
+    assert_eq!(logons.len(), 1);
+    assert_eq!(logons[0][&34], "1");
+    assert_eq!(logons[0][&49], API_KEY);
+    assert_eq!(logons[0][&56], ACCEPTOR_COMP_ID);
+    assert_eq!(logons[0][&141], "Y");
+    assert_eq!(logons[0][&553], API_KEY);
+    assert_eq!(logons[0][&554], "test_passphrase");
+    assert!(!logons[0][&58].is_empty()); // Signature
+}
+
+#[rstest]
+fn test_fill_generates_accepted_and_filled() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    add_order(&cache, "O-001", false);
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    state.send("8", 2, false, &fill_fields("O-001", "T-001"));
+
+    drain_until(&drop_copy, &handler, |events| events.len() == 2);
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    let OrderEventAny::Accepted(accepted) = &events[0] else {
+        panic!("Expected accepted, was {:?}", events[0]);
+    };
+    assert_eq!(accepted.venue_order_id, VenueOrderId::from(VENUE_ORDER_ID));
+    let OrderEventAny::Filled(filled) = &events[1] else {
+        panic!("Expected filled, was {:?}", events[1]);
+    };
+    assert_eq!(filled.client_order_id, ClientOrderId::from("O-001"));
+    assert_eq!(filled.trade_id, TradeId::from("T-001"));
+    assert_eq!(filled.last_qty, Quantity::from("0.0050"));
+    assert_eq!(filled.last_qty.precision, 4);
+    assert_eq!(filled.last_px, Price::from("60000.5"));
+    assert_eq!(filled.last_px.precision, 1);
+    assert_eq!(filled.commission, Some(Money::from("0.06 USDC")));
+    assert_eq!(filled.liquidity_side, LiquiditySide::Maker);
+    assert_eq!(filled.account_id, account_id());
+    assert_eq!(filled.ts_event, UnixNanos::from(1_742_646_896_789_000_000));
+}
+
+#[rstest]
+fn test_canceled_report_generates_canceled() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    add_order(&cache, "O-001", true);
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    state.send("8", 2, false, &order_fields("O-001", "0", "0", "0"));
+    state.send("8", 3, false, &order_fields("O-001", "4", "4", "0"));
+
+    drain_until(&drop_copy, &handler, |events| !events.is_empty());
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Canceled(canceled) = &events[0] else {
+        panic!("Expected canceled, was {:?}", events[0]);
+    };
+    assert_eq!(canceled.client_order_id, ClientOrderId::from("O-001"));
+    assert_eq!(
+        canceled.venue_order_id,
+        Some(VenueOrderId::from(VENUE_ORDER_ID))
+    );
+}
+
+#[rstest]
+fn test_pending_and_external_reports_are_ignored() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    add_order(&cache, "O-001", false);
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    state.send("8", 2, false, &order_fields("O-001", "A", "A", "0"));
+    state.send("8", 3, false, &fill_fields("O-EXTERNAL", "T-001"));
+    state.send("8", 4, false, &order_fields("O-001", "0", "0", "0"));
+
+    drain_until(&drop_copy, &handler, |events| !events.is_empty());
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+}
+
+#[rstest]
+fn test_sequence_gap_requests_resend_and_deduplicates_fills() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    add_order(&cache, "O-001", false);
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    // Sequence number 2 is missed
+    state.send("8", 3, false, &fill_fields("O-001", "T-002"));
+
+    wait_until(|| !state.received("2").is_empty());
+    let resend_requests = state.received("2");
+    assert_eq!(resend_requests[0][&7], "2");
+    assert_eq!(resend_requests[0][&16], "0");
+
+    state.send("8", 2, true, &fill_fields("O-001", "T-001"));
+    state.send("8", 3, true, &fill_fields("O-001", "T-002"));
+    state.send("8", 4, false, &order_fields("O-001", "4", "4", "0.01"));
+
+    drain_until(&drop_copy, &handler, |events| {
+        events
+            .iter()
+            .any(|event| matches!(event, OrderEventAny::Canceled(_)))
+    });
+    let trade_ids: Vec<_> = get_saved_messages::<OrderEventAny>(handler)
+        .iter()
+        .filter_map(|event| match event {
+            OrderEventAny::Filled(filled) => Some(filled.trade_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(trade_ids, [TradeId::from("T-002"), TradeId::from("T-001")]);
+}
+
+#[rstest]
+fn test_test_request_replies_with_heartbeat() {
+    let (addr, state, cache) = setup();
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    state.send("1", 2, false, &[(112, "TEST-1")]);
+
+    wait_until(|| {
+        state
+            .received("0")
+            .iter()
+            .any(|fields| fields.get(&112).map(String::as_str) == Some("TEST-1"))
+    });
+}
+
+#[rstest]
+fn test_resend_request_is_gap_filled() {
+    let (addr, state, cache) = setup();
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    state.send("2", 2, false, &[(7, "1"), (16, "0")]);
+
+    wait_until(|| !state.received("4").is_empty());
+    let gap_fill = &state.received("4")[0];
+    assert_eq!(gap_fill[&34], "1");
+    assert_eq!(gap_fill[&43], "Y");
+    assert_eq!(gap_fill[&123], "Y");
+    assert!(gap_fill[&36].parse::<usize>().unwrap() > 1);
+}
+
+#[rstest]
+fn test_disconnect_logs_out() {
+    let (addr, state, cache) = setup();
+    let mut drop_copy = create_drop_copy(addr, cache);
+    drop_copy.connect().unwrap();
+
+    drop_copy.disconnect().unwrap();
+
+    assert!(!drop_copy.is_logged_on());
+    assert_eq!(state.received("5").len(), 1);
+}
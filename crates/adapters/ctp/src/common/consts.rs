@@ -0,0 +1,71 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::sync::LazyLock;
+
+use ant_model::{
+    enums::{OrderType, TimeInForce},
+    identifiers::Venue,
+};
+use ustr::Ustr;
+
+pub const CTP: &str = "CTP";
+
+pub const SHFE: &str = "SHFE";
+pub const DCE: &str = "DCE";
+pub const CZCE: &str = "CZCE";
+pub const CFFEX: &str = "CFFEX";
+pub const INE: &str = "INE";
+pub const GFEX: &str = "GFEX";
+
+pub static SHFE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(SHFE)));
+pub static DCE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(DCE)));
+pub static CZCE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(CZCE)));
+pub static CFFEX_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(CFFEX)));
+pub static INE_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(INE)));
+pub static GFEX_VENUE: LazyLock<Venue> = LazyLock::new(|| Venue::new(Ustr::from(GFEX)));
+
+/// The China futures exchanges reachable through a CTP front.
+pub const CTP_SUPPORTED_EXCHANGES: &[&str] = &[SHFE, DCE, CZCE, CFFEX, INE, GFEX];
+
+/// CTP supported order time in force.
+///
+/// # Notes
+///
+/// - Limit orders rest for the trading day (`THOST_FTDC_TC_GFD`), which is mapped from `Day` and `Gtc`.
+/// - IOC and FOK are expressed through the time and volume conditions of the input order.
+pub const CTP_SUPPORTED_TIME_IN_FORCE: &[TimeInForce] = &[
+    TimeInForce::Day,
+    TimeInForce::Gtc, // Mapped to good-for-day, CTP orders never survive the trading day
+    TimeInForce::Ioc,
+    TimeInForce::Fok,
+];
+
+/// CTP supported order types.
+///
+/// # Notes
+///
+/// - Market orders are only accepted by CFFEX and DCE/CZCE for some products, the exchange
+///   rejects them otherwise.
+pub const CTP_SUPPORTED_ORDER_TYPES: &[OrderType] = &[OrderType::Market, OrderType::Limit];
+
+/// The UTC offset of exchange local time (China Standard Time has no daylight saving).
+pub const CTP_UTC_OFFSET_SECS: i32 = 8 * 3600;
+
+/// The `ErrorID` reported by CTP on success.
+pub const CTP_ERROR_ID_SUCCESS: i32 = 0;
+
+/// The price CTP reports when a field has no value (`DBL_MAX`).
+pub const CTP_INVALID_PRICE: f64 = f64::MAX;
@@ -0,0 +1,595 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Enumerations mapping CTP single character codes to ant types.
+
+use std::str::FromStr;
+
+use ant_model::{
+    enums::{OptionKind, OrderSide, OrderStatus, OrderType, PositionSide, TimeInForce},
+    identifiers::Venue,
+};
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, EnumString};
+
+use super::consts::{CFFEX_VENUE, CZCE_VENUE, DCE_VENUE, GFEX_VENUE, INE_VENUE, SHFE_VENUE};
+
+/// Represents the China futures exchanges reachable through CTP.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum CtpExchange {
+    /// Shanghai Futures Exchange.
+    Shfe,
+    /// Dalian Commodity Exchange.
+    Dce,
+    /// Zhengzhou Commodity Exchange.
+    Czce,
+    /// China Financial Futures Exchange.
+    Cffex,
+    /// Shanghai International Energy Exchange.
+    Ine,
+    /// Guangzhou Futures Exchange.
+    Gfex,
+}
+
+impl CtpExchange {
+    /// Returns the ant [`Venue`] for the exchange.
+    #[must_use]
+    pub fn venue(&self) -> Venue {
+        match self {
+            Self::Shfe => *SHFE_VENUE,
+            Self::Dce => *DCE_VENUE,
+            Self::Czce => *CZCE_VENUE,
+            Self::Cffex => *CFFEX_VENUE,
+            Self::Ine => *INE_VENUE,
+            Self::Gfex => *GFEX_VENUE,
+        }
+    }
+
+    /// Returns whether the exchange distinguishes close-today from close-yesterday.
+    ///
+    /// SHFE and INE require closing orders to specify which position bucket is being closed,
+    /// a generic close is treated as close-yesterday and rejected if there is no yesterday position.
+    #[must_use]
+    pub const fn requires_close_today(&self) -> bool {
+        matches!(self, Self::Shfe | Self::Ine)
+    }
+}
+
+impl TryFrom<Venue> for CtpExchange {
+    type Error = anyhow::Error;
+
+    fn try_from(venue: Venue) -> Result<Self, Self::Error> {
+        Self::from_str(venue.as_str())
+            .map_err(|_| anyhow::anyhow!("Venue {venue} is not a CTP exchange"))
+    }
+}
+
+/// Represents the direction of a CTP order or trade (`TThostFtdcDirectionType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpDirection {
+    /// Buy.
+    #[serde(rename = "0")]
+    Buy,
+    /// Sell.
+    #[serde(rename = "1")]
+    Sell,
+}
+
+impl From<OrderSide> for CtpDirection {
+    fn from(value: OrderSide) -> Self {
+        match value {
+            OrderSide::Buy => Self::Buy,
+            OrderSide::Sell => Self::Sell,
+            _ => panic!("Invalid `OrderSide`"),
+        }
+    }
+}
+
+impl From<CtpDirection> for OrderSide {
+    fn from(value: CtpDirection) -> Self {
+        match value {
+            CtpDirection::Buy => Self::Buy,
+            CtpDirection::Sell => Self::Sell,
+        }
+    }
+}
+
+/// Represents the open/close offset of a CTP order (`TThostFtdcOffsetFlagType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpOffsetFlag {
+    /// Open a new position.
+    #[serde(rename = "0")]
+    Open,
+    /// Close an existing position (close-yesterday on SHFE/INE).
+    #[serde(rename = "1")]
+    Close,
+    /// Forced close by the broker.
+    #[serde(rename = "2")]
+    ForceClose,
+    /// Close a position opened during the current trading day.
+    #[serde(rename = "3")]
+    CloseToday,
+    /// Close a position carried over from a previous trading day.
+    #[serde(rename = "4")]
+    CloseYesterday,
+}
+
+impl CtpOffsetFlag {
+    /// Returns whether the offset flag reduces an existing position.
+    #[must_use]
+    pub const fn is_close(&self) -> bool {
+        !matches!(self, Self::Open)
+    }
+}
+
+/// Represents the status of a CTP order (`TThostFtdcOrderStatusType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpOrderStatus {
+    /// All volume traded.
+    #[serde(rename = "0")]
+    AllTraded,
+    /// Partially traded and still working at the exchange.
+    #[serde(rename = "1")]
+    PartTradedQueueing,
+    /// Partially traded and no longer working (remaining volume canceled).
+    #[serde(rename = "2")]
+    PartTradedNotQueueing,
+    /// No volume traded and working at the exchange.
+    #[serde(rename = "3")]
+    NoTradeQueueing,
+    /// No volume traded and no longer working.
+    #[serde(rename = "4")]
+    NoTradeNotQueueing,
+    /// Canceled.
+    #[serde(rename = "5")]
+    Canceled,
+    /// Accepted by the CTP front but not yet acknowledged by the exchange.
+    #[serde(rename = "a")]
+    Unknown,
+    /// Conditional order not yet triggered.
+    #[serde(rename = "b")]
+    NotTouched,
+    /// Conditional order triggered.
+    #[serde(rename = "c")]
+    Touched,
+}
+
+impl From<CtpOrderStatus> for OrderStatus {
+    fn from(value: CtpOrderStatus) -> Self {
+        match value {
+            CtpOrderStatus::AllTraded => Self::Filled,
+            CtpOrderStatus::PartTradedQueueing => Self::PartiallyFilled,
+            CtpOrderStatus::PartTradedNotQueueing
+            | CtpOrderStatus::NoTradeNotQueueing
+            | CtpOrderStatus::Canceled => Self::Canceled,
+            CtpOrderStatus::NoTradeQueueing | CtpOrderStatus::NotTouched => Self::Accepted,
+            CtpOrderStatus::Unknown => Self::Submitted,
+            CtpOrderStatus::Touched => Self::Triggered,
+        }
+    }
+}
+
+/// Represents the product class of a CTP instrument (`TThostFtdcProductClassType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpProductClass {
+    /// Futures.
+    #[serde(rename = "1")]
+    Futures,
+    /// Options on futures.
+    #[serde(rename = "2")]
+    Options,
+    /// Combination (spread).
+    #[serde(rename = "3")]
+    Combination,
+    /// Spot.
+    #[serde(rename = "4")]
+    Spot,
+    /// Exchange for physicals.
+    #[serde(rename = "5")]
+    Efp,
+    /// Options on spot (index options).
+    #[serde(rename = "6")]
+    SpotOption,
+}
+
+/// Represents the kind of a CTP option (`TThostFtdcOptionsTypeType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpOptionsType {
+    /// Call option.
+    #[serde(rename = "1")]
+    Call,
+    /// Put option.
+    #[serde(rename = "2")]
+    Put,
+}
+
+impl From<CtpOptionsType> for OptionKind {
+    fn from(value: CtpOptionsType) -> Self {
+        match value {
+            CtpOptionsType::Call => Self::Call,
+            CtpOptionsType::Put => Self::Put,
+        }
+    }
+}
+
+/// Represents the price type of a CTP order (`TThostFtdcOrderPriceTypeType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpOrderPriceType {
+    /// Any price (market).
+    #[serde(rename = "1")]
+    AnyPrice,
+    /// Limit price.
+    #[serde(rename = "2")]
+    LimitPrice,
+    /// Best price.
+    #[serde(rename = "3")]
+    BestPrice,
+    /// Last price.
+    #[serde(rename = "4")]
+    LastPrice,
+}
+
+impl From<CtpOrderPriceType> for OrderType {
+    fn from(value: CtpOrderPriceType) -> Self {
+        match value {
+            CtpOrderPriceType::AnyPrice | CtpOrderPriceType::BestPrice => Self::Market,
+            CtpOrderPriceType::LimitPrice | CtpOrderPriceType::LastPrice => Self::Limit,
+        }
+    }
+}
+
+/// Represents the time condition of a CTP order (`TThostFtdcTimeConditionType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpTimeCondition {
+    /// Immediate or cancel.
+    #[serde(rename = "1")]
+    Ioc,
+    /// Good for session.
+    #[serde(rename = "2")]
+    Gfs,
+    /// Good for day.
+    #[serde(rename = "3")]
+    Gfd,
+    /// Good till date.
+    #[serde(rename = "4")]
+    Gtd,
+    /// Good till canceled.
+    #[serde(rename = "5")]
+    Gtc,
+    /// Good for auction.
+    #[serde(rename = "6")]
+    Gfa,
+}
+
+/// Represents the volume condition of a CTP order (`TThostFtdcVolumeConditionType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpVolumeCondition {
+    /// Any volume.
+    #[serde(rename = "1")]
+    AnyVolume,
+    /// Minimum volume.
+    #[serde(rename = "2")]
+    MinVolume,
+    /// Complete volume (fill or kill).
+    #[serde(rename = "3")]
+    CompleteVolume,
+}
+
+/// Returns the CTP time and volume conditions for the given `time_in_force`.
+///
+/// # Errors
+///
+/// Returns an error if the time in force is not supported by CTP.
+pub fn ctp_conditions_from_time_in_force(
+    time_in_force: TimeInForce,
+) -> anyhow::Result<(CtpTimeCondition, CtpVolumeCondition)> {
+    match time_in_force {
+        TimeInForce::Day | TimeInForce::Gtc => {
+            Ok((CtpTimeCondition::Gfd, CtpVolumeCondition::AnyVolume))
+        }
+        TimeInForce::Ioc => Ok((CtpTimeCondition::Ioc, CtpVolumeCondition::AnyVolume)),
+        TimeInForce::Fok => Ok((CtpTimeCondition::Ioc, CtpVolumeCondition::CompleteVolume)),
+        _ => anyhow::bail!("Unsupported time in force for CTP: {time_in_force}"),
+    }
+}
+
+/// Returns the ant [`TimeInForce`] for the given CTP time and volume conditions.
+#[must_use]
+pub fn parse_time_in_force(
+    time_condition: CtpTimeCondition,
+    volume_condition: CtpVolumeCondition,
+) -> TimeInForce {
+    match (time_condition, volume_condition) {
+        (CtpTimeCondition::Ioc, CtpVolumeCondition::CompleteVolume) => TimeInForce::Fok,
+        (CtpTimeCondition::Ioc, _) => TimeInForce::Ioc,
+        (CtpTimeCondition::Gtd, _) => TimeInForce::Gtd,
+        (CtpTimeCondition::Gtc, _) => TimeInForce::Gtc,
+        (CtpTimeCondition::Gfa, _) => TimeInForce::AtTheOpen,
+        (CtpTimeCondition::Gfs | CtpTimeCondition::Gfd, _) => TimeInForce::Day,
+    }
+}
+
+/// Represents the direction of a CTP investor position (`TThostFtdcPosiDirectionType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpPosiDirection {
+    /// Net position.
+    #[serde(rename = "1")]
+    Net,
+    /// Long position.
+    #[serde(rename = "2")]
+    Long,
+    /// Short position.
+    #[serde(rename = "3")]
+    Short,
+}
+
+impl From<CtpPosiDirection> for PositionSide {
+    fn from(value: CtpPosiDirection) -> Self {
+        match value {
+            CtpPosiDirection::Net => Self::Flat,
+            CtpPosiDirection::Long => Self::Long,
+            CtpPosiDirection::Short => Self::Short,
+        }
+    }
+}
+
+/// Represents the hedge flag of a CTP order (`TThostFtdcHedgeFlagType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpHedgeFlag {
+    /// Speculation.
+    #[serde(rename = "1")]
+    Speculation,
+    /// Arbitrage.
+    #[serde(rename = "2")]
+    Arbitrage,
+    /// Hedge.
+    #[serde(rename = "3")]
+    Hedge,
+    /// Market maker.
+    #[serde(rename = "5")]
+    MarketMaker,
+}
+
+/// Represents the action flag of a CTP order action (`TThostFtdcActionFlagType`).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Serialize,
+    Deserialize,
+)]
+pub enum CtpActionFlag {
+    /// Delete (cancel) the order.
+    #[serde(rename = "0")]
+    Delete,
+    /// Modify the order.
+    #[serde(rename = "3")]
+    Modify,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(CtpOffsetFlag::Open, "\"0\"")]
+    #[case(CtpOffsetFlag::Close, "\"1\"")]
+    #[case(CtpOffsetFlag::CloseToday, "\"3\"")]
+    #[case(CtpOffsetFlag::CloseYesterday, "\"4\"")]
+    fn test_offset_flag_serde_round_trip(#[case] flag: CtpOffsetFlag, #[case] json: &str) {
+        assert_eq!(serde_json::to_string(&flag).unwrap(), json);
+        assert_eq!(serde_json::from_str::<CtpOffsetFlag>(json).unwrap(), flag);
+    }
+
+    #[rstest]
+    #[case(CtpOrderStatus::AllTraded, OrderStatus::Filled)]
+    #[case(CtpOrderStatus::PartTradedQueueing, OrderStatus::PartiallyFilled)]
+    #[case(CtpOrderStatus::PartTradedNotQueueing, OrderStatus::Canceled)]
+    #[case(CtpOrderStatus::NoTradeQueueing, OrderStatus::Accepted)]
+    #[case(CtpOrderStatus::Canceled, OrderStatus::Canceled)]
+    #[case(CtpOrderStatus::Unknown, OrderStatus::Submitted)]
+    fn test_order_status_conversion(#[case] status: CtpOrderStatus, #[case] expected: OrderStatus) {
+        assert_eq!(OrderStatus::from(status), expected);
+    }
+
+    #[rstest]
+    #[case(CtpExchange::Shfe, true)]
+    #[case(CtpExchange::Ine, true)]
+    #[case(CtpExchange::Dce, false)]
+    #[case(CtpExchange::Czce, false)]
+    #[case(CtpExchange::Cffex, false)]
+    #[case(CtpExchange::Gfex, false)]
+    fn test_requires_close_today(#[case] exchange: CtpExchange, #[case] expected: bool) {
+        assert_eq!(exchange.requires_close_today(), expected);
+    }
+
+    #[rstest]
+    fn test_exchange_from_venue() {
+        let exchange = CtpExchange::try_from(Venue::from("SHFE")).unwrap();
+        assert_eq!(exchange, CtpExchange::Shfe);
+        assert_eq!(exchange.venue(), *SHFE_VENUE);
+        assert!(CtpExchange::try_from(Venue::from("XNAS")).is_err());
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Day, TimeInForce::Day)]
+    #[case(TimeInForce::Gtc, TimeInForce::Day)]
+    #[case(TimeInForce::Ioc, TimeInForce::Ioc)]
+    #[case(TimeInForce::Fok, TimeInForce::Fok)]
+    fn test_time_in_force_round_trip(#[case] input: TimeInForce, #[case] expected: TimeInForce) {
+        let (time_condition, volume_condition) = ctp_conditions_from_time_in_force(input).unwrap();
+        assert_eq!(
+            parse_time_in_force(time_condition, volume_condition),
+            expected
+        );
+    }
+}
@@ -0,0 +1,32 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Common functionality shared across the CTP adapter.
+//!
+//! This module provides core utilities, constants, and data structures used throughout
+//! the CTP integration, including:
+//!
+//! - Venue constants for the supported China futures exchanges.
+//! - CTP enumerations (which are single character codes on the wire).
+//! - Parsing utilities for converting CTP fields to ant types.
+//! - Offset flag resolution for the SHFE/INE close-today and close-yesterday rules.
+
+pub mod consts;
+pub mod enums;
+pub mod offset;
+pub mod parse;
+
+#[cfg(test)]
+pub(crate) mod testing;
@@ -0,0 +1,470 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Offset flag resolution for CTP orders.
+//!
+//! CTP accounts hold long and short positions separately, and every order must state whether it
+//! opens or closes a position. SHFE and INE further require closing orders to specify whether
+//! they close today's position or yesterday's position. The [`CtpPositionLedger`] tracks the
+//! closable volume per bucket and splits an ant order into the CTP legs required to express it.
+
+use ahash::AHashMap;
+use ant_model::enums::OrderSide;
+use ustr::Ustr;
+
+use super::enums::{CtpDirection, CtpExchange, CtpOffsetFlag, CtpPosiDirection};
+use crate::front::models::CtpInvestorPositionField;
+
+/// A single CTP order leg resolved from an ant order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OffsetLeg {
+    /// The offset flag for the leg.
+    pub offset: CtpOffsetFlag,
+    /// The volume of the leg.
+    pub volume: u32,
+}
+
+impl OffsetLeg {
+    /// Creates a new [`OffsetLeg`] instance.
+    #[must_use]
+    pub const fn new(offset: CtpOffsetFlag, volume: u32) -> Self {
+        Self { offset, volume }
+    }
+}
+
+/// The closable volume of a single position direction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PositionBucket {
+    /// The volume opened during the current trading day.
+    pub today: u32,
+    /// The volume carried over from previous trading days.
+    pub yesterday: u32,
+    /// The today volume reserved by working closing orders.
+    pub today_frozen: u32,
+    /// The yesterday volume reserved by working closing orders.
+    pub yesterday_frozen: u32,
+}
+
+impl PositionBucket {
+    /// Returns the today volume available to close.
+    #[must_use]
+    pub const fn today_available(&self) -> u32 {
+        self.today.saturating_sub(self.today_frozen)
+    }
+
+    /// Returns the yesterday volume available to close.
+    #[must_use]
+    pub const fn yesterday_available(&self) -> u32 {
+        self.yesterday.saturating_sub(self.yesterday_frozen)
+    }
+
+    /// Returns the total position volume.
+    #[must_use]
+    pub const fn total(&self) -> u32 {
+        self.today + self.yesterday
+    }
+}
+
+/// Tracks today and yesterday positions per instrument and direction.
+#[derive(Clone, Debug, Default)]
+pub struct CtpPositionLedger {
+    long: AHashMap<Ustr, PositionBucket>,
+    short: AHashMap<Ustr, PositionBucket>,
+}
+
+impl CtpPositionLedger {
+    /// Creates a new empty [`CtpPositionLedger`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears all positions, typically before replaying a position query.
+    pub fn clear(&mut self) {
+        self.long.clear();
+        self.short.clear();
+    }
+
+    /// Returns the position bucket for the given instrument and direction.
+    #[must_use]
+    pub fn bucket(&self, instrument_id: &Ustr, direction: CtpPosiDirection) -> PositionBucket {
+        let positions = match direction {
+            CtpPosiDirection::Long => &self.long,
+            CtpPosiDirection::Short => &self.short,
+            CtpPosiDirection::Net => return PositionBucket::default(),
+        };
+        positions.get(instrument_id).copied().unwrap_or_default()
+    }
+
+    /// Applies a row of a position query.
+    ///
+    /// SHFE and INE report today and yesterday positions as separate rows, other exchanges
+    /// report a single row, so rows are accumulated rather than replacing the bucket.
+    pub fn apply_position(&mut self, position: &CtpInvestorPositionField) {
+        let Some(bucket) = self.bucket_mut(position.instrument_id, position.posi_direction) else {
+            return;
+        };
+        bucket.today += position.today_position.max(0) as u32;
+        bucket.yesterday += position.remaining_yd_position().max(0) as u32;
+    }
+
+    /// Applies a trade to the ledger, releasing any volume frozen by the order.
+    pub fn apply_trade(
+        &mut self,
+        instrument_id: Ustr,
+        direction: CtpDirection,
+        offset: CtpOffsetFlag,
+        volume: u32,
+    ) {
+        if offset == CtpOffsetFlag::Open {
+            if let Some(bucket) = self.bucket_mut(instrument_id, opened_direction(direction)) {
+                bucket.today += volume;
+            }
+            return;
+        }
+
+        self.release(instrument_id, direction, offset, volume);
+
+        let Some(bucket) = self.bucket_mut(instrument_id, closed_direction(direction)) else {
+            return;
+        };
+        match offset {
+            CtpOffsetFlag::CloseToday => bucket.today = bucket.today.saturating_sub(volume),
+            CtpOffsetFlag::CloseYesterday => {
+                bucket.yesterday = bucket.yesterday.saturating_sub(volume);
+            }
+            _ => {
+                // Generic closes consume yesterday's position first
+                let from_yesterday = volume.min(bucket.yesterday);
+                bucket.yesterday -= from_yesterday;
+                bucket.today = bucket.today.saturating_sub(volume - from_yesterday);
+            }
+        }
+    }
+
+    /// Reserves the volume of a working closing leg so it is not closed twice.
+    pub fn freeze(&mut self, instrument_id: Ustr, direction: CtpDirection, leg: OffsetLeg) {
+        if !leg.offset.is_close() {
+            return;
+        }
+        let Some(bucket) = self.bucket_mut(instrument_id, closed_direction(direction)) else {
+            return;
+        };
+        match leg.offset {
+            CtpOffsetFlag::CloseToday => bucket.today_frozen += leg.volume,
+            CtpOffsetFlag::CloseYesterday => bucket.yesterday_frozen += leg.volume,
+            _ => {
+                let from_yesterday = leg.volume.min(bucket.yesterday_available());
+                bucket.yesterday_frozen += from_yesterday;
+                bucket.today_frozen += leg.volume - from_yesterday;
+            }
+        }
+    }
+
+    /// Releases volume reserved by a closing leg (on fill, cancel or reject).
+    pub fn release(
+        &mut self,
+        instrument_id: Ustr,
+        direction: CtpDirection,
+        offset: CtpOffsetFlag,
+        volume: u32,
+    ) {
+        if !offset.is_close() {
+            return;
+        }
+        let Some(bucket) = self.bucket_mut(instrument_id, closed_direction(direction)) else {
+            return;
+        };
+        match offset {
+            CtpOffsetFlag::CloseToday => {
+                bucket.today_frozen = bucket.today_frozen.saturating_sub(volume);
+            }
+            CtpOffsetFlag::CloseYesterday => {
+                bucket.yesterday_frozen = bucket.yesterday_frozen.saturating_sub(volume);
+            }
+            _ => {
+                let from_yesterday = volume.min(bucket.yesterday_frozen);
+                bucket.yesterday_frozen -= from_yesterday;
+                bucket.today_frozen = bucket.today_frozen.saturating_sub(volume - from_yesterday);
+            }
+        }
+    }
+
+    /// Resolves the CTP legs for an order of `quantity` on `side`.
+    ///
+    /// The order first closes any opposite position, with the remainder opening a new position.
+    /// On SHFE and INE yesterday's position is closed before today's position, as close-today
+    /// is typically charged a higher commission. When `reduce_only` is set no opening leg is
+    /// produced and any volume which cannot be closed is dropped.
+    #[must_use]
+    pub fn resolve(
+        &self,
+        exchange: CtpExchange,
+        instrument_id: &Ustr,
+        side: OrderSide,
+        quantity: u32,
+        reduce_only: bool,
+    ) -> Vec<OffsetLeg> {
+        let direction = CtpDirection::from(side);
+        let bucket = self.bucket(instrument_id, closed_direction(direction));
+        let mut legs = Vec::with_capacity(2);
+        let mut remaining = quantity;
+
+        if exchange.requires_close_today() {
+            let yesterday = remaining.min(bucket.yesterday_available());
+            if yesterday > 0 {
+                legs.push(OffsetLeg::new(CtpOffsetFlag::CloseYesterday, yesterday));
+                remaining -= yesterday;
+            }
+            let today = remaining.min(bucket.today_available());
+            if today > 0 {
+                legs.push(OffsetLeg::new(CtpOffsetFlag::CloseToday, today));
+                remaining -= today;
+            }
+        } else {
+            let available = bucket.yesterday_available() + bucket.today_available();
+            let close = remaining.min(available);
+            if close > 0 {
+                legs.push(OffsetLeg::new(CtpOffsetFlag::Close, close));
+                remaining -= close;
+            }
+        }
+
+        if remaining > 0 && !reduce_only {
+            legs.push(OffsetLeg::new(CtpOffsetFlag::Open, remaining));
+        }
+
+        legs
+    }
+
+    fn bucket_mut(
+        &mut self,
+        instrument_id: Ustr,
+        direction: CtpPosiDirection,
+    ) -> Option<&mut PositionBucket> {
+        match direction {
+            CtpPosiDirection::Long => Some(self.long.entry(instrument_id).or_default()),
+            CtpPosiDirection::Short => Some(self.short.entry(instrument_id).or_default()),
+            CtpPosiDirection::Net => None,
+        }
+    }
+}
+
+/// Returns the position direction opened by an order of the given direction.
+const fn opened_direction(direction: CtpDirection) -> CtpPosiDirection {
+    match direction {
+        CtpDirection::Buy => CtpPosiDirection::Long,
+        CtpDirection::Sell => CtpPosiDirection::Short,
+    }
+}
+
+/// Returns the position direction closed by an order of the given direction.
+const fn closed_direction(direction: CtpDirection) -> CtpPosiDirection {
+    match direction {
+        CtpDirection::Buy => CtpPosiDirection::Short,
+        CtpDirection::Sell => CtpPosiDirection::Long,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn position(
+        instrument_id: &str,
+        exchange: CtpExchange,
+        posi_direction: CtpPosiDirection,
+        position: i32,
+        today_position: i32,
+    ) -> CtpInvestorPositionField {
+        CtpInvestorPositionField {
+            instrument_id: Ustr::from(instrument_id),
+            exchange_id: exchange,
+            posi_direction,
+            position,
+            today_position,
+            yd_position: position - today_position,
+        }
+    }
+
+    #[fixture]
+    fn shfe_ledger() -> CtpPositionLedger {
+        // SHFE reports today and yesterday positions as separate rows
+        let mut ledger = CtpPositionLedger::new();
+        ledger.apply_position(&position(
+            "rb2501",
+            CtpExchange::Shfe,
+            CtpPosiDirection::Long,
+            3,
+            0,
+        ));
+        ledger.apply_position(&position(
+            "rb2501",
+            CtpExchange::Shfe,
+            CtpPosiDirection::Long,
+            2,
+            2,
+        ));
+        ledger
+    }
+
+    #[rstest]
+    fn test_apply_position_accumulates_rows(shfe_ledger: CtpPositionLedger) {
+        let bucket = shfe_ledger.bucket(&Ustr::from("rb2501"), CtpPosiDirection::Long);
+        assert_eq!(bucket.yesterday, 3);
+        assert_eq!(bucket.today, 2);
+        assert_eq!(bucket.total(), 5);
+    }
+
+    #[rstest]
+    fn test_resolve_open_when_flat() {
+        let ledger = CtpPositionLedger::new();
+        let legs = ledger.resolve(
+            CtpExchange::Shfe,
+            &Ustr::from("rb2501"),
+            OrderSide::Buy,
+            4,
+            false,
+        );
+        assert_eq!(legs, vec![OffsetLeg::new(CtpOffsetFlag::Open, 4)]);
+    }
+
+    #[rstest]
+    #[case(2, vec![OffsetLeg::new(CtpOffsetFlag::CloseYesterday, 2)])]
+    #[case(4, vec![
+        OffsetLeg::new(CtpOffsetFlag::CloseYesterday, 3),
+        OffsetLeg::new(CtpOffsetFlag::CloseToday, 1),
+    ])]
+    #[case(7, vec![
+        OffsetLeg::new(CtpOffsetFlag::CloseYesterday, 3),
+        OffsetLeg::new(CtpOffsetFlag::CloseToday, 2),
+        OffsetLeg::new(CtpOffsetFlag::Open, 2),
+    ])]
+    fn test_resolve_shfe_close_splits_today_and_yesterday(
+        shfe_ledger: CtpPositionLedger,
+        #[case] quantity: u32,
+        #[case] expected: Vec<OffsetLeg>,
+    ) {
+        let legs = shfe_ledger.resolve(
+            CtpExchange::Shfe,
+            &Ustr::from("rb2501"),
+            OrderSide::Sell,
+            quantity,
+            false,
+        );
+        assert_eq!(legs, expected);
+    }
+
+    #[rstest]
+    fn test_resolve_reduce_only_drops_opening_leg(shfe_ledger: CtpPositionLedger) {
+        let legs = shfe_ledger.resolve(
+            CtpExchange::Shfe,
+            &Ustr::from("rb2501"),
+            OrderSide::Sell,
+            7,
+            true,
+        );
+        assert_eq!(
+            legs,
+            vec![
+                OffsetLeg::new(CtpOffsetFlag::CloseYesterday, 3),
+                OffsetLeg::new(CtpOffsetFlag::CloseToday, 2),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_resolve_generic_close_on_other_exchanges() {
+        let mut ledger = CtpPositionLedger::new();
+        ledger.apply_position(&position(
+            "m2501",
+            CtpExchange::Dce,
+            CtpPosiDirection::Short,
+            5,
+            2,
+        ));
+
+        let legs = ledger.resolve(
+            CtpExchange::Dce,
+            &Ustr::from("m2501"),
+            OrderSide::Buy,
+            6,
+            false,
+        );
+
+        assert_eq!(
+            legs,
+            vec![
+                OffsetLeg::new(CtpOffsetFlag::Close, 5),
+                OffsetLeg::new(CtpOffsetFlag::Open, 1),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_frozen_volume_is_not_closed_twice(mut shfe_ledger: CtpPositionLedger) {
+        let instrument_id = Ustr::from("rb2501");
+        shfe_ledger.freeze(
+            instrument_id,
+            CtpDirection::Sell,
+            OffsetLeg::new(CtpOffsetFlag::CloseYesterday, 3),
+        );
+
+        let legs =
+            shfe_ledger.resolve(CtpExchange::Shfe, &instrument_id, OrderSide::Sell, 2, false);
+        assert_eq!(legs, vec![OffsetLeg::new(CtpOffsetFlag::CloseToday, 2)]);
+
+        shfe_ledger.release(
+            instrument_id,
+            CtpDirection::Sell,
+            CtpOffsetFlag::CloseYesterday,
+            3,
+        );
+        let legs =
+            shfe_ledger.resolve(CtpExchange::Shfe, &instrument_id, OrderSide::Sell, 2, false);
+        assert_eq!(legs, vec![OffsetLeg::new(CtpOffsetFlag::CloseYesterday, 2)]);
+    }
+
+    #[rstest]
+    fn test_apply_trade_updates_buckets(mut shfe_ledger: CtpPositionLedger) {
+        let instrument_id = Ustr::from("rb2501");
+        shfe_ledger.apply_trade(
+            instrument_id,
+            CtpDirection::Sell,
+            CtpOffsetFlag::CloseToday,
+            1,
+        );
+        shfe_ledger.apply_trade(
+            instrument_id,
+            CtpDirection::Sell,
+            CtpOffsetFlag::CloseYesterday,
+            2,
+        );
+        shfe_ledger.apply_trade(instrument_id, CtpDirection::Sell, CtpOffsetFlag::Open, 4);
+
+        let long = shfe_ledger.bucket(&instrument_id, CtpPosiDirection::Long);
+        assert_eq!(long.today, 1);
+        assert_eq!(long.yesterday, 1);
+
+        let short = shfe_ledger.bucket(&instrument_id, CtpPosiDirection::Short);
+        assert_eq!(short.today, 4);
+        assert_eq!(short.yesterday, 0);
+    }
+}
@@ -0,0 +1,728 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parsing functions for converting CTP fields to ant types.
+
+use std::str::FromStr;
+
+use ant_core::{UnixNanos, datetime::NANOSECONDS_IN_MILLISECOND};
+use ant_model::{
+    data::{QuoteTick, TradeTick},
+    enums::{AggressorSide, AssetClass, LiquiditySide, OrderStatus},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, Symbol, TradeId, VenueOrderId},
+    instruments::{FuturesContract, Instrument, InstrumentEnum, OptionContract},
+    reports::{FillReport, OrderStatusReport},
+    types::{Currency, Money, Price, Quantity},
+};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use rust_decimal::{Decimal, prelude::FromPrimitive};
+use ustr::Ustr;
+
+use super::{
+    consts::{CTP_INVALID_PRICE, CTP_UTC_OFFSET_SECS},
+    enums::{CtpExchange, CtpProductClass, parse_time_in_force},
+};
+use crate::front::models::{
+    CtpDepthMarketDataField, CtpInstrumentField, CtpOrderField, CtpTradeField,
+};
+
+/// The hour of exchange local time after which updates belong to the night session.
+const NIGHT_SESSION_START_HOUR: u32 = 18;
+
+/// The hour of exchange local time before which updates belong to the night session
+/// continuing past midnight.
+const NIGHT_SESSION_END_HOUR: u32 = 3;
+
+/// Returns the ant [`InstrumentId`] for the given CTP instrument code and exchange.
+#[must_use]
+pub fn parse_instrument_id(instrument_id: Ustr, exchange: CtpExchange) -> InstrumentId {
+    InstrumentId::new(Symbol::from_ustr_unchecked(instrument_id), exchange.venue())
+}
+
+/// Returns whether a CTP price field holds a value (CTP uses `DBL_MAX` for no value).
+#[must_use]
+pub fn is_valid_price(value: f64) -> bool {
+    value.is_finite() && value != CTP_INVALID_PRICE
+}
+
+/// Parses a CTP date (YYYYMMDD).
+///
+/// # Errors
+///
+/// Returns an error if `date` is not a valid YYYYMMDD date.
+pub fn parse_ctp_date(date: &str) -> anyhow::Result<NaiveDate> {
+    NaiveDate::parse_from_str(date.trim(), "%Y%m%d")
+        .map_err(|e| anyhow::anyhow!("Invalid CTP date '{date}': {e}"))
+}
+
+/// Parses a CTP local date (YYYYMMDD), time (HH:MM:SS) and millisecond into UNIX nanoseconds.
+///
+/// # Errors
+///
+/// Returns an error if the date or time cannot be parsed.
+pub fn parse_ctp_timestamp(date: &str, time: &str, millisec: i32) -> anyhow::Result<UnixNanos> {
+    let date = parse_ctp_date(date)?;
+    let time = NaiveTime::parse_from_str(time.trim(), "%H:%M:%S")
+        .map_err(|e| anyhow::anyhow!("Invalid CTP time '{time}': {e}"))?;
+    Ok(local_to_unix_nanos(
+        NaiveDateTime::new(date, time),
+        millisec,
+    ))
+}
+
+/// Parses a CTP timestamp stamped with the trading day rather than the calendar day.
+///
+/// Night session updates from some exchanges (notably DCE) carry the trading day, which is the
+/// next business day, in `ActionDay`/`TradeDate`. When the given `date` equals the `trading_day`
+/// the calendar day is recovered from the session hour. Exchange holidays are not considered,
+/// so a night session before a holiday resolves to the previous weekday.
+///
+/// # Errors
+///
+/// Returns an error if the dates or time cannot be parsed.
+pub fn parse_session_timestamp(
+    date: &str,
+    trading_day: &str,
+    time: &str,
+    millisec: i32,
+) -> anyhow::Result<UnixNanos> {
+    let date = if date.trim().is_empty() {
+        trading_day
+    } else {
+        date
+    };
+
+    let mut calendar_day = parse_ctp_date(date)?;
+    let time = NaiveTime::parse_from_str(time.trim(), "%H:%M:%S")
+        .map_err(|e| anyhow::anyhow!("Invalid CTP time '{time}': {e}"))?;
+
+    if date.trim() == trading_day.trim() {
+        let hour = time.hour();
+        if hour >= NIGHT_SESSION_START_HOUR {
+            calendar_day = previous_weekday(calendar_day);
+        } else if hour < NIGHT_SESSION_END_HOUR && calendar_day.weekday() == Weekday::Mon {
+            // Friday's night session continues into Saturday
+            calendar_day -= Duration::days(2);
+        }
+    }
+
+    Ok(local_to_unix_nanos(
+        NaiveDateTime::new(calendar_day, time),
+        millisec,
+    ))
+}
+
+fn previous_weekday(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Mon => date - Duration::days(3),
+        Weekday::Sun => date - Duration::days(2),
+        _ => date - Duration::days(1),
+    }
+}
+
+fn local_to_unix_nanos(local: NaiveDateTime, millisec: i32) -> UnixNanos {
+    let utc = local - Duration::seconds(i64::from(CTP_UTC_OFFSET_SECS));
+    let nanos = utc.and_utc().timestamp_nanos_opt().unwrap_or_default() as u64;
+    UnixNanos::from(nanos + millisec.max(0) as u64 * NANOSECONDS_IN_MILLISECOND)
+}
+
+fn parse_price_increment(price_tick: f64) -> anyhow::Result<Price> {
+    if !is_valid_price(price_tick) || price_tick <= 0.0 {
+        anyhow::bail!("Invalid CTP price tick {price_tick}");
+    }
+    // Shortest round trip formatting preserves the exchange tick precision, e.g. 0.2 or 0.0001
+    Price::from_str(&price_tick.to_string()).map_err(|e| anyhow::anyhow!(e))
+}
+
+fn parse_asset_class(field: &CtpInstrumentField) -> AssetClass {
+    match field.exchange_id {
+        // CFFEX lists treasury bond futures (T, TF, TS, TL) and equity index futures/options
+        CtpExchange::Cffex if field.product_id.as_str().starts_with('T') => AssetClass::Debt,
+        CtpExchange::Cffex => AssetClass::Index,
+        _ => AssetClass::Commodity,
+    }
+}
+
+/// Parses a CTP instrument definition into an ant instrument.
+///
+/// Returns `Ok(None)` for product classes which are not supported (combinations, spot and EFP).
+///
+/// # Errors
+///
+/// Returns an error if the instrument definition is invalid.
+pub fn parse_instrument(
+    field: &CtpInstrumentField,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<InstrumentEnum>> {
+    let instrument_id = parse_instrument_id(field.instrument_id, field.exchange_id);
+    let raw_symbol = Symbol::from_ustr_unchecked(field.instrument_id);
+    let asset_class = parse_asset_class(field);
+    let exchange = Some(Ustr::from(field.exchange_id.as_ref()));
+    let currency = Currency::CNY();
+    let activation_ns = parse_ctp_timestamp(&field.open_date, "00:00:00", 0)?;
+    let expiration_ns = parse_ctp_timestamp(&field.expire_date, "15:00:00", 0)?;
+    let price_increment = parse_price_increment(field.price_tick)?;
+    let multiplier = Quantity::from(field.volume_multiple);
+    let lot_size = Quantity::from(1);
+    let max_quantity =
+        (field.max_limit_order_volume > 0).then(|| Quantity::from(field.max_limit_order_volume));
+    let min_quantity =
+        (field.min_limit_order_volume > 0).then(|| Quantity::from(field.min_limit_order_volume));
+    let margin_ratio = field.long_margin_ratio.max(field.short_margin_ratio);
+    let margin = (is_valid_price(margin_ratio) && margin_ratio > 0.0)
+        .then(|| Decimal::from_f64(margin_ratio))
+        .flatten();
+
+    let instrument = match field.product_class {
+        CtpProductClass::Futures => InstrumentEnum::FuturesContract(FuturesContract::new_checked(
+            instrument_id,
+            raw_symbol,
+            asset_class,
+            exchange,
+            field.product_id,
+            activation_ns,
+            expiration_ns,
+            currency,
+            price_increment.precision,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            None,
+            None,
+            margin,
+            margin,
+            None,
+            None,
+            ts_init,
+            ts_init,
+        )?),
+        CtpProductClass::Options | CtpProductClass::SpotOption => {
+            let option_kind = field.options_type.ok_or_else(|| {
+                anyhow::anyhow!("Missing `OptionsType` for option {}", field.instrument_id)
+            })?;
+            let underlying = if field.underlying_instr_id.is_empty() {
+                field.product_id
+            } else {
+                field.underlying_instr_id
+            };
+            let strike_price = Price::new_checked(field.strike_price, price_increment.precision)?;
+
+            InstrumentEnum::OptionContract(OptionContract::new_checked(
+                instrument_id,
+                raw_symbol,
+                asset_class,
+                exchange,
+                underlying,
+                option_kind.into(),
+                strike_price,
+                currency,
+                activation_ns,
+                expiration_ns,
+                price_increment.precision,
+                price_increment,
+                multiplier,
+                lot_size,
+                max_quantity,
+                min_quantity,
+                None,
+                None,
+                margin,
+                margin,
+                None,
+                None,
+                ts_init,
+                ts_init,
+            )?)
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(instrument))
+}
+
+/// Parses the timestamp of a depth market data update.
+///
+/// # Errors
+///
+/// Returns an error if the update time cannot be parsed.
+pub fn parse_market_data_timestamp(md: &CtpDepthMarketDataField) -> anyhow::Result<UnixNanos> {
+    parse_session_timestamp(
+        &md.action_day,
+        &md.trading_day,
+        &md.update_time,
+        md.update_millisec,
+    )
+}
+
+/// Parses a CTP depth market data update into a [`QuoteTick`].
+///
+/// Returns `Ok(None)` when either side of the book is empty, as happens when an instrument is
+/// trading at its daily price limit.
+///
+/// # Errors
+///
+/// Returns an error if the update cannot be parsed.
+pub fn parse_quote_tick(
+    md: &CtpDepthMarketDataField,
+    instrument: &InstrumentEnum,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<QuoteTick>> {
+    if !is_valid_price(md.bid_price1)
+        || !is_valid_price(md.ask_price1)
+        || md.bid_volume1 <= 0
+        || md.ask_volume1 <= 0
+    {
+        return Ok(None);
+    }
+
+    let ts_event = parse_market_data_timestamp(md)?;
+    let quote = QuoteTick::new_checked(
+        instrument.id(),
+        instrument.make_price(md.bid_price1),
+        instrument.make_price(md.ask_price1),
+        Quantity::new(f64::from(md.bid_volume1), instrument.size_precision()),
+        Quantity::new(f64::from(md.ask_volume1), instrument.size_precision()),
+        ts_event,
+        ts_init,
+    )?;
+
+    Ok(Some(quote))
+}
+
+/// Derives a [`TradeTick`] from the change in cumulative volume between depth updates.
+///
+/// CTP does not publish individual trades, so the volume traded since the previous snapshot is
+/// reported as a single trade at the last price with no aggressor side. Returns `None` for the
+/// first snapshot of an instrument (no `last_volume`) or when no volume traded.
+///
+/// # Errors
+///
+/// Returns an error if the update cannot be parsed.
+pub fn parse_trade_tick(
+    md: &CtpDepthMarketDataField,
+    instrument: &InstrumentEnum,
+    last_volume: Option<i64>,
+    ts_init: UnixNanos,
+) -> anyhow::Result<Option<TradeTick>> {
+    let Some(last_volume) = last_volume else {
+        return Ok(None);
+    };
+
+    let delta = md.volume - last_volume;
+    if delta <= 0 || !is_valid_price(md.last_price) {
+        return Ok(None);
+    }
+
+    let ts_event = parse_market_data_timestamp(md)?;
+    let trade_id = TradeId::new(format!("{}-{}", md.trading_day.trim(), md.volume));
+    let trade = TradeTick::new_checked(
+        instrument.id(),
+        instrument.make_price(md.last_price),
+        Quantity::new(delta as f64, instrument.size_precision()),
+        AggressorSide::NoAggressor,
+        trade_id,
+        ts_event,
+        ts_init,
+    )?;
+
+    Ok(Some(trade))
+}
+
+/// Parses a CTP order into an [`OrderStatusReport`].
+///
+/// The session scoped order key is used as the venue order ID, as the exchange `OrderSysID` is
+/// only assigned once the exchange accepts the order.
+///
+/// # Errors
+///
+/// Returns an error if the order cannot be parsed.
+pub fn parse_order_status_report(
+    order: &CtpOrderField,
+    account_id: AccountId,
+    instrument: &InstrumentEnum,
+    client_order_id: Option<ClientOrderId>,
+    ts_init: UnixNanos,
+) -> anyhow::Result<OrderStatusReport> {
+    let order_status: OrderStatus = order.order_status.into();
+    let ts_accepted = parse_session_timestamp(
+        &order.insert_date,
+        &order.trading_day,
+        &order.insert_time,
+        0,
+    )?;
+    let ts_last = if order.cancel_time.trim().is_empty() {
+        ts_accepted
+    } else {
+        parse_session_timestamp(
+            &order.insert_date,
+            &order.trading_day,
+            &order.cancel_time,
+            0,
+        )?
+    };
+
+    let mut report = OrderStatusReport::new(
+        account_id,
+        instrument.id(),
+        client_order_id,
+        VenueOrderId::new(order.order_key()),
+        order.direction.into(),
+        order.order_price_type.into(),
+        parse_time_in_force(order.time_condition, order.volume_condition),
+        order_status,
+        Quantity::new(
+            f64::from(order.volume_total_original),
+            instrument.size_precision(),
+        ),
+        Quantity::new(f64::from(order.volume_traded), instrument.size_precision()),
+        ts_accepted,
+        ts_last,
+        ts_init,
+        None,
+    );
+
+    if is_valid_price(order.limit_price) && order.limit_price > 0.0 {
+        report = report.with_price(instrument.make_price(order.limit_price));
+    }
+    if order_status == OrderStatus::Canceled && !order.status_msg.is_empty() {
+        report = report.with_cancel_reason(order.status_msg.clone());
+    }
+    if order.comb_offset_flag.is_close() {
+        report = report.with_reduce_only(true);
+    }
+
+    Ok(report)
+}
+
+/// Returns the ant [`TradeId`] for a CTP trade.
+///
+/// CTP trade IDs are unique per exchange and direction, both sides of a self-trade share an ID.
+#[must_use]
+pub fn parse_trade_id(trade: &CtpTradeField) -> TradeId {
+    TradeId::new(format!("{}-{}", trade.trade_id.trim(), trade.direction))
+}
+
+/// Parses a CTP trade into a [`FillReport`].
+///
+/// CTP does not report commissions per trade, so the commission is zero.
+///
+/// # Errors
+///
+/// Returns an error if the trade cannot be parsed.
+pub fn parse_fill_report(
+    trade: &CtpTradeField,
+    account_id: AccountId,
+    instrument: &InstrumentEnum,
+    venue_order_id: VenueOrderId,
+    client_order_id: Option<ClientOrderId>,
+    ts_init: UnixNanos,
+) -> anyhow::Result<FillReport> {
+    let ts_event =
+        parse_session_timestamp(&trade.trade_date, &trade.trading_day, &trade.trade_time, 0)?;
+
+    Ok(FillReport::new(
+        account_id,
+        instrument.id(),
+        venue_order_id,
+        parse_trade_id(trade),
+        trade.direction.into(),
+        Quantity::new(f64::from(trade.volume), instrument.size_precision()),
+        instrument.make_price(trade.price),
+        Money::new(0.0, instrument.quote_currency()),
+        LiquiditySide::NoLiquiditySide,
+        client_order_id,
+        None,
+        ts_event,
+        ts_init,
+        None,
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ant_model::enums::{OptionKind, OrderSide, OrderType, TimeInForce};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        common::testing::load_test_json,
+        front::{CtpCallback, mock::MockCtpRecording},
+    };
+
+    #[fixture]
+    fn recording() -> MockCtpRecording {
+        serde_json::from_str(&load_test_json("recording_shfe_session.json")).unwrap()
+    }
+
+    fn instrument_field(recording: &MockCtpRecording, symbol: &str) -> CtpInstrumentField {
+        recording
+            .on_qry_instrument
+            .iter()
+            .find_map(|callback| match callback {
+                CtpCallback::RspQryInstrument { instrument, .. }
+                    if instrument.instrument_id == symbol =>
+                {
+                    Some(instrument.clone())
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    fn instrument(recording: &MockCtpRecording, symbol: &str) -> InstrumentEnum {
+        parse_instrument(&instrument_field(recording, symbol), UnixNanos::default())
+            .unwrap()
+            .unwrap()
+    }
+
+    fn market_data(recording: &MockCtpRecording) -> Vec<CtpDepthMarketDataField> {
+        recording
+            .on_subscribe
+            .iter()
+            .filter_map(|callback| match callback {
+                CtpCallback::RtnDepthMarketData(md) => Some(md.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_parse_ctp_timestamp() {
+        let ts = parse_ctp_timestamp("20250106", "09:00:00", 500).unwrap();
+        // 2025-01-06T01:00:00.500Z
+        assert_eq!(ts.as_u64(), 1_736_125_200_500_000_000);
+    }
+
+    #[rstest]
+    #[case("20250106", "20250106", "21:00:01", "20250103", "21:00:01")] // Friday night session
+    #[case("20250106", "20250106", "01:30:00", "20250104", "01:30:00")] // Past midnight into Saturday
+    #[case("20250107", "20250107", "21:00:01", "20250106", "21:00:01")] // Monday night session
+    #[case("20250106", "20250107", "21:00:01", "20250106", "21:00:01")] // Calendar day reported
+    #[case("", "20250107", "09:00:00", "20250107", "09:00:00")] // Missing action day
+    fn test_parse_session_timestamp(
+        #[case] date: &str,
+        #[case] trading_day: &str,
+        #[case] time: &str,
+        #[case] expected_date: &str,
+        #[case] expected_time: &str,
+    ) {
+        let ts = parse_session_timestamp(date, trading_day, time, 0).unwrap();
+        let expected = parse_ctp_timestamp(expected_date, expected_time, 0).unwrap();
+        assert_eq!(ts, expected);
+    }
+
+    #[rstest]
+    fn test_parse_futures_instrument(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "rb2505");
+
+        let InstrumentEnum::FuturesContract(futures) = instrument else {
+            panic!("Expected FuturesContract");
+        };
+        assert_eq!(futures.id, InstrumentId::from("rb2505.SHFE"));
+        assert_eq!(futures.asset_class, AssetClass::Commodity);
+        assert_eq!(futures.underlying, Ustr::from("rb"));
+        assert_eq!(futures.currency, Currency::CNY());
+        assert_eq!(futures.price_increment, Price::from("1"));
+        assert_eq!(futures.multiplier, Quantity::from(10));
+        assert_eq!(futures.max_quantity, Some(Quantity::from(500)));
+        assert_eq!(
+            futures.expiration_ns,
+            parse_ctp_timestamp("20250515", "15:00:00", 0).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("IF2503", AssetClass::Index, "0.2")]
+    #[case("T2503", AssetClass::Debt, "0.005")]
+    fn test_parse_cffex_instrument(
+        recording: MockCtpRecording,
+        #[case] symbol: &str,
+        #[case] asset_class: AssetClass,
+        #[case] price_increment: &str,
+    ) {
+        let InstrumentEnum::FuturesContract(futures) = instrument(&recording, symbol) else {
+            panic!("Expected FuturesContract");
+        };
+        assert_eq!(futures.asset_class, asset_class);
+        assert_eq!(futures.price_increment, Price::from(price_increment));
+    }
+
+    #[rstest]
+    fn test_parse_option_instrument(recording: MockCtpRecording) {
+        let InstrumentEnum::OptionContract(option) = instrument(&recording, "cu2503C75000") else {
+            panic!("Expected OptionContract");
+        };
+        assert_eq!(option.id, InstrumentId::from("cu2503C75000.SHFE"));
+        assert_eq!(option.underlying, Ustr::from("cu2503"));
+        assert_eq!(option.option_kind, OptionKind::Call);
+        assert_eq!(option.strike_price, Price::from("75000"));
+        assert_eq!(option.margin_init, Decimal::ZERO);
+    }
+
+    #[rstest]
+    fn test_parse_combination_instrument_is_skipped(recording: MockCtpRecording) {
+        let field = instrument_field(&recording, "SP m2505&m2509");
+        assert!(
+            parse_instrument(&field, UnixNanos::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[rstest]
+    fn test_parse_quote_tick(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "rb2505");
+        let md = &market_data(&recording)[1];
+
+        let quote = parse_quote_tick(md, &instrument, UnixNanos::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(quote.instrument_id, InstrumentId::from("rb2505.SHFE"));
+        assert_eq!(quote.bid_price, Price::from("3500"));
+        assert_eq!(quote.ask_price, Price::from("3501"));
+        assert_eq!(quote.bid_size, Quantity::from(8));
+        assert_eq!(quote.ask_size, Quantity::from(11));
+        assert_eq!(
+            quote.ts_event,
+            parse_ctp_timestamp("20250106", "09:00:00", 500).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_parse_quote_tick_at_limit_price_is_skipped(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "m2505");
+        let md = &market_data(&recording)[4];
+
+        let quote = parse_quote_tick(md, &instrument, UnixNanos::default()).unwrap();
+
+        assert!(quote.is_none());
+    }
+
+    #[rstest]
+    fn test_parse_trade_tick_from_volume_delta(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "rb2505");
+        let data = market_data(&recording);
+
+        let first = parse_trade_tick(&data[0], &instrument, None, UnixNanos::default()).unwrap();
+        let second = parse_trade_tick(
+            &data[1],
+            &instrument,
+            Some(data[0].volume),
+            UnixNanos::default(),
+        )
+        .unwrap()
+        .unwrap();
+        let third = parse_trade_tick(
+            &data[2],
+            &instrument,
+            Some(data[1].volume),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert!(first.is_none());
+        assert_eq!(second.price, Price::from("3501"));
+        assert_eq!(second.size, Quantity::from(12));
+        assert_eq!(second.aggressor_side, AggressorSide::NoAggressor);
+        assert_eq!(second.trade_id, TradeId::new("20250106-1012"));
+        assert!(third.is_none());
+    }
+
+    #[rstest]
+    fn test_parse_order_status_report(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "rb2505");
+        let CtpCallback::RtnOrder(order) = &recording.on_order_insert[3] else {
+            panic!("Expected RtnOrder");
+        };
+
+        let report = parse_order_status_report(
+            order,
+            AccountId::from("CTP-000001"),
+            &instrument,
+            Some(ClientOrderId::from("O-123")),
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.venue_order_id, VenueOrderId::new("1-123456-1"));
+        assert_eq!(report.client_order_id, Some(ClientOrderId::from("O-123")));
+        assert_eq!(report.order_side, OrderSide::Sell);
+        assert_eq!(report.order_type, OrderType::Limit);
+        assert_eq!(report.time_in_force, TimeInForce::Day);
+        assert_eq!(report.order_status, OrderStatus::PartiallyFilled);
+        assert_eq!(report.quantity, Quantity::from(3));
+        assert_eq!(report.filled_qty, Quantity::from(2));
+        assert_eq!(report.price, Some(Price::from("3500")));
+        assert!(report.reduce_only);
+    }
+
+    #[rstest]
+    fn test_parse_canceled_order_status_report(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "rb2505");
+        let CtpCallback::RtnOrder(order) = &recording.on_order_action[0] else {
+            panic!("Expected RtnOrder");
+        };
+
+        let report = parse_order_status_report(
+            order,
+            AccountId::from("CTP-000001"),
+            &instrument,
+            None,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.order_status, OrderStatus::Canceled);
+        assert_eq!(report.cancel_reason, Some("已撤单".to_string()));
+        assert_eq!(
+            report.ts_last,
+            parse_ctp_timestamp("20250106", "09:02:00", 0).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_parse_fill_report(recording: MockCtpRecording) {
+        let instrument = instrument(&recording, "rb2505");
+        let CtpCallback::RtnTrade(trade) = &recording.on_order_insert[2] else {
+            panic!("Expected RtnTrade");
+        };
+
+        let report = parse_fill_report(
+            trade,
+            AccountId::from("CTP-000001"),
+            &instrument,
+            VenueOrderId::new("1-123456-1"),
+            None,
+            UnixNanos::default(),
+        )
+        .unwrap();
+
+        assert_eq!(report.trade_id, TradeId::new("10001-Sell"));
+        assert_eq!(report.order_side, OrderSide::Sell);
+        assert_eq!(report.last_qty, Quantity::from(2));
+        assert_eq!(report.last_px, Price::from("3500"));
+        assert_eq!(report.commission, Money::new(0.0, Currency::CNY()));
+        assert_eq!(
+            report.ts_event,
+            parse_ctp_timestamp("20250106", "09:01:00", 0).unwrap()
+        );
+    }
+}
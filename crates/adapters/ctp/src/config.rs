@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Configuration structures for the CTP adapter.
+//!
+//! The native CTP libraries are not bundled with this crate, so each configuration carries the
+//! front implementation to use alongside the credentials passed to it on connect.
+
+use std::sync::Arc;
+
+use ant_model::identifiers::{AccountId, TraderId};
+
+use crate::front::{CtpLoginParams, CtpMdApi, CtpTraderApi};
+
+/// Configuration for the CTP data client.
+#[derive(Debug, Clone)]
+pub struct CtpDataClientConfig {
+    /// The market data front address, e.g. `tcp://180.168.146.187:10211`.
+    pub md_front: String,
+    /// The trader front address (used to load instrument definitions).
+    pub trader_front: String,
+    /// The login credentials.
+    pub login: CtpLoginParams,
+    /// The market data front implementation.
+    pub md_api: Arc<dyn CtpMdApi>,
+    /// The trader front implementation used for instrument queries.
+    pub trader_api: Arc<dyn CtpTraderApi>,
+}
+
+impl CtpDataClientConfig {
+    /// Creates a new [`CtpDataClientConfig`] instance.
+    #[must_use]
+    pub fn new(
+        md_front: String,
+        trader_front: String,
+        login: CtpLoginParams,
+        md_api: Arc<dyn CtpMdApi>,
+        trader_api: Arc<dyn CtpTraderApi>,
+    ) -> Self {
+        Self {
+            md_front,
+            trader_front,
+            login,
+            md_api,
+            trader_api,
+        }
+    }
+}
+
+/// Configuration for the CTP execution client.
+#[derive(Debug, Clone)]
+pub struct CtpExecClientConfig {
+    /// The trader ID for the client.
+    pub trader_id: TraderId,
+    /// The trader front address, e.g. `tcp://180.168.146.187:10201`.
+    pub trader_front: String,
+    /// The login credentials (the user ID is also the investor ID).
+    pub login: CtpLoginParams,
+    /// The trader front implementation.
+    pub trader_api: Arc<dyn CtpTraderApi>,
+}
+
+impl CtpExecClientConfig {
+    /// Creates a new [`CtpExecClientConfig`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        trader_front: String,
+        login: CtpLoginParams,
+        trader_api: Arc<dyn CtpTraderApi>,
+    ) -> Self {
+        Self {
+            trader_id,
+            trader_front,
+            login,
+            trader_api,
+        }
+    }
+
+    /// Returns the account ID for the investor, e.g. `CTP-000001`.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        AccountId::from(format!("CTP-{}", self.login.user_id).as_str())
+    }
+}
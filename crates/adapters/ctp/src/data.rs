@@ -0,0 +1,542 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the CTP live market data client.
+//!
+//! Instrument definitions are loaded from the trader front on connect, as the CTP market data
+//! front only publishes depth snapshots. Each depth snapshot is converted into a [`QuoteTick`]
+//! and, when the cumulative volume has increased, a derived [`TradeTick`].
+//!
+//! [`QuoteTick`]: ant_model::data::QuoteTick
+//! [`TradeTick`]: ant_model::data::TradeTick
+
+use std::{
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use ahash::{AHashMap, AHashSet};
+use ant_common::{
+    messages::{
+        DataEvent, DataResponse,
+        data::{
+            InstrumentResponse, InstrumentsResponse, RequestInstrument, RequestInstruments,
+            SubscribeQuotes, SubscribeTrades, UnsubscribeQuotes, UnsubscribeTrades,
+        },
+    },
+    runner::get_data_event_sender,
+};
+use ant_core::time::AtomicTime;
+use ant_data::client::DataClient;
+use ant_model::{
+    data::Data,
+    identifiers::{ClientId, InstrumentId, Venue},
+    instruments::{Instrument, InstrumentEnum},
+};
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+    task::JoinHandle,
+};
+use ustr::Ustr;
+
+use crate::{
+    common::{
+        consts::CTP,
+        parse::{parse_instrument, parse_quote_tick, parse_trade_tick},
+    },
+    config::CtpDataClientConfig,
+    front::{CtpCallback, models::CtpDepthMarketDataField},
+};
+
+/// The maximum time to wait for the trader front to log in and return instruments.
+const CTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default)]
+struct CtpSubscriptions {
+    quotes: AHashSet<InstrumentId>,
+    trades: AHashSet<InstrumentId>,
+}
+
+impl CtpSubscriptions {
+    fn is_subscribed(&self, instrument_id: &InstrumentId) -> bool {
+        self.quotes.contains(instrument_id) || self.trades.contains(instrument_id)
+    }
+}
+
+/// A CTP data client streaming depth market data from a CTP market data front.
+#[derive(Debug)]
+pub struct CtpDataClient {
+    client_id: ClientId,
+    config: CtpDataClientConfig,
+    clock: &'static AtomicTime,
+    is_connected: Arc<AtomicBool>,
+    /// Instruments keyed by CTP instrument code (market data carries no exchange).
+    instruments: Arc<RwLock<AHashMap<Ustr, InstrumentEnum>>>,
+    subscriptions: Arc<RwLock<CtpSubscriptions>>,
+    task_handle: Option<JoinHandle<()>>,
+    data_sender: UnboundedSender<DataEvent>,
+}
+
+impl CtpDataClient {
+    /// Creates a new [`CtpDataClient`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the data event sender has not been initialized by the runner.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        config: CtpDataClientConfig,
+        clock: &'static AtomicTime,
+    ) -> Self {
+        Self {
+            client_id,
+            config,
+            clock,
+            is_connected: Arc::new(AtomicBool::new(false)),
+            instruments: Arc::new(RwLock::new(AHashMap::new())),
+            subscriptions: Arc::new(RwLock::new(CtpSubscriptions::default())),
+            task_handle: None,
+            data_sender: get_data_event_sender(),
+        }
+    }
+
+    /// Returns the instruments loaded from the trader front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instruments lock is poisoned.
+    #[must_use]
+    pub fn instruments(&self) -> Vec<InstrumentEnum> {
+        self.instruments
+            .read()
+            .expect("Instruments lock poisoned")
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    async fn load_instruments(&self) -> anyhow::Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let trader_api = self.config.trader_api.clone();
+        trader_api.connect(&self.config.login, tx)?;
+
+        let result = tokio::time::timeout(CTP_CONNECT_TIMEOUT, async {
+            await_login(&mut rx).await?;
+            trader_api.req_qry_instrument()?;
+            receive_instruments(&mut rx, self.clock.get_time_ns()).await
+        })
+        .await;
+
+        // The trader session is only used to query instruments
+        trader_api.disconnect()?;
+
+        let instruments = result.map_err(|_| {
+            anyhow::anyhow!(
+                "Timed out loading instruments from {}",
+                self.config.trader_front
+            )
+        })??;
+
+        tracing::info!("Loaded {} instruments", instruments.len());
+
+        let mut map = self.instruments.write().expect("Instruments lock poisoned");
+        for instrument in instruments {
+            map.insert(instrument.raw_symbol().inner(), instrument);
+        }
+        Ok(())
+    }
+
+    fn update_market_data_subscription(
+        &self,
+        instrument_id: InstrumentId,
+        was_subscribed: bool,
+    ) -> anyhow::Result<()> {
+        let is_subscribed = self
+            .subscriptions
+            .read()
+            .expect("Subscriptions lock poisoned")
+            .is_subscribed(&instrument_id);
+        let symbols = [instrument_id.symbol.inner()];
+
+        match (was_subscribed, is_subscribed) {
+            (false, true) => self.config.md_api.subscribe_market_data(&symbols),
+            (true, false) => self.config.md_api.unsubscribe_market_data(&symbols),
+            _ => Ok(()),
+        }
+    }
+
+    fn is_subscribed(&self, instrument_id: &InstrumentId) -> bool {
+        self.subscriptions
+            .read()
+            .expect("Subscriptions lock poisoned")
+            .is_subscribed(instrument_id)
+    }
+
+    fn check_instrument(&self, instrument_id: &InstrumentId) -> anyhow::Result<()> {
+        let instruments = self.instruments.read().expect("Instruments lock poisoned");
+        match instruments.get(&instrument_id.symbol.inner()) {
+            Some(instrument) if instrument.id() == *instrument_id => Ok(()),
+            _ => anyhow::bail!("Instrument {instrument_id} not found for CTP"),
+        }
+    }
+}
+
+async fn await_login(rx: &mut UnboundedReceiver<CtpCallback>) -> anyhow::Result<()> {
+    while let Some(callback) = rx.recv().await {
+        match callback {
+            CtpCallback::RspUserLogin { info, .. } if info.is_error() => {
+                anyhow::bail!("CTP login failed ({}): {}", info.error_id, info.error_msg);
+            }
+            CtpCallback::RspUserLogin { .. } => return Ok(()),
+            CtpCallback::RspError { info } => {
+                anyhow::bail!("CTP error ({}): {}", info.error_id, info.error_msg);
+            }
+            _ => {}
+        }
+    }
+    anyhow::bail!("CTP front disconnected before login")
+}
+
+async fn receive_instruments(
+    rx: &mut UnboundedReceiver<CtpCallback>,
+    ts_init: ant_core::UnixNanos,
+) -> anyhow::Result<Vec<InstrumentEnum>> {
+    let mut instruments = Vec::new();
+    while let Some(callback) = rx.recv().await {
+        if let CtpCallback::RspQryInstrument {
+            instrument,
+            is_last,
+        } = callback
+        {
+            match parse_instrument(&instrument, ts_init) {
+                Ok(Some(instrument)) => instruments.push(instrument),
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    "Failed to parse instrument {}: {e}",
+                    instrument.instrument_id
+                ),
+            }
+            if is_last {
+                return Ok(instruments);
+            }
+        }
+    }
+    anyhow::bail!("CTP front disconnected while querying instruments")
+}
+
+/// Processes callbacks from the market data front until the channel closes.
+async fn run_market_data(
+    mut rx: UnboundedReceiver<CtpCallback>,
+    instruments: Arc<RwLock<AHashMap<Ustr, InstrumentEnum>>>,
+    subscriptions: Arc<RwLock<CtpSubscriptions>>,
+    data_sender: UnboundedSender<DataEvent>,
+    is_connected: Arc<AtomicBool>,
+    clock: &'static AtomicTime,
+) {
+    let mut last_volumes: AHashMap<Ustr, i64> = AHashMap::new();
+
+    while let Some(callback) = rx.recv().await {
+        match callback {
+            CtpCallback::RtnDepthMarketData(md) => {
+                let Some(instrument) = instruments
+                    .read()
+                    .expect("Instruments lock poisoned")
+                    .get(&md.instrument_id)
+                    .cloned()
+                else {
+                    tracing::warn!("Market data for unknown instrument {}", md.instrument_id);
+                    continue;
+                };
+
+                let last_volume = last_volumes.insert(md.instrument_id, md.volume);
+                let (quotes, trades) = {
+                    let subs = subscriptions.read().expect("Subscriptions lock poisoned");
+                    let instrument_id = instrument.id();
+                    (
+                        subs.quotes.contains(&instrument_id),
+                        subs.trades.contains(&instrument_id),
+                    )
+                };
+
+                for data in parse_market_data(&md, &instrument, last_volume, quotes, trades, clock)
+                {
+                    if let Err(e) = data_sender.send(DataEvent::Data(data)) {
+                        tracing::error!("Failed to send data: {e}");
+                    }
+                }
+            }
+            CtpCallback::RspSubMarketData {
+                instrument_id,
+                info,
+            } if info.is_error() => {
+                tracing::error!(
+                    "Failed to subscribe {instrument_id} ({}): {}",
+                    info.error_id,
+                    info.error_msg
+                );
+            }
+            CtpCallback::RspUserLogin { info, .. } if info.is_error() => {
+                tracing::error!(
+                    "Market data login failed ({}): {}",
+                    info.error_id,
+                    info.error_msg
+                );
+            }
+            CtpCallback::FrontDisconnected { reason } => {
+                // The CTP API reconnects automatically, and replays login on reconnect
+                tracing::warn!("Market data front disconnected (reason {reason:#x})");
+                is_connected.store(false, Ordering::Relaxed);
+            }
+            CtpCallback::FrontConnected => {
+                tracing::info!("Market data front connected");
+                is_connected.store(true, Ordering::Relaxed);
+            }
+            CtpCallback::RspError { info } => {
+                tracing::error!("CTP error ({}): {}", info.error_id, info.error_msg);
+            }
+            _ => {}
+        }
+    }
+
+    tracing::debug!("Market data stream ended");
+}
+
+fn parse_market_data(
+    md: &CtpDepthMarketDataField,
+    instrument: &InstrumentEnum,
+    last_volume: Option<i64>,
+    quotes: bool,
+    trades: bool,
+    clock: &'static AtomicTime,
+) -> Vec<Data> {
+    let ts_init = clock.get_time_ns();
+    let mut data = Vec::with_capacity(2);
+
+    if quotes {
+        match parse_quote_tick(md, instrument, ts_init) {
+            Ok(Some(quote)) => data.push(Data::Quote(quote)),
+            Ok(None) => {}
+            Err(e) => tracing::error!("Failed to parse quote for {}: {e}", md.instrument_id),
+        }
+    }
+
+    if trades {
+        match parse_trade_tick(md, instrument, last_volume, ts_init) {
+            Ok(Some(trade)) => data.push(Data::Trade(trade)),
+            Ok(None) => {}
+            Err(e) => tracing::error!("Failed to parse trade for {}: {e}", md.instrument_id),
+        }
+    }
+
+    data
+}
+
+#[async_trait::async_trait]
+impl DataClient for CtpDataClient {
+    fn client_id(&self) -> ClientId {
+        self.client_id
+    }
+
+    /// Returns `None`, as a CTP front serves all the China futures exchanges.
+    fn venue(&self) -> Option<Venue> {
+        None
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Starting");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Stopping");
+        if let Some(handle) = self.task_handle.take() {
+            handle.abort();
+        }
+        self.config.md_api.disconnect()?;
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn reset(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Resetting");
+        *self
+            .subscriptions
+            .write()
+            .expect("Subscriptions lock poisoned") = CtpSubscriptions::default();
+        self.is_connected.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn dispose(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Disposing");
+        self.stop()
+    }
+
+    async fn connect(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Connecting...");
+
+        self.load_instruments().await?;
+
+        let (tx, rx) = unbounded_channel();
+        self.config.md_api.connect(&self.config.login, tx)?;
+        self.task_handle = Some(tokio::spawn(run_market_data(
+            rx,
+            self.instruments.clone(),
+            self.subscriptions.clone(),
+            self.data_sender.clone(),
+            self.is_connected.clone(),
+            self.clock,
+        )));
+
+        self.is_connected.store(true, Ordering::Relaxed);
+        tracing::info!("Connected to {}", self.config.md_front);
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> anyhow::Result<()> {
+        tracing::debug!("Disconnecting...");
+
+        self.config.md_api.disconnect()?;
+        if let Some(handle) = self.task_handle.take() {
+            handle.abort();
+            if let Err(e) = handle.await
+                && !e.is_cancelled()
+            {
+                tracing::error!("Task join error: {e}");
+            }
+        }
+
+        self.is_connected.store(false, Ordering::Relaxed);
+        tracing::info!("Disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.is_connected.load(Ordering::Relaxed)
+    }
+
+    fn is_disconnected(&self) -> bool {
+        !self.is_connected()
+    }
+
+    fn subscribe_quotes(&mut self, cmd: &SubscribeQuotes) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe quotes: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let was_subscribed = self.is_subscribed(&cmd.instrument_id);
+        self.subscriptions
+            .write()
+            .expect("Subscriptions lock poisoned")
+            .quotes
+            .insert(cmd.instrument_id);
+        self.update_market_data_subscription(cmd.instrument_id, was_subscribed)
+    }
+
+    fn subscribe_trades(&mut self, cmd: &SubscribeTrades) -> anyhow::Result<()> {
+        tracing::debug!("Subscribe trades: {cmd:?}");
+        self.check_instrument(&cmd.instrument_id)?;
+
+        let was_subscribed = self.is_subscribed(&cmd.instrument_id);
+        self.subscriptions
+            .write()
+            .expect("Subscriptions lock poisoned")
+            .trades
+            .insert(cmd.instrument_id);
+        self.update_market_data_subscription(cmd.instrument_id, was_subscribed)
+    }
+
+    fn unsubscribe_quotes(&mut self, cmd: &UnsubscribeQuotes) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe quotes: {cmd:?}");
+
+        let was_subscribed = self.is_subscribed(&cmd.instrument_id);
+        self.subscriptions
+            .write()
+            .expect("Subscriptions lock poisoned")
+            .quotes
+            .remove(&cmd.instrument_id);
+        self.update_market_data_subscription(cmd.instrument_id, was_subscribed)
+    }
+
+    fn unsubscribe_trades(&mut self, cmd: &UnsubscribeTrades) -> anyhow::Result<()> {
+        tracing::debug!("Unsubscribe trades: {cmd:?}");
+
+        let was_subscribed = self.is_subscribed(&cmd.instrument_id);
+        self.subscriptions
+            .write()
+            .expect("Subscriptions lock poisoned")
+            .trades
+            .remove(&cmd.instrument_id);
+        self.update_market_data_subscription(cmd.instrument_id, was_subscribed)
+    }
+
+    fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
+        tracing::debug!("Request instruments: {request:?}");
+
+        let instruments: Vec<InstrumentEnum> = self
+            .instruments()
+            .into_iter()
+            .filter(|instrument| {
+                request
+                    .venue
+                    .is_none_or(|venue| instrument.id().venue == venue)
+            })
+            .collect();
+
+        let response = DataResponse::Instruments(InstrumentsResponse::new(
+            request.request_id,
+            self.client_id,
+            request.venue.unwrap_or_else(|| Venue::from(CTP)),
+            instruments,
+            None,
+            None,
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        ));
+
+        self.data_sender.send(DataEvent::Response(response))?;
+        Ok(())
+    }
+
+    fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
+        tracing::debug!("Request instrument: {request:?}");
+
+        let instrument = self
+            .instruments
+            .read()
+            .expect("Instruments lock poisoned")
+            .get(&request.instrument_id.symbol.inner())
+            .filter(|instrument| instrument.id() == request.instrument_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Instrument {} not found", request.instrument_id))?;
+
+        let response = DataResponse::Instrument(Box::new(InstrumentResponse::new(
+            request.request_id,
+            self.client_id,
+            request.instrument_id,
+            instrument,
+            None,
+            None,
+            self.clock.get_time_ns(),
+            request.params.clone(),
+        )));
+
+        self.data_sender.send(DataEvent::Response(response))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,1458 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the CTP live execution client.
+//!
+//! An ant order may be split into several CTP orders ("legs") so that each leg carries a single
+//! offset flag, e.g. selling 5 lots on SHFE against 3 lots held from yesterday and 2 lots opened
+//! today submits a close-yesterday leg for 3 and a close-today leg for 2. Legs are tracked
+//! against the ant order, which is accepted on the first leg acknowledged by the exchange and
+//! closed once every leg is done.
+//!
+//! CTP callbacks arrive on the front threads and are queued to the client, which must be
+//! drained from the engine thread with [`CtpExecutionClient::drain_callbacks`] so that order
+//! events are published on the thread owning the message bus.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use ahash::{AHashMap, AHashSet};
+use ant_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+        GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount, QueryOrder,
+        SubmitOrder, SubmitOrderList,
+    },
+};
+use ant_core::UnixNanos;
+use ant_execution::client::{ExecutionClient, LiveExecutionClient, base::BaseExecutionClient};
+use ant_model::{
+    accounts::AccountAny,
+    enums::{AccountType, LiquiditySide, OmsType, OrderSide, OrderType, PositionSideSpecified},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, Venue,
+        VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, Currency, MarginBalance, Money, Quantity},
+};
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+use ustr::Ustr;
+
+use crate::{
+    common::{
+        consts::{CTP, CTP_SUPPORTED_EXCHANGES},
+        enums::{
+            CtpActionFlag, CtpDirection, CtpExchange, CtpHedgeFlag, CtpOffsetFlag,
+            CtpOrderPriceType, CtpOrderStatus, CtpPosiDirection, CtpTimeCondition,
+            ctp_conditions_from_time_in_force,
+        },
+        offset::{CtpPositionLedger, OffsetLeg},
+        parse::{
+            parse_fill_report, parse_instrument_id, parse_order_status_report, parse_trade_id,
+        },
+    },
+    config::CtpExecClientConfig,
+    front::{
+        CtpCallback,
+        models::{
+            CtpInputOrderActionField, CtpInputOrderField, CtpInvestorPositionField, CtpOrderField,
+            CtpRspInfo, CtpRspUserLogin, CtpTradeField, format_order_key,
+        },
+    },
+};
+
+/// A single CTP order submitted for part of an ant order.
+#[derive(Debug)]
+struct CtpOrderLeg {
+    order_ref: Ustr,
+    offset: CtpOffsetFlag,
+    volume: u32,
+    /// The traded volume reported by the last `OnRtnOrder`.
+    volume_traded: u32,
+    /// The traded volume received through `OnRtnTrade`.
+    filled: u32,
+    is_done: bool,
+}
+
+impl CtpOrderLeg {
+    const fn new(order_ref: Ustr, leg: OffsetLeg) -> Self {
+        Self {
+            order_ref,
+            offset: leg.offset,
+            volume: leg.volume,
+            volume_traded: 0,
+            filled: 0,
+            is_done: false,
+        }
+    }
+
+    /// Returns whether the leg is done and all of its trades have been received.
+    const fn is_complete(&self) -> bool {
+        self.is_done && self.filled >= self.volume_traded
+    }
+}
+
+/// The CTP state of an ant order.
+#[derive(Debug)]
+struct CtpOrderState {
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: u32,
+    quote_currency: Currency,
+    venue_order_id: VenueOrderId,
+    legs: Vec<CtpOrderLeg>,
+    is_accepted: bool,
+    is_closed: bool,
+    reject_reason: Option<String>,
+}
+
+impl CtpOrderState {
+    fn leg_mut(&mut self, order_ref: &Ustr) -> Option<&mut CtpOrderLeg> {
+        self.legs.iter_mut().find(|leg| leg.order_ref == *order_ref)
+    }
+
+    fn filled(&self) -> u32 {
+        self.legs.iter().map(|leg| leg.filled).sum()
+    }
+
+    fn is_complete(&self) -> bool {
+        self.legs.iter().all(CtpOrderLeg::is_complete)
+    }
+
+    const fn snapshot(&self) -> CtpOrderSnapshot {
+        CtpOrderSnapshot {
+            strategy_id: self.strategy_id,
+            instrument_id: self.instrument_id,
+            client_order_id: self.client_order_id,
+            venue_order_id: self.venue_order_id,
+            order_side: self.order_side,
+            order_type: self.order_type,
+            quote_currency: self.quote_currency,
+        }
+    }
+
+    /// Closes the order once every leg is complete, returning the closing event (if any).
+    fn check_complete(&mut self) -> Option<CtpOrderEvent> {
+        if self.is_closed || !self.is_complete() {
+            return None;
+        }
+        self.is_closed = true;
+
+        if !self.is_accepted {
+            let reason = self
+                .reject_reason
+                .clone()
+                .unwrap_or_else(|| "Rejected by CTP".to_string());
+            Some(CtpOrderEvent::Rejected(self.snapshot(), reason))
+        } else if self.filled() < self.quantity {
+            Some(CtpOrderEvent::Canceled(self.snapshot()))
+        } else {
+            None
+        }
+    }
+}
+
+/// The identifiers of an ant order required to generate its events.
+#[derive(Clone, Copy, Debug)]
+struct CtpOrderSnapshot {
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quote_currency: Currency,
+}
+
+/// An order event pending generation once the client state is no longer borrowed.
+#[derive(Debug)]
+enum CtpOrderEvent {
+    Accepted(CtpOrderSnapshot),
+    Filled(CtpOrderSnapshot, CtpTradeField),
+    Rejected(CtpOrderSnapshot, String),
+    Canceled(CtpOrderSnapshot),
+    CancelRejected(CtpOrderSnapshot, String),
+}
+
+/// The trader front session established on login.
+#[derive(Debug, Default)]
+struct CtpSession {
+    front_id: i32,
+    session_id: i32,
+    next_order_ref: u64,
+    trading_day: String,
+    is_logged_in: bool,
+    is_querying_positions: bool,
+}
+
+impl CtpSession {
+    fn next_order_ref(&mut self) -> Ustr {
+        self.next_order_ref += 1;
+        Ustr::from(&self.next_order_ref.to_string())
+    }
+
+    fn order_key(&self, order_ref: &str) -> String {
+        format_order_key(self.front_id, self.session_id, order_ref)
+    }
+
+    fn owns(&self, front_id: i32, session_id: i32) -> bool {
+        self.front_id == front_id && self.session_id == session_id
+    }
+}
+
+/// A CTP execution client trading futures and options on the China futures exchanges.
+///
+/// The client uses [`OmsType::Netting`] and reports the `CTP` venue, so routing must be
+/// registered with the execution engine for each exchange in [`Self::routing_venues`].
+pub struct CtpExecutionClient {
+    core: BaseExecutionClient,
+    config: CtpExecClientConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    is_connected: Cell<bool>,
+    callback_rx: RefCell<Option<UnboundedReceiver<CtpCallback>>>,
+    session: RefCell<CtpSession>,
+    ledger: RefCell<CtpPositionLedger>,
+    orders: RefCell<AHashMap<ClientOrderId, CtpOrderState>>,
+    order_refs: RefCell<AHashMap<Ustr, ClientOrderId>>,
+    /// The latest state of every CTP order seen, keyed by session scoped order key.
+    order_history: RefCell<AHashMap<String, CtpOrderField>>,
+    /// Maps exchange order IDs to session scoped order keys.
+    order_sys_ids: RefCell<AHashMap<String, String>>,
+    trade_history: RefCell<Vec<CtpTradeField>>,
+    trade_ids: RefCell<AHashSet<(CtpExchange, String, CtpDirection)>>,
+    positions: RefCell<Vec<CtpInvestorPositionField>>,
+}
+
+impl std::fmt::Debug for CtpExecutionClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(CtpExecutionClient))
+            .field("client_id", &self.core.client_id)
+            .field("account_id", &self.core.account_id)
+            .field("trader_front", &self.config.trader_front)
+            .finish()
+    }
+}
+
+impl CtpExecutionClient {
+    /// Creates a new [`CtpExecutionClient`] instance.
+    #[must_use]
+    pub fn new(
+        client_id: ClientId,
+        config: CtpExecClientConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> Self {
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            client_id,
+            Venue::from(CTP),
+            OmsType::Netting,
+            config.account_id(),
+            AccountType::Margin,
+            Some(Currency::CNY()),
+            clock.clone(),
+            cache.clone(),
+        );
+
+        Self {
+            core,
+            config,
+            clock,
+            cache,
+            is_connected: Cell::new(false),
+            callback_rx: RefCell::new(None),
+            session: RefCell::new(CtpSession::default()),
+            ledger: RefCell::new(CtpPositionLedger::new()),
+            orders: RefCell::new(AHashMap::new()),
+            order_refs: RefCell::new(AHashMap::new()),
+            order_history: RefCell::new(AHashMap::new()),
+            order_sys_ids: RefCell::new(AHashMap::new()),
+            trade_history: RefCell::new(Vec::new()),
+            trade_ids: RefCell::new(AHashSet::new()),
+            positions: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the exchange venues which should be routed to this client.
+    #[must_use]
+    pub fn routing_venues() -> Vec<Venue> {
+        CTP_SUPPORTED_EXCHANGES
+            .iter()
+            .map(|exchange| Venue::from(*exchange))
+            .collect()
+    }
+
+    /// Returns a snapshot of the position ledger used to resolve offset flags.
+    #[must_use]
+    pub fn ledger(&self) -> CtpPositionLedger {
+        self.ledger.borrow().clone()
+    }
+
+    /// Processes all queued callbacks from the trader front, returning the number processed.
+    ///
+    /// Must be called from the thread owning the message bus, typically on each engine tick.
+    pub fn drain_callbacks(&self) -> usize {
+        let mut count = 0;
+        loop {
+            // Release the receiver borrow before handling, as handlers may send requests which
+            // synchronously queue further callbacks
+            let callback = match self.callback_rx.borrow_mut().as_mut() {
+                Some(rx) => match rx.try_recv() {
+                    Ok(callback) => callback,
+                    Err(_) => break,
+                },
+                None => break,
+            };
+            self.handle_callback(callback);
+            count += 1;
+        }
+        count
+    }
+
+    fn handle_callback(&self, callback: CtpCallback) {
+        match callback {
+            CtpCallback::FrontConnected => log::info!("Trader front connected"),
+            CtpCallback::FrontDisconnected { reason } => {
+                log::warn!("Trader front disconnected (reason {reason:#x})");
+                self.session.borrow_mut().is_logged_in = false;
+                self.is_connected.set(false);
+            }
+            CtpCallback::RspUserLogin { login, info } => self.handle_login(&login, &info),
+            CtpCallback::RspQryInvestorPosition { position, is_last } => {
+                self.handle_position(position.as_ref(), is_last);
+            }
+            CtpCallback::RtnOrder(order) => self.handle_order(order),
+            CtpCallback::RtnTrade(trade) => self.handle_trade(trade),
+            CtpCallback::RspOrderInsert { order, info }
+            | CtpCallback::ErrRtnOrderInsert { order, info } => {
+                self.handle_order_insert_error(&order, &info);
+            }
+            CtpCallback::RspOrderAction { action, info }
+            | CtpCallback::ErrRtnOrderAction { action, info } => {
+                self.handle_order_action_error(&action, &info);
+            }
+            CtpCallback::RspError { info } => {
+                log::error!("CTP error ({}): {}", info.error_id, info.error_msg);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_login(&self, login: &CtpRspUserLogin, info: &CtpRspInfo) {
+        if info.is_error() {
+            log::error!("CTP login failed ({}): {}", info.error_id, info.error_msg);
+            return;
+        }
+
+        {
+            let mut session = self.session.borrow_mut();
+            session.front_id = login.front_id;
+            session.session_id = login.session_id;
+            session.next_order_ref = login.max_order_ref.trim().parse().unwrap_or_default();
+            session.trading_day.clone_from(&login.trading_day);
+            session.is_logged_in = true;
+            session.is_querying_positions = true;
+        }
+
+        log::info!(
+            "Logged in to {} (front {}, session {}, trading day {})",
+            self.config.trader_front,
+            login.front_id,
+            login.session_id,
+            login.trading_day
+        );
+
+        self.is_connected.set(true);
+
+        if let Err(e) = self.config.trader_api.req_qry_investor_position() {
+            log::error!("Failed to query investor positions: {e}");
+        }
+    }
+
+    fn handle_position(&self, position: Option<&CtpInvestorPositionField>, is_last: bool) {
+        {
+            let mut session = self.session.borrow_mut();
+            if session.is_querying_positions {
+                // First row of a new query replaces the previous snapshot
+                session.is_querying_positions = false;
+                self.ledger.borrow_mut().clear();
+                self.positions.borrow_mut().clear();
+            }
+            if is_last {
+                session.is_querying_positions = true;
+            }
+        }
+
+        if let Some(position) = position {
+            self.ledger.borrow_mut().apply_position(position);
+            self.positions.borrow_mut().push(position.clone());
+        }
+    }
+
+    fn handle_order(&self, order: CtpOrderField) {
+        let key = order.order_key();
+        let order_sys_id = order.order_sys_id.trim().to_string();
+        if !order_sys_id.is_empty() {
+            self.order_sys_ids
+                .borrow_mut()
+                .insert(order_sys_id, key.clone());
+        }
+        self.order_history.borrow_mut().insert(key, order.clone());
+
+        let Some(client_order_id) = self.own_client_order_id(&order) else {
+            log::debug!("Received external CTP order {}", order.order_key());
+            return;
+        };
+
+        let mut events = Vec::new();
+        {
+            let mut orders = self.orders.borrow_mut();
+            let Some(state) = orders.get_mut(&client_order_id) else {
+                return;
+            };
+
+            let was_accepted = state.is_accepted;
+            let mut is_accepted = false;
+            let mut reject_reason = None;
+            let mut released = None;
+            if let Some(leg) = state.leg_mut(&order.order_ref) {
+                leg.volume_traded = order.volume_traded.max(0) as u32;
+                match order.order_status {
+                    CtpOrderStatus::NoTradeQueueing
+                    | CtpOrderStatus::PartTradedQueueing
+                    | CtpOrderStatus::NotTouched
+                    | CtpOrderStatus::Touched => is_accepted = true,
+                    CtpOrderStatus::AllTraded => {
+                        is_accepted = true;
+                        leg.is_done = true;
+                    }
+                    CtpOrderStatus::Canceled
+                    | CtpOrderStatus::NoTradeNotQueueing
+                    | CtpOrderStatus::PartTradedNotQueueing => {
+                        if !leg.is_done {
+                            leg.is_done = true;
+                            released = Some((leg.offset, leg.volume - leg.volume_traded));
+                        }
+                        if order.order_sys_id.trim().is_empty() {
+                            // Canceled before reaching the exchange order book
+                            reject_reason = Some(order.status_msg.clone());
+                        } else {
+                            is_accepted = true;
+                        }
+                    }
+                    CtpOrderStatus::Unknown => {}
+                }
+            }
+            if is_accepted {
+                state.is_accepted = true;
+            }
+            if reject_reason.is_some() {
+                state.reject_reason = reject_reason;
+            }
+
+            if let Some((offset, volume)) = released {
+                self.ledger.borrow_mut().release(
+                    order.instrument_id,
+                    order.direction,
+                    offset,
+                    volume,
+                );
+            }
+
+            if state.is_accepted && !was_accepted {
+                events.push(CtpOrderEvent::Accepted(state.snapshot()));
+            }
+            events.extend(state.check_complete());
+        }
+
+        self.emit(events);
+    }
+
+    fn handle_trade(&self, trade: CtpTradeField) {
+        let trade_key = (
+            trade.exchange_id,
+            trade.trade_id.trim().to_string(),
+            trade.direction,
+        );
+        if !self.trade_ids.borrow_mut().insert(trade_key) {
+            log::warn!("Duplicate CTP trade {}", trade.trade_id.trim());
+            return;
+        }
+
+        let volume = trade.volume.max(0) as u32;
+        self.ledger.borrow_mut().apply_trade(
+            trade.instrument_id,
+            trade.direction,
+            trade.offset_flag,
+            volume,
+        );
+        self.trade_history.borrow_mut().push(trade.clone());
+
+        let Some(client_order_id) = self.trade_client_order_id(&trade) else {
+            log::debug!("Received external CTP trade {}", trade.trade_id.trim());
+            return;
+        };
+
+        let mut events = Vec::new();
+        {
+            let mut orders = self.orders.borrow_mut();
+            let Some(state) = orders.get_mut(&client_order_id) else {
+                return;
+            };
+            let Some(leg) = state.leg_mut(&trade.order_ref) else {
+                return;
+            };
+            leg.filled += volume;
+
+            events.push(CtpOrderEvent::Filled(state.snapshot(), trade));
+            events.extend(state.check_complete());
+        }
+
+        self.emit(events);
+    }
+
+    fn handle_order_insert_error(&self, order: &CtpInputOrderField, info: &CtpRspInfo) {
+        log::warn!(
+            "CTP order {} rejected ({}): {}",
+            order.order_ref,
+            info.error_id,
+            info.error_msg
+        );
+
+        let Some(client_order_id) = self.order_refs.borrow().get(&order.order_ref).copied() else {
+            return;
+        };
+
+        let mut events = Vec::new();
+        {
+            let mut orders = self.orders.borrow_mut();
+            let Some(state) = orders.get_mut(&client_order_id) else {
+                return;
+            };
+
+            if let Some(leg) = state.leg_mut(&order.order_ref)
+                && !leg.is_done
+            {
+                leg.is_done = true;
+                self.ledger.borrow_mut().release(
+                    order.instrument_id,
+                    order.direction,
+                    leg.offset,
+                    leg.volume,
+                );
+            }
+            state.reject_reason = Some(info.error_msg.clone());
+            events.extend(state.check_complete());
+        }
+
+        self.emit(events);
+    }
+
+    fn handle_order_action_error(&self, action: &CtpInputOrderActionField, info: &CtpRspInfo) {
+        let Some(client_order_id) = self.order_refs.borrow().get(&action.order_ref).copied() else {
+            log::warn!(
+                "CTP order action rejected ({}): {}",
+                info.error_id,
+                info.error_msg
+            );
+            return;
+        };
+
+        let snapshot = match self.orders.borrow().get(&client_order_id) {
+            Some(state) => state.snapshot(),
+            None => return,
+        };
+
+        self.emit(vec![CtpOrderEvent::CancelRejected(
+            snapshot,
+            info.error_msg.clone(),
+        )]);
+    }
+
+    /// Generates the order events, which must be done with no client state borrowed as
+    /// strategies may react to an event by sending further commands to the client.
+    fn emit(&self, events: Vec<CtpOrderEvent>) {
+        let ts_event = self.clock.borrow().timestamp_ns();
+
+        for event in events {
+            match event {
+                CtpOrderEvent::Accepted(order) => self.core.generate_order_accepted(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    ts_event,
+                ),
+                CtpOrderEvent::Filled(order, trade) => {
+                    let instrument = self.instrument(&order.instrument_id);
+                    let Some(instrument) = instrument else {
+                        log::error!("Instrument {} not found in cache", order.instrument_id);
+                        continue;
+                    };
+
+                    self.core.generate_order_filled(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        PositionId::new(format!("{}-{}", order.instrument_id, order.strategy_id)),
+                        parse_trade_id(&trade),
+                        order.order_side,
+                        order.order_type,
+                        Quantity::new(f64::from(trade.volume), instrument.size_precision()),
+                        instrument.make_price(trade.price),
+                        order.quote_currency,
+                        Money::new(0.0, order.quote_currency),
+                        LiquiditySide::NoLiquiditySide,
+                        ts_event,
+                    );
+                }
+                CtpOrderEvent::Rejected(order, reason) => self.core.generate_order_rejected(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    &reason,
+                    ts_event,
+                    false,
+                ),
+                CtpOrderEvent::Canceled(order) => self.core.generate_order_canceled(
+                    order.strategy_id,
+                    order.instrument_id,
+                    order.client_order_id,
+                    order.venue_order_id,
+                    ts_event,
+                ),
+                CtpOrderEvent::CancelRejected(order, reason) => {
+                    self.core.generate_order_cancel_rejected(
+                        order.strategy_id,
+                        order.instrument_id,
+                        order.client_order_id,
+                        order.venue_order_id,
+                        &reason,
+                        ts_event,
+                    );
+                }
+            }
+        }
+    }
+
+    fn own_client_order_id(&self, order: &CtpOrderField) -> Option<ClientOrderId> {
+        if !self.session.borrow().owns(order.front_id, order.session_id) {
+            return None;
+        }
+        self.order_refs.borrow().get(&order.order_ref).copied()
+    }
+
+    fn trade_client_order_id(&self, trade: &CtpTradeField) -> Option<ClientOrderId> {
+        let key = self
+            .order_sys_ids
+            .borrow()
+            .get(trade.order_sys_id.trim())
+            .cloned()?;
+        let order_history = self.order_history.borrow();
+        let order = order_history.get(&key)?;
+        self.own_client_order_id(order)
+    }
+
+    fn reject(&self, cmd: &SubmitOrder, reason: &str) {
+        log::error!("Rejecting order {}: {reason}", cmd.client_order_id);
+        self.core.generate_order_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            reason,
+            self.clock.borrow().timestamp_ns(),
+            false,
+        );
+    }
+
+    fn submit(&self, cmd: &SubmitOrder, order: &OrderAny) {
+        if !self.session.borrow().is_logged_in {
+            self.reject(cmd, "CTP trader front not logged in");
+            return;
+        }
+
+        let exchange = match CtpExchange::try_from(order.instrument_id().venue) {
+            Ok(exchange) => exchange,
+            Err(e) => return self.reject(cmd, &e.to_string()),
+        };
+
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&order.instrument_id())
+            .cloned();
+        let Some(instrument) = instrument else {
+            return self.reject(
+                cmd,
+                &format!("Instrument {} not found", order.instrument_id()),
+            );
+        };
+
+        let (order_price_type, limit_price) = match order.order_type() {
+            OrderType::Market => (CtpOrderPriceType::AnyPrice, 0.0),
+            OrderType::Limit => (
+                CtpOrderPriceType::LimitPrice,
+                order.price().map(|p| p.as_f64()).unwrap_or_default(),
+            ),
+            order_type => {
+                return self.reject(cmd, &format!("Unsupported order type {order_type}"));
+            }
+        };
+
+        let (mut time_condition, volume_condition) =
+            match ctp_conditions_from_time_in_force(order.time_in_force()) {
+                Ok(conditions) => conditions,
+                Err(e) => return self.reject(cmd, &e.to_string()),
+            };
+        if order_price_type == CtpOrderPriceType::AnyPrice {
+            // CTP market orders must be immediate
+            time_condition = CtpTimeCondition::Ioc;
+        }
+
+        let quantity = order.quantity().as_f64() as u32;
+        let symbol = order.instrument_id().symbol.inner();
+        let legs = self.ledger.borrow().resolve(
+            exchange,
+            &symbol,
+            order.order_side(),
+            quantity,
+            order.is_reduce_only(),
+        );
+        if legs.is_empty() {
+            return self.reject(cmd, "No position to reduce");
+        }
+
+        let ts_event = self.clock.borrow().timestamp_ns();
+        self.core.generate_order_submitted(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            ts_event,
+        );
+
+        let direction = CtpDirection::from(order.order_side());
+        let mut requests = Vec::with_capacity(legs.len());
+        let mut state_legs = Vec::with_capacity(legs.len());
+        {
+            let mut session = self.session.borrow_mut();
+            for leg in legs {
+                let order_ref = session.next_order_ref();
+                state_legs.push(CtpOrderLeg::new(order_ref, leg));
+                requests.push(CtpInputOrderField {
+                    broker_id: Ustr::from(&self.config.login.broker_id),
+                    investor_id: Ustr::from(&self.config.login.user_id),
+                    instrument_id: symbol,
+                    exchange_id: exchange,
+                    order_ref,
+                    direction,
+                    comb_offset_flag: leg.offset,
+                    comb_hedge_flag: CtpHedgeFlag::Speculation,
+                    order_price_type,
+                    limit_price,
+                    volume_total_original: leg.volume as i32,
+                    time_condition,
+                    volume_condition,
+                });
+            }
+        }
+
+        let venue_order_id =
+            VenueOrderId::new(self.session.borrow().order_key(&state_legs[0].order_ref));
+        {
+            let mut order_refs = self.order_refs.borrow_mut();
+            let mut ledger = self.ledger.borrow_mut();
+            for leg in &state_legs {
+                order_refs.insert(leg.order_ref, cmd.client_order_id);
+                ledger.freeze(symbol, direction, OffsetLeg::new(leg.offset, leg.volume));
+            }
+        }
+
+        self.orders.borrow_mut().insert(
+            cmd.client_order_id,
+            CtpOrderState {
+                strategy_id: cmd.strategy_id,
+                instrument_id: cmd.instrument_id,
+                client_order_id: cmd.client_order_id,
+                order_side: order.order_side(),
+                order_type: order.order_type(),
+                quantity,
+                quote_currency: instrument.quote_currency(),
+                venue_order_id,
+                legs: state_legs,
+                is_accepted: false,
+                is_closed: false,
+                reject_reason: None,
+            },
+        );
+
+        for request in requests {
+            if let Err(e) = self.config.trader_api.req_order_insert(&request) {
+                let info = CtpRspInfo {
+                    error_id: -1,
+                    error_msg: format!("Failed to send order insert: {e}"),
+                };
+                self.handle_order_insert_error(&request, &info);
+            }
+        }
+
+        self.drain_callbacks();
+    }
+
+    fn cancel(&self, client_order_id: &ClientOrderId, ts_event: UnixNanos) -> anyhow::Result<()> {
+        let actions: Vec<CtpInputOrderActionField> = {
+            let orders = self.orders.borrow();
+            let Some(state) = orders.get(client_order_id) else {
+                anyhow::bail!("Order {client_order_id} not found");
+            };
+            if state.is_closed {
+                anyhow::bail!("Order {client_order_id} already closed");
+            }
+
+            let session = self.session.borrow();
+            let exchange = CtpExchange::try_from(state.instrument_id.venue)?;
+            state
+                .legs
+                .iter()
+                .filter(|leg| !leg.is_done)
+                .map(|leg| CtpInputOrderActionField {
+                    broker_id: Ustr::from(&self.config.login.broker_id),
+                    investor_id: Ustr::from(&self.config.login.user_id),
+                    instrument_id: state.instrument_id.symbol.inner(),
+                    exchange_id: exchange,
+                    order_ref: leg.order_ref,
+                    front_id: session.front_id,
+                    session_id: session.session_id,
+                    action_flag: CtpActionFlag::Delete,
+                })
+                .collect()
+        };
+
+        log::debug!(
+            "Canceling {client_order_id} ({} legs) at {ts_event}",
+            actions.len()
+        );
+
+        for action in &actions {
+            self.config.trader_api.req_order_action(action)?;
+        }
+        self.drain_callbacks();
+        Ok(())
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentEnum> {
+        self.cache.borrow().instrument(instrument_id).cloned()
+    }
+
+    fn order_report(&self, order: &CtpOrderField) -> Option<OrderStatusReport> {
+        let instrument_id = parse_instrument_id(order.instrument_id, order.exchange_id);
+        let instrument = self.instrument(&instrument_id)?;
+        let client_order_id = self.own_client_order_id(order);
+        parse_order_status_report(
+            order,
+            self.core.account_id,
+            &instrument,
+            client_order_id,
+            self.clock.borrow().timestamp_ns(),
+        )
+        .inspect_err(|e| log::error!("Failed to parse order {}: {e}", order.order_key()))
+        .ok()
+    }
+}
+
+impl ExecutionClient for CtpExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.is_connected.get()
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        self.core.venue
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        log::info!("Started");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        log::info!("Stopped");
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(cmd, &cmd.order);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // CTP has no native order lists, orders are submitted individually
+        for order in &cmd.order_list.orders {
+            let submit = SubmitOrder::new(
+                cmd.trader_id,
+                cmd.client_id,
+                cmd.strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                cmd.venue_order_id,
+                order.clone(),
+                cmd.exec_algorith_id,
+                cmd.position_id,
+                cmd.command_id,
+                cmd.ts_init,
+            )?;
+            self.submit(&submit, order);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        self.core.generate_order_modify_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            cmd.venue_order_id,
+            "CTP does not support order modification",
+            self.clock.borrow().timestamp_ns(),
+        );
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        if let Err(e) = self.cancel(&cmd.client_order_id, cmd.ts_init) {
+            self.core.generate_order_cancel_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                &e.to_string(),
+                self.clock.borrow().timestamp_ns(),
+            );
+        }
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        let client_order_ids: Vec<ClientOrderId> = self
+            .orders
+            .borrow()
+            .values()
+            .filter(|state| {
+                !state.is_closed
+                    && state.instrument_id == cmd.instrument_id
+                    && (cmd.order_side == OrderSide::NoOrderSide
+                        || state.order_side == cmd.order_side)
+            })
+            .map(|state| state.client_order_id)
+            .collect();
+
+        for client_order_id in client_order_ids {
+            if let Err(e) = self.cancel(&client_order_id, cmd.ts_init) {
+                log::error!("Failed to cancel {client_order_id}: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        for cancel in &cmd.cancels {
+            self.cancel_order(cancel)?;
+        }
+        Ok(())
+    }
+
+    fn query_account(&self, cmd: &QueryAccount) -> anyhow::Result<()> {
+        log::debug!("Account queries are not yet supported for CTP: {cmd:?}");
+        Ok(())
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        // CTP pushes every order state change, so the latest state is already held locally
+        let order = self
+            .order_history
+            .borrow()
+            .get(cmd.venue_order_id.as_str())
+            .cloned();
+        match order.and_then(|order| self.order_report(&order)) {
+            Some(report) => log::info!("{report:?}"),
+            None => log::warn!("Order {} not found", cmd.client_order_id),
+        }
+        Ok(())
+    }
+
+    fn as_live(&self) -> Option<&dyn LiveExecutionClient> {
+        Some(self)
+    }
+}
+
+impl LiveExecutionClient for CtpExecutionClient {
+    fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected.get() {
+            return Ok(());
+        }
+
+        let (tx, rx) = unbounded_channel();
+        self.config.trader_api.connect(&self.config.login, tx)?;
+        *self.callback_rx.borrow_mut() = Some(rx);
+
+        // Login completes asynchronously, orders are rejected until it does
+        self.drain_callbacks();
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> anyhow::Result<()> {
+        self.config.trader_api.disconnect()?;
+        self.drain_callbacks();
+        *self.callback_rx.borrow_mut() = None;
+        self.session.borrow_mut().is_logged_in = false;
+        self.is_connected.set(false);
+        log::info!("Disconnected");
+        Ok(())
+    }
+
+    fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let key = if let Some(venue_order_id) = cmd.venue_order_id() {
+            Some(venue_order_id.to_string())
+        } else if let Some(client_order_id) = cmd.client_order_id() {
+            self.orders
+                .borrow()
+                .get(&client_order_id)
+                .map(|state| state.venue_order_id.to_string())
+        } else {
+            None
+        };
+
+        let Some(key) = key else {
+            return Ok(None);
+        };
+
+        Ok(self
+            .order_history
+            .borrow()
+            .get(&key)
+            .and_then(|order| self.order_report(order)))
+    }
+
+    fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        Ok(self
+            .order_history
+            .borrow()
+            .values()
+            .filter_map(|order| self.order_report(order))
+            .filter(|report| {
+                cmd.instrument_id()
+                    .is_none_or(|instrument_id| report.instrument_id == instrument_id)
+            })
+            .collect())
+    }
+
+    fn generate_fill_reports(&self, cmd: GenerateFillReports) -> anyhow::Result<Vec<FillReport>> {
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let order_sys_ids = self.order_sys_ids.borrow();
+        let mut reports = Vec::new();
+
+        for trade in self.trade_history.borrow().iter() {
+            let instrument_id = parse_instrument_id(trade.instrument_id, trade.exchange_id);
+            if cmd
+                .instrument_id()
+                .is_some_and(|filter| filter != instrument_id)
+            {
+                continue;
+            }
+            let Some(instrument) = self.instrument(&instrument_id) else {
+                log::warn!("Instrument {instrument_id} not found for fill report");
+                continue;
+            };
+
+            let venue_order_id = order_sys_ids.get(trade.order_sys_id.trim()).map_or_else(
+                || VenueOrderId::new(trade.order_sys_id.trim()),
+                VenueOrderId::new,
+            );
+
+            reports.push(parse_fill_report(
+                trade,
+                self.core.account_id,
+                &instrument,
+                venue_order_id,
+                self.trade_client_order_id(trade),
+                ts_init,
+            )?);
+        }
+
+        Ok(reports)
+    }
+
+    fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let ledger = self.ledger.borrow();
+        let mut seen = AHashSet::new();
+        let mut reports = Vec::new();
+
+        for position in self.positions.borrow().iter() {
+            let instrument_id = parse_instrument_id(position.instrument_id, position.exchange_id);
+            if !seen.insert(instrument_id)
+                || cmd
+                    .instrument_id()
+                    .is_some_and(|filter| filter != instrument_id)
+            {
+                continue;
+            }
+
+            // Long and short positions are held separately on CTP, ant nets them
+            let long = ledger.bucket(&position.instrument_id, CtpPosiDirection::Long);
+            let short = ledger.bucket(&position.instrument_id, CtpPosiDirection::Short);
+            let net = i64::from(long.total()) - i64::from(short.total());
+            let position_side = match net.signum() {
+                1 => PositionSideSpecified::Long,
+                -1 => PositionSideSpecified::Short,
+                _ => PositionSideSpecified::Flat,
+            };
+
+            reports.push(PositionStatusReport::new(
+                self.core.account_id,
+                instrument_id,
+                position_side,
+                Quantity::from(net.unsigned_abs()),
+                None,
+                ts_init,
+                ts_init,
+                None,
+            ));
+        }
+
+        Ok(reports)
+    }
+
+    fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        // CTP only holds orders and trades for the current trading day
+        log::debug!("Generating mass status (lookback_mins={lookback_mins:?})");
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            self.core.venue,
+            ts_init,
+            None,
+        );
+
+        let orders =
+            GenerateOrderStatusReport::new(ant_core::UUID4::new(), ts_init, None, None, None);
+        mass_status.add_order_reports(self.generate_order_status_reports(&orders)?);
+        mass_status.add_fill_reports(self.generate_fill_reports(GenerateFillReports::new(
+            ant_core::UUID4::new(),
+            ts_init,
+            None,
+            None,
+            None,
+            None,
+        ))?);
+        mass_status.add_position_reports(self.generate_position_status_reports(
+            &GeneratePositionReports::new(ant_core::UUID4::new(), ts_init, None, None, None),
+        )?);
+
+        Ok(Some(mass_status))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ant_common::{
+        clock::TestClock,
+        msgbus::{
+            self, get_message_saving_handler, get_saved_messages, handler::ShareableMessageHandler,
+        },
+    };
+    use ant_core::UUID4;
+    use ant_model::{
+        events::OrderEventAny,
+        identifiers::{TradeId, TraderId},
+        orders::builder::OrderTestBuilder,
+        types::Price,
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        common::{parse::parse_instrument, testing::load_test_json},
+        front::{
+            CtpLoginParams,
+            mock::{MockCtpFront, MockCtpRecording},
+        },
+    };
+
+    const RECORDING: &str = "recording_shfe_session.json";
+
+    #[fixture]
+    fn front() -> Arc<MockCtpFront> {
+        Arc::new(MockCtpFront::from_json(&load_test_json(RECORDING)).unwrap())
+    }
+
+    fn order_event_handler() -> ShareableMessageHandler {
+        let handler =
+            get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+        msgbus::register("ExecEngine.process".into(), handler.clone());
+        handler
+    }
+
+    fn create_client(front: Arc<MockCtpFront>) -> CtpExecutionClient {
+        let recording: MockCtpRecording = serde_json::from_str(&load_test_json(RECORDING)).unwrap();
+        let mut cache = Cache::default();
+        for callback in &recording.on_qry_instrument {
+            if let CtpCallback::RspQryInstrument { instrument, .. } = callback
+                && let Some(instrument) =
+                    parse_instrument(instrument, UnixNanos::default()).unwrap()
+            {
+                cache.add_instrument(instrument).unwrap();
+            }
+        }
+
+        let login = CtpLoginParams {
+            broker_id: "9999".to_string(),
+            user_id: "000001".to_string(),
+            ..Default::default()
+        };
+        let config = CtpExecClientConfig::new(
+            TraderId::from("TRADER-001"),
+            "tcp://127.0.0.1:10201".to_string(),
+            login,
+            front,
+        );
+
+        CtpExecutionClient::new(
+            ClientId::from("CTP"),
+            config,
+            Rc::new(RefCell::new(TestClock::new())),
+            Rc::new(RefCell::new(cache)),
+        )
+    }
+
+    fn limit_order(instrument_id: &str, side: OrderSide, quantity: u64, price: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(InstrumentId::from(instrument_id))
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .price(Price::from(price))
+            .build()
+    }
+
+    fn submit_order(order: &OrderAny) -> SubmitOrder {
+        SubmitOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("CTP"),
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::from("NONE"),
+            order: order.clone(),
+            exec_algorith_id: None,
+            position_id: None,
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        }
+    }
+
+    #[rstest]
+    fn test_connect_logs_in_and_loads_positions(front: Arc<MockCtpFront>) {
+        let mut client = create_client(front);
+
+        client.connect().unwrap();
+
+        assert!(client.is_connected());
+        let bucket = client
+            .ledger()
+            .bucket(&Ustr::from("rb2505"), CtpPosiDirection::Long);
+        assert_eq!(bucket.yesterday, 3);
+        assert_eq!(bucket.today, 2);
+    }
+
+    #[rstest]
+    fn test_submit_order_when_not_connected_rejects(front: Arc<MockCtpFront>) {
+        let handler = order_event_handler();
+        let client = create_client(front.clone());
+        let order = limit_order("rb2505.SHFE", OrderSide::Sell, 1, "3500");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+        assert!(front.order_inserts().is_empty());
+    }
+
+    #[rstest]
+    fn test_submit_order_splits_close_yesterday_and_close_today(front: Arc<MockCtpFront>) {
+        let handler = order_event_handler();
+        let mut client = create_client(front.clone());
+        client.connect().unwrap();
+        let order = limit_order("rb2505.SHFE", OrderSide::Sell, 5, "3500");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let inserts = front.order_inserts();
+        assert_eq!(inserts.len(), 2);
+        assert_eq!(inserts[0].order_ref, Ustr::from("1"));
+        assert_eq!(inserts[0].comb_offset_flag, CtpOffsetFlag::CloseYesterday);
+        assert_eq!(inserts[0].volume_total_original, 3);
+        assert_eq!(inserts[1].order_ref, Ustr::from("2"));
+        assert_eq!(inserts[1].comb_offset_flag, CtpOffsetFlag::CloseToday);
+        assert_eq!(inserts[1].volume_total_original, 2);
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 4);
+        assert!(matches!(events[0], OrderEventAny::Submitted(_)));
+        let OrderEventAny::Accepted(accepted) = &events[1] else {
+            panic!("Expected accepted, was {:?}", events[1]);
+        };
+        assert_eq!(accepted.venue_order_id, VenueOrderId::from("1-123456-1"));
+        let OrderEventAny::Filled(fill) = &events[2] else {
+            panic!("Expected fill, was {:?}", events[2]);
+        };
+        assert_eq!(fill.last_qty, Quantity::from(2));
+        assert_eq!(fill.last_px, Price::from("3500"));
+        assert_eq!(fill.trade_id, TradeId::from("10001-Sell"));
+        assert!(matches!(events[3], OrderEventAny::Filled(_)));
+
+        let bucket = client
+            .ledger()
+            .bucket(&Ustr::from("rb2505"), CtpPosiDirection::Long);
+        assert_eq!(bucket.yesterday, 0);
+        assert_eq!(bucket.today, 2);
+        assert_eq!(bucket.today_available(), 0);
+    }
+
+    #[rstest]
+    fn test_cancel_order_cancels_working_leg(front: Arc<MockCtpFront>) {
+        let handler = order_event_handler();
+        let mut client = create_client(front.clone());
+        client.connect().unwrap();
+        let order = limit_order("rb2505.SHFE", OrderSide::Sell, 5, "3500");
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let cancel = CancelOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("CTP"),
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::from("1-123456-1"),
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        };
+        client.cancel_order(&cancel).unwrap();
+
+        let actions = front.order_actions();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].order_ref, Ustr::from("2"));
+        assert_eq!(actions[0].action_flag, CtpActionFlag::Delete);
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert!(matches!(events.last(), Some(OrderEventAny::Canceled(_))));
+
+        let bucket = client
+            .ledger()
+            .bucket(&Ustr::from("rb2505"), CtpPosiDirection::Long);
+        assert_eq!(bucket.today_available(), 2);
+    }
+
+    #[rstest]
+    fn test_order_insert_error_rejects_order(front: Arc<MockCtpFront>) {
+        let handler = order_event_handler();
+        let mut client = create_client(front);
+        client.connect().unwrap();
+        // The recorded session rejects the third order for insufficient funds
+        client.session.borrow_mut().next_order_ref = 2;
+        let order = limit_order("rb2505.SHFE", OrderSide::Buy, 1, "3500");
+
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let events = get_saved_messages::<OrderEventAny>(handler);
+        assert_eq!(events.len(), 2);
+        let OrderEventAny::Rejected(rejected) = &events[1] else {
+            panic!("Expected rejected, was {:?}", events[1]);
+        };
+        assert_eq!(rejected.reason.as_str(), "CTP:资金不足");
+    }
+
+    #[rstest]
+    fn test_generate_reports(front: Arc<MockCtpFront>) {
+        let mut client = create_client(front);
+        client.connect().unwrap();
+        let order = limit_order("rb2505.SHFE", OrderSide::Sell, 5, "3500");
+        client.submit_order(&submit_order(&order)).unwrap();
+
+        let mass_status = client.generate_mass_status(None).unwrap().unwrap();
+
+        assert_eq!(mass_status.order_reports().len(), 2);
+        assert_eq!(mass_status.fill_reports().values().flatten().count(), 2);
+        let positions = mass_status.position_reports();
+        let report = &positions[&InstrumentId::from("rb2505.SHFE")][0];
+        assert_eq!(report.quantity, Quantity::from(2));
+    }
+}
@@ -0,0 +1,146 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating CTP clients.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use ant_common::{cache::Cache, clock::Clock};
+use ant_core::time::get_atomic_clock_realtime;
+use ant_data::client::DataClient;
+use ant_execution::client::ExecutionClient;
+use ant_model::identifiers::ClientId;
+use ant_system::factories::{ClientConfig, DataClientFactory, ExecutionClientFactory};
+
+use crate::{
+    config::{CtpDataClientConfig, CtpExecClientConfig},
+    data::CtpDataClient,
+    execution::CtpExecutionClient,
+};
+
+impl ClientConfig for CtpDataClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl ClientConfig for CtpExecClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating CTP data clients.
+#[derive(Debug)]
+pub struct CtpDataClientFactory;
+
+impl CtpDataClientFactory {
+    /// Creates a new [`CtpDataClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CtpDataClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataClientFactory for CtpDataClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        _cache: Rc<RefCell<Cache>>,
+        _clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn DataClient>> {
+        let ctp_config = config
+            .as_any()
+            .downcast_ref::<CtpDataClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for CtpDataClientFactory. Expected CtpDataClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client = CtpDataClient::new(
+            ClientId::from(name),
+            ctp_config.clone(),
+            get_atomic_clock_realtime(),
+        );
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        "CTP"
+    }
+
+    fn config_type(&self) -> &'static str {
+        "CtpDataClientConfig"
+    }
+}
+
+/// Factory for creating CTP execution clients.
+#[derive(Debug)]
+pub struct CtpExecutionClientFactory;
+
+impl CtpExecutionClientFactory {
+    /// Creates a new [`CtpExecutionClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CtpExecutionClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutionClientFactory for CtpExecutionClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn ExecutionClient>> {
+        let ctp_config = config
+            .as_any()
+            .downcast_ref::<CtpExecClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for CtpExecutionClientFactory. Expected CtpExecClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client =
+            CtpExecutionClient::new(ClientId::from(name), ctp_config.clone(), clock, cache);
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        "CTP"
+    }
+
+    fn config_type(&self) -> &'static str {
+        "CtpExecClientConfig"
+    }
+}
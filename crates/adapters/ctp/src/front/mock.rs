@@ -0,0 +1,379 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A mock CTP front which replays recorded callbacks for local testing.
+//!
+//! Recordings are JSON documents grouping the callbacks captured from a live front by the
+//! request which triggered them. Order flows are matched to requests by `OrderRef`, so
+//! recordings should be captured from a fresh session where order references start at 1.
+
+use std::{
+    path::Path,
+    sync::{Mutex, MutexGuard},
+};
+
+use ahash::AHashSet;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+use ustr::Ustr;
+
+use super::{
+    CtpCallback, CtpLoginParams, CtpMdApi, CtpTraderApi,
+    models::{CtpInputOrderActionField, CtpInputOrderField, CtpRspInfo, CtpRspUserLogin},
+};
+
+/// A set of recorded CTP callbacks grouped by the request which triggered them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MockCtpRecording {
+    /// Replayed to each front on connect (typically `FrontConnected` and `RspUserLogin`).
+    #[serde(default)]
+    pub on_connect: Vec<CtpCallback>,
+    /// Replayed in response to `ReqQryInstrument`.
+    #[serde(default)]
+    pub on_qry_instrument: Vec<CtpCallback>,
+    /// Replayed in response to `ReqQryInvestorPosition`.
+    #[serde(default)]
+    pub on_qry_investor_position: Vec<CtpCallback>,
+    /// Market data replayed per instrument when subscribed.
+    #[serde(default)]
+    pub on_subscribe: Vec<CtpCallback>,
+    /// Order and trade callbacks replayed per `OrderRef` on `ReqOrderInsert`.
+    #[serde(default)]
+    pub on_order_insert: Vec<CtpCallback>,
+    /// Order callbacks replayed per `OrderRef` on `ReqOrderAction`.
+    #[serde(default)]
+    pub on_order_action: Vec<CtpCallback>,
+}
+
+#[derive(Debug, Default)]
+struct MockCtpState {
+    md_tx: Option<UnboundedSender<CtpCallback>>,
+    trader_tx: Option<UnboundedSender<CtpCallback>>,
+    subscriptions: AHashSet<Ustr>,
+    order_inserts: Vec<CtpInputOrderField>,
+    order_actions: Vec<CtpInputOrderActionField>,
+}
+
+/// A mock CTP front implementing both [`CtpMdApi`] and [`CtpTraderApi`].
+///
+/// Requests are recorded so tests can assert on what the clients sent to the front.
+#[derive(Debug)]
+pub struct MockCtpFront {
+    recording: MockCtpRecording,
+    state: Mutex<MockCtpState>,
+}
+
+impl MockCtpFront {
+    /// Creates a new [`MockCtpFront`] instance which replays the given `recording`.
+    #[must_use]
+    pub fn new(recording: MockCtpRecording) -> Self {
+        Self {
+            recording,
+            state: Mutex::new(MockCtpState::default()),
+        }
+    }
+
+    /// Creates a new [`MockCtpFront`] from a JSON recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the recording cannot be deserialized.
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        Ok(Self::new(serde_json::from_str(json)?))
+    }
+
+    /// Creates a new [`MockCtpFront`] from a JSON recording file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or deserialized.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Pushes an arbitrary callback to the trader front, as if sent unsolicited by the exchange.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trader front is not connected.
+    pub fn push_trader_callback(&self, callback: CtpCallback) -> anyhow::Result<()> {
+        let state = self.state();
+        let tx = state
+            .trader_tx
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Trader front not connected"))?;
+        tx.send(callback)?;
+        Ok(())
+    }
+
+    /// Returns the instrument codes currently subscribed for market data.
+    #[must_use]
+    pub fn subscriptions(&self) -> Vec<Ustr> {
+        self.state().subscriptions.iter().copied().collect()
+    }
+
+    /// Returns the order insert requests received by the front.
+    #[must_use]
+    pub fn order_inserts(&self) -> Vec<CtpInputOrderField> {
+        self.state().order_inserts.clone()
+    }
+
+    /// Returns the order action requests received by the front.
+    #[must_use]
+    pub fn order_actions(&self) -> Vec<CtpInputOrderActionField> {
+        self.state().order_actions.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockCtpState> {
+        self.state.lock().expect("Mock CTP front state poisoned")
+    }
+
+    fn replay<'a>(
+        tx: Option<&UnboundedSender<CtpCallback>>,
+        callbacks: impl Iterator<Item = &'a CtpCallback>,
+    ) -> anyhow::Result<()> {
+        let tx = tx.ok_or_else(|| anyhow::anyhow!("Front not connected"))?;
+        for callback in callbacks {
+            tx.send(callback.clone())?;
+        }
+        Ok(())
+    }
+
+    fn login_callbacks(&self, params: &CtpLoginParams) -> Vec<CtpCallback> {
+        if !self.recording.on_connect.is_empty() {
+            return self.recording.on_connect.clone();
+        }
+
+        vec![
+            CtpCallback::FrontConnected,
+            CtpCallback::RspUserLogin {
+                login: CtpRspUserLogin {
+                    trading_day: String::new(),
+                    broker_id: Ustr::from(&params.broker_id),
+                    user_id: Ustr::from(&params.user_id),
+                    front_id: 1,
+                    session_id: 1,
+                    max_order_ref: "0".to_string(),
+                },
+                info: CtpRspInfo::default(),
+            },
+        ]
+    }
+}
+
+fn callback_order_ref(callback: &CtpCallback) -> Option<Ustr> {
+    match callback {
+        CtpCallback::RtnOrder(order) => Some(order.order_ref),
+        CtpCallback::RtnTrade(trade) => Some(trade.order_ref),
+        CtpCallback::RspOrderInsert { order, .. }
+        | CtpCallback::ErrRtnOrderInsert { order, .. } => Some(order.order_ref),
+        CtpCallback::RspOrderAction { action, .. }
+        | CtpCallback::ErrRtnOrderAction { action, .. } => Some(action.order_ref),
+        _ => None,
+    }
+}
+
+impl CtpMdApi for MockCtpFront {
+    fn connect(
+        &self,
+        params: &CtpLoginParams,
+        callback_tx: UnboundedSender<CtpCallback>,
+    ) -> anyhow::Result<()> {
+        let callbacks = self.login_callbacks(params);
+        let mut state = self.state();
+        Self::replay(Some(&callback_tx), callbacks.iter())?;
+        state.md_tx = Some(callback_tx);
+        Ok(())
+    }
+
+    fn disconnect(&self) -> anyhow::Result<()> {
+        let mut state = self.state();
+        state.md_tx = None;
+        state.subscriptions.clear();
+        Ok(())
+    }
+
+    fn subscribe_market_data(&self, instrument_ids: &[Ustr]) -> anyhow::Result<()> {
+        let mut state = self.state();
+        for instrument_id in instrument_ids {
+            state.subscriptions.insert(*instrument_id);
+            let ack = CtpCallback::RspSubMarketData {
+                instrument_id: *instrument_id,
+                info: CtpRspInfo::default(),
+            };
+            let data = self.recording.on_subscribe.iter().filter(|callback| {
+                matches!(callback, CtpCallback::RtnDepthMarketData(md) if md.instrument_id == *instrument_id)
+            });
+            Self::replay(state.md_tx.as_ref(), std::iter::once(&ack).chain(data))?;
+        }
+        Ok(())
+    }
+
+    fn unsubscribe_market_data(&self, instrument_ids: &[Ustr]) -> anyhow::Result<()> {
+        let mut state = self.state();
+        for instrument_id in instrument_ids {
+            state.subscriptions.remove(instrument_id);
+        }
+        Ok(())
+    }
+}
+
+impl CtpTraderApi for MockCtpFront {
+    fn connect(
+        &self,
+        params: &CtpLoginParams,
+        callback_tx: UnboundedSender<CtpCallback>,
+    ) -> anyhow::Result<()> {
+        let callbacks = self.login_callbacks(params);
+        let mut state = self.state();
+        Self::replay(Some(&callback_tx), callbacks.iter())?;
+        state.trader_tx = Some(callback_tx);
+        Ok(())
+    }
+
+    fn disconnect(&self) -> anyhow::Result<()> {
+        self.state().trader_tx = None;
+        Ok(())
+    }
+
+    fn req_order_insert(&self, order: &CtpInputOrderField) -> anyhow::Result<()> {
+        let mut state = self.state();
+        state.order_inserts.push(order.clone());
+        let callbacks = self
+            .recording
+            .on_order_insert
+            .iter()
+            .filter(|callback| callback_order_ref(callback) == Some(order.order_ref));
+        Self::replay(state.trader_tx.as_ref(), callbacks)
+    }
+
+    fn req_order_action(&self, action: &CtpInputOrderActionField) -> anyhow::Result<()> {
+        let mut state = self.state();
+        state.order_actions.push(action.clone());
+        let callbacks = self
+            .recording
+            .on_order_action
+            .iter()
+            .filter(|callback| callback_order_ref(callback) == Some(action.order_ref));
+        Self::replay(state.trader_tx.as_ref(), callbacks)
+    }
+
+    fn req_qry_instrument(&self) -> anyhow::Result<()> {
+        let state = self.state();
+        Self::replay(
+            state.trader_tx.as_ref(),
+            self.recording.on_qry_instrument.iter(),
+        )
+    }
+
+    fn req_qry_investor_position(&self) -> anyhow::Result<()> {
+        let state = self.state();
+        if self.recording.on_qry_investor_position.is_empty() {
+            let empty = CtpCallback::RspQryInvestorPosition {
+                position: None,
+                is_last: true,
+            };
+            return Self::replay(state.trader_tx.as_ref(), std::iter::once(&empty));
+        }
+        Self::replay(
+            state.trader_tx.as_ref(),
+            self.recording.on_qry_investor_position.iter(),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+    use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+    use super::*;
+    use crate::common::testing::load_test_json;
+
+    #[fixture]
+    fn front() -> MockCtpFront {
+        MockCtpFront::from_json(&load_test_json("recording_shfe_session.json")).unwrap()
+    }
+
+    fn drain(rx: &mut UnboundedReceiver<CtpCallback>) -> Vec<CtpCallback> {
+        let mut callbacks = Vec::new();
+        while let Ok(callback) = rx.try_recv() {
+            callbacks.push(callback);
+        }
+        callbacks
+    }
+
+    #[rstest]
+    fn test_connect_replays_recorded_login(front: MockCtpFront) {
+        let (tx, mut rx) = unbounded_channel();
+
+        CtpTraderApi::connect(&front, &CtpLoginParams::default(), tx).unwrap();
+
+        let callbacks = drain(&mut rx);
+        assert!(matches!(callbacks[0], CtpCallback::FrontConnected));
+        let CtpCallback::RspUserLogin { login, .. } = &callbacks[1] else {
+            panic!("Expected login, was {:?}", callbacks[1]);
+        };
+        assert_eq!(login.session_id, 123_456);
+    }
+
+    #[rstest]
+    fn test_connect_without_recording_sends_default_login() {
+        let front = MockCtpFront::new(MockCtpRecording::default());
+        let (tx, mut rx) = unbounded_channel();
+
+        CtpMdApi::connect(&front, &CtpLoginParams::default(), tx).unwrap();
+
+        let callbacks = drain(&mut rx);
+        assert_eq!(callbacks.len(), 2);
+        assert!(matches!(
+            &callbacks[1],
+            CtpCallback::RspUserLogin { login, .. } if login.front_id == 1 && login.session_id == 1
+        ));
+    }
+
+    #[rstest]
+    fn test_subscribe_replays_market_data_for_instrument(front: MockCtpFront) {
+        let (tx, mut rx) = unbounded_channel();
+        CtpMdApi::connect(&front, &CtpLoginParams::default(), tx).unwrap();
+        drain(&mut rx);
+
+        front
+            .subscribe_market_data(&[Ustr::from("rb2505")])
+            .unwrap();
+
+        let callbacks = drain(&mut rx);
+        assert_eq!(callbacks.len(), 4);
+        assert!(matches!(callbacks[0], CtpCallback::RspSubMarketData { .. }));
+        assert!(callbacks[1..].iter().all(|callback| matches!(
+            callback,
+            CtpCallback::RtnDepthMarketData(md) if md.instrument_id == Ustr::from("rb2505")
+        )));
+        assert_eq!(front.subscriptions(), vec![Ustr::from("rb2505")]);
+    }
+
+    #[rstest]
+    fn test_request_when_not_connected_returns_error(front: MockCtpFront) {
+        assert!(front.req_qry_instrument().is_err());
+        assert!(
+            front
+                .push_trader_callback(CtpCallback::FrontConnected)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,192 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Abstractions over the CTP market data and trader fronts.
+//!
+//! The native CTP API delivers all responses through SPI callbacks on its own threads. A front
+//! binding forwards each callback as a [`CtpCallback`] over an unbounded channel, which the
+//! data and execution clients consume on their own task or thread.
+
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+use ustr::Ustr;
+
+pub mod mock;
+pub mod models;
+
+use self::models::{
+    CtpDepthMarketDataField, CtpInputOrderActionField, CtpInputOrderField, CtpInstrumentField,
+    CtpInvestorPositionField, CtpOrderField, CtpRspInfo, CtpRspUserLogin, CtpTradeField,
+};
+
+/// A callback received from a CTP front (one variant per `CThostFtdc*Spi` method used).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum CtpCallback {
+    /// `OnFrontConnected`.
+    FrontConnected,
+    /// `OnFrontDisconnected`.
+    FrontDisconnected { reason: i32 },
+    /// `OnRspUserLogin`.
+    RspUserLogin {
+        login: CtpRspUserLogin,
+        info: CtpRspInfo,
+    },
+    /// `OnRspQryInstrument`.
+    RspQryInstrument {
+        instrument: CtpInstrumentField,
+        is_last: bool,
+    },
+    /// `OnRspQryInvestorPosition`.
+    RspQryInvestorPosition {
+        position: Option<CtpInvestorPositionField>,
+        is_last: bool,
+    },
+    /// `OnRspSubMarketData`.
+    RspSubMarketData {
+        instrument_id: Ustr,
+        info: CtpRspInfo,
+    },
+    /// `OnRtnDepthMarketData`.
+    RtnDepthMarketData(CtpDepthMarketDataField),
+    /// `OnRspOrderInsert` (the CTP front rejected the order).
+    RspOrderInsert {
+        order: CtpInputOrderField,
+        info: CtpRspInfo,
+    },
+    /// `OnErrRtnOrderInsert` (the exchange rejected the order).
+    ErrRtnOrderInsert {
+        order: CtpInputOrderField,
+        info: CtpRspInfo,
+    },
+    /// `OnRspOrderAction` (the CTP front rejected the order action).
+    RspOrderAction {
+        action: CtpInputOrderActionField,
+        info: CtpRspInfo,
+    },
+    /// `OnErrRtnOrderAction` (the exchange rejected the order action).
+    ErrRtnOrderAction {
+        action: CtpInputOrderActionField,
+        info: CtpRspInfo,
+    },
+    /// `OnRtnOrder`.
+    RtnOrder(CtpOrderField),
+    /// `OnRtnTrade`.
+    RtnTrade(CtpTradeField),
+    /// `OnRspError`.
+    RspError { info: CtpRspInfo },
+}
+
+/// The login credentials for a CTP front.
+#[derive(Clone, Debug, Default)]
+pub struct CtpLoginParams {
+    /// The broker ID.
+    pub broker_id: String,
+    /// The user ID.
+    pub user_id: String,
+    /// The password.
+    pub password: String,
+    /// The application ID used for terminal authentication (trader front only).
+    pub app_id: Option<String>,
+    /// The authentication code used for terminal authentication (trader front only).
+    pub auth_code: Option<String>,
+}
+
+/// Abstraction over a CTP market data front (`CThostFtdcMdApi`).
+pub trait CtpMdApi: Send + Sync + Debug {
+    /// Connects to the front, logs in and forwards all callbacks to `callback_tx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the front cannot be connected.
+    fn connect(
+        &self,
+        params: &CtpLoginParams,
+        callback_tx: UnboundedSender<CtpCallback>,
+    ) -> anyhow::Result<()>;
+
+    /// Disconnects from the front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the front fails to disconnect.
+    fn disconnect(&self) -> anyhow::Result<()>;
+
+    /// Subscribes to depth market data for the given instrument codes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    fn subscribe_market_data(&self, instrument_ids: &[Ustr]) -> anyhow::Result<()>;
+
+    /// Unsubscribes from depth market data for the given instrument codes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    fn unsubscribe_market_data(&self, instrument_ids: &[Ustr]) -> anyhow::Result<()>;
+}
+
+/// Abstraction over a CTP trader front (`CThostFtdcTraderApi`).
+pub trait CtpTraderApi: Send + Sync + Debug {
+    /// Connects to the front, authenticates, logs in, confirms the settlement statement
+    /// and forwards all callbacks to `callback_tx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the front cannot be connected.
+    fn connect(
+        &self,
+        params: &CtpLoginParams,
+        callback_tx: UnboundedSender<CtpCallback>,
+    ) -> anyhow::Result<()>;
+
+    /// Disconnects from the front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the front fails to disconnect.
+    fn disconnect(&self) -> anyhow::Result<()>;
+
+    /// Sends an order insert request (`ReqOrderInsert`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    fn req_order_insert(&self, order: &CtpInputOrderField) -> anyhow::Result<()>;
+
+    /// Sends an order action request (`ReqOrderAction`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    fn req_order_action(&self, action: &CtpInputOrderActionField) -> anyhow::Result<()>;
+
+    /// Queries all instruments (`ReqQryInstrument`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    fn req_qry_instrument(&self) -> anyhow::Result<()>;
+
+    /// Queries the investor positions (`ReqQryInvestorPosition`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be sent.
+    fn req_qry_investor_position(&self) -> anyhow::Result<()>;
+}
@@ -0,0 +1,351 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Data models mirroring the CTP API field structs (`CThostFtdc*Field`).
+//!
+//! Field names follow the CTP headers so recorded callbacks can be deserialized directly.
+//! Character code fields are represented by the enums in [`crate::common::enums`].
+
+use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::common::enums::{
+    CtpActionFlag, CtpDirection, CtpExchange, CtpHedgeFlag, CtpOffsetFlag, CtpOptionsType,
+    CtpOrderPriceType, CtpOrderStatus, CtpPosiDirection, CtpProductClass, CtpTimeCondition,
+    CtpVolumeCondition,
+};
+
+/// Response information attached to CTP `OnRsp*` and `OnErrRtn*` callbacks (`CThostFtdcRspInfoField`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpRspInfo {
+    /// The error code, zero on success.
+    #[serde(rename = "ErrorID")]
+    pub error_id: i32,
+    /// The error message (converted from GBK by the front binding).
+    pub error_msg: String,
+}
+
+impl CtpRspInfo {
+    /// Returns whether the response represents an error.
+    #[must_use]
+    pub const fn is_error(&self) -> bool {
+        self.error_id != 0
+    }
+}
+
+/// User login response (`CThostFtdcRspUserLoginField`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpRspUserLogin {
+    /// The current trading day (YYYYMMDD).
+    pub trading_day: String,
+    /// The broker ID.
+    #[serde(rename = "BrokerID")]
+    pub broker_id: Ustr,
+    /// The user ID.
+    #[serde(rename = "UserID")]
+    pub user_id: Ustr,
+    /// The front ID assigned to this connection.
+    #[serde(rename = "FrontID")]
+    pub front_id: i32,
+    /// The session ID assigned to this connection.
+    #[serde(rename = "SessionID")]
+    pub session_id: i32,
+    /// The maximum order reference used so far in this session.
+    pub max_order_ref: String,
+}
+
+/// Instrument definition (`CThostFtdcInstrumentField`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpInstrumentField {
+    /// The instrument code, e.g. `rb2501`.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The listing exchange.
+    #[serde(rename = "ExchangeID")]
+    pub exchange_id: CtpExchange,
+    /// The instrument name.
+    pub instrument_name: String,
+    /// The product code, e.g. `rb`.
+    #[serde(rename = "ProductID")]
+    pub product_id: Ustr,
+    /// The product class.
+    pub product_class: CtpProductClass,
+    /// The contract multiplier.
+    pub volume_multiple: i32,
+    /// The minimum price increment.
+    pub price_tick: f64,
+    /// The listing date (YYYYMMDD).
+    pub open_date: String,
+    /// The expiry date (YYYYMMDD).
+    pub expire_date: String,
+    /// The maximum volume for a limit order.
+    pub max_limit_order_volume: i32,
+    /// The minimum volume for a limit order.
+    pub min_limit_order_volume: i32,
+    /// The long margin ratio.
+    pub long_margin_ratio: f64,
+    /// The short margin ratio.
+    pub short_margin_ratio: f64,
+    /// The underlying instrument code (options only).
+    #[serde(rename = "UnderlyingInstrID", default)]
+    pub underlying_instr_id: Ustr,
+    /// The strike price (options only).
+    #[serde(default)]
+    pub strike_price: f64,
+    /// The option kind (options only).
+    #[serde(default)]
+    pub options_type: Option<CtpOptionsType>,
+    /// Whether the instrument is currently tradable.
+    pub is_trading: i32,
+}
+
+/// Level 1 depth market data (`CThostFtdcDepthMarketDataField`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpDepthMarketDataField {
+    /// The trading day (YYYYMMDD).
+    pub trading_day: String,
+    /// The calendar day of the update (YYYYMMDD).
+    ///
+    /// DCE reports the trading day rather than the calendar day during the night session.
+    #[serde(default)]
+    pub action_day: String,
+    /// The instrument code.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The last traded price.
+    pub last_price: f64,
+    /// The cumulative traded volume for the trading day.
+    pub volume: i64,
+    /// The cumulative turnover for the trading day.
+    pub turnover: f64,
+    /// The open interest.
+    pub open_interest: f64,
+    /// The upper price limit.
+    pub upper_limit_price: f64,
+    /// The lower price limit.
+    pub lower_limit_price: f64,
+    /// The update time (HH:MM:SS).
+    pub update_time: String,
+    /// The millisecond component of the update time.
+    pub update_millisec: i32,
+    /// The best bid price.
+    pub bid_price1: f64,
+    /// The best bid volume.
+    pub bid_volume1: i32,
+    /// The best ask price.
+    pub ask_price1: f64,
+    /// The best ask volume.
+    pub ask_volume1: i32,
+}
+
+/// Input order request (`CThostFtdcInputOrderField`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpInputOrderField {
+    /// The broker ID.
+    #[serde(rename = "BrokerID")]
+    pub broker_id: Ustr,
+    /// The investor ID.
+    #[serde(rename = "InvestorID")]
+    pub investor_id: Ustr,
+    /// The instrument code.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The listing exchange.
+    #[serde(rename = "ExchangeID")]
+    pub exchange_id: CtpExchange,
+    /// The order reference, unique within the session.
+    pub order_ref: Ustr,
+    /// The order direction.
+    pub direction: CtpDirection,
+    /// The offset flag (CTP supports combination offsets, only the first leg is used).
+    pub comb_offset_flag: CtpOffsetFlag,
+    /// The hedge flag.
+    pub comb_hedge_flag: CtpHedgeFlag,
+    /// The order price type.
+    pub order_price_type: CtpOrderPriceType,
+    /// The limit price (zero for market orders).
+    pub limit_price: f64,
+    /// The order volume.
+    pub volume_total_original: i32,
+    /// The time condition.
+    pub time_condition: CtpTimeCondition,
+    /// The volume condition.
+    pub volume_condition: CtpVolumeCondition,
+}
+
+/// Order action request (`CThostFtdcInputOrderActionField`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpInputOrderActionField {
+    /// The broker ID.
+    #[serde(rename = "BrokerID")]
+    pub broker_id: Ustr,
+    /// The investor ID.
+    #[serde(rename = "InvestorID")]
+    pub investor_id: Ustr,
+    /// The instrument code.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The listing exchange.
+    #[serde(rename = "ExchangeID")]
+    pub exchange_id: CtpExchange,
+    /// The order reference of the order to act on.
+    pub order_ref: Ustr,
+    /// The front ID of the session which inserted the order.
+    #[serde(rename = "FrontID")]
+    pub front_id: i32,
+    /// The session ID of the session which inserted the order.
+    #[serde(rename = "SessionID")]
+    pub session_id: i32,
+    /// The action to perform.
+    pub action_flag: CtpActionFlag,
+}
+
+/// Order state (`CThostFtdcOrderField`), pushed on every change via `OnRtnOrder`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpOrderField {
+    /// The instrument code.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The listing exchange.
+    #[serde(rename = "ExchangeID")]
+    pub exchange_id: CtpExchange,
+    /// The order reference.
+    pub order_ref: Ustr,
+    /// The front ID of the session which inserted the order.
+    #[serde(rename = "FrontID")]
+    pub front_id: i32,
+    /// The session ID of the session which inserted the order.
+    #[serde(rename = "SessionID")]
+    pub session_id: i32,
+    /// The exchange assigned order ID (empty until the exchange accepts the order).
+    #[serde(rename = "OrderSysID", default)]
+    pub order_sys_id: String,
+    /// The order direction.
+    pub direction: CtpDirection,
+    /// The offset flag.
+    pub comb_offset_flag: CtpOffsetFlag,
+    /// The order price type.
+    pub order_price_type: CtpOrderPriceType,
+    /// The limit price.
+    pub limit_price: f64,
+    /// The original order volume.
+    pub volume_total_original: i32,
+    /// The volume traded so far.
+    pub volume_traded: i32,
+    /// The time condition.
+    pub time_condition: CtpTimeCondition,
+    /// The volume condition.
+    pub volume_condition: CtpVolumeCondition,
+    /// The order status.
+    pub order_status: CtpOrderStatus,
+    /// The status message.
+    #[serde(default)]
+    pub status_msg: String,
+    /// The trading day (YYYYMMDD).
+    pub trading_day: String,
+    /// The calendar day the order was inserted (YYYYMMDD).
+    pub insert_date: String,
+    /// The time the order was inserted (HH:MM:SS).
+    pub insert_time: String,
+    /// The time the order was canceled (HH:MM:SS, empty if not canceled).
+    #[serde(default)]
+    pub cancel_time: String,
+}
+
+impl CtpOrderField {
+    /// Returns the session scoped key which uniquely identifies the order.
+    #[must_use]
+    pub fn order_key(&self) -> String {
+        format_order_key(self.front_id, self.session_id, &self.order_ref)
+    }
+}
+
+/// Trade (`CThostFtdcTradeField`), pushed on every fill via `OnRtnTrade`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpTradeField {
+    /// The instrument code.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The listing exchange.
+    #[serde(rename = "ExchangeID")]
+    pub exchange_id: CtpExchange,
+    /// The order reference of the filled order.
+    pub order_ref: Ustr,
+    /// The exchange assigned order ID.
+    #[serde(rename = "OrderSysID")]
+    pub order_sys_id: String,
+    /// The exchange assigned trade ID (unique per exchange and direction).
+    #[serde(rename = "TradeID")]
+    pub trade_id: String,
+    /// The trade direction.
+    pub direction: CtpDirection,
+    /// The offset flag of the fill.
+    pub offset_flag: CtpOffsetFlag,
+    /// The fill price.
+    pub price: f64,
+    /// The fill volume.
+    pub volume: i32,
+    /// The calendar day of the trade (YYYYMMDD).
+    pub trade_date: String,
+    /// The time of the trade (HH:MM:SS).
+    pub trade_time: String,
+    /// The trading day (YYYYMMDD).
+    pub trading_day: String,
+}
+
+/// Investor position (`CThostFtdcInvestorPositionField`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CtpInvestorPositionField {
+    /// The instrument code.
+    #[serde(rename = "InstrumentID")]
+    pub instrument_id: Ustr,
+    /// The listing exchange.
+    #[serde(rename = "ExchangeID")]
+    pub exchange_id: CtpExchange,
+    /// The position direction.
+    pub posi_direction: CtpPosiDirection,
+    /// The total position.
+    pub position: i32,
+    /// The position opened during the current trading day.
+    pub today_position: i32,
+    /// The position volume carried from the previous trading day (before today's closes).
+    pub yd_position: i32,
+}
+
+impl CtpInvestorPositionField {
+    /// Returns the currently held yesterday position (total less today's position).
+    #[must_use]
+    pub const fn remaining_yd_position(&self) -> i32 {
+        self.position - self.today_position
+    }
+}
+
+/// Returns the session scoped order key `{front_id}-{session_id}-{order_ref}`.
+///
+/// CTP order references are only unique within a front session, so the key is used as the
+/// ant venue order ID until the exchange assigns an `OrderSysID`.
+#[must_use]
+pub fn format_order_key(front_id: i32, session_id: i32, order_ref: &str) -> String {
+    format!("{front_id}-{session_id}-{}", order_ref.trim())
+}
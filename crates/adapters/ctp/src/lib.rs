@@ -1,14 +1,61 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! [antTrader](http://anttrader.io) adapter for the China futures exchanges via **CTP**.
+//!
+//! The `ant-ctp` crate provides a [`CtpDataClient`] and [`CtpExecutionClient`] for trading
+//! futures and options listed on SHFE, DCE, CZCE, CFFEX, INE and GFEX through the CTP
+//! (Comprehensive Transaction Platform) market data and trader fronts.
+//!
+//! The native CTP API is callback driven. This crate models each callback as a [`CtpCallback`]
+//! and abstracts the fronts behind the [`CtpMdApi`] and [`CtpTraderApi`] traits, so the clients
+//! can be driven by a native binding or by the [`MockCtpFront`] which replays recorded callbacks.
+//!
+//! # Platform
+//!
+//! [antTrader](http://anttrader.io) is an open-source, high-performance, production-grade
+//! algorithmic trading platform, providing quantitative traders with the ability to backtest
+//! portfolios of automated trading strategies on historical data with an event-driven engine,
+//! and also deploy those same strategies live, with no code changes.
+//!
+//! antTrader's design, architecture, and implementation philosophy prioritizes software correctness and safety at the
+//! highest level, with the aim of supporting mission-critical, trading system backtesting and live deployment workloads.
+
+#![warn(rustc::all)]
+#![deny(unsafe_code)]
+#![deny(nonstandard_style)]
+#![deny(missing_debug_implementations)]
+#![deny(clippy::missing_panics_doc)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+pub mod common;
+pub mod config;
+pub mod data;
+pub mod execution;
+pub mod factories;
+pub mod front;
+
+// Re-exports for convenient access to primary types
+pub use crate::{
+    common::{
+        enums::{CtpDirection, CtpExchange, CtpOffsetFlag, CtpOrderStatus},
+        offset::{CtpPositionLedger, OffsetLeg},
+    },
+    config::{CtpDataClientConfig, CtpExecClientConfig},
+    data::CtpDataClient,
+    execution::CtpExecutionClient,
+    factories::{CtpDataClientFactory, CtpExecutionClientFactory},
+    front::{CtpCallback, CtpMdApi, CtpTraderApi, mock::MockCtpFront},
+};
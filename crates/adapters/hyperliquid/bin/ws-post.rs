@@ -13,15 +13,16 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-//! Minimal WS post example: info (l2Book) and a stubbed order action.
+//! Minimal WS post example: info (l2Book) and a signed order action.
 
 use std::time::Duration;
 
 use ant_hyperliquid::{
-    common::consts::{HyperliquidNetwork, ws_url},
+    common::{consts::ws_url, credential::Secrets},
+    signing::{HyperliquidEip712Signer, NonceManager, SignerId},
     websocket::{
         client::HyperliquidWebSocketClient,
-        messages::{ActionPayload, ActionRequest, SignatureData, TimeInForceRequest},
+        messages::{ActionPayload, ActionRequest, TimeInForceRequest},
         post::{Grouping, OrderBuilder},
     },
 };
@@ -37,7 +38,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .with_max_level(LevelFilter::INFO)
         .init();
 
-    let network = HyperliquidNetwork::from_env();
+    // Requires HYPERLIQUID_PK (and optionally HYPERLIQUID_VAULT and HYPERLIQUID_NET)
+    let secrets = Secrets::from_env()?;
+    let network = secrets.network;
     let ws_url = ws_url(network);
     info!(component = "ws_post", %ws_url, ?network, "connecting");
     let mut client = HyperliquidWebSocketClient::connect(ws_url).await?;
@@ -63,7 +66,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     if !should_send {
         warn!(
             component = "ws_post",
-            "skipping action: set HL_SEND=1 to send the stubbed order"
+            "skipping action: set HL_SEND=1 to send the order"
         );
         return Ok(());
     }
@@ -76,7 +79,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
-    // === ACTION (stub): place a post-only limit (requires real signature!) ===
+    // === ACTION: place a post-only limit ===
     let action: ActionRequest = OrderBuilder::new()
         .grouping(Grouping::Na)
         .push_limit(
@@ -86,20 +89,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             /*sz*/ "0.001", // size
             /*reduce_only*/ false,
             TimeInForceRequest::Alo, // post-only
-            Some("test-cloid-1".to_string()),
+            Some("0x00000000000000000000000000000001".to_string()),
         )
         .build();
 
-    // TODO: sign properly; below is a placeholder signature (r,s,v must be valid!)
+    let signer = HyperliquidEip712Signer::new(&secrets.private_key, network)?;
+    let nonce = NonceManager::new().next(SignerId::from(signer.address().as_str()))?;
+    let signature = signer.sign_l1_action(&action, nonce, secrets.vault_address.as_ref())?;
     let payload = ActionPayload {
         action,
-        nonce: 0, // e.g., time-based nonce or your NonceManager
-        signature: SignatureData {
-            r: "0x0".into(),
-            s: "0x0".into(),
-            v: "0x1b".into(),
-        },
-        vault_address: None,
+        nonce: nonce.as_millis() as u64,
+        signature,
+        vault_address: secrets.vault_address.map(|vault| vault.to_hex()),
     };
 
     match client
@@ -108,7 +109,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     {
         Ok(resp) => info!(component = "ws_post", ?resp, "action response"),
         Err(e) => {
-            warn!(component = "ws_post", error = %e, "action failed (expected with dummy signature)")
+            warn!(component = "ws_post", error = %e, "action failed")
         }
     }
 
@@ -15,6 +15,11 @@
 
 use std::str::FromStr;
 
+use alloy_primitives::keccak256;
+use ant_model::{
+    enums::{OrderStatus, OrderType},
+    identifiers::ClientOrderId,
+};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serializer};
 
@@ -66,6 +71,48 @@ where
     }
 }
 
+/// Returns the Hyperliquid client order ID (cloid) for the `client_order_id`.
+///
+/// A cloid must be a 128-bit hex string, so it is taken from the keccak hash of the client order
+/// ID. The mapping is deterministic, allowing orders to be matched after a restart.
+pub fn client_order_id_to_cloid(client_order_id: &ClientOrderId) -> String {
+    let hash = keccak256(client_order_id.as_str());
+    format!("0x{}", hex::encode(&hash[..16]))
+}
+
+/// Parses a Hyperliquid order status (from `orderUpdates` or `orderStatus`).
+///
+/// Returns `None` for unrecognized statuses.
+pub fn parse_order_status(status: &str) -> Option<OrderStatus> {
+    match status {
+        "open" => Some(OrderStatus::Accepted),
+        "triggered" => Some(OrderStatus::Triggered),
+        "filled" => Some(OrderStatus::Filled),
+        "canceled" | "scheduledCancel" => Some(OrderStatus::Canceled),
+        "rejected" => Some(OrderStatus::Rejected),
+        // E.g. `marginCanceled` or `reduceOnlyCanceled`
+        s if s.ends_with("Canceled") => Some(OrderStatus::Canceled),
+        // E.g. `tickRejected` or `badAloPxRejected`
+        s if s.ends_with("Rejected") => Some(OrderStatus::Rejected),
+        _ => None,
+    }
+}
+
+/// Parses a Hyperliquid order type (from `frontendOpenOrders`), e.g. `Stop Market`.
+///
+/// Returns `None` for unrecognized order types.
+pub fn parse_order_type(order_type: &str) -> Option<OrderType> {
+    match order_type {
+        "Market" => Some(OrderType::Market),
+        "Limit" => Some(OrderType::Limit),
+        "Stop Market" => Some(OrderType::StopMarket),
+        "Stop Limit" => Some(OrderType::StopLimit),
+        "Take Profit Market" => Some(OrderType::MarketIfTouched),
+        "Take Profit Limit" => Some(OrderType::LimitIfTouched),
+        _ => None,
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -156,4 +203,47 @@ mod tests {
         assert_eq!(test_struct.value, parsed.value);
         assert_eq!(None, parsed.optional_value);
     }
+
+    #[rstest]
+    fn test_client_order_id_to_cloid() {
+        let cloid = client_order_id_to_cloid(&ClientOrderId::from("O-20250101-000000-001-001-1"));
+
+        assert_eq!(cloid.len(), 34);
+        assert!(cloid.starts_with("0x"));
+        assert!(cloid[2..].chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(
+            cloid,
+            client_order_id_to_cloid(&ClientOrderId::from("O-20250101-000000-001-001-1"))
+        );
+        assert_ne!(
+            cloid,
+            client_order_id_to_cloid(&ClientOrderId::from("O-20250101-000000-001-001-2"))
+        );
+    }
+
+    #[rstest]
+    #[case("open", Some(OrderStatus::Accepted))]
+    #[case("triggered", Some(OrderStatus::Triggered))]
+    #[case("filled", Some(OrderStatus::Filled))]
+    #[case("canceled", Some(OrderStatus::Canceled))]
+    #[case("marginCanceled", Some(OrderStatus::Canceled))]
+    #[case("scheduledCancel", Some(OrderStatus::Canceled))]
+    #[case("rejected", Some(OrderStatus::Rejected))]
+    #[case("badAloPxRejected", Some(OrderStatus::Rejected))]
+    #[case("unknown", None)]
+    fn test_parse_order_status(#[case] status: &str, #[case] expected: Option<OrderStatus>) {
+        assert_eq!(parse_order_status(status), expected);
+    }
+
+    #[rstest]
+    #[case("Market", Some(OrderType::Market))]
+    #[case("Limit", Some(OrderType::Limit))]
+    #[case("Stop Market", Some(OrderType::StopMarket))]
+    #[case("Stop Limit", Some(OrderType::StopLimit))]
+    #[case("Take Profit Market", Some(OrderType::MarketIfTouched))]
+    #[case("Take Profit Limit", Some(OrderType::LimitIfTouched))]
+    #[case("Twap", None)]
+    fn test_parse_order_type(#[case] order_type: &str, #[case] expected: Option<OrderType>) {
+        assert_eq!(parse_order_type(order_type), expected);
+    }
 }
@@ -15,7 +15,11 @@
 
 use std::fmt;
 
+use ahash::AHashMap;
 use serde::{Deserialize, Serialize};
+use ustr::Ustr;
+
+use crate::http::models::{HyperliquidMeta, HyperliquidSpotMeta};
 
 /// Represents an asset ID for Hyperliquid.
 ///
@@ -77,6 +81,105 @@ impl fmt::Display for HyperliquidAssetId {
     }
 }
 
+/// Returns the instrument symbol of the perpetual for the `coin`, e.g. `BTC-USD-PERP`.
+pub fn perp_symbol(coin: &str) -> String {
+    format!("{coin}-USD-PERP")
+}
+
+/// Returns the instrument symbol of the spot pair, e.g. `PURR-USDC-SPOT`.
+pub fn spot_symbol(base: &str, quote: &str) -> String {
+    format!("{base}-{quote}-SPOT")
+}
+
+/// Represents a tradable asset resolved from the Hyperliquid metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperliquidAsset {
+    /// The asset ID used in exchange actions.
+    pub id: HyperliquidAssetId,
+    /// The coin identifying the asset in info requests and user events (e.g. "BTC" or "@107").
+    pub coin: Ustr,
+    /// The number of decimal places for size.
+    pub sz_decimals: u32,
+}
+
+/// Maps instrument symbols to Hyperliquid assets, and asset coins back to symbols.
+///
+/// Perpetuals are keyed by [`perp_symbol`] with the asset ID of their position in the perp
+/// universe, spot pairs by [`spot_symbol`] with an asset ID of 10000 plus their pair index.
+#[derive(Debug, Clone, Default)]
+pub struct HyperliquidAssetIndex {
+    assets: AHashMap<Ustr, HyperliquidAsset>,
+    symbols: AHashMap<Ustr, Ustr>,
+}
+
+impl HyperliquidAssetIndex {
+    /// Creates a new [`HyperliquidAssetIndex`] from the perp and spot metadata.
+    pub fn new(meta: &HyperliquidMeta, spot_meta: &HyperliquidSpotMeta) -> Self {
+        let mut index = Self::default();
+
+        for (i, asset) in meta.universe.iter().enumerate() {
+            index.insert(
+                &perp_symbol(&asset.name),
+                HyperliquidAsset {
+                    id: HyperliquidAssetId::perp(i as u32),
+                    coin: Ustr::from(&asset.name),
+                    sz_decimals: asset.sz_decimals,
+                },
+            );
+        }
+
+        let tokens: AHashMap<u32, _> = spot_meta
+            .tokens
+            .iter()
+            .map(|token| (token.index, token))
+            .collect();
+        for pair in &spot_meta.universe {
+            let (Some(base), Some(quote)) =
+                (tokens.get(&pair.tokens[0]), tokens.get(&pair.tokens[1]))
+            else {
+                tracing::warn!("Unknown tokens for spot pair {}", pair.name);
+                continue;
+            };
+            index.insert(
+                &spot_symbol(&base.name, &quote.name),
+                HyperliquidAsset {
+                    id: HyperliquidAssetId::spot(pair.index),
+                    coin: Ustr::from(&pair.name),
+                    sz_decimals: base.sz_decimals,
+                },
+            );
+        }
+
+        index
+    }
+
+    fn insert(&mut self, symbol: &str, asset: HyperliquidAsset) {
+        let symbol = Ustr::from(symbol);
+        self.symbols.insert(asset.coin, symbol);
+        self.assets.insert(symbol, asset);
+    }
+
+    /// Returns the asset for the instrument `symbol`.
+    pub fn asset(&self, symbol: &str) -> Option<&HyperliquidAsset> {
+        self.assets.get(&Ustr::from(symbol))
+    }
+
+    /// Returns the instrument symbol for the asset `coin`.
+    pub fn symbol(&self, coin: &str) -> Option<Ustr> {
+        self.symbols.get(&Ustr::from(coin)).copied()
+    }
+
+    /// Returns the number of assets in the index.
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns whether the index has no assets.
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -113,4 +216,45 @@ mod tests {
         assert!(asset_id.is_builder_perp());
         assert_eq!(asset_id.base_index(), 7);
     }
+
+    #[rstest]
+    fn test_asset_index() {
+        let meta: HyperliquidMeta = serde_json::from_str(
+            r#"{"universe": [{"name": "BTC", "szDecimals": 5}, {"name": "ETH", "szDecimals": 4}]}"#,
+        )
+        .unwrap();
+        let spot_meta: HyperliquidSpotMeta = serde_json::from_str(
+            r#"{
+                "tokens": [
+                    {"name": "USDC", "szDecimals": 8, "index": 0},
+                    {"name": "PURR", "szDecimals": 0, "index": 1},
+                    {"name": "HYPE", "szDecimals": 2, "index": 150}
+                ],
+                "universe": [
+                    {"name": "PURR/USDC", "tokens": [1, 0], "index": 0},
+                    {"name": "@107", "tokens": [150, 0], "index": 107},
+                    {"name": "@999", "tokens": [999, 0], "index": 999}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let index = HyperliquidAssetIndex::new(&meta, &spot_meta);
+
+        assert_eq!(index.len(), 4);
+        let eth = index.asset("ETH-USD-PERP").unwrap();
+        assert_eq!(eth.id, HyperliquidAssetId::perp(1));
+        assert_eq!(eth.coin, "ETH");
+        assert_eq!(eth.sz_decimals, 4);
+
+        let hype = index.asset("HYPE-USDC-SPOT").unwrap();
+        assert_eq!(hype.id.to_raw(), 10_107);
+        assert_eq!(hype.coin, "@107");
+        assert_eq!(hype.sz_decimals, 2);
+
+        assert_eq!(index.symbol("@107").unwrap(), "HYPE-USDC-SPOT");
+        assert_eq!(index.symbol("PURR/USDC").unwrap(), "PURR-USDC-SPOT");
+        assert_eq!(index.symbol("BTC").unwrap(), "BTC-USD-PERP");
+        assert!(index.symbol("@999").is_none());
+    }
 }
@@ -12,3 +12,137 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
+
+//! Configuration structures for the Hyperliquid adapter.
+//!
+//! A private key which is not provided is read from the `HYPERLIQUID_PK` environment variable,
+//! and a vault address from `HYPERLIQUID_VAULT`. URLs which are not provided default to the
+//! mainnet (or testnet) endpoints.
+
+use ant_model::identifiers::{AccountId, TraderId};
+
+use crate::common::consts::{HyperliquidNetwork, ws_url};
+
+/// Configuration for the Hyperliquid execution client.
+#[derive(Clone)]
+pub struct HyperliquidExecClientConfig {
+    /// The trader ID for the client.
+    pub trader_id: TraderId,
+    /// The account ID for the client.
+    pub account_id: AccountId,
+    /// The EVM private key of the signing wallet (read from `HYPERLIQUID_PK` if `None`).
+    pub private_key: Option<String>,
+    /// The vault or sub-account address to trade on behalf of (read from `HYPERLIQUID_VAULT`
+    /// if `None`).
+    pub vault_address: Option<String>,
+    /// The address whose orders, fills and margin are tracked, required when signing with an
+    /// agent (API) wallet. Defaults to the vault address, or else the signing wallet address.
+    pub account_address: Option<String>,
+    /// The WebSocket URL override.
+    pub base_url_ws: Option<String>,
+    /// If the testnet should be used, which also changes the signed action source.
+    pub testnet: bool,
+    /// The maximum slippage in basis points from the mid price for market orders, which
+    /// Hyperliquid executes as aggressive IOC limit orders.
+    pub market_order_slippage_bps: u32,
+    /// The timeout in seconds for WebSocket post requests.
+    pub post_timeout_secs: u64,
+}
+
+impl std::fmt::Debug for HyperliquidExecClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(HyperliquidExecClientConfig))
+            .field("trader_id", &self.trader_id)
+            .field("account_id", &self.account_id)
+            .field(
+                "private_key",
+                &self.private_key.as_ref().map(|_| "***redacted***"),
+            )
+            .field("vault_address", &self.vault_address)
+            .field("account_address", &self.account_address)
+            .field("base_url_ws", &self.base_url_ws)
+            .field("testnet", &self.testnet)
+            .field("market_order_slippage_bps", &self.market_order_slippage_bps)
+            .field("post_timeout_secs", &self.post_timeout_secs)
+            .finish()
+    }
+}
+
+impl HyperliquidExecClientConfig {
+    /// Creates a new [`HyperliquidExecClientConfig`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, account_id: AccountId) -> Self {
+        Self {
+            trader_id,
+            account_id,
+            private_key: None,
+            vault_address: None,
+            account_address: None,
+            base_url_ws: None,
+            testnet: false,
+            market_order_slippage_bps: 500,
+            post_timeout_secs: 10,
+        }
+    }
+
+    /// Returns the network the client trades on.
+    #[must_use]
+    pub const fn network(&self) -> HyperliquidNetwork {
+        if self.testnet {
+            HyperliquidNetwork::Testnet
+        } else {
+            HyperliquidNetwork::Mainnet
+        }
+    }
+
+    /// Returns the WebSocket URL.
+    #[must_use]
+    pub fn ws_url(&self) -> String {
+        self.base_url_ws
+            .clone()
+            .unwrap_or_else(|| ws_url(self.network()).to_string())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::common::consts::{HYPERLIQUID_TESTNET_WS_URL, HYPERLIQUID_WS_URL};
+
+    #[rstest]
+    fn test_exec_client_config_urls() {
+        let mut config = HyperliquidExecClientConfig::new(
+            TraderId::from("TRADER-001"),
+            AccountId::from("HYPERLIQUID-001"),
+        );
+        assert_eq!(config.network(), HyperliquidNetwork::Mainnet);
+        assert_eq!(config.ws_url(), HYPERLIQUID_WS_URL);
+
+        config.testnet = true;
+        assert_eq!(config.network(), HyperliquidNetwork::Testnet);
+        assert_eq!(config.ws_url(), HYPERLIQUID_TESTNET_WS_URL);
+
+        config.base_url_ws = Some("ws://127.0.0.1:8080/ws".to_string());
+        assert_eq!(config.ws_url(), "ws://127.0.0.1:8080/ws");
+    }
+
+    #[rstest]
+    fn test_exec_client_config_debug_redacts_private_key() {
+        let mut config = HyperliquidExecClientConfig::new(
+            TraderId::from("TRADER-001"),
+            AccountId::from("HYPERLIQUID-001"),
+        );
+        config.private_key = Some(format!("0x{}", "ab".repeat(32)));
+
+        let debug = format!("{config:?}");
+
+        assert!(!debug.contains("abab"));
+        assert!(debug.contains("***redacted***"));
+    }
+}
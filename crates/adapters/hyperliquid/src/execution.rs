@@ -0,0 +1,1595 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the Hyperliquid live execution client.
+//!
+//! Orders are placed, modified and canceled with signed exchange actions posted over the
+//! WebSocket, while order updates and fills are streamed from the `orderUpdates` and
+//! `userEvents` channels of the tracked user. Reports for reconciliation are requested with
+//! info posts over the same connection.
+//!
+//! WebSocket messages arrive on the shared runtime and are queued to the client, which must be
+//! drained from the engine thread with [`HyperliquidExecutionClient::drain_events`] so that order
+//! events are published on the thread owning the message bus.
+//!
+//! Order actions are signed on the engine thread and posted from the shared runtime rather than
+//! waiting for the venue, and their responses are queued to the client in the same way.
+
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+use ahash::{AHashMap, AHashSet};
+use ant_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+        GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount, QueryOrder,
+        SubmitOrder, SubmitOrderList,
+    },
+    runtime::{block_on, get_runtime},
+};
+use ant_core::{UnixNanos, env::get_env_var};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient, base::BaseExecutionClient};
+use ant_model::{
+    accounts::AccountAny,
+    enums::{
+        AccountType, LiquiditySide, OmsType, OrderSide, OrderStatus, OrderType,
+        PositionSideSpecified, PriceType, TimeInForce,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, Symbol, TradeId,
+        Venue, VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, Currency, MarginBalance, Money, Price, Quantity},
+};
+use anyhow::Context;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+    task::JoinHandle,
+};
+use tracing::{debug, error, info, warn};
+
+use crate::{
+    common::{
+        consts::HYPERLIQUID_VENUE,
+        credential::{EvmPrivateKey, VaultAddress, normalize_address},
+        parse::{client_order_id_to_cloid, parse_order_status, parse_order_type},
+        types::{HyperliquidAsset, HyperliquidAssetIndex},
+    },
+    config::HyperliquidExecClientConfig,
+    http::models::{
+        HyperliquidActionStatus, HyperliquidClearinghouseState, HyperliquidExchangeResponse,
+        HyperliquidMeta, HyperliquidOpenOrder, HyperliquidSpotMeta,
+    },
+    signing::{HyperliquidEip712Signer, NonceManager, SignerId},
+    websocket::{
+        client::HyperliquidWebSocketClient,
+        messages::{
+            ActionPayload, ActionRequest, CancelByCloidRequest, HyperliquidWsMessage,
+            ModifyRequest, OrderRequest, OrderTypeRequest, PostResponsePayload, TimeInForceRequest,
+            TpSlRequest, WsFillData, WsNonUserCancelData, WsOrderData, WsUserEventData,
+        },
+    },
+};
+
+/// The grouping of orders placed without TP/SL attachments.
+const HYPERLIQUID_ORDER_GROUPING: &str = "na";
+
+/// An order action posted to Hyperliquid, whose response is handled on the engine thread.
+#[derive(Debug)]
+enum PendingAction {
+    Submit(Box<SubmitOrder>),
+    Cancel(Vec<OrderAny>),
+    Modify {
+        cmd: ModifyOrder,
+        venue_order_id: VenueOrderId,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Option<Price>,
+    },
+}
+
+/// A message queued to the client from the shared runtime.
+#[derive(Debug)]
+enum ExecMessage {
+    Ws(HyperliquidWsMessage),
+    ActionResponse {
+        action: PendingAction,
+        result: anyhow::Result<Vec<HyperliquidActionStatus>>,
+    },
+}
+
+/// Posts a signed exchange action, returning the status of each order in the action.
+async fn post_action(
+    ws_client: &HyperliquidWebSocketClient,
+    payload: ActionPayload,
+    timeout: Duration,
+) -> anyhow::Result<Vec<HyperliquidActionStatus>> {
+    match ws_client.post_action_raw(payload, timeout).await? {
+        PostResponsePayload::Action { payload } => {
+            let response: HyperliquidExchangeResponse = serde_json::from_value(payload)?;
+            Ok(response.into_statuses()?)
+        }
+        PostResponsePayload::Error { payload } => anyhow::bail!(payload),
+        PostResponsePayload::Info { .. } => {
+            anyhow::bail!("Expected action response, was info")
+        }
+    }
+}
+
+/// A Hyperliquid execution client trading perpetuals and spot pairs.
+///
+/// Hyperliquid nets positions per asset, so the client always uses [`OmsType::Netting`]. Orders
+/// are identified by a cloid derived from their client order ID, and are placed on behalf of the
+/// configured vault or sub-account when one is set.
+pub struct HyperliquidExecutionClient {
+    core: BaseExecutionClient,
+    config: HyperliquidExecClientConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    signer: HyperliquidEip712Signer,
+    vault_address: Option<VaultAddress>,
+    /// The address whose orders, fills and margin are tracked.
+    user: String,
+    nonces: NonceManager,
+    ws_client: Option<Arc<HyperliquidWebSocketClient>>,
+    assets: RefCell<HyperliquidAssetIndex>,
+    /// Client order IDs keyed by their cloid.
+    cloids: RefCell<AHashMap<String, ClientOrderId>>,
+    is_connected: Cell<bool>,
+    event_tx: RefCell<Option<UnboundedSender<ExecMessage>>>,
+    event_rx: RefCell<Option<UnboundedReceiver<ExecMessage>>>,
+    stream_handle: RefCell<Option<JoinHandle<()>>>,
+    /// Orders for which an accepted event has been generated by this client.
+    accepted: RefCell<AHashSet<ClientOrderId>>,
+    /// Trades already applied, as fills may be resent on reconnect.
+    trade_ids: RefCell<AHashSet<TradeId>>,
+}
+
+impl std::fmt::Debug for HyperliquidExecutionClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(HyperliquidExecutionClient))
+            .field("client_id", &self.core.client_id)
+            .field("account_id", &self.core.account_id)
+            .field("user", &self.user)
+            .field("vault_address", &self.vault_address)
+            .field("network", &self.signer.network())
+            .finish()
+    }
+}
+
+impl HyperliquidExecutionClient {
+    /// Creates a new [`HyperliquidExecutionClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The private key is not provided or found in the environment, or is invalid.
+    /// - The vault or account address is invalid.
+    pub fn new(
+        client_id: ClientId,
+        config: HyperliquidExecClientConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> anyhow::Result<Self> {
+        let private_key = config
+            .private_key
+            .clone()
+            .or_else(|| get_env_var("HYPERLIQUID_PK").ok())
+            .context("Hyperliquid private key is required for the execution client")?;
+        let signer =
+            HyperliquidEip712Signer::new(&EvmPrivateKey::new(private_key)?, config.network())?;
+
+        let vault_address = config
+            .vault_address
+            .clone()
+            .or_else(|| get_env_var("HYPERLIQUID_VAULT").ok())
+            .filter(|address| !address.trim().is_empty())
+            .map(|address| VaultAddress::parse(&address))
+            .transpose()?;
+
+        // Agent wallets sign for an account they do not hold, which must then be configured
+        let user = match &config.account_address {
+            Some(address) => normalize_address(address)?,
+            None => vault_address.map_or_else(|| signer.address(), |vault| vault.to_hex()),
+        };
+
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            client_id,
+            *HYPERLIQUID_VENUE,
+            OmsType::Netting,
+            config.account_id,
+            AccountType::Margin,
+            None,
+            clock.clone(),
+            cache.clone(),
+        );
+
+        Ok(Self {
+            core,
+            config,
+            clock,
+            cache,
+            signer,
+            vault_address,
+            user,
+            nonces: NonceManager::new(),
+            ws_client: None,
+            assets: RefCell::new(HyperliquidAssetIndex::default()),
+            cloids: RefCell::new(AHashMap::new()),
+            is_connected: Cell::new(false),
+            event_tx: RefCell::new(None),
+            event_rx: RefCell::new(None),
+            stream_handle: RefCell::new(None),
+            accepted: RefCell::new(AHashSet::new()),
+            trade_ids: RefCell::new(AHashSet::new()),
+        })
+    }
+
+    /// Returns the address whose orders, fills and margin are tracked.
+    #[must_use]
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// Processes all queued WebSocket messages and action responses, returning the number
+    /// processed.
+    ///
+    /// Must be called from the thread owning the message bus, typically on each engine tick.
+    pub fn drain_events(&self) -> usize {
+        let mut count = 0;
+        loop {
+            // Release the receiver borrow before handling, as strategies may react to an event
+            // by sending further commands to the client
+            let msg = match self.event_rx.borrow_mut().as_mut() {
+                Some(rx) => match rx.try_recv() {
+                    Ok(msg) => msg,
+                    Err(_) => break,
+                },
+                None => break,
+            };
+            match msg {
+                ExecMessage::Ws(msg) => self.handle_ws_message(msg),
+                ExecMessage::ActionResponse { action, result } => {
+                    self.handle_action_response(action, result);
+                }
+            }
+            count += 1;
+        }
+        count
+    }
+
+    fn handle_ws_message(&self, msg: HyperliquidWsMessage) {
+        match msg {
+            HyperliquidWsMessage::OrderUpdates { data } => {
+                for update in &data {
+                    self.handle_order_update(update);
+                }
+            }
+            HyperliquidWsMessage::UserEvents { data } => match data {
+                WsUserEventData::Fills { fills } => {
+                    for fill in &fills {
+                        self.handle_fill(fill);
+                    }
+                }
+                WsUserEventData::NonUserCancel { non_user_cancel } => {
+                    for cancel in &non_user_cancel {
+                        self.handle_non_user_cancel(cancel);
+                    }
+                }
+                other => debug!("User event: {other:?}"),
+            },
+            // Post responses are routed to their requests by the WebSocket client
+            HyperliquidWsMessage::Post { .. } => {}
+            HyperliquidWsMessage::SubscriptionResponse { data } => {
+                debug!("Subscribed to {data:?}");
+            }
+            other => debug!("Unhandled WebSocket message: {other:?}"),
+        }
+    }
+
+    fn ws_client(&self) -> anyhow::Result<&Arc<HyperliquidWebSocketClient>> {
+        self.ws_client
+            .as_ref()
+            .context("Hyperliquid execution client not connected")
+    }
+
+    fn post_timeout(&self) -> Duration {
+        Duration::from_secs(self.config.post_timeout_secs)
+    }
+
+    /// Posts an info request, returning the response data.
+    fn post_info<T: DeserializeOwned>(&self, request: serde_json::Value) -> anyhow::Result<T> {
+        let response = block_on(
+            self.ws_client()?
+                .post_info_raw(request, self.post_timeout()),
+        )?;
+        match response {
+            PostResponsePayload::Info { mut payload } => {
+                // The data is wrapped with the request type, e.g. {"type": "meta", "data": ...}
+                if payload.get("type").is_some()
+                    && let Some(data) = payload.get_mut("data")
+                {
+                    return Ok(serde_json::from_value(data.take())?);
+                }
+                Ok(serde_json::from_value(payload)?)
+            }
+            PostResponsePayload::Error { payload } => {
+                anyhow::bail!("Info request failed: {payload}")
+            }
+            PostResponsePayload::Action { .. } => {
+                anyhow::bail!("Expected info response, was action")
+            }
+        }
+    }
+
+    /// Signs an exchange action with the next nonce.
+    fn sign_action(&self, action: ActionRequest) -> anyhow::Result<ActionPayload> {
+        let nonce = self.nonces.next(SignerId::from(self.signer.address()))?;
+        let signature = self
+            .signer
+            .sign_l1_action(&action, nonce, self.vault_address.as_ref())?;
+        Ok(ActionPayload {
+            action,
+            nonce: u64::try_from(nonce.as_millis())?,
+            signature,
+            vault_address: self.vault_address.map(|vault| vault.to_hex()),
+        })
+    }
+
+    /// Signs the exchange `action` and spawns posting it on the shared runtime, so the calling
+    /// thread is not held for the round trip.
+    ///
+    /// The response is queued to be handled for the `pending` action from
+    /// [`HyperliquidExecutionClient::drain_events`], and a failure to sign is handled at once.
+    fn spawn_action(&self, action: ActionRequest, pending: PendingAction) {
+        let tx = self.event_tx.borrow().clone();
+        let prepared = self.ws_client().and_then(|ws_client| {
+            let tx = tx.context("Hyperliquid execution client not connected")?;
+            Ok((Arc::clone(ws_client), tx, self.sign_action(action)?))
+        });
+        let (ws_client, tx, payload) = match prepared {
+            Ok(prepared) => prepared,
+            Err(e) => return self.handle_action_response(pending, Err(e)),
+        };
+
+        let timeout = self.post_timeout();
+        get_runtime().spawn(async move {
+            let result = post_action(&ws_client, payload, timeout).await;
+            if let Err(e) = tx.send(ExecMessage::ActionResponse {
+                action: pending,
+                result,
+            }) {
+                error!("Action response received after disconnect: {e}");
+            }
+        });
+    }
+
+    fn handle_action_response(
+        &self,
+        action: PendingAction,
+        result: anyhow::Result<Vec<HyperliquidActionStatus>>,
+    ) {
+        match action {
+            PendingAction::Submit(cmd) => match result {
+                Ok(statuses) => {
+                    self.handle_submit_status(&cmd, &cmd.order, statuses.into_iter().next());
+                }
+                Err(e) => self.reject(&cmd, &format!("Failed to submit order: {e}")),
+            },
+            PendingAction::Cancel(orders) => self.handle_cancel_statuses(&orders, result),
+            PendingAction::Modify {
+                cmd,
+                venue_order_id,
+                quantity,
+                price,
+                trigger_price,
+            } => {
+                // Hyperliquid assigns a new order ID when the modified order is replaced
+                let new_venue_order_id = result.and_then(|statuses| {
+                    match statuses.into_iter().next() {
+                        Some(HyperliquidActionStatus::Error(reason)) => anyhow::bail!(reason),
+                        Some(HyperliquidActionStatus::Resting { oid, .. }) => {
+                            Ok(VenueOrderId::new(oid.to_string()))
+                        }
+                        _ => Ok(venue_order_id),
+                    }
+                });
+
+                let ts_event = self.clock.borrow().timestamp_ns();
+                match new_venue_order_id {
+                    Ok(new_venue_order_id) => self.core.generate_order_updated(
+                        cmd.strategy_id,
+                        cmd.instrument_id,
+                        cmd.client_order_id,
+                        new_venue_order_id,
+                        quantity,
+                        price,
+                        trigger_price,
+                        ts_event,
+                        new_venue_order_id != venue_order_id,
+                    ),
+                    Err(e) => self.core.generate_order_modify_rejected(
+                        cmd.strategy_id,
+                        cmd.instrument_id,
+                        cmd.client_order_id,
+                        venue_order_id,
+                        &e.to_string(),
+                        ts_event,
+                    ),
+                }
+            }
+        }
+    }
+
+    fn load_assets(&self) -> anyhow::Result<()> {
+        let meta: HyperliquidMeta = self.post_info(json!({ "type": "meta" }))?;
+        let spot_meta: HyperliquidSpotMeta = self.post_info(json!({ "type": "spotMeta" }))?;
+        let assets = HyperliquidAssetIndex::new(&meta, &spot_meta);
+        info!("Loaded {} assets", assets.len());
+        *self.assets.borrow_mut() = assets;
+        Ok(())
+    }
+
+    fn asset(&self, instrument_id: &InstrumentId) -> anyhow::Result<HyperliquidAsset> {
+        self.assets
+            .borrow()
+            .asset(instrument_id.symbol.as_str())
+            .cloned()
+            .with_context(|| format!("No Hyperliquid asset for {instrument_id}"))
+    }
+
+    /// Returns the instrument ID of the asset `coin`.
+    fn coin_instrument_id(&self, coin: &str) -> Option<InstrumentId> {
+        self.assets
+            .borrow()
+            .symbol(coin)
+            .map(|symbol| InstrumentId::new(Symbol::new(symbol), *HYPERLIQUID_VENUE))
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentEnum> {
+        self.cache.borrow().instrument(instrument_id).cloned()
+    }
+
+    /// Registers the cloid of the order so updates can be matched before it is accepted.
+    fn register_cloid(&self, client_order_id: ClientOrderId) {
+        self.cloids
+            .borrow_mut()
+            .insert(client_order_id_to_cloid(&client_order_id), client_order_id);
+    }
+
+    /// Registers the cloids of the open Hyperliquid orders in the cache, e.g. after a restart.
+    fn register_cached_cloids(&self) {
+        let cache = self.cache.borrow();
+        for order in cache.orders(Some(&HYPERLIQUID_VENUE), None, None, None) {
+            if !order.is_closed() {
+                self.register_cloid(order.client_order_id());
+            }
+        }
+    }
+
+    fn cloid_client_order_id(&self, cloid: Option<&str>) -> Option<ClientOrderId> {
+        cloid.and_then(|cloid| self.cloids.borrow().get(cloid).copied())
+    }
+
+    /// Returns the cached order for an update, or `None` for orders not placed by ant.
+    fn report_order(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: &VenueOrderId,
+    ) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        let client_order_id = client_order_id
+            .filter(|id| cache.order(id).is_some())
+            .or_else(|| cache.client_order_id(venue_order_id).copied())?;
+        cache.order(&client_order_id).cloned()
+    }
+
+    /// Generates an accepted event if the order has not yet been accepted.
+    fn ensure_accepted(&self, order: &OrderAny, venue_order_id: VenueOrderId, ts_event: UnixNanos) {
+        if order.status() == OrderStatus::Submitted
+            && self.accepted.borrow_mut().insert(order.client_order_id())
+        {
+            self.core.generate_order_accepted(
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                venue_order_id,
+                ts_event,
+            );
+        }
+    }
+
+    fn handle_order_update(&self, update: &WsOrderData) {
+        let venue_order_id = VenueOrderId::new(update.order.oid.to_string());
+        let client_order_id = self.cloid_client_order_id(update.order.cloid.as_deref());
+        let Some(order) = self.report_order(client_order_id, &venue_order_id) else {
+            debug!("Order update for external order {venue_order_id}");
+            return;
+        };
+
+        if order.is_closed() {
+            debug!("Order {} already closed", order.client_order_id());
+            return;
+        }
+
+        let Some(status) = parse_order_status(&update.status) else {
+            debug!("Order {venue_order_id} status {}", update.status);
+            return;
+        };
+
+        let strategy_id = order.strategy_id();
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let ts_event = millis_to_nanos(update.status_timestamp);
+
+        match status {
+            // Fills are generated from the user events
+            OrderStatus::Accepted | OrderStatus::Filled => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+            }
+            OrderStatus::Triggered => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                if order.status() != OrderStatus::Triggered {
+                    self.core.generate_order_triggered(
+                        strategy_id,
+                        instrument_id,
+                        client_order_id,
+                        venue_order_id,
+                        ts_event,
+                    );
+                }
+            }
+            OrderStatus::Canceled => {
+                // A modify which changes the order ID cancels the order under its previous ID
+                if order
+                    .venue_order_id()
+                    .is_some_and(|current| current != venue_order_id)
+                {
+                    debug!("Order {client_order_id} replaced {venue_order_id}");
+                    return;
+                }
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_canceled(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Rejected => self.core.generate_order_rejected(
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                &update.status,
+                ts_event,
+                update.status == "badAloPxRejected",
+            ),
+            status => debug!("Order {client_order_id} status {status:?}"),
+        }
+    }
+
+    /// Handles an order canceled by Hyperliquid, e.g. for insufficient margin.
+    fn handle_non_user_cancel(&self, cancel: &WsNonUserCancelData) {
+        let venue_order_id = VenueOrderId::new(cancel.oid.to_string());
+        let Some(order) = self.report_order(None, &venue_order_id) else {
+            debug!("Cancel of external order {venue_order_id}");
+            return;
+        };
+        if order.is_closed() {
+            return;
+        }
+        self.core.generate_order_canceled(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            self.clock.borrow().timestamp_ns(),
+        );
+    }
+
+    fn handle_fill(&self, fill: &WsFillData) {
+        let venue_order_id = VenueOrderId::new(fill.oid.to_string());
+        let client_order_id = self.cloid_client_order_id(fill.cloid.as_deref());
+        let Some(order) = self.report_order(client_order_id, &venue_order_id) else {
+            debug!("Fill for external order {venue_order_id}");
+            return;
+        };
+
+        let trade_id = TradeId::new(fill.tid.to_string());
+        if !self.trade_ids.borrow_mut().insert(trade_id) {
+            debug!("Duplicate fill {trade_id}");
+            return;
+        }
+
+        let Some(instrument) = self.instrument(&order.instrument_id()) else {
+            error!("Instrument {} not found in cache", order.instrument_id());
+            return;
+        };
+        let (last_qty, last_px, commission) = match parse_fill(fill, &instrument) {
+            Ok(fill) => fill,
+            Err(e) => {
+                error!("Failed to parse fill {trade_id}: {e}");
+                return;
+            }
+        };
+        let ts_event = millis_to_nanos(fill.time);
+
+        self.ensure_accepted(&order, venue_order_id, ts_event);
+
+        self.core.generate_order_filled(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            venue_order_id,
+            venue_position_id(order.instrument_id(), order.strategy_id()),
+            trade_id,
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            instrument.quote_currency(),
+            commission,
+            liquidity_side(fill.crossed),
+            ts_event,
+        );
+    }
+
+    /// Builds the request placing the `order` with the `quantity` and prices.
+    ///
+    /// Hyperliquid has no native market orders, so market orders are sent as IOC limit orders
+    /// through the cached mid (or last) price, and market trigger orders with a limit through
+    /// the trigger price.
+    fn order_request(
+        &self,
+        order: &OrderAny,
+        instrument: &InstrumentEnum,
+        quantity: Quantity,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+    ) -> anyhow::Result<OrderRequest> {
+        let asset = self.asset(&order.instrument_id())?;
+        let side = order.order_side();
+
+        let (order_type, limit_price) = match order.order_type() {
+            OrderType::Market => {
+                let mid = self.reference_price(&order.instrument_id())?;
+                let order_type = OrderTypeRequest::Limit {
+                    tif: TimeInForceRequest::Ioc,
+                };
+                (order_type, self.aggressive_price(mid, side, instrument)?)
+            }
+            OrderType::Limit => {
+                let price = price.context("Limit order has no price")?;
+                let order_type = OrderTypeRequest::Limit {
+                    tif: limit_time_in_force(order)?,
+                };
+                (order_type, price.as_decimal())
+            }
+            order_type @ (OrderType::StopMarket
+            | OrderType::StopLimit
+            | OrderType::MarketIfTouched
+            | OrderType::LimitIfTouched) => {
+                let trigger_price = trigger_price.context("Trigger order has no trigger price")?;
+                let is_market = matches!(
+                    order_type,
+                    OrderType::StopMarket | OrderType::MarketIfTouched
+                );
+                let limit_price = if is_market {
+                    self.aggressive_price(trigger_price.as_decimal(), side, instrument)?
+                } else {
+                    price.context("Trigger order has no price")?.as_decimal()
+                };
+                let tpsl = if matches!(order_type, OrderType::StopMarket | OrderType::StopLimit) {
+                    TpSlRequest::Sl
+                } else {
+                    TpSlRequest::Tp
+                };
+                let order_type = OrderTypeRequest::Trigger {
+                    is_market,
+                    trigger_px: format_decimal(trigger_price.as_decimal()),
+                    tpsl,
+                };
+                (order_type, limit_price)
+            }
+            order_type => anyhow::bail!("Unsupported order type {order_type:?}"),
+        };
+
+        Ok(OrderRequest {
+            a: asset.id.to_raw(),
+            b: side == OrderSide::Buy,
+            p: format_decimal(limit_price),
+            s: format_decimal(quantity.as_decimal()),
+            r: order.is_reduce_only(),
+            t: order_type,
+            c: Some(client_order_id_to_cloid(&order.client_order_id())),
+        })
+    }
+
+    /// Returns the cached mid price of the instrument, or the last trade price without quotes.
+    ///
+    /// The cache is used rather than requesting mids, so that submitting does not wait on the
+    /// venue, which requires market data for the instrument.
+    fn reference_price(&self, instrument_id: &InstrumentId) -> anyhow::Result<Decimal> {
+        let cache = self.cache.borrow();
+        cache
+            .price(instrument_id, PriceType::Mid)
+            .or_else(|| cache.price(instrument_id, PriceType::Last))
+            .map(|price| price.as_decimal())
+            .with_context(|| format!("No market price for {instrument_id} to price market order"))
+    }
+
+    /// Returns a limit price through the `reference` price by the configured slippage.
+    fn aggressive_price(
+        &self,
+        reference: Decimal,
+        side: OrderSide,
+        instrument: &InstrumentEnum,
+    ) -> anyhow::Result<Decimal> {
+        let slippage = Decimal::from(self.config.market_order_slippage_bps) / Decimal::from(10_000);
+        let price = match side {
+            OrderSide::Buy => reference * (Decimal::ONE + slippage),
+            _ => reference * (Decimal::ONE - slippage),
+        };
+        // Hyperliquid accepts prices with at most five significant figures
+        let price = price
+            .round_sf(5)
+            .with_context(|| format!("Invalid price {price}"))?;
+        Ok(price.round_dp(u32::from(instrument.price_precision())))
+    }
+
+    fn reject(&self, cmd: &SubmitOrder, reason: &str) {
+        error!("Rejecting order {}: {reason}", cmd.client_order_id);
+        self.core.generate_order_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            reason,
+            self.clock.borrow().timestamp_ns(),
+            false,
+        );
+    }
+
+    fn submit(&self, cmd: &SubmitOrder, order: &OrderAny) {
+        if !self.is_connected.get() {
+            self.reject(cmd, "Hyperliquid execution client not connected");
+            return;
+        }
+
+        let Some(instrument) = self.instrument(&order.instrument_id()) else {
+            return self.reject(
+                cmd,
+                &format!("Instrument {} not found", order.instrument_id()),
+            );
+        };
+
+        let request = match self.order_request(
+            order,
+            &instrument,
+            order.quantity(),
+            order.price(),
+            order.trigger_price(),
+        ) {
+            Ok(request) => request,
+            Err(e) => return self.reject(cmd, &e.to_string()),
+        };
+
+        self.core.generate_order_submitted(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            self.clock.borrow().timestamp_ns(),
+        );
+        self.register_cloid(order.client_order_id());
+
+        let action = ActionRequest::Order {
+            orders: vec![request],
+            grouping: HYPERLIQUID_ORDER_GROUPING.to_string(),
+        };
+        let mut cmd = cmd.clone();
+        cmd.order = order.clone();
+        self.spawn_action(action, PendingAction::Submit(Box::new(cmd)));
+    }
+
+    fn handle_submit_status(
+        &self,
+        cmd: &SubmitOrder,
+        order: &OrderAny,
+        status: Option<HyperliquidActionStatus>,
+    ) {
+        let ts_event = self.clock.borrow().timestamp_ns();
+        match status {
+            // Fills are generated from the user events
+            Some(
+                HyperliquidActionStatus::Resting { oid, .. }
+                | HyperliquidActionStatus::Filled { oid, .. },
+            ) => {
+                let venue_order_id = VenueOrderId::new(oid.to_string());
+                if let Some(order) = self.report_order(Some(cmd.client_order_id), &venue_order_id) {
+                    self.ensure_accepted(&order, venue_order_id, ts_event);
+                }
+            }
+            Some(HyperliquidActionStatus::Error(reason)) => {
+                // Post-only orders which would cross are rejected with "Post only order would
+                // have immediately matched"
+                let due_post_only = order.is_post_only() && reason.starts_with("Post only");
+                self.core.generate_order_rejected(
+                    cmd.strategy_id,
+                    cmd.instrument_id,
+                    cmd.client_order_id,
+                    &reason,
+                    ts_event,
+                    due_post_only,
+                );
+            }
+            // Accepted from the order stream once Hyperliquid assigns the order ID
+            Some(
+                HyperliquidActionStatus::WaitingForTrigger
+                | HyperliquidActionStatus::WaitingForFill,
+            ) => debug!("Order {} pending", cmd.client_order_id),
+            status => warn!(
+                "Unexpected status for order {}: {status:?}",
+                cmd.client_order_id
+            ),
+        }
+    }
+
+    /// Cancels the `orders` by cloid, generating a canceled or cancel rejected event for each
+    /// once the response is handled.
+    fn cancel(&self, orders: Vec<OrderAny>) {
+        if orders.is_empty() {
+            return;
+        }
+
+        let cancels = orders
+            .iter()
+            .map(|order| {
+                Ok(CancelByCloidRequest {
+                    asset: self.asset(&order.instrument_id())?.id.to_raw(),
+                    cloid: client_order_id_to_cloid(&order.client_order_id()),
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>();
+        match cancels {
+            Ok(cancels) => self.spawn_action(
+                ActionRequest::CancelByCloid { cancels },
+                PendingAction::Cancel(orders),
+            ),
+            Err(e) => self.handle_cancel_statuses(&orders, Err(e)),
+        }
+    }
+
+    fn handle_cancel_statuses(
+        &self,
+        orders: &[OrderAny],
+        result: anyhow::Result<Vec<HyperliquidActionStatus>>,
+    ) {
+        let ts_event = self.clock.borrow().timestamp_ns();
+        let statuses = match result {
+            Ok(statuses) => statuses,
+            Err(e) => {
+                for order in orders {
+                    self.cancel_rejected(order, &e.to_string(), ts_event);
+                }
+                return;
+            }
+        };
+
+        for (order, status) in orders.iter().zip(statuses) {
+            match (status, order.venue_order_id()) {
+                (HyperliquidActionStatus::Error(reason), _) => {
+                    self.cancel_rejected(order, &reason, ts_event);
+                }
+                (_, Some(venue_order_id)) => self.core.generate_order_canceled(
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    order.client_order_id(),
+                    venue_order_id,
+                    ts_event,
+                ),
+                // Canceled from the order stream once the order ID is known
+                (_, None) => debug!("Canceled order {} before accepted", order.client_order_id()),
+            }
+        }
+    }
+
+    fn cancel_rejected(&self, order: &OrderAny, reason: &str, ts_event: UnixNanos) {
+        self.core.generate_order_cancel_rejected(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order
+                .venue_order_id()
+                .unwrap_or_else(|| VenueOrderId::from("NONE")),
+            reason,
+            ts_event,
+        );
+    }
+
+    fn modify(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.is_connected.get(),
+            "Hyperliquid execution client not connected"
+        );
+
+        let order = self
+            .cache
+            .borrow()
+            .order(&cmd.client_order_id)
+            .cloned()
+            .with_context(|| format!("Order {} not found", cmd.client_order_id))?;
+        let venue_order_id = order
+            .venue_order_id()
+            .with_context(|| format!("Order {} not yet accepted", cmd.client_order_id))?;
+        let instrument = self
+            .instrument(&cmd.instrument_id)
+            .with_context(|| format!("Instrument {} not found", cmd.instrument_id))?;
+
+        let quantity = cmd.quantity.unwrap_or_else(|| order.quantity());
+        let trigger_price = cmd.trigger_price.or_else(|| order.trigger_price());
+        let request = self.order_request(
+            &order,
+            &instrument,
+            quantity,
+            cmd.price.or_else(|| order.price()),
+            trigger_price,
+        )?;
+        let price =
+            Price::from_decimal(Decimal::from_str(&request.p)?, instrument.price_precision())?;
+
+        let modify = ModifyRequest {
+            oid: venue_order_id.as_str().parse()?,
+            order: request,
+        };
+        self.spawn_action(
+            ActionRequest::Modify {
+                modifies: vec![modify],
+            },
+            PendingAction::Modify {
+                cmd: cmd.clone(),
+                venue_order_id,
+                quantity,
+                price,
+                trigger_price,
+            },
+        );
+        Ok(())
+    }
+
+    fn request_account_state(&self) -> anyhow::Result<()> {
+        let state: HyperliquidClearinghouseState = self.request_clearinghouse_state()?;
+        let usdc = Currency::USDC();
+        let total = Money::from_decimal(
+            Decimal::from_str(&state.margin_summary.account_value)?,
+            usdc,
+        )?;
+        let margin_used = Decimal::from_str(&state.margin_summary.total_margin_used)?;
+        let locked =
+            Money::from_decimal(margin_used.clamp(Decimal::ZERO, total.as_decimal()), usdc)?;
+        let balance = AccountBalance::new_checked(total, locked, total - locked)?;
+
+        let ts_event = if state.time == 0 {
+            self.clock.borrow().timestamp_ns()
+        } else {
+            millis_to_nanos(state.time)
+        };
+        self.core
+            .generate_account_state(vec![balance], vec![], true, ts_event)
+    }
+
+    fn request_clearinghouse_state(&self) -> anyhow::Result<HyperliquidClearinghouseState> {
+        self.post_info(json!({ "type": "clearinghouseState", "user": self.user }))
+    }
+
+    fn request_order_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let orders: Vec<HyperliquidOpenOrder> =
+            self.post_info(json!({ "type": "frontendOpenOrders", "user": self.user }))?;
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        let mut reports = Vec::with_capacity(orders.len());
+        for order in &orders {
+            if let Some(report) = self.parse_open_order(order, ts_init)?
+                && instrument_id.is_none_or(|id| report.instrument_id == id)
+            {
+                reports.push(report);
+            }
+        }
+        Ok(reports)
+    }
+
+    fn parse_open_order(
+        &self,
+        order: &HyperliquidOpenOrder,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let Some(instrument) = self
+            .coin_instrument_id(&order.coin)
+            .and_then(|id| self.instrument(&id))
+        else {
+            debug!("No instrument for open order on {}", order.coin);
+            return Ok(None);
+        };
+        let size_precision = instrument.size_precision();
+        let price_precision = instrument.price_precision();
+
+        let venue_order_id = VenueOrderId::new(order.oid.to_string());
+        let client_order_id = self
+            .cloid_client_order_id(order.cloid.as_deref())
+            .or_else(|| {
+                self.cache
+                    .borrow()
+                    .client_order_id(&venue_order_id)
+                    .copied()
+            });
+        let orig_sz = Decimal::from_str(&order.orig_sz)?;
+        let filled_sz = orig_sz - Decimal::from_str(&order.sz)?;
+        let order_status = if filled_sz.is_zero() {
+            OrderStatus::Accepted
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        let order_type = order
+            .order_type
+            .as_deref()
+            .and_then(parse_order_type)
+            .unwrap_or(OrderType::Limit);
+        let time_in_force = match order.tif.as_deref() {
+            Some("Ioc") => TimeInForce::Ioc,
+            _ => TimeInForce::Gtc,
+        };
+        let ts_accepted = millis_to_nanos(order.timestamp);
+
+        let mut report = OrderStatusReport::new(
+            self.core.account_id,
+            instrument.id(),
+            client_order_id,
+            venue_order_id,
+            order.side.into(),
+            order_type,
+            time_in_force,
+            order_status,
+            Quantity::from_decimal(orig_sz, size_precision)?,
+            Quantity::from_decimal(filled_sz, size_precision)?,
+            ts_accepted,
+            ts_accepted,
+            ts_init,
+            None,
+        )
+        .with_price(Price::from_decimal(
+            Decimal::from_str(&order.limit_px)?,
+            price_precision,
+        )?)
+        .with_post_only(order.tif.as_deref() == Some("Alo"))
+        .with_reduce_only(order.reduce_only);
+        if order.is_trigger
+            && let Some(trigger_px) = &order.trigger_px
+        {
+            report = report.with_trigger_price(Price::from_decimal(
+                Decimal::from_str(trigger_px)?,
+                price_precision,
+            )?);
+        }
+        Ok(Some(report))
+    }
+
+    fn request_fill_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        let fills: Vec<WsFillData> =
+            self.post_info(json!({ "type": "userFills", "user": self.user }))?;
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let start = self.lookback_start(lookback_mins);
+
+        let mut reports = Vec::with_capacity(fills.len());
+        for fill in &fills {
+            let Some(instrument) = self
+                .coin_instrument_id(&fill.coin)
+                .and_then(|id| self.instrument(&id))
+            else {
+                debug!("No instrument for fill on {}", fill.coin);
+                continue;
+            };
+            let ts_event = millis_to_nanos(fill.time);
+            if instrument_id.is_some_and(|id| id != instrument.id())
+                || start.is_some_and(|start| ts_event < start)
+            {
+                continue;
+            }
+
+            let venue_order_id = VenueOrderId::new(fill.oid.to_string());
+            let client_order_id = self
+                .cloid_client_order_id(fill.cloid.as_deref())
+                .or_else(|| {
+                    self.cache
+                        .borrow()
+                        .client_order_id(&venue_order_id)
+                        .copied()
+                });
+            let (last_qty, last_px, commission) = parse_fill(fill, &instrument)?;
+            reports.push(FillReport::new(
+                self.core.account_id,
+                instrument.id(),
+                venue_order_id,
+                TradeId::new(fill.tid.to_string()),
+                parse_side(&fill.side),
+                last_qty,
+                last_px,
+                commission,
+                liquidity_side(fill.crossed),
+                client_order_id,
+                None,
+                ts_event,
+                ts_init,
+                None,
+            ));
+        }
+        Ok(reports)
+    }
+
+    fn request_position_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let state = self.request_clearinghouse_state()?;
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        let mut reports = Vec::with_capacity(state.asset_positions.len());
+        for asset_position in &state.asset_positions {
+            let position = &asset_position.position;
+            let Some(instrument) = self
+                .coin_instrument_id(&position.coin)
+                .and_then(|id| self.instrument(&id))
+            else {
+                debug!("No instrument for position on {}", position.coin);
+                continue;
+            };
+            if instrument_id.is_some_and(|id| id != instrument.id()) {
+                continue;
+            }
+
+            let size = Decimal::from_str(&position.szi)?;
+            let position_side = if size.is_sign_positive() && !size.is_zero() {
+                PositionSideSpecified::Long
+            } else if size.is_sign_negative() && !size.is_zero() {
+                PositionSideSpecified::Short
+            } else {
+                PositionSideSpecified::Flat
+            };
+            reports.push(PositionStatusReport::new(
+                self.core.account_id,
+                instrument.id(),
+                position_side,
+                Quantity::from_decimal(size.abs(), instrument.size_precision())?,
+                None,
+                ts_init,
+                ts_init,
+                None,
+            ));
+        }
+        Ok(reports)
+    }
+
+    fn lookback_start(&self, lookback_mins: Option<u64>) -> Option<UnixNanos> {
+        let now = self.clock.borrow().timestamp_ns();
+        lookback_mins.map(|mins| {
+            UnixNanos::from(
+                now.as_u64()
+                    .saturating_sub(mins.saturating_mul(60_000_000_000)),
+            )
+        })
+    }
+}
+
+/// Returns the netting position ID for fills, as Hyperliquid has no venue position IDs.
+fn venue_position_id(instrument_id: InstrumentId, strategy_id: StrategyId) -> PositionId {
+    PositionId::new(format!("{instrument_id}-{strategy_id}"))
+}
+
+/// Returns the time in force of a limit order, with post-only orders sent as add liquidity only.
+fn limit_time_in_force(order: &OrderAny) -> anyhow::Result<TimeInForceRequest> {
+    if order.is_post_only() {
+        return Ok(TimeInForceRequest::Alo);
+    }
+    match order.time_in_force() {
+        TimeInForce::Gtc => Ok(TimeInForceRequest::Gtc),
+        TimeInForce::Ioc => Ok(TimeInForceRequest::Ioc),
+        time_in_force => anyhow::bail!("Unsupported time in force {time_in_force:?}"),
+    }
+}
+
+/// Formats the `value` in the canonical wire format, without trailing zeros.
+fn format_decimal(value: Decimal) -> String {
+    value.normalize().to_string()
+}
+
+fn millis_to_nanos(millis: u64) -> UnixNanos {
+    UnixNanos::from(millis.saturating_mul(1_000_000))
+}
+
+fn parse_side(side: &str) -> OrderSide {
+    if side == "B" {
+        OrderSide::Buy
+    } else {
+        OrderSide::Sell
+    }
+}
+
+fn liquidity_side(crossed: bool) -> LiquiditySide {
+    if crossed {
+        LiquiditySide::Taker
+    } else {
+        LiquiditySide::Maker
+    }
+}
+
+/// Parses the quantity, price and commission of the `fill`.
+fn parse_fill(
+    fill: &WsFillData,
+    instrument: &InstrumentEnum,
+) -> anyhow::Result<(Quantity, Price, Money)> {
+    let last_qty =
+        Quantity::from_decimal(Decimal::from_str(&fill.sz)?, instrument.size_precision())?;
+    let last_px = Price::from_decimal(Decimal::from_str(&fill.px)?, instrument.price_precision())?;
+
+    // Fees are charged in the settlement currency, or in the received token for spot buys
+    let currency = [
+        instrument.base_currency(),
+        Some(instrument.settlement_currency()),
+    ]
+    .into_iter()
+    .flatten()
+    .find(|currency| currency.code.as_str() == fill.fee_token)
+    .unwrap_or_else(|| instrument.quote_currency());
+    let commission = Money::from_decimal(Decimal::from_str(&fill.fee)?, currency)?;
+
+    Ok((last_qty, last_px, commission))
+}
+
+impl ExecutionClient for HyperliquidExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.is_connected.get()
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        self.core.venue
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        info!("Started");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        info!("Stopped");
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(cmd, &cmd.order);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // Contingent order lists are not supported, orders are submitted individually
+        for order in &cmd.order_list.orders {
+            let submit = SubmitOrder::new(
+                cmd.trader_id,
+                cmd.client_id,
+                cmd.strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                cmd.venue_order_id,
+                order.clone(),
+                cmd.exec_algorith_id,
+                cmd.position_id,
+                cmd.command_id,
+                cmd.ts_init,
+            )?;
+            self.submit(&submit, order);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        if let Err(e) = self.modify(cmd) {
+            self.core.generate_order_modify_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                &e.to_string(),
+                self.clock.borrow().timestamp_ns(),
+            );
+        }
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        let order = self.cache.borrow().order(&cmd.client_order_id).cloned();
+        match order {
+            Some(order) => self.cancel(vec![order]),
+            None => self.core.generate_order_cancel_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                &format!("Order {} not found", cmd.client_order_id),
+                self.clock.borrow().timestamp_ns(),
+            ),
+        }
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.is_connected.get(),
+            "Hyperliquid execution client not connected"
+        );
+
+        let side = (cmd.order_side != OrderSide::NoOrderSide).then_some(cmd.order_side);
+        let orders: Vec<OrderAny> = self
+            .cache
+            .borrow()
+            .orders_open(None, Some(&cmd.instrument_id), None, side)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.cancel(orders);
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        let mut orders = Vec::with_capacity(cmd.cancels.len());
+        for cancel in &cmd.cancels {
+            let order = self.cache.borrow().order(&cancel.client_order_id).cloned();
+            match order {
+                Some(order) => orders.push(order),
+                None => self.core.generate_order_cancel_rejected(
+                    cancel.strategy_id,
+                    cancel.instrument_id,
+                    cancel.client_order_id,
+                    cancel.venue_order_id,
+                    &format!("Order {} not found", cancel.client_order_id),
+                    self.clock.borrow().timestamp_ns(),
+                ),
+            }
+        }
+        self.cancel(orders);
+        Ok(())
+    }
+
+    fn query_account(&self, cmd: &QueryAccount) -> anyhow::Result<()> {
+        debug!("Query account: {cmd:?}");
+        self.request_account_state()
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        // The order status is queried by cloid, returning {"status": "order", "order": ...}
+        let response: serde_json::Value = self.post_info(json!({
+            "type": "orderStatus",
+            "user": self.user,
+            "oid": client_order_id_to_cloid(&cmd.client_order_id),
+        }))?;
+        match response.get("order") {
+            Some(order) => {
+                let update: WsOrderData = serde_json::from_value(order.clone())?;
+                self.handle_order_update(&update);
+            }
+            None => warn!("Order {} not found", cmd.client_order_id),
+        }
+        Ok(())
+    }
+
+    fn as_live(&self) -> Option<&dyn LiveExecutionClient> {
+        Some(self)
+    }
+}
+
+impl LiveExecutionClient for HyperliquidExecutionClient {
+    fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected.get() {
+            return Ok(());
+        }
+
+        let url = self.config.ws_url();
+        let user = self.user.clone();
+        let mut ws_client = block_on(async {
+            let mut ws_client = HyperliquidWebSocketClient::connect(&url).await?;
+            ws_client.subscribe_order_updates(&user).await?;
+            ws_client.subscribe_user_events(&user).await?;
+            anyhow::Ok(ws_client)
+        })?;
+
+        let mut inbound = ws_client
+            .take_event_receiver()
+            .context("WebSocket event receiver already taken")?;
+        let (tx, rx) = unbounded_channel();
+        *self.event_tx.borrow_mut() = Some(tx.clone());
+        *self.event_rx.borrow_mut() = Some(rx);
+        // Keep the inbound queue drained, as post responses are read from the same connection
+        *self.stream_handle.borrow_mut() = Some(get_runtime().spawn(async move {
+            while let Some(msg) = inbound.recv().await {
+                if tx.send(ExecMessage::Ws(msg)).is_err() {
+                    break;
+                }
+            }
+            debug!("WebSocket stream ended");
+        }));
+        self.ws_client = Some(Arc::new(ws_client));
+
+        self.load_assets()?;
+        self.register_cached_cloids();
+
+        self.is_connected.set(true);
+        self.core.set_connected(true);
+        self.request_account_state()?;
+
+        info!("Connected to {url} for {}", self.user);
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> anyhow::Result<()> {
+        if let Some(ws_client) = self.ws_client.take()
+            && let Err(e) = block_on(ws_client.disconnect())
+        {
+            error!("Failed to close WebSocket: {e}");
+        }
+        if let Some(handle) = self.stream_handle.borrow_mut().take() {
+            handle.abort();
+        }
+        *self.event_tx.borrow_mut() = None;
+        self.drain_events();
+        *self.event_rx.borrow_mut() = None;
+        self.is_connected.set(false);
+        self.core.set_connected(false);
+        info!("Disconnected");
+        Ok(())
+    }
+
+    fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let reports = self.request_order_status_reports(cmd.instrument_id())?;
+        Ok(reports.into_iter().find(|report| {
+            cmd.client_order_id()
+                .is_some_and(|id| report.client_order_id == Some(id))
+                || cmd
+                    .venue_order_id()
+                    .is_some_and(|id| report.venue_order_id.as_str() == id.as_str())
+        }))
+    }
+
+    fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        self.request_order_status_reports(cmd.instrument_id())
+    }
+
+    fn generate_fill_reports(&self, cmd: GenerateFillReports) -> anyhow::Result<Vec<FillReport>> {
+        self.request_fill_reports(cmd.instrument_id(), None)
+    }
+
+    fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        self.request_position_status_reports(cmd.instrument_id())
+    }
+
+    fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        debug!("Generating mass status (lookback_mins={lookback_mins:?})");
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            self.core.venue,
+            ts_init,
+            None,
+        );
+
+        mass_status.add_order_reports(self.request_order_status_reports(None)?);
+        mass_status.add_fill_reports(self.request_fill_reports(None, lookback_mins)?);
+        mass_status.add_position_reports(self.request_position_status_reports(None)?);
+
+        Ok(Some(mass_status))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_venue_position_id() {
+        assert_eq!(
+            venue_position_id(
+                InstrumentId::from("BTC-USD-PERP.HYPERLIQUID"),
+                StrategyId::from("S-001")
+            ),
+            PositionId::from("BTC-USD-PERP.HYPERLIQUID-S-001")
+        );
+    }
+
+    #[rstest]
+    #[case("50000.0", "50000")]
+    #[case("0.00100", "0.001")]
+    #[case("123.45", "123.45")]
+    fn test_format_decimal(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(format_decimal(Decimal::from_str(value).unwrap()), expected);
+    }
+
+    #[rstest]
+    fn test_liquidity_side() {
+        assert_eq!(liquidity_side(true), LiquiditySide::Taker);
+        assert_eq!(liquidity_side(false), LiquiditySide::Maker);
+    }
+}
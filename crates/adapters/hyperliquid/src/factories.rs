@@ -0,0 +1,88 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating Hyperliquid clients.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use ant_common::{cache::Cache, clock::Clock};
+use ant_execution::client::ExecutionClient;
+use ant_model::identifiers::ClientId;
+use ant_system::factories::{ClientConfig, ExecutionClientFactory};
+
+use crate::{
+    common::consts::HYPERLIQUID, config::HyperliquidExecClientConfig,
+    execution::HyperliquidExecutionClient,
+};
+
+impl ClientConfig for HyperliquidExecClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating Hyperliquid execution clients.
+#[derive(Debug)]
+pub struct HyperliquidExecutionClientFactory;
+
+impl HyperliquidExecutionClientFactory {
+    /// Creates a new [`HyperliquidExecutionClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for HyperliquidExecutionClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutionClientFactory for HyperliquidExecutionClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn ExecutionClient>> {
+        let hyperliquid_config = config
+            .as_any()
+            .downcast_ref::<HyperliquidExecClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for HyperliquidExecutionClientFactory. Expected HyperliquidExecClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client = HyperliquidExecutionClient::new(
+            ClientId::from(name),
+            hyperliquid_config.clone(),
+            clock,
+            cache,
+        )?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        HYPERLIQUID
+    }
+
+    fn config_type(&self) -> &'static str {
+        "HyperliquidExecClientConfig"
+    }
+}
@@ -15,7 +15,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::common::enums::HyperliquidSide;
+use crate::{
+    common::enums::HyperliquidSide,
+    http::error::{Error, Result},
+};
 
 /// Represents metadata about available markets from `POST /info`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +37,40 @@ pub struct HyperliquidAssetInfo {
     pub sz_decimals: u32,
 }
 
+/// Represents spot metadata from `POST /info` (`spotMeta`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperliquidSpotMeta {
+    /// The tokens which make up the spot pairs.
+    #[serde(default)]
+    pub tokens: Vec<HyperliquidSpotToken>,
+    /// The spot pairs.
+    #[serde(default)]
+    pub universe: Vec<HyperliquidSpotPair>,
+}
+
+/// Represents a spot token from the spot meta endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperliquidSpotToken {
+    /// Token name (e.g., "USDC").
+    pub name: String,
+    /// Number of decimal places for size.
+    #[serde(rename = "szDecimals")]
+    pub sz_decimals: u32,
+    /// Token index referenced by the spot pairs.
+    pub index: u32,
+}
+
+/// Represents a spot pair from the spot meta endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperliquidSpotPair {
+    /// Pair name used as the coin of the pair (e.g., "PURR/USDC" or "@107").
+    pub name: String,
+    /// Base and quote token indices.
+    pub tokens: [u32; 2],
+    /// Pair index, from which the asset ID is derived.
+    pub index: u32,
+}
+
 /// Represents an L2 order book snapshot from `POST /info`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HyperliquidL2Book {
@@ -132,6 +169,126 @@ pub struct HyperliquidOrderInfo {
     pub orig_sz: String,
 }
 
+/// Represents an open order from `POST /info` (`frontendOpenOrders`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HyperliquidOpenOrder {
+    /// Coin symbol.
+    pub coin: String,
+    /// Order side (buy/sell).
+    pub side: HyperliquidSide,
+    /// Limit price.
+    pub limit_px: String,
+    /// Remaining order size.
+    pub sz: String,
+    /// Order ID.
+    pub oid: u64,
+    /// Order timestamp in milliseconds.
+    pub timestamp: u64,
+    /// Original order size.
+    pub orig_sz: String,
+    /// Client order ID.
+    #[serde(default)]
+    pub cloid: Option<String>,
+    /// Order type (e.g., "Limit" or "Stop Market").
+    #[serde(default)]
+    pub order_type: Option<String>,
+    /// Time in force of limit orders.
+    #[serde(default)]
+    pub tif: Option<String>,
+    /// Whether the order is reduce only.
+    #[serde(default)]
+    pub reduce_only: bool,
+    /// Whether the order is a trigger order.
+    #[serde(default)]
+    pub is_trigger: bool,
+    /// Trigger price of trigger orders.
+    #[serde(default)]
+    pub trigger_px: Option<String>,
+}
+
+/// Represents the perpetuals account summary from `POST /info` (`clearinghouseState`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HyperliquidClearinghouseState {
+    /// Margin summary across all positions.
+    pub margin_summary: HyperliquidMarginSummary,
+    /// Amount available to withdraw.
+    pub withdrawable: String,
+    /// Open perpetual positions.
+    #[serde(default)]
+    pub asset_positions: Vec<HyperliquidAssetPosition>,
+    /// Time in milliseconds.
+    #[serde(default)]
+    pub time: u64,
+}
+
+/// Represents a margin summary within the clearinghouse state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HyperliquidMarginSummary {
+    /// Total account value.
+    pub account_value: String,
+    /// Total notional position.
+    pub total_ntl_pos: String,
+    /// Total raw USD.
+    pub total_raw_usd: String,
+    /// Total margin used by positions.
+    pub total_margin_used: String,
+}
+
+/// Represents an entry of the open positions within the clearinghouse state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HyperliquidAssetPosition {
+    /// The position details.
+    pub position: HyperliquidPosition,
+}
+
+/// Represents an open perpetual position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HyperliquidPosition {
+    /// Coin symbol.
+    pub coin: String,
+    /// Signed position size (negative for shorts).
+    pub szi: String,
+    /// Average entry price.
+    #[serde(default)]
+    pub entry_px: Option<String>,
+}
+
+/// Represents the outcome of a single order, cancel or modify within an exchange action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HyperliquidActionStatus {
+    /// The cancel or modify succeeded.
+    Success,
+    /// The order is resting on the book.
+    Resting {
+        /// Order ID.
+        oid: u64,
+        /// Client order ID.
+        #[serde(default)]
+        cloid: Option<String>,
+    },
+    /// The order filled immediately.
+    #[serde(rename_all = "camelCase")]
+    Filled {
+        /// Total filled size.
+        total_sz: String,
+        /// Average fill price.
+        avg_px: String,
+        /// Order ID.
+        oid: u64,
+    },
+    /// The trigger order is waiting for its trigger price.
+    WaitingForTrigger,
+    /// The order is waiting to be filled.
+    WaitingForFill,
+    /// The order, cancel or modify was rejected.
+    Error(String),
+}
+
 /// Represents an exchange action request wrapper for `POST /exchange`.
 #[derive(Debug, Clone, Serialize)]
 pub struct HyperliquidExchangeRequest<T> {
@@ -196,6 +353,35 @@ pub enum HyperliquidExchangeResponse {
     },
 }
 
+impl HyperliquidExchangeResponse {
+    /// Returns the per order statuses of a response to an order, cancel or modify action.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the whole action was rejected or the response cannot be decoded.
+    pub fn into_statuses(self) -> Result<Vec<HyperliquidActionStatus>> {
+        match self {
+            Self::Status { status, response } if status == "ok" => {
+                let statuses = response
+                    .get("data")
+                    .and_then(|data| data.get("statuses"))
+                    .cloned()
+                    .unwrap_or_default();
+                if statuses.is_null() {
+                    return Ok(Vec::new());
+                }
+                serde_json::from_value(statuses).map_err(Error::Serde)
+            }
+            Self::Status { response, .. } => Err(Error::exchange(
+                response
+                    .as_str()
+                    .map_or_else(|| response.to_string(), str::to_string),
+            )),
+            Self::Error { error } => Err(Error::exchange(error)),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
@@ -239,4 +425,73 @@ mod tests {
             _ => panic!("Expected status response"),
         }
     }
+
+    #[rstest]
+    fn test_spot_meta_deserialization() {
+        let json = r#"{
+            "tokens": [
+                {"name": "USDC", "szDecimals": 8, "weiDecimals": 8, "index": 0},
+                {"name": "PURR", "szDecimals": 0, "weiDecimals": 5, "index": 1}
+            ],
+            "universe": [{"name": "PURR/USDC", "tokens": [1, 0], "index": 0, "isCanonical": true}]
+        }"#;
+
+        let meta: HyperliquidSpotMeta = serde_json::from_str(json).unwrap();
+
+        assert_eq!(meta.tokens.len(), 2);
+        assert_eq!(meta.tokens[1].name, "PURR");
+        assert_eq!(meta.universe[0].name, "PURR/USDC");
+        assert_eq!(meta.universe[0].tokens, [1, 0]);
+    }
+
+    #[rstest]
+    fn test_exchange_response_statuses() {
+        let json = r#"{
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": {
+                    "statuses": [
+                        {"resting": {"oid": 77738308}},
+                        {"filled": {"totalSz": "0.02", "avgPx": "1891.4", "oid": 77747314}},
+                        {"error": "Order must have minimum value of $10."},
+                        "success"
+                    ]
+                }
+            }
+        }"#;
+
+        let response: HyperliquidExchangeResponse = serde_json::from_str(json).unwrap();
+        let statuses = response.into_statuses().unwrap();
+
+        assert_eq!(
+            statuses,
+            vec![
+                HyperliquidActionStatus::Resting {
+                    oid: 77738308,
+                    cloid: None
+                },
+                HyperliquidActionStatus::Filled {
+                    total_sz: "0.02".to_string(),
+                    avg_px: "1891.4".to_string(),
+                    oid: 77747314,
+                },
+                HyperliquidActionStatus::Error("Order must have minimum value of $10.".to_string()),
+                HyperliquidActionStatus::Success,
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_exchange_response_error_status() {
+        let json = r#"{"status": "err", "response": "User or API Wallet does not exist."}"#;
+
+        let response: HyperliquidExchangeResponse = serde_json::from_str(json).unwrap();
+        let err = response.into_statuses().unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("User or API Wallet does not exist.")
+        );
+    }
 }
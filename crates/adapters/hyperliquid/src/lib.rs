@@ -48,9 +48,17 @@
 
 pub mod common;
 pub mod config;
+pub mod execution;
+pub mod factories;
 pub mod http;
 pub mod signing;
 pub mod websocket;
 
 #[cfg(feature = "python")]
 pub mod python;
+
+// Re-exports for convenient access to primary types
+pub use crate::{
+    config::HyperliquidExecClientConfig, execution::HyperliquidExecutionClient,
+    factories::HyperliquidExecutionClientFactory, websocket::client::HyperliquidWebSocketClient,
+};
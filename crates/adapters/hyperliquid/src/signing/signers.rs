@@ -13,230 +13,455 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use serde_json::Value;
+//! EIP-712 signing of Hyperliquid exchange actions.
+//!
+//! Trading actions (orders, cancels, modifies) are L1 actions. They are not signed directly.
+//! Instead the keccak hash of the msgpack encoded action, nonce and optional vault address is
+//! wrapped in a "phantom agent", which is signed as EIP-712 typed data under the `Exchange`
+//! domain. The exchange recovers the signer from the signature and compares it with the user (or
+//! an approved agent wallet of the user).
+
+use alloy_primitives::{Address, B256, keccak256};
+use k256::ecdsa::SigningKey;
+use serde::Serialize;
 
 use super::{nonce::TimeNonce, types::HyperliquidActionType};
-use crate::{common::credential::EvmPrivateKey, http::error::Result};
+use crate::{
+    common::{
+        consts::HyperliquidNetwork,
+        credential::{EvmPrivateKey, VaultAddress},
+    },
+    http::error::{Error, Result},
+    websocket::messages::{ActionRequest, SignatureData},
+};
+
+/// The EIP-712 domain type string.
+const EIP712_DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// The EIP-712 type string of the phantom agent signed for L1 actions.
+const AGENT_TYPE: &str = "Agent(string source,bytes32 connectionId)";
+
+/// The name of the EIP-712 domain for L1 actions.
+const L1_DOMAIN_NAME: &str = "Exchange";
+
+/// The version of the EIP-712 domain for L1 actions.
+const L1_DOMAIN_VERSION: &str = "1";
+
+/// The chain ID of the EIP-712 domain for L1 actions, which is fixed regardless of network.
+const L1_CHAIN_ID: u64 = 1337;
 
 /// Request to be signed by the Hyperliquid EIP-712 signer.
 #[derive(Debug, Clone)]
 pub struct SignRequest {
-    pub action: Value,
+    pub action: ActionRequest,
     pub time_nonce: TimeNonce,
     pub action_type: HyperliquidActionType,
+    /// The vault or sub-account the action is made on behalf of.
+    pub vault_address: Option<VaultAddress>,
 }
 
 /// Bundle containing signature for Hyperliquid requests.
 #[derive(Debug, Clone)]
 pub struct SignatureBundle {
-    pub signature: String,
+    pub signature: SignatureData,
 }
 
 /// EIP-712 signer for Hyperliquid.
-#[derive(Debug)]
 pub struct HyperliquidEip712Signer {
-    private_key: EvmPrivateKey,
+    signing_key: SigningKey,
+    address: Address,
+    network: HyperliquidNetwork,
+}
+
+impl std::fmt::Debug for HyperliquidEip712Signer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(HyperliquidEip712Signer))
+            .field("address", &self.address)
+            .field("network", &self.network)
+            .finish_non_exhaustive()
+    }
 }
 
 impl HyperliquidEip712Signer {
-    pub fn new(private_key: EvmPrivateKey) -> Self {
-        Self { private_key }
+    /// Creates a new [`HyperliquidEip712Signer`] signing for the `network`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the private key is not a valid secp256k1 scalar.
+    pub fn new(private_key: &EvmPrivateKey, network: HyperliquidNetwork) -> Result<Self> {
+        let signing_key = SigningKey::from_slice(private_key.as_bytes())
+            .map_err(|_| Error::bad_request("EVM private key is not a valid secp256k1 key"))?;
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+        // The address is derived from the uncompressed key without its 0x04 prefix
+        let address = Address::from_raw_public_key(&public_key.as_bytes()[1..]);
+
+        Ok(Self {
+            signing_key,
+            address,
+            network,
+        })
     }
 
+    /// Signs the action in the `request`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the action cannot be encoded, or for user-signed actions (transfers
+    /// and withdrawals), which are not supported.
     pub fn sign(&self, request: &SignRequest) -> Result<SignatureBundle> {
         let signature = match request.action_type {
-            HyperliquidActionType::L1 => {
-                self.sign_l1_action(&request.action, request.time_nonce)?
-            }
+            HyperliquidActionType::L1 => self.sign_l1_action(
+                &request.action,
+                request.time_nonce,
+                request.vault_address.as_ref(),
+            )?,
             HyperliquidActionType::UserSigned => {
-                self.sign_user_signed_action(&request.action, request.time_nonce)?
+                return Err(Error::bad_request("User-signed actions are not supported"));
             }
         };
 
         Ok(SignatureBundle { signature })
     }
 
-    pub fn sign_l1_action(&self, action: &Value, nonce: TimeNonce) -> Result<String> {
-        let _canonicalized = Self::canonicalize_action(action)?;
-        // TODO: Implement actual EIP-712 signing with self.private_key
-        let _key_hash = self.private_key.to_string().len(); // Use private_key to avoid dead_code warning
-        Ok(format!("0x{:064x}{:064x}", nonce.as_millis(), 1))
-    }
-
-    pub fn sign_user_signed_action(&self, action: &Value, nonce: TimeNonce) -> Result<String> {
-        let _canonicalized = Self::canonicalize_action(action)?;
-        // TODO: Implement actual EIP-712 signing with self.private_key
-        let _key_hash = self.private_key.to_string().len(); // Use private_key to avoid dead_code warning
-        Ok(format!("0x{:064x}{:064x}", nonce.as_millis(), 2))
-    }
-
-    fn canonicalize_action(action: &Value) -> Result<Value> {
-        match action {
-            Value::Object(obj) => {
-                let mut canonicalized = serde_json::Map::new();
-                for (key, value) in obj {
-                    let canon_value = match key.as_str() {
-                        "destination" | "address" | "user" if value.is_string() => {
-                            Value::String(Self::canonicalize_address(value.as_str().unwrap()))
-                        }
-                        "amount" | "px" | "sz" | "price" | "size" if value.is_string() => {
-                            Value::String(Self::canonicalize_decimal(value.as_str().unwrap()))
-                        }
-                        _ => Self::canonicalize_action(value)?,
-                    };
-                    canonicalized.insert(key.clone(), canon_value);
-                }
-                Ok(Value::Object(canonicalized))
-            }
-            Value::Array(arr) => {
-                let canonicalized: Result<Vec<_>> =
-                    arr.iter().map(Self::canonicalize_action).collect();
-                Ok(Value::Array(canonicalized?))
-            }
-            _ => Ok(action.clone()),
-        }
+    /// Signs an L1 `action` with the `nonce`, on behalf of the `vault_address` if provided.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the action cannot be msgpack encoded or the nonce is negative.
+    pub fn sign_l1_action<T: Serialize>(
+        &self,
+        action: &T,
+        nonce: TimeNonce,
+        vault_address: Option<&VaultAddress>,
+    ) -> Result<SignatureData> {
+        let connection_id = action_hash(action, nonce, vault_address)?;
+        let digest = agent_digest(self.network, connection_id);
+        self.sign_digest(digest)
     }
 
-    fn canonicalize_address(addr: &str) -> String {
-        if addr.starts_with("0x") || addr.starts_with("0X") {
-            format!("0x{}", &addr[2..].to_lowercase())
-        } else {
-            format!("0x{}", addr.to_lowercase())
-        }
+    fn sign_digest(&self, digest: B256) -> Result<SignatureData> {
+        let (signature, recovery_id) = self
+            .signing_key
+            .sign_prehash_recoverable(digest.as_slice())
+            .map_err(|e| Error::auth(format!("Failed to sign action: {e}")))?;
+        let bytes = signature.to_bytes();
+
+        Ok(SignatureData {
+            r: format!("0x{}", hex::encode(&bytes[..32])),
+            s: format!("0x{}", hex::encode(&bytes[32..])),
+            v: 27 + recovery_id.to_byte(),
+        })
     }
 
-    fn canonicalize_decimal(decimal: &str) -> String {
-        if let Ok(num) = decimal.parse::<f64>() {
-            if num.fract() == 0.0 {
-                format!("{:.0}", num)
-            } else {
-                let trimmed = format!("{}", num)
-                    .trim_end_matches('0')
-                    .trim_end_matches('.')
-                    .to_string();
-                if trimmed.is_empty() || trimmed == "-" {
-                    "0".to_string()
-                } else {
-                    trimmed
-                }
-            }
-        } else {
-            decimal.to_string()
-        }
+    /// Returns the address of the signing wallet (lowercase, 0x-prefixed).
+    pub fn address(&self) -> String {
+        format!("{:#x}", self.address)
     }
 
-    pub fn address(&self) -> Result<String> {
-        // TODO: Derive actual address from self.private_key
-        let _key_hash = self.private_key.to_string().len(); // Use private_key to avoid dead_code warning
-        Ok("0x0000000000000000000000000000000000000000".to_string())
+    /// Returns the network the signer signs for.
+    pub fn network(&self) -> HyperliquidNetwork {
+        self.network
     }
 }
 
+/// Returns the hash of an L1 `action`, which is used as the connection ID of the phantom agent.
+///
+/// The hash covers the msgpack encoding of the action, the big-endian nonce, and a flag byte
+/// followed by the vault address when trading on behalf of a vault or sub-account.
+///
+/// # Errors
+///
+/// Returns an error if the action cannot be msgpack encoded or the nonce is negative.
+pub fn action_hash<T: Serialize>(
+    action: &T,
+    nonce: TimeNonce,
+    vault_address: Option<&VaultAddress>,
+) -> Result<B256> {
+    let nonce = u64::try_from(nonce.as_millis())
+        .map_err(|_| Error::bad_request(format!("Invalid nonce {nonce}")))?;
+    let mut data = rmp_serde::to_vec_named(action)
+        .map_err(|e| Error::decode(format!("Failed to encode action: {e}")))?;
+    data.extend_from_slice(&nonce.to_be_bytes());
+    match vault_address {
+        Some(vault_address) => {
+            data.push(1);
+            data.extend_from_slice(vault_address.as_bytes());
+        }
+        None => data.push(0),
+    }
+    Ok(keccak256(data))
+}
+
+/// Returns the EIP-712 digest of the phantom agent for the `connection_id`.
+fn agent_digest(network: HyperliquidNetwork, connection_id: B256) -> B256 {
+    let source = match network {
+        HyperliquidNetwork::Mainnet => "a",
+        HyperliquidNetwork::Testnet => "b",
+    };
+
+    let mut agent = Vec::with_capacity(96);
+    agent.extend_from_slice(keccak256(AGENT_TYPE).as_slice());
+    agent.extend_from_slice(keccak256(source).as_slice());
+    agent.extend_from_slice(connection_id.as_slice());
+
+    let mut data = Vec::with_capacity(66);
+    data.extend_from_slice(&[0x19, 0x01]);
+    data.extend_from_slice(l1_domain_separator().as_slice());
+    data.extend_from_slice(keccak256(agent).as_slice());
+    keccak256(data)
+}
+
+/// Returns the EIP-712 domain separator for L1 actions.
+fn l1_domain_separator() -> B256 {
+    let mut chain_id = [0u8; 32];
+    chain_id[24..].copy_from_slice(&L1_CHAIN_ID.to_be_bytes());
+
+    let mut domain = Vec::with_capacity(160);
+    domain.extend_from_slice(keccak256(EIP712_DOMAIN_TYPE).as_slice());
+    domain.extend_from_slice(keccak256(L1_DOMAIN_NAME).as_slice());
+    domain.extend_from_slice(keccak256(L1_DOMAIN_VERSION).as_slice());
+    domain.extend_from_slice(&chain_id);
+    // The verifying contract is the zero address, left padded to 32 bytes
+    domain.extend_from_slice(&[0u8; 32]);
+    keccak256(domain)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 
 #[cfg(test)]
 mod tests {
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
     use rstest::rstest;
-    use serde_json::json;
 
     use super::*;
+    use crate::websocket::{
+        messages::{OrderRequest, OrderTypeRequest, TimeInForceRequest},
+        post::cancel_by_cloid,
+    };
+
+    const TEST_PRIVATE_KEY: &str =
+        "0x0123456789012345678901234567890123456789012345678901234567890123";
+
+    #[derive(Serialize)]
+    struct DummyAction {
+        #[serde(rename = "type")]
+        action_type: &'static str,
+        num: u64,
+    }
+
+    fn signer(network: HyperliquidNetwork) -> HyperliquidEip712Signer {
+        let private_key = EvmPrivateKey::new(TEST_PRIVATE_KEY.to_string()).unwrap();
+        HyperliquidEip712Signer::new(&private_key, network).unwrap()
+    }
+
+    fn order_action() -> ActionRequest {
+        ActionRequest::Order {
+            orders: vec![OrderRequest {
+                a: 1,
+                b: true,
+                p: "100".to_string(),
+                s: "100".to_string(),
+                r: false,
+                t: OrderTypeRequest::Limit {
+                    tif: TimeInForceRequest::Gtc,
+                },
+                c: None,
+            }],
+            grouping: "na".to_string(),
+        }
+    }
+
+    /// Recovers the address which produced the `signature` over the L1 `action`.
+    fn recover_address<T: Serialize>(
+        action: &T,
+        nonce: TimeNonce,
+        vault_address: Option<&VaultAddress>,
+        network: HyperliquidNetwork,
+        signature: &SignatureData,
+    ) -> String {
+        let digest = agent_digest(network, action_hash(action, nonce, vault_address).unwrap());
+        let mut bytes = hex::decode(&signature.r[2..]).unwrap();
+        bytes.extend(hex::decode(&signature.s[2..]).unwrap());
+        let key = VerifyingKey::recover_from_prehash(
+            digest.as_slice(),
+            &Signature::from_slice(&bytes).unwrap(),
+            RecoveryId::from_byte(signature.v - 27).unwrap(),
+        )
+        .unwrap();
+        let public_key = key.to_encoded_point(false);
+        format!(
+            "{:#x}",
+            Address::from_raw_public_key(&public_key.as_bytes()[1..])
+        )
+    }
 
     #[rstest]
-    fn test_address_canonicalization() {
-        assert_eq!(
-            HyperliquidEip712Signer::canonicalize_address("0xABCDEF123456789"),
-            "0xabcdef123456789"
-        );
-        assert_eq!(
-            HyperliquidEip712Signer::canonicalize_address("ABCDEF123456789"),
-            "0xabcdef123456789"
-        );
+    fn test_address_derivation() {
+        let private_key = EvmPrivateKey::new(
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318".to_string(),
+        )
+        .unwrap();
+        let signer =
+            HyperliquidEip712Signer::new(&private_key, HyperliquidNetwork::Mainnet).unwrap();
+
         assert_eq!(
-            HyperliquidEip712Signer::canonicalize_address("0XABCDEF123456789"),
-            "0xabcdef123456789"
+            signer.address(),
+            "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23"
         );
     }
 
     #[rstest]
-    fn test_decimal_canonicalization() {
-        assert_eq!(
-            HyperliquidEip712Signer::canonicalize_decimal("100.000"),
-            "100"
-        );
+    fn test_invalid_private_key() {
+        let private_key = EvmPrivateKey::new(format!("0x{}", "0".repeat(64))).unwrap();
+
+        assert!(HyperliquidEip712Signer::new(&private_key, HyperliquidNetwork::Mainnet).is_err());
+    }
+
+    #[rstest]
+    fn test_l1_domain_separator() {
         assert_eq!(
-            HyperliquidEip712Signer::canonicalize_decimal("100.100"),
-            "100.1"
+            format!("{:#x}", l1_domain_separator()),
+            "0xd79297fcdf2ffcd4ae223d01edaa2ba214ff8f401d7c9300d995d17c82aa4040"
         );
-        assert_eq!(HyperliquidEip712Signer::canonicalize_decimal("0.000"), "0");
-        assert_eq!(
-            HyperliquidEip712Signer::canonicalize_decimal("123.456"),
-            "123.456"
+    }
+
+    // Vectors from the Hyperliquid Python SDK, which prints `r` and `s` without leading zeros
+    #[rstest]
+    #[case::mainnet(
+        HyperliquidNetwork::Mainnet,
+        "0x053749d5b30552aeb2fca34b530185976545bb22d0b3ce6f62e31be961a59298",
+        "0x755c40ba9bf05223521753995abb2f73ab3229be8ec921f350cb447e384d8ed8",
+        27
+    )]
+    #[case::testnet(
+        HyperliquidNetwork::Testnet,
+        "0x542af61ef1f429707e3c76c5293c80d01f74ef853e34b76efffcb57e574f9510",
+        "0x17b8b32f086e8cdede991f1e2c529f5dd5297cbe8128500e00cbaf766204a613",
+        28
+    )]
+    fn test_sign_l1_action_vector(
+        #[case] network: HyperliquidNetwork,
+        #[case] r: &str,
+        #[case] s: &str,
+        #[case] v: u8,
+    ) {
+        let action = DummyAction {
+            action_type: "dummy",
+            num: 100_000_000_000,
+        };
+
+        let signature = signer(network)
+            .sign_l1_action(&action, TimeNonce::from_millis(0), None)
+            .unwrap();
+
+        assert_eq!(signature.r, r);
+        assert_eq!(signature.s, s);
+        assert_eq!(signature.v, v);
+    }
+
+    #[rstest]
+    fn test_order_action_msgpack_encoding() {
+        let encoded = rmp_serde::to_vec_named(&order_action()).unwrap();
+
+        // {"type": "order", "orders": [{"a": 1, "b": true, "p": "100", "s": "100", "r": false,
+        //  "t": {"limit": {"tif": "Gtc"}}}], "grouping": "na"}
+        let expected = concat!(
+            "83a474797065a56f72646572a66f726465727391",
+            "86a16101a162c3a170a3313030a173a3313030a172c2",
+            "a17481a56c696d697481a3746966a3477463",
+            "a867726f7570696e67a26e61",
         );
+        assert_eq!(hex::encode(encoded), expected);
+    }
+
+    #[rstest]
+    #[case::mainnet(HyperliquidNetwork::Mainnet)]
+    #[case::testnet(HyperliquidNetwork::Testnet)]
+    fn test_sign_order_recovers_signer(#[case] network: HyperliquidNetwork) {
+        let signer = signer(network);
+        let nonce = TimeNonce::from_millis(1_700_000_000_000);
+        let action = order_action();
+
+        let signature = signer.sign_l1_action(&action, nonce, None).unwrap();
+
         assert_eq!(
-            HyperliquidEip712Signer::canonicalize_decimal("123.450"),
-            "123.45"
+            recover_address(&action, nonce, None, network, &signature),
+            signer.address()
         );
     }
 
     #[rstest]
-    fn test_action_canonicalization() {
-        let action = json!({
-            "destination": "0xABCDEF123456789",
-            "amount": "100.000",
-            "other": "unchanged"
-        });
+    fn test_sign_is_deterministic() {
+        let signer = signer(HyperliquidNetwork::Mainnet);
+        let nonce = TimeNonce::from_millis(1_700_000_000_000);
 
-        let canonicalized = HyperliquidEip712Signer::canonicalize_action(&action).unwrap();
+        let first = signer.sign_l1_action(&order_action(), nonce, None).unwrap();
+        let second = signer.sign_l1_action(&order_action(), nonce, None).unwrap();
 
-        assert_eq!(canonicalized["destination"], "0xabcdef123456789");
-        assert_eq!(canonicalized["amount"], "100");
-        assert_eq!(canonicalized["other"], "unchanged");
+        assert_eq!(first, second);
     }
 
     #[rstest]
-    fn test_sign_request_l1_action() {
-        let private_key = EvmPrivateKey::new(
-            "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
-        )
-        .unwrap();
-        let signer = HyperliquidEip712Signer::new(private_key);
+    fn test_vault_address_changes_action_hash() {
+        let vault = VaultAddress::parse("0x1719884eb866cb12b2287399b15f7db5e7d775ea").unwrap();
+        let action = cancel_by_cloid(1, "0x00000000000000000000000000000001");
+        let nonce = TimeNonce::from_millis(1_700_000_000_000);
+
+        let without_vault = action_hash(&action, nonce, None).unwrap();
+        let with_vault = action_hash(&action, nonce, Some(&vault)).unwrap();
+        assert_ne!(without_vault, with_vault);
+
+        let signer = signer(HyperliquidNetwork::Mainnet);
+        let signature = signer.sign_l1_action(&action, nonce, Some(&vault)).unwrap();
+        assert_eq!(
+            recover_address(
+                &action,
+                nonce,
+                Some(&vault),
+                HyperliquidNetwork::Mainnet,
+                &signature
+            ),
+            signer.address()
+        );
+    }
 
+    #[rstest]
+    fn test_sign_request() {
+        let signer = signer(HyperliquidNetwork::Mainnet);
         let request = SignRequest {
-            action: json!({
-                "type": "withdraw",
-                "destination": "0xABCDEF123456789",
-                "amount": "100.000"
-            }),
-            time_nonce: TimeNonce::from_millis(1640995200000),
+            action: order_action(),
+            time_nonce: TimeNonce::from_millis(1_700_000_000_000),
             action_type: HyperliquidActionType::L1,
+            vault_address: None,
         };
 
-        let result = signer.sign(&request).unwrap();
-        assert!(result.signature.ends_with("0000000000000001"));
+        let bundle = signer.sign(&request).unwrap();
+
+        assert_eq!(
+            bundle.signature,
+            signer
+                .sign_l1_action(&request.action, request.time_nonce, None)
+                .unwrap()
+        );
     }
 
     #[rstest]
-    fn test_sign_request_user_action() {
-        let private_key = EvmPrivateKey::new(
-            "0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef".to_string(),
-        )
-        .unwrap();
-        let signer = HyperliquidEip712Signer::new(private_key);
-
+    fn test_sign_request_user_signed_unsupported() {
         let request = SignRequest {
-            action: json!({
-                "type": "order",
-                "coin": "BTC",
-                "px": "50000.00",
-                "sz": "0.1"
-            }),
-            time_nonce: TimeNonce::from_millis(1640995200000),
+            action: order_action(),
+            time_nonce: TimeNonce::from_millis(1_700_000_000_000),
             action_type: HyperliquidActionType::UserSigned,
+            vault_address: None,
         };
 
-        let result = signer.sign(&request).unwrap();
-        assert!(result.signature.ends_with("0000000000000002"));
+        assert!(signer(HyperliquidNetwork::Mainnet).sign(&request).is_err());
+    }
+
+    #[rstest]
+    fn test_negative_nonce_rejected() {
+        assert!(action_hash(&order_action(), TimeNonce::from_millis(-1), None).is_err());
     }
 }
@@ -42,8 +42,8 @@ impl From<&str> for SignerId {
 /// Hyperliquid action types for different signing schemes.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum HyperliquidActionType {
-    /// L1 actions (agent deposits, withdrawals) - signed with L1 scheme.
+    /// L1 actions (orders, cancels, modifies) - signed as a phantom agent.
     L1,
-    /// User actions (trading) - signed with user-signed scheme.
+    /// User actions (transfers, withdrawals) - signed directly as EIP-712 typed data.
     UserSigned,
 }
@@ -15,9 +15,9 @@
 
 use std::{collections::HashSet, sync::Arc, time::Duration};
 
+use ant_network::websocket::{WebSocketClient, WebSocketConfig, channel_message_handler};
 use anyhow::Result;
 use futures_util::future::BoxFuture;
-use ant_network::websocket::{WebSocketClient, WebSocketConfig, channel_message_handler};
 use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::Message;
 use tracing::{debug, error, info, warn};
@@ -104,7 +104,7 @@ impl HyperliquidCodec {
 #[derive(Debug)]
 pub struct HyperliquidWebSocketInnerClient {
     inner: Arc<WebSocketClient>,
-    rx_inbound: mpsc::Receiver<HyperliquidWsMessage>,
+    rx_inbound: Option<mpsc::Receiver<HyperliquidWsMessage>>,
     sent_subscriptions: HashSet<String>,
     _reader_task: tokio::task::JoinHandle<()>,
     post_router: Arc<PostRouter>,
@@ -219,7 +219,7 @@ impl HyperliquidWebSocketInnerClient {
 
         let hl_client = Self {
             inner: client,
-            rx_inbound,
+            rx_inbound: Some(rx_inbound),
             sent_subscriptions: HashSet::new(),
             _reader_task: reader_task,
             post_router,
@@ -266,9 +266,14 @@ impl HyperliquidWebSocketInnerClient {
     }
 
     /// Get the next event from the WebSocket stream.
-    /// Returns None when the connection is closed or the receiver is exhausted.
+    /// Returns None when the connection is closed, the receiver is exhausted or has been taken.
     pub async fn ws_next_event(&mut self) -> Option<HyperliquidWsMessage> {
-        self.rx_inbound.recv().await
+        self.rx_inbound.as_mut()?.recv().await
+    }
+
+    /// Takes the inbound message receiver so events can be consumed independently of posts.
+    pub fn take_inbound_receiver(&mut self) -> Option<mpsc::Receiver<HyperliquidWsMessage>> {
+        self.rx_inbound.take()
     }
 
     /// Returns true if the WebSocket connection is active.
@@ -292,7 +297,7 @@ impl HyperliquidWebSocketInnerClient {
     }
 
     /// Disconnect the WebSocket client.
-    pub async fn ws_disconnect(&mut self) -> Result<()> {
+    pub async fn ws_disconnect(&self) -> Result<()> {
         self.inner.disconnect().await;
         Ok(())
     }
@@ -398,11 +403,17 @@ impl HyperliquidWebSocketClient {
     }
 
     /// Get the next event from the WebSocket stream.
-    /// Returns None when the connection is closed or the receiver is exhausted.
+    /// Returns None when the connection is closed, the receiver is exhausted or has been taken.
     pub async fn next_event(&mut self) -> Option<HyperliquidWsMessage> {
         self.inner.ws_next_event().await
     }
 
+    /// Takes the event receiver, allowing events to be consumed on another task while posts
+    /// are sent through this client.
+    pub fn take_event_receiver(&mut self) -> Option<mpsc::Receiver<HyperliquidWsMessage>> {
+        self.inner.take_inbound_receiver()
+    }
+
     /// Returns true if the WebSocket connection is active.
     pub fn is_active(&self) -> bool {
         self.inner.is_active()
@@ -424,7 +435,7 @@ impl HyperliquidWebSocketClient {
     }
 
     /// Disconnect the WebSocket client.
-    pub async fn disconnect(&mut self) -> Result<()> {
+    pub async fn disconnect(&self) -> Result<()> {
         self.inner.ws_disconnect().await
     }
 
@@ -444,7 +455,7 @@ impl HyperliquidWebSocketClient {
 
     /// High-level: fire arbitrary info (WS post) returning raw payload.
     pub async fn post_info_raw(
-        &mut self,
+        &self,
         payload: serde_json::Value,
         timeout: Duration,
     ) -> HyperliquidResult<PostResponsePayload> {
@@ -453,7 +464,7 @@ impl HyperliquidWebSocketClient {
 
     /// High-level: fire action (already signed ActionPayload)
     pub async fn post_action_raw(
-        &mut self,
+        &self,
         action: ActionPayload,
         timeout: Duration,
     ) -> HyperliquidResult<PostResponsePayload> {
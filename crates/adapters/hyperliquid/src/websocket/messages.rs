@@ -120,17 +120,23 @@ pub struct ActionPayload {
 }
 
 /// Signature data
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SignatureData {
+    /// The 0x-prefixed 32-byte `r` value.
     pub r: String,
+    /// The 0x-prefixed 32-byte `s` value.
     pub s: String,
-    pub v: String,
+    /// The recovery ID (27 or 28).
+    pub v: u8,
 }
 
-/// Action request types
+/// Action request types.
+///
+/// Field order matters: L1 actions are signed over their msgpack encoding, which must match the
+/// encoding Hyperliquid derives from the action it receives.
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type")]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "camelCase")]
 pub enum ActionRequest {
     /// Place orders
     Order {
@@ -142,6 +148,7 @@ pub enum ActionRequest {
     /// Cancel orders by client order ID
     CancelByCloid { cancels: Vec<CancelByCloidRequest> },
     /// Modify orders
+    #[serde(rename = "batchModify")]
     Modify { modifies: Vec<ModifyRequest> },
 }
 
@@ -165,9 +172,8 @@ pub struct OrderRequest {
     pub c: Option<String>,
 }
 
-/// Order type in request format
+/// Order type in request format, e.g. `{"limit": {"tif": "Gtc"}}`
 #[derive(Debug, Clone, Serialize)]
-#[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
 pub enum OrderTypeRequest {
     Limit {
@@ -424,6 +430,9 @@ pub struct WsFillData {
     pub fee_token: String,
     #[serde(rename = "builderFee")]
     pub builder_fee: Option<String>,
+    /// Client order ID of the filled order, if it was placed with one.
+    #[serde(default)]
+    pub cloid: Option<String>,
 }
 
 /// Fill liquidation data
@@ -668,6 +677,76 @@ mod tests {
         assert!(json.contains(r#""p":"50000.0""#));
     }
 
+    #[rstest]
+    fn test_trigger_order_type_serialization() {
+        let order_type = OrderTypeRequest::Trigger {
+            is_market: true,
+            trigger_px: "3400".to_string(),
+            tpsl: TpSlRequest::Sl,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&order_type).unwrap(),
+            serde_json::json!({ "trigger": { "isMarket": true, "triggerPx": "3400", "tpsl": "sl" } })
+        );
+    }
+
+    #[rstest]
+    fn test_cancel_by_cloid_action_serialization() {
+        let action = ActionRequest::CancelByCloid {
+            cancels: vec![CancelByCloidRequest {
+                asset: 10107,
+                cloid: "0x6ea93992c3b4627788e6e764de787016".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            serde_json::json!({
+                "type": "cancelByCloid",
+                "cancels": [{ "asset": 10107, "cloid": "0x6ea93992c3b4627788e6e764de787016" }],
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_modify_action_serialization() {
+        let action = ActionRequest::Modify {
+            modifies: vec![ModifyRequest {
+                oid: 91490942,
+                order: OrderRequest {
+                    a: 0,
+                    b: true,
+                    p: "96000".to_string(),
+                    s: "0.03".to_string(),
+                    r: false,
+                    t: OrderTypeRequest::Limit {
+                        tif: TimeInForceRequest::Alo,
+                    },
+                    c: None,
+                },
+            }],
+        };
+
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            serde_json::json!({
+                "type": "batchModify",
+                "modifies": [{
+                    "oid": 91490942,
+                    "order": {
+                        "a": 0,
+                        "b": true,
+                        "p": "96000",
+                        "s": "0.03",
+                        "r": false,
+                        "t": { "limit": { "tif": "Alo" } },
+                    },
+                }],
+            })
+        );
+    }
+
     #[rstest]
     fn test_ws_trade_data_deserialization() {
         let json = r#"{
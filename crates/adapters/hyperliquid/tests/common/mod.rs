@@ -0,0 +1,239 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mock Hyperliquid WebSocket server replaying the fixtures under `test_data`.
+//!
+//! Info posts are answered from the fixtures and exchange actions are recorded and acknowledged,
+//! resting orders unless they are post-only (rejected as crossing) or IOC (filled).
+
+#![allow(dead_code)] // Not every test binary uses every helper
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{
+    Router,
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+    routing::get,
+};
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+/// The order ID assigned to orders placed on the mock server.
+pub const MOCK_OID: u64 = 77738308;
+
+/// The order ID assigned to orders modified on the mock server.
+pub const MOCK_MODIFIED_OID: u64 = 77738309;
+
+// Server state recording what the client sent
+#[derive(Clone, Default)]
+pub struct MockServerState {
+    pub subscriptions: Arc<Mutex<Vec<Value>>>,
+    pub info_requests: Arc<Mutex<Vec<Value>>>,
+    pub actions: Arc<Mutex<Vec<Value>>>,
+}
+
+impl MockServerState {
+    /// Returns the recorded info requests of the `request_type`.
+    pub async fn info_requests_for(&self, request_type: &str) -> Vec<Value> {
+        self.info_requests
+            .lock()
+            .await
+            .iter()
+            .filter(|request| request["type"] == request_type)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the recorded signed action payloads of the `action_type`.
+    pub async fn actions_for(&self, action_type: &str) -> Vec<Value> {
+        self.actions
+            .lock()
+            .await
+            .iter()
+            .filter(|payload| payload["action"]["type"] == action_type)
+            .cloned()
+            .collect()
+    }
+}
+
+// Load test data from existing files
+pub fn load_test_data(filename: &str) -> Value {
+    let path = format!("test_data/{filename}");
+    let content = std::fs::read_to_string(path).expect("Failed to read test data");
+    serde_json::from_str(&content).expect("Failed to parse test data")
+}
+
+/// Returns the response data of an info request.
+fn info_response(request: &Value) -> Option<Value> {
+    let fixture = match request["type"].as_str()? {
+        "meta" => "info_meta.json",
+        "spotMeta" => "info_spot_meta.json",
+        "allMids" => "info_all_mids.json",
+        "clearinghouseState" => "info_clearinghouse_state.json",
+        "frontendOpenOrders" => "info_frontend_open_orders.json",
+        "userFills" => "info_user_fills.json",
+        _ => return None,
+    };
+    Some(load_test_data(fixture))
+}
+
+/// Returns the status of a single order placed on the mock server.
+fn order_status(order: &Value) -> Value {
+    match order["t"]["limit"]["tif"].as_str() {
+        Some("Alo") => json!({
+            "error": "Post only order would have immediately matched, bbo was 100000.0@100001.0. asset=0",
+        }),
+        Some("Ioc") => json!({
+            "filled": { "totalSz": order["s"], "avgPx": "100010.0", "oid": MOCK_OID },
+        }),
+        _ => json!({ "resting": { "oid": MOCK_OID, "cloid": order["c"] } }),
+    }
+}
+
+/// Returns the exchange response to a signed action.
+fn action_response(action: &Value) -> Value {
+    let action_type = action["type"].as_str().unwrap_or_default();
+    let statuses: Vec<Value> = match action_type {
+        "order" => action["orders"]
+            .as_array()
+            .map(|orders| orders.iter().map(order_status).collect())
+            .unwrap_or_default(),
+        "cancel" | "cancelByCloid" => action["cancels"]
+            .as_array()
+            .map(|cancels| cancels.iter().map(|_| json!("success")).collect())
+            .unwrap_or_default(),
+        "batchModify" => action["modifies"]
+            .as_array()
+            .map(|modifies| {
+                modifies
+                    .iter()
+                    .map(|_| json!({ "resting": { "oid": MOCK_MODIFIED_OID } }))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => return json!({ "status": "err", "response": format!("Unknown action {action_type}") }),
+    };
+    json!({
+        "status": "ok",
+        "response": { "type": action_type, "data": { "statuses": statuses } },
+    })
+}
+
+/// Returns the channel message pushed after a subscription.
+fn subscription_fixture(subscription: &Value) -> Option<Value> {
+    let (channel, fixture) = match subscription["type"].as_str()? {
+        "orderUpdates" => ("orderUpdates", "ws_order_updates.json"),
+        "userEvents" => ("userEvents", "ws_user_events_fills.json"),
+        _ => return None,
+    };
+    Some(json!({ "channel": channel, "data": load_test_data(fixture) }))
+}
+
+// WebSocket handler for the mock server
+async fn handle_websocket(ws: WebSocketUpgrade, State(state): State<MockServerState>) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_post(state: &MockServerState, request: &Value) -> Value {
+    let payload = &request["payload"];
+    match request["type"].as_str() {
+        Some("info") => {
+            state.info_requests.lock().await.push(payload.clone());
+            match info_response(payload) {
+                // Info data is wrapped with the request type
+                Some(data) => json!({
+                    "type": "info",
+                    "payload": { "type": payload["type"], "data": data },
+                }),
+                None => json!({ "type": "error", "payload": "Unknown info request" }),
+            }
+        }
+        Some("action") => {
+            state.actions.lock().await.push(payload.clone());
+            json!({ "type": "action", "payload": action_response(&payload["action"]) })
+        }
+        _ => json!({ "type": "error", "payload": "Unknown post request" }),
+    }
+}
+
+async fn handle_socket(mut socket: WebSocket, state: MockServerState) {
+    while let Some(Ok(msg)) = socket.recv().await {
+        let Message::Text(text) = msg else {
+            continue;
+        };
+        let Ok(request) = serde_json::from_str::<Value>(text.as_str()) else {
+            continue;
+        };
+
+        let replies = match request["method"].as_str() {
+            Some("subscribe") => {
+                let subscription = request["subscription"].clone();
+                state.subscriptions.lock().await.push(subscription.clone());
+                let response = json!({
+                    "channel": "subscriptionResponse",
+                    "data": subscription,
+                });
+                std::iter::once(response)
+                    .chain(subscription_fixture(&subscription))
+                    .collect()
+            }
+            Some("post") => {
+                let response = handle_post(&state, &request["request"]).await;
+                vec![json!({
+                    "channel": "post",
+                    "data": { "id": request["id"], "response": response },
+                })]
+            }
+            Some("ping") => vec![json!({ "channel": "pong" })],
+            _ => Vec::new(),
+        };
+
+        for reply in replies {
+            if socket
+                .send(Message::Text(reply.to_string().into()))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+fn create_test_router(state: MockServerState) -> Router {
+    Router::new()
+        .route("/ws", get(handle_websocket))
+        .with_state(state)
+}
+
+/// Starts the mock server on a free port, returning its address and recorded state.
+pub async fn start_mock_server() -> (SocketAddr, MockServerState) {
+    // Bind to port 0 to let the OS assign an available port
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let state = MockServerState::default();
+    let router = create_test_router(state.clone());
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    (addr, state)
+}
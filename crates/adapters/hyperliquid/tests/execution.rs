@@ -0,0 +1,865 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests for the Hyperliquid execution client using a mock WebSocket server.
+
+mod common;
+
+use std::{
+    cell::RefCell,
+    net::SocketAddr,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ant_common::{
+    cache::Cache,
+    clock::TestClock,
+    messages::execution::{BatchCancelOrders, CancelOrder, ModifyOrder, SubmitOrder},
+    msgbus::{
+        self, get_message_saving_handler, get_saved_messages, handler::ShareableMessageHandler,
+    },
+    runtime::get_runtime,
+};
+use ant_core::{UUID4, UnixNanos};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient};
+use ant_hyperliquid::{HyperliquidExecClientConfig, HyperliquidExecutionClient};
+use ant_model::{
+    enums::{
+        CurrencyType, LiquiditySide, OrderSide, OrderType, PositionSideSpecified, TimeInForce,
+    },
+    events::{AccountState, OrderEventAny},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, Symbol, TradeId, TraderId,
+        VenueOrderId,
+    },
+    instruments::{CryptoPerpetual, CurrencyPair, InstrumentEnum},
+    orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+    types::{Currency, Money, Price, Quantity},
+};
+use common::{MOCK_MODIFIED_OID, MOCK_OID, MockServerState, start_mock_server};
+use rstest::rstest;
+use serde_json::{Value, json};
+use ustr::Ustr;
+
+const TEST_PRIVATE_KEY: &str = "0x0123456789012345678901234567890123456789012345678901234567890123";
+const TEST_ADDRESS: &str = "0x14791697260e4c9a71f18484c9f997b308e59325";
+const TEST_VAULT: &str = "0x1719884eb866cb12b2287399b15f7db5e7d775ea";
+
+fn account_id() -> AccountId {
+    AccountId::from("HYPERLIQUID-master")
+}
+
+fn btc_id() -> InstrumentId {
+    InstrumentId::from("BTC-USD-PERP.HYPERLIQUID")
+}
+
+fn eth_id() -> InstrumentId {
+    InstrumentId::from("ETH-USD-PERP.HYPERLIQUID")
+}
+
+fn hype_id() -> InstrumentId {
+    InstrumentId::from("HYPE-USDC-SPOT.HYPERLIQUID")
+}
+
+fn order_event_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+    msgbus::register("ExecEngine.process".into(), handler.clone());
+    handler
+}
+
+fn account_state_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<AccountState>(Some(Ustr::from("Portfolio.update_account")));
+    msgbus::register("Portfolio.update_account".into(), handler.clone());
+    handler
+}
+
+fn perpetual(
+    instrument_id: InstrumentId,
+    price_increment: &str,
+    size_increment: &str,
+) -> InstrumentEnum {
+    let price_increment = Price::from(price_increment);
+    let size_increment = Quantity::from(size_increment);
+    InstrumentEnum::CryptoPerpetual(CryptoPerpetual::new(
+        instrument_id,
+        instrument_id.symbol,
+        Currency::from(instrument_id.symbol.as_str().split('-').next().unwrap()),
+        Currency::USD(),
+        Currency::USDC(),
+        false,
+        price_increment.precision,
+        size_increment.precision,
+        price_increment,
+        size_increment,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    ))
+}
+
+fn hype_spot() -> InstrumentEnum {
+    let hype = Currency::new("HYPE", 8, 0, "HYPE", CurrencyType::Crypto);
+    Currency::register(hype, true).unwrap();
+    InstrumentEnum::CurrencyPair(CurrencyPair::new(
+        hype_id(),
+        Symbol::from("@107"),
+        hype,
+        Currency::USDC(),
+        3,
+        2,
+        Price::from("0.001"),
+        Quantity::from("0.01"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    ))
+}
+
+fn create_config(addr: SocketAddr) -> HyperliquidExecClientConfig {
+    let mut config = HyperliquidExecClientConfig::new(TraderId::from("TRADER-001"), account_id());
+    config.private_key = Some(TEST_PRIVATE_KEY.to_string());
+    config.base_url_ws = Some(format!("ws://{addr}/ws"));
+    config
+}
+
+fn create_client(
+    config: HyperliquidExecClientConfig,
+    cache: Rc<RefCell<Cache>>,
+) -> HyperliquidExecutionClient {
+    HyperliquidExecutionClient::new(
+        ClientId::from("HYPERLIQUID"),
+        config,
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+    )
+    .unwrap()
+}
+
+/// Starts the mock server and returns a cache holding the instruments it serves.
+fn setup() -> (SocketAddr, MockServerState, Rc<RefCell<Cache>>) {
+    let (addr, state) = get_runtime().block_on(start_mock_server());
+
+    let mut cache = Cache::default();
+    cache
+        .add_instrument(perpetual(btc_id(), "0.1", "0.00001"))
+        .unwrap();
+    cache
+        .add_instrument(perpetual(eth_id(), "0.01", "0.0001"))
+        .unwrap();
+    cache.add_instrument(hype_spot()).unwrap();
+    (addr, state, Rc::new(RefCell::new(cache)))
+}
+
+fn limit_order(
+    instrument_id: InstrumentId,
+    client_order_id: &str,
+    side: OrderSide,
+    quantity: &str,
+    price: &str,
+) -> OrderAny {
+    OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_id)
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(side)
+        .quantity(Quantity::from(quantity))
+        .price(Price::from(price))
+        .build()
+}
+
+/// Adds the order to the cache in the submitted state, optionally accepted by the venue.
+fn add_order(cache: &Rc<RefCell<Cache>>, mut order: OrderAny, accepted: bool) -> OrderAny {
+    order
+        .apply(TestOrderEventStubs::submitted(&order, account_id()))
+        .unwrap();
+    if accepted {
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id(),
+                VenueOrderId::new(MOCK_OID.to_string()),
+            ))
+            .unwrap();
+    }
+    cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+    order
+}
+
+fn submit_order(order: &OrderAny) -> SubmitOrder {
+    SubmitOrder {
+        trader_id: TraderId::from("TRADER-001"),
+        client_id: ClientId::from("HYPERLIQUID"),
+        strategy_id: StrategyId::from("S-001"),
+        instrument_id: order.instrument_id(),
+        client_order_id: order.client_order_id(),
+        venue_order_id: VenueOrderId::from("NONE"),
+        order: order.clone(),
+        exec_algorith_id: None,
+        position_id: None,
+        command_id: UUID4::new(),
+        ts_init: UnixNanos::default(),
+    }
+}
+
+fn cancel_order(order: &OrderAny) -> CancelOrder {
+    CancelOrder {
+        trader_id: TraderId::from("TRADER-001"),
+        client_id: ClientId::from("HYPERLIQUID"),
+        strategy_id: order.strategy_id(),
+        instrument_id: order.instrument_id(),
+        client_order_id: order.client_order_id(),
+        venue_order_id: VenueOrderId::new(MOCK_OID.to_string()),
+        command_id: UUID4::new(),
+        ts_init: UnixNanos::default(),
+    }
+}
+
+/// Returns the single signed order placed on the mock server.
+fn placed_order(state: &MockServerState) -> Value {
+    let actions = get_runtime().block_on(state.actions_for("order"));
+    assert_eq!(actions.len(), 1);
+    actions[0]["action"]["orders"][0].clone()
+}
+
+/// Drains the client until `condition` holds for the saved events, or panics after five seconds.
+fn drain_until<F>(
+    client: &HyperliquidExecutionClient,
+    handler: &ShareableMessageHandler,
+    condition: F,
+) where
+    F: Fn(&[OrderEventAny]) -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        client.drain_events();
+        if condition(&get_saved_messages::<OrderEventAny>(handler.clone())) {
+            return;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Timed out waiting for order events"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[rstest]
+fn test_connect_subscribes_and_requests_account_state() {
+    let _orders = order_event_handler();
+    let accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let mut client = create_client(create_config(addr), cache);
+
+    client.connect().unwrap();
+
+    assert!(client.is_connected());
+    assert_eq!(client.user(), TEST_ADDRESS);
+    get_runtime().block_on(async {
+        let subscriptions = state.subscriptions.lock().await.clone();
+        assert_eq!(
+            subscriptions,
+            vec![
+                json!({ "type": "orderUpdates", "user": TEST_ADDRESS }),
+                json!({ "type": "userEvents", "user": TEST_ADDRESS }),
+            ]
+        );
+        assert_eq!(state.info_requests_for("meta").await.len(), 1);
+        assert_eq!(state.info_requests_for("spotMeta").await.len(), 1);
+        let requests = state.info_requests_for("clearinghouseState").await;
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["user"], TEST_ADDRESS);
+    });
+
+    let account_states = get_saved_messages::<AccountState>(accounts);
+    assert!(!account_states.is_empty());
+    let balance = &account_states[0].balances[0];
+    assert_eq!(balance.total, Money::from("10250.50 USDC"));
+    assert_eq!(balance.locked, Money::from("500.00 USDC"));
+
+    client.disconnect().unwrap();
+    assert!(!client.is_connected());
+}
+
+#[rstest]
+fn test_missing_private_key_errors() {
+    let mut config = HyperliquidExecClientConfig::new(TraderId::from("TRADER-001"), account_id());
+    config.private_key = Some(String::new());
+
+    let result = HyperliquidExecutionClient::new(
+        ClientId::from("HYPERLIQUID"),
+        config,
+        Rc::new(RefCell::new(TestClock::new())),
+        Rc::new(RefCell::new(Cache::default())),
+    );
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_order_stream_generates_accepted() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    add_order(
+        &cache,
+        limit_order(
+            btc_id(),
+            "O-20250105-003",
+            OrderSide::Buy,
+            "0.02",
+            "99000.0",
+        ),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+
+    drain_until(&client, &handler, |events| {
+        events
+            .iter()
+            .any(|e| matches!(e, OrderEventAny::Accepted(_)))
+    });
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Accepted(accepted) = &events[0] else {
+        panic!("Expected accepted, was {:?}", events[0]);
+    };
+    assert_eq!(accepted.venue_order_id, VenueOrderId::from("91490950"));
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_user_events_fill_generates_accepted_and_filled() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            btc_id(),
+            "O-20250105-002",
+            OrderSide::Sell,
+            "0.01",
+            "100005.0",
+        ),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+
+    drain_until(&client, &handler, |events| {
+        events.iter().any(|e| matches!(e, OrderEventAny::Filled(_)))
+    });
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[0], OrderEventAny::Accepted(_)));
+    let OrderEventAny::Filled(fill) = &events[1] else {
+        panic!("Expected fill, was {:?}", events[1]);
+    };
+    assert_eq!(fill.client_order_id, order.client_order_id());
+    assert_eq!(fill.venue_order_id, VenueOrderId::from("91490951"));
+    assert_eq!(fill.trade_id, TradeId::from("118906512037801"));
+    assert_eq!(fill.last_qty, Quantity::from("0.01000"));
+    assert_eq!(fill.last_px, Price::from("100005.0"));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Maker);
+    assert_eq!(fill.commission, Some(Money::from("0.150007 USDC")));
+    assert_eq!(
+        fill.position_id.map(|id| id.to_string()),
+        Some(format!("{}-{}", btc_id(), order.strategy_id()))
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_limit_order_sends_signed_order_action() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            btc_id(),
+            "O-20250105-004",
+            OrderSide::Buy,
+            "0.02",
+            "95000.0",
+        ),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 2);
+    assert!(matches!(events[0], OrderEventAny::Submitted(_)));
+    let OrderEventAny::Accepted(accepted) = &events[1] else {
+        panic!("Expected accepted, was {:?}", events[1]);
+    };
+    assert_eq!(
+        accepted.venue_order_id,
+        VenueOrderId::new(MOCK_OID.to_string())
+    );
+
+    let actions = get_runtime().block_on(state.actions_for("order"));
+    assert_eq!(actions.len(), 1);
+    let payload = &actions[0];
+    assert_eq!(
+        payload["action"],
+        json!({
+            "type": "order",
+            "orders": [{
+                "a": 0,
+                "b": true,
+                "p": "95000",
+                "s": "0.02",
+                "r": false,
+                "t": { "limit": { "tif": "Gtc" } },
+                "c": "0x6a426e06b503882e42cea3f857473251",
+            }],
+            "grouping": "na",
+        })
+    );
+    assert!(payload["nonce"].as_u64().unwrap() > 0);
+    assert!(payload["vaultAddress"].is_null());
+    let signature = &payload["signature"];
+    assert_eq!(signature["r"].as_str().unwrap().len(), 66);
+    assert_eq!(signature["s"].as_str().unwrap().len(), 66);
+    assert!(matches!(signature["v"].as_u64(), Some(27 | 28)));
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_post_only_order_crossing_is_rejected() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(btc_id())
+            .client_order_id(ClientOrderId::from("O-20250105-005"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("0.01"))
+            .price(Price::from("100001.0"))
+            .post_only(true)
+            .build(),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 2);
+    let OrderEventAny::Rejected(rejected) = &events[1] else {
+        panic!("Expected rejected, was {:?}", events[1]);
+    };
+    assert_eq!(rejected.due_post_only, 1);
+    assert_eq!(
+        placed_order(&state)["t"],
+        json!({ "limit": { "tif": "Alo" } })
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_market_order_sends_ioc_through_mid_price() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(btc_id())
+            .client_order_id(ClientOrderId::from("O-20250105-006"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("0.01"))
+            .build(),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert!(matches!(events[1], OrderEventAny::Accepted(_)));
+    let placed = placed_order(&state);
+    // 5% (500 bps) through the 100000.0 mid
+    assert_eq!(placed["p"], "105000");
+    assert_eq!(placed["t"], json!({ "limit": { "tif": "Ioc" } }));
+    assert_eq!(
+        get_runtime()
+            .block_on(state.info_requests_for("allMids"))
+            .len(),
+        1
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_spot_order_uses_spot_asset_id() {
+    let _orders = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            hype_id(),
+            "O-20250105-007",
+            OrderSide::Sell,
+            "10.00",
+            "25.500",
+        ),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let placed = placed_order(&state);
+    // Spot assets are offset by 10000 from the spot pair index
+    assert_eq!(placed["a"], 10107);
+    assert_eq!(placed["b"], false);
+    assert_eq!(placed["p"], "25.5");
+    assert_eq!(placed["s"], "10");
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_order_with_unsupported_time_in_force_rejects() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(btc_id())
+            .client_order_id(ClientOrderId::from("O-20250105-009"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("0.01"))
+            .price(Price::from("95000.0"))
+            .time_in_force(TimeInForce::Fok)
+            .build(),
+        false,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    assert!(
+        get_runtime()
+            .block_on(state.actions_for("order"))
+            .is_empty()
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_order_when_not_connected_rejects() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    let order = limit_order(
+        btc_id(),
+        "O-20250105-010",
+        OrderSide::Buy,
+        "0.01",
+        "95000.0",
+    );
+
+    let client = create_client(create_config(addr), cache);
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    assert!(
+        get_runtime()
+            .block_on(state.actions_for("order"))
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_vault_orders_are_signed_for_vault() {
+    let _orders = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            btc_id(),
+            "O-20250105-011",
+            OrderSide::Buy,
+            "0.02",
+            "95000.0",
+        ),
+        false,
+    );
+
+    let mut config = create_config(addr);
+    config.vault_address = Some(TEST_VAULT.to_string());
+    let mut client = create_client(config, cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    // The vault is tracked in place of the signing wallet
+    assert_eq!(client.user(), TEST_VAULT);
+    get_runtime().block_on(async {
+        let subscriptions = state.subscriptions.lock().await.clone();
+        assert!(subscriptions.iter().all(|s| s["user"] == TEST_VAULT));
+        let actions = state.actions_for("order").await;
+        assert_eq!(actions[0]["vaultAddress"], TEST_VAULT);
+    });
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_cancel_order_cancels_by_cloid() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            eth_id(),
+            "O-20250105-008",
+            OrderSide::Sell,
+            "0.5",
+            "3600.00",
+        ),
+        true,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client.cancel_order(&cancel_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Canceled(canceled) = &events[0] else {
+        panic!("Expected canceled, was {:?}", events[0]);
+    };
+    assert_eq!(canceled.client_order_id, order.client_order_id());
+
+    let actions = get_runtime().block_on(state.actions_for("cancelByCloid"));
+    assert_eq!(actions.len(), 1);
+    assert_eq!(
+        actions[0]["action"]["cancels"],
+        json!([{ "asset": 1, "cloid": "0x6ea93992c3b4627788e6e764de787016" }])
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_batch_cancel_orders_sends_single_action() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let orders: Vec<OrderAny> = ["O-20250105-011", "O-20250105-012"]
+        .into_iter()
+        .map(|id| {
+            add_order(
+                &cache,
+                limit_order(btc_id(), id, OrderSide::Buy, "0.01", "95000.0"),
+                true,
+            )
+        })
+        .collect();
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client
+        .batch_cancel_orders(&BatchCancelOrders {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("HYPERLIQUID"),
+            strategy_id: StrategyId::from("S-001"),
+            instrument_id: btc_id(),
+            cancels: orders.iter().map(cancel_order).collect(),
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        })
+        .unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    let canceled: Vec<ClientOrderId> = events
+        .iter()
+        .filter_map(|e| match e {
+            OrderEventAny::Canceled(canceled) => Some(canceled.client_order_id),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        canceled,
+        orders
+            .iter()
+            .map(Order::client_order_id)
+            .collect::<Vec<_>>()
+    );
+
+    let actions = get_runtime().block_on(state.actions_for("cancelByCloid"));
+    assert_eq!(actions.len(), 1);
+    assert_eq!(
+        actions[0]["action"]["cancels"],
+        json!([
+            { "asset": 0, "cloid": "0x270c78972fac98c8c6f6393d56224aaa" },
+            { "asset": 0, "cloid": "0x1a649a14d1659802d1d7a1e2a424e98f" },
+        ])
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_modify_order_generates_updated_with_new_venue_order_id() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = add_order(
+        &cache,
+        limit_order(
+            btc_id(),
+            "O-20250105-004",
+            OrderSide::Buy,
+            "0.02",
+            "95000.0",
+        ),
+        true,
+    );
+
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+    client
+        .modify_order(&ModifyOrder {
+            trader_id: TraderId::from("TRADER-001"),
+            client_id: ClientId::from("HYPERLIQUID"),
+            strategy_id: order.strategy_id(),
+            instrument_id: btc_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::new(MOCK_OID.to_string()),
+            quantity: Some(Quantity::from("0.03")),
+            price: Some(Price::from("96000.0")),
+            trigger_price: None,
+            command_id: UUID4::new(),
+            ts_init: UnixNanos::default(),
+        })
+        .unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    let OrderEventAny::Updated(updated) = &events[0] else {
+        panic!("Expected updated, was {:?}", events[0]);
+    };
+    assert_eq!(updated.quantity, Quantity::from("0.03"));
+    assert_eq!(updated.price, Some(Price::from("96000.0")));
+    assert_eq!(
+        updated.venue_order_id,
+        Some(VenueOrderId::new(MOCK_MODIFIED_OID.to_string()))
+    );
+
+    let actions = get_runtime().block_on(state.actions_for("batchModify"));
+    assert_eq!(actions.len(), 1);
+    let modify = &actions[0]["action"]["modifies"][0];
+    assert_eq!(modify["oid"], MOCK_OID);
+    assert_eq!(modify["order"]["p"], "96000");
+    assert_eq!(modify["order"]["s"], "0.03");
+    assert_eq!(modify["order"]["c"], "0x6a426e06b503882e42cea3f857473251");
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_generate_mass_status_from_info_fixtures() {
+    let _orders = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    let mut client = create_client(create_config(addr), cache);
+    client.connect().unwrap();
+
+    let mass_status = client.generate_mass_status(None).unwrap().unwrap();
+
+    assert_eq!(mass_status.account_id, account_id());
+    let order_reports = mass_status.order_reports();
+    assert_eq!(order_reports.len(), 2);
+    let stop = order_reports
+        .values()
+        .find(|report| report.venue_order_id == VenueOrderId::from("91490943"))
+        .unwrap();
+    assert_eq!(stop.instrument_id, eth_id());
+    assert_eq!(stop.order_type, OrderType::StopLimit);
+    assert_eq!(
+        mass_status
+            .fill_reports()
+            .values()
+            .map(Vec::len)
+            .sum::<usize>(),
+        2
+    );
+    let position_reports = mass_status.position_reports();
+    assert_eq!(position_reports.len(), 1);
+    let position = &position_reports[&btc_id()][0];
+    assert_eq!(position.position_side, PositionSideSpecified::Short);
+    assert_eq!(position.quantity, Quantity::from("0.05000"));
+
+    client.disconnect().unwrap();
+}
@@ -41,7 +41,7 @@ use ant_model::{
     },
     events::AccountState,
     identifiers::{AccountId, ClientOrderId, InstrumentId, Symbol, TradeId, VenueOrderId},
-    instruments::{CryptoFuture, CryptoPerpetual, CurrencyPair, InstrumentAny, OptionContract},
+    instruments::{CryptoFuture, CryptoPerpetual, CurrencyPair, InstrumentEnum, OptionContract},
     reports::{FillReport, OrderStatusReport, PositionStatusReport},
     types::{AccountBalance, Currency, Money, Price, Quantity},
 };
@@ -72,7 +72,7 @@ use crate::{
 /// would yield `Some("")`, which is semantically different from the intended
 /// absence of a value.  Applying this helper via
 ///
-/// ```rust
+/// ```ignore
 /// #[serde(deserialize_with = "crate::common::parse::deserialize_empty_string_as_none")]
 /// pub cl_ord_id: Option<String>,
 /// ```
@@ -148,17 +148,17 @@ fn get_currency(code: &str) -> Currency {
 }
 
 /// Returns the [`OKXInstrumentType`] that corresponds to the supplied
-/// [`InstrumentAny`].
+/// [`InstrumentEnum`].
 ///
 /// # Errors
 ///
 /// Returns an error if the instrument variant is not supported by OKX.
-pub fn okx_instrument_type(instrument: &InstrumentAny) -> anyhow::Result<OKXInstrumentType> {
+pub fn okx_instrument_type(instrument: &InstrumentEnum) -> anyhow::Result<OKXInstrumentType> {
     match instrument {
-        InstrumentAny::CurrencyPair(_) => Ok(OKXInstrumentType::Spot),
-        InstrumentAny::CryptoPerpetual(_) => Ok(OKXInstrumentType::Swap),
-        InstrumentAny::CryptoFuture(_) => Ok(OKXInstrumentType::Futures),
-        InstrumentAny::CryptoOption(_) => Ok(OKXInstrumentType::Option),
+        InstrumentEnum::CurrencyPair(_) => Ok(OKXInstrumentType::Spot),
+        InstrumentEnum::CryptoPerpetual(_) => Ok(OKXInstrumentType::Swap),
+        InstrumentEnum::CryptoFuture(_) => Ok(OKXInstrumentType::Futures),
+        InstrumentEnum::CryptoOption(_) => Ok(OKXInstrumentType::Option),
         _ => anyhow::bail!("Invalid instrument type for OKX: {instrument:?}"),
     }
 }
@@ -766,7 +766,7 @@ pub fn okx_channel_to_bar_spec(channel: &OKXWsChannel) -> Option<BarSpecificatio
 pub fn parse_instrument_any(
     instrument: &OKXInstrument,
     ts_init: UnixNanos,
-) -> anyhow::Result<Option<InstrumentAny>> {
+) -> anyhow::Result<Option<InstrumentEnum>> {
     match instrument.inst_type {
         OKXInstrumentType::Spot => {
             parse_spot_instrument(instrument, None, None, None, None, ts_init).map(Some)
@@ -817,7 +817,7 @@ trait InstrumentParser {
         common: CommonInstrumentData,
         margin_fees: MarginAndFees,
         ts_init: UnixNanos,
-    ) -> anyhow::Result<InstrumentAny>;
+    ) -> anyhow::Result<InstrumentEnum>;
 }
 
 /// Extracts common fields shared across all instrument types.
@@ -868,7 +868,7 @@ fn parse_instrument_with_parser<P: InstrumentParser>(
     maker_fee: Option<Decimal>,
     taker_fee: Option<Decimal>,
     ts_init: UnixNanos,
-) -> anyhow::Result<InstrumentAny> {
+) -> anyhow::Result<InstrumentEnum> {
     let common = parse_common_instrument_data(definition)?;
     parser.parse_specific_fields(
         definition,
@@ -893,7 +893,7 @@ impl InstrumentParser for SpotInstrumentParser {
         common: CommonInstrumentData,
         margin_fees: MarginAndFees,
         ts_init: UnixNanos,
-    ) -> anyhow::Result<InstrumentAny> {
+    ) -> anyhow::Result<InstrumentEnum> {
         let base_currency = get_currency(&definition.base_ccy);
         let quote_currency = get_currency(&definition.quote_ccy);
 
@@ -922,7 +922,7 @@ impl InstrumentParser for SpotInstrumentParser {
             ts_init,
         );
 
-        Ok(InstrumentAny::CurrencyPair(instrument))
+        Ok(InstrumentEnum::CurrencyPair(instrument))
     }
 }
 
@@ -934,7 +934,7 @@ pub fn parse_spot_instrument(
     maker_fee: Option<Decimal>,
     taker_fee: Option<Decimal>,
     ts_init: UnixNanos,
-) -> anyhow::Result<InstrumentAny> {
+) -> anyhow::Result<InstrumentEnum> {
     parse_instrument_with_parser(
         definition,
         SpotInstrumentParser,
@@ -954,7 +954,7 @@ pub fn parse_swap_instrument(
     maker_fee: Option<Decimal>,
     taker_fee: Option<Decimal>,
     ts_init: UnixNanos,
-) -> anyhow::Result<InstrumentAny> {
+) -> anyhow::Result<InstrumentEnum> {
     let instrument_id = parse_instrument_id(definition.inst_id);
     let raw_symbol = Symbol::from_ustr_unchecked(definition.inst_id);
     let (base_currency, quote_currency) = definition
@@ -1033,7 +1033,7 @@ pub fn parse_swap_instrument(
         ts_init,
     );
 
-    Ok(InstrumentAny::CryptoPerpetual(instrument))
+    Ok(InstrumentEnum::CryptoPerpetual(instrument))
 }
 
 /// Parses an OKX futures instrument definition into a ant crypto future.
@@ -1044,7 +1044,7 @@ pub fn parse_futures_instrument(
     maker_fee: Option<Decimal>,
     taker_fee: Option<Decimal>,
     ts_init: UnixNanos,
-) -> anyhow::Result<InstrumentAny> {
+) -> anyhow::Result<InstrumentEnum> {
     let instrument_id = parse_instrument_id(definition.inst_id);
     let raw_symbol = Symbol::from_ustr_unchecked(definition.inst_id);
     let underlying = get_currency(&definition.uly);
@@ -1109,7 +1109,7 @@ pub fn parse_futures_instrument(
         ts_init,
     );
 
-    Ok(InstrumentAny::CryptoFuture(instrument))
+    Ok(InstrumentEnum::CryptoFuture(instrument))
 }
 
 /// Parses an OKX option instrument definition into a ant option contract.
@@ -1120,7 +1120,7 @@ pub fn parse_option_instrument(
     maker_fee: Option<Decimal>,
     taker_fee: Option<Decimal>,
     ts_init: UnixNanos,
-) -> anyhow::Result<InstrumentAny> {
+) -> anyhow::Result<InstrumentEnum> {
     let instrument_id = parse_instrument_id(definition.inst_id);
     let raw_symbol = Symbol::from_ustr_unchecked(definition.inst_id);
     let asset_class = AssetClass::Cryptocurrency;
@@ -1181,7 +1181,7 @@ pub fn parse_option_instrument(
         ts_init,
     );
 
-    Ok(InstrumentAny::OptionContract(instrument))
+    Ok(InstrumentEnum::OptionContract(instrument))
 }
 
 /// Parses an OKX account into a ant account state.
@@ -1225,9 +1225,7 @@ pub fn parse_account_state(
 
 #[cfg(test)]
 mod tests {
-    use ant_model::{
-        enums::AggregationSource, identifiers::InstrumentId, instruments::Instrument,
-    };
+    use ant_model::{enums::AggregationSource, identifiers::InstrumentId, instruments::Instrument};
     use rstest::rstest;
 
     use super::*;
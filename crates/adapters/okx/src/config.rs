@@ -15,4 +15,219 @@
 
 //! Configuration structures for the OKX adapter.
 //!
-//! This module will contain configuration options for the OKX data and execution clients.
+//! Credentials which are not provided are read from the `OKX_API_KEY`, `OKX_API_SECRET` and
+//! `OKX_API_PASSPHRASE` environment variables, and URLs which are not provided default to the
+//! production (or demo) endpoints.
+
+use ant_model::{
+    enums::OmsType,
+    identifiers::{AccountId, TraderId},
+};
+
+use crate::common::{
+    enums::{OKXInstrumentType, OKXPositionMode, OKXTradeMode},
+    urls::{
+        get_http_base_url, get_ws_base_url_business, get_ws_base_url_private,
+        get_ws_base_url_public,
+    },
+};
+
+/// The instrument types loaded by default: spot, swap, futures and options.
+pub const OKX_DEFAULT_INSTRUMENT_TYPES: [OKXInstrumentType; 4] = [
+    OKXInstrumentType::Spot,
+    OKXInstrumentType::Swap,
+    OKXInstrumentType::Futures,
+    OKXInstrumentType::Option,
+];
+
+/// Configuration for the OKX data client.
+#[derive(Debug, Clone)]
+pub struct OKXDataClientConfig {
+    /// The API key (only required for private data).
+    pub api_key: Option<String>,
+    /// The API secret.
+    pub api_secret: Option<String>,
+    /// The API passphrase.
+    pub api_passphrase: Option<String>,
+    /// The instrument types to load on connect.
+    pub instrument_types: Vec<OKXInstrumentType>,
+    /// The HTTP base URL override.
+    pub base_url_http: Option<String>,
+    /// The public WebSocket URL override.
+    pub base_url_ws_public: Option<String>,
+    /// The business WebSocket URL override (used for bars).
+    pub base_url_ws_business: Option<String>,
+    /// If the demo trading endpoints should be used.
+    pub is_demo: bool,
+    /// The HTTP request timeout in seconds.
+    pub http_timeout_secs: Option<u64>,
+}
+
+impl Default for OKXDataClientConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            api_secret: None,
+            api_passphrase: None,
+            instrument_types: OKX_DEFAULT_INSTRUMENT_TYPES.to_vec(),
+            base_url_http: None,
+            base_url_ws_public: None,
+            base_url_ws_business: None,
+            is_demo: false,
+            http_timeout_secs: Some(60),
+        }
+    }
+}
+
+impl OKXDataClientConfig {
+    /// Creates a new [`OKXDataClientConfig`] instance loading the `instrument_types`.
+    #[must_use]
+    pub fn new(instrument_types: Vec<OKXInstrumentType>) -> Self {
+        Self {
+            instrument_types,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether all of the API credentials have been provided.
+    #[must_use]
+    pub const fn has_api_credentials(&self) -> bool {
+        self.api_key.is_some() && self.api_secret.is_some() && self.api_passphrase.is_some()
+    }
+
+    /// Returns the HTTP base URL.
+    #[must_use]
+    pub fn http_base_url(&self) -> String {
+        self.base_url_http.clone().unwrap_or_else(get_http_base_url)
+    }
+
+    /// Returns the public WebSocket URL.
+    #[must_use]
+    pub fn ws_public_url(&self) -> String {
+        self.base_url_ws_public
+            .clone()
+            .unwrap_or_else(|| get_ws_base_url_public(self.is_demo))
+    }
+
+    /// Returns the business WebSocket URL.
+    #[must_use]
+    pub fn ws_business_url(&self) -> String {
+        self.base_url_ws_business
+            .clone()
+            .unwrap_or_else(|| get_ws_base_url_business(self.is_demo))
+    }
+}
+
+/// Configuration for the OKX execution client.
+#[derive(Debug, Clone)]
+pub struct OKXExecClientConfig {
+    /// The trader ID for the client.
+    pub trader_id: TraderId,
+    /// The account ID for the client.
+    pub account_id: AccountId,
+    /// The API key (read from `OKX_API_KEY` if `None`).
+    pub api_key: Option<String>,
+    /// The API secret (read from `OKX_API_SECRET` if `None`).
+    pub api_secret: Option<String>,
+    /// The API passphrase (read from `OKX_API_PASSPHRASE` if `None`).
+    pub api_passphrase: Option<String>,
+    /// The instrument types to trade.
+    pub instrument_types: Vec<OKXInstrumentType>,
+    /// The position mode set for the account on connect.
+    pub position_mode: OKXPositionMode,
+    /// The trade mode for margin instruments (spot orders always use [`OKXTradeMode::Cash`]).
+    pub margin_mode: OKXTradeMode,
+    /// The HTTP base URL override.
+    pub base_url_http: Option<String>,
+    /// The private WebSocket URL override.
+    pub base_url_ws_private: Option<String>,
+    /// If the demo trading endpoints should be used.
+    pub is_demo: bool,
+    /// The HTTP request timeout in seconds.
+    pub http_timeout_secs: Option<u64>,
+}
+
+impl OKXExecClientConfig {
+    /// Creates a new [`OKXExecClientConfig`] instance.
+    #[must_use]
+    pub fn new(trader_id: TraderId, account_id: AccountId) -> Self {
+        Self {
+            trader_id,
+            account_id,
+            api_key: None,
+            api_secret: None,
+            api_passphrase: None,
+            instrument_types: OKX_DEFAULT_INSTRUMENT_TYPES.to_vec(),
+            position_mode: OKXPositionMode::NetMode,
+            margin_mode: OKXTradeMode::Cross,
+            base_url_http: None,
+            base_url_ws_private: None,
+            is_demo: false,
+            http_timeout_secs: Some(60),
+        }
+    }
+
+    /// Returns the order management system type implied by the position mode.
+    #[must_use]
+    pub const fn oms_type(&self) -> OmsType {
+        match self.position_mode {
+            OKXPositionMode::NetMode => OmsType::Netting,
+            OKXPositionMode::LongShortMode => OmsType::Hedging,
+        }
+    }
+
+    /// Returns the HTTP base URL.
+    #[must_use]
+    pub fn http_base_url(&self) -> String {
+        self.base_url_http.clone().unwrap_or_else(get_http_base_url)
+    }
+
+    /// Returns the private WebSocket URL.
+    #[must_use]
+    pub fn ws_private_url(&self) -> String {
+        self.base_url_ws_private
+            .clone()
+            .unwrap_or_else(|| get_ws_base_url_private(self.is_demo))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_data_client_config_urls() {
+        let mut config = OKXDataClientConfig::default();
+        assert_eq!(
+            config.instrument_types,
+            OKX_DEFAULT_INSTRUMENT_TYPES.to_vec()
+        );
+        assert_eq!(config.ws_public_url(), get_ws_base_url_public(false));
+
+        config.base_url_ws_public = Some("ws://127.0.0.1:8080/ws/v5/public".to_string());
+        config.is_demo = true;
+        assert_eq!(config.ws_public_url(), "ws://127.0.0.1:8080/ws/v5/public");
+        assert_eq!(config.ws_business_url(), get_ws_base_url_business(true));
+        assert!(!config.has_api_credentials());
+    }
+
+    #[rstest]
+    #[case(OKXPositionMode::NetMode, OmsType::Netting)]
+    #[case(OKXPositionMode::LongShortMode, OmsType::Hedging)]
+    fn test_exec_client_config_oms_type(
+        #[case] position_mode: OKXPositionMode,
+        #[case] expected: OmsType,
+    ) {
+        let mut config =
+            OKXExecClientConfig::new(TraderId::from("TRADER-001"), AccountId::from("OKX-001"));
+        config.position_mode = position_mode;
+
+        assert_eq!(config.oms_type(), expected);
+    }
+}
@@ -0,0 +1,1109 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the OKX live execution client.
+//!
+//! Orders are placed, amended and canceled over the private WebSocket, which also streams order
+//! and account updates. Reports for reconciliation are requested over HTTP.
+//!
+//! WebSocket messages arrive on the shared runtime and are queued to the client, which must be
+//! drained from the engine thread with [`OKXExecutionClient::drain_events`] so that order events
+//! are published on the thread owning the message bus.
+//!
+//! Order commands spawn their requests on the shared runtime rather than waiting for the venue,
+//! and a failed request is queued to the client as a rejected event in the same way.
+
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    rc::Rc,
+};
+
+use ahash::AHashSet;
+use ant_common::{
+    cache::Cache,
+    clock::Clock,
+    messages::execution::{
+        BatchCancelOrders, CancelAllOrders, CancelOrder, GenerateFillReports,
+        GenerateOrderStatusReport, GeneratePositionReports, ModifyOrder, QueryAccount, QueryOrder,
+        SubmitOrder, SubmitOrderList,
+    },
+    runtime::{block_on, get_runtime},
+};
+use ant_core::{UUID4, UnixNanos, time::get_atomic_clock_realtime};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient, base::BaseExecutionClient};
+use ant_model::{
+    accounts::AccountAny,
+    enums::{AccountType, OmsType, OrderSide, OrderStatus, PositionSide},
+    events::{AccountState, OrderCancelRejected, OrderModifyRejected, OrderRejected},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, Venue,
+        VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{AccountBalance, MarginBalance},
+};
+use chrono::{Duration, Utc};
+use futures_util::StreamExt;
+use tokio::{
+    sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+    task::JoinHandle,
+};
+use ustr::Ustr;
+
+use crate::{
+    common::{
+        consts::{OKX_SUPPORTED_ORDER_TYPES, OKX_SUPPORTED_TIME_IN_FORCE, OKX_VENUE},
+        enums::{OKXInstrumentType, OKXPositionMode, OKXTradeMode},
+        parse::okx_instrument_type,
+    },
+    config::OKXExecClientConfig,
+    http::client::OKXHttpClient,
+    websocket::{
+        client::OKXWebSocketClient,
+        error::OKXWsError,
+        messages::{AntWsMessage, ExecutionReport},
+    },
+};
+
+/// The heartbeat interval in seconds, OKX closes connections idle for 30 seconds.
+const OKX_WS_HEARTBEAT_SECS: u64 = 20;
+
+/// The maximum time in seconds to wait for the WebSocket connection to become active.
+const OKX_WS_CONNECT_TIMEOUT_SECS: f64 = 10.0;
+
+/// An OKX execution client trading spot, swap, futures and options.
+///
+/// The client uses [`OmsType::Netting`] in net position mode and [`OmsType::Hedging`] in
+/// long/short position mode, where the OKX position side is derived from the order side and
+/// whether the order is reduce-only.
+pub struct OKXExecutionClient {
+    core: BaseExecutionClient,
+    config: OKXExecClientConfig,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    http_client: OKXHttpClient,
+    ws_client: OKXWebSocketClient,
+    is_connected: Cell<bool>,
+    event_tx: RefCell<Option<UnboundedSender<AntWsMessage>>>,
+    event_rx: RefCell<Option<UnboundedReceiver<AntWsMessage>>>,
+    stream_handle: RefCell<Option<JoinHandle<()>>>,
+    /// Orders for which an accepted event has been generated by this client.
+    accepted: RefCell<AHashSet<ClientOrderId>>,
+    /// Trades already applied, as OKX may resend fills on reconnect.
+    trade_ids: RefCell<AHashSet<TradeId>>,
+}
+
+impl std::fmt::Debug for OKXExecutionClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(OKXExecutionClient))
+            .field("client_id", &self.core.client_id)
+            .field("account_id", &self.core.account_id)
+            .field("position_mode", &self.config.position_mode)
+            .finish()
+    }
+}
+
+impl OKXExecutionClient {
+    /// Creates a new [`OKXExecutionClient`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API credentials are not provided or found in the environment.
+    pub fn new(
+        client_id: ClientId,
+        config: OKXExecClientConfig,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+    ) -> anyhow::Result<Self> {
+        let http_client = OKXHttpClient::with_credentials(
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            Some(config.http_base_url()),
+            config.http_timeout_secs,
+            None,
+            None,
+            None,
+        )?;
+        let ws_client = OKXWebSocketClient::with_credentials(
+            Some(config.ws_private_url()),
+            config.api_key.clone(),
+            config.api_secret.clone(),
+            config.api_passphrase.clone(),
+            Some(config.account_id),
+            Some(OKX_WS_HEARTBEAT_SECS),
+        )?;
+
+        let core = BaseExecutionClient::new(
+            config.trader_id,
+            client_id,
+            *OKX_VENUE,
+            config.oms_type(),
+            config.account_id,
+            AccountType::Margin,
+            None,
+            clock.clone(),
+            cache.clone(),
+        );
+
+        Ok(Self {
+            core,
+            config,
+            clock,
+            cache,
+            http_client,
+            ws_client,
+            is_connected: Cell::new(false),
+            event_tx: RefCell::new(None),
+            event_rx: RefCell::new(None),
+            stream_handle: RefCell::new(None),
+            accepted: RefCell::new(AHashSet::new()),
+            trade_ids: RefCell::new(AHashSet::new()),
+        })
+    }
+
+    /// Processes all queued WebSocket messages, returning the number processed.
+    ///
+    /// Must be called from the thread owning the message bus, typically on each engine tick.
+    pub fn drain_events(&self) -> usize {
+        let mut count = 0;
+        loop {
+            // Release the receiver borrow before handling, as strategies may react to an event
+            // by sending further commands to the client
+            let msg = match self.event_rx.borrow_mut().as_mut() {
+                Some(rx) => match rx.try_recv() {
+                    Ok(msg) => msg,
+                    Err(_) => break,
+                },
+                None => break,
+            };
+            self.handle_ws_message(msg);
+            count += 1;
+        }
+        count
+    }
+
+    fn handle_ws_message(&self, msg: AntWsMessage) {
+        match msg {
+            AntWsMessage::ExecutionReports(reports) => {
+                for report in reports {
+                    match report {
+                        ExecutionReport::Order(report) => self.handle_order_report(&report),
+                        ExecutionReport::Fill(report) => self.handle_fill_report(&report),
+                    }
+                }
+            }
+            AntWsMessage::AccountUpdate(state) => self.apply_account_state(state),
+            AntWsMessage::OrderRejected(event) => self.core.generate_order_rejected(
+                event.strategy_id,
+                event.instrument_id,
+                event.client_order_id,
+                &event.reason,
+                event.ts_event,
+                event.due_post_only != 0,
+            ),
+            AntWsMessage::OrderCancelRejected(event) => {
+                let venue_order_id = event
+                    .venue_order_id
+                    .or_else(|| self.venue_order_id(&event.client_order_id));
+                if let Some(venue_order_id) = venue_order_id {
+                    self.core.generate_order_cancel_rejected(
+                        event.strategy_id,
+                        event.instrument_id,
+                        event.client_order_id,
+                        venue_order_id,
+                        &event.reason,
+                        event.ts_event,
+                    );
+                }
+            }
+            AntWsMessage::OrderModifyRejected(event) => {
+                let venue_order_id = event
+                    .venue_order_id
+                    .or_else(|| self.venue_order_id(&event.client_order_id));
+                if let Some(venue_order_id) = venue_order_id {
+                    self.core.generate_order_modify_rejected(
+                        event.strategy_id,
+                        event.instrument_id,
+                        event.client_order_id,
+                        venue_order_id,
+                        &event.reason,
+                        event.ts_event,
+                    );
+                }
+            }
+            AntWsMessage::Error(e) => log::error!("OKX WebSocket error {}: {}", e.code, e.message),
+            AntWsMessage::Reconnected => log::info!("OKX WebSocket reconnected"),
+            other => log::debug!("Unhandled WebSocket message: {other:?}"),
+        }
+    }
+
+    fn venue_order_id(&self, client_order_id: &ClientOrderId) -> Option<VenueOrderId> {
+        let venue_order_id = self.cache.borrow().venue_order_id(client_order_id).copied();
+        if venue_order_id.is_none() {
+            log::warn!("No venue order ID found for {client_order_id}");
+        }
+        venue_order_id
+    }
+
+    fn apply_account_state(&self, state: AccountState) {
+        if let Err(e) = self.core.generate_account_state(
+            state.balances,
+            state.margins,
+            state.is_reported,
+            state.ts_event,
+        ) {
+            log::error!("Failed to generate account state: {e}");
+        }
+    }
+
+    /// Returns the cached order for the report, or `None` for orders not placed by ant.
+    fn report_order(
+        &self,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: &VenueOrderId,
+    ) -> Option<OrderAny> {
+        let cache = self.cache.borrow();
+        let client_order_id = client_order_id
+            .filter(|id| cache.order(id).is_some())
+            .or_else(|| cache.client_order_id(venue_order_id).copied())?;
+        cache.order(&client_order_id).cloned()
+    }
+
+    /// Generates an accepted event if the order has not yet been accepted.
+    fn ensure_accepted(&self, order: &OrderAny, venue_order_id: VenueOrderId, ts_event: UnixNanos) {
+        if order.status() == OrderStatus::Submitted
+            && self.accepted.borrow_mut().insert(order.client_order_id())
+        {
+            self.core.generate_order_accepted(
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                venue_order_id,
+                ts_event,
+            );
+        }
+    }
+
+    fn handle_order_report(&self, report: &OrderStatusReport) {
+        let Some(order) = self.report_order(report.client_order_id, &report.venue_order_id) else {
+            log::debug!("Order report for external order {}", report.venue_order_id);
+            return;
+        };
+
+        let strategy_id = order.strategy_id();
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let venue_order_id = report.venue_order_id;
+        let ts_event = report.ts_last;
+
+        match report.order_status {
+            OrderStatus::Accepted => {
+                if order.status() == OrderStatus::Submitted {
+                    self.ensure_accepted(&order, venue_order_id, ts_event);
+                } else if report.quantity != order.quantity()
+                    || (report.price.is_some() && report.price != order.price())
+                {
+                    // OKX republishes the live state after an amendment
+                    let Some(price) = report.price.or_else(|| order.price()) else {
+                        return;
+                    };
+                    self.core.generate_order_updated(
+                        strategy_id,
+                        instrument_id,
+                        client_order_id,
+                        venue_order_id,
+                        report.quantity,
+                        price,
+                        report.trigger_price,
+                        ts_event,
+                        false,
+                    );
+                }
+            }
+            OrderStatus::Triggered => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_triggered(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Canceled => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_canceled(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Expired => {
+                self.ensure_accepted(&order, venue_order_id, ts_event);
+                self.core.generate_order_expired(
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    venue_order_id,
+                    ts_event,
+                );
+            }
+            OrderStatus::Rejected => self.core.generate_order_rejected(
+                strategy_id,
+                instrument_id,
+                client_order_id,
+                report.cancel_reason.as_deref().unwrap_or("Rejected by OKX"),
+                ts_event,
+                report.post_only,
+            ),
+            status => log::debug!("Order {client_order_id} status {status:?}"),
+        }
+    }
+
+    fn handle_fill_report(&self, report: &FillReport) {
+        let Some(order) = self.report_order(report.client_order_id, &report.venue_order_id) else {
+            log::debug!("Fill report for external order {}", report.venue_order_id);
+            return;
+        };
+
+        if !self.trade_ids.borrow_mut().insert(report.trade_id) {
+            log::debug!("Duplicate fill {}", report.trade_id);
+            return;
+        }
+
+        let instrument = self.instrument(&order.instrument_id());
+        let Some(instrument) = instrument else {
+            log::error!("Instrument {} not found in cache", order.instrument_id());
+            return;
+        };
+
+        self.ensure_accepted(&order, report.venue_order_id, report.ts_event);
+
+        let position_side =
+            self.position_side(&instrument, order.order_side(), order.is_reduce_only());
+        self.core.generate_order_filled(
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.venue_position_id.unwrap_or_else(|| {
+                venue_position_id(order.instrument_id(), order.strategy_id(), position_side)
+            }),
+            report.trade_id,
+            order.order_side(),
+            order.order_type(),
+            report.last_qty,
+            report.last_px,
+            instrument.quote_currency(),
+            report.commission,
+            report.liquidity_side,
+            report.ts_event,
+        );
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> Option<InstrumentEnum> {
+        self.cache.borrow().instrument(instrument_id).cloned()
+    }
+
+    /// Returns the trade mode for orders on the `instrument`.
+    fn trade_mode(&self, instrument: &InstrumentEnum) -> OKXTradeMode {
+        match okx_instrument_type(instrument) {
+            Ok(OKXInstrumentType::Spot) => OKXTradeMode::Cash,
+            _ => self.config.margin_mode,
+        }
+    }
+
+    /// Returns the OKX position side for an order, which is only set in long/short mode.
+    fn position_side(
+        &self,
+        instrument: &InstrumentEnum,
+        order_side: OrderSide,
+        reduce_only: bool,
+    ) -> Option<PositionSide> {
+        if self.config.position_mode != OKXPositionMode::LongShortMode
+            || matches!(okx_instrument_type(instrument), Ok(OKXInstrumentType::Spot))
+        {
+            return None;
+        }
+        okx_position_side(order_side, reduce_only)
+    }
+
+    fn reject(&self, cmd: &SubmitOrder, reason: &str) {
+        log::error!("Rejecting order {}: {reason}", cmd.client_order_id);
+        self.core.generate_order_rejected(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            reason,
+            self.clock.borrow().timestamp_ns(),
+            false,
+        );
+    }
+
+    /// Spawns an order `request` on the shared runtime, so the calling thread is not held for
+    /// the round trip.
+    ///
+    /// If the request fails the event built by `on_error`, from the reason and the time of the
+    /// failure, is queued to be published from [`OKXExecutionClient::drain_events`].
+    fn spawn_order_request<F, E>(&self, request: F, on_error: E)
+    where
+        F: Future<Output = Result<(), OKXWsError>> + Send + 'static,
+        E: FnOnce(String, UnixNanos) -> AntWsMessage + Send + 'static,
+    {
+        let Some(tx) = self.event_tx.borrow().clone() else {
+            log::error!("Cannot send order request: OKX execution client not connected");
+            return;
+        };
+        get_runtime().spawn(async move {
+            if let Err(e) = request.await {
+                let ts_event = get_atomic_clock_realtime().get_time_ns();
+                if tx.send(on_error(e.to_string(), ts_event)).is_err() {
+                    log::error!("Order request failed after disconnect: {e}");
+                }
+            }
+        });
+    }
+
+    fn submit(&self, cmd: &SubmitOrder, order: &OrderAny) {
+        if !self.is_connected.get() {
+            self.reject(cmd, "OKX execution client not connected");
+            return;
+        }
+
+        let Some(instrument) = self.instrument(&order.instrument_id()) else {
+            return self.reject(
+                cmd,
+                &format!("Instrument {} not found", order.instrument_id()),
+            );
+        };
+
+        if !OKX_SUPPORTED_ORDER_TYPES.contains(&order.order_type()) {
+            return self.reject(
+                cmd,
+                &format!("Unsupported order type {}", order.order_type()),
+            );
+        }
+        if !OKX_SUPPORTED_TIME_IN_FORCE.contains(&order.time_in_force()) {
+            return self.reject(
+                cmd,
+                &format!("Unsupported time in force {}", order.time_in_force()),
+            );
+        }
+
+        self.core.generate_order_submitted(
+            cmd.strategy_id,
+            cmd.instrument_id,
+            cmd.client_order_id,
+            self.clock.borrow().timestamp_ns(),
+        );
+
+        let ws_client = self.ws_client.clone();
+        let trader_id = cmd.trader_id;
+        let strategy_id = cmd.strategy_id;
+        let instrument_id = order.instrument_id();
+        let client_order_id = order.client_order_id();
+        let account_id = self.core.account_id;
+        let trade_mode = self.trade_mode(&instrument);
+        let position_side =
+            self.position_side(&instrument, order.order_side(), order.is_reduce_only());
+        let order = order.clone();
+
+        self.spawn_order_request(
+            async move {
+                ws_client
+                    .submit_order(
+                        trader_id,
+                        strategy_id,
+                        instrument_id,
+                        trade_mode,
+                        client_order_id,
+                        order.order_side(),
+                        order.order_type(),
+                        order.quantity(),
+                        Some(order.time_in_force()),
+                        order.price(),
+                        order.trigger_price(),
+                        Some(order.is_post_only()),
+                        Some(order.is_reduce_only()),
+                        Some(order.is_quote_quantity()),
+                        position_side,
+                    )
+                    .await
+            },
+            move |reason, ts_event| {
+                AntWsMessage::OrderRejected(OrderRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    account_id,
+                    Ustr::from(&format!("Failed to submit order: {reason}")),
+                    UUID4::new(),
+                    ts_event,
+                    ts_event,
+                    false,
+                    false,
+                ))
+            },
+        );
+    }
+
+    /// Sends a cancel for the order, a failure is reported as an order cancel rejected event.
+    fn cancel(
+        &self,
+        strategy_id: StrategyId,
+        instrument_id: InstrumentId,
+        client_order_id: ClientOrderId,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.is_connected.get(),
+            "OKX execution client not connected"
+        );
+
+        let venue_order_id = self
+            .cache
+            .borrow()
+            .venue_order_id(&client_order_id)
+            .copied();
+        let ws_client = self.ws_client.clone();
+        let trader_id = self.core.trader_id;
+        let account_id = self.core.account_id;
+
+        self.spawn_order_request(
+            async move {
+                ws_client
+                    .cancel_order(
+                        trader_id,
+                        strategy_id,
+                        instrument_id,
+                        Some(client_order_id),
+                        venue_order_id,
+                    )
+                    .await
+            },
+            move |reason, ts_event| {
+                AntWsMessage::OrderCancelRejected(OrderCancelRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    Ustr::from(&reason),
+                    UUID4::new(),
+                    ts_event,
+                    ts_event,
+                    false,
+                    venue_order_id,
+                    Some(account_id),
+                ))
+            },
+        );
+        Ok(())
+    }
+
+    fn request_account_state(&self) -> anyhow::Result<()> {
+        let state = block_on(self.http_client.request_account_state(self.core.account_id))?;
+        self.apply_account_state(state);
+        Ok(())
+    }
+
+    /// Returns the instrument types to query reports for, narrowed by the `instrument_id`.
+    fn report_instrument_types(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> Vec<Option<OKXInstrumentType>> {
+        match instrument_id {
+            // The instrument type is resolved from the instrument
+            Some(_) => vec![None],
+            None => self
+                .config
+                .instrument_types
+                .iter()
+                .map(|instrument_type| Some(*instrument_type))
+                .collect(),
+        }
+    }
+
+    fn request_order_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        lookback_mins: Option<u64>,
+        open_only: bool,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        let start = lookback_start(lookback_mins);
+        let mut reports = Vec::new();
+        for instrument_type in self.report_instrument_types(instrument_id) {
+            reports.extend(block_on(self.http_client.request_order_status_reports(
+                self.core.account_id,
+                instrument_type,
+                instrument_id,
+                start,
+                None,
+                open_only,
+                None,
+            ))?);
+        }
+        Ok(reports)
+    }
+
+    fn request_fill_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Vec<FillReport>> {
+        let start = lookback_start(lookback_mins);
+        let mut reports = Vec::new();
+        for instrument_type in self.report_instrument_types(instrument_id) {
+            reports.extend(block_on(self.http_client.request_fill_reports(
+                self.core.account_id,
+                instrument_type,
+                instrument_id,
+                start,
+                None,
+                None,
+            ))?);
+        }
+        Ok(reports)
+    }
+
+    fn request_position_status_reports(
+        &self,
+        instrument_id: Option<InstrumentId>,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        let mut reports = Vec::new();
+        for instrument_type in self.report_instrument_types(instrument_id) {
+            // OKX has no positions for spot instruments
+            if instrument_type == Some(OKXInstrumentType::Spot) {
+                continue;
+            }
+            reports.extend(block_on(self.http_client.request_position_status_reports(
+                self.core.account_id,
+                instrument_type,
+                instrument_id,
+            ))?);
+        }
+        Ok(reports)
+    }
+
+    /// Loads the instruments for the configured instrument types, merged with any OKX
+    /// instruments already in the cache.
+    fn load_instruments(&mut self) -> anyhow::Result<Vec<InstrumentEnum>> {
+        let mut instruments: Vec<InstrumentEnum> = self
+            .cache
+            .borrow()
+            .instruments(&OKX_VENUE, None)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        for instrument_type in &self.config.instrument_types {
+            let loaded = block_on(self.http_client.request_instruments(*instrument_type))?;
+            log::info!("Loaded {} {instrument_type:?} instruments", loaded.len());
+            instruments.extend(loaded);
+        }
+
+        self.http_client.add_instruments(instruments.clone());
+        Ok(instruments)
+    }
+}
+
+/// Returns the OKX position side in long/short mode, reduce-only orders close the opposite side.
+#[must_use]
+pub const fn okx_position_side(order_side: OrderSide, reduce_only: bool) -> Option<PositionSide> {
+    match (order_side, reduce_only) {
+        (OrderSide::Buy, false) | (OrderSide::Sell, true) => Some(PositionSide::Long),
+        (OrderSide::Sell, false) | (OrderSide::Buy, true) => Some(PositionSide::Short),
+        _ => None,
+    }
+}
+
+fn venue_position_id(
+    instrument_id: InstrumentId,
+    strategy_id: StrategyId,
+    position_side: Option<PositionSide>,
+) -> PositionId {
+    match position_side {
+        Some(side) => PositionId::new(format!("{instrument_id}-{side}")),
+        None => PositionId::new(format!("{instrument_id}-{strategy_id}")),
+    }
+}
+
+fn lookback_start(lookback_mins: Option<u64>) -> Option<chrono::DateTime<Utc>> {
+    lookback_mins
+        .map(|mins| Utc::now() - Duration::minutes(i64::try_from(mins).unwrap_or(i64::MAX)))
+}
+
+impl ExecutionClient for OKXExecutionClient {
+    fn is_connected(&self) -> bool {
+        self.is_connected.get()
+    }
+
+    fn client_id(&self) -> ClientId {
+        self.core.client_id
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.core.account_id
+    }
+
+    fn venue(&self) -> Venue {
+        self.core.venue
+    }
+
+    fn oms_type(&self) -> OmsType {
+        self.core.oms_type
+    }
+
+    fn get_account(&self) -> Option<AccountAny> {
+        self.core.get_account()
+    }
+
+    fn generate_account_state(
+        &self,
+        balances: Vec<AccountBalance>,
+        margins: Vec<MarginBalance>,
+        reported: bool,
+        ts_event: UnixNanos,
+    ) -> anyhow::Result<()> {
+        self.core
+            .generate_account_state(balances, margins, reported, ts_event)
+    }
+
+    fn start(&mut self) -> anyhow::Result<()> {
+        log::info!("Started");
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        log::info!("Stopped");
+        Ok(())
+    }
+
+    fn submit_order(&self, cmd: &SubmitOrder) -> anyhow::Result<()> {
+        self.submit(cmd, &cmd.order);
+        Ok(())
+    }
+
+    fn submit_order_list(&self, cmd: &SubmitOrderList) -> anyhow::Result<()> {
+        // Contingent order lists are not supported, orders are submitted individually
+        for order in &cmd.order_list.orders {
+            let submit = SubmitOrder::new(
+                cmd.trader_id,
+                cmd.client_id,
+                cmd.strategy_id,
+                order.instrument_id(),
+                order.client_order_id(),
+                cmd.venue_order_id,
+                order.clone(),
+                cmd.exec_algorith_id,
+                cmd.position_id,
+                cmd.command_id,
+                cmd.ts_init,
+            )?;
+            self.submit(&submit, order);
+        }
+        Ok(())
+    }
+
+    fn modify_order(&self, cmd: &ModifyOrder) -> anyhow::Result<()> {
+        let venue_order_id = self
+            .cache
+            .borrow()
+            .venue_order_id(&cmd.client_order_id)
+            .copied();
+        if !self.is_connected.get() {
+            self.core.generate_order_modify_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                "OKX execution client not connected",
+                self.clock.borrow().timestamp_ns(),
+            );
+            return Ok(());
+        }
+
+        let ws_client = self.ws_client.clone();
+        let trader_id = cmd.trader_id;
+        let strategy_id = cmd.strategy_id;
+        let instrument_id = cmd.instrument_id;
+        let client_order_id = cmd.client_order_id;
+        let price = cmd.price;
+        let quantity = cmd.quantity;
+        let account_id = self.core.account_id;
+        let rejected_venue_order_id = venue_order_id.unwrap_or(cmd.venue_order_id);
+
+        self.spawn_order_request(
+            async move {
+                ws_client
+                    .modify_order(
+                        trader_id,
+                        strategy_id,
+                        instrument_id,
+                        Some(client_order_id),
+                        price,
+                        quantity,
+                        venue_order_id,
+                    )
+                    .await
+            },
+            move |reason, ts_event| {
+                AntWsMessage::OrderModifyRejected(OrderModifyRejected::new(
+                    trader_id,
+                    strategy_id,
+                    instrument_id,
+                    client_order_id,
+                    Ustr::from(&reason),
+                    UUID4::new(),
+                    ts_event,
+                    ts_event,
+                    false,
+                    Some(rejected_venue_order_id),
+                    Some(account_id),
+                ))
+            },
+        );
+        Ok(())
+    }
+
+    fn cancel_order(&self, cmd: &CancelOrder) -> anyhow::Result<()> {
+        if let Err(e) = self.cancel(cmd.strategy_id, cmd.instrument_id, cmd.client_order_id) {
+            self.core.generate_order_cancel_rejected(
+                cmd.strategy_id,
+                cmd.instrument_id,
+                cmd.client_order_id,
+                cmd.venue_order_id,
+                &e.to_string(),
+                self.clock.borrow().timestamp_ns(),
+            );
+        }
+        Ok(())
+    }
+
+    fn cancel_all_orders(&self, cmd: &CancelAllOrders) -> anyhow::Result<()> {
+        // OKX mass cancel is limited to options market maker protection, so orders are
+        // canceled individually
+        let side = (cmd.order_side != OrderSide::NoOrderSide).then_some(cmd.order_side);
+        let orders: Vec<(StrategyId, ClientOrderId)> = self
+            .cache
+            .borrow()
+            .orders_open(Some(&self.core.venue), Some(&cmd.instrument_id), None, side)
+            .into_iter()
+            .map(|order| (order.strategy_id(), order.client_order_id()))
+            .collect();
+
+        for (strategy_id, client_order_id) in orders {
+            if let Err(e) = self.cancel(strategy_id, cmd.instrument_id, client_order_id) {
+                log::error!("Failed to cancel {client_order_id}: {e}");
+            }
+        }
+        Ok(())
+    }
+
+    fn batch_cancel_orders(&self, cmd: &BatchCancelOrders) -> anyhow::Result<()> {
+        for cancel in &cmd.cancels {
+            self.cancel_order(cancel)?;
+        }
+        Ok(())
+    }
+
+    fn query_account(&self, cmd: &QueryAccount) -> anyhow::Result<()> {
+        log::debug!("Query account: {cmd:?}");
+        self.request_account_state()
+    }
+
+    fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()> {
+        let report = self
+            .request_order_status_reports(Some(cmd.instrument_id), None, false)?
+            .into_iter()
+            .find(|report| report.client_order_id == Some(cmd.client_order_id));
+        match report {
+            Some(report) => self.handle_order_report(&report),
+            None => log::warn!("Order {} not found", cmd.client_order_id),
+        }
+        Ok(())
+    }
+
+    fn as_live(&self) -> Option<&dyn LiveExecutionClient> {
+        Some(self)
+    }
+}
+
+impl LiveExecutionClient for OKXExecutionClient {
+    fn connect(&mut self) -> anyhow::Result<()> {
+        if self.is_connected.get() {
+            return Ok(());
+        }
+
+        let instruments = self.load_instruments()?;
+        block_on(
+            self.http_client
+                .set_position_mode(self.config.position_mode),
+        )?;
+
+        // The instruments cache must be initialized before connecting, as it is captured by
+        // the message handler for parsing
+        self.ws_client.initialize_instruments_cache(instruments);
+        let ws_client = &mut self.ws_client;
+        block_on(async {
+            ws_client.connect().await?;
+            ws_client
+                .wait_until_active(OKX_WS_CONNECT_TIMEOUT_SECS)
+                .await?;
+            ws_client.subscribe_orders(OKXInstrumentType::Any).await?;
+            ws_client.subscribe_account().await?;
+            anyhow::Ok(())
+        })?;
+
+        let stream = self.ws_client.stream();
+        let (tx, rx) = unbounded_channel();
+        *self.event_tx.borrow_mut() = Some(tx.clone());
+        *self.event_rx.borrow_mut() = Some(rx);
+        *self.stream_handle.borrow_mut() = Some(get_runtime().spawn(async move {
+            tokio::pin!(stream);
+            while let Some(msg) = stream.next().await {
+                if tx.send(msg).is_err() {
+                    break;
+                }
+            }
+            log::debug!("WebSocket stream ended");
+        }));
+
+        self.is_connected.set(true);
+        self.core.set_connected(true);
+        self.request_account_state()?;
+
+        log::info!("Connected to {}", self.ws_client.url());
+        Ok(())
+    }
+
+    fn disconnect(&mut self) -> anyhow::Result<()> {
+        if let Err(e) = block_on(self.ws_client.close()) {
+            log::error!("Failed to close WebSocket: {e}");
+        }
+        if let Some(handle) = self.stream_handle.borrow_mut().take() {
+            handle.abort();
+        }
+        *self.event_tx.borrow_mut() = None;
+        self.drain_events();
+        *self.event_rx.borrow_mut() = None;
+        self.is_connected.set(false);
+        self.core.set_connected(false);
+        log::info!("Disconnected");
+        Ok(())
+    }
+
+    fn generate_order_status_report(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Option<OrderStatusReport>> {
+        let reports = self.request_order_status_reports(cmd.instrument_id(), None, false)?;
+        Ok(reports.into_iter().find(|report| {
+            cmd.client_order_id()
+                .is_some_and(|id| report.client_order_id == Some(id))
+                || cmd
+                    .venue_order_id()
+                    .is_some_and(|id| report.venue_order_id.as_str() == id.as_str())
+        }))
+    }
+
+    fn generate_order_status_reports(
+        &self,
+        cmd: &GenerateOrderStatusReport,
+    ) -> anyhow::Result<Vec<OrderStatusReport>> {
+        self.request_order_status_reports(cmd.instrument_id(), None, false)
+    }
+
+    fn generate_fill_reports(&self, cmd: GenerateFillReports) -> anyhow::Result<Vec<FillReport>> {
+        self.request_fill_reports(cmd.instrument_id(), None)
+    }
+
+    fn generate_position_status_reports(
+        &self,
+        cmd: &GeneratePositionReports,
+    ) -> anyhow::Result<Vec<PositionStatusReport>> {
+        self.request_position_status_reports(cmd.instrument_id())
+    }
+
+    fn generate_mass_status(
+        &self,
+        lookback_mins: Option<u64>,
+    ) -> anyhow::Result<Option<ExecutionMassStatus>> {
+        log::debug!("Generating mass status (lookback_mins={lookback_mins:?})");
+
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let mut mass_status = ExecutionMassStatus::new(
+            self.core.client_id,
+            self.core.account_id,
+            self.core.venue,
+            ts_init,
+            None,
+        );
+
+        mass_status.add_order_reports(self.request_order_status_reports(
+            None,
+            lookback_mins,
+            false,
+        )?);
+        mass_status.add_fill_reports(self.request_fill_reports(None, lookback_mins)?);
+        mass_status.add_position_reports(self.request_position_status_reports(None)?);
+
+        Ok(Some(mass_status))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(OrderSide::Buy, false, Some(PositionSide::Long))]
+    #[case(OrderSide::Sell, false, Some(PositionSide::Short))]
+    #[case(OrderSide::Buy, true, Some(PositionSide::Short))]
+    #[case(OrderSide::Sell, true, Some(PositionSide::Long))]
+    #[case(OrderSide::NoOrderSide, false, None)]
+    fn test_okx_position_side(
+        #[case] order_side: OrderSide,
+        #[case] reduce_only: bool,
+        #[case] expected: Option<PositionSide>,
+    ) {
+        assert_eq!(okx_position_side(order_side, reduce_only), expected);
+    }
+
+    #[rstest]
+    fn test_venue_position_id() {
+        let instrument_id = InstrumentId::from("BTC-USDT-SWAP.OKX");
+        let strategy_id = StrategyId::from("S-001");
+
+        assert_eq!(
+            venue_position_id(instrument_id, strategy_id, Some(PositionSide::Short)),
+            PositionId::from("BTC-USDT-SWAP.OKX-SHORT")
+        );
+        assert_eq!(
+            venue_position_id(instrument_id, strategy_id, None),
+            PositionId::from("BTC-USDT-SWAP.OKX-S-001")
+        );
+    }
+}
@@ -0,0 +1,147 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Factory functions for creating OKX clients.
+
+use std::{any::Any, cell::RefCell, rc::Rc};
+
+use ant_common::{cache::Cache, clock::Clock};
+use ant_core::time::get_atomic_clock_realtime;
+use ant_data::client::DataClient;
+use ant_execution::client::ExecutionClient;
+use ant_model::identifiers::ClientId;
+use ant_system::factories::{ClientConfig, DataClientFactory, ExecutionClientFactory};
+
+use crate::{
+    common::consts::OKX,
+    config::{OKXDataClientConfig, OKXExecClientConfig},
+    data::OKXDataClient,
+    execution::OKXExecutionClient,
+};
+
+impl ClientConfig for OKXDataClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl ClientConfig for OKXExecClientConfig {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Factory for creating OKX data clients.
+#[derive(Debug)]
+pub struct OKXDataClientFactory;
+
+impl OKXDataClientFactory {
+    /// Creates a new [`OKXDataClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for OKXDataClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataClientFactory for OKXDataClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        _cache: Rc<RefCell<Cache>>,
+        _clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn DataClient>> {
+        let okx_config = config
+            .as_any()
+            .downcast_ref::<OKXDataClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for OKXDataClientFactory. Expected OKXDataClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client = OKXDataClient::new(
+            ClientId::from(name),
+            okx_config.clone(),
+            get_atomic_clock_realtime(),
+        )?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        OKX
+    }
+
+    fn config_type(&self) -> &'static str {
+        "OKXDataClientConfig"
+    }
+}
+
+/// Factory for creating OKX execution clients.
+#[derive(Debug)]
+pub struct OKXExecutionClientFactory;
+
+impl OKXExecutionClientFactory {
+    /// Creates a new [`OKXExecutionClientFactory`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for OKXExecutionClientFactory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExecutionClientFactory for OKXExecutionClientFactory {
+    fn create(
+        &self,
+        name: &str,
+        config: &dyn ClientConfig,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+    ) -> anyhow::Result<Box<dyn ExecutionClient>> {
+        let okx_config = config
+            .as_any()
+            .downcast_ref::<OKXExecClientConfig>()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid config type for OKXExecutionClientFactory. Expected OKXExecClientConfig, got {:?}",
+                    config
+                )
+            })?;
+
+        let client =
+            OKXExecutionClient::new(ClientId::from(name), okx_config.clone(), clock, cache)?;
+        Ok(Box::new(client))
+    }
+
+    fn name(&self) -> &'static str {
+        OKX
+    }
+
+    fn config_type(&self) -> &'static str {
+        "OKXExecClientConfig"
+    }
+}
@@ -41,7 +41,6 @@ use std::{
 };
 
 use ahash::AHashSet;
-use chrono::{DateTime, Utc};
 use ant_core::{
     UnixNanos, consts::ANT_USER_AGENT, env::get_env_var, time::get_atomic_clock_realtime,
 };
@@ -50,7 +49,7 @@ use ant_model::{
     enums::{AggregationSource, BarAggregation},
     events::AccountState,
     identifiers::{AccountId, InstrumentId},
-    instruments::{Instrument, InstrumentAny},
+    instruments::{Instrument, InstrumentEnum},
     reports::{FillReport, OrderStatusReport, PositionStatusReport},
 };
 use ant_network::{
@@ -58,6 +57,7 @@ use ant_network::{
     ratelimiter::quota::Quota,
     retry::{RetryConfig, RetryManager},
 };
+use chrono::{DateTime, Utc};
 use reqwest::{Method, StatusCode, header::USER_AGENT};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tokio_util::sync::CancellationToken;
@@ -685,7 +685,7 @@ impl OKXHttpInnerClient {
 )]
 pub struct OKXHttpClient {
     pub(crate) inner: Arc<OKXHttpInnerClient>,
-    pub(crate) instruments_cache: Arc<Mutex<HashMap<Ustr, InstrumentAny>>>,
+    pub(crate) instruments_cache: Arc<Mutex<HashMap<Ustr, InstrumentEnum>>>,
     cache_initialized: bool,
 }
 
@@ -744,9 +744,18 @@ impl OKXHttpClient {
         retry_delay_ms: Option<u64>,
         retry_delay_max_ms: Option<u64>,
     ) -> anyhow::Result<Self> {
-        let api_key = api_key.unwrap_or(get_env_var("OKX_API_KEY")?);
-        let api_secret = api_secret.unwrap_or(get_env_var("OKX_API_SECRET")?);
-        let api_passphrase = api_passphrase.unwrap_or(get_env_var("OKX_API_PASSPHRASE")?);
+        let api_key = match api_key {
+            Some(api_key) => api_key,
+            None => get_env_var("OKX_API_KEY")?,
+        };
+        let api_secret = match api_secret {
+            Some(api_secret) => api_secret,
+            None => get_env_var("OKX_API_SECRET")?,
+        };
+        let api_passphrase = match api_passphrase {
+            Some(api_passphrase) => api_passphrase,
+            None => get_env_var("OKX_API_PASSPHRASE")?,
+        };
         let base_url = base_url.unwrap_or(OKX_HTTP_URL.to_string());
 
         Ok(Self {
@@ -770,7 +779,7 @@ impl OKXHttpClient {
     /// # Errors
     ///
     /// Returns an error if the instrument is not found in the cache.
-    fn get_instrument_from_cache(&self, symbol: Ustr) -> anyhow::Result<InstrumentAny> {
+    fn get_instrument_from_cache(&self, symbol: Ustr) -> anyhow::Result<InstrumentEnum> {
         self.instruments_cache
             .lock()
             .expect("`instruments_cache` lock poisoned")
@@ -779,7 +788,7 @@ impl OKXHttpClient {
             .ok_or_else(|| anyhow::anyhow!("Instrument {symbol} not in cache"))
     }
 
-    async fn instrument_or_fetch(&self, symbol: Ustr) -> anyhow::Result<InstrumentAny> {
+    async fn instrument_or_fetch(&self, symbol: Ustr) -> anyhow::Result<InstrumentEnum> {
         if let Ok(inst) = self.get_instrument_from_cache(symbol) {
             return Ok(inst);
         }
@@ -864,7 +873,7 @@ impl OKXHttpClient {
     /// # Panics
     ///
     /// Panics if the instruments cache mutex is poisoned.
-    pub fn add_instruments(&mut self, instruments: Vec<InstrumentAny>) {
+    pub fn add_instruments(&mut self, instruments: Vec<InstrumentEnum>) {
         for inst in instruments {
             self.instruments_cache
                 .lock()
@@ -882,7 +891,7 @@ impl OKXHttpClient {
     /// # Panics
     ///
     /// Panics if the instruments cache mutex is poisoned.
-    pub fn add_instrument(&mut self, instrument: InstrumentAny) {
+    pub fn add_instrument(&mut self, instrument: InstrumentEnum) {
         self.instruments_cache
             .lock()
             .unwrap()
@@ -959,7 +968,7 @@ impl OKXHttpClient {
     pub async fn request_instruments(
         &self,
         instrument_type: OKXInstrumentType,
-    ) -> anyhow::Result<Vec<InstrumentAny>> {
+    ) -> anyhow::Result<Vec<InstrumentEnum>> {
         let mut params = GetInstrumentsParamsBuilder::default();
         params.inst_type(instrument_type);
         let params = params.build().map_err(|e| anyhow::anyhow!(e))?;
@@ -972,7 +981,7 @@ impl OKXHttpClient {
 
         let ts_init = self.generate_ts_init();
 
-        let mut instruments: Vec<InstrumentAny> = Vec::new();
+        let mut instruments: Vec<InstrumentEnum> = Vec::new();
         for inst in &resp {
             if let Some(instrument_any) = parse_instrument_any(inst, ts_init)? {
                 instruments.push(instrument_any);
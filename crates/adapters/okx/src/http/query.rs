@@ -30,7 +30,7 @@
 //!
 //! let params = GetTradesParamsBuilder::default()
 //!     .inst_id("BTC-USDT")
-//!     .limit(200)
+//!     .limit(200_u32)
 //!     .build()
 //!     .unwrap();
 //! ```
@@ -51,6 +51,9 @@
 
 pub mod common;
 pub mod config;
+pub mod data;
+pub mod execution;
+pub mod factories;
 pub mod http;
 pub mod websocket;
 
@@ -63,6 +66,10 @@ pub use crate::{
         enums::{OKXInstrumentType, OKXOrderType, OKXPositionMode, OKXPositionSide, OKXSide},
         models::OKXInstrument,
     },
+    config::{OKXDataClientConfig, OKXExecClientConfig},
+    data::OKXDataClient,
+    execution::OKXExecutionClient,
+    factories::{OKXDataClientFactory, OKXExecutionClientFactory},
     http::{client::OKXHttpClient, error::OKXHttpError},
     websocket::{client::OKXWebSocketClient, error::OKXWsError},
 };
@@ -31,18 +31,14 @@ use std::{
 };
 
 use ahash::{AHashMap, AHashSet};
-use dashmap::DashMap;
-use futures_util::Stream;
 use ant_common::runtime::get_runtime;
-use ant_core::{
-    UUID4, consts::ANT_USER_AGENT, env::get_env_var, time::get_atomic_clock_realtime,
-};
+use ant_core::{UUID4, consts::ANT_USER_AGENT, env::get_env_var, time::get_atomic_clock_realtime};
 use ant_model::{
     data::BarType,
     enums::{OrderSide, OrderStatus, OrderType, PositionSide, TimeInForce},
     events::{AccountState, OrderCancelRejected, OrderModifyRejected, OrderRejected},
     identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
-    instruments::{Instrument, InstrumentAny},
+    instruments::{Instrument, InstrumentEnum},
     types::{Money, Price, Quantity},
 };
 use ant_network::{
@@ -51,6 +47,8 @@ use ant_network::{
     retry::{RetryManager, create_websocket_retry_manager},
     websocket::{WebSocketClient, WebSocketConfig, channel_message_handler},
 };
+use dashmap::DashMap;
+use futures_util::Stream;
 use reqwest::header::USER_AGENT;
 use serde_json::Value;
 use tokio::sync::mpsc::UnboundedReceiver;
@@ -62,11 +60,10 @@ use super::{
     enums::{OKXWsChannel, OKXWsOperation},
     error::OKXWsError,
     messages::{
-        ExecutionReport, AntWsMessage, OKXAuthentication, OKXAuthenticationArg,
-        OKXSubscription, OKXSubscriptionArg, OKXWebSocketError, OKXWebSocketEvent, OKXWsRequest,
-        WsAmendOrderParams, WsAmendOrderParamsBuilder, WsCancelOrderParams,
-        WsCancelOrderParamsBuilder, WsMassCancelParams, WsPostOrderParams,
-        WsPostOrderParamsBuilder,
+        AntWsMessage, ExecutionReport, OKXAuthentication, OKXAuthenticationArg, OKXSubscription,
+        OKXSubscriptionArg, OKXWebSocketError, OKXWebSocketEvent, OKXWsRequest, WsAmendOrderParams,
+        WsAmendOrderParamsBuilder, WsCancelOrderParams, WsCancelOrderParamsBuilder,
+        WsMassCancelParams, WsPostOrderParams, WsPostOrderParamsBuilder,
     },
     parse::{parse_book_msg_vec, parse_ws_message_data},
 };
@@ -167,7 +164,7 @@ pub struct OKXWebSocketClient {
     pending_cancel_requests: Arc<DashMap<String, CancelRequestData>>,
     pending_amend_requests: Arc<DashMap<String, AmendRequestData>>,
     pending_mass_cancel_requests: Arc<DashMap<String, MassCancelRequestData>>,
-    instruments_cache: Arc<AHashMap<Ustr, InstrumentAny>>,
+    instruments_cache: Arc<AHashMap<Ustr, InstrumentEnum>>,
     retry_manager: Arc<RetryManager<OKXWsError>>,
     cancellation_token: CancellationToken,
 }
@@ -257,9 +254,18 @@ impl OKXWebSocketClient {
         heartbeat: Option<u64>,
     ) -> anyhow::Result<Self> {
         let url = url.unwrap_or(OKX_WS_PUBLIC_URL.to_string());
-        let api_key = api_key.unwrap_or(get_env_var("OKX_API_KEY")?);
-        let api_secret = api_secret.unwrap_or(get_env_var("OKX_API_SECRET")?);
-        let api_passphrase = api_passphrase.unwrap_or(get_env_var("OKX_API_PASSPHRASE")?);
+        let api_key = match api_key {
+            Some(api_key) => api_key,
+            None => get_env_var("OKX_API_KEY")?,
+        };
+        let api_secret = match api_secret {
+            Some(api_secret) => api_secret,
+            None => get_env_var("OKX_API_SECRET")?,
+        };
+        let api_passphrase = match api_passphrase {
+            Some(api_passphrase) => api_passphrase,
+            None => get_env_var("OKX_API_PASSPHRASE")?,
+        };
 
         Self::new(
             Some(url),
@@ -334,8 +340,8 @@ impl OKXWebSocketClient {
     }
 
     /// Initialize the instruments cache with the given `instruments`.
-    pub fn initialize_instruments_cache(&mut self, instruments: Vec<InstrumentAny>) {
-        let mut instruments_cache: AHashMap<Ustr, InstrumentAny> = AHashMap::new();
+    pub fn initialize_instruments_cache(&mut self, instruments: Vec<InstrumentEnum>) {
+        let mut instruments_cache: AHashMap<Ustr, InstrumentEnum> = AHashMap::new();
         for inst in instruments {
             instruments_cache.insert(inst.symbol().inner(), inst.clone());
         }
@@ -805,8 +811,8 @@ impl OKXWebSocketClient {
 
         // Determine instrument family based on instrument type
         let inst_family = match instrument {
-            InstrumentAny::CurrencyPair(_) => symbol.as_str().to_string(),
-            InstrumentAny::CryptoPerpetual(_) => {
+            InstrumentEnum::CurrencyPair(_) => symbol.as_str().to_string(),
+            InstrumentEnum::CryptoPerpetual(_) => {
                 // For SWAP: "BTC-USDT-SWAP" -> "BTC-USDT"
                 symbol
                     .as_str()
@@ -814,7 +820,7 @@ impl OKXWebSocketClient {
                     .unwrap_or(symbol.as_str())
                     .to_string()
             }
-            InstrumentAny::CryptoFuture(_) => {
+            InstrumentEnum::CryptoFuture(_) => {
                 // For FUTURES: extract the underlying pair
                 let parts: Vec<&str> = symbol.as_str().split('-').collect();
                 if parts.len() >= 2 {
@@ -1913,7 +1919,7 @@ impl OKXWebSocketClient {
             builder.px(p.to_string());
         }
 
-        builder.tag(OKX_ant_BROKER_ID);
+        builder.tag(OKX_ANT_BROKER_ID);
 
         let params = builder
             .build()
@@ -2476,7 +2482,7 @@ struct OKXWsMessageHandler {
     pending_cancel_requests: Arc<DashMap<String, CancelRequestData>>,
     pending_amend_requests: Arc<DashMap<String, AmendRequestData>>,
     pending_mass_cancel_requests: Arc<DashMap<String, MassCancelRequestData>>,
-    instruments_cache: Arc<AHashMap<Ustr, InstrumentAny>>,
+    instruments_cache: Arc<AHashMap<Ustr, InstrumentEnum>>,
     last_account_state: Option<AccountState>,
     fee_cache: AHashMap<Ustr, Money>, // Key is order ID
     funding_rate_cache: AHashMap<Ustr, (Ustr, u64)>, // Cache (funding_rate, funding_time) by inst_id
@@ -2488,7 +2494,7 @@ impl OKXWsMessageHandler {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         account_id: AccountId,
-        instruments_cache: Arc<AHashMap<Ustr, InstrumentAny>>,
+        instruments_cache: Arc<AHashMap<Ustr, InstrumentEnum>>,
         reader: UnboundedReceiver<Message>,
         signal: Arc<AtomicBool>,
         tx: tokio::sync::mpsc::UnboundedSender<AntWsMessage>,
@@ -2792,9 +2798,7 @@ impl OKXWsMessageHandler {
                                             continue; // Nothing to update
                                         }
                                         self.last_account_state = Some(account_state.clone());
-                                        return Some(AntWsMessage::AccountUpdate(
-                                            account_state,
-                                        ));
+                                        return Some(AntWsMessage::AccountUpdate(account_state));
                                     }
                                     Err(e) => {
                                         tracing::error!("Failed to parse account state: {e}");
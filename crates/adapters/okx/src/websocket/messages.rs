@@ -13,13 +13,13 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use derive_builder::Builder;
 use ant_model::{
     data::{Data, FundingRateUpdate, OrderBookDeltas},
     events::{AccountState, OrderCancelRejected, OrderModifyRejected, OrderRejected},
-    instruments::InstrumentAny,
+    instruments::InstrumentEnum,
     reports::{FillReport, OrderStatusReport},
 };
+use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -40,7 +40,7 @@ pub enum AntWsMessage {
     Data(Vec<Data>),
     Deltas(OrderBookDeltas),
     FundingRates(Vec<FundingRateUpdate>),
-    Instrument(Box<InstrumentAny>),
+    Instrument(Box<InstrumentEnum>),
     AccountUpdate(AccountState),
     OrderRejected(OrderRejected),
     OrderCancelRejected(OrderCancelRejected),
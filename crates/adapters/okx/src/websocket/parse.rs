@@ -26,7 +26,7 @@ use ant_model::{
         OrderType, RecordFlag, TimeInForce,
     },
     identifiers::{AccountId, InstrumentId, TradeId, VenueOrderId},
-    instruments::{Instrument, InstrumentAny},
+    instruments::{Instrument, InstrumentEnum},
     reports::{FillReport, OrderStatusReport},
     types::{Currency, Money, Price, Quantity},
 };
@@ -49,7 +49,7 @@ use crate::{
             parse_quantity,
         },
     },
-    websocket::messages::{ExecutionReport, AntWsMessage, OKXFundingRateMsg},
+    websocket::messages::{AntWsMessage, ExecutionReport, OKXFundingRateMsg},
 };
 
 /// Parses vector of OKX book messages into ant order book deltas.
@@ -534,7 +534,7 @@ pub fn parse_candle_msg(
 pub fn parse_order_msg_vec(
     data: Vec<OKXOrderMsg>,
     account_id: AccountId,
-    instruments: &AHashMap<Ustr, InstrumentAny>,
+    instruments: &AHashMap<Ustr, InstrumentEnum>,
     fee_cache: &AHashMap<Ustr, Money>,
     ts_init: UnixNanos,
 ) -> anyhow::Result<Vec<ExecutionReport>> {
@@ -568,7 +568,7 @@ pub fn parse_order_msg_vec(
 /// Parses an OKX order message into a ant order status report.
 pub fn parse_order_status_report(
     msg: &OKXOrderMsg,
-    instrument: &InstrumentAny,
+    instrument: &InstrumentEnum,
     account_id: AccountId,
     ts_init: UnixNanos,
 ) -> anyhow::Result<OrderStatusReport> {
@@ -641,7 +641,7 @@ pub fn parse_order_status_report(
 /// Parses an OKX order message into a ant fill report.
 pub fn parse_fill_report(
     msg: &OKXOrderMsg,
-    instrument: &InstrumentAny,
+    instrument: &InstrumentEnum,
     account_id: AccountId,
     previous_fee: Option<Money>,
     ts_init: UnixNanos,
@@ -1237,7 +1237,7 @@ mod tests {
 
         instruments.insert(
             Ustr::from("BTC-USDT-SWAP"),
-            InstrumentAny::CryptoPerpetual(instrument),
+            InstrumentEnum::CryptoPerpetual(instrument),
         );
 
         let ts_init = UnixNanos::default();
@@ -1313,7 +1313,7 @@ mod tests {
 
         let result = parse_order_status_report(
             order_msg,
-            &InstrumentAny::CryptoPerpetual(instrument),
+            &InstrumentEnum::CryptoPerpetual(instrument),
             account_id,
             ts_init,
         );
@@ -1377,7 +1377,7 @@ mod tests {
 
         let result = parse_fill_report(
             order_msg,
-            &InstrumentAny::CryptoPerpetual(instrument),
+            &InstrumentEnum::CryptoPerpetual(instrument),
             account_id,
             None,
             ts_init,
@@ -1547,7 +1547,7 @@ mod tests {
 
         let fill_report_1 = parse_fill_report(
             &order_msg_1,
-            &InstrumentAny::CryptoPerpetual(instrument),
+            &InstrumentEnum::CryptoPerpetual(instrument),
             account_id,
             None,
             ts_init,
@@ -1594,7 +1594,7 @@ mod tests {
 
         let fill_report_2 = parse_fill_report(
             &order_msg_2,
-            &InstrumentAny::CryptoPerpetual(instrument),
+            &InstrumentEnum::CryptoPerpetual(instrument),
             account_id,
             Some(fill_report_1.commission),
             ts_init,
@@ -0,0 +1,348 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mock OKX HTTP and WebSocket server replaying the fixtures under `test_data`.
+
+#![allow(dead_code)] // Not every test binary uses every helper
+
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
+
+use axum::{
+    Json, Router,
+    extract::{
+        Query, State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+    routing::{get, post},
+};
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+
+/// The instrument ID of the linear swap used by the order, position and funding fixtures.
+pub const LINEAR_SWAP_INST_ID: &str = "BTC-USDT-SWAP";
+
+// Server state recording what the clients sent
+#[derive(Clone, Default)]
+pub struct MockServerState {
+    pub http_requests: Arc<Mutex<Vec<String>>>,
+    pub subscriptions: Arc<Mutex<Vec<Value>>>,
+    pub order_ops: Arc<Mutex<Vec<Value>>>,
+    pub position_mode: Arc<Mutex<Option<String>>>,
+    pub logins: Arc<Mutex<usize>>,
+}
+
+impl MockServerState {
+    /// Returns the channels subscribed to across all WebSocket connections.
+    pub async fn subscribed_channels(&self) -> Vec<String> {
+        self.subscriptions
+            .lock()
+            .await
+            .iter()
+            .filter_map(|arg| arg.get("channel").and_then(Value::as_str))
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+// Load test data from existing files
+pub fn load_test_data(filename: &str) -> Value {
+    let path = format!("test_data/{filename}");
+    let content = std::fs::read_to_string(path).expect("Failed to read test data");
+    serde_json::from_str(&content).expect("Failed to parse test data")
+}
+
+fn okx_response(data: Value) -> Json<Value> {
+    Json(json!({ "code": "0", "msg": "", "data": data }))
+}
+
+fn fixture_data(filename: &str) -> Value {
+    load_test_data(filename)["data"].clone()
+}
+
+// The swap fixture only contains the inverse BTC-USD-SWAP, so a linear swap matching the order
+// and position fixtures is derived from it
+fn linear_swap_instrument() -> Value {
+    let mut instrument = fixture_data("http_get_instruments_swap.json")[0].clone();
+    instrument["instId"] = json!(LINEAR_SWAP_INST_ID);
+    instrument["uly"] = json!("BTC-USDT");
+    instrument["instFamily"] = json!("BTC-USDT");
+    instrument["ctType"] = json!("linear");
+    instrument["ctVal"] = json!("0.01");
+    instrument["ctValCcy"] = json!("BTC");
+    instrument["settleCcy"] = json!("USDT");
+    instrument["lotSz"] = json!("0.01");
+    instrument["minSz"] = json!("0.01");
+    instrument
+}
+
+async fn record(state: &MockServerState, path: &str, params: &HashMap<String, String>) {
+    let mut params: Vec<_> = params.iter().map(|(k, v)| format!("{k}={v}")).collect();
+    params.sort();
+    state
+        .http_requests
+        .lock()
+        .await
+        .push(format!("{path}?{}", params.join("&")));
+}
+
+fn inst_type(params: &HashMap<String, String>) -> &str {
+    params.get("instType").map_or("", String::as_str)
+}
+
+async fn handle_instruments(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "instruments", &params).await;
+
+    let data = match inst_type(&params) {
+        "SPOT" => {
+            // BTC-USDT is only available in the margin fixture
+            let mut spot = fixture_data("http_get_instruments_spot.json");
+            let margin = fixture_data("http_get_instruments_margin.json");
+            for mut instrument in margin.as_array().unwrap().clone() {
+                instrument["instType"] = json!("SPOT");
+                spot.as_array_mut().unwrap().push(instrument);
+            }
+            spot
+        }
+        "MARGIN" => fixture_data("http_get_instruments_margin.json"),
+        "SWAP" => {
+            let mut swaps = fixture_data("http_get_instruments_swap.json");
+            swaps.as_array_mut().unwrap().push(linear_swap_instrument());
+            swaps
+        }
+        "FUTURES" => fixture_data("http_get_instruments_futures.json"),
+        "OPTION" => fixture_data("http_get_instruments_option.json"),
+        _ => json!([]),
+    };
+    okx_response(data)
+}
+
+async fn handle_set_position_mode(
+    State(state): State<MockServerState>,
+    Json(body): Json<Value>,
+) -> Json<Value> {
+    let pos_mode = body["posMode"].as_str().unwrap_or_default().to_string();
+    *state.position_mode.lock().await = Some(pos_mode.clone());
+    okx_response(json!([{ "posMode": pos_mode }]))
+}
+
+async fn handle_balance(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "balance", &params).await;
+    okx_response(fixture_data("http_get_account_balance.json"))
+}
+
+async fn handle_orders_history(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "orders-history", &params).await;
+    match inst_type(&params) {
+        "SWAP" => okx_response(fixture_data("http_get_orders_history.json")),
+        _ => okx_response(json!([])),
+    }
+}
+
+async fn handle_positions(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "positions", &params).await;
+    match inst_type(&params) {
+        "SWAP" => okx_response(fixture_data("http_get_positions.json")),
+        _ => okx_response(json!([])),
+    }
+}
+
+async fn handle_empty(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "empty", &params).await;
+    okx_response(json!([]))
+}
+
+async fn handle_trades(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "history-trades", &params).await;
+    okx_response(fixture_data("http_get_trades.json"))
+}
+
+async fn handle_candles(
+    State(state): State<MockServerState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Json<Value> {
+    record(&state, "candles", &params).await;
+    okx_response(fixture_data("http_get_candlesticks.json"))
+}
+
+// WebSocket handler for the mock server
+async fn handle_websocket(ws: WebSocketUpgrade, State(state): State<MockServerState>) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+/// Returns the fixture pushed after a subscription to the `channel`.
+fn channel_fixture(channel: &str) -> Option<Value> {
+    let fixture = match channel {
+        "bbo-tbt" => load_test_data("ws_bbo_tbt.json"),
+        "trades" => load_test_data("ws_trades.json"),
+        "books" => load_test_data("ws_books_snapshot.json"),
+        "funding-rate" => load_test_data("ws_funding_rate.json"),
+        "orders" => load_test_data("ws_orders.json"),
+        // The account fixture is stored as the bare data array
+        "account" => json!({
+            "arg": { "channel": "account" },
+            "data": load_test_data("ws_account.json"),
+        }),
+        channel if channel.starts_with("candle") => load_test_data("ws_candle.json"),
+        _ => return None,
+    };
+    Some(fixture)
+}
+
+async fn send_json(socket: &mut WebSocket, value: &Value) -> bool {
+    socket
+        .send(Message::Text(value.to_string().into()))
+        .await
+        .is_ok()
+}
+
+async fn handle_socket(mut socket: WebSocket, state: MockServerState) {
+    while let Some(Ok(msg)) = socket.recv().await {
+        let Message::Text(text) = msg else {
+            continue;
+        };
+        if text.as_str() == "ping" {
+            continue;
+        }
+        let Ok(request) = serde_json::from_str::<Value>(text.as_str()) else {
+            continue;
+        };
+
+        let op = request["op"].as_str().unwrap_or_default().to_string();
+        let args = request["args"].as_array().cloned().unwrap_or_default();
+
+        match op.as_str() {
+            "login" => {
+                *state.logins.lock().await += 1;
+                let response =
+                    json!({ "event": "login", "code": "0", "msg": "", "connId": "mock" });
+                if !send_json(&mut socket, &response).await {
+                    break;
+                }
+            }
+            "subscribe" | "unsubscribe" => {
+                for arg in args {
+                    let response = json!({ "event": op, "arg": arg, "connId": "mock" });
+                    if !send_json(&mut socket, &response).await {
+                        return;
+                    }
+                    if op == "subscribe" {
+                        state.subscriptions.lock().await.push(arg.clone());
+                        let channel = arg["channel"].as_str().unwrap_or_default();
+                        if let Some(fixture) = channel_fixture(channel)
+                            && !send_json(&mut socket, &fixture).await
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+            "order" | "cancel-order" | "amend-order" => {
+                state.order_ops.lock().await.push(request.clone());
+                let data: Vec<Value> = args
+                    .iter()
+                    .map(|arg| {
+                        json!({
+                            "clOrdId": arg["clOrdId"].as_str().unwrap_or_default(),
+                            "ordId": arg["ordId"].as_str().unwrap_or("2497956918703120385"),
+                            "sCode": "0",
+                            "sMsg": "",
+                        })
+                    })
+                    .collect();
+                let response = json!({
+                    "id": request["id"],
+                    "op": op,
+                    "code": "0",
+                    "msg": "",
+                    "data": data,
+                });
+                if !send_json(&mut socket, &response).await {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn create_test_router(state: MockServerState) -> Router {
+    Router::new()
+        .route("/api/v5/public/instruments", get(handle_instruments))
+        .route(
+            "/api/v5/account/set-position-mode",
+            post(handle_set_position_mode),
+        )
+        .route("/api/v5/account/balance", get(handle_balance))
+        .route("/api/v5/account/positions", get(handle_positions))
+        .route("/api/v5/trade/orders-history", get(handle_orders_history))
+        .route("/api/v5/trade/orders-pending", get(handle_empty))
+        .route("/api/v5/trade/fills", get(handle_empty))
+        .route("/api/v5/market/history-trades", get(handle_trades))
+        .route("/api/v5/market/candles", get(handle_candles))
+        .route("/api/v5/market/history-candles", get(handle_candles))
+        .route("/ws/v5/public", get(handle_websocket))
+        .route("/ws/v5/business", get(handle_websocket))
+        .route("/ws/v5/private", get(handle_websocket))
+        .with_state(state)
+}
+
+/// Starts the mock server on a free port, returning its address and recorded state.
+pub async fn start_mock_server() -> (SocketAddr, MockServerState) {
+    // Bind to port 0 to let the OS assign an available port
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let state = MockServerState::default();
+    let router = create_test_router(state.clone());
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    (addr, state)
+}
+
+/// Polls `condition` every 10ms until it holds or the `timeout` elapses.
+pub async fn wait_until<F>(mut condition: F, timeout: Duration) -> bool
+where
+    F: AsyncFnMut() -> bool,
+{
+    tokio::time::timeout(timeout, async {
+        while !condition().await {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .is_ok()
+}
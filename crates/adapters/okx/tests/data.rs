@@ -0,0 +1,355 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests for the OKX data client using a mock server.
+
+mod common;
+
+use std::{net::SocketAddr, num::NonZeroUsize, time::Duration};
+
+use ant_common::{
+    messages::{
+        DataEvent, DataResponse,
+        data::{
+            RequestBars, RequestTrades, SubscribeBars, SubscribeBookDeltas, SubscribeFundingRates,
+            SubscribeQuotes, SubscribeTrades,
+        },
+    },
+    runner::set_data_event_sender,
+};
+use ant_core::{UUID4, time::get_atomic_clock_realtime};
+use ant_data::client::DataClient;
+use ant_model::{
+    data::{BarType, Data},
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId},
+    instruments::Instrument,
+    types::{Price, Quantity},
+};
+use ant_okx::{OKXDataClient, OKXDataClientConfig};
+use common::{start_mock_server, wait_until};
+use rstest::rstest;
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
+
+fn client_id() -> ClientId {
+    ClientId::from("OKX")
+}
+
+fn create_client(addr: SocketAddr) -> (OKXDataClient, UnboundedReceiver<DataEvent>) {
+    let (tx, rx) = unbounded_channel();
+    set_data_event_sender(tx);
+
+    let config = OKXDataClientConfig {
+        base_url_http: Some(format!("http://{addr}")),
+        base_url_ws_public: Some(format!("ws://{addr}/ws/v5/public")),
+        base_url_ws_business: Some(format!("ws://{addr}/ws/v5/business")),
+        ..Default::default()
+    };
+    let client = OKXDataClient::new(client_id(), config, get_atomic_clock_realtime()).unwrap();
+    (client, rx)
+}
+
+/// Receives events until one matches `predicate`, or panics after five seconds.
+async fn recv_matching<F>(rx: &mut UnboundedReceiver<DataEvent>, mut predicate: F) -> DataEvent
+where
+    F: FnMut(&DataEvent) -> bool,
+{
+    tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let event = rx.recv().await.expect("Data event channel closed");
+            if predicate(&event) {
+                return event;
+            }
+        }
+    })
+    .await
+    .expect("Timed out waiting for data event")
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_connect_loads_all_instrument_types() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, _rx) = create_client(addr);
+
+    client.connect().await.unwrap();
+
+    assert!(client.is_connected());
+    let instrument_ids: Vec<InstrumentId> =
+        client.instruments().iter().map(Instrument::id).collect();
+    for instrument_id in [
+        "BTC-USDT.OKX",
+        "BTC-USD-SWAP.OKX",
+        "BTC-USDT-SWAP.OKX",
+        "BTC-USD-241227.OKX",
+        "BTC-USD-241217-92000-C.OKX",
+    ] {
+        assert!(
+            instrument_ids.contains(&InstrumentId::from(instrument_id)),
+            "{instrument_id} not loaded"
+        );
+    }
+    let requests = state.http_requests.lock().await.clone();
+    assert_eq!(
+        requests,
+        vec![
+            "instruments?instType=SPOT",
+            "instruments?instType=SWAP",
+            "instruments?instType=FUTURES",
+            "instruments?instType=OPTION",
+        ]
+    );
+
+    client.disconnect().await.unwrap();
+    assert!(client.is_disconnected());
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_quotes_and_trades() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, mut rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let ts_init = get_atomic_clock_realtime().get_time_ns();
+    client
+        .subscribe_quotes(&SubscribeQuotes::new(
+            instrument_id,
+            Some(client_id()),
+            None,
+            UUID4::new(),
+            ts_init,
+            None,
+        ))
+        .unwrap();
+    client
+        .subscribe_trades(&SubscribeTrades::new(
+            instrument_id,
+            Some(client_id()),
+            None,
+            UUID4::new(),
+            ts_init,
+            None,
+        ))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| matches!(e, DataEvent::Data(Data::Quote(_)))).await;
+    let DataEvent::Data(Data::Quote(quote)) = event else {
+        unreachable!()
+    };
+    assert_eq!(quote.instrument_id, instrument_id);
+    // Prices are rounded to the 0.1 tick size of the instrument
+    assert_eq!(quote.bid_price, Price::from("8477.0"));
+    assert_eq!(
+        quote.bid_size,
+        Quantity::new(256.0, quote.bid_size.precision)
+    );
+
+    let event = recv_matching(&mut rx, |e| matches!(e, DataEvent::Data(Data::Trade(_)))).await;
+    let DataEvent::Data(Data::Trade(trade)) = event else {
+        unreachable!()
+    };
+    assert_eq!(trade.instrument_id, instrument_id);
+    assert_eq!(trade.trade_id.to_string(), "130639474");
+
+    let channels = state.subscribed_channels().await;
+    assert!(channels.contains(&"bbo-tbt".to_string()));
+    assert!(channels.contains(&"trades".to_string()));
+
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_book_deltas_and_funding_rates() {
+    let (addr, _state) = start_mock_server().await;
+    let (mut client, mut rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let ts_init = get_atomic_clock_realtime().get_time_ns();
+    client
+        .subscribe_book_deltas(&SubscribeBookDeltas::new(
+            InstrumentId::from("BTC-USDT.OKX"),
+            BookType::L2_MBP,
+            Some(client_id()),
+            None,
+            UUID4::new(),
+            ts_init,
+            None,
+            false,
+            None,
+        ))
+        .unwrap();
+    client
+        .subscribe_funding_rates(&SubscribeFundingRates::new(
+            InstrumentId::from("BTC-USDT-SWAP.OKX"),
+            Some(client_id()),
+            None,
+            UUID4::new(),
+            ts_init,
+            None,
+        ))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| matches!(e, DataEvent::Data(Data::Deltas(_)))).await;
+    let DataEvent::Data(Data::Deltas(deltas)) = event else {
+        unreachable!()
+    };
+    assert_eq!(deltas.instrument_id, InstrumentId::from("BTC-USDT.OKX"));
+    assert!(!deltas.deltas.is_empty());
+
+    let event = recv_matching(&mut rx, |e| {
+        matches!(e, DataEvent::Data(Data::FundingRateUpdate(_)))
+    })
+    .await;
+    let DataEvent::Data(Data::FundingRateUpdate(update)) = event else {
+        unreachable!()
+    };
+    assert_eq!(
+        update.instrument_id,
+        InstrumentId::from("BTC-USDT-SWAP.OKX")
+    );
+
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_book_deltas_rejects_unsupported_book_type() {
+    let (addr, _state) = start_mock_server().await;
+    let (mut client, _rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let result = client.subscribe_book_deltas(&SubscribeBookDeltas::new(
+        InstrumentId::from("BTC-USDT.OKX"),
+        BookType::L3_MBO,
+        Some(client_id()),
+        None,
+        UUID4::new(),
+        get_atomic_clock_realtime().get_time_ns(),
+        None,
+        false,
+        None,
+    ));
+
+    assert!(result.is_err());
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_subscribe_bars_uses_business_websocket() {
+    let (addr, state) = start_mock_server().await;
+    let (mut client, mut rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let bar_type = BarType::from("BTC-USDT.OKX-1-DAY-LAST-EXTERNAL");
+    client
+        .subscribe_bars(&SubscribeBars::new(
+            bar_type,
+            Some(client_id()),
+            None,
+            UUID4::new(),
+            get_atomic_clock_realtime().get_time_ns(),
+            false,
+            None,
+        ))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| matches!(e, DataEvent::Data(Data::Bar(_)))).await;
+    let DataEvent::Data(Data::Bar(bar)) = event else {
+        unreachable!()
+    };
+    assert_eq!(bar.bar_type, bar_type);
+
+    let subscribed = wait_until(
+        async || {
+            state
+                .subscribed_channels()
+                .await
+                .iter()
+                .any(|c| c.starts_with("candle"))
+        },
+        Duration::from_secs(1),
+    )
+    .await;
+    assert!(subscribed);
+
+    client.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_request_trades_and_bars() {
+    let (addr, _state) = start_mock_server().await;
+    let (mut client, mut rx) = create_client(addr);
+    client.connect().await.unwrap();
+
+    let instrument_id = InstrumentId::from("BTC-USDT.OKX");
+    let ts_init = get_atomic_clock_realtime().get_time_ns();
+    let request_id = UUID4::new();
+    client
+        .request_trades(&RequestTrades::new(
+            instrument_id,
+            None,
+            None,
+            NonZeroUsize::new(100),
+            Some(client_id()),
+            request_id,
+            ts_init,
+            None,
+        ))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| {
+        matches!(e, DataEvent::Response(DataResponse::Trades(_)))
+    })
+    .await;
+    let DataEvent::Response(DataResponse::Trades(response)) = event else {
+        unreachable!()
+    };
+    assert_eq!(response.correlation_id, request_id);
+    assert_eq!(response.instrument_id, instrument_id);
+    assert_eq!(response.data.len(), 2);
+
+    let bar_type = BarType::from("BTC-USDT.OKX-1-MINUTE-LAST-EXTERNAL");
+    let request_id = UUID4::new();
+    client
+        .request_bars(&RequestBars::new(
+            bar_type,
+            None,
+            None,
+            NonZeroUsize::new(100),
+            Some(client_id()),
+            request_id,
+            ts_init,
+            None,
+        ))
+        .unwrap();
+
+    let event = recv_matching(&mut rx, |e| {
+        matches!(e, DataEvent::Response(DataResponse::Bars(_)))
+    })
+    .await;
+    let DataEvent::Response(DataResponse::Bars(response)) = event else {
+        unreachable!()
+    };
+    assert_eq!(response.correlation_id, request_id);
+    assert_eq!(response.bar_type, bar_type);
+    assert!(!response.data.is_empty());
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,334 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests for the OKX execution client using a mock server.
+
+mod common;
+
+use std::{
+    cell::RefCell,
+    net::SocketAddr,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use ant_common::{
+    cache::Cache,
+    clock::TestClock,
+    messages::execution::{GeneratePositionReports, SubmitOrder},
+    msgbus::{
+        self, get_message_saving_handler, get_saved_messages, handler::ShareableMessageHandler,
+    },
+    runtime::get_runtime,
+};
+use ant_core::{UUID4, UnixNanos};
+use ant_execution::client::{ExecutionClient, LiveExecutionClient};
+use ant_model::{
+    enums::{OrderSide, OrderType, PositionSide},
+    events::{AccountState, OrderEventAny},
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
+    },
+    orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+    types::{Price, Quantity},
+};
+use ant_okx::{
+    OKXExecClientConfig, OKXExecutionClient,
+    common::enums::{OKXInstrumentType, OKXPositionMode},
+    http::client::OKXHttpClient,
+};
+use common::{LINEAR_SWAP_INST_ID, MockServerState, start_mock_server};
+use rstest::rstest;
+use ustr::Ustr;
+
+const CLIENT_ORDER_ID: &str = "001BTCUSDT20250106001";
+
+fn account_id() -> AccountId {
+    AccountId::from("OKX-001")
+}
+
+fn instrument_id() -> InstrumentId {
+    InstrumentId::from(format!("{LINEAR_SWAP_INST_ID}.OKX").as_str())
+}
+
+fn order_event_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<OrderEventAny>(Some(Ustr::from("ExecEngine.process")));
+    msgbus::register("ExecEngine.process".into(), handler.clone());
+    handler
+}
+
+fn account_state_handler() -> ShareableMessageHandler {
+    let handler =
+        get_message_saving_handler::<AccountState>(Some(Ustr::from("Portfolio.update_account")));
+    msgbus::register("Portfolio.update_account".into(), handler.clone());
+    handler
+}
+
+fn create_client(
+    addr: SocketAddr,
+    position_mode: OKXPositionMode,
+    cache: Rc<RefCell<Cache>>,
+) -> OKXExecutionClient {
+    let mut config = OKXExecClientConfig::new(TraderId::from("TRADER-001"), account_id());
+    config.api_key = Some("test_api_key".to_string());
+    config.api_secret = Some("test_api_secret".to_string());
+    config.api_passphrase = Some("test_passphrase".to_string());
+    config.instrument_types = vec![OKXInstrumentType::Swap];
+    config.position_mode = position_mode;
+    config.base_url_http = Some(format!("http://{addr}"));
+    config.base_url_ws_private = Some(format!("ws://{addr}/ws/v5/private"));
+
+    OKXExecutionClient::new(
+        ClientId::from("OKX"),
+        config,
+        Rc::new(RefCell::new(TestClock::new())),
+        cache,
+    )
+    .unwrap()
+}
+
+/// Starts the mock server and returns a cache holding the swap instruments it serves.
+fn setup() -> (SocketAddr, MockServerState, Rc<RefCell<Cache>>) {
+    let (addr, state) = get_runtime().block_on(start_mock_server());
+
+    let http_client =
+        OKXHttpClient::new(Some(format!("http://{addr}")), Some(60), None, None, None).unwrap();
+    let instruments = get_runtime()
+        .block_on(http_client.request_instruments(OKXInstrumentType::Swap))
+        .unwrap();
+
+    let mut cache = Cache::default();
+    for instrument in instruments {
+        cache.add_instrument(instrument).unwrap();
+    }
+    (addr, state, Rc::new(RefCell::new(cache)))
+}
+
+fn market_order(client_order_id: &str, side: OrderSide, quantity: &str) -> OrderAny {
+    OrderTestBuilder::new(OrderType::Market)
+        .instrument_id(instrument_id())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(side)
+        .quantity(Quantity::from(quantity))
+        .build()
+}
+
+fn limit_order(client_order_id: &str, side: OrderSide, quantity: &str, price: &str) -> OrderAny {
+    OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument_id())
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .side(side)
+        .quantity(Quantity::from(quantity))
+        .price(Price::from(price))
+        .build()
+}
+
+fn submit_order(order: &OrderAny) -> SubmitOrder {
+    SubmitOrder {
+        trader_id: TraderId::from("TRADER-001"),
+        client_id: ClientId::from("OKX"),
+        strategy_id: StrategyId::from("S-001"),
+        instrument_id: order.instrument_id(),
+        client_order_id: order.client_order_id(),
+        venue_order_id: VenueOrderId::from("NONE"),
+        order: order.clone(),
+        exec_algorith_id: None,
+        position_id: None,
+        command_id: UUID4::new(),
+        ts_init: UnixNanos::default(),
+    }
+}
+
+/// Drains the client until `condition` holds for the saved events, or panics after five seconds.
+fn drain_until<F>(client: &OKXExecutionClient, handler: &ShareableMessageHandler, condition: F)
+where
+    F: Fn(&[OrderEventAny]) -> bool,
+{
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        client.drain_events();
+        if condition(&get_saved_messages::<OrderEventAny>(handler.clone())) {
+            return;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Timed out waiting for order events"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[rstest]
+fn test_connect_sets_position_mode_and_subscribes() {
+    let _orders = order_event_handler();
+    let accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let mut client = create_client(addr, OKXPositionMode::LongShortMode, cache);
+
+    client.connect().unwrap();
+
+    assert!(client.is_connected());
+    get_runtime().block_on(async {
+        assert_eq!(
+            state.position_mode.lock().await.as_deref(),
+            Some("long_short_mode")
+        );
+        assert_eq!(*state.logins.lock().await, 1);
+        let channels = state.subscribed_channels().await;
+        assert!(channels.contains(&"orders".to_string()));
+        assert!(channels.contains(&"account".to_string()));
+    });
+
+    // The account state is requested over HTTP on connect
+    let account_states = get_saved_messages::<AccountState>(accounts);
+    assert!(!account_states.is_empty());
+    assert_eq!(account_states[0].account_id, account_id());
+
+    client.disconnect().unwrap();
+    assert!(!client.is_connected());
+}
+
+#[rstest]
+fn test_order_update_generates_accepted_and_filled() {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+
+    let mut order = market_order(CLIENT_ORDER_ID, OrderSide::Buy, "0.03");
+    order
+        .apply(TestOrderEventStubs::submitted(&order, account_id()))
+        .unwrap();
+    cache
+        .borrow_mut()
+        .add_order(order, None, None, false)
+        .unwrap();
+
+    let mut client = create_client(addr, OKXPositionMode::LongShortMode, cache);
+    client.connect().unwrap();
+
+    drain_until(&client, &handler, |events| {
+        events.iter().any(|e| matches!(e, OrderEventAny::Filled(_)))
+    });
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 2);
+    let OrderEventAny::Accepted(accepted) = &events[0] else {
+        panic!("Expected accepted, was {:?}", events[0]);
+    };
+    assert_eq!(
+        accepted.venue_order_id,
+        VenueOrderId::from("2497956918703120384")
+    );
+    let OrderEventAny::Filled(fill) = &events[1] else {
+        panic!("Expected fill, was {:?}", events[1]);
+    };
+    assert_eq!(fill.client_order_id, ClientOrderId::from(CLIENT_ORDER_ID));
+    assert_eq!(fill.trade_id, TradeId::from("1518905529"));
+    assert_eq!(fill.last_qty, Quantity::from("0.03"));
+    assert_eq!(
+        fill.position_id.map(|id| id.to_string()),
+        Some(format!("{}-{}", instrument_id(), PositionSide::Long))
+    );
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+#[case(OKXPositionMode::NetMode, None)]
+#[case(OKXPositionMode::LongShortMode, Some("short"))]
+fn test_submit_order_sends_order_over_websocket(
+    #[case] position_mode: OKXPositionMode,
+    #[case] expected_pos_side: Option<&str>,
+) {
+    let handler = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, state, cache) = setup();
+    let order = limit_order("O-20250106-001", OrderSide::Sell, "0.05", "100000.0");
+    cache
+        .borrow_mut()
+        .add_order(order.clone(), None, None, false)
+        .unwrap();
+
+    let mut client = create_client(addr, position_mode, cache);
+    client.connect().unwrap();
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert!(matches!(events.first(), Some(OrderEventAny::Submitted(_))));
+
+    let order_ops = get_runtime().block_on(async {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while state.order_ops.lock().await.is_empty() && Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        state.order_ops.lock().await.clone()
+    });
+    assert_eq!(order_ops.len(), 1);
+    assert_eq!(order_ops[0]["op"], "order");
+    let args = &order_ops[0]["args"][0];
+    assert_eq!(args["instId"], LINEAR_SWAP_INST_ID);
+    assert_eq!(args["clOrdId"], "O-20250106-001");
+    assert_eq!(args["side"], "sell");
+    assert_eq!(args["ordType"], "limit");
+    assert_eq!(args["tdMode"], "cross");
+    assert_eq!(args["posSide"].as_str(), expected_pos_side);
+
+    client.disconnect().unwrap();
+}
+
+#[rstest]
+fn test_submit_order_when_not_connected_rejects() {
+    let handler = order_event_handler();
+    let (addr, state, cache) = setup();
+    let order = limit_order("O-20250106-002", OrderSide::Buy, "0.05", "90000.0");
+
+    let client = create_client(addr, OKXPositionMode::NetMode, cache);
+    client.submit_order(&submit_order(&order)).unwrap();
+
+    let events = get_saved_messages::<OrderEventAny>(handler);
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], OrderEventAny::Rejected(_)));
+    assert!(get_runtime().block_on(state.order_ops.lock()).is_empty());
+}
+
+#[rstest]
+fn test_generate_mass_status_from_http_fixtures() {
+    let _orders = order_event_handler();
+    let _accounts = account_state_handler();
+    let (addr, _state, cache) = setup();
+    let mut client = create_client(addr, OKXPositionMode::NetMode, cache);
+    client.connect().unwrap();
+
+    let mass_status = client.generate_mass_status(None).unwrap().unwrap();
+
+    assert_eq!(mass_status.account_id, account_id());
+    assert_eq!(mass_status.order_reports().len(), 1);
+    assert_eq!(mass_status.position_reports().len(), 1);
+
+    let cmd = GeneratePositionReports::new(
+        UUID4::new(),
+        UnixNanos::default(),
+        Some(instrument_id()),
+        None,
+        None,
+    );
+    let position_reports = client.generate_position_status_reports(&cmd).unwrap();
+    assert_eq!(position_reports.len(), 1);
+    assert_eq!(position_reports[0].instrument_id, instrument_id());
+
+    client.disconnect().unwrap();
+}
@@ -0,0 +1,726 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Lazy Tardis data sources for backtesting.
+//!
+//! Provides iterators of [`Data`] chunks which read from a Tardis Machine replay or from a
+//! directory of Tardis CSV files on demand. Each stream can be passed straight to
+//! `BacktestEngine::add_data_stream` (or `BacktestDataIterator::add_stream`), so multi-day
+//! datasets can be replayed with bounded memory and without first converting to Parquet.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    ffi::OsStr,
+    fmt::Debug,
+    mem,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, SyncSender},
+    },
+    thread,
+};
+
+use ant_model::{data::Data, identifiers::InstrumentId};
+use csv::StringRecord;
+use futures_util::{StreamExt, pin_mut};
+use ustr::Ustr;
+
+use crate::{
+    csv::{
+        create_csv_reader, stream_deltas, stream_depth10_from_snapshot5,
+        stream_depth10_from_snapshot25, stream_funding_rates, stream_quotes, stream_trades,
+    },
+    enums::{TardisCsvDataType, TardisExchange},
+    machine::{
+        TardisMachineClient,
+        client::handle_ws_stream,
+        replay_normalized,
+        types::{ReplayNormalizedRequestOptions, TardisInstrumentKey, TardisInstrumentMiniInfo},
+    },
+    parse::parse_instrument_id,
+};
+
+/// The default number of chunks buffered ahead of the consumer for a Tardis Machine replay.
+pub const DEFAULT_REPLAY_BUFFER_CHUNKS: usize = 4;
+
+/// Provides a lazy stream of [`Data`] chunks replayed from a Tardis Machine server.
+///
+/// The replay runs on a dedicated thread which reads ahead by at most `buffer_chunks` chunks,
+/// applying backpressure to the WebSocket while the consumer catches up. Dropping the stream
+/// signals the replay to stop.
+#[derive(Debug)]
+pub struct TardisMachineDataStream {
+    rx: Receiver<Vec<Data>>,
+    signal: Arc<AtomicBool>,
+}
+
+impl TardisMachineDataStream {
+    /// Connects to the Tardis Machine replay endpoint of `client` and returns a stream of
+    /// chunks of up to `chunk_size` items.
+    ///
+    /// Messages are parsed using the instrument definitions held by `client`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `chunk_size` is zero.
+    /// - `client` has no instrument definitions.
+    /// - The replay thread cannot be spawned.
+    /// - The connection to the Tardis Machine server fails or is rejected.
+    pub fn new(
+        client: &TardisMachineClient,
+        options: Vec<ReplayNormalizedRequestOptions>,
+        chunk_size: usize,
+        buffer_chunks: usize,
+    ) -> anyhow::Result<Self> {
+        anyhow::ensure!(chunk_size > 0, "`chunk_size` must be positive");
+        anyhow::ensure!(
+            !client.instruments.is_empty(),
+            "No instrument definitions for Tardis Machine replay, call `add_instrument_info` first"
+        );
+
+        let base_url = client.base_url.clone();
+        let instruments = client.instruments.clone();
+        let signal = Arc::new(AtomicBool::new(false));
+        let replay_signal = signal.clone();
+        let (tx, rx) = mpsc::sync_channel(buffer_chunks);
+        let (connect_tx, connect_rx) = mpsc::sync_channel(1);
+
+        thread::Builder::new()
+            .name("tardis-machine-replay".to_string())
+            .spawn(move || {
+                let runtime = match tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    Ok(runtime) => runtime,
+                    Err(e) => {
+                        let _ = connect_tx.send(Err(anyhow::anyhow!(e)));
+                        return;
+                    }
+                };
+                runtime.block_on(replay_chunks(
+                    base_url,
+                    options,
+                    instruments,
+                    replay_signal,
+                    chunk_size,
+                    tx,
+                    connect_tx,
+                ));
+            })?;
+
+        connect_rx
+            .recv()
+            .map_err(|_| anyhow::anyhow!("Tardis Machine replay thread terminated"))??;
+
+        Ok(Self { rx, signal })
+    }
+}
+
+impl Iterator for TardisMachineDataStream {
+    type Item = Vec<Data>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rx.recv().ok()
+    }
+}
+
+impl Drop for TardisMachineDataStream {
+    fn drop(&mut self) {
+        self.signal.store(true, Ordering::Relaxed);
+    }
+}
+
+async fn replay_chunks(
+    base_url: String,
+    options: Vec<ReplayNormalizedRequestOptions>,
+    instruments: HashMap<TardisInstrumentKey, Arc<TardisInstrumentMiniInfo>>,
+    signal: Arc<AtomicBool>,
+    chunk_size: usize,
+    tx: SyncSender<Vec<Data>>,
+    connect_tx: SyncSender<anyhow::Result<()>>,
+) {
+    let stream = match replay_normalized(&base_url, options, signal).await {
+        Ok(stream) => {
+            let _ = connect_tx.send(Ok(()));
+            stream
+        }
+        Err(e) => {
+            let _ = connect_tx.send(Err(e.into()));
+            return;
+        }
+    };
+
+    let stream = handle_ws_stream(Box::pin(stream), None, Some(instruments));
+    pin_mut!(stream);
+
+    let mut chunk = Vec::with_capacity(chunk_size);
+    while let Some(data) = stream.next().await {
+        chunk.push(data);
+        if chunk.len() < chunk_size {
+            continue;
+        }
+
+        // Blocks while the buffer is full, which stops reading from the socket until
+        // the consumer requests more data
+        let full = mem::replace(&mut chunk, Vec::with_capacity(chunk_size));
+        if tx.send(full).is_err() {
+            tracing::debug!("Replay stream dropped by consumer");
+            return;
+        }
+    }
+
+    if !chunk.is_empty() {
+        let _ = tx.send(chunk);
+    }
+}
+
+type DataChunks = Box<dyn Iterator<Item = anyhow::Result<Vec<Data>>>>;
+
+/// Provides a lazy stream of [`Data`] chunks read from Tardis CSV files of a single data type
+/// and instrument, reading one file after another.
+///
+/// A read error is logged and ends the stream.
+pub struct TardisCsvDataStream {
+    data_type: TardisCsvDataType,
+    instrument_id: InstrumentId,
+    files: VecDeque<PathBuf>,
+    chunk_size: usize,
+    price_precision: Option<u8>,
+    size_precision: Option<u8>,
+    current: Option<(PathBuf, DataChunks)>,
+}
+
+impl Debug for TardisCsvDataStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(TardisCsvDataStream))
+            .field("data_type", &self.data_type)
+            .field("instrument_id", &self.instrument_id)
+            .field("files", &self.files)
+            .field("chunk_size", &self.chunk_size)
+            .field("price_precision", &self.price_precision)
+            .field("size_precision", &self.size_precision)
+            .field("current", &self.current.as_ref().map(|(path, _)| path))
+            .finish()
+    }
+}
+
+impl TardisCsvDataStream {
+    /// Creates a new [`TardisCsvDataStream`] instance reading `files` in the given order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    #[must_use]
+    pub fn new(
+        data_type: TardisCsvDataType,
+        instrument_id: InstrumentId,
+        files: Vec<PathBuf>,
+        chunk_size: usize,
+    ) -> Self {
+        assert!(chunk_size > 0, "`chunk_size` must be positive");
+
+        Self {
+            data_type,
+            instrument_id,
+            files: files.into(),
+            chunk_size,
+            price_precision: None,
+            size_precision: None,
+            current: None,
+        }
+    }
+
+    /// Sets the price and size precisions, otherwise they are inferred from each file.
+    #[must_use]
+    pub const fn with_precisions(mut self, price_precision: u8, size_precision: u8) -> Self {
+        self.price_precision = Some(price_precision);
+        self.size_precision = Some(size_precision);
+        self
+    }
+
+    /// Sets the instrument ID assigned to the data, e.g. to match a normalized instrument definition.
+    #[must_use]
+    pub const fn with_instrument_id(mut self, instrument_id: InstrumentId) -> Self {
+        self.instrument_id = instrument_id;
+        self
+    }
+
+    /// Returns a name for the stream, unique per data type and instrument.
+    #[must_use]
+    pub fn name(&self) -> String {
+        format!("{}-{}", self.data_type, self.instrument_id)
+    }
+
+    #[must_use]
+    pub const fn data_type(&self) -> TardisCsvDataType {
+        self.data_type
+    }
+
+    #[must_use]
+    pub const fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Returns the files not yet read, in read order.
+    #[must_use]
+    pub fn files(&self) -> Vec<&Path> {
+        self.files.iter().map(PathBuf::as_path).collect()
+    }
+
+    fn open(&self, path: &Path) -> anyhow::Result<DataChunks> {
+        let chunk_size = self.chunk_size;
+        let price_precision = self.price_precision;
+        let size_precision = self.size_precision;
+        let instrument_id = Some(self.instrument_id);
+
+        let chunks: DataChunks = match self.data_type {
+            TardisCsvDataType::IncrementalBookL2 => Box::new(
+                stream_deltas(
+                    path.to_path_buf(),
+                    chunk_size,
+                    price_precision,
+                    size_precision,
+                    instrument_id,
+                    None,
+                )?
+                .map(|chunk| chunk.map(|deltas| deltas.into_iter().map(Data::Delta).collect())),
+            ),
+            TardisCsvDataType::BookSnapshot5 => Box::new(
+                stream_depth10_from_snapshot5(
+                    path.to_path_buf(),
+                    chunk_size,
+                    price_precision,
+                    size_precision,
+                    instrument_id,
+                    None,
+                )?
+                .map(|chunk| chunk.map(depths_to_data)),
+            ),
+            TardisCsvDataType::BookSnapshot25 => Box::new(
+                stream_depth10_from_snapshot25(
+                    path.to_path_buf(),
+                    chunk_size,
+                    price_precision,
+                    size_precision,
+                    instrument_id,
+                    None,
+                )?
+                .map(|chunk| chunk.map(depths_to_data)),
+            ),
+            TardisCsvDataType::Quotes => Box::new(
+                stream_quotes(
+                    path.to_path_buf(),
+                    chunk_size,
+                    price_precision,
+                    size_precision,
+                    instrument_id,
+                    None,
+                )?
+                .map(|chunk| chunk.map(|quotes| quotes.into_iter().map(Data::Quote).collect())),
+            ),
+            TardisCsvDataType::Trades => Box::new(
+                stream_trades(
+                    path.to_path_buf(),
+                    chunk_size,
+                    price_precision,
+                    size_precision,
+                    instrument_id,
+                    None,
+                )?
+                .map(|chunk| chunk.map(|trades| trades.into_iter().map(Data::Trade).collect())),
+            ),
+            TardisCsvDataType::DerivativeTicker => Box::new(
+                stream_funding_rates(path.to_path_buf(), chunk_size, instrument_id, None)?.map(
+                    |chunk| {
+                        chunk.map(|rates| rates.into_iter().map(Data::FundingRateUpdate).collect())
+                    },
+                ),
+            ),
+        };
+
+        Ok(chunks)
+    }
+
+    fn stop(&mut self) {
+        self.current = None;
+        self.files.clear();
+    }
+}
+
+impl Iterator for TardisCsvDataStream {
+    type Item = Vec<Data>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.current.is_none() {
+                let path = self.files.pop_front()?;
+                tracing::debug!("Streaming {} from {path:?}", self.data_type);
+
+                match self.open(&path) {
+                    Ok(chunks) => self.current = Some((path, chunks)),
+                    Err(e) => {
+                        tracing::error!("Failed to open {path:?}: {e}");
+                        self.stop();
+                        return None;
+                    }
+                }
+            }
+
+            let (path, chunks) = self.current.as_mut()?;
+            match chunks.next() {
+                Some(Ok(chunk)) if chunk.is_empty() => {}
+                Some(Ok(chunk)) => return Some(chunk),
+                Some(Err(e)) => {
+                    tracing::error!("Failed to read {path:?}: {e}");
+                    self.stop();
+                    return None;
+                }
+                None => self.current = None,
+            }
+        }
+    }
+}
+
+fn depths_to_data(depths: Vec<ant_model::data::OrderBookDepth10>) -> Vec<Data> {
+    depths
+        .into_iter()
+        .map(|depth| Data::Depth10(Box::new(depth)))
+        .collect()
+}
+
+// Files of a data type and instrument, keyed by their first local timestamp
+type CsvFileGroup = (TardisCsvDataType, InstrumentId, Vec<(u64, PathBuf)>);
+
+/// Discovers the Tardis CSV files (`.csv` or `.csv.gz`) in `directory` and groups them into
+/// a [`TardisCsvDataStream`] per data type and instrument.
+///
+/// The data type of each file is inferred from its header, and the files of each stream are
+/// ordered by their first local timestamp, so daily files can be replayed as one stream.
+/// Files with an unrecognized header or no records are skipped. Streams are returned sorted
+/// by name.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `chunk_size` is zero.
+/// - `directory` cannot be read.
+/// - A CSV file cannot be opened or its first record cannot be parsed.
+pub fn discover_csv_streams<P: AsRef<Path>>(
+    directory: P,
+    chunk_size: usize,
+) -> anyhow::Result<Vec<TardisCsvDataStream>> {
+    anyhow::ensure!(chunk_size > 0, "`chunk_size` must be positive");
+
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(directory.as_ref())? {
+        let path = entry?.path();
+        if path.is_file() && is_csv_file(&path) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    let mut groups: BTreeMap<String, CsvFileGroup> = BTreeMap::new();
+
+    for path in paths {
+        let Some((data_type, instrument_id, first_ts)) = inspect_csv_file(&path)? else {
+            continue;
+        };
+
+        groups
+            .entry(format!("{data_type}-{instrument_id}"))
+            .or_insert_with(|| (data_type, instrument_id, Vec::new()))
+            .2
+            .push((first_ts, path));
+    }
+
+    Ok(groups
+        .into_values()
+        .map(|(data_type, instrument_id, mut files)| {
+            files.sort();
+            let files = files.into_iter().map(|(_, path)| path).collect();
+            TardisCsvDataStream::new(data_type, instrument_id, files, chunk_size)
+        })
+        .collect())
+}
+
+fn is_csv_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(OsStr::to_str) else {
+        return false;
+    };
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".csv") || name.ends_with(".csv.gz")
+}
+
+fn inspect_csv_file(path: &Path) -> anyhow::Result<Option<(TardisCsvDataType, InstrumentId, u64)>> {
+    let mut reader = create_csv_reader(path)?;
+
+    let headers = reader.headers()?.clone();
+    let Some(data_type) = infer_csv_data_type(&headers) else {
+        tracing::warn!("Skipping {path:?}: unrecognized Tardis CSV header");
+        return Ok(None);
+    };
+
+    let mut record = StringRecord::new();
+    if !reader.read_record(&mut record)? {
+        tracing::debug!("Skipping {path:?}: no records");
+        return Ok(None);
+    }
+
+    let field = |index: usize, name: &str| {
+        record
+            .get(index)
+            .ok_or_else(|| anyhow::anyhow!("Missing `{name}` in first record of {path:?}"))
+    };
+
+    let exchange = TardisExchange::from_str(field(0, "exchange")?)?;
+    let symbol = Ustr::from(field(1, "symbol")?);
+    let local_ts = field(3, "local_timestamp")?.parse::<u64>()?;
+
+    Ok(Some((
+        data_type,
+        parse_instrument_id(&exchange, symbol),
+        local_ts,
+    )))
+}
+
+/// Infers the Tardis CSV data type from the given `headers`.
+fn infer_csv_data_type(headers: &StringRecord) -> Option<TardisCsvDataType> {
+    let has = |name: &str| headers.iter().any(|header| header == name);
+
+    if has("is_snapshot") {
+        Some(TardisCsvDataType::IncrementalBookL2)
+    } else if has("asks[24].price") {
+        Some(TardisCsvDataType::BookSnapshot25)
+    } else if has("asks[4].price") {
+        Some(TardisCsvDataType::BookSnapshot5)
+    } else if has("ask_price") && has("bid_price") {
+        Some(TardisCsvDataType::Quotes)
+    } else if has("id") && has("side") {
+        Some(TardisCsvDataType::Trades)
+    } else if has("funding_rate") {
+        Some(TardisCsvDataType::DerivativeTicker)
+    } else {
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use ant_core::UnixNanos;
+    use ant_model::data::HasTsInit;
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const TRADES_HEADER: &str = "exchange,symbol,timestamp,local_timestamp,id,side,price,amount";
+    const DELTAS_HEADER: &str =
+        "exchange,symbol,timestamp,local_timestamp,is_snapshot,side,price,amount";
+
+    fn trades_csv(local_timestamps: &[u64]) -> String {
+        let mut csv = format!("{TRADES_HEADER}\n");
+        for (i, ts) in local_timestamps.iter().enumerate() {
+            csv.push_str(&format!(
+                "bitmex,XBTUSD,{ts},{ts},trade-{ts}-{i},buy,8531.5,100\n"
+            ));
+        }
+        csv
+    }
+
+    fn write_file(dir: &TempDir, name: &str, contents: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn ts_inits(chunks: &[Vec<Data>]) -> Vec<Vec<u64>> {
+        chunks
+            .iter()
+            .map(|chunk| chunk.iter().map(|data| data.ts_init().as_u64()).collect())
+            .collect()
+    }
+
+    #[rstest]
+    #[case(DELTAS_HEADER, Some(TardisCsvDataType::IncrementalBookL2))]
+    #[case(TRADES_HEADER, Some(TardisCsvDataType::Trades))]
+    #[case(
+        "exchange,symbol,timestamp,local_timestamp,ask_amount,ask_price,bid_price,bid_amount",
+        Some(TardisCsvDataType::Quotes)
+    )]
+    #[case(
+        "exchange,symbol,timestamp,local_timestamp,funding_timestamp,funding_rate,predicted_funding_rate,open_interest,last_price,index_price,mark_price",
+        Some(TardisCsvDataType::DerivativeTicker)
+    )]
+    #[case("exchange,symbol,timestamp,local_timestamp,liquidations", None)]
+    fn test_infer_csv_data_type(
+        #[case] headers: &str,
+        #[case] expected: Option<TardisCsvDataType>,
+    ) {
+        let headers = StringRecord::from(headers.split(',').collect::<Vec<_>>());
+
+        assert_eq!(infer_csv_data_type(&headers), expected);
+    }
+
+    #[rstest]
+    #[case(5, TardisCsvDataType::BookSnapshot5)]
+    #[case(25, TardisCsvDataType::BookSnapshot25)]
+    fn test_infer_csv_data_type_book_snapshots(
+        #[case] depth: usize,
+        #[case] expected: TardisCsvDataType,
+    ) {
+        let mut headers = vec![
+            "exchange".to_string(),
+            "symbol".to_string(),
+            "timestamp".to_string(),
+            "local_timestamp".to_string(),
+        ];
+        for level in 0..depth {
+            for side in ["asks", "bids"] {
+                headers.push(format!("{side}[{level}].price"));
+                headers.push(format!("{side}[{level}].amount"));
+            }
+        }
+        let headers = StringRecord::from(headers);
+
+        assert_eq!(infer_csv_data_type(&headers), Some(expected));
+    }
+
+    #[rstest]
+    fn test_discover_csv_streams_groups_and_orders_files() {
+        let dir = TempDir::new().unwrap();
+        // File names sort opposite to the data order
+        let day_2 = write_file(&dir, "a_trades.csv", &trades_csv(&[2_000_000, 2_000_001]));
+        let day_1 = write_file(&dir, "b_trades.csv", &trades_csv(&[1_000_000]));
+        write_file(
+            &dir,
+            "deltas.csv",
+            &format!("{DELTAS_HEADER}\nderibit,BTC-PERPETUAL,1,1,true,ask,6421.5,18640\n"),
+        );
+        write_file(&dir, "empty_trades.csv", &format!("{TRADES_HEADER}\n"));
+        write_file(&dir, "unknown.csv", "foo,bar\n1,2\n");
+        write_file(&dir, "notes.txt", "not a csv");
+
+        let streams = discover_csv_streams(dir.path(), 10).unwrap();
+
+        assert_eq!(streams.len(), 2);
+        assert_eq!(
+            streams[0].name(),
+            "incremental_book_L2-BTC-PERPETUAL.DERIBIT"
+        );
+        assert_eq!(streams[1].name(), "trades-XBTUSD.BITMEX");
+        assert_eq!(streams[1].data_type(), TardisCsvDataType::Trades);
+        assert_eq!(streams[1].files(), vec![day_1.as_path(), day_2.as_path()]);
+    }
+
+    #[rstest]
+    fn test_discover_csv_streams_with_missing_directory() {
+        let dir = TempDir::new().unwrap();
+
+        assert!(discover_csv_streams(dir.path().join("missing"), 10).is_err());
+    }
+
+    #[rstest]
+    fn test_csv_stream_reads_files_in_chunks() {
+        let dir = TempDir::new().unwrap();
+        write_file(&dir, "day_1.csv", &trades_csv(&[1, 2, 3]));
+        write_file(&dir, "day_2.csv", &trades_csv(&[4, 5]));
+        let stream = discover_csv_streams(dir.path(), 2).unwrap().pop().unwrap();
+
+        let chunks: Vec<Vec<Data>> = stream.collect();
+
+        // Chunks do not span files
+        assert_eq!(
+            ts_inits(&chunks),
+            vec![vec![1_000, 2_000], vec![3_000], vec![4_000, 5_000]]
+        );
+        assert!(
+            chunks
+                .iter()
+                .flatten()
+                .all(|data| matches!(data, Data::Trade(_)))
+        );
+    }
+
+    #[rstest]
+    fn test_csv_stream_with_instrument_id_and_precisions() {
+        let dir = TempDir::new().unwrap();
+        write_file(&dir, "trades.csv", &trades_csv(&[1]));
+        let instrument_id = InstrumentId::from("XBTUSD-PERP.BITMEX");
+        let stream = discover_csv_streams(dir.path(), 10)
+            .unwrap()
+            .pop()
+            .unwrap()
+            .with_instrument_id(instrument_id)
+            .with_precisions(2, 1);
+
+        assert_eq!(stream.name(), "trades-XBTUSD-PERP.BITMEX");
+
+        let chunks: Vec<Vec<Data>> = stream.collect();
+        let Data::Trade(trade) = chunks[0][0] else {
+            panic!("Expected trade, was {:?}", chunks[0][0]);
+        };
+        assert_eq!(trade.instrument_id, instrument_id);
+        assert_eq!(trade.price.precision, 2);
+        assert_eq!(trade.size.precision, 1);
+        assert_eq!(trade.ts_init, UnixNanos::from(1_000));
+    }
+
+    #[rstest]
+    fn test_csv_stream_ends_on_missing_file() {
+        let dir = TempDir::new().unwrap();
+        let path = write_file(&dir, "trades.csv", &trades_csv(&[1]));
+        let mut stream = TardisCsvDataStream::new(
+            TardisCsvDataType::Trades,
+            InstrumentId::from("XBTUSD.BITMEX"),
+            vec![dir.path().join("missing.csv"), path],
+            10,
+        );
+
+        assert!(stream.next().is_none());
+        assert!(stream.files().is_empty());
+    }
+
+    #[rstest]
+    fn test_csv_stream_of_fixture_files() {
+        let stream = TardisCsvDataStream::new(
+            TardisCsvDataType::IncrementalBookL2,
+            InstrumentId::from("BTC-PERPETUAL.DERIBIT"),
+            vec![crate::tests::get_test_data_path("csv/deltas_1.csv")],
+            1,
+        );
+
+        let chunks: Vec<Vec<Data>> = stream.collect();
+
+        assert_eq!(chunks.len(), 2);
+        assert!(
+            chunks
+                .iter()
+                .flatten()
+                .all(|data| matches!(data, Data::Delta(_)))
+        );
+    }
+}
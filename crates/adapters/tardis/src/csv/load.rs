@@ -168,7 +168,7 @@ pub fn load_deltas<P: AsRef<Path>>(
             && let Some(last_delta) = deltas.last_mut()
         {
             // Set previous delta flags as F_LAST
-            last_delta.flags = RecordFlag::F_LAST.value();
+            last_delta.flags = RecordFlag::F_LAST as u8;
         }
 
         last_ts_event = ts_event;
@@ -184,7 +184,7 @@ pub fn load_deltas<P: AsRef<Path>>(
 
     // Set F_LAST flag for final delta
     if let Some(last_delta) = deltas.last_mut() {
-        last_delta.flags = RecordFlag::F_LAST.value();
+        last_delta.flags = RecordFlag::F_LAST as u8;
     }
 
     Ok(deltas)
@@ -264,7 +264,7 @@ pub fn load_depth10_from_snapshot5<P: AsRef<Path>>(
             Some(id) => *id,
             None => parse_instrument_id(&data.exchange, data.symbol),
         };
-        let flags = RecordFlag::F_LAST.value();
+        let flags = RecordFlag::F_LAST as u8;
         let sequence = 0; // Sequence not available
         let ts_event = parse_timestamp(data.timestamp);
         let ts_init = parse_timestamp(data.local_timestamp);
@@ -424,7 +424,7 @@ pub fn load_depth10_from_snapshot25<P: AsRef<Path>>(
             Some(id) => *id,
             None => parse_instrument_id(&data.exchange, data.symbol),
         };
-        let flags = RecordFlag::F_LAST.value();
+        let flags = RecordFlag::F_LAST as u8;
         let sequence = 0; // Sequence not available
         let ts_event = parse_timestamp(data.timestamp);
         let ts_init = parse_timestamp(data.local_timestamp);
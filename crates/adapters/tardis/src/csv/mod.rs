@@ -64,7 +64,7 @@ fn infer_precision(value: f64) -> u8 {
     }
 }
 
-fn create_csv_reader<P: AsRef<Path>>(
+pub(crate) fn create_csv_reader<P: AsRef<Path>>(
     filepath: P,
 ) -> anyhow::Result<Reader<Box<dyn std::io::Read>>> {
     let filepath_ref = filepath.as_ref();
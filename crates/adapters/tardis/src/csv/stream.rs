@@ -163,7 +163,7 @@ impl Iterator for DeltaStreamIterator {
                             if self.last_ts_event != delta.ts_event
                                 && let Some(last_delta) = self.buffer.last_mut()
                             {
-                                last_delta.flags = RecordFlag::F_LAST.value();
+                                last_delta.flags = RecordFlag::F_LAST as u8;
                             }
 
                             assert!(
@@ -197,7 +197,7 @@ impl Iterator for DeltaStreamIterator {
                     }
                     // Set F_LAST flag for final delta in chunk
                     if let Some(last_delta) = self.buffer.last_mut() {
-                        last_delta.flags = RecordFlag::F_LAST.value();
+                        last_delta.flags = RecordFlag::F_LAST as u8;
                     }
                     return Some(Ok(self.buffer.clone()));
                 }
@@ -385,7 +385,7 @@ impl Iterator for BatchedDeltasStreamIterator {
                     if self.last_ts_event != delta.ts_event && !self.current_batch.is_empty() {
                         // Set F_LAST on the last delta of the completed batch
                         if let Some(last_delta) = self.current_batch.last_mut() {
-                            last_delta.flags = RecordFlag::F_LAST.value();
+                            last_delta.flags = RecordFlag::F_LAST as u8;
                         }
                         self.pending_batches
                             .push(std::mem::take(&mut self.current_batch));
@@ -413,7 +413,7 @@ impl Iterator for BatchedDeltasStreamIterator {
         if !self.current_batch.is_empty() && batches_created < self.chunk_size {
             // Ensure the last delta of the last batch has F_LAST set
             if let Some(last_delta) = self.current_batch.last_mut() {
-                last_delta.flags = RecordFlag::F_LAST.value();
+                last_delta.flags = RecordFlag::F_LAST as u8;
             }
             self.pending_batches
                 .push(std::mem::take(&mut self.current_batch));
@@ -951,7 +951,7 @@ impl Depth10StreamIterator {
             ask_counts[i] = ask_count;
         }
 
-        let flags = RecordFlag::F_SNAPSHOT.value();
+        let flags = RecordFlag::F_SNAPSHOT as u8;
         let sequence = 0;
         let ts_event = parse_timestamp(data.timestamp);
         let ts_init = parse_timestamp(data.local_timestamp);
@@ -1030,7 +1030,7 @@ impl Depth10StreamIterator {
             ask_counts[i] = ask_count;
         }
 
-        let flags = RecordFlag::F_SNAPSHOT.value();
+        let flags = RecordFlag::F_SNAPSHOT as u8;
         let sequence = 0;
         let ts_event = parse_timestamp(data.timestamp);
         let ts_init = parse_timestamp(data.local_timestamp);
@@ -114,6 +114,44 @@ pub enum TardisBarKind {
     Tick,
 }
 
+/// The data type of a Tardis downloadable CSV dataset.
+/// See <https://docs.tardis.dev/downloadable-csv-files#data-types> for the dataset schemas.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Display,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    FromRepr,
+)]
+pub enum TardisCsvDataType {
+    #[strum(serialize = "incremental_book_L2")]
+    #[serde(rename = "incremental_book_L2")]
+    IncrementalBookL2,
+    #[strum(serialize = "book_snapshot_5")]
+    #[serde(rename = "book_snapshot_5")]
+    BookSnapshot5,
+    #[strum(serialize = "book_snapshot_25")]
+    #[serde(rename = "book_snapshot_25")]
+    BookSnapshot25,
+    #[strum(serialize = "quotes")]
+    #[serde(rename = "quotes")]
+    Quotes,
+    #[strum(serialize = "trades")]
+    #[serde(rename = "trades")]
+    Trades,
+    #[strum(serialize = "derivative_ticker")]
+    #[serde(rename = "derivative_ticker")]
+    DerivativeTicker,
+}
+
 #[derive(
     Copy,
     Clone,
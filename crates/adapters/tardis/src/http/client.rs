@@ -16,7 +16,7 @@
 use std::{env, time::Duration};
 
 use ant_core::{UnixNanos, consts::ANT_USER_AGENT};
-use ant_model::instruments::InstrumentAny;
+use ant_model::instruments::InstrumentEnum;
 use reqwest::Response;
 
 use super::{
@@ -173,7 +173,7 @@ impl TardisHttpClient {
         available_offset: Option<UnixNanos>,
         effective: Option<UnixNanos>,
         ts_init: Option<UnixNanos>,
-    ) -> Result<Vec<InstrumentAny>> {
+    ) -> Result<Vec<InstrumentEnum>> {
         let response = self.instruments_info(exchange, symbol, filter).await?;
 
         Ok(response
@@ -18,7 +18,7 @@ use ant_model::{
     currencies::CURRENCY_MAP,
     enums::CurrencyType,
     identifiers::{InstrumentId, Symbol},
-    instruments::{CryptoFuture, CryptoOption, CryptoPerpetual, CurrencyPair, InstrumentAny},
+    instruments::{CryptoFuture, CryptoOption, CryptoPerpetual, CurrencyPair, InstrumentEnum},
     types::{Currency, Price, Quantity},
 };
 use rust_decimal::Decimal;
@@ -52,8 +52,8 @@ pub fn create_currency_pair(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
-    InstrumentAny::CurrencyPair(CurrencyPair::new(
+) -> InstrumentEnum {
+    InstrumentEnum::CurrencyPair(CurrencyPair::new(
         instrument_id,
         raw_symbol,
         get_currency(info.base_currency.to_uppercase().as_str()),
@@ -94,10 +94,10 @@ pub fn create_crypto_perpetual(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
+) -> InstrumentEnum {
     let is_inverse = info.inverse.unwrap_or(false);
 
-    InstrumentAny::CryptoPerpetual(CryptoPerpetual::new(
+    InstrumentEnum::CryptoPerpetual(CryptoPerpetual::new(
         instrument_id,
         raw_symbol,
         get_currency(info.base_currency.to_uppercase().as_str()),
@@ -142,10 +142,10 @@ pub fn create_crypto_future(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
+) -> InstrumentEnum {
     let is_inverse = info.inverse.unwrap_or(false);
 
-    InstrumentAny::CryptoFuture(CryptoFuture::new(
+    InstrumentEnum::CryptoFuture(CryptoFuture::new(
         instrument_id,
         raw_symbol,
         get_currency(info.base_currency.to_uppercase().as_str()),
@@ -197,10 +197,10 @@ pub fn create_crypto_option(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
+) -> InstrumentEnum {
     let is_inverse = info.inverse.unwrap_or(false);
 
-    InstrumentAny::CryptoOption(CryptoOption::new(
+    InstrumentEnum::CryptoOption(CryptoOption::new(
         instrument_id,
         raw_symbol,
         get_currency(info.base_currency.to_uppercase().as_str()),
@@ -19,7 +19,7 @@ use chrono::{DateTime, Utc};
 use ant_core::UnixNanos;
 use ant_model::{
     identifiers::Symbol,
-    instruments::InstrumentAny,
+    instruments::InstrumentEnum,
     types::{Currency, Price, Quantity},
 };
 use rust_decimal::Decimal;
@@ -43,7 +43,7 @@ pub fn parse_instrument_any(
     effective: Option<UnixNanos>,
     ts_init: Option<UnixNanos>,
     normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
+) -> Vec<InstrumentEnum> {
     match info.instrument_type {
         TardisInstrumentType::Spot => {
             parse_spot_instrument(info, effective, ts_init, normalize_symbols)
@@ -65,7 +65,7 @@ fn parse_spot_instrument(
     effective: Option<UnixNanos>,
     ts_init: Option<UnixNanos>,
     normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
+) -> Vec<InstrumentEnum> {
     let instrument_id = if normalize_symbols {
         normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
     } else {
@@ -214,7 +214,7 @@ fn parse_perp_instrument(
     effective: Option<UnixNanos>,
     ts_init: Option<UnixNanos>,
     normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
+) -> Vec<InstrumentEnum> {
     let instrument_id = if normalize_symbols {
         normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
     } else {
@@ -362,7 +362,7 @@ fn parse_future_instrument(
     effective: Option<UnixNanos>,
     ts_init: Option<UnixNanos>,
     normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
+) -> Vec<InstrumentEnum> {
     let instrument_id = if normalize_symbols {
         normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
     } else {
@@ -518,7 +518,7 @@ fn parse_option_instrument(
     effective: Option<UnixNanos>,
     ts_init: Option<UnixNanos>,
     normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
+) -> Vec<InstrumentEnum> {
     let instrument_id = if normalize_symbols {
         normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
     } else {
@@ -46,6 +46,7 @@
 #![deny(clippy::missing_panics_doc)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod backtest;
 pub mod config;
 pub mod csv;
 pub mod enums;
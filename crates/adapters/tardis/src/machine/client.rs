@@ -133,7 +133,7 @@ impl TardisMachineClient {
     }
 }
 
-fn handle_ws_stream<S>(
+pub(crate) fn handle_ws_stream<S>(
     stream: S,
     instrument: Option<Arc<TardisInstrumentMiniInfo>>,
     instrument_map: Option<HashMap<TardisInstrumentKey, Arc<TardisInstrumentMiniInfo>>>,
@@ -249,7 +249,7 @@ pub fn parse_book_msg_as_deltas(
     }
 
     if let Some(last_delta) = deltas.last_mut() {
-      // TODO laiqb check  last_delta.flags += RecordFlag::F_LAST.value();
+        last_delta.flags += RecordFlag::F_LAST as u8;
     }
 
     // TODO: Opaque pointer wrapper necessary for Cython (remove once Cython gone)
@@ -283,7 +283,7 @@ pub fn parse_book_level(
     let order_id = 0; // Not applicable for L2 data
     let order = BookOrder::new(side, price, size, order_id);
     let flags = if is_snapshot {
-        1 // TODO laiqb check  RecordFlag::F_SNAPSHOT.value()
+        RecordFlag::F_SNAPSHOT as u8
     } else {
         0
     };
@@ -468,7 +468,7 @@ mod tests {
 
         assert_eq!(deltas.deltas.len(), 1);
         assert_eq!(deltas.instrument_id, instrument_id);
-        assert_eq!(deltas.flags, RecordFlag::F_LAST.value());
+        assert_eq!(deltas.flags, RecordFlag::F_LAST as u8);
         assert_eq!(deltas.sequence, 0);
         assert_eq!(deltas.ts_event, UnixNanos::from(1571830193469000000));
         assert_eq!(deltas.ts_init, UnixNanos::from(1571830193469000000));
@@ -480,7 +480,7 @@ mod tests {
         assert_eq!(deltas.deltas[0].order.price, Price::from("7985"));
         assert_eq!(deltas.deltas[0].order.size, Quantity::from(283318));
         assert_eq!(deltas.deltas[0].order.order_id, 0);
-        assert_eq!(deltas.deltas[0].flags, RecordFlag::F_LAST.value());
+        assert_eq!(deltas.deltas[0].flags, RecordFlag::F_LAST as u8);
         assert_eq!(deltas.deltas[0].sequence, 0);
         assert_eq!(
             deltas.deltas[0].ts_event,
@@ -510,7 +510,7 @@ mod tests {
         assert_eq!(deltas.instrument_id, instrument_id);
         assert_eq!(
             deltas.flags,
-            RecordFlag::F_LAST.value() + RecordFlag::F_SNAPSHOT.value()
+            RecordFlag::F_LAST as u8 + RecordFlag::F_SNAPSHOT as u8
         );
         assert_eq!(deltas.sequence, 0);
         assert_eq!(deltas.ts_event, UnixNanos::from(1572010786950000000));
@@ -521,7 +521,7 @@ mod tests {
         assert_eq!(delta_0.order.price, Price::from("7633.5"));
         assert_eq!(delta_0.order.size, Quantity::from(1906067));
         assert_eq!(delta_0.order.order_id, 0);
-        assert_eq!(delta_0.flags, RecordFlag::F_SNAPSHOT.value());
+        assert_eq!(delta_0.flags, RecordFlag::F_SNAPSHOT as u8);
         assert_eq!(delta_0.sequence, 0);
         assert_eq!(delta_0.ts_event, UnixNanos::from(1572010786950000000));
         assert_eq!(delta_0.ts_init, UnixNanos::from(1572010786961000000));
@@ -531,7 +531,7 @@ mod tests {
         assert_eq!(delta_2.order.price, Price::from("7634.0"));
         assert_eq!(delta_2.order.size, Quantity::from(1467849));
         assert_eq!(delta_2.order.order_id, 0);
-        assert_eq!(delta_2.flags, RecordFlag::F_SNAPSHOT.value());
+        assert_eq!(delta_2.flags, RecordFlag::F_SNAPSHOT as u8);
         assert_eq!(delta_2.sequence, 0);
         assert_eq!(delta_2.ts_event, UnixNanos::from(1572010786950000000));
         assert_eq!(delta_2.ts_init, UnixNanos::from(1572010786961000000));
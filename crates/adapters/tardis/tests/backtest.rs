@@ -0,0 +1,202 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Integration tests feeding Tardis streams into the backtest data iterator.
+
+mod common;
+
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+use ant_backtest::data_iterator::BacktestDataIterator;
+use ant_model::{
+    data::{Data, HasTsInit},
+    identifiers::InstrumentId,
+};
+use ant_tardis::{
+    backtest::{TardisMachineDataStream, discover_csv_streams},
+    enums::TardisExchange,
+    machine::{
+        TardisMachineClient,
+        types::{ReplayNormalizedRequestOptions, TardisInstrumentMiniInfo},
+    },
+};
+use chrono::NaiveDate;
+use common::{REPLAY_FIXTURES, start_mock_server};
+use rstest::rstest;
+use tempfile::TempDir;
+
+fn replay_options() -> Vec<ReplayNormalizedRequestOptions> {
+    vec![ReplayNormalizedRequestOptions {
+        exchange: TardisExchange::Bitmex,
+        symbols: Some(vec!["XBTUSD".to_string()]),
+        from: NaiveDate::from_ymd_opt(2019, 10, 23).unwrap(),
+        to: NaiveDate::from_ymd_opt(2019, 10, 24).unwrap(),
+        data_types: vec!["book_change".to_string(), "trade".to_string()],
+        with_disconnect_messages: Some(true),
+    }]
+}
+
+fn machine_client(base_url: &str) -> TardisMachineClient {
+    let mut client = TardisMachineClient::new(Some(base_url), false).unwrap();
+    client.add_instrument_info(TardisInstrumentMiniInfo::new(
+        InstrumentId::from("XBTUSD.BITMEX"),
+        None,
+        TardisExchange::Bitmex,
+        1,
+        0,
+    ));
+    client
+}
+
+#[rstest]
+fn test_machine_replay_stream_into_data_iterator() {
+    let (addr, state) = start_mock_server(Some(3));
+    let client = machine_client(&format!("ws://{addr}"));
+
+    let stream = TardisMachineDataStream::new(&client, replay_options(), 4, 1).unwrap();
+    let mut iterator = BacktestDataIterator::new();
+    iterator.add_stream("tardis-machine", stream, true);
+
+    let mut data = Vec::new();
+    while let Some(item) = iterator.next() {
+        data.push(item);
+    }
+
+    // Disconnect messages carry no data
+    assert_eq!(data.len(), 3 * (REPLAY_FIXTURES.len() - 1));
+    assert_eq!(
+        data.iter()
+            .filter(|item| matches!(item, Data::Deltas(_)))
+            .count(),
+        3
+    );
+    assert_eq!(
+        data.iter()
+            .filter(|item| matches!(item, Data::Trade(_)))
+            .count(),
+        3
+    );
+    assert!(iterator.is_done());
+
+    let requests = state.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0][0]["exchange"], "bitmex");
+    assert_eq!(requests[0][0]["symbols"][0], "XBTUSD");
+    assert_eq!(requests[0][0]["from"], "2019-10-23");
+}
+
+#[rstest]
+fn test_machine_replay_stream_chunks() {
+    let (addr, _state) = start_mock_server(Some(3));
+    let client = machine_client(&format!("ws://{addr}"));
+
+    let stream = TardisMachineDataStream::new(&client, replay_options(), 4, 1).unwrap();
+    let chunk_lens: Vec<usize> = stream.map(|chunk| chunk.len()).collect();
+
+    assert_eq!(chunk_lens, vec![4, 2]);
+}
+
+#[rstest]
+fn test_machine_replay_stream_stops_when_dropped() {
+    let (addr, state) = start_mock_server(None);
+    let client = machine_client(&format!("ws://{addr}"));
+
+    let mut stream = TardisMachineDataStream::new(&client, replay_options(), 2, 1).unwrap();
+    assert_eq!(stream.next().map(|chunk| chunk.len()), Some(2));
+    drop(stream);
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !state.is_disconnected() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(state.is_disconnected());
+}
+
+#[rstest]
+fn test_machine_replay_stream_without_instruments() {
+    let client = TardisMachineClient::new(Some("ws://127.0.0.1:1"), false).unwrap();
+
+    let result = TardisMachineDataStream::new(&client, replay_options(), 4, 1);
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_machine_replay_stream_connection_failure() {
+    // Bind then release a port so nothing is listening on it
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = machine_client(&format!("ws://{addr}"));
+
+    let result = TardisMachineDataStream::new(&client, replay_options(), 4, 1);
+
+    assert!(result.is_err());
+}
+
+#[rstest]
+fn test_csv_streams_merge_into_data_iterator() {
+    let dir = TempDir::new().unwrap();
+    let header = "exchange,symbol,timestamp,local_timestamp,id,side,price,amount";
+    fs::write(
+        dir.path().join("bitmex_trades_2019-10-24.csv"),
+        format!("{header}\nbitmex,XBTUSD,3,3,c,buy,7996.0,50\nbitmex,XBTUSD,6,6,d,buy,7996.5,50\n"),
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("bitmex_trades_2019-10-23.csv"),
+        format!(
+            "{header}\nbitmex,XBTUSD,1,1,a,sell,7995.5,10\nbitmex,XBTUSD,2,2,b,buy,7996.0,20\n"
+        ),
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("deribit_trades_2019-10-23.csv"),
+        format!("{header}\nderibit,BTC-PERPETUAL,4,4,e,sell,7990.0,10\nderibit,BTC-PERPETUAL,5,5,f,buy,7991.0,10\n"),
+    )
+    .unwrap();
+
+    let streams = discover_csv_streams(dir.path(), 1).unwrap();
+    assert_eq!(streams.len(), 2);
+
+    let mut iterator = BacktestDataIterator::new();
+    for stream in streams {
+        iterator.add_stream(&stream.name(), stream, true);
+    }
+
+    let mut ts_inits = Vec::new();
+    let mut instruments = Vec::new();
+    while let Some(data) = iterator.next() {
+        ts_inits.push(data.ts_init().as_u64());
+        instruments.push(data.instrument_id().to_string());
+    }
+
+    assert_eq!(ts_inits, vec![1_000, 2_000, 3_000, 4_000, 5_000, 6_000]);
+    assert_eq!(
+        instruments,
+        vec![
+            "XBTUSD.BITMEX",
+            "XBTUSD.BITMEX",
+            "XBTUSD.BITMEX",
+            "BTC-PERPETUAL.DERIBIT",
+            "BTC-PERPETUAL.DERIBIT",
+            "XBTUSD.BITMEX",
+        ]
+    );
+}
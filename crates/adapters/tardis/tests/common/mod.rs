@@ -0,0 +1,132 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Local stand-in for a Tardis Machine server replaying the JSON fixtures under `src/tests/data`.
+
+#![allow(dead_code)] // Not every test binary uses every helper
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use axum::{
+    Router,
+    extract::{
+        Query, State,
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code},
+    },
+    response::Response,
+    routing::get,
+};
+
+/// The fixtures sent (in order) for each replay round.
+pub const REPLAY_FIXTURES: [&str; 3] = ["book_change.json", "trade.json", "disconnect.json"];
+
+// Server state recording what the client requested
+#[derive(Clone)]
+pub struct MockServerState {
+    /// The number of replay rounds to send before closing, `None` to send until disconnected.
+    pub rounds: Option<usize>,
+    pub requests: Arc<Mutex<Vec<serde_json::Value>>>,
+    pub disconnected: Arc<AtomicBool>,
+}
+
+impl MockServerState {
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected.load(Ordering::Relaxed)
+    }
+}
+
+pub fn load_fixture(file_name: &str) -> String {
+    let path = format!("{}/src/tests/data/{file_name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(path).expect("Failed to read fixture")
+}
+
+async fn handle_replay(
+    ws: WebSocketUpgrade,
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<MockServerState>,
+) -> Response {
+    let options = params
+        .get("options")
+        .and_then(|options| serde_json::from_str(options).ok())
+        .unwrap_or_default();
+    state.requests.lock().unwrap().push(options);
+
+    ws.on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(mut socket: WebSocket, state: MockServerState) {
+    let messages: Vec<String> = REPLAY_FIXTURES.iter().map(|f| load_fixture(f)).collect();
+
+    let mut round = 0;
+    while state.rounds.is_none_or(|rounds| round < rounds) {
+        for msg in &messages {
+            if socket
+                .send(Message::Text(msg.clone().into()))
+                .await
+                .is_err()
+            {
+                state.disconnected.store(true, Ordering::Relaxed);
+                return;
+            }
+        }
+        round += 1;
+    }
+
+    let _ = socket
+        .send(Message::Close(Some(CloseFrame {
+            code: close_code::NORMAL,
+            reason: "Replay complete".into(),
+        })))
+        .await;
+}
+
+/// Starts the mock server on a free port on its own runtime thread, returning its address
+/// and recorded state.
+pub fn start_mock_server(rounds: Option<usize>) -> (SocketAddr, MockServerState) {
+    let state = MockServerState {
+        rounds,
+        requests: Arc::default(),
+        disconnected: Arc::default(),
+    };
+    let router = Router::new()
+        .route("/ws-replay-normalized", get(handle_replay))
+        .with_state(state.clone());
+
+    let (addr_tx, addr_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async move {
+            // Bind to port 0 to let the OS assign an available port
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            addr_tx.send(listener.local_addr().unwrap()).unwrap();
+            axum::serve(listener, router).await.unwrap();
+        });
+    });
+
+    (addr_rx.recv().unwrap(), state)
+}
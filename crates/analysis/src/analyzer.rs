@@ -22,6 +22,7 @@ use std::{
 use ant_core::UnixNanos;
 use ant_model::{
     accounts::Account,
+    events::AccountState,
     identifiers::PositionId,
     position::Position,
     types::{Currency, Money},
@@ -29,15 +30,18 @@ use ant_model::{
 use rust_decimal::Decimal;
 
 use crate::{
-    Returns,
-    statistic::PortfolioStatistic,
+    EquityCurve, Returns,
+    statistic::{PortfolioStatistic, calculate_drawdowns},
     statistics::{
+        avg_holding_period::AvgHoldingPeriod, cagr::CAGR, calmar_ratio::CalmarRatio,
         expectancy::Expectancy, long_ratio::LongRatio, loser_max::MaxLoser, loser_min::MinLoser,
+        max_drawdown::MaxDrawdown, max_drawdown_duration::MaxDrawdownDuration,
         profit_factor::ProfitFactor, returns_avg::ReturnsAverage,
         returns_avg_loss::ReturnsAverageLoss, returns_avg_win::ReturnsAverageWin,
         returns_volatility::ReturnsVolatility, risk_return_ratio::RiskReturnRatio,
-        sharpe_ratio::SharpeRatio, sortino_ratio::SortinoRatio, win_rate::WinRate,
-        winner_avg::AvgWinner, winner_max::MaxWinner, winner_min::MinWinner,
+        sharpe_ratio::SharpeRatio, sortino_ratio::SortinoRatio, time_in_market::TimeInMarket,
+        ulcer_index::UlcerIndex, win_rate::WinRate, winner_avg::AvgWinner, winner_max::MaxWinner,
+        winner_min::MinWinner,
     },
 };
 
@@ -45,9 +49,9 @@ pub type Statistic = Arc<dyn PortfolioStatistic<Item = f64> + Send + Sync>;
 
 /// Analyzes portfolio performance and calculates various statistics.
 ///
-/// The `PortfolioAnalyzer` tracks account balances, positions, and realized PnLs
-/// to provide comprehensive portfolio analysis including returns, PnL calculations,
-/// and customizable statistics.
+/// The `PortfolioAnalyzer` tracks account balances, positions, realized PnLs and
+/// per-currency equity curves to provide comprehensive portfolio analysis including
+/// returns, PnL calculations, drawdowns and customizable statistics.
 #[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
@@ -61,6 +65,7 @@ pub struct PortfolioAnalyzer {
     positions: Vec<Position>,
     realized_pnls: HashMap<Currency, Vec<(PositionId, f64)>>,
     returns: Returns,
+    equity_curves: HashMap<Currency, EquityCurve>,
 }
 
 impl Default for PortfolioAnalyzer {
@@ -83,6 +88,13 @@ impl Default for PortfolioAnalyzer {
         analyzer.register_statistic(Arc::new(ProfitFactor {}));
         analyzer.register_statistic(Arc::new(RiskReturnRatio {}));
         analyzer.register_statistic(Arc::new(LongRatio::new(None)));
+        analyzer.register_statistic(Arc::new(MaxDrawdown {}));
+        analyzer.register_statistic(Arc::new(MaxDrawdownDuration {}));
+        analyzer.register_statistic(Arc::new(CAGR::new(None)));
+        analyzer.register_statistic(Arc::new(CalmarRatio::new(None)));
+        analyzer.register_statistic(Arc::new(UlcerIndex {}));
+        analyzer.register_statistic(Arc::new(TimeInMarket {}));
+        analyzer.register_statistic(Arc::new(AvgHoldingPeriod {}));
         analyzer
     }
 }
@@ -100,6 +112,7 @@ impl PortfolioAnalyzer {
             positions: Vec::new(),
             realized_pnls: HashMap::new(),
             returns: BTreeMap::new(),
+            equity_curves: HashMap::new(),
         }
     }
 
@@ -124,6 +137,7 @@ impl PortfolioAnalyzer {
         self.account_balances.clear();
         self.realized_pnls.clear();
         self.returns.clear();
+        self.equity_curves.clear();
     }
 
     /// Returns all tracked currencies.
@@ -150,10 +164,44 @@ impl PortfolioAnalyzer {
         self.account_balances = account.balances_total();
         self.realized_pnls.clear();
         self.returns.clear();
+        self.equity_curves.clear();
 
+        for state in account.events() {
+            self.add_account_state(&state);
+        }
         self.add_positions(positions);
     }
 
+    /// Records the total balance of each currency in an account `state` on the equity curves.
+    ///
+    /// A later state with the same `ts_event` replaces the earlier value.
+    pub fn add_account_state(&mut self, state: &AccountState) {
+        for balance in &state.balances {
+            self.equity_curves
+                .entry(balance.currency)
+                .or_default()
+                .insert(state.ts_event, balance.total.as_f64());
+        }
+    }
+
+    /// Returns the equity curve for a specific currency.
+    ///
+    /// Falls back to the first tracked currency when `currency` is `None`.
+    #[must_use]
+    pub fn equity_curve(&self, currency: Option<&Currency>) -> Option<&EquityCurve> {
+        let currency = currency.or_else(|| self.equity_curves.keys().next())?;
+        self.equity_curves.get(currency)
+    }
+
+    /// Returns the drawdown (underwater) series for a specific currency.
+    ///
+    /// Each value is the fractional decline of equity from its running peak, so `0.0` marks a
+    /// new high and `-0.2` a 20% drawdown.
+    #[must_use]
+    pub fn drawdowns(&self, currency: Option<&Currency>) -> Option<EquityCurve> {
+        self.equity_curve(currency).map(calculate_drawdowns)
+    }
+
     /// Adds new positions for analysis.
     pub fn add_positions(&mut self, positions: &[Position]) {
         self.positions.extend_from_slice(positions);
@@ -340,6 +388,27 @@ impl PortfolioAnalyzer {
         output
     }
 
+    /// Gets all equity curve based performance statistics for a specific currency.
+    #[must_use]
+    pub fn get_performance_stats_equity(
+        &self,
+        currency: Option<&Currency>,
+    ) -> HashMap<String, f64> {
+        let mut output = HashMap::new();
+
+        let Some(equity_curve) = self.equity_curve(currency) else {
+            return output;
+        };
+
+        for (name, stat) in &self.statistics {
+            if let Some(value) = stat.calculate_from_equity_curve(equity_curve) {
+                output.insert(name.clone(), value);
+            }
+        }
+
+        output
+    }
+
     /// Gets general portfolio statistics.
     #[must_use]
     pub fn get_performance_stats_general(&self) -> HashMap<String, f64> {
@@ -420,7 +489,7 @@ impl PortfolioAnalyzer {
 mod tests {
     use std::sync::Arc;
 
-    use ant_core::approx_eq;
+    use ant_core::{UUID4, approx_eq};
     use ant_model::{
         enums::{AccountType, LiquiditySide, OrderSide},
         events::{AccountState, OrderFilled},
@@ -561,7 +630,7 @@ mod tests {
             todo!()
         }
         fn events(&self) -> Vec<AccountState> {
-            todo!()
+            Vec::new()
         }
         fn event_count(&self) -> usize {
             todo!()
@@ -847,4 +916,78 @@ mod tests {
         assert!(analyzer.realized_pnls.is_empty());
         assert!(analyzer.returns.is_empty());
     }
+
+    fn create_account_state(ts_event: u64, total: f64, currency: Currency) -> AccountState {
+        let total = Money::new(total, currency);
+        AccountState::new(
+            AccountId::new("test-account"),
+            AccountType::Cash,
+            vec![AccountBalance::new(total, Money::new(0.0, currency), total)],
+            Vec::new(),
+            true,
+            UUID4::new(),
+            UnixNanos::from(ts_event),
+            UnixNanos::from(ts_event),
+            Some(currency),
+        )
+    }
+
+    #[rstest]
+    fn test_equity_curve_and_drawdowns_from_account_states() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let currency = Currency::USD();
+
+        analyzer.add_account_state(&create_account_state(1, 1000.0, currency));
+        analyzer.add_account_state(&create_account_state(2, 1200.0, currency));
+        analyzer.add_account_state(&create_account_state(3, 900.0, currency));
+        analyzer.add_account_state(&create_account_state(4, 1300.0, currency));
+
+        let equity_curve = analyzer.equity_curve(Some(&currency)).unwrap();
+        assert_eq!(
+            equity_curve.values().copied().collect::<Vec<f64>>(),
+            vec![1000.0, 1200.0, 900.0, 1300.0]
+        );
+
+        let drawdowns = analyzer.drawdowns(None).unwrap();
+        let drawdowns: Vec<f64> = drawdowns.values().copied().collect();
+        assert!(approx_eq!(f64, drawdowns[0], 0.0, epsilon = 1e-9));
+        assert!(approx_eq!(f64, drawdowns[1], 0.0, epsilon = 1e-9));
+        assert!(approx_eq!(f64, drawdowns[2], -0.25, epsilon = 1e-9));
+        assert!(approx_eq!(f64, drawdowns[3], 0.0, epsilon = 1e-9));
+
+        assert!(analyzer.equity_curve(Some(&Currency::EUR())).is_none());
+    }
+
+    #[rstest]
+    fn test_performance_stats_equity() {
+        let mut analyzer = PortfolioAnalyzer::default();
+        let currency = Currency::USD();
+
+        analyzer.add_account_state(&create_account_state(1, 1000.0, currency));
+        analyzer.add_account_state(&create_account_state(2, 800.0, currency));
+        analyzer.add_account_state(&create_account_state(3, 1100.0, currency));
+
+        let stats = analyzer.get_performance_stats_equity(Some(&currency));
+        assert!(approx_eq!(f64, stats["MaxDrawdown"], -0.2, epsilon = 1e-9));
+        assert!(stats.contains_key("MaxDrawdownDuration"));
+        assert!(stats.contains_key("UlcerIndex"));
+        assert!(!stats.contains_key("WinRate"));
+
+        assert!(
+            analyzer
+                .get_performance_stats_equity(Some(&Currency::EUR()))
+                .is_empty()
+        );
+    }
+
+    #[rstest]
+    fn test_reset_clears_equity_curves() {
+        let mut analyzer = PortfolioAnalyzer::new();
+        let currency = Currency::USD();
+
+        analyzer.add_account_state(&create_account_state(1, 1000.0, currency));
+        analyzer.reset();
+
+        assert!(analyzer.equity_curve(None).is_none());
+    }
 }
@@ -21,6 +21,7 @@
 //!
 //! - Portfolio analyzer for tracking account states and positions.
 //! - Extensive collection of performance statistics and risk metrics.
+//! - Equity curve and drawdown analysis from account state events.
 //! - Flexible statistic calculation framework supporting different data sources.
 //! - Support for multi-currency portfolios and unrealized PnL calculations.
 //!
@@ -67,3 +68,8 @@ use ant_core::UnixNanos;
 ///
 /// Maps timestamps to return values for time-series analysis of portfolio performance.
 pub type Returns = BTreeMap<UnixNanos, f64>;
+
+/// Type alias for a time-indexed equity curve used in portfolio analysis.
+///
+/// Maps timestamps to the total account balance for drawdown and growth statistics.
+pub type EquityCurve = BTreeMap<UnixNanos, f64>;
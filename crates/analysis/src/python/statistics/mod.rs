@@ -15,11 +15,16 @@
 
 //! Python bindings for trading performance statistics.
 
+pub mod avg_holding_period;
+pub mod cagr;
+pub mod calmar_ratio;
 pub mod expectancy;
 pub mod long_ratio;
 pub mod loser_avg;
 pub mod loser_max;
 pub mod loser_min;
+pub mod max_drawdown;
+pub mod max_drawdown_duration;
 pub mod profit_factor;
 pub mod returns_avg;
 pub mod returns_avg_loss;
@@ -28,6 +33,8 @@ pub mod returns_volatlity;
 pub mod risk_return_ratio;
 pub mod sharpe_ratio;
 pub mod sortino_ratio;
+pub mod time_in_market;
+pub mod ulcer_index;
 pub mod win_rate;
 pub mod winner_avg;
 pub mod winner_max;
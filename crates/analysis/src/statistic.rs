@@ -17,15 +17,26 @@ use std::{collections::BTreeMap, fmt::Debug};
 
 use ant_model::{orders::Order, position::Position};
 
-use crate::Returns;
+use crate::{EquityCurve, Returns};
 
-const IMPL_ERR: &str = "is not implemented for";
+/// Number of nanoseconds in a day, for statistics expressed in days.
+pub(crate) const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
 
 /// Trait for portfolio performance statistics that can be calculated from different data sources.
 ///
 /// This trait provides a flexible framework for implementing various financial performance
-/// metrics that can operate on returns, realized PnLs, orders, or positions data.
+/// metrics that can operate on returns, realized PnLs, orders, positions or equity curve data.
 /// Each statistic implementation should override the relevant calculation methods.
+///
+/// # Default implementations
+///
+/// All `calculate_from_*` methods return `None` by default, meaning the statistic does not apply
+/// to that data source. This is a behavior change: the returns, realized PnLs, orders and
+/// positions methods previously panicked when not overridden. The [`PortfolioAnalyzer`]
+/// evaluates every registered statistic against every data source, so a statistic which only
+/// supports some sources must not panic for the others.
+///
+/// [`PortfolioAnalyzer`]: crate::analyzer::PortfolioAnalyzer
 #[allow(unused_variables)]
 pub trait PortfolioStatistic: Debug {
     type Item;
@@ -35,42 +46,38 @@ pub trait PortfolioStatistic: Debug {
 
     /// Calculates the statistic from time-indexed returns data.
     ///
-    /// # Panics
-    ///
-    /// Panics if this method is not implemented for the specific statistic.
+    /// Returns `None` by default (previously panicked).
     fn calculate_from_returns(&self, returns: &Returns) -> Option<Self::Item> {
-        panic!("`calculate_from_returns` {IMPL_ERR} `{}`", self.name());
+        None
     }
 
     /// Calculates the statistic from realized profit and loss values.
     ///
-    /// # Panics
-    ///
-    /// Panics if this method is not implemented for the specific statistic.
+    /// Returns `None` by default (previously panicked).
     fn calculate_from_realized_pnls(&self, realized_pnls: &[f64]) -> Option<Self::Item> {
-        panic!(
-            "`calculate_from_realized_pnls` {IMPL_ERR} `{}`",
-            self.name()
-        );
+        None
     }
 
     /// Calculates the statistic from order data.
     ///
-    /// # Panics
-    ///
-    /// Panics if this method is not implemented for the specific statistic.
+    /// Returns `None` by default (previously panicked).
     #[allow(dead_code)]
     fn calculate_from_orders(&self, orders: Vec<Box<dyn Order>>) -> Option<Self::Item> {
-        panic!("`calculate_from_orders` {IMPL_ERR} `{}`", self.name());
+        None
     }
 
     /// Calculates the statistic from position data.
     ///
-    /// # Panics
-    ///
-    /// Panics if this method is not implemented for the specific statistic.
+    /// Returns `None` by default (previously panicked).
     fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
-        panic!("`calculate_from_positions` {IMPL_ERR} `{}`", self.name());
+        None
+    }
+
+    /// Calculates the statistic from a time-indexed equity curve.
+    ///
+    /// Returns `None` by default.
+    fn calculate_from_equity_curve(&self, equity_curve: &EquityCurve) -> Option<Self::Item> {
+        None
     }
 
     /// Validates that returns data is not empty.
@@ -108,3 +115,20 @@ pub trait PortfolioStatistic: Debug {
         variance.sqrt()
     }
 }
+
+/// Calculates the drawdown series (underwater curve) for the `equity_curve`.
+///
+/// Each drawdown is the fractional decline of equity from the running peak, so values are
+/// zero at a new high and negative while underwater.
+#[must_use]
+pub fn calculate_drawdowns(equity_curve: &EquityCurve) -> EquityCurve {
+    let mut peak = f64::NEG_INFINITY;
+    equity_curve
+        .iter()
+        .map(|(&timestamp, &equity)| {
+            peak = peak.max(equity);
+            let drawdown = if peak > 0.0 { equity / peak - 1.0 } else { 0.0 };
+            (timestamp, drawdown)
+        })
+        .collect()
+}
@@ -0,0 +1,143 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use ant_model::position::Position;
+
+use crate::statistic::{NANOS_PER_DAY, PortfolioStatistic};
+
+/// Calculates the average holding period of closed positions in days.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct AvgHoldingPeriod {}
+
+impl PortfolioStatistic for AvgHoldingPeriod {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(AvgHoldingPeriod).to_string()
+    }
+
+    fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
+        let durations: Vec<f64> = positions
+            .iter()
+            .filter(|p| p.is_closed())
+            .map(|p| p.duration_ns as f64 / NANOS_PER_DAY)
+            .collect();
+
+        if durations.is_empty() {
+            return None;
+        }
+
+        Some(durations.iter().sum::<f64>() / durations.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use ant_model::{
+        enums::{OrderSide, PositionSide},
+        identifiers::{
+            AccountId, ClientOrderId, PositionId,
+            stubs::{instrument_id_aud_usd_sim, strategy_id_ema_cross, trader_id},
+        },
+        types::{Currency, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_test_position(ts_opened: u64, ts_closed: Option<u64>, ts_last: u64) -> Position {
+        Position {
+            events: Vec::new(),
+            trader_id: trader_id(),
+            strategy_id: strategy_id_ema_cross(),
+            instrument_id: instrument_id_aud_usd_sim(),
+            id: PositionId::new("test-position"),
+            account_id: AccountId::new("test-account"),
+            opening_order_id: ClientOrderId::default(),
+            closing_order_id: None,
+            entry: OrderSide::Buy,
+            side: if ts_closed.is_some() {
+                PositionSide::Flat
+            } else {
+                PositionSide::Long
+            },
+            signed_qty: 0.0,
+            quantity: Quantity::default(),
+            peak_qty: Quantity::default(),
+            price_precision: 2,
+            size_precision: 2,
+            multiplier: Quantity::default(),
+            is_inverse: false,
+            base_currency: None,
+            quote_currency: Currency::USD(),
+            settlement_currency: Currency::USD(),
+            ts_init: UnixNanos::default(),
+            ts_opened: UnixNanos::from(ts_opened),
+            ts_last: UnixNanos::from(ts_last),
+            ts_closed: ts_closed.map(UnixNanos::from),
+            duration_ns: ts_closed.map_or(0, |ts_closed| ts_closed - ts_opened),
+            avg_px_open: 0.0,
+            avg_px_close: None,
+            realized_return: 0.0,
+            realized_pnl: None,
+            trade_ids: Vec::new(),
+            buy_qty: Quantity::default(),
+            sell_qty: Quantity::default(),
+            commissions: HashMap::new(),
+        }
+    }
+
+    #[rstest]
+    fn test_empty_positions() {
+        let avg_holding_period = AvgHoldingPeriod {};
+        let result = avg_holding_period.calculate_from_positions(&[]);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_only_open_positions() {
+        let avg_holding_period = AvgHoldingPeriod {};
+        let positions = vec![create_test_position(0, None, 10)];
+        let result = avg_holding_period.calculate_from_positions(&positions);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_closed_positions() {
+        let one_day = NANOS_PER_DAY as u64;
+        let avg_holding_period = AvgHoldingPeriod {};
+        let positions = vec![
+            create_test_position(0, Some(one_day), one_day),
+            create_test_position(0, Some(3 * one_day), 3 * one_day),
+            create_test_position(0, None, 10 * one_day),
+        ];
+        let result = avg_holding_period.calculate_from_positions(&positions);
+        assert!(approx_eq!(f64, result.unwrap(), 2.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let avg_holding_period = AvgHoldingPeriod {};
+        assert_eq!(avg_holding_period.name(), "AvgHoldingPeriod");
+    }
+}
@@ -0,0 +1,128 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    EquityCurve,
+    statistic::{NANOS_PER_DAY, PortfolioStatistic},
+};
+
+/// Calculates the compound annual growth rate (CAGR) of the equity curve.
+///
+/// The growth from the first to the last equity value is annualized over the elapsed
+/// calendar time, returned as a fraction (e.g. 0.1 for 10% per year).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct CAGR {
+    /// The number of days per year (default: 365).
+    period: usize,
+}
+
+impl CAGR {
+    /// Creates a new [`CAGR`] instance.
+    #[must_use]
+    pub fn new(period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(365),
+        }
+    }
+}
+
+impl PortfolioStatistic for CAGR {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(CAGR).to_string()
+    }
+
+    fn calculate_from_equity_curve(&self, equity_curve: &EquityCurve) -> Option<Self::Item> {
+        let (Some((&start_ts, &start)), Some((&end_ts, &end))) = (
+            equity_curve.first_key_value(),
+            equity_curve.last_key_value(),
+        ) else {
+            return Some(f64::NAN);
+        };
+
+        let days = (end_ts.as_u64() - start_ts.as_u64()) as f64 / NANOS_PER_DAY;
+        if days <= 0.0 || start <= 0.0 || end < 0.0 {
+            return Some(f64::NAN);
+        }
+
+        let years = days / self.period as f64;
+        Some((end / start).powf(1.0 / years) - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_equity_curve(points: Vec<(u64, f64)>) -> EquityCurve {
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        points
+            .into_iter()
+            .map(|(day, value)| (UnixNanos::from(start_time + day * one_day_in_nanos), value))
+            .collect::<BTreeMap<_, _>>()
+    }
+
+    #[rstest]
+    fn test_empty_equity_curve() {
+        let cagr = CAGR::new(None);
+        let equity_curve = create_equity_curve(vec![]);
+        let result = cagr.calculate_from_equity_curve(&equity_curve);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_single_point() {
+        let cagr = CAGR::new(None);
+        let equity_curve = create_equity_curve(vec![(0, 100.0)]);
+        let result = cagr.calculate_from_equity_curve(&equity_curve);
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_two_year_growth() {
+        let cagr = CAGR::new(None);
+        let equity_curve = create_equity_curve(vec![(0, 100.0), (365, 90.0), (730, 121.0)]);
+        let result = cagr.calculate_from_equity_curve(&equity_curve);
+        assert!(approx_eq!(f64, result.unwrap(), 0.1, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_custom_period() {
+        let cagr = CAGR::new(Some(252));
+        let equity_curve = create_equity_curve(vec![(0, 100.0), (252, 110.0)]);
+        let result = cagr.calculate_from_equity_curve(&equity_curve);
+        assert!(approx_eq!(f64, result.unwrap(), 0.1, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let cagr = CAGR::new(None);
+        assert_eq!(cagr.name(), "CAGR");
+    }
+}
@@ -0,0 +1,116 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    EquityCurve,
+    statistic::PortfolioStatistic,
+    statistics::{cagr::CAGR, max_drawdown::MaxDrawdown},
+};
+
+/// Calculates the Calmar ratio of the equity curve.
+///
+/// The Calmar ratio measures return relative to downside risk and is calculated as:
+/// CAGR / |Maximum Drawdown|
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct CalmarRatio {
+    /// The number of days per year used to annualize growth (default: 365).
+    period: usize,
+}
+
+impl CalmarRatio {
+    /// Creates a new [`CalmarRatio`] instance.
+    #[must_use]
+    pub fn new(period: Option<usize>) -> Self {
+        Self {
+            period: period.unwrap_or(365),
+        }
+    }
+}
+
+impl PortfolioStatistic for CalmarRatio {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(CalmarRatio).to_string()
+    }
+
+    fn calculate_from_equity_curve(&self, equity_curve: &EquityCurve) -> Option<Self::Item> {
+        let cagr = CAGR::new(Some(self.period)).calculate_from_equity_curve(equity_curve)?;
+        let max_drawdown = MaxDrawdown {}.calculate_from_equity_curve(equity_curve)?;
+
+        if cagr.is_nan() || max_drawdown.abs() < f64::EPSILON {
+            return Some(f64::NAN);
+        }
+
+        Some(cagr / max_drawdown.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_equity_curve(points: Vec<(u64, f64)>) -> EquityCurve {
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        points
+            .into_iter()
+            .map(|(day, value)| (UnixNanos::from(start_time + day * one_day_in_nanos), value))
+            .collect::<BTreeMap<_, _>>()
+    }
+
+    #[rstest]
+    fn test_empty_equity_curve() {
+        let calmar = CalmarRatio::new(None);
+        let equity_curve = create_equity_curve(vec![]);
+        let result = calmar.calculate_from_equity_curve(&equity_curve);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_no_drawdown() {
+        let calmar = CalmarRatio::new(None);
+        let equity_curve = create_equity_curve(vec![(0, 100.0), (365, 110.0)]);
+        let result = calmar.calculate_from_equity_curve(&equity_curve);
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_calmar_ratio() {
+        let calmar = CalmarRatio::new(None);
+        let equity_curve = create_equity_curve(vec![(0, 100.0), (365, 80.0), (730, 121.0)]);
+        let result = calmar.calculate_from_equity_curve(&equity_curve);
+        // CAGR of 10% over a maximum drawdown of 20%
+        assert!(approx_eq!(f64, result.unwrap(), 0.5, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let calmar = CalmarRatio::new(None);
+        assert_eq!(calmar.name(), "CalmarRatio");
+    }
+}
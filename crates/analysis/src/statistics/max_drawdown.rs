@@ -0,0 +1,102 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    EquityCurve,
+    statistic::{PortfolioStatistic, calculate_drawdowns},
+};
+
+/// Calculates the maximum drawdown of the equity curve.
+///
+/// The maximum drawdown is the largest peak-to-trough decline in equity, returned as a
+/// negative fraction of the peak (e.g. -0.25 for a 25% drawdown).
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct MaxDrawdown {}
+
+impl PortfolioStatistic for MaxDrawdown {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(MaxDrawdown).to_string()
+    }
+
+    fn calculate_from_equity_curve(&self, equity_curve: &EquityCurve) -> Option<Self::Item> {
+        if equity_curve.is_empty() {
+            return Some(f64::NAN);
+        }
+
+        let drawdowns = calculate_drawdowns(equity_curve);
+        Some(drawdowns.values().copied().fold(0.0, f64::min))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_equity_curve(values: Vec<f64>) -> EquityCurve {
+        let mut equity_curve = BTreeMap::new();
+        for (i, value) in values.iter().enumerate() {
+            equity_curve.insert(UnixNanos::from(i as u64), *value);
+        }
+        equity_curve
+    }
+
+    #[rstest]
+    fn test_empty_equity_curve() {
+        let max_drawdown = MaxDrawdown {};
+        let equity_curve = create_equity_curve(vec![]);
+        let result = max_drawdown.calculate_from_equity_curve(&equity_curve);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_no_drawdown() {
+        let max_drawdown = MaxDrawdown {};
+        let equity_curve = create_equity_curve(vec![100.0, 110.0, 120.0]);
+        let result = max_drawdown.calculate_from_equity_curve(&equity_curve);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[rstest]
+    fn test_max_drawdown() {
+        let max_drawdown = MaxDrawdown {};
+        let equity_curve = create_equity_curve(vec![100.0, 120.0, 90.0, 110.0, 80.0, 130.0]);
+        let result = max_drawdown.calculate_from_equity_curve(&equity_curve);
+        assert!(approx_eq!(
+            f64,
+            result.unwrap(),
+            80.0 / 120.0 - 1.0,
+            epsilon = 1e-9
+        ));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let max_drawdown = MaxDrawdown {};
+        assert_eq!(max_drawdown.name(), "MaxDrawdown");
+    }
+}
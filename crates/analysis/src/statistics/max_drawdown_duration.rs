@@ -0,0 +1,130 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    EquityCurve,
+    statistic::{NANOS_PER_DAY, PortfolioStatistic},
+};
+
+/// Calculates the longest drawdown duration of the equity curve in days.
+///
+/// A drawdown lasts from the time of the running peak until equity recovers to that peak,
+/// or until the end of the curve if it has not yet recovered.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct MaxDrawdownDuration {}
+
+impl PortfolioStatistic for MaxDrawdownDuration {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(MaxDrawdownDuration).to_string()
+    }
+
+    fn calculate_from_equity_curve(&self, equity_curve: &EquityCurve) -> Option<Self::Item> {
+        let Some((&first_ts, &first_equity)) = equity_curve.first_key_value() else {
+            return Some(f64::NAN);
+        };
+
+        let mut peak = first_equity;
+        let mut peak_ts = first_ts;
+        let mut in_drawdown = false;
+        let mut max_duration = 0;
+
+        for (&timestamp, &equity) in equity_curve {
+            if equity < peak {
+                in_drawdown = true;
+                max_duration = max_duration.max(timestamp.as_u64() - peak_ts.as_u64());
+                continue;
+            }
+
+            if in_drawdown {
+                // Recovering to the peak ends the drawdown
+                max_duration = max_duration.max(timestamp.as_u64() - peak_ts.as_u64());
+                in_drawdown = false;
+            }
+            peak = equity;
+            peak_ts = timestamp;
+        }
+
+        Some(max_duration as f64 / NANOS_PER_DAY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_equity_curve(values: Vec<f64>) -> EquityCurve {
+        let mut equity_curve = BTreeMap::new();
+        let one_day_in_nanos = 86_400_000_000_000;
+        let start_time = 1_600_000_000_000_000_000;
+
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = start_time + i as u64 * one_day_in_nanos;
+            equity_curve.insert(UnixNanos::from(timestamp), value);
+        }
+
+        equity_curve
+    }
+
+    #[rstest]
+    fn test_empty_equity_curve() {
+        let duration = MaxDrawdownDuration {};
+        let equity_curve = create_equity_curve(vec![]);
+        let result = duration.calculate_from_equity_curve(&equity_curve);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_no_drawdown() {
+        let duration = MaxDrawdownDuration {};
+        let equity_curve = create_equity_curve(vec![100.0, 110.0, 120.0]);
+        let result = duration.calculate_from_equity_curve(&equity_curve);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[rstest]
+    fn test_recovered_drawdown() {
+        let duration = MaxDrawdownDuration {};
+        let equity_curve = create_equity_curve(vec![100.0, 90.0, 95.0, 100.0, 110.0, 105.0]);
+        let result = duration.calculate_from_equity_curve(&equity_curve);
+        assert!(approx_eq!(f64, result.unwrap(), 3.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_unrecovered_drawdown() {
+        let duration = MaxDrawdownDuration {};
+        let equity_curve = create_equity_curve(vec![100.0, 120.0, 110.0, 100.0, 90.0]);
+        let result = duration.calculate_from_equity_curve(&equity_curve);
+        assert!(approx_eq!(f64, result.unwrap(), 3.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let duration = MaxDrawdownDuration {};
+        assert_eq!(duration.name(), "MaxDrawdownDuration");
+    }
+}
@@ -0,0 +1,168 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use ant_model::position::Position;
+
+use crate::statistic::PortfolioStatistic;
+
+/// Calculates the fraction of time the portfolio held at least one open position.
+///
+/// Position lifetimes are merged so overlapping positions are only counted once, and the
+/// covered time is divided by the span from the first open to the last position timestamp.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct TimeInMarket {}
+
+impl PortfolioStatistic for TimeInMarket {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(TimeInMarket).to_string()
+    }
+
+    fn calculate_from_positions(&self, positions: &[Position]) -> Option<Self::Item> {
+        if positions.is_empty() {
+            return None;
+        }
+
+        let mut intervals: Vec<(u64, u64)> = positions
+            .iter()
+            .map(|p| {
+                let start = p.ts_opened.as_u64();
+                let end = p.ts_closed.unwrap_or(p.ts_last).as_u64();
+                (start, end.max(start))
+            })
+            .collect();
+        intervals.sort_unstable();
+
+        let span_start = intervals[0].0;
+        let span_end = intervals.iter().map(|(_, end)| *end).max()?;
+        if span_end <= span_start {
+            return None;
+        }
+
+        let mut covered = 0;
+        let (mut current_start, mut current_end) = intervals[0];
+        for &(start, end) in &intervals[1..] {
+            if start <= current_end {
+                current_end = current_end.max(end);
+            } else {
+                covered += current_end - current_start;
+                current_start = start;
+                current_end = end;
+            }
+        }
+        covered += current_end - current_start;
+
+        Some(covered as f64 / (span_end - span_start) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use ant_model::{
+        enums::{OrderSide, PositionSide},
+        identifiers::{
+            AccountId, ClientOrderId, PositionId,
+            stubs::{instrument_id_aud_usd_sim, strategy_id_ema_cross, trader_id},
+        },
+        types::{Currency, Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_test_position(ts_opened: u64, ts_closed: Option<u64>, ts_last: u64) -> Position {
+        Position {
+            events: Vec::new(),
+            trader_id: trader_id(),
+            strategy_id: strategy_id_ema_cross(),
+            instrument_id: instrument_id_aud_usd_sim(),
+            id: PositionId::new("test-position"),
+            account_id: AccountId::new("test-account"),
+            opening_order_id: ClientOrderId::default(),
+            closing_order_id: None,
+            entry: OrderSide::Buy,
+            side: if ts_closed.is_some() {
+                PositionSide::Flat
+            } else {
+                PositionSide::Long
+            },
+            signed_qty: 0.0,
+            quantity: Quantity::default(),
+            peak_qty: Quantity::default(),
+            price_precision: 2,
+            size_precision: 2,
+            multiplier: Quantity::default(),
+            is_inverse: false,
+            base_currency: None,
+            quote_currency: Currency::USD(),
+            settlement_currency: Currency::USD(),
+            ts_init: UnixNanos::default(),
+            ts_opened: UnixNanos::from(ts_opened),
+            ts_last: UnixNanos::from(ts_last),
+            ts_closed: ts_closed.map(UnixNanos::from),
+            duration_ns: ts_closed.map_or(0, |ts_closed| ts_closed - ts_opened),
+            avg_px_open: 0.0,
+            avg_px_close: None,
+            realized_return: 0.0,
+            realized_pnl: None,
+            trade_ids: Vec::new(),
+            buy_qty: Quantity::default(),
+            sell_qty: Quantity::default(),
+            commissions: HashMap::new(),
+        }
+    }
+
+    #[rstest]
+    fn test_empty_positions() {
+        let time_in_market = TimeInMarket {};
+        let result = time_in_market.calculate_from_positions(&[]);
+        assert!(result.is_none());
+    }
+
+    #[rstest]
+    fn test_single_position() {
+        let time_in_market = TimeInMarket {};
+        let positions = vec![create_test_position(0, Some(10), 10)];
+        let result = time_in_market.calculate_from_positions(&positions);
+        assert!(approx_eq!(f64, result.unwrap(), 1.0, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_overlapping_and_disjoint_positions() {
+        let time_in_market = TimeInMarket {};
+        let positions = vec![
+            create_test_position(0, Some(10), 10),
+            create_test_position(5, Some(20), 20),
+            create_test_position(30, None, 40),
+        ];
+        let result = time_in_market.calculate_from_positions(&positions);
+        assert!(approx_eq!(f64, result.unwrap(), 0.75, epsilon = 1e-9));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let time_in_market = TimeInMarket {};
+        assert_eq!(time_in_market.name(), "TimeInMarket");
+    }
+}
@@ -0,0 +1,107 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::{
+    EquityCurve,
+    statistic::{PortfolioStatistic, calculate_drawdowns},
+};
+
+/// Calculates the Ulcer index of the equity curve.
+///
+/// The Ulcer index measures the depth and duration of drawdowns as the root mean square
+/// of the percentage drawdowns from the running peak.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "ant_trader.core.ant_pyo3.analysis")
+)]
+pub struct UlcerIndex {}
+
+impl PortfolioStatistic for UlcerIndex {
+    type Item = f64;
+
+    fn name(&self) -> String {
+        stringify!(UlcerIndex).to_string()
+    }
+
+    fn calculate_from_equity_curve(&self, equity_curve: &EquityCurve) -> Option<Self::Item> {
+        if equity_curve.is_empty() {
+            return Some(f64::NAN);
+        }
+
+        let drawdowns = calculate_drawdowns(equity_curve);
+        let sum_squares: f64 = drawdowns
+            .values()
+            .map(|drawdown| (drawdown * 100.0).powi(2))
+            .sum();
+
+        Some((sum_squares / drawdowns.len() as f64).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ant_core::{UnixNanos, approx_eq};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn create_equity_curve(values: Vec<f64>) -> EquityCurve {
+        let mut equity_curve = BTreeMap::new();
+        for (i, value) in values.iter().enumerate() {
+            equity_curve.insert(UnixNanos::from(i as u64), *value);
+        }
+        equity_curve
+    }
+
+    #[rstest]
+    fn test_empty_equity_curve() {
+        let ulcer_index = UlcerIndex {};
+        let equity_curve = create_equity_curve(vec![]);
+        let result = ulcer_index.calculate_from_equity_curve(&equity_curve);
+        assert!(result.is_some());
+        assert!(result.unwrap().is_nan());
+    }
+
+    #[rstest]
+    fn test_no_drawdown() {
+        let ulcer_index = UlcerIndex {};
+        let equity_curve = create_equity_curve(vec![100.0, 110.0, 120.0]);
+        let result = ulcer_index.calculate_from_equity_curve(&equity_curve);
+        assert_eq!(result, Some(0.0));
+    }
+
+    #[rstest]
+    fn test_ulcer_index() {
+        let ulcer_index = UlcerIndex {};
+        let equity_curve = create_equity_curve(vec![100.0, 90.0, 100.0]);
+        let result = ulcer_index.calculate_from_equity_curve(&equity_curve);
+        assert!(approx_eq!(
+            f64,
+            result.unwrap(),
+            (100.0_f64 / 3.0).sqrt(),
+            epsilon = 1e-9
+        ));
+    }
+
+    #[rstest]
+    fn test_name() {
+        let ulcer_index = UlcerIndex {};
+        assert_eq!(ulcer_index.name(), "UlcerIndex");
+    }
+}
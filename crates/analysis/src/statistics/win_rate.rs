@@ -55,6 +55,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::{EquityCurve, Returns};
 
     #[rstest]
     fn test_empty_pnls() {
@@ -64,6 +65,18 @@ mod tests {
         assert!(approx_eq!(f64, result.unwrap(), 0.0, epsilon = 1e-9));
     }
 
+    #[rstest]
+    fn test_other_data_sources_not_applicable() {
+        let win_rate = WinRate {};
+        assert!(win_rate.calculate_from_returns(&Returns::new()).is_none());
+        assert!(win_rate.calculate_from_positions(&[]).is_none());
+        assert!(
+            win_rate
+                .calculate_from_equity_curve(&EquityCurve::new())
+                .is_none()
+        );
+    }
+
     #[rstest]
     fn test_all_winning_trades() {
         let win_rate = WinRate {};
@@ -25,7 +25,9 @@ use ant_common::{
 };
 use ant_core::{UUID4, UnixNanos};
 use ant_data::engine::config::DataEngineConfig;
-use ant_execution::engine::config::ExecutionEngineConfig;
+use ant_execution::{
+    engine::config::ExecutionEngineConfig, matching_engine::config::LiquidityExhaustedBehavior,
+};
 use ant_model::{
     data::BarSpecification,
     enums::{AccountType, BookType, OmsType},
@@ -35,7 +37,7 @@ use ant_model::{
 use ant_persistence::config::StreamingConfig;
 use ant_portfolio::config::PortfolioConfig;
 use ant_risk::engine::config::RiskEngineConfig;
-use ant_system::config::NautilusKernelConfig;
+use ant_system::config::AntKernelConfig;
 use ustr::Ustr;
 
 /// Configuration for ``BacktestEngine`` instances.
@@ -137,7 +139,7 @@ impl BacktestEngineConfig {
     }
 }
 
-impl NautilusKernelConfig for BacktestEngineConfig {
+impl AntKernelConfig for BacktestEngineConfig {
     fn environment(&self) -> Environment {
         self.environment
     }
@@ -290,6 +292,20 @@ pub struct BacktestVenueConfig {
     default_leverage: Option<f64>,
     /// The instrument specific leverage configuration (for margin accounts).
     leverages: Option<HashMap<Currency, f64>>,
+    /// The default daily price limit as a fraction of the previous close or settlement
+    /// (e.g. 0.10 for +/-10%). Use `None` for no price limits.
+    default_price_limit_pct: Option<f64>,
+    /// The instrument specific daily price limits (take precedence over the default).
+    price_limit_pcts: Option<HashMap<InstrumentId, f64>>,
+    /// If orders should not fill while the market is locked at the limit price.
+    limit_locked_no_fill: bool,
+    /// If quantity bought on a trading day cannot be sold until the next trading day (T+1).
+    use_t_plus_one: bool,
+    /// The offset (seconds) added to UTC timestamps to determine the trading day, e.g. 8 hours
+    /// for China A-shares and 16 hours for China futures with night sessions.
+    trading_day_offset_secs: i64,
+    /// The behavior when an aggressive order exhausts the simulated L1 book volume.
+    liquidity_exhausted_behavior: LiquidityExhaustedBehavior,
 }
 
 impl BacktestVenueConfig {
@@ -315,6 +331,12 @@ impl BacktestVenueConfig {
         base_currency: Option<Currency>,
         default_leverage: Option<f64>,
         leverages: Option<HashMap<Currency, f64>>,
+        default_price_limit_pct: Option<f64>,
+        price_limit_pcts: Option<HashMap<InstrumentId, f64>>,
+        limit_locked_no_fill: Option<bool>,
+        use_t_plus_one: Option<bool>,
+        trading_day_offset_secs: Option<i64>,
+        liquidity_exhausted_behavior: Option<LiquidityExhaustedBehavior>,
     ) -> Self {
         Self {
             name,
@@ -336,6 +358,12 @@ impl BacktestVenueConfig {
             base_currency,
             default_leverage,
             leverages,
+            default_price_limit_pct,
+            price_limit_pcts,
+            limit_locked_no_fill: limit_locked_no_fill.unwrap_or(false),
+            use_t_plus_one: use_t_plus_one.unwrap_or(false),
+            trading_day_offset_secs: trading_day_offset_secs.unwrap_or(0),
+            liquidity_exhausted_behavior: liquidity_exhausted_behavior.unwrap_or_default(),
         }
     }
 }
@@ -15,28 +15,189 @@
 
 //! Provides a `BacktestDataClient` implementation for backtesting.
 
-// Under development
-#![allow(dead_code)]
-#![allow(unused_variables)]
-
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::VecDeque, num::NonZeroUsize, rc::Rc};
 
 use ant_common::{
     cache::Cache,
+    clock::Clock,
     messages::data::{
-        RequestBars, RequestBookSnapshot, RequestCustomData, RequestInstrument, RequestInstruments,
-        RequestQuotes, RequestTrades, SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10,
-        SubscribeBookSnapshots, SubscribeCustomData, SubscribeIndexPrices, SubscribeInstrument,
-        SubscribeInstrumentClose, SubscribeInstrumentStatus, SubscribeInstruments,
-        SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades, UnsubscribeBars,
-        UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeBookSnapshots,
-        UnsubscribeCustomData, UnsubscribeIndexPrices, UnsubscribeInstrument,
-        UnsubscribeInstrumentClose, UnsubscribeInstrumentStatus, UnsubscribeInstruments,
-        UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+        BarsResponse, BookResponse, CustomDataResponse, DataResponse, InstrumentResponse,
+        InstrumentsResponse, QuotesResponse, RequestBars, RequestBookSnapshot, RequestCustomData,
+        RequestInstrument, RequestInstruments, RequestQuotes, RequestTrades, SubscribeBars,
+        SubscribeBookDeltas, SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCustomData,
+        SubscribeIndexPrices, SubscribeInstrument, SubscribeInstrumentClose,
+        SubscribeInstrumentStatus, SubscribeInstruments, SubscribeMarkPrices, SubscribeQuotes,
+        SubscribeTrades, TradesResponse, UnsubscribeBars, UnsubscribeBookDeltas,
+        UnsubscribeBookDepth10, UnsubscribeBookSnapshots, UnsubscribeCustomData,
+        UnsubscribeIndexPrices, UnsubscribeInstrument, UnsubscribeInstrumentClose,
+        UnsubscribeInstrumentStatus, UnsubscribeInstruments, UnsubscribeMarkPrices,
+        UnsubscribeQuotes, UnsubscribeTrades,
     },
 };
+use ant_core::UnixNanos;
 use ant_data::client::DataClient;
-use ant_model::identifiers::{ClientId, Venue};
+use ant_model::{
+    data::{Data, HasTsInit},
+    enums::BookType,
+    identifiers::{ClientId, Venue},
+    orderbook::OrderBook,
+};
+use ant_persistence::backend::catalog::{CatalogPathPrefix, ParquetDataCatalog};
+use ant_serialization::arrow::DecodeDataFromRecordBatch;
+use chrono::{DateTime, Utc};
+
+/// Provides the historical data used to answer [`BacktestDataClient`] requests.
+///
+/// Requests are answered from the data loaded into the engine, falling back to the
+/// [`ParquetDataCatalog`] (if set) for the parts of the requested range which the loaded data
+/// does not cover. Only data with a `ts_init` at or before the current simulated time is
+/// returned, so there is no look-ahead.
+///
+/// The `DataEngine` is still executing the request when the client handles it, so responses
+/// are queued here and sent to the `DataEngine` by the engine once the current event has been
+/// processed.
+#[derive(Clone, Debug)]
+pub struct BacktestDataSource {
+    data: Rc<RefCell<Vec<Data>>>,
+    catalog: Rc<RefCell<Option<ParquetDataCatalog>>>,
+    responses: Rc<RefCell<VecDeque<DataResponse>>>,
+}
+
+impl BacktestDataSource {
+    /// Creates a new [`BacktestDataSource`] instance over the engine's loaded `data`.
+    #[must_use]
+    pub fn new(data: Rc<RefCell<Vec<Data>>>) -> Self {
+        Self {
+            data,
+            catalog: Rc::new(RefCell::new(None)),
+            responses: Rc::new(RefCell::new(VecDeque::new())),
+        }
+    }
+
+    /// Sets the catalog used for requests which cannot be answered from the loaded data.
+    pub fn set_catalog(&self, catalog: ParquetDataCatalog) {
+        *self.catalog.borrow_mut() = Some(catalog);
+    }
+
+    /// Returns whether a catalog has been set.
+    #[must_use]
+    pub fn has_catalog(&self) -> bool {
+        self.catalog.borrow().is_some()
+    }
+
+    /// Drains all queued responses in the order they were produced.
+    #[must_use]
+    pub fn drain_responses(&self) -> Vec<DataResponse> {
+        self.responses.borrow_mut().drain(..).collect()
+    }
+
+    fn push_response(&self, response: DataResponse) {
+        self.responses.borrow_mut().push_back(response);
+    }
+
+    /// Returns the data selected by `select` with `start <= ts_init <= end`, limited to the
+    /// most recent `limit` items.
+    fn query<T>(
+        &self,
+        identifier: String,
+        start: Option<UnixNanos>,
+        end: UnixNanos,
+        limit: Option<NonZeroUsize>,
+        select: impl Fn(&Data) -> Option<T>,
+    ) -> anyhow::Result<Vec<T>>
+    where
+        T: HasTsInit + DecodeDataFromRecordBatch + CatalogPathPrefix + TryFrom<Data>,
+    {
+        let in_range =
+            |ts_init: UnixNanos| start.is_none_or(|start| ts_init >= start) && ts_init <= end;
+
+        let mut items: Vec<T> = self
+            .data
+            .borrow()
+            .iter()
+            .filter(|data| in_range(data.ts_init()))
+            .filter_map(select)
+            .collect();
+
+        // Loaded data is not necessarily sorted (chunks may be added without sorting)
+        items.sort_by_key(HasTsInit::ts_init);
+
+        if let Some(catalog) = self.catalog.borrow_mut().as_mut() {
+            // Fill the parts of the requested range not covered by the loaded data
+            let gaps = match (items.first(), items.last()) {
+                (Some(first), Some(last)) => {
+                    let mut gaps = Vec::with_capacity(2);
+                    let first_ts = first.ts_init().as_u64();
+                    if first_ts > 0 && start.is_none_or(|start| start.as_u64() < first_ts) {
+                        gaps.push((start, UnixNanos::from(first_ts - 1)));
+                    }
+                    if last.ts_init() < end {
+                        gaps.push((Some(UnixNanos::from(last.ts_init().as_u64() + 1)), end));
+                    }
+                    gaps
+                }
+                _ => vec![(start, end)],
+            };
+
+            for (gap_start, gap_end) in gaps {
+                items.extend(
+                    catalog
+                        .query_typed_data::<T>(
+                            Some(vec![identifier.clone()]),
+                            gap_start,
+                            Some(gap_end),
+                            None,
+                            None,
+                        )?
+                        .into_iter()
+                        .filter(|item| {
+                            let ts_init = item.ts_init();
+                            in_range(ts_init)
+                                && gap_start.is_none_or(|gap_start| ts_init >= gap_start)
+                                && ts_init <= gap_end
+                        }),
+                );
+            }
+            items.sort_by_key(HasTsInit::ts_init);
+        }
+
+        if let Some(limit) = limit
+            && items.len() > limit.get()
+        {
+            items.drain(..items.len() - limit.get());
+        }
+
+        Ok(items)
+    }
+}
+
+/// Returns a copy of the given `book` with at most `depth` levels on each side.
+fn truncate_book(book: &OrderBook, depth: usize) -> OrderBook {
+    let mut truncated = OrderBook::new(book.instrument_id, book.book_type);
+    for level in book.bids(Some(depth)).chain(book.asks(Some(depth))) {
+        for order in level.iter() {
+            truncated.add(*order, 0, book.sequence, book.ts_last);
+        }
+    }
+    truncated.update_count = book.update_count;
+    truncated
+}
+
+/// Returns the type name of the given `data`, as used in a `DataType`.
+fn data_type_name(data: &Data) -> &'static str {
+    match data {
+        Data::Delta(_) => "OrderBookDelta",
+        Data::Deltas(_) => "OrderBookDeltas",
+        Data::Depth10(_) => "OrderBookDepth10",
+        Data::Quote(_) => "QuoteTick",
+        Data::Trade(_) => "TradeTick",
+        Data::Bar(_) => "Bar",
+        Data::MarkPriceUpdate(_) => "MarkPriceUpdate",
+        Data::IndexPriceUpdate(_) => "IndexPriceUpdate",
+        Data::FundingRateUpdate(_) => "FundingRateUpdate",
+        Data::InstrumentClose(_) => "InstrumentClose",
+    }
+}
 
 #[derive(Debug)]
 /// Data client implementation for backtesting market data operations.
@@ -49,16 +210,41 @@ pub struct BacktestDataClient {
     pub client_id: ClientId,
     pub venue: Venue,
     cache: Rc<RefCell<Cache>>,
+    clock: Rc<RefCell<dyn Clock>>,
+    source: BacktestDataSource,
 }
 
 impl BacktestDataClient {
-    pub const fn new(client_id: ClientId, venue: Venue, cache: Rc<RefCell<Cache>>) -> Self {
+    pub fn new(
+        client_id: ClientId,
+        venue: Venue,
+        cache: Rc<RefCell<Cache>>,
+        clock: Rc<RefCell<dyn Clock>>,
+        source: BacktestDataSource,
+    ) -> Self {
         Self {
             client_id,
             venue,
             cache,
+            clock,
+            source,
         }
     }
+
+    fn ts_now(&self) -> UnixNanos {
+        self.clock.borrow().timestamp_ns()
+    }
+
+    /// Returns the request bounds as UNIX nanoseconds, with `end` capped at the current time.
+    fn bounds(
+        &self,
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    ) -> (Option<UnixNanos>, UnixNanos) {
+        let ts_now = self.ts_now();
+        let end = end.map_or(ts_now, |end| UnixNanos::from(end).min(ts_now));
+        (start.map(UnixNanos::from), end)
+    }
 }
 
 #[async_trait::async_trait]
@@ -227,31 +413,228 @@ impl DataClient for BacktestDataClient {
     // -- DATA REQUEST HANDLERS ---------------------------------------------------------------------------
 
     fn request_data(&self, request: &RequestCustomData) -> anyhow::Result<()> {
-        todo!()
+        let (start, end) = self.bounds(request.start, request.end);
+        let type_name = request.data_type.type_name();
+        let instrument_id = request
+            .data_type
+            .metadata()
+            .and_then(|_| request.data_type.instrument_id());
+
+        let mut data: Vec<Data> = self
+            .source
+            .data
+            .borrow()
+            .iter()
+            .filter(|data| {
+                let ts_init = data.ts_init();
+                data_type_name(data) == type_name
+                    && instrument_id.is_none_or(|id| data.instrument_id() == id)
+                    && start.is_none_or(|start| ts_init >= start)
+                    && ts_init <= end
+            })
+            .cloned()
+            .collect();
+        if let Some(limit) = request.limit
+            && data.len() > limit.get()
+        {
+            data.drain(..data.len() - limit.get());
+        }
+
+        self.source
+            .push_response(DataResponse::Data(CustomDataResponse::new(
+                request.request_id,
+                self.client_id,
+                Some(self.venue),
+                request.data_type.clone(),
+                data,
+                start,
+                Some(end),
+                self.ts_now(),
+                request.params.clone(),
+            )));
+        Ok(())
     }
 
     fn request_instruments(&self, request: &RequestInstruments) -> anyhow::Result<()> {
-        todo!()
+        let (start, end) = self.bounds(request.start, request.end);
+        let venue = request.venue.unwrap_or(self.venue);
+        let instruments = self
+            .cache
+            .borrow()
+            .instruments(&venue, None)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        self.source
+            .push_response(DataResponse::Instruments(InstrumentsResponse::new(
+                request.request_id,
+                self.client_id,
+                venue,
+                instruments,
+                start,
+                Some(end),
+                self.ts_now(),
+                request.params.clone(),
+            )));
+        Ok(())
     }
 
     fn request_instrument(&self, request: &RequestInstrument) -> anyhow::Result<()> {
-        todo!()
+        let (start, end) = self.bounds(request.start, request.end);
+        let instrument = self
+            .cache
+            .borrow()
+            .instrument(&request.instrument_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Instrument {} not found", request.instrument_id))?;
+
+        self.source
+            .push_response(DataResponse::Instrument(Box::new(InstrumentResponse::new(
+                request.request_id,
+                self.client_id,
+                request.instrument_id,
+                instrument,
+                start,
+                Some(end),
+                self.ts_now(),
+                request.params.clone(),
+            ))));
+        Ok(())
     }
 
     fn request_book_snapshot(&self, request: &RequestBookSnapshot) -> anyhow::Result<()> {
-        todo!()
+        let ts_now = self.ts_now();
+        let instrument_id = request.instrument_id;
+
+        // Use the maintained book if there is one, otherwise rebuild it from the loaded data
+        let cached_book = self.cache.borrow().order_book(&instrument_id).cloned();
+        let mut book = cached_book.unwrap_or_else(|| {
+            let data = self.source.data.borrow();
+            let mut book_data: Vec<&Data> = data
+                .iter()
+                .filter(|data| {
+                    data.ts_init() <= ts_now
+                        && data.is_order_book_data()
+                        && data.instrument_id() == instrument_id
+                })
+                .collect();
+            // Loaded data is not necessarily sorted, and updates must be applied in order
+            book_data.sort_by_key(|data| data.ts_init());
+
+            let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+            for data in book_data {
+                match data {
+                    Data::Delta(delta) => book.apply_delta(delta),
+                    Data::Deltas(deltas) => book.apply_deltas(deltas),
+                    Data::Depth10(depth) => book.apply_depth(depth),
+                    _ => {}
+                }
+            }
+            book
+        });
+
+        if let Some(depth) = request.depth {
+            book = truncate_book(&book, depth.get());
+        }
+
+        self.source
+            .push_response(DataResponse::Book(BookResponse::new(
+                request.request_id,
+                self.client_id,
+                instrument_id,
+                book,
+                None,
+                Some(ts_now),
+                ts_now,
+                request.params.clone(),
+            )));
+        Ok(())
     }
 
     fn request_quotes(&self, request: &RequestQuotes) -> anyhow::Result<()> {
-        todo!()
+        let (start, end) = self.bounds(request.start, request.end);
+        let instrument_id = request.instrument_id;
+        let quotes = self.source.query(
+            instrument_id.to_string(),
+            start,
+            end,
+            request.limit,
+            |data| match data {
+                Data::Quote(quote) if quote.instrument_id == instrument_id => Some(*quote),
+                _ => None,
+            },
+        )?;
+
+        self.source
+            .push_response(DataResponse::Quotes(QuotesResponse::new(
+                request.request_id,
+                self.client_id,
+                instrument_id,
+                quotes,
+                start,
+                Some(end),
+                self.ts_now(),
+                request.params.clone(),
+            )));
+        Ok(())
     }
 
     fn request_trades(&self, request: &RequestTrades) -> anyhow::Result<()> {
-        todo!()
+        let (start, end) = self.bounds(request.start, request.end);
+        let instrument_id = request.instrument_id;
+        let trades = self.source.query(
+            instrument_id.to_string(),
+            start,
+            end,
+            request.limit,
+            |data| match data {
+                Data::Trade(trade) if trade.instrument_id == instrument_id => Some(*trade),
+                _ => None,
+            },
+        )?;
+
+        self.source
+            .push_response(DataResponse::Trades(TradesResponse::new(
+                request.request_id,
+                self.client_id,
+                instrument_id,
+                trades,
+                start,
+                Some(end),
+                self.ts_now(),
+                request.params.clone(),
+            )));
+        Ok(())
     }
 
     fn request_bars(&self, request: &RequestBars) -> anyhow::Result<()> {
-        todo!()
+        let (start, end) = self.bounds(request.start, request.end);
+        let bar_type = request.bar_type;
+        let bars =
+            self.source.query(
+                bar_type.to_string(),
+                start,
+                end,
+                request.limit,
+                |data| match data {
+                    Data::Bar(bar) if bar.bar_type == bar_type => Some(*bar),
+                    _ => None,
+                },
+            )?;
+
+        self.source
+            .push_response(DataResponse::Bars(BarsResponse::new(
+                request.request_id,
+                self.client_id,
+                bar_type,
+                bars,
+                start,
+                Some(end),
+                self.ts_now(),
+                request.params.clone(),
+            )));
+        Ok(())
     }
 }
 
@@ -260,3 +643,248 @@ impl DataClient for BacktestDataClient {
 unsafe impl Send for BacktestDataClient {}
 #[allow(unsafe_code)]
 unsafe impl Sync for BacktestDataClient {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, num::NonZeroUsize, rc::Rc};
+
+    use ant_common::{
+        cache::Cache,
+        clock::TestClock,
+        messages::data::{
+            DataResponse, RequestBars, RequestBookSnapshot, RequestCustomData, RequestInstrument,
+            RequestQuotes,
+        },
+    };
+    use ant_core::{UUID4, UnixNanos};
+    use ant_data::client::DataClient;
+    use ant_model::{
+        data::{Bar, BarType, BookOrder, Data, DataType, OrderBookDelta, QuoteTick},
+        enums::{BookAction, OrderSide},
+        identifiers::{ClientId, InstrumentId, Venue},
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::{BacktestDataClient, BacktestDataSource};
+
+    const INSTRUMENT_ID: &str = "ETHUSDT-PERP.BINANCE";
+    const BAR_TYPE: &str = "ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL";
+
+    fn quote(ts: u64) -> Data {
+        Data::Quote(QuoteTick::new(
+            InstrumentId::from(INSTRUMENT_ID),
+            Price::from("1000.00"),
+            Price::from("1000.01"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    fn bar(ts: u64) -> Data {
+        Data::Bar(Bar::new(
+            BarType::from(BAR_TYPE),
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Price::from("999.00"),
+            Price::from("1000.50"),
+            Quantity::from("10.000"),
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    fn bid_delta(action: BookAction, price: &str, ts: u64) -> Data {
+        Data::Delta(OrderBookDelta::new(
+            InstrumentId::from(INSTRUMENT_ID),
+            action,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from(price),
+                Quantity::from("1.000"),
+                0,
+            ),
+            0,
+            ts,
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    #[fixture]
+    fn clock() -> Rc<RefCell<TestClock>> {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        clock.borrow_mut().set_time(UnixNanos::from(3));
+        clock
+    }
+
+    #[fixture]
+    fn client(clock: Rc<RefCell<TestClock>>) -> BacktestDataClient {
+        let data = vec![
+            quote(1),
+            bar(1),
+            quote(2),
+            bar(2),
+            quote(3),
+            bar(3),
+            quote(4),
+            bar(4),
+        ];
+        let source = BacktestDataSource::new(Rc::new(RefCell::new(data)));
+        BacktestDataClient::new(
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            Rc::new(RefCell::new(Cache::default())),
+            clock,
+            source,
+        )
+    }
+
+    #[rstest]
+    fn test_request_quotes_bounded_by_current_time(client: BacktestDataClient) {
+        let request_id = UUID4::new();
+        let request = RequestQuotes {
+            instrument_id: InstrumentId::from(INSTRUMENT_ID),
+            start: None,
+            end: None,
+            limit: None,
+            client_id: None,
+            request_id,
+            ts_init: UnixNanos::from(3),
+            params: None,
+        };
+
+        client.request_quotes(&request).unwrap();
+        let responses = client.source.drain_responses();
+
+        assert_eq!(responses.len(), 1);
+        let DataResponse::Quotes(response) = &responses[0] else {
+            panic!("Expected quotes response");
+        };
+        assert_eq!(response.correlation_id, request_id);
+        assert_eq!(response.data.len(), 3);
+        assert_eq!(response.end, Some(UnixNanos::from(3)));
+        assert!(client.source.drain_responses().is_empty());
+    }
+
+    #[rstest]
+    fn test_request_bars_with_limit_returns_most_recent(client: BacktestDataClient) {
+        let request = RequestBars {
+            bar_type: BarType::from(BAR_TYPE),
+            start: None,
+            end: None,
+            limit: NonZeroUsize::new(2),
+            client_id: None,
+            request_id: UUID4::new(),
+            ts_init: UnixNanos::from(3),
+            params: None,
+        };
+
+        client.request_bars(&request).unwrap();
+        let responses = client.source.drain_responses();
+
+        let DataResponse::Bars(response) = &responses[0] else {
+            panic!("Expected bars response");
+        };
+        let ts: Vec<UnixNanos> = response.data.iter().map(|bar| bar.ts_init).collect();
+        assert_eq!(ts, vec![UnixNanos::from(2), UnixNanos::from(3)]);
+    }
+
+    #[rstest]
+    fn test_request_book_snapshot_applies_data_in_order_up_to_depth(clock: Rc<RefCell<TestClock>>) {
+        // Data added without sorting, with a delete preceding the add it applies to
+        let data = vec![
+            bid_delta(BookAction::Delete, "98.00", 2),
+            bid_delta(BookAction::Add, "98.00", 1),
+            bid_delta(BookAction::Add, "100.00", 1),
+            bid_delta(BookAction::Add, "99.00", 2),
+            bid_delta(BookAction::Add, "101.00", 4),
+        ];
+        let source = BacktestDataSource::new(Rc::new(RefCell::new(data)));
+        let client = BacktestDataClient::new(
+            ClientId::from("BINANCE"),
+            Venue::from("BINANCE"),
+            Rc::new(RefCell::new(Cache::default())),
+            clock,
+            source,
+        );
+        let request = |depth| RequestBookSnapshot {
+            instrument_id: InstrumentId::from(INSTRUMENT_ID),
+            depth: NonZeroUsize::new(depth),
+            client_id: None,
+            request_id: UUID4::new(),
+            ts_init: UnixNanos::from(3),
+            params: None,
+        };
+
+        client.request_book_snapshot(&request(0)).unwrap();
+        client.request_book_snapshot(&request(1)).unwrap();
+        let responses = client.source.drain_responses();
+
+        let bid_prices: Vec<Vec<Price>> = responses
+            .iter()
+            .map(|response| {
+                let DataResponse::Book(response) = response else {
+                    panic!("Expected book response");
+                };
+                response
+                    .data
+                    .bids(None)
+                    .map(|level| level.price.value)
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            bid_prices,
+            vec![
+                vec![Price::from("100.00"), Price::from("99.00")],
+                vec![Price::from("100.00")],
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_request_data_filters_by_data_type(client: BacktestDataClient) {
+        let request = RequestCustomData {
+            client_id: ClientId::from("BINANCE"),
+            data_type: DataType::new("QuoteTick", None),
+            start: None,
+            end: None,
+            limit: None,
+            request_id: UUID4::new(),
+            ts_init: UnixNanos::from(3),
+            params: None,
+        };
+
+        client.request_data(&request).unwrap();
+        let responses = client.source.drain_responses();
+
+        let DataResponse::Data(response) = &responses[0] else {
+            panic!("Expected custom data response");
+        };
+        let data = response.data.downcast_ref::<Vec<Data>>().unwrap();
+        assert_eq!(data.len(), 3);
+        assert!(data.iter().all(|data| matches!(data, Data::Quote(_))));
+    }
+
+    #[rstest]
+    fn test_request_instrument_not_found(client: BacktestDataClient) {
+        let request = RequestInstrument {
+            instrument_id: InstrumentId::from(INSTRUMENT_ID),
+            start: None,
+            end: None,
+            client_id: None,
+            request_id: UUID4::new(),
+            ts_init: UnixNanos::from(3),
+            params: None,
+        };
+
+        assert!(client.request_instrument(&request).is_err());
+        assert!(client.source.drain_responses().is_empty());
+    }
+}
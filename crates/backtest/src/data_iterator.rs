@@ -46,10 +46,23 @@ impl PartialOrd for HeapEntry {
     }
 }
 
+/// A lazy source of data for a stream, yielding chunks of data in time order.
+struct DataGenerator(Box<dyn Iterator<Item = Vec<Data>>>);
+
+impl std::fmt::Debug for DataGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(stringify!(DataGenerator))
+    }
+}
+
 /// Multi-stream, time-ordered data iterator used by the backtest engine.
+///
+/// Streams are either held in memory, or loaded lazily one chunk at a time so that large data
+/// sets can be replayed with bounded memory.
 #[derive(Debug, Default)]
 pub struct BacktestDataIterator {
     streams: HashMap<i32, Vec<Data>>, // key: priority, value: Vec<Data>
+    generators: HashMap<i32, DataGenerator>, // priority -> source of the next chunk
     names: HashMap<i32, String>,      // priority -> name
     priorities: HashMap<String, i32>, // name -> priority
     indices: HashMap<i32, usize>,     // cursor per stream
@@ -64,6 +77,7 @@ impl BacktestDataIterator {
     pub fn new() -> Self {
         Self {
             streams: HashMap::new(),
+            generators: HashMap::new(),
             names: HashMap::new(),
             priorities: HashMap::new(),
             indices: HashMap::new(),
@@ -83,6 +97,46 @@ impl BacktestDataIterator {
         // Ensure sorted by ts_init
         data.sort_by_key(ant_model::data::HasTsInit::ts_init);
 
+        self.insert_stream(name, data, append_data);
+    }
+
+    /// Add (or replace) a named data stream which is loaded lazily from `chunks`.
+    ///
+    /// Each chunk is requested once the previous chunk has been replayed, so only one chunk per
+    /// stream is held in memory. Chunks are sorted by `ts_init`, and each chunk must not precede
+    /// the previous one. Lazy streams are consumed as they are replayed and cannot be rewound.
+    pub fn add_stream<I>(&mut self, name: &str, chunks: I, append_data: bool)
+    where
+        I: Iterator<Item = Vec<Data>> + 'static,
+    {
+        let mut generator = DataGenerator(Box::new(chunks));
+        let Some(data) = Self::next_chunk(&mut generator) else {
+            return;
+        };
+
+        self.insert_stream(name, data, append_data);
+        if let Some(priority) = self.priorities.get(name) {
+            self.generators.insert(*priority, generator);
+        }
+    }
+
+    /// Returns whether any stream is loaded lazily, in which case the last timestamp is unknown.
+    #[must_use]
+    pub fn has_lazy_streams(&self) -> bool {
+        !self.generators.is_empty()
+    }
+
+    /// Returns the `ts_init` of the next element across all streams, without advancing.
+    #[must_use]
+    pub fn peek_ts_init(&self) -> Option<UnixNanos> {
+        if let Some(p) = self.single_priority {
+            let idx = self.indices.get(&p)?;
+            return self.streams.get(&p)?.get(*idx).map(HasTsInit::ts_init);
+        }
+        self.heap.peek().map(|entry| entry.ts)
+    }
+
+    fn insert_stream(&mut self, name: &str, data: Vec<Data>, append_data: bool) {
         let priority = if let Some(p) = self.priorities.get(name) {
             // Replace existing stream – remove previous traces then re-insert below.
             *p
@@ -108,6 +162,7 @@ impl BacktestDataIterator {
     pub fn remove_data(&mut self, name: &str, complete_remove: bool) {
         if let Some(priority) = self.priorities.remove(name) {
             self.streams.remove(&priority);
+            self.generators.remove(&priority);
             self.indices.remove(&priority);
             self.names.remove(&priority);
 
@@ -124,6 +179,8 @@ impl BacktestDataIterator {
     }
 
     /// Move cursor of stream to `index` (0-based).
+    ///
+    /// For a lazy stream the index is within the chunk currently loaded.
     pub fn set_index(&mut self, name: &str, index: usize) {
         if let Some(priority) = self.priorities.get(name) {
             self.indices.insert(*priority, index);
@@ -136,13 +193,16 @@ impl BacktestDataIterator {
     pub fn next(&mut self) -> Option<Data> {
         // Fast path for single stream
         if let Some(p) = self.single_priority {
-            let data = self.streams.get_mut(&p)?;
-            let idx = self.indices.get_mut(&p)?;
-            if *idx >= data.len() {
+            let data = self.streams.get(&p)?;
+            let idx = *self.indices.get(&p)?;
+            if idx >= data.len() {
                 return None;
             }
-            let element = data[*idx].clone();
-            *idx += 1;
+            let element = data[idx].clone();
+            let is_chunk_end = idx + 1 >= data.len();
+            if !(is_chunk_end && self.load_next_chunk(p)) {
+                self.indices.insert(p, idx + 1);
+            }
             return Some(element);
         }
 
@@ -152,11 +212,18 @@ impl BacktestDataIterator {
         let element = stream_vec[entry.index].clone();
 
         // Advance cursor and push next entry
-        let next_index = entry.index + 1;
+        let mut next_index = entry.index + 1;
+        if next_index >= stream_vec.len() && self.load_next_chunk(entry.priority) {
+            next_index = 0;
+        }
         self.indices.insert(entry.priority, next_index);
-        if next_index < stream_vec.len() {
+        if let Some(next) = self
+            .streams
+            .get(&entry.priority)
+            .and_then(|vec| vec.get(next_index))
+        {
             self.heap.push(HeapEntry {
-                ts: stream_vec[next_index].ts_init(),
+                ts: next.ts_init(),
                 priority: entry.priority,
                 index: next_index,
             });
@@ -165,6 +232,30 @@ impl BacktestDataIterator {
         Some(element)
     }
 
+    /// Replaces the replayed chunk of a lazy stream with its next chunk, returning whether a
+    /// chunk was loaded.
+    fn load_next_chunk(&mut self, priority: i32) -> bool {
+        let Some(generator) = self.generators.get_mut(&priority) else {
+            return false;
+        };
+        let Some(data) = Self::next_chunk(generator) else {
+            // Exhausted, the replayed last chunk is kept so the stream reads as done
+            self.generators.remove(&priority);
+            return false;
+        };
+
+        self.streams.insert(priority, data);
+        self.indices.insert(priority, 0);
+        true
+    }
+
+    /// Returns the next non-empty chunk from the `generator`, sorted by `ts_init`.
+    fn next_chunk(generator: &mut DataGenerator) -> Option<Vec<Data>> {
+        let mut data = generator.0.by_ref().find(|chunk| !chunk.is_empty())?;
+        data.sort_by_key(HasTsInit::ts_init);
+        Some(data)
+    }
+
     #[must_use]
     pub fn is_done(&self) -> bool {
         if let Some(p) = self.single_priority {
@@ -260,4 +351,85 @@ mod tests {
             ]
         );
     }
+
+    #[rstest]
+    fn test_lazy_stream_loads_chunks() {
+        let mut it = BacktestDataIterator::new();
+        let chunks = vec![
+            vec![quote("A.B", 2), quote("A.B", 1)],
+            vec![],
+            vec![quote("A.B", 3)],
+        ];
+        it.add_stream("lazy", chunks.into_iter(), true);
+
+        assert!(it.has_lazy_streams());
+        assert_eq!(it.peek_ts_init(), Some(UnixNanos::from(1)));
+
+        let mut ts = Vec::new();
+        while let Some(d) = it.next() {
+            ts.push(d.ts_init());
+        }
+        assert_eq!(
+            ts,
+            vec![UnixNanos::from(1), UnixNanos::from(2), UnixNanos::from(3)]
+        );
+        assert!(it.is_done());
+        assert!(!it.has_lazy_streams());
+    }
+
+    #[rstest]
+    fn test_lazy_stream_merge_with_in_memory_stream() {
+        let mut it = BacktestDataIterator::new();
+        it.add_data("s1", vec![quote("A.B", 2), quote("A.B", 5)], true);
+        let chunks = vec![
+            vec![quote("C.D", 1), quote("C.D", 3)],
+            vec![quote("C.D", 4), quote("C.D", 6)],
+        ];
+        it.add_stream("s2", chunks.into_iter(), true);
+
+        let mut ts = Vec::new();
+        while let Some(d) = it.next() {
+            ts.push(d.ts_init());
+        }
+        assert_eq!(ts, (1..=6).map(UnixNanos::from).collect::<Vec<_>>());
+        assert!(it.is_done());
+    }
+
+    #[rstest]
+    fn test_lazy_stream_requests_chunks_on_demand() {
+        let requested = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = requested.clone();
+        let chunks = (1..=3).map(move |ts| {
+            counter.set(counter.get() + 1);
+            vec![quote("A.B", ts)]
+        });
+
+        let mut it = BacktestDataIterator::new();
+        it.add_stream("lazy", chunks, true);
+        assert_eq!(requested.get(), 1);
+
+        it.next();
+        assert_eq!(requested.get(), 2);
+    }
+
+    #[rstest]
+    fn test_empty_lazy_stream_is_ignored() {
+        let mut it = BacktestDataIterator::new();
+        it.add_stream("lazy", std::iter::empty(), true);
+
+        assert!(!it.has_lazy_streams());
+        assert!(it.peek_ts_init().is_none());
+        assert!(it.next().is_none());
+    }
+
+    #[rstest]
+    fn test_remove_lazy_stream() {
+        let mut it = BacktestDataIterator::new();
+        it.add_stream("lazy", vec![vec![quote("A.B", 1)]].into_iter(), true);
+
+        it.remove_data("lazy", true);
+
+        assert!(!it.has_lazy_streams());
+        assert!(it.next().is_none());
+    }
 }
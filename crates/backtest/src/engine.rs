@@ -22,31 +22,60 @@
 use std::{
     any::Any,
     cell::RefCell,
-    collections::{HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::Debug,
     rc::Rc,
+    sync::Arc,
 };
 
-use ant_common::timer::TimeEventHandlerV2;
-use ant_core::{UUID4, UnixNanos};
+use ant_analysis::analyzer::PortfolioAnalyzer;
+use ant_common::{
+    actor::DataActor,
+    clock::{Clock, TestClock},
+    component::Component,
+    logging::{
+        logging_clock_set_realtime_mode, logging_clock_set_static_mode,
+        logging_clock_set_static_time,
+    },
+    msgbus::{self, switchboard::MessagingSwitchboard},
+    runner::{SyncDataCommandSender, set_data_cmd_sender, try_get_data_cmd_sender},
+    timer::TimeEventHandlerV2,
+};
+use ant_core::{UUID4, UnixNanos, time::get_atomic_clock_realtime};
 use ant_data::client::DataClientAdapter;
-use ant_execution::models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel};
+use ant_execution::{
+    algorithm::ExecAlgorithm,
+    matching_engine::config::LiquidityExhaustedBehavior,
+    models::{fee::FeeModelAny, fill::FillModelAny, latency::LatencyModel},
+};
 use ant_model::{
-    data::Data,
+    accounts::Account,
+    data::{Data, HasTsInit},
     enums::{AccountType, BookType, OmsType},
     identifiers::{AccountId, ClientId, InstrumentId, Venue},
-    instruments::{Instrument, InstrumentAny},
+    instruments::{Instrument, InstrumentEnum},
+    position::Position,
     types::{Currency, Money},
 };
-use ant_system::{config::NautilusKernelConfig, kernel::NautilusKernel};
+use ant_persistence::backend::catalog::ParquetDataCatalog;
+use ant_system::{config::AntKernelConfig, kernel::AntKernel};
+use ant_trading::strategy::Strategy;
 use rust_decimal::Decimal;
 
 use crate::{
-    accumulator::TimeEventAccumulator, config::BacktestEngineConfig,
-    data_client::BacktestDataClient, exchange::SimulatedExchange,
-    execution_client::BacktestExecutionClient, modules::SimulationModule,
+    accumulator::TimeEventAccumulator,
+    config::BacktestEngineConfig,
+    data_client::{BacktestDataClient, BacktestDataSource},
+    data_iterator::BacktestDataIterator,
+    exchange::SimulatedExchange,
+    execution_client::BacktestExecutionClient,
+    modules::SimulationModule,
+    result::BacktestResult,
 };
 
+/// The name of the data stream holding all data added through [`BacktestEngine::add_data`].
+const BACKTEST_DATA_STREAM: &str = "backtest_data";
+
 /// Core backtesting engine for running event-driven strategy backtests on historical data.
 ///
 /// The `BacktestEngine` provides a high-fidelity simulation environment that processes
@@ -62,15 +91,17 @@ use crate::{
 pub struct BacktestEngine {
     instance_id: UUID4,
     config: BacktestEngineConfig,
-    kernel: NautilusKernel,
+    kernel: AntKernel,
     accumulator: TimeEventAccumulator,
-    run_config_id: Option<UUID4>,
+    run_config_id: Option<String>,
     run_id: Option<UUID4>,
     venues: HashMap<Venue, Rc<RefCell<SimulatedExchange>>>,
     has_data: HashSet<InstrumentId>,
     has_book_data: HashSet<InstrumentId>,
-    data: VecDeque<Data>,
-    index: usize,
+    data: Rc<RefCell<Vec<Data>>>,
+    data_source: BacktestDataSource,
+    data_iterator: BacktestDataIterator,
+    data_pending: bool,
     iteration: usize,
     run_started: Option<UnixNanos>,
     run_finished: Option<UnixNanos>,
@@ -93,9 +124,17 @@ impl BacktestEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if the core `NautilusKernel` fails to initialize.
+    /// Returns an error if the core `AntKernel` fails to initialize.
     pub fn new(config: BacktestEngineConfig) -> anyhow::Result<Self> {
-        let kernel = NautilusKernel::new("BacktestEngine".to_string(), config.clone())?;
+        let kernel = AntKernel::new("BacktestEngine".to_string(), config.clone())?;
+
+        // Data commands (including requests) are executed synchronously in a backtest
+        if try_get_data_cmd_sender().is_none() {
+            set_data_cmd_sender(Arc::new(SyncDataCommandSender));
+        }
+
+        let data = Rc::new(RefCell::new(Vec::new()));
+        let data_source = BacktestDataSource::new(data.clone());
 
         Ok(Self {
             instance_id: kernel.instance_id,
@@ -107,8 +146,10 @@ impl BacktestEngine {
             venues: HashMap::new(),
             has_data: HashSet::new(),
             has_book_data: HashSet::new(),
-            data: VecDeque::new(),
-            index: 0,
+            data,
+            data_source,
+            data_iterator: BacktestDataIterator::new(),
+            data_pending: false,
             iteration: 0,
             run_started: None,
             run_finished: None,
@@ -132,7 +173,7 @@ impl BacktestEngine {
         default_leverage: Option<Decimal>,
         leverages: HashMap<InstrumentId, Decimal>,
         modules: Vec<Box<dyn SimulationModule>>,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
         latency_model: Option<LatencyModel>,
         routing: Option<bool>,
@@ -148,6 +189,12 @@ impl BacktestEngine {
         trade_execution: Option<bool>,
         allow_cash_borrowing: Option<bool>,
         frozen_account: Option<bool>,
+        default_price_limit_pct: Option<f64>,
+        price_limit_pcts: HashMap<InstrumentId, f64>,
+        limit_locked_no_fill: Option<bool>,
+        use_t_plus_one: Option<bool>,
+        trading_day_offset_secs: Option<i64>,
+        liquidity_exhausted_behavior: Option<LiquidityExhaustedBehavior>,
     ) -> anyhow::Result<()> {
         let default_leverage: Decimal = default_leverage.unwrap_or_else(|| {
             if account_type == AccountType::Margin {
@@ -182,6 +229,12 @@ impl BacktestEngine {
             use_message_queue,
             allow_cash_borrowing,
             frozen_account,
+            default_price_limit_pct,
+            price_limit_pcts,
+            limit_locked_no_fill,
+            use_t_plus_one,
+            trading_day_offset_secs,
+            liquidity_exhausted_behavior,
         )?;
         let exchange = Rc::new(RefCell::new(exchange));
         self.venues.insert(venue, exchange.clone());
@@ -199,14 +252,17 @@ impl BacktestEngine {
         let exec_client = Rc::new(exec_client);
 
         exchange.borrow_mut().register_client(exec_client.clone());
-        self.kernel.exec_engine.register_client(exec_client)?;
+        self.kernel
+            .exec_engine
+            .borrow_mut()
+            .register_client(exec_client)?;
 
         log::info!("Adding exchange {venue} to engine");
 
         Ok(())
     }
 
-    pub fn change_fill_model(&mut self, venue: Venue, fill_model: FillModel) {
+    pub fn change_fill_model(&mut self, venue: Venue, fill_model: FillModelAny) {
         if let Some(exchange) = self.venues.get_mut(&venue) {
             exchange.borrow_mut().set_fill_model(fill_model);
         } else {
@@ -227,11 +283,11 @@ impl BacktestEngine {
     /// # Panics
     ///
     /// Panics if adding the instrument to the simulated exchange fails.
-    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+    pub fn add_instrument(&mut self, instrument: InstrumentEnum) -> anyhow::Result<()> {
         let instrument_id = instrument.id();
         if let Some(exchange) = self.venues.get_mut(&instrument.id().venue) {
             // check if instrument is of variant CurrencyPair
-            if matches!(instrument, InstrumentAny::CurrencyPair(_))
+            if matches!(instrument, InstrumentEnum::CurrencyPair(_))
                 && exchange.borrow().account_type != AccountType::Margin
                 && exchange.borrow().base_currency.is_some()
             {
@@ -266,6 +322,12 @@ impl BacktestEngine {
         Ok(())
     }
 
+    /// Adds the given `data` to the engine, to be replayed on the next call to
+    /// [`BacktestEngine::run`].
+    ///
+    /// Data is always replayed in `ts_init` order; `sort` additionally sorts the
+    /// engine's retained data so that range queries (e.g. the first and last
+    /// timestamps) are correct when chunks are added out of order.
     pub fn add_data(
         &mut self,
         data: Vec<Data>,
@@ -278,15 +340,9 @@ impl BacktestEngine {
             return;
         }
 
-        // If requested, sort by ts_init so internal stream is monotonic.
-        let mut to_add = data;
-        if sort {
-            to_add.sort_by_key(ant_model::data::HasTsInit::ts_init);
-        }
-
         // Instrument & book tracking using Data helpers
         if validate {
-            for item in &to_add {
+            for item in &data {
                 let instr_id = item.instrument_id();
                 self.has_data.insert(instr_id);
 
@@ -295,59 +351,205 @@ impl BacktestEngine {
                 }
 
                 // Ensure appropriate market data client exists
-                self.add_market_data_client_if_not_exists(instr_id.venue);
+                match client_id {
+                    Some(client_id) => self.add_data_client_if_not_exists(client_id),
+                    None => self.add_market_data_client_if_not_exists(instr_id.venue),
+                }
             }
         }
 
-        // Extend master data vector and ensure internal iterator (index) remains valid.
-        for item in to_add {
-            self.data.push_back(item);
-        }
+        let count = data.len();
+        let mut all_data = self.data.borrow_mut();
+        all_data.extend(data);
 
         if sort {
-            // VecDeque cannot be sorted directly; convert to Vec for sorting, then back.
-            let mut vec: Vec<Data> = self.data.drain(..).collect();
-            vec.sort_by_key(ant_model::data::HasTsInit::ts_init);
-            self.data = vec.into();
+            all_data.sort_by_key(HasTsInit::ts_init);
         }
+        drop(all_data);
+
+        // The iterator stream is built once from all added data when the run starts
+        self.data_pending = true;
 
         log::info!(
-            "Added {} data element{} to BacktestEngine",
-            self.data.len(),
-            if self.data.len() == 1 { "" } else { "s" }
+            "Added {count} data element{} to BacktestEngine",
+            if count == 1 { "" } else { "s" }
         );
     }
 
-    pub fn add_actor(&mut self) {
-        todo!("implement add_actor")
+    /// Adds a named stream of data to the engine which is loaded lazily from `chunks`, to be
+    /// replayed on the next call to [`BacktestEngine::run`] merged with all other data.
+    ///
+    /// Only one chunk per stream is held in memory, allowing large data sets to be replayed with
+    /// bounded memory. Each chunk must not precede the previous one in `ts_init`. The stream is
+    /// consumed as it is replayed, so must be added again after a [`BacktestEngine::reset`].
+    ///
+    /// Data is not validated up front, so the market data clients are those registered for the
+    /// instruments added to the engine, and the data is not available for historical requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is the name of the stream of data added with [`BacktestEngine::add_data`].
+    pub fn add_data_stream<I>(&mut self, name: &str, chunks: I)
+    where
+        I: Iterator<Item = Vec<Data>> + 'static,
+    {
+        assert_ne!(
+            name, BACKTEST_DATA_STREAM,
+            "Stream name '{BACKTEST_DATA_STREAM}' is reserved"
+        );
+
+        self.data_iterator.add_stream(name, chunks, true);
+
+        log::info!("Added data stream '{name}' to BacktestEngine");
     }
 
-    pub fn add_actors(&mut self) {
-        todo!("implement add_actors")
+    /// Adds the given actor to the engine's trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the actor cannot be registered with the trader.
+    pub fn add_actor<T>(&mut self, actor: T) -> anyhow::Result<()>
+    where
+        T: DataActor + Component + Debug + 'static,
+    {
+        self.kernel.trader.add_actor(actor)
     }
 
-    pub fn add_strategy(&mut self) {
-        todo!("implement add_strategy")
+    /// Adds the given actors to the engine's trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any actor cannot be registered with the trader.
+    pub fn add_actors<T>(&mut self, actors: Vec<T>) -> anyhow::Result<()>
+    where
+        T: DataActor + Component + Debug + 'static,
+    {
+        for actor in actors {
+            self.add_actor(actor)?;
+        }
+        Ok(())
     }
 
-    pub fn add_strategies(&mut self) {
-        todo!("implement add_strategies")
+    /// Adds the given strategy to the engine's trader.
+    ///
+    /// Any OMS type override and external order claims configured for the strategy
+    /// are registered with the execution engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the external order claims conflict with another strategy,
+    /// or if the strategy cannot be registered with the trader.
+    pub fn add_strategy<T>(&mut self, strategy: T) -> anyhow::Result<()>
+    where
+        T: Strategy + Debug + 'static,
+    {
+        let strategy_id = strategy.strategy_id();
+        let core = strategy.core();
+
+        {
+            let mut exec_engine = self.kernel.exec_engine.borrow_mut();
+            if let Some(oms_type) = core.oms_type() {
+                exec_engine.register_oms_type(strategy_id, oms_type);
+            }
+            exec_engine
+                .register_external_order_claims(strategy_id, &core.external_order_claims())?;
+        }
+
+        self.kernel.trader.add_strategy(strategy)
     }
 
-    pub fn add_exec_algorithm(&mut self) {
-        todo!("implement add_exec_algorithm")
+    /// Adds the given strategies to the engine's trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any strategy cannot be added.
+    pub fn add_strategies<T>(&mut self, strategies: Vec<T>) -> anyhow::Result<()>
+    where
+        T: Strategy + Debug + 'static,
+    {
+        for strategy in strategies {
+            self.add_strategy(strategy)?;
+        }
+        Ok(())
     }
 
-    pub fn add_exec_algorithms(&mut self) {
-        todo!("implement add_exec_algorithms")
+    /// Adds the given execution algorithm to the engine's trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the execution algorithm cannot be registered with the trader.
+    pub fn add_exec_algorithm<T>(&mut self, exec_algorithm: T) -> anyhow::Result<()>
+    where
+        T: ExecAlgorithm + Debug + 'static,
+    {
+        self.kernel.trader.add_exec_algorithm(exec_algorithm)
     }
 
+    /// Adds the given execution algorithms to the engine's trader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any execution algorithm cannot be added.
+    pub fn add_exec_algorithms<T>(&mut self, exec_algorithms: Vec<T>) -> anyhow::Result<()>
+    where
+        T: ExecAlgorithm + Debug + 'static,
+    {
+        for exec_algorithm in exec_algorithms {
+            self.add_exec_algorithm(exec_algorithm)?;
+        }
+        Ok(())
+    }
+
+    /// Resets the engine so that the loaded data can be replayed again.
+    ///
+    /// All stateful fields are reset to their initial values, the kernel and every
+    /// simulated exchange are reset, and the data iterator is rewound. Loaded data
+    /// and registered components are retained.
     pub fn reset(&mut self) {
-        todo!("implement reset")
+        log::debug!("Resetting");
+
+        if self.kernel.trader.is_running() {
+            // End current backtest run
+            self.end();
+        }
+
+        self.kernel.reset();
+        self.kernel.clock.borrow_mut().reset();
+
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().reset();
+        }
+
+        self.accumulator = TimeEventAccumulator::new();
+        self.data_iterator.set_index(BACKTEST_DATA_STREAM, 0);
+
+        self.run_config_id = None;
+        self.run_id = None;
+        self.run_started = None;
+        self.run_finished = None;
+        self.backtest_start = None;
+        self.backtest_end = None;
+        self.iteration = 0;
+
+        log::info!("Reset");
     }
 
+    /// Clears all data from the engine.
+    ///
+    /// Use this between chunks of a streaming run to release the memory held by the
+    /// previous chunk before adding the next one.
     pub fn clear_data(&mut self) {
-        todo!("implement clear_data")
+        self.data.borrow_mut().clear();
+        self.data_iterator = BacktestDataIterator::new();
+        self.data_pending = false;
+        self.has_data.clear();
+        self.has_book_data.clear();
+    }
+
+    /// Sets the catalog used to answer historical data requests which cannot be answered from
+    /// the data loaded into the engine.
+    pub fn set_catalog(&mut self, catalog: ParquetDataCatalog) {
+        self.data_source.set_catalog(catalog);
     }
 
     pub fn clear_strategies(&mut self) {
@@ -358,31 +560,285 @@ impl BacktestEngine {
         todo!("implement clear_exec_algorithms")
     }
 
+    /// Disposes of the engine, releasing all data and kernel resources.
+    ///
+    /// The engine cannot be used again after this call.
     pub fn dispose(&mut self) {
-        todo!("implement dispose")
+        self.clear_data();
+        self.kernel.dispose();
     }
 
-    pub fn run(&mut self) {
-        todo!("implement run")
+    /// Runs a backtest over the loaded data.
+    ///
+    /// If `start` is `None` the run starts from the first data point, and if `end` is
+    /// `None` it runs to the last data point.
+    ///
+    /// When `streaming` is `true` the run is not ended once the loaded data is
+    /// exhausted. The next chunk can then be loaded with [`BacktestEngine::clear_data`]
+    /// and [`BacktestEngine::add_data`], and processed by calling `run` again. Call
+    /// [`BacktestEngine::end`] once the final chunk has been processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start` is greater than `end`.
+    pub fn run(
+        &mut self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        run_config_id: Option<String>,
+        streaming: bool,
+    ) -> anyhow::Result<()> {
+        self.run_impl(start, end, run_config_id, streaming)?;
+
+        if !streaming {
+            self.end();
+        }
+
+        Ok(())
+    }
+
+    fn run_impl(
+        &mut self,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        run_config_id: Option<String>,
+        streaming: bool,
+    ) -> anyhow::Result<()> {
+        if self.data_pending {
+            self.data_iterator
+                .add_data(BACKTEST_DATA_STREAM, self.data.borrow().clone(), true);
+            self.data_pending = false;
+        }
+
+        let start = start.unwrap_or_else(|| {
+            self.data_iterator.peek_ts_init().unwrap_or_else(|| {
+                self.data
+                    .borrow()
+                    .first()
+                    .map_or_else(UnixNanos::default, HasTsInit::ts_init)
+            })
+        });
+        let end = end.unwrap_or_else(|| {
+            // The last timestamp of lazily loaded data is not known until it is replayed
+            if self.data_iterator.has_lazy_streams() {
+                return UnixNanos::max();
+            }
+            self.data
+                .borrow()
+                .last()
+                .map_or_else(UnixNanos::max, HasTsInit::ts_init)
+        });
+
+        anyhow::ensure!(start <= end, "`start` {start} was greater than `end` {end}");
+
+        if self.run_started.is_none() {
+            // Initialize run
+            self.run_config_id = run_config_id;
+            self.run_id = Some(UUID4::new());
+            self.run_started = Some(get_atomic_clock_realtime().get_time_ns());
+            self.backtest_start = Some(start);
+
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().initialize_account();
+            }
+
+            // Re-initialize the logging clock for the backtest
+            logging_clock_set_static_mode();
+            logging_clock_set_static_time(start.as_u64());
+
+            // Setup new clocks
+            self.set_clocks_time(start);
+
+            self.kernel.start();
+            self.process_data_responses();
+            self.log_pre_run();
+        }
+
+        self.log_run(start, end);
+        self.backtest_end = Some(end);
+
+        let mut last_ns = UnixNanos::default();
+        let mut raw_handlers: Vec<TimeEventHandlerV2> = Vec::new();
+
+        while let Some(data) = self.data_iterator.next() {
+            let ts_init = data.ts_init();
+            if ts_init < start {
+                continue;
+            }
+            if ts_init > end {
+                break;
+            }
+
+            if ts_init > last_ns {
+                // Advance clocks to the next data time, events prior to now are processed
+                last_ns = ts_init;
+                raw_handlers = self.advance_time(ts_init);
+            }
+
+            // Process data through the venue, then the data engine
+            self.route_data_to_exchange(&data);
+            self.kernel.data_engine.borrow_mut().process_data(data);
+            self.process_data_responses();
+
+            // Process all exchange messages
+            for exchange in self.venues.values() {
+                exchange.borrow_mut().process(ts_init);
+            }
+
+            if !raw_handlers.is_empty() {
+                // Process events which fire at exactly the data timestamp
+                self.process_raw_time_event_handlers(
+                    std::mem::take(&mut raw_handlers),
+                    last_ns,
+                    true,
+                    false,
+                );
+            }
+
+            self.iteration += 1;
+        }
+
+        // Process remaining messages
+        let ts_now = self.kernel.clock.borrow().timestamp_ns();
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().process(ts_now);
+        }
+
+        if !streaming && end != UnixNanos::max() {
+            // Flush timers up to and including the end of the run
+            self.flush_accumulator_events(end);
+        }
+
+        Ok(())
     }
 
+    /// Manually ends the backtest run.
+    ///
+    /// Stops the kernel, processes any remaining exchange messages and logs the
+    /// post-run diagnostics. Called automatically at the end of a non-streaming run.
     pub fn end(&mut self) {
-        todo!("implement end")
+        // Stop the kernel (trader and engines)
+        self.kernel.stop();
+
+        // Process remaining messages
+        let ts_now = self.kernel.clock.borrow().timestamp_ns();
+        for exchange in self.venues.values() {
+            exchange.borrow_mut().process(ts_now);
+        }
+
+        self.run_finished = Some(get_atomic_clock_realtime().get_time_ns());
+        self.backtest_end = Some(ts_now);
+
+        self.log_post_run();
+
+        logging_clock_set_realtime_mode();
     }
 
-    pub fn get_result(&self) {
-        // TODO: implement full BacktestResult aggregation once portfolio analysis
-        // components are available in Rust. For now we simply log and return.
-        log::info!("BacktestEngine::get_result called – not yet implemented");
+    /// Returns the result of the last backtest run.
+    ///
+    /// PnL and equity curve statistics are calculated per venue account currency, while returns
+    /// and general statistics are calculated across the positions of all venues.
+    #[must_use]
+    pub fn get_result(&self) -> BacktestResult {
+        let cache = self.kernel.cache.borrow();
+        let mut stats_pnls = BTreeMap::new();
+
+        for venue in self.venues.keys() {
+            let Some(account) = cache.account_for_venue(venue) else {
+                log::warn!("No account for venue {venue}, skipping PnL statistics");
+                continue;
+            };
+            let positions: Vec<Position> = cache
+                .positions(Some(venue), None, None, None)
+                .into_iter()
+                .cloned()
+                .collect();
+
+            let mut analyzer = PortfolioAnalyzer::default();
+            analyzer.calculate_statistics(account, &positions);
+
+            for currency in account.currencies() {
+                match analyzer.get_performance_stats_pnls(Some(&currency), None) {
+                    Ok(mut stats) => {
+                        stats.extend(analyzer.get_performance_stats_equity(Some(&currency)));
+                        stats_pnls.insert(currency.code.to_string(), stats.into_iter().collect());
+                    }
+                    Err(e) => log::error!("Error calculating PnL statistics for {currency}: {e}"),
+                }
+            }
+        }
+
+        let positions: Vec<Position> = cache
+            .positions(None, None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+        let mut analyzer = PortfolioAnalyzer::default();
+        analyzer.add_positions(&positions);
+        let stats_returns = analyzer.get_performance_stats_returns();
+        let stats_general = analyzer.get_performance_stats_general();
+
+        let elapsed_time = match (self.run_started, self.run_finished) {
+            (Some(started), Some(finished)) => {
+                finished.as_u64().saturating_sub(started.as_u64()) as f64 / 1_000_000_000.0
+            }
+            _ => 0.0,
+        };
+
+        BacktestResult {
+            trader_id: self.kernel.trader_id().to_string(),
+            machine_id: self.kernel.machine_id.clone(),
+            run_config_id: self.run_config_id.clone(),
+            instance_id: self.instance_id,
+            run_id: self.run_id,
+            run_started: self.run_started,
+            run_finished: self.run_finished,
+            backtest_start: self.backtest_start,
+            backtest_end: self.backtest_end,
+            elapsed_time,
+            iterations: self.iteration,
+            total_orders: cache.orders_total_count(None, None, None, None),
+            total_positions: positions.len(),
+            stats_pnls,
+            stats_returns: stats_returns.into_iter().collect(),
+            stats_general: stats_general.into_iter().collect(),
+        }
+    }
+
+    /// Returns the number of data points processed across all runs since the last reset.
+    #[must_use]
+    pub const fn iteration(&self) -> usize {
+        self.iteration
     }
 
-    pub fn next(&mut self) {
-        self.data.pop_front();
+    /// Returns the run ID of the current (or last) run, if any.
+    #[must_use]
+    pub const fn run_id(&self) -> Option<UUID4> {
+        self.run_id
     }
 
-    pub fn advance_time(&mut self, _ts_now: UnixNanos) -> Vec<TimeEventHandlerV2> {
-        // TODO: integrate TestClock advancement when kernel clocks are exposed.
-        self.accumulator.drain()
+    /// Advances all clocks to `ts_now`, processing any time events prior to it.
+    ///
+    /// Returns all the accumulated handlers so that events at exactly `ts_now` can be
+    /// processed after the data for that timestamp.
+    pub fn advance_time(&mut self, ts_now: UnixNanos) -> Vec<TimeEventHandlerV2> {
+        for clock in self.kernel.trader.get_component_clocks() {
+            Self::advance_clock_on_accumulator(&mut self.accumulator, &clock, ts_now, false);
+        }
+
+        // Finally advance the kernel clock
+        let kernel_clock = self.kernel.clock.clone();
+        Self::advance_clock_on_accumulator(&mut self.accumulator, &kernel_clock, ts_now, true);
+
+        let raw_handlers = self.accumulator.drain();
+
+        // Handle all events prior to `ts_now`
+        self.process_raw_time_event_handlers(raw_handlers.clone(), ts_now, false, false);
+
+        // Set all clocks to now
+        self.set_clocks_time(ts_now);
+
+        raw_handlers
     }
 
     pub fn process_raw_time_event_handlers(
@@ -402,7 +858,10 @@ impl BacktestEngine {
             }
 
             if last_ts_init != Some(ts_event_init) {
-                // First handler for this timestamp – process exchange queues beforehand.
+                // First handler for this timestamp – set clocks to the event time
+                // and process exchange queues beforehand.
+                self.set_clocks_time(ts_event_init);
+
                 for exchange in self.venues.values() {
                     exchange.borrow_mut().process(ts_event_init);
                 }
@@ -410,19 +869,101 @@ impl BacktestEngine {
             }
 
             handler.run();
+            self.process_data_responses();
         }
     }
 
     pub fn log_pre_run(&self) {
-        todo!("implement log_pre_run_diagnostics")
+        log::info!("=================================================================");
+        log::info!(" BACKTEST PRE-RUN");
+        log::info!("=================================================================");
+
+        for exchange in self.venues.values() {
+            self.log_account_balances(&exchange.borrow(), false);
+        }
     }
 
-    pub fn log_run(&self) {
-        todo!("implement log_run")
+    pub fn log_run(&self, start: UnixNanos, end: UnixNanos) {
+        log::info!("=================================================================");
+        log::info!(" BACKTEST RUN");
+        log::info!("=================================================================");
+        log::info!(
+            "Run config ID:  {}",
+            Self::fmt_opt(self.run_config_id.as_ref())
+        );
+        log::info!("Run ID:         {}", Self::fmt_opt(self.run_id.as_ref()));
+        log::info!(
+            "Run started:    {}",
+            Self::fmt_opt(self.run_started.map(|ts| ts.to_rfc3339()).as_ref())
+        );
+        log::info!(
+            "Backtest start: {}",
+            Self::fmt_opt(self.backtest_start.map(|ts| ts.to_rfc3339()).as_ref())
+        );
+        log::info!("Batch start:    {}", start.to_rfc3339());
+        if end == UnixNanos::max() {
+            log::info!("Batch end:      None");
+        } else {
+            log::info!("Batch end:      {}", end.to_rfc3339());
+        }
+        log::info!("-----------------------------------------------------------------");
     }
 
     pub fn log_post_run(&self) {
-        todo!("implement log_post_run")
+        let elapsed_secs = match (self.run_started, self.run_finished) {
+            (Some(started), Some(finished)) => finished
+                .duration_since(&started)
+                .map_or(0.0, |d| d as f64 / 1_000_000_000.0),
+            _ => 0.0,
+        };
+        let backtest_range_secs = match (self.backtest_start, self.backtest_end) {
+            (Some(start), Some(end)) => end
+                .duration_since(&start)
+                .map_or(0.0, |d| d as f64 / 1_000_000_000.0),
+            _ => 0.0,
+        };
+
+        let (total_orders, total_positions) = {
+            let cache = self.kernel.cache.borrow();
+            (
+                cache.orders_total_count(None, None, None, None),
+                cache.positions_total_count(None, None, None, None),
+            )
+        };
+
+        log::info!("=================================================================");
+        log::info!(" BACKTEST POST-RUN");
+        log::info!("=================================================================");
+        log::info!(
+            "Run config ID:  {}",
+            Self::fmt_opt(self.run_config_id.as_ref())
+        );
+        log::info!("Run ID:         {}", Self::fmt_opt(self.run_id.as_ref()));
+        log::info!(
+            "Run started:    {}",
+            Self::fmt_opt(self.run_started.map(|ts| ts.to_rfc3339()).as_ref())
+        );
+        log::info!(
+            "Run finished:   {}",
+            Self::fmt_opt(self.run_finished.map(|ts| ts.to_rfc3339()).as_ref())
+        );
+        log::info!("Elapsed time:   {elapsed_secs:.3}s");
+        log::info!(
+            "Backtest start: {}",
+            Self::fmt_opt(self.backtest_start.map(|ts| ts.to_rfc3339()).as_ref())
+        );
+        log::info!(
+            "Backtest end:   {}",
+            Self::fmt_opt(self.backtest_end.map(|ts| ts.to_rfc3339()).as_ref())
+        );
+        log::info!("Backtest range: {backtest_range_secs:.3}s");
+        log::info!("Iterations:     {}", self.iteration);
+        log::info!("Total orders:   {total_orders}");
+        log::info!("Total positions: {total_positions}");
+
+        for exchange in self.venues.values() {
+            self.log_account_balances(&exchange.borrow(), true);
+        }
     }
 
     pub fn add_data_client_if_not_exists(&mut self, client_id: ClientId) {
@@ -439,7 +980,13 @@ impl BacktestEngine {
         // Create a generic, venue-agnostic backtest data client. We use a dummy
         // venue derived from the client id for uniqueness.
         let venue = Venue::from(client_id.as_str());
-        let backtest_client = BacktestDataClient::new(client_id, venue, self.kernel.cache.clone());
+        let backtest_client = BacktestDataClient::new(
+            client_id,
+            venue,
+            self.kernel.cache.clone(),
+            self.kernel.clock.clone(),
+            self.data_source.clone(),
+        );
         let data_client_adapter = DataClientAdapter::new(
             backtest_client.client_id,
             None, // no specific venue association
@@ -471,6 +1018,95 @@ impl BacktestEngine {
         }
     }
 
+    fn route_data_to_exchange(&self, data: &Data) {
+        let venue = data.instrument_id().venue;
+        let Some(exchange) = self.venues.get(&venue) else {
+            // Data for a venue without a simulated exchange only reaches the data engine
+            return;
+        };
+        let mut exchange = exchange.borrow_mut();
+
+        match data {
+            Data::Delta(delta) => exchange.process_order_book_delta(*delta),
+            Data::Deltas(deltas) => exchange.process_order_book_deltas((**deltas).clone()),
+            Data::Depth10(depth) => exchange.process_order_book_depth10(depth),
+            Data::Quote(quote) => exchange.process_quote_tick(quote),
+            Data::Trade(trade) => exchange.process_trade_tick(trade),
+            Data::Bar(bar) => exchange.process_bar(*bar),
+            Data::InstrumentClose(close) => exchange.process_instrument_close(close),
+            Data::FundingRateUpdate(funding_rate) => exchange.process_funding_rate(funding_rate),
+            Data::MarkPriceUpdate(_) | Data::IndexPriceUpdate(_) => {}
+        }
+    }
+
+    /// Sends the responses to any data requests made while processing the last event to the
+    /// `DataEngine`, which is no longer borrowed at this point.
+    fn process_data_responses(&self) {
+        let endpoint = MessagingSwitchboard::data_engine_response();
+        for response in self.data_source.drain_responses() {
+            msgbus::send_any(endpoint, &response);
+        }
+    }
+
+    fn flush_accumulator_events(&mut self, ts_now: UnixNanos) {
+        let raw_handlers = self.advance_time(ts_now);
+        self.process_raw_time_event_handlers(raw_handlers, ts_now, false, true);
+    }
+
+    fn set_clocks_time(&self, ts_now: UnixNanos) {
+        for clock in self.kernel.trader.get_component_clocks() {
+            Self::test_clock_mut(&clock, |test_clock| test_clock.set_time(ts_now));
+        }
+        Self::test_clock_mut(&self.kernel.clock, |test_clock| test_clock.set_time(ts_now));
+    }
+
+    fn advance_clock_on_accumulator(
+        accumulator: &mut TimeEventAccumulator,
+        clock: &Rc<RefCell<dyn Clock>>,
+        to_time_ns: UnixNanos,
+        set_time: bool,
+    ) {
+        Self::test_clock_mut(clock, |test_clock| {
+            accumulator.advance_clock(test_clock, to_time_ns, set_time);
+        });
+    }
+
+    // The borrow is released before returning, so that time event handlers can
+    // freely access the clock once run.
+    fn test_clock_mut<R>(clock: &Rc<RefCell<dyn Clock>>, f: impl FnOnce(&mut TestClock) -> R) -> R {
+        let mut clock = clock.borrow_mut();
+        let test_clock = clock
+            .as_any_mut()
+            .downcast_mut::<TestClock>()
+            .expect("`BacktestEngine` requires a `TestClock`");
+        f(test_clock)
+    }
+
+    fn log_account_balances(&self, exchange: &SimulatedExchange, post_run: bool) {
+        log::info!("SimulatedVenue {}", exchange.id);
+
+        let Some(account) = exchange.get_account() else {
+            log::info!("No account for venue {}", exchange.id);
+            return;
+        };
+
+        log::info!("-----------------------------------------------------------------");
+        if post_run {
+            log::info!("Balances ending:");
+        } else {
+            log::info!("Balances starting:");
+        }
+
+        for balance in account.balances().values() {
+            log::info!("{}", balance.total);
+        }
+        log::info!("-----------------------------------------------------------------");
+    }
+
+    fn fmt_opt<T: std::fmt::Display>(value: Option<&T>) -> String {
+        value.map_or_else(|| "None".to_string(), ToString::to_string)
+    }
+
     // TODO: We might want venue to be optional for multi-venue clients
     pub fn add_market_data_client_if_not_exists(&mut self, venue: Venue) {
         let client_id = ClientId::from(venue.as_str());
@@ -481,8 +1117,13 @@ impl BacktestEngine {
             .registered_clients()
             .contains(&client_id)
         {
-            let backtest_client =
-                BacktestDataClient::new(client_id, venue, self.kernel.cache.clone());
+            let backtest_client = BacktestDataClient::new(
+                client_id,
+                venue,
+                self.kernel.cache.clone(),
+                self.kernel.clock.clone(),
+                self.data_source.clone(),
+            );
             let data_client_adapter = DataClientAdapter::new(
                 client_id,
                 Some(venue), // TBD
@@ -502,14 +1143,16 @@ impl BacktestEngine {
 mod tests {
     use std::collections::HashMap;
 
-    use ant_execution::models::{fee::FeeModelAny, fill::FillModel};
+    use ant_core::UnixNanos;
+    use ant_execution::models::{fee::FeeModelAny, fill::FillModelAny};
     use ant_model::{
+        data::{Data, QuoteTick},
         enums::{AccountType, BookType, OmsType},
-        identifiers::{ClientId, Venue},
+        identifiers::{ClientId, InstrumentId, Venue},
         instruments::{
-            CryptoPerpetual, Instrument, InstrumentAny, stubs::crypto_perpetual_ethusdt,
+            CryptoPerpetual, Instrument, InstrumentEnum, stubs::crypto_perpetual_ethusdt,
         },
-        types::Money,
+        types::{Money, Price, Quantity},
     };
     use rstest::rstest;
 
@@ -530,7 +1173,7 @@ mod tests {
                 None,
                 HashMap::new(),
                 vec![],
-                FillModel::default(),
+                FillModelAny::default(),
                 FeeModelAny::default(),
                 None,
                 None,
@@ -546,6 +1189,12 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                HashMap::new(),
+                None,
+                None,
+                None,
+                None,
             )
             .unwrap();
         engine
@@ -555,7 +1204,7 @@ mod tests {
     fn test_engine_venue_and_instrument_initialization(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let venue = Venue::from("BINANCE");
         let client_id = ClientId::from(venue.as_str());
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
         let instrument_id = instrument.id();
         let mut engine = get_backtest_engine(None);
         engine.add_instrument(instrument).unwrap();
@@ -563,7 +1212,7 @@ mod tests {
         // Check the venue and exec client has been added
         assert_eq!(engine.venues.len(), 1);
         assert!(engine.venues.contains_key(&venue));
-        assert!(engine.kernel.exec_engine.get_client(&client_id).is_some());
+        assert!(engine.kernel.exec_engine().get_client(&client_id).is_some());
 
         // Check the instrument has been added
         assert!(
@@ -590,4 +1239,199 @@ mod tests {
                 .contains(&client_id)
         );
     }
+
+    fn quote(instrument_id: InstrumentId, ts: u64) -> Data {
+        Data::Quote(QuoteTick::new(
+            instrument_id,
+            Price::from("1000.00"),
+            Price::from("1000.01"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            ts.into(),
+            ts.into(),
+        ))
+    }
+
+    #[rstest]
+    fn test_run_processes_all_data_and_advances_clock(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut engine = get_backtest_engine(None);
+        engine.add_instrument(instrument).unwrap();
+        engine.add_data(
+            vec![
+                quote(instrument_id, 3),
+                quote(instrument_id, 1),
+                quote(instrument_id, 2),
+            ],
+            None,
+            true,
+            true,
+        );
+
+        engine.run(None, None, None, false).unwrap();
+
+        assert_eq!(engine.iteration(), 3);
+        assert!(engine.run_id().is_some());
+        assert_eq!(
+            engine.kernel.clock.borrow().timestamp_ns(),
+            UnixNanos::from(3)
+        );
+    }
+
+    #[rstest]
+    fn test_run_with_start_and_end_bounds(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut engine = get_backtest_engine(None);
+        engine.add_instrument(instrument).unwrap();
+        engine.add_data(
+            (1..=5).map(|ts| quote(instrument_id, ts)).collect(),
+            None,
+            true,
+            true,
+        );
+
+        engine
+            .run(
+                Some(UnixNanos::from(2)),
+                Some(UnixNanos::from(4)),
+                None,
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(engine.iteration(), 3);
+    }
+
+    #[rstest]
+    fn test_run_streaming_chunks(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut engine = get_backtest_engine(None);
+        engine.add_instrument(instrument).unwrap();
+
+        engine.add_data(
+            vec![quote(instrument_id, 1), quote(instrument_id, 2)],
+            None,
+            true,
+            true,
+        );
+        engine.run(None, None, None, true).unwrap();
+        let run_id = engine.run_id();
+
+        engine.clear_data();
+        engine.add_data(
+            vec![quote(instrument_id, 3), quote(instrument_id, 4)],
+            None,
+            true,
+            true,
+        );
+        engine.run(None, None, None, true).unwrap();
+        engine.end();
+
+        assert_eq!(engine.iteration(), 4);
+        assert_eq!(engine.run_id(), run_id);
+        assert_eq!(
+            engine.kernel.clock.borrow().timestamp_ns(),
+            UnixNanos::from(4)
+        );
+    }
+
+    #[rstest]
+    fn test_run_with_data_stream(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut engine = get_backtest_engine(None);
+        engine.add_instrument(instrument).unwrap();
+        engine.add_data(
+            vec![quote(instrument_id, 3), quote(instrument_id, 6)],
+            None,
+            true,
+            true,
+        );
+        let chunks = vec![
+            vec![quote(instrument_id, 1), quote(instrument_id, 2)],
+            vec![quote(instrument_id, 4), quote(instrument_id, 5)],
+        ];
+        engine.add_data_stream("lazy", chunks.into_iter());
+
+        engine.run(None, None, None, false).unwrap();
+
+        let result = engine.get_result();
+        assert_eq!(engine.iteration(), 6);
+        assert_eq!(result.backtest_start, Some(UnixNanos::from(1)));
+        assert_eq!(
+            engine.kernel.clock.borrow().timestamp_ns(),
+            UnixNanos::from(6)
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "is reserved")]
+    fn test_add_data_stream_with_reserved_name() {
+        let mut engine = get_backtest_engine(None);
+        engine.add_data_stream(super::BACKTEST_DATA_STREAM, std::iter::empty());
+    }
+
+    #[rstest]
+    fn test_run_when_start_after_end_returns_error() {
+        let mut engine = get_backtest_engine(None);
+
+        let result = engine.run(
+            Some(UnixNanos::from(2)),
+            Some(UnixNanos::from(1)),
+            None,
+            false,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_reset_allows_data_to_be_replayed(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut engine = get_backtest_engine(None);
+        engine.add_instrument(instrument).unwrap();
+        engine.add_data(
+            vec![quote(instrument_id, 1), quote(instrument_id, 2)],
+            None,
+            true,
+            true,
+        );
+        engine.run(None, None, None, false).unwrap();
+
+        engine.reset();
+
+        assert_eq!(engine.iteration(), 0);
+        assert!(engine.run_id().is_none());
+    }
+
+    #[rstest]
+    fn test_get_result_after_run(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument_id = instrument.id();
+        let mut engine = get_backtest_engine(None);
+        engine.add_instrument(instrument).unwrap();
+        engine.add_data(
+            vec![quote(instrument_id, 1), quote(instrument_id, 2)],
+            None,
+            true,
+            true,
+        );
+        engine
+            .run(None, None, Some("test-run".to_string()), false)
+            .unwrap();
+
+        let result = engine.get_result();
+
+        assert_eq!(result.run_id, engine.run_id());
+        assert_eq!(result.run_config_id, Some("test-run".to_string()));
+        assert_eq!(result.iterations, 2);
+        assert_eq!(result.backtest_start, Some(UnixNanos::from(1)));
+        assert_eq!(result.total_orders, 0);
+        assert_eq!(result.total_positions, 0);
+        assert_eq!(result.stats_pnls["USD"]["PnL (total)"], 0.0);
+    }
 }
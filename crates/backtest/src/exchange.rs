@@ -33,18 +33,22 @@ use ant_core::{
 };
 use ant_execution::{
     client::ExecutionClient,
-    matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel, latency::LatencyModel},
+    matching_engine::{
+        config::{LiquidityExhaustedBehavior, OrderMatchingEngineConfig, PriceLimitConfig},
+        engine::OrderMatchingEngine,
+        rules::TradingDayCalendar,
+    },
+    models::{fee::{FeeModel, FeeModelAny}, fill::FillModelAny, latency::LatencyModel},
 };
 use ant_model::{
     accounts::AccountAny,
     data::{
-        Bar, Data, InstrumentStatus, OrderBookDelta, OrderBookDeltas, OrderBookDeltas_API,
-        QuoteTick, TradeTick,
+        Bar, Data, FundingRateUpdate, InstrumentClose, InstrumentStatus, OrderBookDelta,
+        OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
     },
-    enums::{AccountType, BookType, OmsType},
+    enums::{AccountType, BookType, OmsType, PriceType},
     identifiers::{InstrumentId, Venue},
-    instruments::{Instrument, InstrumentAny},
+    instruments::{Instrument, InstrumentEnum},
     orderbook::OrderBook,
     orders::PassiveOrderAny,
     types::{AccountBalance, Currency, Money, Price},
@@ -114,9 +118,9 @@ pub struct SimulatedExchange {
     exec_client: Option<Rc<dyn ExecutionClient>>,
     pub base_currency: Option<Currency>,
     fee_model: FeeModelAny,
-    fill_model: FillModel,
+    fill_model: FillModelAny,
     latency_model: Option<LatencyModel>,
-    instruments: HashMap<InstrumentId, InstrumentAny>,
+    instruments: HashMap<InstrumentId, InstrumentEnum>,
     matching_engines: HashMap<InstrumentId, OrderMatchingEngine>,
     leverages: HashMap<InstrumentId, Decimal>,
     modules: Vec<Box<dyn SimulationModule>>,
@@ -125,6 +129,7 @@ pub struct SimulatedExchange {
     message_queue: VecDeque<TradingCommand>,
     inflight_queue: BinaryHeap<InflightCommand>,
     inflight_counter: HashMap<UnixNanos, u32>,
+    pending_funding_rates: HashMap<InstrumentId, FundingRateUpdate>,
     bar_execution: bool,
     reject_stop_orders: bool,
     support_gtd_orders: bool,
@@ -135,6 +140,13 @@ pub struct SimulatedExchange {
     use_message_queue: bool,
     allow_cash_borrowing: bool,
     frozen_account: bool,
+    default_price_limit_pct: Option<f64>,
+    price_limit_pcts: HashMap<InstrumentId, f64>,
+    limit_locked_no_fill: bool,
+    use_t_plus_one: bool,
+    trading_day_offset_secs: i64,
+    liquidity_exhausted_behavior: LiquidityExhaustedBehavior,
+    trading_calendar: Option<Rc<dyn TradingDayCalendar>>,
 }
 
 impl Debug for SimulatedExchange {
@@ -166,7 +178,7 @@ impl SimulatedExchange {
         modules: Vec<Box<dyn SimulationModule>>,
         cache: Rc<RefCell<Cache>>,
         clock: Rc<RefCell<dyn Clock>>,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
         book_type: BookType,
         latency_model: Option<LatencyModel>,
@@ -180,6 +192,12 @@ impl SimulatedExchange {
         use_message_queue: Option<bool>,
         allow_cash_borrowing: Option<bool>,
         frozen_account: Option<bool>,
+        default_price_limit_pct: Option<f64>,
+        price_limit_pcts: HashMap<InstrumentId, f64>,
+        limit_locked_no_fill: Option<bool>,
+        use_t_plus_one: Option<bool>,
+        trading_day_offset_secs: Option<i64>,
+        liquidity_exhausted_behavior: Option<LiquidityExhaustedBehavior>,
     ) -> anyhow::Result<Self> {
         if starting_balances.is_empty() {
             anyhow::bail!("Starting balances must be provided")
@@ -209,6 +227,7 @@ impl SimulatedExchange {
             message_queue: VecDeque::new(),
             inflight_queue: BinaryHeap::new(),
             inflight_counter: HashMap::new(),
+            pending_funding_rates: HashMap::new(),
             bar_execution: bar_execution.unwrap_or(true),
             reject_stop_orders: reject_stop_orders.unwrap_or(true),
             support_gtd_orders: support_gtd_orders.unwrap_or(true),
@@ -219,6 +238,13 @@ impl SimulatedExchange {
             use_message_queue: use_message_queue.unwrap_or(true),
             allow_cash_borrowing: allow_cash_borrowing.unwrap_or(false),
             frozen_account: frozen_account.unwrap_or(false),
+            default_price_limit_pct,
+            price_limit_pcts,
+            limit_locked_no_fill: limit_locked_no_fill.unwrap_or(false),
+            use_t_plus_one: use_t_plus_one.unwrap_or(false),
+            trading_day_offset_secs: trading_day_offset_secs.unwrap_or(0),
+            liquidity_exhausted_behavior: liquidity_exhausted_behavior.unwrap_or_default(),
+            trading_calendar: None,
         })
     }
 
@@ -226,7 +252,7 @@ impl SimulatedExchange {
         self.exec_client = Some(client);
     }
 
-    pub fn set_fill_model(&mut self, fill_model: FillModel) {
+    pub fn set_fill_model(&mut self, fill_model: FillModelAny) {
         for matching_engine in self.matching_engines.values_mut() {
             matching_engine.set_fill_model(fill_model.clone());
             log::info!(
@@ -255,7 +281,7 @@ impl SimulatedExchange {
     /// # Panics
     ///
     /// Panics if the instrument cannot be added to the exchange.
-    pub fn add_instrument(&mut self, instrument: InstrumentAny) -> anyhow::Result<()> {
+    pub fn add_instrument(&mut self, instrument: InstrumentEnum) -> anyhow::Result<()> {
         check_equal(
             &instrument.id().venue,
             &self.id,
@@ -265,14 +291,24 @@ impl SimulatedExchange {
         .expect(FAILED);
 
         if self.account_type == AccountType::Cash
-            && (matches!(instrument, InstrumentAny::CryptoPerpetual(_))
-                || matches!(instrument, InstrumentAny::CryptoFuture(_)))
+            && (matches!(instrument, InstrumentEnum::CryptoPerpetual(_))
+                || matches!(instrument, InstrumentEnum::CryptoFuture(_)))
         {
             anyhow::bail!("Cash account cannot trade futures or perpetuals")
         }
 
+        self.fee_model.check_instrument(&instrument)?;
+
         self.instruments.insert(instrument.id(), instrument.clone());
 
+        // Instrument specific price limits take precedence over the venue default
+        let price_limit = self
+            .price_limit_pcts
+            .get(&instrument.id())
+            .copied()
+            .or(self.default_price_limit_pct)
+            .map(|limit_pct| PriceLimitConfig::new(limit_pct, self.limit_locked_no_fill));
+
         let matching_engine_config = OrderMatchingEngineConfig::new(
             self.bar_execution,
             self.reject_stop_orders,
@@ -281,9 +317,13 @@ impl SimulatedExchange {
             self.use_position_ids,
             self.use_random_ids,
             self.use_reduce_only,
+            price_limit,
+            self.use_t_plus_one,
+            self.trading_day_offset_secs,
+            self.liquidity_exhausted_behavior,
         );
         let instrument_id = instrument.id();
-        let matching_engine = OrderMatchingEngine::new(
+        let mut matching_engine = OrderMatchingEngine::new(
             instrument,
             self.instruments.len() as u32,
             self.fill_model.clone(),
@@ -295,6 +335,9 @@ impl SimulatedExchange {
             Rc::clone(&self.cache),
             matching_engine_config,
         );
+        if let Some(calendar) = &self.trading_calendar {
+            matching_engine.set_trading_calendar(calendar.clone());
+        }
         self.matching_engines.insert(instrument_id, matching_engine);
 
         log::info!("Added instrument {instrument_id} and created matching engine");
@@ -555,6 +598,37 @@ impl SimulatedExchange {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if adding a missing instrument during depth processing fails.
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        for module in &self.modules {
+            module.pre_process(Data::Depth10(Box::new(*depth)));
+        }
+
+        if !self.matching_engines.contains_key(&depth.instrument_id) {
+            let instrument = {
+                let cache = self.cache.as_ref().borrow();
+                cache.instrument(&depth.instrument_id).cloned()
+            };
+
+            if let Some(instrument) = instrument {
+                self.add_instrument(instrument).unwrap();
+            } else {
+                panic!(
+                    "No matching engine found for instrument {}",
+                    depth.instrument_id
+                );
+            }
+        }
+
+        if let Some(matching_engine) = self.matching_engines.get_mut(&depth.instrument_id) {
+            matching_engine.process_order_book_depth10(depth);
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
     /// # Panics
     ///
     /// Panics if adding a missing instrument during quote tick processing fails.
@@ -648,6 +722,155 @@ impl SimulatedExchange {
         }
     }
 
+    /// # Panics
+    ///
+    /// Panics if adding a missing instrument during instrument close processing fails.
+    pub fn process_instrument_close(&mut self, close: &InstrumentClose) {
+        for module in &self.modules {
+            module.pre_process(Data::InstrumentClose(*close));
+        }
+
+        if !self.matching_engines.contains_key(&close.instrument_id) {
+            let instrument = {
+                let cache = self.cache.as_ref().borrow();
+                cache.instrument(&close.instrument_id).cloned()
+            };
+
+            if let Some(instrument) = instrument {
+                self.add_instrument(instrument).unwrap();
+            } else {
+                panic!(
+                    "No matching engine found for instrument {}",
+                    close.instrument_id
+                );
+            }
+        }
+
+        if let Some(matching_engine) = self.matching_engines.get_mut(&close.instrument_id) {
+            matching_engine.process_instrument_close(close);
+        } else {
+            panic!("Matching engine should be initialized");
+        }
+    }
+
+    /// Processes a funding rate update for a perpetual instrument.
+    ///
+    /// Updates without a `next_funding_ns` are treated as realized funding and settled at once.
+    /// Otherwise the rate is held until the next funding time is reached, with later updates
+    /// for the same instrument replacing the pending rate.
+    pub fn process_funding_rate(&mut self, funding_rate: &FundingRateUpdate) {
+        for module in &self.modules {
+            module.pre_process(Data::FundingRateUpdate(*funding_rate));
+        }
+
+        self.settle_funding_rates(funding_rate.ts_event);
+
+        match funding_rate.next_funding_ns {
+            Some(next_funding_ns) if next_funding_ns > funding_rate.ts_event => {
+                self.pending_funding_rates
+                    .insert(funding_rate.instrument_id, *funding_rate);
+            }
+            _ => self.apply_funding_rate(funding_rate),
+        }
+    }
+
+    fn settle_funding_rates(&mut self, ts_now: UnixNanos) {
+        let due: Vec<FundingRateUpdate> = self
+            .pending_funding_rates
+            .values()
+            .filter(|funding_rate| {
+                funding_rate
+                    .next_funding_ns
+                    .is_some_and(|next_funding_ns| next_funding_ns <= ts_now)
+            })
+            .copied()
+            .collect();
+
+        for funding_rate in due {
+            self.pending_funding_rates
+                .remove(&funding_rate.instrument_id);
+            self.apply_funding_rate(&funding_rate);
+        }
+    }
+
+    /// Debits or credits the account with the funding payment for every open position in the
+    /// instrument, based on the notional value at the current mark price.
+    ///
+    /// Long positions pay short positions when the rate is positive, and receive when negative.
+    fn apply_funding_rate(&mut self, funding_rate: &FundingRateUpdate) {
+        if self.account_type != AccountType::Margin || funding_rate.rate.is_zero() {
+            return;
+        }
+
+        let instrument_id = funding_rate.instrument_id;
+        let mut payments: HashMap<Currency, Decimal> = HashMap::new();
+        {
+            let cache = self.cache.borrow();
+            let Some(instrument) = cache.instrument(&instrument_id) else {
+                log::error!("Cannot apply funding rate: no instrument {instrument_id}");
+                return;
+            };
+            let Some(mark_price) = cache
+                .mark_price(&instrument_id)
+                .map(|mark_price| mark_price.value)
+                .or_else(|| cache.price(&instrument_id, PriceType::Last))
+            else {
+                log::error!("Cannot apply funding rate: no mark price for {instrument_id}");
+                return;
+            };
+
+            for position in cache.positions_open(Some(&self.id), Some(&instrument_id), None, None) {
+                let notional =
+                    instrument.calculate_notional_value(position.quantity, mark_price, None);
+                let payment = notional.as_decimal() * funding_rate.rate;
+                let payment = if position.is_long() {
+                    -payment
+                } else {
+                    payment
+                };
+                *payments.entry(notional.currency).or_default() += payment;
+            }
+        }
+
+        for (currency, amount) in payments {
+            match Money::from_decimal(amount, currency) {
+                Ok(adjustment) if !adjustment.is_zero() => {
+                    log::info!("Applying funding payment {adjustment} for {instrument_id}");
+                    self.adjust_account(adjustment);
+                }
+                Ok(_) => {}
+                Err(e) => log::error!("Cannot apply funding payment for {instrument_id}: {e}"),
+            }
+        }
+    }
+
+    /// Sets the exchange trading calendar used to resolve trading days for daily price limits
+    /// and the T+1 rule, in place of the fixed trading day offset.
+    pub fn set_trading_calendar(&mut self, calendar: Rc<dyn TradingDayCalendar>) {
+        for matching_engine in self.matching_engines.values_mut() {
+            matching_engine.set_trading_calendar(calendar.clone());
+        }
+        self.trading_calendar = Some(calendar);
+    }
+
+    /// Sets the reference price (previous close or settlement) for the daily price limit band
+    /// of the given instrument, typically to seed the first trading day of a backtest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no matching engine exists for `instrument_id`.
+    pub fn set_price_limit_reference(
+        &mut self,
+        instrument_id: InstrumentId,
+        reference: Price,
+    ) -> anyhow::Result<()> {
+        let Some(matching_engine) = self.matching_engines.get_mut(&instrument_id) else {
+            anyhow::bail!("No matching engine found for instrument {instrument_id}")
+        };
+        matching_engine.set_price_limit_reference(reference);
+        Ok(())
+    }
+
     /// # Panics
     ///
     /// Panics if adding a missing instrument during instrument status processing fails.
@@ -683,6 +906,9 @@ impl SimulatedExchange {
     pub fn process(&mut self, ts_now: UnixNanos) {
         // TODO implement correct clock fixed time setting self.clock.set_time(ts_now);
 
+        // Settle funding for any pending rates which have reached their funding time
+        self.settle_funding_rates(ts_now);
+
         // Process inflight commands
         while let Some(inflight) = self.inflight_queue.peek() {
             if inflight.ts > ts_now {
@@ -711,7 +937,11 @@ impl SimulatedExchange {
             matching_engine.reset();
         }
 
-        // TODO Clear the inflight and message queues
+        self.message_queue.clear();
+        self.inflight_queue.clear();
+        self.inflight_counter.clear();
+        self.pending_funding_rates.clear();
+
         log::info!("Resetting exchange state");
     }
 
@@ -808,30 +1038,35 @@ mod tests {
     };
     use ant_core::{AtomicTime, UUID4, UnixNanos};
     use ant_execution::models::{
-        fee::{FeeModelAny, MakerTakerFeeModel},
-        fill::FillModel,
+        fee::{ChinaFuturesFeeModel, FeeModelAny, FeeRateTable, MakerTakerFeeModel},
+        fill::FillModelAny,
         latency::LatencyModel,
     };
     use ant_model::{
         accounts::{AccountAny, MarginAccount},
         data::{
-            Bar, BarType, BookOrder, InstrumentStatus, OrderBookDelta, OrderBookDeltas, QuoteTick,
-            TradeTick,
+            Bar, BarType, BookOrder, FundingRateUpdate, InstrumentClose, InstrumentStatus,
+            MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick,
         },
         enums::{
-            AccountType, AggressorSide, BookAction, BookType, MarketStatus, MarketStatusAction,
-            OmsType, OrderSide, OrderType,
+            AccountType, AggressorSide, BookAction, BookType, InstrumentCloseType, MarketStatus,
+            MarketStatusAction, OmsType, OrderSide, OrderType,
         },
         events::AccountState,
         identifiers::{
-            AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, Venue,
-            VenueOrderId,
+            AccountId, ClientId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId,
+            TraderId, Venue, VenueOrderId,
         },
-        instruments::{CryptoPerpetual, InstrumentAny, stubs::crypto_perpetual_ethusdt},
-        orders::OrderTestBuilder,
+        instruments::{
+            CryptoPerpetual, Instrument, InstrumentEnum,
+            stubs::{audusd_sim, crypto_perpetual_ethusdt},
+        },
+        orders::{OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
         types::{AccountBalance, Currency, Money, Price, Quantity},
     };
     use rstest::rstest;
+    use rust_decimal::Decimal;
 
     use crate::{
         exchange::{InflightCommand, SimulatedExchange},
@@ -861,7 +1096,7 @@ mod tests {
                 vec![],
                 cache.clone(),
                 clock,
-                FillModel::default(),
+                FillModelAny::default(),
                 FeeModelAny::MakerTaker(MakerTakerFeeModel),
                 book_type,
                 None,
@@ -875,6 +1110,12 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
+                HashMap::new(),
+                None,
+                None,
+                None,
+                None,
             )
             .unwrap(),
         ));
@@ -933,7 +1174,7 @@ mod tests {
             BookType::L1_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
         exchange.borrow_mut().add_instrument(instrument).unwrap();
     }
 
@@ -946,10 +1187,30 @@ mod tests {
             BookType::L1_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
         exchange.borrow_mut().add_instrument(instrument).unwrap();
     }
 
+    #[rstest]
+    fn test_add_instrument_without_fee_rate_returns_error(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let exchange = get_exchange(
+            Venue::new("BINANCE"),
+            AccountType::Margin,
+            BookType::L1_MBP,
+            None,
+        );
+        exchange.borrow_mut().fee_model =
+            FeeModelAny::ChinaFutures(ChinaFuturesFeeModel::new(FeeRateTable::default(), 0));
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+
+        let result = exchange.borrow_mut().add_instrument(instrument);
+
+        assert!(result.is_err());
+        assert!(exchange.borrow().matching_engines.is_empty());
+    }
+
     #[rstest]
     fn test_exchange_process_quote_tick(crypto_perpetual_ethusdt: CryptoPerpetual) {
         let exchange = get_exchange(
@@ -958,7 +1219,7 @@ mod tests {
             BookType::L1_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -993,7 +1254,7 @@ mod tests {
             BookType::L1_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -1028,7 +1289,7 @@ mod tests {
             BookType::L1_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -1065,7 +1326,7 @@ mod tests {
             BookType::L1_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -1116,7 +1377,7 @@ mod tests {
             BookType::L2_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -1176,7 +1437,7 @@ mod tests {
             BookType::L2_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -1248,7 +1509,7 @@ mod tests {
             BookType::L2_MBP,
             None,
         );
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
 
         // register instrument
         exchange.borrow_mut().add_instrument(instrument).unwrap();
@@ -1277,6 +1538,71 @@ mod tests {
         assert_eq!(market_status, MarketStatus::Closed);
     }
 
+    #[rstest]
+    fn test_exchange_price_limits_from_instrument_close(crypto_perpetual_ethusdt: CryptoPerpetual) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut exchange = SimulatedExchange::new(
+            Venue::new("BINANCE"),
+            OmsType::Netting,
+            AccountType::Margin,
+            vec![Money::new(1000.0, Currency::USD())],
+            None,
+            1.into(),
+            HashMap::new(),
+            vec![],
+            Rc::new(RefCell::new(Cache::default())),
+            Rc::new(RefCell::new(TestClock::new())),
+            FillModelAny::default(),
+            FeeModelAny::MakerTaker(MakerTakerFeeModel),
+            BookType::L1_MBP,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(0.10),
+            HashMap::from([(instrument.id(), 0.05)]),
+            Some(true),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        exchange.add_instrument(instrument).unwrap();
+
+        exchange.process_instrument_close(&InstrumentClose::new(
+            crypto_perpetual_ethusdt.id,
+            Price::from("1000.00"),
+            InstrumentCloseType::EndOfSession,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        ));
+        exchange.process_quote_tick(&QuoteTick::new(
+            crypto_perpetual_ethusdt.id,
+            Price::from("1000.00"),
+            Price::from("1001.00"),
+            Quantity::from("1.000"),
+            Quantity::from("1.000"),
+            UnixNanos::from(86_400_000_000_000),
+            UnixNanos::from(86_400_000_000_000),
+        ));
+
+        // Instrument specific limit of 5% applies over the venue default of 10%
+        let band = exchange
+            .get_matching_engine(&crypto_perpetual_ethusdt.id)
+            .unwrap()
+            .price_limit_band()
+            .unwrap();
+        assert_eq!(band.lower, Price::from("950.00"));
+        assert_eq!(band.upper, Price::from("1050.00"));
+    }
+
     #[rstest]
     fn test_accounting() {
         let account_type = AccountType::Margin;
@@ -1337,6 +1663,127 @@ mod tests {
         assert_eq!(current_balance.total, Money::new(1500.0, Currency::USD()));
     }
 
+    fn get_funding_exchange(
+        order_side: OrderSide,
+    ) -> (Rc<RefCell<SimulatedExchange>>, InstrumentEnum) {
+        let account_type = AccountType::Margin;
+        let mut cache = Cache::default();
+        let margin_account = MarginAccount::new(
+            AccountState::new(
+                AccountId::from("SIM-001"),
+                account_type,
+                vec![AccountBalance::new(
+                    Money::from("1000 USD"),
+                    Money::from("0 USD"),
+                    Money::from("1000 USD"),
+                )],
+                vec![],
+                false,
+                UUID4::default(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                None,
+            ),
+            false,
+        );
+        cache
+            .add_account(AccountAny::Margin(margin_account))
+            .unwrap();
+
+        let instrument = InstrumentEnum::CurrencyPair(audusd_sim());
+        cache.add_instrument(instrument.clone()).unwrap();
+        cache
+            .add_mark_price(MarkPriceUpdate::new(
+                instrument.id(),
+                Price::from("0.80000"),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            ))
+            .unwrap();
+
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(order_side)
+            .quantity(Quantity::from(100_000))
+            .build();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            &instrument,
+            None,
+            Some(PositionId::new("P-001")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let position = Position::new(&instrument, filled.into());
+        cache.add_position(position, OmsType::Netting).unwrap();
+        cache.build_index();
+
+        let exchange = get_exchange(
+            Venue::new("SIM"),
+            account_type,
+            BookType::L2_MBP,
+            Some(Rc::new(RefCell::new(cache))),
+        );
+        exchange.borrow_mut().initialize_account();
+
+        (exchange, instrument)
+    }
+
+    #[rstest]
+    fn test_process_funding_rate_settles_long_at_next_funding_time() {
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus::register("Portfolio.update_account".into(), handler.clone());
+        let (exchange, instrument) = get_funding_exchange(OrderSide::Buy);
+
+        let funding_rate = FundingRateUpdate::new(
+            instrument.id(),
+            Decimal::new(1, 3),
+            Some(UnixNanos::from(10)),
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        exchange.borrow_mut().process_funding_rate(&funding_rate);
+
+        // Rate is pending until the funding time is reached
+        exchange.borrow_mut().process(UnixNanos::from(9));
+        assert_eq!(get_saved_messages::<AccountState>(handler.clone()).len(), 1);
+
+        exchange.borrow_mut().process(UnixNanos::from(10));
+
+        // Long pays 100_000 * 0.80000 * 0.001 = 80 USD
+        let messages = get_saved_messages::<AccountState>(handler);
+        assert_eq!(messages.len(), 2);
+        let balance = messages.last().unwrap().balances[0];
+        assert_eq!(balance.total, Money::from("920 USD"));
+        assert_eq!(balance.free, Money::from("920 USD"));
+    }
+
+    #[rstest]
+    fn test_process_funding_rate_without_next_funding_time_credits_short() {
+        let handler = get_message_saving_handler::<AccountState>(None);
+        msgbus::register("Portfolio.update_account".into(), handler.clone());
+        let (exchange, instrument) = get_funding_exchange(OrderSide::Sell);
+
+        let funding_rate = FundingRateUpdate::new(
+            instrument.id(),
+            Decimal::new(1, 3),
+            None,
+            UnixNanos::from(1),
+            UnixNanos::from(1),
+        );
+        exchange.borrow_mut().process_funding_rate(&funding_rate);
+
+        // Short receives 100_000 * 0.80000 * 0.001 = 80 USD
+        let messages = get_saved_messages::<AccountState>(handler);
+        assert_eq!(messages.len(), 2);
+        let balance = messages.last().unwrap().balances[0];
+        assert_eq!(balance.total, Money::from("1080 USD"));
+    }
+
     #[rstest]
     fn test_inflight_commands_binary_heap_ordering_respecting_timestamp_counter() {
         // Create 3 inflight commands with different timestamps and counters
@@ -1385,7 +1832,7 @@ mod tests {
             None,
         );
 
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
         exchange.borrow_mut().add_instrument(instrument).unwrap();
 
         let command1 = create_submit_order_command(UnixNanos::from(100));
@@ -1421,7 +1868,7 @@ mod tests {
         );
         exchange.borrow_mut().set_latency_model(latency_model);
 
-        let instrument = InstrumentAny::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
         exchange.borrow_mut().add_instrument(instrument).unwrap();
 
         let command1 = create_submit_order_command(UnixNanos::from(100));
@@ -64,6 +64,7 @@ pub mod engine;
 pub mod exchange;
 pub mod execution_client;
 pub mod modules;
+pub mod result;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
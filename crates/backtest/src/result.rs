@@ -0,0 +1,105 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides the `BacktestResult` summarizing a backtest run.
+
+use std::collections::BTreeMap;
+
+use ant_core::{UUID4, UnixNanos};
+use serde::{Deserialize, Serialize};
+
+/// Represents the results of a single backtest run.
+///
+/// The statistics are calculated with a `PortfolioAnalyzer` from the positions and accounts in
+/// the cache at the time the result is generated. PnL statistics are keyed by currency code,
+/// then by statistic name.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BacktestResult {
+    /// The trader ID of the backtest.
+    pub trader_id: String,
+    /// The machine ID the backtest ran on.
+    pub machine_id: String,
+    /// The run configuration ID, if any.
+    pub run_config_id: Option<String>,
+    /// The instance ID of the backtest engine.
+    pub instance_id: UUID4,
+    /// The ID of the run.
+    pub run_id: Option<UUID4>,
+    /// The UNIX timestamp (nanoseconds) when the run started (wall clock).
+    pub run_started: Option<UnixNanos>,
+    /// The UNIX timestamp (nanoseconds) when the run finished (wall clock).
+    pub run_finished: Option<UnixNanos>,
+    /// The UNIX timestamp (nanoseconds) of the start of the backtest period.
+    pub backtest_start: Option<UnixNanos>,
+    /// The UNIX timestamp (nanoseconds) of the end of the backtest period.
+    pub backtest_end: Option<UnixNanos>,
+    /// The elapsed wall clock time of the run (seconds).
+    pub elapsed_time: f64,
+    /// The number of data iterations processed.
+    pub iterations: usize,
+    /// The total number of orders.
+    pub total_orders: usize,
+    /// The total number of positions.
+    pub total_positions: usize,
+    /// The PnL statistics per currency.
+    pub stats_pnls: BTreeMap<String, BTreeMap<String, f64>>,
+    /// The returns statistics.
+    pub stats_returns: BTreeMap<String, f64>,
+    /// The general statistics.
+    pub stats_general: BTreeMap<String, f64>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use ant_core::{UUID4, UnixNanos};
+    use rstest::rstest;
+
+    use super::BacktestResult;
+
+    #[rstest]
+    fn test_json_round_trip() {
+        let mut pnls = BTreeMap::new();
+        pnls.insert("PnL (total)".to_string(), 1_250.5);
+        let result = BacktestResult {
+            trader_id: "BACKTESTER-001".to_string(),
+            machine_id: "research-01".to_string(),
+            run_config_id: Some("ema-cross".to_string()),
+            instance_id: UUID4::new(),
+            run_id: Some(UUID4::new()),
+            run_started: Some(UnixNanos::from(1_000)),
+            run_finished: Some(UnixNanos::from(2_000)),
+            backtest_start: Some(UnixNanos::from(100)),
+            backtest_end: Some(UnixNanos::from(200)),
+            elapsed_time: 0.000_001,
+            iterations: 10,
+            total_orders: 4,
+            total_positions: 2,
+            stats_pnls: BTreeMap::from([("USDT".to_string(), pnls)]),
+            stats_returns: BTreeMap::from([("Returns Volatility (252 days)".to_string(), 0.2)]),
+            stats_general: BTreeMap::from([("Long Ratio".to_string(), 0.5)]),
+        };
+
+        let json = serde_json::to_string(&result).unwrap();
+        let decoded: BacktestResult = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, result);
+        assert!(json.contains("\"total_orders\":4"));
+    }
+}
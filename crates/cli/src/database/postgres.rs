@@ -1,18 +1,46 @@
-use anyhow::Ok;
-use tokio::time::{ Duration};
+use ant_infrastructure::sql::pg::{
+    connect_pg, drop_postgres, get_postgres_connect_options, init_postgres,
+};
 
 use crate::opt::{DatabaseCommand, DatabaseOpt};
 
-pub async fn run_database_command(opt: DatabaseOpt) -> anyhow::Result<()>{
+/// Runs the Postgres database command in `opt`, applying or dropping the cache schema.
+///
+/// # Errors
+///
+/// Returns an error if connecting to the database or executing the command fails.
+pub async fn run_database_command(opt: DatabaseOpt) -> anyhow::Result<()> {
     match opt.command {
-        DatabaseCommand::Init(_config) => {
-            tokio::time::sleep(Duration::from_millis(1)).await;
-            log::info!("init database");
-        },
-        DatabaseCommand::Drop(_config) => {
-            tokio::time::sleep(Duration::from_millis(1)).await;
-            log::info!("drop database");
+        DatabaseCommand::Init(config) => {
+            let pg_connect_options = get_postgres_connect_options(
+                config.host,
+                config.port,
+                config.username,
+                config.password,
+                config.database,
+            );
+            let pg = connect_pg(pg_connect_options.clone().into()).await?;
+            log::info!("Initializing database {}", pg_connect_options.database);
+            init_postgres(
+                &pg,
+                pg_connect_options.database,
+                pg_connect_options.password,
+                config.schema,
+            )
+            .await?;
+        }
+        DatabaseCommand::Drop(config) => {
+            let pg_connect_options = get_postgres_connect_options(
+                config.host,
+                config.port,
+                config.username,
+                config.password,
+                config.database,
+            );
+            let pg = connect_pg(pg_connect_options.clone().into()).await?;
+            log::info!("Dropping database {}", pg_connect_options.database);
+            drop_postgres(&pg, pg_connect_options.database).await?;
         }
     }
     Ok(())
-}
\ No newline at end of file
+}
@@ -27,12 +27,15 @@ use ahash::{AHashMap, AHashSet};
 use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use ant_core::{UUID4, UnixNanos, correctness::check_predicate_true};
+#[cfg(feature = "indicators")]
+use ant_indicators::indicator::Indicator;
 #[cfg(feature = "defi")]
 use ant_model::defi::{Block, Blockchain, Pool, PoolLiquidityUpdate, PoolSwap};
 use ant_model::{
     data::{
         Bar, BarType, DataType, FundingRateUpdate, IndexPriceUpdate, InstrumentStatus,
-        MarkPriceUpdate, OrderBookDeltas, QuoteTick, TradeTick, close::InstrumentClose,
+        MarkPriceUpdate, OptionChain, OptionSeriesId, OrderBookDeltas, QuoteTick, StrikeRange,
+        TradeTick, close::InstrumentClose,
     },
     enums::BookType,
     identifiers::{ActorId, ClientId, ComponentId, InstrumentId, TraderId, Venue},
@@ -65,11 +68,12 @@ use crate::{
             SubscribeBars, SubscribeBookDeltas, SubscribeBookSnapshots, SubscribeCommand,
             SubscribeCustomData, SubscribeFundingRates, SubscribeIndexPrices, SubscribeInstrument,
             SubscribeInstrumentClose, SubscribeInstrumentStatus, SubscribeInstruments,
-            SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades, TradesResponse, UnsubscribeBars,
-            UnsubscribeBookDeltas, UnsubscribeBookSnapshots, UnsubscribeCommand,
-            UnsubscribeCustomData, UnsubscribeFundingRates, UnsubscribeIndexPrices,
-            UnsubscribeInstrument, UnsubscribeInstrumentClose, UnsubscribeInstrumentStatus,
-            UnsubscribeInstruments, UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+            SubscribeMarkPrices, SubscribeOptionChain, SubscribeQuotes, SubscribeTrades,
+            TradesResponse, UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookSnapshots,
+            UnsubscribeCommand, UnsubscribeCustomData, UnsubscribeFundingRates,
+            UnsubscribeIndexPrices, UnsubscribeInstrument, UnsubscribeInstrumentClose,
+            UnsubscribeInstrumentStatus, UnsubscribeInstruments, UnsubscribeMarkPrices,
+            UnsubscribeOptionChain, UnsubscribeQuotes, UnsubscribeTrades,
         },
         system::ShutdownSystem,
     },
@@ -80,7 +84,8 @@ use crate::{
             MessagingSwitchboard, get_bars_topic, get_book_deltas_topic, get_book_snapshots_topic,
             get_custom_topic, get_funding_rate_topic, get_index_price_topic,
             get_instrument_close_topic, get_instrument_status_topic, get_instrument_topic,
-            get_instruments_topic, get_mark_price_topic, get_quotes_topic, get_trades_topic,
+            get_instruments_topic, get_mark_price_topic, get_option_chain_topic, get_quotes_topic,
+            get_trades_topic,
         },
     },
     signal::Signal,
@@ -374,6 +379,16 @@ pub trait DataActor:
         Ok(())
     }
 
+    /// Actions to be performed when receiving an option chain update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the option chain update fails.
+    #[allow(unused_variables)]
+    fn on_option_chain(&mut self, chain: &OptionChain) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     #[cfg(feature = "defi")]
     /// Actions to be performed when receiving a block.
     ///
@@ -564,6 +579,10 @@ pub trait DataActor:
     fn handle_quote(&mut self, quote: &QuoteTick) {
         log_received(&quote);
 
+        // Indicators are updated regardless of running state
+        #[cfg(feature = "indicators")]
+        self.indicators.handle_quote(quote);
+
         if self.not_running() {
             log_not_running(&quote);
             return;
@@ -578,6 +597,10 @@ pub trait DataActor:
     fn handle_trade(&mut self, trade: &TradeTick) {
         log_received(&trade);
 
+        // Indicators are updated regardless of running state
+        #[cfg(feature = "indicators")]
+        self.indicators.handle_trade(trade);
+
         if self.not_running() {
             log_not_running(&trade);
             return;
@@ -592,6 +615,10 @@ pub trait DataActor:
     fn handle_bar(&mut self, bar: &Bar) {
         log_received(&bar);
 
+        // Indicators are updated regardless of running state
+        #[cfg(feature = "indicators")]
+        self.indicators.handle_bar(bar);
+
         if self.not_running() {
             log_not_running(&bar);
             return;
@@ -672,6 +699,20 @@ pub trait DataActor:
         }
     }
 
+    /// Handles a received option chain.
+    fn handle_option_chain(&mut self, chain: &OptionChain) {
+        log_received(&chain);
+
+        if self.not_running() {
+            log_not_running(&chain);
+            return;
+        }
+
+        if let Err(e) = self.on_option_chain(chain) {
+            log_error(&e);
+        }
+    }
+
     #[cfg(feature = "defi")]
     /// Handles a received block.
     fn handle_block(&mut self, block: &Block) {
@@ -783,6 +824,11 @@ pub trait DataActor:
     fn handle_quotes_response(&mut self, resp: &QuotesResponse) {
         log_received(&resp);
 
+        #[cfg(feature = "indicators")]
+        for quote in &resp.data {
+            self.indicators.handle_quote(quote);
+        }
+
         if let Err(e) = self.on_historical_quotes(&resp.data) {
             log_error(&e);
         }
@@ -792,6 +838,11 @@ pub trait DataActor:
     fn handle_trades_response(&mut self, resp: &TradesResponse) {
         log_received(&resp);
 
+        #[cfg(feature = "indicators")]
+        for trade in &resp.data {
+            self.indicators.handle_trade(trade);
+        }
+
         if let Err(e) = self.on_historical_trades(&resp.data) {
             log_error(&e);
         }
@@ -801,6 +852,11 @@ pub trait DataActor:
     fn handle_bars_response(&mut self, resp: &BarsResponse) {
         log_received(&resp);
 
+        #[cfg(feature = "indicators")]
+        for bar in &resp.data {
+            self.indicators.handle_bar(bar);
+        }
+
         if let Err(e) = self.on_historical_bars(&resp.data) {
             log_error(&e);
         }
@@ -1159,6 +1215,39 @@ pub trait DataActor:
         );
     }
 
+    /// Subscribe to streaming [`OptionChain`] updates for the `series_id`.
+    ///
+    /// The data engine builds the chain from the quotes of every listed option in the series
+    /// whose strike falls within `strike_range`, publishing the chain on each quote update.
+    fn subscribe_option_chain(
+        &mut self,
+        series_id: OptionSeriesId,
+        strike_range: StrikeRange,
+        client_id: Option<ClientId>,
+        params: Option<IndexMap<String, String>>,
+    ) where
+        Self: 'static + Debug + Sized,
+    {
+        let actor_id = self.actor_id().inner();
+        let topic = get_option_chain_topic(series_id);
+
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |chain: &OptionChain| {
+                get_actor_unchecked::<Self>(&actor_id).handle_option_chain(chain);
+            },
+        )));
+
+        DataActorCore::subscribe_option_chain(
+            self,
+            topic,
+            handler,
+            series_id,
+            strike_range,
+            client_id,
+            params,
+        );
+    }
+
     #[cfg(feature = "defi")]
     /// Subscribe to streaming [`Block`] data for the `chain`.
     fn subscribe_blocks(
@@ -1415,6 +1504,18 @@ pub trait DataActor:
         DataActorCore::unsubscribe_instrument_close(self, instrument_id, client_id, params);
     }
 
+    /// Unsubscribe from streaming [`OptionChain`] updates for the `series_id`.
+    fn unsubscribe_option_chain(
+        &mut self,
+        series_id: OptionSeriesId,
+        client_id: Option<ClientId>,
+        params: Option<IndexMap<String, String>>,
+    ) where
+        Self: 'static + Debug + Sized,
+    {
+        DataActorCore::unsubscribe_option_chain(self, series_id, client_id, params);
+    }
+
     #[cfg(feature = "defi")]
     /// Unsubscribe from streaming [`Block`] data for the `chain`.
     fn unsubscribe_blocks(
@@ -1916,6 +2017,30 @@ impl DataActorCore {
             .clone()
     }
 
+    /// Returns a read-only reference to the cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the actor has not yet been registered (cache is `None`).
+    pub fn cache(&self) -> Ref<'_, Cache> {
+        self.cache
+            .as_ref()
+            .expect("DataActor must be registered before accessing cache")
+            .borrow()
+    }
+
+    /// Returns a clone of the reference-counted cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the actor has not yet been registered (cache is `None`).
+    pub fn cache_rc(&self) -> Rc<RefCell<Cache>> {
+        self.cache
+            .as_ref()
+            .expect("DataActor must be registered before accessing cache")
+            .clone()
+    }
+
     fn clock_ref(&self) -> Ref<'_, dyn Clock> {
         self.clock
             .as_ref()
@@ -1975,6 +2100,54 @@ impl DataActorCore {
         Ok(())
     }
 
+    /// Registers the given indicator to receive quotes for the given instrument ID.
+    ///
+    /// Registered indicators are updated before the actor's `on_quote` handler is called.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_quotes(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_quotes(instrument_id, indicator);
+    }
+
+    /// Registers the given indicator to receive trades for the given instrument ID.
+    ///
+    /// Registered indicators are updated before the actor's `on_trade` handler is called.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_trades(
+        &mut self,
+        instrument_id: InstrumentId,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_trades(instrument_id, indicator);
+    }
+
+    /// Registers the given indicator to receive bars for the given bar type.
+    ///
+    /// Registered indicators are updated before the actor's `on_bar` handler is called.
+    #[cfg(feature = "indicators")]
+    pub fn register_indicator_for_bars(
+        &mut self,
+        bar_type: BarType,
+        indicator: Rc<RefCell<dyn Indicator>>,
+    ) {
+        self.indicators
+            .register_indicator_for_bars(bar_type, indicator);
+    }
+
+    /// Returns whether all registered indicators are initialized.
+    ///
+    /// Returns `false` if no indicators are registered.
+    #[cfg(feature = "indicators")]
+    #[must_use]
+    pub fn indicators_initialized(&self) -> bool {
+        self.indicators.is_initialized()
+    }
+
     /// Register an event type for warning log levels.
     pub fn register_warning_event(&mut self, event_type: &str) {
         self.warning_events.insert(event_type.to_string());
@@ -2404,6 +2577,34 @@ impl DataActorCore {
         self.send_data_cmd(DataCommand::Subscribe(command));
     }
 
+    /// Helper method for registering option chain subscriptions from the trait.
+    #[allow(clippy::too_many_arguments)]
+    pub fn subscribe_option_chain(
+        &mut self,
+        topic: MStr<Topic>,
+        handler: ShareableMessageHandler,
+        series_id: OptionSeriesId,
+        strike_range: StrikeRange,
+        client_id: Option<ClientId>,
+        params: Option<IndexMap<String, String>>,
+    ) {
+        self.check_registered();
+
+        self.add_subscription(topic, handler);
+
+        let command = SubscribeCommand::OptionChain(SubscribeOptionChain {
+            series_id,
+            strike_range,
+            client_id,
+            venue: Some(series_id.venue),
+            command_id: UUID4::new(),
+            ts_init: self.timestamp_ns(),
+            params,
+        });
+
+        self.send_data_cmd(DataCommand::Subscribe(command));
+    }
+
     #[cfg(feature = "defi")]
     /// Helper method for registering block subscriptions from the trait.
     pub fn subscribe_blocks(
@@ -2828,6 +3029,30 @@ impl DataActorCore {
         self.send_data_cmd(DataCommand::Unsubscribe(command));
     }
 
+    /// Helper method for unsubscribing from option chains.
+    pub fn unsubscribe_option_chain(
+        &mut self,
+        series_id: OptionSeriesId,
+        client_id: Option<ClientId>,
+        params: Option<IndexMap<String, String>>,
+    ) {
+        self.check_registered();
+
+        let topic = get_option_chain_topic(series_id);
+        self.remove_subscription(topic);
+
+        let command = UnsubscribeCommand::OptionChain(UnsubscribeOptionChain {
+            series_id,
+            client_id,
+            venue: Some(series_id.venue),
+            command_id: UUID4::new(),
+            ts_init: self.timestamp_ns(),
+            params,
+        });
+
+        self.send_data_cmd(DataCommand::Unsubscribe(command));
+    }
+
     #[cfg(feature = "defi")]
     /// Helper method for unsubscribing from blocks.
     pub fn unsubscribe_blocks(
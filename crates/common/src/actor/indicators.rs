@@ -13,23 +13,21 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-// Under development
-#![allow(dead_code)]
-#![allow(unused_variables)]
-#![allow(unused_imports)]
-
-use std::{collections::HashMap, sync::Arc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use ant_indicators::indicator::Indicator;
-use ant_model::{data::BarType, identifiers::InstrumentId};
+use ant_model::{
+    data::{Bar, BarType, QuoteTick, TradeTick},
+    identifiers::InstrumentId,
+};
 
 /// Contains all indicator-related references.
 #[derive(Clone, Default)]
 pub(crate) struct Indicators {
-    pub indicators: Vec<Arc<dyn Indicator>>,
-    pub indicators_for_quotes: HashMap<InstrumentId, Vec<Arc<dyn Indicator>>>,
-    pub indicators_for_trades: HashMap<InstrumentId, Vec<Arc<dyn Indicator>>>,
-    pub indicators_for_bars: HashMap<BarType, Vec<Arc<dyn Indicator>>>,
+    pub indicators: Vec<Rc<RefCell<dyn Indicator>>>,
+    pub indicators_for_quotes: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_trades: HashMap<InstrumentId, Vec<Rc<RefCell<dyn Indicator>>>>,
+    pub indicators_for_bars: HashMap<BarType, Vec<Rc<RefCell<dyn Indicator>>>>,
 }
 
 impl Indicators {
@@ -41,63 +39,42 @@ impl Indicators {
 
         self.indicators
             .iter()
-            .all(|indicator| indicator.initialized())
+            .all(|indicator| indicator.borrow().initialized())
     }
 
     /// Register an indicator to receive quote ticks for the given instrument ID.
     pub fn register_indicator_for_quotes(
         &mut self,
         instrument_id: InstrumentId,
-        indicator: Arc<dyn Indicator>,
+        indicator: Rc<RefCell<dyn Indicator>>,
     ) {
-        // Add to overall indicators if not already present
-        if !self.indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            self.indicators.push(indicator.clone());
-        }
+        self.add_indicator(&indicator);
 
         // Add to instrument-specific quotes indicators
         let indicators = self.indicators_for_quotes.entry(instrument_id).or_default();
-
-        if !indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            indicators.push(indicator);
-            // TODO: Log registration
-        } else {
-            // TODO: Log error - already registered
-        }
+        Self::add_to(indicators, indicator, &instrument_id.to_string(), "quotes");
     }
 
     /// Register an indicator to receive trade ticks for the given instrument ID.
     pub fn register_indicator_for_trades(
         &mut self,
         instrument_id: InstrumentId,
-        indicator: Arc<dyn Indicator>,
+        indicator: Rc<RefCell<dyn Indicator>>,
     ) {
-        // Add to overall indicators if not already present
-        if !self.indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            self.indicators.push(indicator.clone());
-        }
+        self.add_indicator(&indicator);
 
         // Add to instrument-specific trades indicators
         let indicators = self.indicators_for_trades.entry(instrument_id).or_default();
-
-        if !indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            indicators.push(indicator);
-            // TODO: Log registration
-        } else {
-            // TODO: Log error - already registered
-        }
+        Self::add_to(indicators, indicator, &instrument_id.to_string(), "trades");
     }
 
     /// Register an indicator to receive bar data for the given bar type.
     pub fn register_indicator_for_bars(
         &mut self,
         bar_type: BarType,
-        indicator: Arc<dyn Indicator>,
+        indicator: Rc<RefCell<dyn Indicator>>,
     ) {
-        // Add to overall indicators if not already present
-        if !self.indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            self.indicators.push(indicator.clone());
-        }
+        self.add_indicator(&indicator);
 
         // Get standard bar type
         let standard_bar_type = bar_type.standard();
@@ -107,12 +84,124 @@ impl Indicators {
             .indicators_for_bars
             .entry(standard_bar_type)
             .or_default();
+        Self::add_to(
+            indicators,
+            indicator,
+            &standard_bar_type.to_string(),
+            "bars",
+        );
+    }
+
+    /// Updates all indicators registered for quotes of the quote's instrument.
+    pub fn handle_quote(&self, quote: &QuoteTick) {
+        if let Some(indicators) = self.indicators_for_quotes.get(&quote.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_quote(quote);
+            }
+        }
+    }
+
+    /// Updates all indicators registered for trades of the trade's instrument.
+    pub fn handle_trade(&self, trade: &TradeTick) {
+        if let Some(indicators) = self.indicators_for_trades.get(&trade.instrument_id) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_trade(trade);
+            }
+        }
+    }
+
+    /// Updates all indicators registered for bars of the bar's type.
+    pub fn handle_bar(&self, bar: &Bar) {
+        if let Some(indicators) = self.indicators_for_bars.get(&bar.bar_type.standard()) {
+            for indicator in indicators {
+                indicator.borrow_mut().handle_bar(bar);
+            }
+        }
+    }
 
-        if !indicators.iter().any(|i| Arc::ptr_eq(i, &indicator)) {
-            indicators.push(indicator);
-            // TODO: Log registration
-        } else {
-            // TODO: Log error - already registered
+    fn add_indicator(&mut self, indicator: &Rc<RefCell<dyn Indicator>>) {
+        // Add to overall indicators if not already present
+        if !self.indicators.iter().any(|i| Rc::ptr_eq(i, indicator)) {
+            self.indicators.push(indicator.clone());
+        }
+    }
+
+    fn add_to(
+        indicators: &mut Vec<Rc<RefCell<dyn Indicator>>>,
+        indicator: Rc<RefCell<dyn Indicator>>,
+        key: &str,
+        data: &str,
+    ) {
+        let name = indicator.borrow().name();
+
+        if indicators.iter().any(|i| Rc::ptr_eq(i, &indicator)) {
+            log::error!("Indicator {name} already registered for {key} {data}");
+            return;
         }
+
+        indicators.push(indicator);
+        log::info!("Registered Indicator {name} for {key} {data}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ant_indicators::average::sma::SimpleMovingAverage;
+    use ant_model::data::stubs::{quote_ethusdt_binance, stub_bar, stub_trade_ethusdt_buyer};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_is_initialized_when_empty() {
+        let indicators = Indicators::default();
+
+        assert!(!indicators.is_initialized());
+    }
+
+    #[rstest]
+    fn test_register_and_handle_quote() {
+        let mut indicators = Indicators::default();
+        let sma = Rc::new(RefCell::new(SimpleMovingAverage::new(1, None)));
+        let quote = quote_ethusdt_binance();
+
+        indicators.register_indicator_for_quotes(quote.instrument_id, sma.clone());
+        indicators.handle_quote(&quote);
+
+        assert!(indicators.is_initialized());
+        assert_eq!(indicators.indicators.len(), 1);
+        assert!(sma.borrow().has_inputs());
+    }
+
+    #[rstest]
+    fn test_register_same_indicator_twice_is_ignored() {
+        let mut indicators = Indicators::default();
+        let sma = Rc::new(RefCell::new(SimpleMovingAverage::new(1, None)));
+        let trade = stub_trade_ethusdt_buyer();
+
+        indicators.register_indicator_for_trades(trade.instrument_id, sma.clone());
+        indicators.register_indicator_for_trades(trade.instrument_id, sma.clone());
+        indicators.handle_trade(&trade);
+
+        assert_eq!(indicators.indicators.len(), 1);
+        assert_eq!(
+            indicators.indicators_for_trades[&trade.instrument_id].len(),
+            1
+        );
+        assert_eq!(sma.borrow().count, 1);
+    }
+
+    #[rstest]
+    fn test_handle_bar_only_updates_matching_bar_type() {
+        let mut indicators = Indicators::default();
+        let sma = Rc::new(RefCell::new(SimpleMovingAverage::new(1, None)));
+        let bar = stub_bar();
+
+        indicators.register_indicator_for_bars(bar.bar_type, sma.clone());
+        indicators.handle_bar(&bar);
+        indicators.handle_quote(&quote_ethusdt_binance());
+
+        assert_eq!(sma.borrow().count, 1);
+        assert_eq!(sma.borrow().value, bar.close.as_f64());
     }
 }
@@ -27,6 +27,8 @@ use bytes::Bytes;
 use indexmap::IndexMap;
 use log::LevelFilter;
 use ant_core::UnixNanos;
+#[cfg(feature = "indicators")]
+use ant_indicators::{average::sma::SimpleMovingAverage, indicator::Indicator};
 use ant_model::{
     data::{
         Bar, BarType, BookOrder, DataType, FundingRateUpdate, IndexPriceUpdate, InstrumentStatus,
@@ -779,6 +781,49 @@ fn test_request_instrument(
     assert_eq!(actor.received_instruments.len(), 1);
     assert_eq!(actor.received_instruments[0], instrument);
 }
+
+#[cfg(feature = "indicators")]
+#[rstest]
+fn test_registered_indicator_updated_from_bars(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+    stub_bar: Bar,
+) {
+    let actor_id = register_data_actor(clock, cache, trader_id);
+    let actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+    let sma = Rc::new(RefCell::new(SimpleMovingAverage::new(1, None)));
+
+    actor.register_indicator_for_bars(stub_bar.bar_type, sma.clone());
+    assert!(!actor.indicators_initialized());
+
+    actor.start().unwrap();
+    actor.handle_bar(&stub_bar);
+
+    assert!(actor.indicators_initialized());
+    assert!(sma.borrow().initialized());
+    assert_eq!(sma.borrow().value, stub_bar.close.as_f64());
+    assert_eq!(actor.received_bars.len(), 1);
+}
+
+#[cfg(feature = "indicators")]
+#[rstest]
+fn test_registered_indicator_updated_when_not_running(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    trader_id: TraderId,
+    quote_audusd: QuoteTick,
+) {
+    let actor_id = register_data_actor(clock, cache, trader_id);
+    let actor = get_actor_unchecked::<TestDataActor>(&actor_id);
+    let sma = Rc::new(RefCell::new(SimpleMovingAverage::new(1, None)));
+
+    actor.register_indicator_for_quotes(quote_audusd.instrument_id, sma.clone());
+    actor.handle_quote(&quote_audusd);
+
+    assert!(sma.borrow().has_inputs());
+    assert!(actor.received_quotes.is_empty());
+}
 //
 // #[rstest]
 // fn test_request_instruments(
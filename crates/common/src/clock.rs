@@ -16,6 +16,7 @@
 //! Real-time and static `Clock` implementations.
 
 use std::{
+    any::Any,
     collections::{BTreeMap, BinaryHeap, HashMap},
     fmt::Debug,
     ops::Deref,
@@ -212,6 +213,12 @@ pub trait Clock: Debug {
 
     /// Resets the clock by clearing it's internal state.
     fn reset(&mut self);
+
+    /// Returns the clock as a `&dyn Any` to allow downcasting to the concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the clock as a `&mut dyn Any` to allow downcasting to the concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
 /// A static test clock.
@@ -579,6 +586,14 @@ impl Clock for TestClock {
         self.heap = BinaryHeap::new();
         self.callbacks = HashMap::new();
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
 
 /// A real-time clock which uses system time.
@@ -854,6 +869,14 @@ impl Clock for LiveClock {
         self.timers.clear();
         self.callbacks.clear();
     }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
 }
 
 // Helper struct to stream events from the heap
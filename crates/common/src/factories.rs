@@ -0,0 +1,649 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides factories for constructing domain objects such as orders.
+
+use ant_core::{AtomicTime, UUID4, UnixNanos};
+use ant_model::{
+    enums::{ContingencyType, OrderSide, TimeInForce, TriggerType},
+    identifiers::{
+        ClientOrderId, ExecAlgorithmId, InstrumentId, OrderListId, StrategyId, TraderId,
+    },
+    orders::{
+        LimitOrder, MarketOrder, OrderAny, OrderList, StopLimitOrder, StopMarketOrder,
+    },
+    types::{Price, Quantity},
+};
+use indexmap::IndexMap;
+use ustr::Ustr;
+
+use crate::generators::{
+    client_order_id::ClientOrderIdGenerator, order_list_id::OrderListIdGenerator,
+};
+
+/// A factory for creating orders on behalf of a single strategy.
+///
+/// Client order IDs and order list IDs are generated from the strategy's identifiers
+/// and the given clock, so every order created by the factory is uniquely tagged.
+#[derive(Debug)]
+pub struct OrderFactory {
+    clock: &'static AtomicTime,
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    order_id_generator: ClientOrderIdGenerator,
+    order_list_id_generator: OrderListIdGenerator,
+}
+
+impl OrderFactory {
+    /// Creates a new [`OrderFactory`] instance.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        strategy_id: StrategyId,
+        init_order_id_count: Option<usize>,
+        init_order_list_id_count: Option<usize>,
+        clock: &'static AtomicTime,
+        use_uuids_for_client_order_ids: bool,
+        use_hyphens_in_client_order_ids: bool,
+    ) -> Self {
+        Self {
+            clock,
+            trader_id,
+            strategy_id,
+            order_id_generator: ClientOrderIdGenerator::new(
+                trader_id,
+                strategy_id,
+                init_order_id_count.unwrap_or(0),
+                clock,
+                use_uuids_for_client_order_ids,
+                use_hyphens_in_client_order_ids,
+            ),
+            order_list_id_generator: OrderListIdGenerator::new(
+                trader_id,
+                strategy_id,
+                init_order_list_id_count.unwrap_or(0),
+                clock,
+            ),
+        }
+    }
+
+    /// Returns the trader ID for the factory.
+    #[must_use]
+    pub const fn trader_id(&self) -> TraderId {
+        self.trader_id
+    }
+
+    /// Returns the strategy ID for the factory.
+    #[must_use]
+    pub const fn strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    /// Sets the client order ID generator count.
+    pub const fn set_client_order_id_count(&mut self, count: usize) {
+        self.order_id_generator.set_count(count);
+    }
+
+    /// Sets the order list ID generator count.
+    pub const fn set_order_list_id_count(&mut self, count: usize) {
+        self.order_list_id_generator.set_count(count);
+    }
+
+    /// Generates a new client order ID.
+    pub fn generate_client_order_id(&mut self) -> ClientOrderId {
+        self.order_id_generator.generate()
+    }
+
+    /// Generates a new order list ID.
+    pub fn generate_order_list_id(&mut self) -> OrderListId {
+        self.order_list_id_generator.generate()
+    }
+
+    /// Resets the factory's ID generators.
+    pub const fn reset_factory(&mut self) {
+        self.order_id_generator.reset();
+        self.order_list_id_generator.reset();
+    }
+
+    /// Creates a new market order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        time_in_force: Option<TimeInForce>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> OrderAny {
+        let client_order_id = self.generate_client_order_id();
+        let exec_spawn_id = exec_algorithm_id.map(|_| client_order_id);
+        let order = MarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            UUID4::new(),
+            self.clock.get_time_ns(),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            None,
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id,
+            tags,
+        );
+        OrderAny::Market(order)
+    }
+
+    /// Creates a new limit order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> OrderAny {
+        let client_order_id = self.generate_client_order_id();
+        let exec_spawn_id = exec_algorithm_id.map(|_| client_order_id);
+        let order = LimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            post_only.unwrap_or(false),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            None,
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id,
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        );
+        OrderAny::Limit(order)
+    }
+
+    /// Creates a new stop-market order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_market(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> OrderAny {
+        let client_order_id = self.generate_client_order_id();
+        let exec_spawn_id = exec_algorithm_id.map(|_| client_order_id);
+        let order = StopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            None,
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id,
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        );
+        OrderAny::StopMarket(order)
+    }
+
+    /// Creates a new stop-limit order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn stop_limit(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: Option<TriggerType>,
+        time_in_force: Option<TimeInForce>,
+        expire_time: Option<UnixNanos>,
+        post_only: Option<bool>,
+        reduce_only: Option<bool>,
+        quote_quantity: Option<bool>,
+        display_qty: Option<Quantity>,
+        emulation_trigger: Option<TriggerType>,
+        trigger_instrument_id: Option<InstrumentId>,
+        exec_algorithm_id: Option<ExecAlgorithmId>,
+        exec_algorithm_params: Option<IndexMap<Ustr, Ustr>>,
+        tags: Option<Vec<Ustr>>,
+    ) -> OrderAny {
+        let client_order_id = self.generate_client_order_id();
+        let exec_spawn_id = exec_algorithm_id.map(|_| client_order_id);
+        let order = StopLimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            client_order_id,
+            order_side,
+            quantity,
+            price,
+            trigger_price,
+            trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force.unwrap_or(TimeInForce::Gtc),
+            expire_time,
+            post_only.unwrap_or(false),
+            reduce_only.unwrap_or(false),
+            quote_quantity.unwrap_or(false),
+            display_qty,
+            emulation_trigger,
+            trigger_instrument_id,
+            None,
+            None,
+            None,
+            None,
+            exec_algorithm_id,
+            exec_algorithm_params,
+            exec_spawn_id,
+            tags,
+            UUID4::new(),
+            self.clock.get_time_ns(),
+        );
+        OrderAny::StopLimit(order)
+    }
+
+    /// Creates a bracket order list consisting of an entry order with a stop-loss and
+    /// take-profit attached.
+    ///
+    /// The entry order is a market order when `entry_price` is `None`, otherwise a limit
+    /// order. The entry triggers the child orders (OTO), and the stop-loss and take-profit
+    /// reduce each other when filled (OUO). Both child orders are `reduce_only`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn bracket(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        entry_price: Option<Price>,
+        sl_trigger_price: Price,
+        sl_trigger_type: Option<TriggerType>,
+        tp_price: Price,
+        time_in_force: Option<TimeInForce>,
+        tags: Option<Vec<Ustr>>,
+    ) -> OrderList {
+        let order_list_id = self.generate_order_list_id();
+        let ts_init = self.clock.get_time_ns();
+        let time_in_force = time_in_force.unwrap_or(TimeInForce::Gtc);
+        let child_side = match order_side {
+            OrderSide::Buy => OrderSide::Sell,
+            _ => OrderSide::Buy,
+        };
+
+        let entry_client_order_id = self.generate_client_order_id();
+        let sl_client_order_id = self.generate_client_order_id();
+        let tp_client_order_id = self.generate_client_order_id();
+
+        let entry_order = match entry_price {
+            Some(price) => OrderAny::Limit(LimitOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_client_order_id,
+                order_side,
+                quantity,
+                price,
+                time_in_force,
+                None,
+                false,
+                false,
+                false,
+                None,
+                None,
+                None,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                Some(vec![sl_client_order_id, tp_client_order_id]),
+                None,
+                None,
+                None,
+                None,
+                tags.clone(),
+                UUID4::new(),
+                ts_init,
+            )),
+            None => OrderAny::Market(MarketOrder::new(
+                self.trader_id,
+                self.strategy_id,
+                instrument_id,
+                entry_client_order_id,
+                order_side,
+                quantity,
+                time_in_force,
+                UUID4::new(),
+                ts_init,
+                false,
+                false,
+                Some(ContingencyType::Oto),
+                Some(order_list_id),
+                Some(vec![sl_client_order_id, tp_client_order_id]),
+                None,
+                None,
+                None,
+                None,
+                tags.clone(),
+            )),
+        };
+
+        let sl_order = OrderAny::StopMarket(StopMarketOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            sl_client_order_id,
+            child_side,
+            quantity,
+            sl_trigger_price,
+            sl_trigger_type.unwrap_or(TriggerType::Default),
+            time_in_force,
+            None,
+            true,
+            false,
+            None,
+            None,
+            None,
+            Some(ContingencyType::Ouo),
+            Some(order_list_id),
+            Some(vec![tp_client_order_id]),
+            Some(entry_client_order_id),
+            None,
+            None,
+            None,
+            tags.clone(),
+            UUID4::new(),
+            ts_init,
+        ));
+
+        let tp_order = OrderAny::Limit(LimitOrder::new(
+            self.trader_id,
+            self.strategy_id,
+            instrument_id,
+            tp_client_order_id,
+            child_side,
+            quantity,
+            tp_price,
+            time_in_force,
+            None,
+            false,
+            true,
+            false,
+            None,
+            None,
+            None,
+            Some(ContingencyType::Ouo),
+            Some(order_list_id),
+            Some(vec![sl_client_order_id]),
+            Some(entry_client_order_id),
+            None,
+            None,
+            None,
+            tags,
+            UUID4::new(),
+            ts_init,
+        ));
+
+        OrderList::new(
+            order_list_id,
+            instrument_id,
+            self.strategy_id,
+            vec![entry_order, sl_order, tp_order],
+            ts_init,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ant_core::time::get_atomic_clock_static;
+    use ant_model::{
+        enums::{ContingencyType, OrderSide, OrderType, TimeInForce},
+        identifiers::{ClientOrderId, InstrumentId, OrderListId, StrategyId, TraderId},
+        orders::Order,
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::OrderFactory;
+
+    #[fixture]
+    fn order_factory() -> OrderFactory {
+        OrderFactory::new(
+            TraderId::default(),
+            StrategyId::default(),
+            None,
+            None,
+            get_atomic_clock_static(),
+            false,
+            true,
+        )
+    }
+
+    #[rstest]
+    fn test_generate_client_order_id(mut order_factory: OrderFactory) {
+        let client_order_id = order_factory.generate_client_order_id();
+        assert_eq!(
+            client_order_id,
+            ClientOrderId::new("O-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_generate_order_list_id(mut order_factory: OrderFactory) {
+        let order_list_id = order_factory.generate_order_list_id();
+        assert_eq!(
+            order_list_id,
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_set_client_order_id_count(mut order_factory: OrderFactory) {
+        order_factory.set_client_order_id_count(9);
+        let client_order_id = order_factory.generate_client_order_id();
+        assert_eq!(
+            client_order_id,
+            ClientOrderId::new("O-19700101-000000-001-001-10")
+        );
+    }
+
+    #[rstest]
+    fn test_reset_factory(mut order_factory: OrderFactory) {
+        order_factory.generate_client_order_id();
+        order_factory.generate_order_list_id();
+        order_factory.reset_factory();
+
+        assert_eq!(
+            order_factory.generate_client_order_id(),
+            ClientOrderId::new("O-19700101-000000-001-001-1")
+        );
+        assert_eq!(
+            order_factory.generate_order_list_id(),
+            OrderListId::new("OL-19700101-000000-001-001-1")
+        );
+    }
+
+    #[rstest]
+    fn test_market_order(mut order_factory: OrderFactory) {
+        let order = order_factory.market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            Some(TimeInForce::Ioc),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(order.order_type(), OrderType::Market);
+        assert_eq!(order.order_side(), OrderSide::Buy);
+        assert_eq!(order.quantity(), Quantity::from("1.0"));
+        assert_eq!(order.time_in_force(), TimeInForce::Ioc);
+        assert_eq!(order.strategy_id(), StrategyId::default());
+        assert!(!order.is_reduce_only());
+    }
+
+    #[rstest]
+    fn test_limit_order(mut order_factory: OrderFactory) {
+        let order = order_factory.limit(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            Quantity::from("2.0"),
+            Price::from("50000.00"),
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(order.order_type(), OrderType::Limit);
+        assert_eq!(order.price(), Some(Price::from("50000.00")));
+        assert_eq!(order.time_in_force(), TimeInForce::Gtc);
+        assert!(order.is_post_only());
+    }
+
+    #[rstest]
+    fn test_stop_market_order(mut order_factory: OrderFactory) {
+        let order = order_factory.stop_market(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Sell,
+            Quantity::from("1.0"),
+            Price::from("49000.00"),
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(order.order_type(), OrderType::StopMarket);
+        assert_eq!(order.trigger_price(), Some(Price::from("49000.00")));
+        assert!(order.is_reduce_only());
+    }
+
+    #[rstest]
+    fn test_bracket_order_list(mut order_factory: OrderFactory) {
+        let order_list = order_factory.bracket(
+            InstrumentId::from("BTCUSDT.BINANCE"),
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            Some(Price::from("50000.00")),
+            Price::from("49000.00"),
+            None,
+            Price::from("52000.00"),
+            None,
+            None,
+        );
+
+        assert_eq!(order_list.orders.len(), 3);
+        let entry = &order_list.orders[0];
+        let sl = &order_list.orders[1];
+        let tp = &order_list.orders[2];
+
+        assert_eq!(entry.order_type(), OrderType::Limit);
+        assert_eq!(entry.contingency_type(), Some(ContingencyType::Oto));
+        assert_eq!(
+            entry.linked_order_ids(),
+            Some(vec![sl.client_order_id(), tp.client_order_id()].as_slice())
+        );
+        assert_eq!(sl.order_type(), OrderType::StopMarket);
+        assert_eq!(sl.order_side(), OrderSide::Sell);
+        assert_eq!(sl.contingency_type(), Some(ContingencyType::Ouo));
+        assert_eq!(sl.parent_order_id(), Some(entry.client_order_id()));
+        assert!(sl.is_reduce_only());
+        assert_eq!(tp.order_type(), OrderType::Limit);
+        assert_eq!(tp.order_side(), OrderSide::Sell);
+        assert_eq!(tp.price(), Some(Price::from("52000.00")));
+        assert!(tp.is_reduce_only());
+    }
+}
@@ -14,6 +14,7 @@ pub mod timer;
 pub mod testing;
 
 pub mod logging;
+pub mod factories;
 pub mod generators;
 pub mod throttler;
 
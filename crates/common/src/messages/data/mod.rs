@@ -41,13 +41,13 @@ pub use subscribe::{
     SubscribeBars, SubscribeBookDeltas, SubscribeBookDepth10, SubscribeBookSnapshots,
     SubscribeCustomData, SubscribeFundingRates, SubscribeIndexPrices, SubscribeInstrument,
     SubscribeInstrumentClose, SubscribeInstrumentStatus, SubscribeInstruments, SubscribeMarkPrices,
-    SubscribeQuotes, SubscribeTrades,
+    SubscribeOptionChain, SubscribeQuotes, SubscribeTrades,
 };
 pub use unsubscribe::{
     UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeBookSnapshots,
     UnsubscribeCustomData, UnsubscribeFundingRates, UnsubscribeIndexPrices, UnsubscribeInstrument,
     UnsubscribeInstrumentClose, UnsubscribeInstrumentStatus, UnsubscribeInstruments,
-    UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+    UnsubscribeMarkPrices, UnsubscribeOptionChain, UnsubscribeQuotes, UnsubscribeTrades,
 };
 
 #[cfg(feature = "defi")]
@@ -88,6 +88,7 @@ pub enum SubscribeCommand {
     FundingRates(SubscribeFundingRates),
     InstrumentStatus(SubscribeInstrumentStatus),
     InstrumentClose(SubscribeInstrumentClose),
+    OptionChain(SubscribeOptionChain),
 }
 
 impl PartialEq for SubscribeCommand {
@@ -118,6 +119,7 @@ impl SubscribeCommand {
             Self::FundingRates(cmd) => cmd.command_id,
             Self::InstrumentStatus(cmd) => cmd.command_id,
             Self::InstrumentClose(cmd) => cmd.command_id,
+            Self::OptionChain(cmd) => cmd.command_id,
         }
     }
 
@@ -137,6 +139,7 @@ impl SubscribeCommand {
             Self::Bars(cmd) => cmd.client_id.as_ref(),
             Self::InstrumentStatus(cmd) => cmd.client_id.as_ref(),
             Self::InstrumentClose(cmd) => cmd.client_id.as_ref(),
+            Self::OptionChain(cmd) => cmd.client_id.as_ref(),
         }
     }
 
@@ -156,6 +159,7 @@ impl SubscribeCommand {
             Self::Bars(cmd) => cmd.venue.as_ref(),
             Self::InstrumentStatus(cmd) => cmd.venue.as_ref(),
             Self::InstrumentClose(cmd) => cmd.venue.as_ref(),
+            Self::OptionChain(cmd) => cmd.venue.as_ref(),
         }
     }
 
@@ -175,6 +179,7 @@ impl SubscribeCommand {
             Self::Bars(cmd) => cmd.ts_init,
             Self::InstrumentStatus(cmd) => cmd.ts_init,
             Self::InstrumentClose(cmd) => cmd.ts_init,
+            Self::OptionChain(cmd) => cmd.ts_init,
         }
     }
 }
@@ -195,6 +200,7 @@ pub enum UnsubscribeCommand {
     FundingRates(UnsubscribeFundingRates),
     InstrumentStatus(UnsubscribeInstrumentStatus),
     InstrumentClose(UnsubscribeInstrumentClose),
+    OptionChain(UnsubscribeOptionChain),
 }
 
 impl PartialEq for UnsubscribeCommand {
@@ -225,6 +231,7 @@ impl UnsubscribeCommand {
             Self::FundingRates(cmd) => cmd.command_id,
             Self::InstrumentStatus(cmd) => cmd.command_id,
             Self::InstrumentClose(cmd) => cmd.command_id,
+            Self::OptionChain(cmd) => cmd.command_id,
         }
     }
 
@@ -244,6 +251,7 @@ impl UnsubscribeCommand {
             Self::FundingRates(cmd) => cmd.client_id.as_ref(),
             Self::InstrumentStatus(cmd) => cmd.client_id.as_ref(),
             Self::InstrumentClose(cmd) => cmd.client_id.as_ref(),
+            Self::OptionChain(cmd) => cmd.client_id.as_ref(),
         }
     }
 
@@ -263,6 +271,7 @@ impl UnsubscribeCommand {
             Self::FundingRates(cmd) => cmd.venue.as_ref(),
             Self::InstrumentStatus(cmd) => cmd.venue.as_ref(),
             Self::InstrumentClose(cmd) => cmd.venue.as_ref(),
+            Self::OptionChain(cmd) => cmd.venue.as_ref(),
         }
     }
 
@@ -282,6 +291,7 @@ impl UnsubscribeCommand {
             Self::Bars(cmd) => cmd.ts_init,
             Self::InstrumentStatus(cmd) => cmd.ts_init,
             Self::InstrumentClose(cmd) => cmd.ts_init,
+            Self::OptionChain(cmd) => cmd.ts_init,
         }
     }
 }
@@ -18,7 +18,7 @@ use std::num::NonZeroUsize;
 use indexmap::IndexMap;
 use ant_core::{UUID4, UnixNanos};
 use ant_model::{
-    data::{BarType, DataType},
+    data::{BarType, DataType, OptionSeriesId, StrikeRange},
     enums::BookType,
     identifiers::{ClientId, InstrumentId, Venue},
 };
@@ -501,3 +501,38 @@ impl SubscribeInstrumentClose {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct SubscribeOptionChain {
+    pub series_id: OptionSeriesId,
+    pub strike_range: StrikeRange,
+    pub client_id: Option<ClientId>,
+    pub venue: Option<Venue>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+    pub params: Option<IndexMap<String, String>>,
+}
+
+impl SubscribeOptionChain {
+    /// Creates a new [`SubscribeOptionChain`] instance.
+    pub fn new(
+        series_id: OptionSeriesId,
+        strike_range: StrikeRange,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+        params: Option<IndexMap<String, String>>,
+    ) -> Self {
+        check_client_id_or_venue(&client_id, &venue);
+        Self {
+            series_id,
+            strike_range,
+            client_id,
+            venue,
+            command_id,
+            ts_init,
+            params,
+        }
+    }
+}
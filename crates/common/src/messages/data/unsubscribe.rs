@@ -16,7 +16,7 @@
 use indexmap::IndexMap;
 use ant_core::{UUID4, UnixNanos};
 use ant_model::{
-    data::{BarType, DataType},
+    data::{BarType, DataType, OptionSeriesId},
     identifiers::{ClientId, InstrumentId, Venue},
 };
 
@@ -476,3 +476,35 @@ impl UnsubscribeInstrumentClose {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct UnsubscribeOptionChain {
+    pub series_id: OptionSeriesId,
+    pub client_id: Option<ClientId>,
+    pub venue: Option<Venue>,
+    pub command_id: UUID4,
+    pub ts_init: UnixNanos,
+    pub params: Option<IndexMap<String, String>>,
+}
+
+impl UnsubscribeOptionChain {
+    /// Creates a new [`UnsubscribeOptionChain`] instance.
+    pub fn new(
+        series_id: OptionSeriesId,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        command_id: UUID4,
+        ts_init: UnixNanos,
+        params: Option<IndexMap<String, String>>,
+    ) -> Self {
+        check_client_id_or_venue(&client_id, &venue);
+        Self {
+            series_id,
+            client_id,
+            venue,
+            command_id,
+            ts_init,
+            params,
+        }
+    }
+}
@@ -46,6 +46,24 @@ impl GenerateOrderStatusReport {
             venue_order_id,
         }
     }
+
+    /// Returns the instrument ID filter for the report (if any).
+    #[must_use]
+    pub const fn instrument_id(&self) -> Option<InstrumentId> {
+        self.instrument_id
+    }
+
+    /// Returns the client order ID filter for the report (if any).
+    #[must_use]
+    pub const fn client_order_id(&self) -> Option<ClientOrderId> {
+        self.client_order_id
+    }
+
+    /// Returns the venue order ID filter for the report (if any).
+    #[must_use]
+    pub const fn venue_order_id(&self) -> Option<ClientOrderId> {
+        self.venue_order_id
+    }
 }
 
 #[derive(Debug)]
@@ -108,6 +126,12 @@ impl GenerateFillReports {
             end,
         }
     }
+
+    /// Returns the instrument ID filter for the reports (if any).
+    #[must_use]
+    pub const fn instrument_id(&self) -> Option<InstrumentId> {
+        self.instrument_id
+    }
 }
 
 #[derive(Debug)]
@@ -136,4 +160,10 @@ impl GeneratePositionReports {
             end,
         }
     }
+
+    /// Returns the instrument ID filter for the reports (if any).
+    #[must_use]
+    pub const fn instrument_id(&self) -> Option<InstrumentId> {
+        self.instrument_id
+    }
 }
@@ -19,8 +19,8 @@ use ahash::AHashMap;
 #[cfg(feature = "defi")]
 use ant_model::defi::Blockchain;
 use ant_model::{
-    data::{BarType, DataType},
-    identifiers::{ClientOrderId, InstrumentId, PositionId, StrategyId, Venue},
+    data::{BarType, DataType, OptionSeriesId},
+    identifiers::{ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, Venue},
 };
 
 use super::core::{Endpoint, MStr, Topic};
@@ -143,6 +143,14 @@ pub fn get_instrument_close_topic(instrument_id: InstrumentId) -> MStr<Topic> {
         .get_instrument_close_topic(instrument_id)
 }
 
+#[must_use]
+pub fn get_option_chain_topic(series_id: OptionSeriesId) -> MStr<Topic> {
+    get_message_bus()
+        .borrow_mut()
+        .switchboard
+        .get_option_chain_topic(series_id)
+}
+
 #[must_use]
 pub fn get_order_snapshots_topic(client_order_id: ClientOrderId) -> MStr<Topic> {
     get_message_bus()
@@ -237,6 +245,7 @@ pub struct MessagingSwitchboard {
     funding_rate_topics: AHashMap<InstrumentId, MStr<Topic>>,
     instrument_status_topics: AHashMap<InstrumentId, MStr<Topic>>,
     instrument_close_topics: AHashMap<InstrumentId, MStr<Topic>>,
+    option_chain_topics: AHashMap<OptionSeriesId, MStr<Topic>>,
     event_orders_topics: AHashMap<StrategyId, MStr<Topic>>,
     event_positions_topics: AHashMap<StrategyId, MStr<Topic>>,
     order_snapshots_topics: AHashMap<ClientOrderId, MStr<Topic>>,
@@ -271,6 +280,7 @@ impl Default for MessagingSwitchboard {
             bar_topics: AHashMap::new(),
             instrument_status_topics: AHashMap::new(),
             instrument_close_topics: AHashMap::new(),
+            option_chain_topics: AHashMap::new(),
             order_snapshots_topics: AHashMap::new(),
             event_orders_topics: AHashMap::new(),
             event_positions_topics: AHashMap::new(),
@@ -310,6 +320,16 @@ impl MessagingSwitchboard {
         "DataEngine.response".into()
     }
 
+    #[must_use]
+    pub fn risk_engine_execute() -> MStr<Endpoint> {
+        "RiskEngine.execute".into()
+    }
+
+    #[must_use]
+    pub fn risk_engine_process() -> MStr<Endpoint> {
+        "RiskEngine.process".into()
+    }
+
     #[must_use]
     pub fn exec_engine_execute() -> MStr<Endpoint> {
         "ExecEngine.execute".into()
@@ -320,6 +340,11 @@ impl MessagingSwitchboard {
         "ExecEngine.process".into()
     }
 
+    #[must_use]
+    pub fn exec_algorithm_execute(exec_algorithm_id: ExecAlgorithmId) -> MStr<Endpoint> {
+        format!("{exec_algorithm_id}.execute").into()
+    }
+
     #[must_use]
     pub fn get_custom_topic(&mut self, data_type: &DataType) -> MStr<Topic> {
         *self
@@ -495,6 +520,20 @@ impl MessagingSwitchboard {
             })
     }
 
+    #[must_use]
+    pub fn get_option_chain_topic(&mut self, series_id: OptionSeriesId) -> MStr<Topic> {
+        *self
+            .option_chain_topics
+            .entry(series_id)
+            .or_insert_with(|| {
+                format!(
+                    "data.option_chain.{}.{}.{}",
+                    series_id.venue, series_id.underlying, series_id.expiration_ns
+                )
+                .into()
+            })
+    }
+
     #[must_use]
     pub fn get_order_snapshots_topic(&mut self, client_order_id: ClientOrderId) -> MStr<Topic> {
         *self
@@ -578,11 +617,13 @@ impl MessagingSwitchboard {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use ant_core::UnixNanos;
     use ant_model::{
-        data::{BarType, DataType},
-        identifiers::InstrumentId,
+        data::{BarType, DataType, OptionSeriesId},
+        identifiers::{InstrumentId, Venue},
     };
     use rstest::*;
+    use ustr::Ustr;
 
     use super::*;
 
@@ -679,6 +720,19 @@ mod tests {
         assert!(switchboard.bar_topics.contains_key(&bar_type));
     }
 
+    #[rstest]
+    fn test_get_option_chain_topic(mut switchboard: MessagingSwitchboard) {
+        let series_id = OptionSeriesId::new(
+            Venue::from("DERIBIT"),
+            Ustr::from("BTC"),
+            UnixNanos::from(1_673_596_800_000_000_000),
+        );
+        let expected_topic = "data.option_chain.DERIBIT.BTC.1673596800000000000".into();
+        let result = switchboard.get_option_chain_topic(series_id);
+        assert_eq!(result, expected_topic);
+        assert!(switchboard.option_chain_topics.contains_key(&series_id));
+    }
+
     #[rstest]
     fn test_get_order_snapshots_topic(mut switchboard: MessagingSwitchboard) {
         let client_order_id = ClientOrderId::from("O-123456789");
@@ -63,6 +63,14 @@ pub fn get_data_cmd_sender() -> Arc<dyn DataCommandSender> {
     })
 }
 
+/// Attempts to get the global data command sender without panicking.
+///
+/// Returns `None` if the sender is not initialized.
+#[must_use]
+pub fn try_get_data_cmd_sender() -> Option<Arc<dyn DataCommandSender>> {
+    DATA_CMD_SENDER.with(|sender| sender.get().cloned())
+}
+
 /// Sets the global data command sender.
 ///
 /// This should be called by the runner when it initializes.
@@ -1,6 +1,6 @@
-use std::sync::OnceLock;
+use std::{future::Future, sync::OnceLock};
 
-use tokio::runtime::Builder;
+use tokio::runtime::{Builder, Handle, RuntimeFlavor};
 
 static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
 
@@ -32,3 +32,26 @@ fn initialize_runtime() -> tokio::runtime::Runtime{
 pub fn get_runtime() -> &'static tokio::runtime::Runtime {
     RUNTIME.get_or_init(initialize_runtime)
 }
+
+/// Runs the `future` to completion on the shared runtime, blocking the calling thread.
+///
+/// Intended for synchronous calls which need the result, such as connecting or requesting
+/// reports. Order commands should instead spawn their requests on [`get_runtime`], so that the
+/// calling thread is not held for a network round trip.
+///
+/// # Panics
+///
+/// Panics if called from within a current-thread runtime, which cannot be blocked.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    match Handle::try_current() {
+        Ok(handle) => {
+            assert!(
+                handle.runtime_flavor() != RuntimeFlavor::CurrentThread,
+                "Cannot block on a future from within a current-thread runtime"
+            );
+            // Hand the worker's other tasks off before blocking it
+            tokio::task::block_in_place(|| get_runtime().block_on(future))
+        }
+        Err(_) => get_runtime().block_on(future),
+    }
+}
@@ -694,6 +694,8 @@ impl DataClientAdapter {
             SubscribeCommand::Bars(cmd) => self.subscribe_bars(cmd),
             SubscribeCommand::InstrumentStatus(cmd) => self.subscribe_instrument_status(cmd),
             SubscribeCommand::InstrumentClose(cmd) => self.subscribe_instrument_close(cmd),
+            // Option chains are composed by the data engine from per-leg quote subscriptions
+            SubscribeCommand::OptionChain(_) => Ok(()),
         } {
             log_command_error(&cmd, &e);
         }
@@ -731,6 +733,7 @@ impl DataClientAdapter {
             UnsubscribeCommand::FundingRates(cmd) => self.unsubscribe_funding_rates(cmd),
             UnsubscribeCommand::InstrumentStatus(cmd) => self.unsubscribe_instrument_status(cmd),
             UnsubscribeCommand::InstrumentClose(cmd) => self.unsubscribe_instrument_close(cmd),
+            UnsubscribeCommand::OptionChain(_) => Ok(()),
         } {
             log_command_error(&cmd, &e);
         }
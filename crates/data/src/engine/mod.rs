@@ -31,6 +31,7 @@
 pub mod book;
 pub mod config;
 mod handlers;
+pub mod option_chain;
 #[cfg(feature = "defi")]
 pub mod pool;
 
@@ -48,6 +49,7 @@ use book::{BookSnapshotInfo, BookSnapshotter, BookUpdater};
 use config::DataEngineConfig;
 use handlers::{BarBarHandler, BarQuoteHandler, BarTradeHandler};
 use indexmap::IndexMap;
+use option_chain::OptionChainUpdater;
 #[cfg(feature = "defi")]
 use ant_common::messages::defi::{DefiSubscribeCommand, DefiUnsubscribeCommand};
 use ant_common::{
@@ -56,14 +58,15 @@ use ant_common::{
     logging::{RECV, RES},
     messages::data::{
         DataCommand, DataResponse, RequestCommand, SubscribeBars, SubscribeBookDeltas,
-        SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCommand, UnsubscribeBars,
-        UnsubscribeBookDeltas, UnsubscribeBookDepth10, UnsubscribeBookSnapshots,
-        UnsubscribeCommand,
+        SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCommand, SubscribeOptionChain,
+        SubscribeQuotes, UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookDepth10,
+        UnsubscribeBookSnapshots, UnsubscribeCommand, UnsubscribeOptionChain, UnsubscribeQuotes,
     },
     msgbus::{self, MStr, Topic, handler::ShareableMessageHandler, switchboard},
     timer::TimeEventCallback,
 };
 use ant_core::{
+    UUID4,
     correctness::{
         FAILED, check_key_in_map, check_key_not_in_map, check_predicate_false, check_predicate_true,
     },
@@ -76,7 +79,8 @@ use ant_model::defi::DefiData;
 use ant_model::{
     data::{
         Bar, BarType, Data, DataType, FundingRateUpdate, IndexPriceUpdate, InstrumentClose,
-        MarkPriceUpdate, OrderBookDelta, OrderBookDeltas, OrderBookDepth10, QuoteTick, TradeTick,
+        MarkPriceUpdate, OptionChain, OptionSeriesId, OrderBookDelta, OrderBookDeltas,
+        OrderBookDepth10, QuoteTick, TradeTick,
     },
     enums::{AggregationSource, BarAggregation, BookType, PriceType, RecordFlag},
     identifiers::{ClientId, InstrumentId, Venue},
@@ -109,6 +113,7 @@ pub struct DataEngine {
     book_intervals: AHashMap<NonZeroUsize, AHashSet<InstrumentId>>,
     book_updaters: AHashMap<InstrumentId, Rc<BookUpdater>>,
     book_snapshotters: AHashMap<InstrumentId, Rc<BookSnapshotter>>,
+    option_chain_updaters: AHashMap<OptionSeriesId, Rc<OptionChainUpdater>>,
     bar_aggregators: AHashMap<BarType, Rc<RefCell<Box<dyn BarAggregator>>>>,
     bar_aggregator_handlers: AHashMap<BarType, Vec<(MStr<Topic>, ShareableMessageHandler)>>,
     _synthetic_quote_feeds: AHashMap<InstrumentId, Vec<SyntheticInstrument>>,
@@ -148,6 +153,7 @@ impl DataEngine {
             book_intervals: AHashMap::new(),
             book_updaters: AHashMap::new(),
             book_snapshotters: AHashMap::new(),
+            option_chain_updaters: AHashMap::new(),
             bar_aggregators: AHashMap::new(),
             bar_aggregator_handlers: AHashMap::new(),
             _synthetic_quote_feeds: AHashMap::new(),
@@ -453,6 +459,20 @@ impl DataEngine {
         self.collect_subscriptions(|client| &client.subscriptions_funding_rates)
     }
 
+    /// Returns all option series for which option chain subscriptions exist.
+    #[must_use]
+    pub fn subscribed_option_chains(&self) -> Vec<OptionSeriesId> {
+        self.option_chain_updaters.keys().copied().collect()
+    }
+
+    /// Returns a copy of the option chain maintained for the `series_id`, if subscribed.
+    #[must_use]
+    pub fn option_chain(&self, series_id: &OptionSeriesId) -> Option<OptionChain> {
+        self.option_chain_updaters
+            .get(series_id)
+            .map(|updater| updater.chain().clone())
+    }
+
     /// Returns all instrument IDs for which status subscriptions exist.
     #[must_use]
     pub fn subscribed_instrument_status(&self) -> Vec<InstrumentId> {
@@ -529,6 +549,8 @@ impl DataEngine {
             SubscribeCommand::BookDepth10(cmd) => self.subscribe_book_depth10(cmd)?,
             SubscribeCommand::BookSnapshots(cmd) => self.subscribe_book_snapshots(cmd)?,
             SubscribeCommand::Bars(cmd) => self.subscribe_bars(cmd)?,
+            // Option chains are forwarded to clients as per-leg quote subscriptions
+            SubscribeCommand::OptionChain(cmd) => return self.subscribe_option_chain(cmd),
             _ => {} // Do nothing else
         }
 
@@ -605,6 +627,7 @@ impl DataEngine {
             UnsubscribeCommand::BookDepth10(cmd) => self.unsubscribe_book_depth10(cmd)?,
             UnsubscribeCommand::BookSnapshots(cmd) => self.unsubscribe_book_snapshots(cmd)?,
             UnsubscribeCommand::Bars(cmd) => self.unsubscribe_bars(cmd)?,
+            UnsubscribeCommand::OptionChain(cmd) => return self.unsubscribe_option_chain(cmd),
             _ => {} // Do nothing else
         }
 
@@ -732,6 +755,7 @@ impl DataEngine {
             Data::Bar(bar) => self.handle_bar(bar),
             Data::MarkPriceUpdate(mark_price) => self.handle_mark_price(mark_price),
             Data::IndexPriceUpdate(index_price) => self.handle_index_price(index_price),
+            Data::FundingRateUpdate(funding_rate) => self.handle_funding_rate(funding_rate),
             Data::InstrumentClose(close) => self.handle_instrument_close(close),
         }
     }
@@ -781,7 +805,7 @@ impl DataEngine {
             DataResponse::Quotes(resp) => self.handle_quotes(&resp.data),
             DataResponse::Trades(resp) => self.handle_trades(&resp.data),
             DataResponse::Bars(resp) => self.handle_bars(&resp.data),
-            _ => todo!(),
+            DataResponse::Book(_) | DataResponse::Data(_) => {}
         }
 
         msgbus::send_response(resp.correlation_id(), &resp);
@@ -1070,6 +1094,60 @@ impl DataEngine {
         Ok(())
     }
 
+    fn subscribe_option_chain(&mut self, cmd: &SubscribeOptionChain) -> anyhow::Result<()> {
+        let series_id = cmd.series_id;
+        let updater = self
+            .option_chain_updaters
+            .get(&series_id)
+            .cloned()
+            .unwrap_or_else(|| Rc::new(OptionChainUpdater::new(series_id)));
+
+        // Add the cached options of the series within the strike range, seeding their quotes
+        let mut added = Vec::new();
+        {
+            let cache = self.cache.borrow();
+            for instrument in cache.instruments(&series_id.venue, Some(&series_id.underlying)) {
+                let in_range = instrument
+                    .strike_price()
+                    .is_some_and(|strike| cmd.strike_range.contains(&strike));
+                if in_range && updater.add_instrument(instrument) {
+                    if let Some(quote) = cache.quote(&instrument.id()) {
+                        updater.update_quote(quote);
+                    }
+                    added.push(instrument.id());
+                }
+            }
+        }
+
+        if updater.chain().is_empty() {
+            anyhow::bail!("Cannot subscribe to option chain {series_id}: no instruments in cache");
+        }
+
+        self.option_chain_updaters
+            .insert(series_id, updater.clone());
+        let handler = ShareableMessageHandler(updater);
+
+        for instrument_id in added {
+            let topic = switchboard::get_quotes_topic(instrument_id);
+            if !msgbus::is_subscribed(topic.as_str(), handler.clone()) {
+                msgbus::subscribe(topic.into(), handler.clone(), Some(self.msgbus_priority));
+            }
+
+            let quotes_cmd = SubscribeCommand::Quotes(SubscribeQuotes::new(
+                instrument_id,
+                cmd.client_id,
+                cmd.venue,
+                UUID4::new(),
+                cmd.ts_init,
+                cmd.params.clone(),
+            ));
+            self.execute_subscribe(&quotes_cmd)?;
+        }
+
+        log::debug!("Created OptionChainUpdater for {series_id}");
+        Ok(())
+    }
+
     fn unsubscribe_book_deltas(&mut self, cmd: &UnsubscribeBookDeltas) -> anyhow::Result<()> {
         if !self.subscribed_book_deltas().contains(&cmd.instrument_id) {
             log::warn!("Cannot unsubscribe from `OrderBookDeltas` data: not subscribed");
@@ -1150,6 +1228,45 @@ impl DataEngine {
         Ok(())
     }
 
+    fn unsubscribe_option_chain(&mut self, cmd: &UnsubscribeOptionChain) -> anyhow::Result<()> {
+        let series_id = cmd.series_id;
+
+        // Keep the chain while any other component is still subscribed to it
+        let topic = switchboard::get_option_chain_topic(series_id);
+        if msgbus::subscriptions_count(topic.as_str()) > 0 {
+            return Ok(());
+        }
+
+        let Some(updater) = self.option_chain_updaters.remove(&series_id) else {
+            log::warn!("Cannot unsubscribe from option chain for {series_id}: not subscribed");
+            return Ok(());
+        };
+
+        let instrument_ids = updater.instrument_ids();
+        let handler = ShareableMessageHandler(updater);
+
+        for instrument_id in instrument_ids {
+            let topic = switchboard::get_quotes_topic(instrument_id);
+            msgbus::unsubscribe_topic(topic, handler.clone());
+
+            // Only release the client quote subscription if nothing else consumes it
+            if msgbus::subscriptions_count(topic.as_str()) == 0 {
+                let quotes_cmd = UnsubscribeCommand::Quotes(UnsubscribeQuotes::new(
+                    instrument_id,
+                    cmd.client_id,
+                    cmd.venue,
+                    UUID4::new(),
+                    cmd.ts_init,
+                    cmd.params.clone(),
+                ));
+                self.execute_unsubscribe(&quotes_cmd)?;
+            }
+        }
+
+        log::debug!("Removed OptionChainUpdater for {series_id}");
+        Ok(())
+    }
+
     fn maintain_book_updater(&mut self, instrument_id: &InstrumentId, topics: &[MStr<Topic>]) {
         if let Some(updater) = self.book_updaters.get(instrument_id) {
             let handler = ShareableMessageHandler(updater.clone());
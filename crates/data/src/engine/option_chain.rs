@@ -0,0 +1,100 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Message handler that maintains an [`OptionChain`] from the quotes of its legs.
+//!
+//! The handler is subscribed to the quote topic of every option in the chain. Each quote
+//! updates the chain (including the implied forward and greeks), which is then published
+//! on the option chain topic for the series.
+
+use std::{
+    any::Any,
+    cell::{Ref, RefCell},
+};
+
+use ant_common::msgbus::{self, MStr, Topic, handler::MessageHandler, switchboard};
+use ant_model::{
+    data::{OptionChain, OptionSeriesId, QuoteTick},
+    identifiers::InstrumentId,
+    instruments::InstrumentEnum,
+};
+use ustr::Ustr;
+
+/// Handles [`QuoteTick`]s for the legs of a single option series.
+#[derive(Debug)]
+pub struct OptionChainUpdater {
+    id: Ustr,
+    topic: MStr<Topic>,
+    chain: RefCell<OptionChain>,
+}
+
+impl OptionChainUpdater {
+    /// Creates a new [`OptionChainUpdater`] with an empty chain for the `series_id`.
+    #[must_use]
+    pub fn new(series_id: OptionSeriesId) -> Self {
+        Self {
+            id: Ustr::from(&format!("{}-{}", stringify!(OptionChainUpdater), series_id)),
+            topic: switchboard::get_option_chain_topic(series_id),
+            chain: RefCell::new(OptionChain::new(series_id)),
+        }
+    }
+
+    /// Returns a read-only reference to the maintained chain.
+    #[must_use]
+    pub fn chain(&self) -> Ref<'_, OptionChain> {
+        self.chain.borrow()
+    }
+
+    /// Returns the instrument IDs of every leg in the chain.
+    #[must_use]
+    pub fn instrument_ids(&self) -> Vec<InstrumentId> {
+        self.chain.borrow().instrument_ids()
+    }
+
+    /// Adds the option `instrument` as a leg of the chain, returning whether it was added.
+    pub fn add_instrument(&self, instrument: &InstrumentEnum) -> bool {
+        self.chain.borrow_mut().add_instrument(instrument)
+    }
+
+    /// Updates the chain with the `quote` without publishing.
+    pub fn update_quote(&self, quote: &QuoteTick) -> bool {
+        self.chain.borrow_mut().update_quote(quote)
+    }
+
+    fn handle_quote(&self, quote: &QuoteTick) {
+        if !self.update_quote(quote) {
+            return;
+        }
+
+        let chain = self.chain.borrow();
+        msgbus::publish(self.topic, &*chain as &dyn Any);
+    }
+}
+
+impl MessageHandler for OptionChainUpdater {
+    fn id(&self) -> Ustr {
+        self.id
+    }
+
+    fn handle(&self, message: &dyn Any) {
+        if let Some(quote) = message.downcast_ref::<QuoteTick>() {
+            self.handle_quote(quote);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
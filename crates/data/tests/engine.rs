@@ -32,10 +32,11 @@ use ant_common::{
         RequestInstrument, RequestInstruments, RequestQuotes, RequestTrades, SubscribeBars,
         SubscribeBookDeltas, SubscribeBookDepth10, SubscribeBookSnapshots, SubscribeCommand,
         SubscribeCustomData, SubscribeFundingRates, SubscribeIndexPrices, SubscribeInstrument,
-        SubscribeMarkPrices, SubscribeQuotes, SubscribeTrades, UnsubscribeBars,
-        UnsubscribeBookDeltas, UnsubscribeBookSnapshots, UnsubscribeCommand, UnsubscribeCustomData,
-        UnsubscribeFundingRates, UnsubscribeIndexPrices, UnsubscribeInstrument,
-        UnsubscribeMarkPrices, UnsubscribeQuotes, UnsubscribeTrades,
+        SubscribeMarkPrices, SubscribeOptionChain, SubscribeQuotes, SubscribeTrades,
+        UnsubscribeBars, UnsubscribeBookDeltas, UnsubscribeBookSnapshots, UnsubscribeCommand,
+        UnsubscribeCustomData, UnsubscribeFundingRates, UnsubscribeIndexPrices,
+        UnsubscribeInstrument, UnsubscribeMarkPrices, UnsubscribeOptionChain, UnsubscribeQuotes,
+        UnsubscribeTrades,
     },
     msgbus::{
         self, MessageBus,
@@ -49,23 +50,24 @@ use ant_data::{client::DataClientAdapter, engine::DataEngine};
 use ant_model::{
     data::{
         Bar, BarType, Data, DataType, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate,
-        OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick,
+        OptionChain, OptionSeriesId, OrderBookDeltas, OrderBookDeltas_API, OrderBookDepth10,
+        QuoteTick, StrikeRange, TradeTick,
         stubs::{stub_delta, stub_deltas, stub_depth10},
     },
     defi::{AmmType, Dex, DexType, chain::chains},
-    enums::{BookType, PriceType},
-    identifiers::{ClientId, InstrumentId, TraderId, Venue},
-    instruments::{CurrencyPair, Instrument, InstrumentEnum, stubs::audusd_sim},
-    types::Price,
+    enums::{AssetClass, BookType, OptionKind, PriceType},
+    identifiers::{ClientId, InstrumentId, Symbol, TraderId, Venue},
+    instruments::{CurrencyPair, Instrument, InstrumentEnum, OptionContract, stubs::audusd_sim},
+    types::{Currency, Price, Quantity},
 };
 #[cfg(feature = "defi")]
 use ant_model::{
     defi::{Block, Blockchain, DefiData, PoolSwap},
     defi::{Pool, Token},
     enums::OrderSide,
-    types::Quantity,
 };
 use rstest::*;
+use ustr::Ustr;
 
 #[fixture]
 fn client_id() -> ClientId {
@@ -898,6 +900,182 @@ fn test_execute_subscribe_funding_rates(
     assert_eq!(recorder.borrow().as_slice(), &[sub_cmd, unsub_cmd]);
 }
 
+fn option_contract(kind: OptionKind, strike: &str) -> InstrumentEnum {
+    let code = match kind {
+        OptionKind::Call => "C",
+        OptionKind::Put => "P",
+    };
+    let symbol = format!("AAPL211217{code}{strike}");
+    InstrumentEnum::OptionContract(OptionContract::new(
+        InstrumentId::from(format!("{symbol}.OPRA").as_str()),
+        Symbol::from(symbol.as_str()),
+        AssetClass::Equity,
+        None,
+        Ustr::from("AAPL"),
+        kind,
+        Price::from(format!("{strike}.00").as_str()),
+        Currency::USD(),
+        UnixNanos::default(),
+        UnixNanos::from(1_639_699_200_000_000_000), // 2021-12-17
+        2,
+        Price::from("0.01"),
+        Quantity::from(100),
+        Quantity::from(1),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        UnixNanos::default(),
+        UnixNanos::default(),
+    ))
+}
+
+#[rstest]
+fn test_execute_subscribe_option_chain(
+    clock: Rc<RefCell<TestClock>>,
+    cache: Rc<RefCell<Cache>>,
+    client_id: ClientId,
+) {
+    let venue = Venue::from("OPRA");
+    let series_id = OptionSeriesId::new(
+        venue,
+        Ustr::from("AAPL"),
+        UnixNanos::from(1_639_699_200_000_000_000),
+    );
+    let call = option_contract(OptionKind::Call, "150");
+    let put = option_contract(OptionKind::Put, "150");
+    let out_of_range = option_contract(OptionKind::Call, "160");
+    for instrument in [&call, &put, &out_of_range] {
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.clone())
+            .unwrap();
+    }
+
+    let mut data_engine = DataEngine::new(clock.clone(), cache.clone(), None);
+    let recorder: Rc<RefCell<Vec<DataCommand>>> = Rc::new(RefCell::new(Vec::new()));
+    register_mock_client(
+        clock,
+        cache,
+        client_id,
+        venue,
+        None,
+        &recorder,
+        &mut data_engine,
+    );
+
+    let handler = get_message_saving_handler::<OptionChain>(None);
+    let topic = switchboard::get_option_chain_topic(series_id);
+    msgbus::subscribe_topic(topic, handler.clone(), None);
+
+    let sub = SubscribeOptionChain::new(
+        series_id,
+        StrikeRange::Between {
+            min: Price::from("140.00"),
+            max: Price::from("150.00"),
+        },
+        Some(client_id),
+        Some(venue),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    data_engine.execute(&DataCommand::Subscribe(SubscribeCommand::OptionChain(sub)));
+
+    assert_eq!(data_engine.subscribed_option_chains(), vec![series_id]);
+    assert!(data_engine.subscribed_quotes().contains(&call.id()));
+    assert!(data_engine.subscribed_quotes().contains(&put.id()));
+    assert!(!data_engine.subscribed_quotes().contains(&out_of_range.id()));
+    {
+        // The chain is forwarded to the client as per-leg quote subscriptions
+        let recorded = recorder.borrow();
+        assert_eq!(recorded.len(), 2);
+        assert!(
+            recorded
+                .iter()
+                .all(|cmd| matches!(cmd, DataCommand::Subscribe(SubscribeCommand::Quotes(_))))
+        );
+    }
+
+    for (instrument, bid, ask) in [(&call, "4.75", "5.25"), (&put, "4.75", "5.25")] {
+        let quote = QuoteTick::new(
+            instrument.id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(10),
+            Quantity::from(10),
+            UnixNanos::from(1_631_836_800_000_000_000), // 2021-09-17
+            UnixNanos::from(1_631_836_800_000_000_000),
+        );
+        data_engine.process_data(Data::Quote(quote));
+    }
+
+    let messages = get_saved_messages::<OptionChain>(handler.clone());
+    let chain = data_engine.option_chain(&series_id).unwrap();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[1].forward_price(), Some(150.0));
+    assert_eq!(chain.len(), 1);
+    assert!(chain.leg(&call.id()).unwrap().greeks.is_some());
+    assert!(chain.leg(&put.id()).unwrap().greeks.is_some());
+
+    msgbus::unsubscribe_topic(topic, handler);
+    let unsub = UnsubscribeOptionChain::new(
+        series_id,
+        Some(client_id),
+        Some(venue),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    data_engine.execute(&DataCommand::Unsubscribe(UnsubscribeCommand::OptionChain(
+        unsub,
+    )));
+
+    assert!(data_engine.subscribed_option_chains().is_empty());
+    assert!(data_engine.subscribed_quotes().is_empty());
+    {
+        let recorded = recorder.borrow();
+        assert_eq!(recorded.len(), 4);
+        assert!(
+            recorded[2..]
+                .iter()
+                .all(|cmd| matches!(cmd, DataCommand::Unsubscribe(UnsubscribeCommand::Quotes(_))))
+        );
+    }
+}
+
+#[rstest]
+fn test_execute_subscribe_option_chain_without_instruments(
+    data_engine: Rc<RefCell<DataEngine>>,
+    client_id: ClientId,
+) {
+    let venue = Venue::from("OPRA");
+    let series_id = OptionSeriesId::new(
+        venue,
+        Ustr::from("AAPL"),
+        UnixNanos::from(1_639_699_200_000_000_000),
+    );
+    let sub = SubscribeOptionChain::new(
+        series_id,
+        StrikeRange::All,
+        Some(client_id),
+        Some(venue),
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+
+    let mut data_engine = data_engine.borrow_mut();
+    let result = data_engine.execute_subscribe(&SubscribeCommand::OptionChain(sub));
+
+    assert!(result.is_err());
+    assert!(data_engine.subscribed_option_chains().is_empty());
+}
+
 // ------------------------------------------------------------------------------------------------
 // Test execute request commands
 // ------------------------------------------------------------------------------------------------
@@ -1548,6 +1726,53 @@ fn test_process_funding_rate_through_any(
     assert!(messages.contains(&funding_rate));
 }
 
+#[rstest]
+fn test_process_funding_rate_through_data(
+    audusd_sim: CurrencyPair,
+    data_engine: Rc<RefCell<DataEngine>>,
+    data_client: DataClientAdapter,
+) {
+    let client_id = data_client.client_id;
+    let venue = data_client.venue;
+    data_engine.borrow_mut().register_client(data_client, None);
+
+    let sub = SubscribeFundingRates::new(
+        audusd_sim.id,
+        Some(client_id),
+        venue,
+        UUID4::new(),
+        UnixNanos::default(),
+        None,
+    );
+    let cmd = DataCommand::Subscribe(SubscribeCommand::FundingRates(sub));
+
+    let endpoint = MessagingSwitchboard::data_engine_execute();
+    msgbus::send_any(endpoint, &cmd as &dyn Any);
+
+    let funding_rate = FundingRateUpdate::new(
+        audusd_sim.id,
+        "0.0001".parse().unwrap(),
+        Some(UnixNanos::from(10)),
+        UnixNanos::from(1),
+        UnixNanos::from(2),
+    );
+    let handler = get_message_saving_handler::<FundingRateUpdate>(None);
+    let topic = switchboard::get_funding_rate_topic(funding_rate.instrument_id);
+    msgbus::subscribe_topic(topic, handler.clone(), None);
+
+    let mut data_engine = data_engine.borrow_mut();
+    data_engine.process_data(Data::FundingRateUpdate(funding_rate));
+    let cache = &data_engine.get_cache();
+    let messages = get_saved_messages::<FundingRateUpdate>(handler);
+
+    assert_eq!(
+        cache.funding_rate(&funding_rate.instrument_id),
+        Some(&funding_rate)
+    );
+    assert_eq!(messages.len(), 1);
+    assert!(messages.contains(&funding_rate));
+}
+
 #[rstest]
 fn test_process_funding_rate(
     audusd_sim: CurrencyPair,
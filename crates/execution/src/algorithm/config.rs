@@ -0,0 +1,39 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use ant_model::identifiers::ExecAlgorithmId;
+
+/// Configuration for [`ExecAlgorithm`](super::ExecAlgorithm) based components.
+#[derive(Debug, Clone)]
+pub struct ExecAlgorithmConfig {
+    /// The unique identifier for the execution algorithm.
+    ///
+    /// If `None` then the default identifier of the algorithm implementation is used.
+    pub exec_algorithm_id: Option<ExecAlgorithmId>,
+    /// If events should be logged.
+    pub log_events: bool,
+    /// If commands should be logged.
+    pub log_commands: bool,
+}
+
+impl Default for ExecAlgorithmConfig {
+    fn default() -> Self {
+        Self {
+            exec_algorithm_id: None,
+            log_events: true,
+            log_commands: true,
+        }
+    }
+}
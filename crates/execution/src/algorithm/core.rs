@@ -0,0 +1,110 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Deref, DerefMut},
+};
+
+use ant_common::actor::{DataActorCore, data_actor::DataActorConfig};
+use ant_model::identifiers::{ActorId, ClientOrderId, ExecAlgorithmId, StrategyId};
+
+use super::config::ExecAlgorithmConfig;
+
+/// The core state shared by all [`ExecAlgorithm`](super::ExecAlgorithm) implementations.
+///
+/// Wraps a [`DataActorCore`] (which it dereferences to) and adds the state required to
+/// spawn secondary orders from primary orders, such as the spawn sequence per primary.
+#[derive(Debug)]
+pub struct ExecAlgorithmCore {
+    /// The underlying data actor core.
+    pub actor: DataActorCore,
+    /// The execution algorithm configuration.
+    pub config: ExecAlgorithmConfig,
+    exec_algorithm_id: ExecAlgorithmId,
+    exec_spawn_ids: HashMap<ClientOrderId, u32>,
+    subscribed_strategies: HashSet<StrategyId>,
+}
+
+impl ExecAlgorithmCore {
+    /// Creates a new [`ExecAlgorithmCore`] instance.
+    ///
+    /// The `default_id` is used when the `config` does not specify an algorithm ID.
+    #[must_use]
+    pub fn new(config: ExecAlgorithmConfig, default_id: ExecAlgorithmId) -> Self {
+        let exec_algorithm_id = config.exec_algorithm_id.unwrap_or(default_id);
+
+        let actor_config = DataActorConfig {
+            actor_id: Some(ActorId::from(exec_algorithm_id.inner().as_str())),
+            log_events: config.log_events,
+            log_commands: config.log_commands,
+        };
+
+        Self {
+            actor: DataActorCore::new(actor_config),
+            config,
+            exec_algorithm_id,
+            exec_spawn_ids: HashMap::new(),
+            subscribed_strategies: HashSet::new(),
+        }
+    }
+
+    /// Returns the execution algorithm ID.
+    #[must_use]
+    pub const fn exec_algorithm_id(&self) -> ExecAlgorithmId {
+        self.exec_algorithm_id
+    }
+
+    /// Returns the next client order ID for an order spawned from the `primary_id`.
+    ///
+    /// Spawned IDs take the form `{primary_id}-E{sequence}` with the sequence starting at 1.
+    pub fn next_spawn_id(&mut self, primary_id: &ClientOrderId) -> ClientOrderId {
+        let sequence = self.exec_spawn_ids.entry(*primary_id).or_insert(0);
+        *sequence += 1;
+        ClientOrderId::from(format!("{primary_id}-E{sequence}").as_str())
+    }
+
+    /// Returns the number of orders spawned so far from the `primary_id`.
+    #[must_use]
+    pub fn spawn_count(&self, primary_id: &ClientOrderId) -> u32 {
+        self.exec_spawn_ids.get(primary_id).copied().unwrap_or(0)
+    }
+
+    /// Records that the algorithm is subscribed to the order events of the `strategy_id`.
+    ///
+    /// Returns `true` if the strategy was not already subscribed.
+    pub fn add_subscribed_strategy(&mut self, strategy_id: StrategyId) -> bool {
+        self.subscribed_strategies.insert(strategy_id)
+    }
+
+    /// Clears all spawn sequences.
+    pub fn reset(&mut self) {
+        self.exec_spawn_ids.clear();
+    }
+}
+
+impl Deref for ExecAlgorithmCore {
+    type Target = DataActorCore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.actor
+    }
+}
+
+impl DerefMut for ExecAlgorithmCore {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.actor
+    }
+}
@@ -0,0 +1,460 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Iceberg execution algorithm.
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use ant_common::actor::{DataActor, DataActorCore};
+use ant_model::{
+    enums::OrderType,
+    events::{OrderCanceled, OrderDenied, OrderExpired, OrderFilled, OrderRejected},
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::{Order, OrderAny},
+    types::Quantity,
+};
+
+use super::{ExecAlgorithm, ExecAlgorithmConfig, ExecAlgorithmCore, order_param};
+
+/// Execution algorithm which works a primary limit order by displaying only a portion of its
+/// quantity at a time.
+///
+/// Orders are configured with the execution algorithm parameter:
+/// - `display_qty`: the quantity of each visible child limit order.
+///
+/// A child limit order for the display quantity is placed at the primary order price, and
+/// replenished each time the previous child is completely filled, canceled or expires. Once the
+/// remaining quantity is no greater than the display quantity the primary order itself is
+/// submitted. Working stops if a child order is denied or rejected, leaving the unworked
+/// quantity on the primary order.
+#[derive(Debug)]
+pub struct IcebergExecAlgorithm {
+    core: ExecAlgorithmCore,
+    display_qtys: HashMap<ClientOrderId, Quantity>,
+}
+
+impl IcebergExecAlgorithm {
+    /// Creates a new [`IcebergExecAlgorithm`] instance.
+    ///
+    /// The algorithm ID defaults to `ICEBERG`.
+    #[must_use]
+    pub fn new(config: ExecAlgorithmConfig) -> Self {
+        Self {
+            core: ExecAlgorithmCore::new(config, ExecAlgorithmId::from("ICEBERG")),
+            display_qtys: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the primary order with the `client_order_id` is being worked.
+    #[must_use]
+    pub fn is_working(&self, client_order_id: &ClientOrderId) -> bool {
+        self.display_qtys.contains_key(client_order_id)
+    }
+
+    /// Places the next visible slice of the primary order with the `primary_id`.
+    fn replenish(&mut self, primary_id: ClientOrderId) -> anyhow::Result<()> {
+        let Some(display_qty) = self.display_qtys.get(&primary_id).copied() else {
+            return Ok(()); // Not working the primary
+        };
+
+        let primary = self.cache().order(&primary_id).cloned();
+        let Some(mut primary) = primary else {
+            self.display_qtys.remove(&primary_id);
+            anyhow::bail!("Cannot replenish: primary order {primary_id} not found in the cache");
+        };
+
+        if !primary.is_active_local() {
+            self.display_qtys.remove(&primary_id);
+            return Ok(());
+        }
+
+        if primary.quantity() <= display_qty {
+            self.display_qtys.remove(&primary_id);
+            return self.submit_order(primary);
+        }
+
+        // SAFETY: Only limit primary orders are accepted
+        let price = primary.price().unwrap();
+        let time_in_force = primary.time_in_force();
+        let expire_time = primary.expire_time();
+        let post_only = primary.is_post_only();
+        let reduce_only = primary.is_reduce_only();
+        let spawned = self.spawn_limit(
+            &mut primary,
+            display_qty,
+            price,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            None,
+            None,
+            true,
+        )?;
+
+        self.submit_order(spawned)
+    }
+
+    /// Replenishes the primary of the closed spawned order with the `client_order_id`.
+    fn on_spawned_order_closed(&mut self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
+        let primary_id = {
+            let cache = self.cache();
+            match cache.order(client_order_id) {
+                Some(order) if order.is_secondary() && order.is_closed() => order.exec_spawn_id(),
+                _ => None,
+            }
+        };
+
+        match primary_id {
+            Some(primary_id) => self.replenish(primary_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Stops working the primary of the spawned order with the `client_order_id`.
+    fn stop_working(&mut self, client_order_id: &ClientOrderId) {
+        let primary_id = self
+            .cache()
+            .order(client_order_id)
+            .filter(|order| order.is_secondary())
+            .and_then(Order::exec_spawn_id);
+
+        if let Some(primary_id) = primary_id
+            && self.display_qtys.remove(&primary_id).is_some()
+        {
+            log::warn!(
+                "Stopped working primary order {primary_id}: child {client_order_id} failed"
+            );
+        }
+    }
+}
+
+impl Deref for IcebergExecAlgorithm {
+    type Target = DataActorCore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for IcebergExecAlgorithm {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl DataActor for IcebergExecAlgorithm {
+    fn on_start(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn on_stop(&mut self) -> anyhow::Result<()> {
+        for primary_id in self.display_qtys.keys() {
+            log::warn!("Stopped working primary order {primary_id} before completion");
+        }
+        self.display_qtys.clear();
+        Ok(())
+    }
+
+    fn on_reset(&mut self) -> anyhow::Result<()> {
+        self.display_qtys.clear();
+        self.core.reset();
+        Ok(())
+    }
+}
+
+impl ExecAlgorithm for IcebergExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let client_order_id = order.client_order_id();
+        if order.order_type() != OrderType::Limit {
+            anyhow::bail!(
+                "Cannot work {} order {client_order_id}: only limit orders are supported",
+                order.order_type()
+            );
+        }
+
+        let display_qty: f64 = order_param(&order, "display_qty")?.ok_or_else(|| {
+            anyhow::anyhow!("Missing exec algorithm parameter 'display_qty' for {client_order_id}")
+        })?;
+        let display_qty = Quantity::new_checked(display_qty, order.quantity().precision)?;
+        if !display_qty.is_positive() {
+            anyhow::bail!("Invalid display_qty {display_qty} for {client_order_id}");
+        }
+
+        self.display_qtys.insert(client_order_id, display_qty);
+        self.replenish(client_order_id)
+    }
+
+    fn on_order_filled(&mut self, event: &OrderFilled) -> anyhow::Result<()> {
+        self.on_spawned_order_closed(&event.client_order_id)
+    }
+
+    fn on_order_canceled(&mut self, event: &OrderCanceled) -> anyhow::Result<()> {
+        self.on_spawned_order_closed(&event.client_order_id)
+    }
+
+    fn on_order_expired(&mut self, event: &OrderExpired) -> anyhow::Result<()> {
+        self.on_spawned_order_closed(&event.client_order_id)
+    }
+
+    fn on_order_denied(&mut self, event: &OrderDenied) -> anyhow::Result<()> {
+        self.stop_working(&event.client_order_id);
+        Ok(())
+    }
+
+    fn on_order_rejected(&mut self, event: &OrderRejected) -> anyhow::Result<()> {
+        self.stop_working(&event.client_order_id);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use ant_common::{
+        cache::Cache,
+        clock::TestClock,
+        component::Component,
+        messages::execution::{SubmitOrder, TradingCommand},
+        msgbus::{
+            self,
+            stubs::{get_message_saving_handler, get_saved_messages},
+            switchboard::MessagingSwitchboard,
+        },
+    };
+    use ant_core::{UUID4, UnixNanos};
+    use ant_model::{
+        enums::OrderType,
+        events::{OrderDenied, OrderEventAny},
+        identifiers::{
+            AccountId, ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, TraderId,
+            VenueOrderId,
+        },
+        instruments::{InstrumentEnum, stubs::audusd_sim},
+        orders::{Order, OrderAny, OrderTestBuilder, stubs::TestOrderEventStubs},
+        types::{Price, Quantity},
+    };
+    use indexmap::IndexMap;
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::IcebergExecAlgorithm;
+    use crate::algorithm::{ExecAlgorithm, ExecAlgorithmConfig};
+
+    #[fixture]
+    fn algorithm() -> IcebergExecAlgorithm {
+        let mut algorithm = IcebergExecAlgorithm::new(ExecAlgorithmConfig::default());
+        algorithm
+            .register(
+                TraderId::from("TRADER-001"),
+                Rc::new(RefCell::new(TestClock::new())),
+                Rc::new(RefCell::new(Cache::new(None, None))),
+            )
+            .unwrap();
+        algorithm.start().unwrap();
+        algorithm
+    }
+
+    fn primary_order(order_type: OrderType, quantity: &str, display_qty: &str) -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-001");
+        let params: IndexMap<Ustr, Ustr> =
+            IndexMap::from([(Ustr::from("display_qty"), Ustr::from(display_qty))]);
+        let mut builder = OrderTestBuilder::new(order_type);
+        builder
+            .trader_id(TraderId::from("TRADER-001"))
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(client_order_id)
+            .quantity(Quantity::from(quantity))
+            .exec_algorithm_id(ExecAlgorithmId::from("ICEBERG"))
+            .exec_algorithm_params(params)
+            .exec_spawn_id(client_order_id);
+        if order_type == OrderType::Limit {
+            builder.price(Price::from("0.70000"));
+        }
+        builder.build()
+    }
+
+    fn submit_command(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                order.venue_order_id().unwrap_or_default(),
+                order.clone(),
+                order.exec_algorithm_id(),
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submitted_orders(commands: &[TradingCommand]) -> Vec<(String, Quantity)> {
+        commands
+            .iter()
+            .map(|command| match command {
+                TradingCommand::SubmitOrder(command) => (
+                    command.client_order_id.to_string(),
+                    command.order.quantity(),
+                ),
+                other => panic!("Unexpected command {other}"),
+            })
+            .collect()
+    }
+
+    /// Fills the cached order with the `client_order_id`, then passes the fill to the algorithm.
+    fn fill_order(algorithm: &mut IcebergExecAlgorithm, client_order_id: &str) {
+        let client_order_id = ClientOrderId::from(client_order_id);
+        let mut order = algorithm.cache().order(&client_order_id).cloned().unwrap();
+        let account_id = AccountId::from("SIM-001");
+        order
+            .apply(TestOrderEventStubs::submitted(&order, account_id))
+            .unwrap();
+        order
+            .apply(TestOrderEventStubs::accepted(
+                &order,
+                account_id,
+                VenueOrderId::from("V-001"),
+            ))
+            .unwrap();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            &InstrumentEnum::CurrencyPair(audusd_sim()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(filled.clone()).unwrap();
+        algorithm
+            .cache_rc()
+            .borrow_mut()
+            .update_order(&order)
+            .unwrap();
+
+        algorithm.handle_order_event(&filled);
+    }
+
+    #[rstest]
+    fn test_iceberg_replenishes_display_qty_until_primary_submitted(
+        mut algorithm: IcebergExecAlgorithm,
+    ) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let primary = primary_order(OrderType::Limit, "10", "4");
+        algorithm.handle_command(&submit_command(&primary));
+        assert!(algorithm.is_working(&primary.client_order_id()));
+
+        fill_order(&mut algorithm, "O-001-E1");
+        fill_order(&mut algorithm, "O-001-E2");
+
+        let commands = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(
+            submitted_orders(&commands),
+            vec![
+                ("O-001-E1".to_string(), Quantity::from("4")),
+                ("O-001-E2".to_string(), Quantity::from("4")),
+                ("O-001".to_string(), Quantity::from("2")),
+            ]
+        );
+        assert!(!algorithm.is_working(&primary.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_iceberg_display_qty_not_less_than_quantity_submits_primary(
+        mut algorithm: IcebergExecAlgorithm,
+    ) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let primary = primary_order(OrderType::Limit, "10", "10");
+        algorithm.handle_command(&submit_command(&primary));
+
+        let commands = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(
+            submitted_orders(&commands),
+            vec![("O-001".to_string(), Quantity::from("10"))]
+        );
+        assert!(!algorithm.is_working(&primary.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_iceberg_child_denied_stops_working_and_restores_primary(
+        mut algorithm: IcebergExecAlgorithm,
+    ) {
+        let primary = primary_order(OrderType::Limit, "10", "4");
+        algorithm.handle_command(&submit_command(&primary));
+
+        let child = algorithm
+            .cache()
+            .order(&ClientOrderId::from("O-001-E1"))
+            .cloned()
+            .unwrap();
+        let denied = OrderDenied::new(
+            child.trader_id(),
+            child.strategy_id(),
+            child.instrument_id(),
+            child.client_order_id(),
+            Ustr::from("Exceeded MAX_NOTIONAL_PER_ORDER"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        algorithm.handle_order_event(&OrderEventAny::Denied(denied));
+
+        assert!(!algorithm.is_working(&primary.client_order_id()));
+        let cached = algorithm.cache().order(&primary.client_order_id()).cloned();
+        assert_eq!(cached.unwrap().quantity(), Quantity::from("10"));
+    }
+
+    #[rstest]
+    #[case(OrderType::Market, "4")]
+    #[case(OrderType::Limit, "0")]
+    #[case(OrderType::Limit, "four")]
+    fn test_iceberg_invalid_order_returns_error(
+        mut algorithm: IcebergExecAlgorithm,
+        #[case] order_type: OrderType,
+        #[case] display_qty: &str,
+    ) {
+        let primary = primary_order(order_type, "10", display_qty);
+
+        assert!(algorithm.on_order(primary.clone()).is_err());
+        assert!(!algorithm.is_working(&primary.client_order_id()));
+    }
+}
@@ -0,0 +1,1050 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution algorithms.
+//!
+//! An [`ExecAlgorithm`] is a [`DataActor`] which receives the orders tagged with its
+//! [`ExecAlgorithmId`] instead of the risk engine. The algorithm works each *primary* order by
+//! spawning *secondary* orders from it, which carry the primary client order ID as their
+//! `exec_spawn_id`, and submitting them through the risk engine. The quantity of every spawned
+//! order is deducted from the primary, so the primary always holds the quantity which remains
+//! to be worked, and is itself submitted for the final slice.
+//!
+//! Algorithms are driven only by order events, data and their component clock, so they run
+//! the same way in backtest and live environments.
+
+pub mod config;
+pub mod core;
+pub mod iceberg;
+mod schedule;
+pub mod twap;
+pub mod vwap;
+
+use std::{fmt::Debug, rc::Rc, str::FromStr};
+
+use ant_common::{
+    actor::{DataActor, registry::try_get_actor_unchecked},
+    logging::{CMD, EVT, RECV, SEND},
+    messages::execution::{CancelOrder, SubmitOrder, TradingCommand},
+    msgbus::{
+        self,
+        handler::{ShareableMessageHandler, TypedMessageHandler},
+        switchboard::{MessagingSwitchboard, get_event_orders_topic},
+    },
+};
+use ant_core::{UUID4, UnixNanos};
+use ant_model::{
+    enums::TimeInForce,
+    events::{
+        OrderAccepted, OrderCanceled, OrderDenied, OrderEventAny, OrderExpired, OrderFilled,
+        OrderRejected, OrderUpdated,
+    },
+    identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, StrategyId},
+    orders::{LimitOrder, MarketOrder, Order, OrderAny, OrderList},
+    types::{Price, Quantity},
+};
+use ustr::Ustr;
+
+pub use self::{
+    config::ExecAlgorithmConfig, core::ExecAlgorithmCore, iceberg::IcebergExecAlgorithm,
+    twap::TwapExecAlgorithm, vwap::VwapExecAlgorithm,
+};
+
+/// Core trait for implementing execution algorithms.
+///
+/// Implementors hold an [`ExecAlgorithmCore`] and dereference to its [`DataActorCore`](ant_common::actor::DataActorCore),
+/// so all data subscription and request functionality of a [`DataActor`] is available.
+/// Primary orders are passed to [`ExecAlgorithm::on_order`], and events for the orders of the
+/// algorithm are dispatched to the `on_order_*` callbacks while the algorithm is running.
+pub trait ExecAlgorithm: DataActor {
+    /// Returns a reference to the execution algorithm core.
+    fn core(&self) -> &ExecAlgorithmCore;
+
+    /// Returns a mutable reference to the execution algorithm core.
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore;
+
+    /// Returns the execution algorithm ID.
+    fn exec_algorithm_id(&self) -> ExecAlgorithmId {
+        self.core().exec_algorithm_id()
+    }
+
+    /// Actions to be performed when a primary order is received for execution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order cannot be worked by the algorithm.
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()>;
+
+    /// Actions to be performed when a primary order list is received for execution.
+    ///
+    /// By default each order of the list is passed to [`ExecAlgorithm::on_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any order cannot be worked by the algorithm.
+    fn on_order_list(&mut self, order_list: OrderList) -> anyhow::Result<()> {
+        for order in order_list.orders {
+            self.on_order(order)?;
+        }
+        Ok(())
+    }
+
+    // -- ORDER EVENT CALLBACKS -------------------------------------------------------------------
+
+    /// Actions to be performed when receiving any event for an order of the algorithm.
+    ///
+    /// Called after the specific order event callback.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_event(&mut self, event: &OrderEventAny) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is denied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_denied(&mut self, event: &OrderDenied) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_accepted(&mut self, event: &OrderAccepted) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_rejected(&mut self, event: &OrderRejected) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is canceled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_canceled(&mut self, event: &OrderCanceled) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_expired(&mut self, event: &OrderExpired) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_filled(&mut self, event: &OrderFilled) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    // -- HANDLERS --------------------------------------------------------------------------------
+
+    /// Handles a trading command sent to the algorithm.
+    ///
+    /// Submitted orders are added to the cache if required, and the algorithm subscribes to the
+    /// order events of the submitting strategy before the order is passed to
+    /// [`ExecAlgorithm::on_order`]. Canceling a primary order cancels all of its working
+    /// spawned orders.
+    fn handle_command(&mut self, command: &TradingCommand)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        if self.core().config.log_commands {
+            log::info!("{RECV}{CMD} {command}");
+        }
+
+        if self.not_running() {
+            log::warn!("Received command when not running - skipping {command}");
+            return;
+        }
+
+        let result = match command {
+            TradingCommand::SubmitOrder(command) => {
+                self.subscribe_strategy_orders(command.strategy_id);
+                cache_primary_order(self, command)
+                    .and_then(|()| self.on_order(command.order.clone()))
+            }
+            TradingCommand::SubmitOrderList(command) => {
+                self.subscribe_strategy_orders(command.strategy_id);
+                self.on_order_list(command.order_list.clone())
+            }
+            TradingCommand::CancelOrder(command) => self.handle_cancel_order(command),
+            _ => {
+                log::error!("Cannot handle command: unrecognized {command}");
+                Ok(())
+            }
+        };
+
+        if let Err(e) = result {
+            log::error!("{e}");
+        }
+    }
+
+    /// Handles a received order event, dispatching events for orders of the algorithm to the
+    /// relevant callbacks.
+    ///
+    /// If a spawned order closes without being completely filled then its unfilled quantity is
+    /// restored to the primary order, provided the primary is still held by the algorithm.
+    fn handle_order_event(&mut self, event: &OrderEventAny) {
+        let Some(order) = self.cache().order(&event.client_order_id()).cloned() else {
+            return;
+        };
+
+        if order.exec_algorithm_id() != Some(self.exec_algorithm_id()) {
+            return; // Not an order of this algorithm
+        }
+
+        if self.core().config.log_events {
+            log::info!("{RECV}{EVT} {event:?}");
+        }
+
+        if self.not_running() {
+            log::warn!("Received order event when not running - skipping {event:?}");
+            return;
+        }
+
+        if matches!(
+            event,
+            OrderEventAny::Denied(_)
+                | OrderEventAny::Rejected(_)
+                | OrderEventAny::Canceled(_)
+                | OrderEventAny::Expired(_)
+        ) && let Err(e) = restore_primary_quantity(self, &order)
+        {
+            log::error!("{e}");
+        }
+
+        let result = match event {
+            OrderEventAny::Denied(e) => self.on_order_denied(e),
+            OrderEventAny::Accepted(e) => self.on_order_accepted(e),
+            OrderEventAny::Rejected(e) => self.on_order_rejected(e),
+            OrderEventAny::Canceled(e) => self.on_order_canceled(e),
+            OrderEventAny::Expired(e) => self.on_order_expired(e),
+            OrderEventAny::Filled(e) => self.on_order_filled(e),
+            _ => Ok(()),
+        };
+
+        if let Err(e) = result.and_then(|()| self.on_order_event(event)) {
+            log::error!("{e}");
+        }
+    }
+
+    /// Subscribes the algorithm to the order events of the `strategy_id` (if not already
+    /// subscribed).
+    fn subscribe_strategy_orders(&mut self, strategy_id: StrategyId)
+    where
+        Self: 'static + Debug + Sized,
+    {
+        if !self.core_mut().add_subscribed_strategy(strategy_id) {
+            return;
+        }
+
+        let actor_id = self.actor_id().inner();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &OrderEventAny| {
+                if let Some(algorithm) = try_get_actor_unchecked::<Self>(&actor_id) {
+                    algorithm.handle_order_event(event);
+                } else {
+                    log::error!(
+                        "Execution algorithm {actor_id} not found for order event handling"
+                    );
+                }
+            },
+        )));
+        msgbus::subscribe_topic(get_event_orders_topic(strategy_id), handler, None);
+    }
+
+    /// Handles a command to cancel a primary or spawned order of the algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a cancel command for a spawned order cannot be created.
+    fn handle_cancel_order(&mut self, command: &CancelOrder) -> anyhow::Result<()> {
+        let Some(order) = self.cache().order(&command.client_order_id).cloned() else {
+            log::error!(
+                "Cannot cancel order: {} not found in the cache",
+                command.client_order_id
+            );
+            return Ok(());
+        };
+
+        if !order.is_primary() {
+            send_exec_command(self, TradingCommand::CancelOrder(command.clone()));
+            return Ok(());
+        }
+
+        let spawned: Vec<OrderAny> = self
+            .cache()
+            .orders_for_exec_spawn(&order.client_order_id())
+            .into_iter()
+            .filter(|o| o.client_order_id() != order.client_order_id() && !o.is_closed())
+            .cloned()
+            .collect();
+
+        for spawned_order in &spawned {
+            self.cancel_order(spawned_order)?;
+        }
+
+        if order.is_active_local() {
+            // The primary is still held by the algorithm, so it is canceled locally
+            let ts_now = self.timestamp_ns();
+            let event = OrderCanceled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                UUID4::new(),
+                ts_now,
+                ts_now,
+                false,
+                order.venue_order_id(),
+                order.account_id(),
+            );
+            send_exec_event(self, OrderEventAny::Canceled(event));
+        } else if !order.is_closed() {
+            send_exec_command(self, TradingCommand::CancelOrder(command.clone()));
+        }
+
+        Ok(())
+    }
+
+    // -- SPAWNING --------------------------------------------------------------------------------
+
+    /// Spawns a market order from the `primary` order for the `quantity`.
+    ///
+    /// If `reduce_primary` is `true` then the `quantity` is deducted from the primary order,
+    /// which must retain a positive quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary order does not belong to the algorithm, or the
+    /// `quantity` cannot be deducted from it.
+    fn spawn_market(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+        time_in_force: TimeInForce,
+        reduce_only: bool,
+        tags: Option<Vec<Ustr>>,
+        reduce_primary: bool,
+    ) -> anyhow::Result<OrderAny> {
+        let client_order_id = prepare_spawn(self, primary, quantity, reduce_primary)?;
+
+        let order = MarketOrder::new_checked(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            client_order_id,
+            primary.order_side(),
+            quantity,
+            time_in_force,
+            UUID4::new(),
+            self.timestamp_ns(),
+            reduce_only,
+            primary.is_quote_quantity(),
+            primary.contingency_type(),
+            primary.order_list_id(),
+            primary.linked_order_ids().map(<[ClientOrderId]>::to_vec),
+            primary.parent_order_id(),
+            primary.exec_algorithm_id(),
+            primary.exec_algorithm_params().cloned(),
+            Some(primary.client_order_id()),
+            tags.or_else(|| primary.tags().map(<[Ustr]>::to_vec)),
+        )?;
+
+        Ok(OrderAny::Market(order))
+    }
+
+    /// Spawns a limit order from the `primary` order for the `quantity` at the `price`.
+    ///
+    /// If `reduce_primary` is `true` then the `quantity` is deducted from the primary order,
+    /// which must retain a positive quantity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the primary order does not belong to the algorithm, the `quantity`
+    /// cannot be deducted from it, or the limit order parameters are invalid.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_limit(
+        &mut self,
+        primary: &mut OrderAny,
+        quantity: Quantity,
+        price: Price,
+        time_in_force: TimeInForce,
+        expire_time: Option<UnixNanos>,
+        post_only: bool,
+        reduce_only: bool,
+        display_qty: Option<Quantity>,
+        tags: Option<Vec<Ustr>>,
+        reduce_primary: bool,
+    ) -> anyhow::Result<OrderAny> {
+        let client_order_id = prepare_spawn(self, primary, quantity, reduce_primary)?;
+
+        let order = LimitOrder::new_checked(
+            primary.trader_id(),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            client_order_id,
+            primary.order_side(),
+            quantity,
+            price,
+            time_in_force,
+            expire_time,
+            post_only,
+            reduce_only,
+            primary.is_quote_quantity(),
+            display_qty,
+            None,
+            None,
+            primary.contingency_type(),
+            primary.order_list_id(),
+            primary.linked_order_ids().map(<[ClientOrderId]>::to_vec),
+            primary.parent_order_id(),
+            primary.exec_algorithm_id(),
+            primary.exec_algorithm_params().cloned(),
+            Some(primary.client_order_id()),
+            tags.or_else(|| primary.tags().map(<[Ustr]>::to_vec)),
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        Ok(OrderAny::Limit(order))
+    }
+
+    // -- TRADING COMMANDS ------------------------------------------------------------------------
+
+    /// Submits the `order` for execution through the risk engine.
+    ///
+    /// The order must be a primary order of the algorithm or an order spawned from one.
+    /// Spawned orders are added to the cache, and are routed with the client and position of
+    /// their primary order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order does not belong to the algorithm or cannot be added to the
+    /// cache.
+    fn submit_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        let exec_algorithm_id = self.exec_algorithm_id();
+        if order.exec_algorithm_id() != Some(exec_algorithm_id) {
+            anyhow::bail!(
+                "Order {} is not for execution algorithm {exec_algorithm_id}",
+                order.client_order_id()
+            );
+        }
+
+        let primary_id = order.exec_spawn_id().ok_or_else(|| {
+            anyhow::anyhow!("Order {} has no exec spawn ID", order.client_order_id())
+        })?;
+
+        let (client_id, position_id) = {
+            let cache = self.cache();
+            (
+                cache.client_id(&primary_id).copied(),
+                cache.position_id(&primary_id).copied(),
+            )
+        };
+        let client_id = client_id.unwrap_or_else(|| default_client_id(&order));
+
+        if order.is_secondary() {
+            self.cache_rc().borrow_mut().add_order(
+                order.clone(),
+                position_id,
+                Some(client_id),
+                false,
+            )?;
+        }
+
+        let command = SubmitOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            order.clone(),
+            order.exec_algorithm_id(),
+            position_id,
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        send_risk_command(self, TradingCommand::SubmitOrder(command));
+        Ok(())
+    }
+
+    /// Cancels the working `order` through the execution engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cancel command cannot be created.
+    fn cancel_order(&mut self, order: &OrderAny) -> anyhow::Result<()> {
+        if order.is_closed() || order.is_pending_cancel() {
+            log::warn!(
+                "Cannot cancel order: state is {}, {order:?}",
+                order.status()
+            );
+            return Ok(());
+        }
+
+        let client_id = self
+            .cache()
+            .client_id(&order.client_order_id())
+            .copied()
+            .unwrap_or_else(|| default_client_id(order));
+
+        let command = CancelOrder::new(
+            order.trader_id(),
+            client_id,
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        send_exec_command(self, TradingCommand::CancelOrder(command));
+        Ok(())
+    }
+}
+
+/// Adds the primary order of a submit `command` to the cache if it does not already exist.
+fn cache_primary_order<T: ExecAlgorithm + ?Sized>(
+    algorithm: &T,
+    command: &SubmitOrder,
+) -> anyhow::Result<()> {
+    let cache_rc = algorithm.cache_rc();
+    let mut cache = cache_rc.borrow_mut();
+    if !cache.order_exists(&command.client_order_id) {
+        cache.add_order(
+            command.order.clone(),
+            command.position_id,
+            Some(command.client_id),
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+/// Validates a spawn from the `primary` order and returns the spawned client order ID.
+fn prepare_spawn<T: ExecAlgorithm + ?Sized>(
+    algorithm: &mut T,
+    primary: &mut OrderAny,
+    quantity: Quantity,
+    reduce_primary: bool,
+) -> anyhow::Result<ClientOrderId> {
+    let exec_algorithm_id = algorithm.exec_algorithm_id();
+    if primary.exec_algorithm_id() != Some(exec_algorithm_id) || !primary.is_primary() {
+        anyhow::bail!(
+            "Cannot spawn from {}: not a primary order for execution algorithm {exec_algorithm_id}",
+            primary.client_order_id()
+        );
+    }
+
+    if !quantity.is_positive() {
+        anyhow::bail!("Cannot spawn order: quantity {quantity} was not positive");
+    }
+
+    if reduce_primary {
+        if quantity >= primary.quantity() {
+            anyhow::bail!(
+                "Cannot spawn order: quantity {quantity} must be less than the primary quantity {}",
+                primary.quantity()
+            );
+        }
+        let new_quantity = primary.quantity() - quantity;
+        update_primary_quantity(algorithm, primary, new_quantity)?;
+    }
+
+    Ok(algorithm
+        .core_mut()
+        .next_spawn_id(&primary.client_order_id()))
+}
+
+/// Applies an update of the `primary` order to the `quantity`, and updates the cache.
+fn update_primary_quantity<T: ExecAlgorithm + ?Sized>(
+    algorithm: &T,
+    primary: &mut OrderAny,
+    quantity: Quantity,
+) -> anyhow::Result<()> {
+    let ts_now = algorithm.timestamp_ns();
+    let event = OrderUpdated::new(
+        primary.trader_id(),
+        primary.strategy_id(),
+        primary.instrument_id(),
+        primary.client_order_id(),
+        quantity,
+        UUID4::new(),
+        ts_now,
+        ts_now,
+        false,
+        primary.venue_order_id(),
+        primary.account_id(),
+        None,
+        None,
+    );
+    primary.apply(OrderEventAny::Updated(event))?;
+    algorithm.cache_rc().borrow_mut().update_order(primary)
+}
+
+/// Restores the unfilled quantity of the closed spawned `order` to its primary order.
+fn restore_primary_quantity<T: ExecAlgorithm + ?Sized>(
+    algorithm: &T,
+    order: &OrderAny,
+) -> anyhow::Result<()> {
+    if !order.is_secondary() || order.leaves_qty().is_zero() {
+        return Ok(());
+    }
+
+    // SAFETY: Secondary orders always have an exec spawn ID
+    let primary_id = order.exec_spawn_id().unwrap();
+    let Some(mut primary) = algorithm.cache().order(&primary_id).cloned() else {
+        anyhow::bail!("Cannot restore primary quantity: {primary_id} not found in the cache");
+    };
+
+    if !primary.is_active_local() {
+        return Ok(()); // Primary was already released or closed
+    }
+
+    let quantity = primary.quantity() + order.leaves_qty();
+    update_primary_quantity(algorithm, &mut primary, quantity)
+}
+
+/// Parses the execution algorithm parameter `key` of the `order` (if present).
+fn order_param<T>(order: &OrderAny, key: &str) -> anyhow::Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let Some(value) = order
+        .exec_algorithm_params()
+        .and_then(|params| params.get(&Ustr::from(key)))
+    else {
+        return Ok(None);
+    };
+
+    value.as_str().parse::<T>().map(Some).map_err(|e| {
+        anyhow::anyhow!(
+            "Invalid exec algorithm parameter '{key}={value}' for {}: {e}",
+            order.client_order_id()
+        )
+    })
+}
+
+fn default_client_id(order: &OrderAny) -> ClientId {
+    ClientId::from(order.instrument_id().venue.as_str())
+}
+
+fn send_risk_command<T: ExecAlgorithm + ?Sized>(algorithm: &T, command: TradingCommand) {
+    if algorithm.core().config.log_commands {
+        log::info!("{CMD}{SEND} {command}");
+    }
+    msgbus::send_any(MessagingSwitchboard::risk_engine_execute(), &command);
+}
+
+fn send_exec_command<T: ExecAlgorithm + ?Sized>(algorithm: &T, command: TradingCommand) {
+    if algorithm.core().config.log_commands {
+        log::info!("{CMD}{SEND} {command}");
+    }
+    msgbus::send_any(MessagingSwitchboard::exec_engine_execute(), &command);
+}
+
+fn send_exec_event<T: ExecAlgorithm + ?Sized>(algorithm: &T, event: OrderEventAny) {
+    if algorithm.core().config.log_events {
+        log::info!("{EVT}{SEND} {event}");
+    }
+    msgbus::send_any(MessagingSwitchboard::exec_engine_process(), &event);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        ops::{Deref, DerefMut},
+        rc::Rc,
+    };
+
+    use ant_common::{
+        actor::{DataActor, DataActorCore},
+        cache::Cache,
+        clock::TestClock,
+        component::Component,
+        messages::execution::{CancelOrder, SubmitOrder, TradingCommand},
+        msgbus::{
+            self,
+            stubs::{get_message_saving_handler, get_saved_messages},
+            switchboard::MessagingSwitchboard,
+        },
+    };
+    use ant_core::{UUID4, UnixNanos};
+    use ant_model::{
+        enums::{OrderStatus, OrderType, TimeInForce},
+        events::{OrderDenied, OrderEventAny},
+        identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, TraderId},
+        orders::{Order, OrderAny, OrderTestBuilder},
+        types::Quantity,
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{ExecAlgorithm, ExecAlgorithmConfig, ExecAlgorithmCore};
+
+    #[derive(Debug)]
+    struct TestExecAlgorithm {
+        core: ExecAlgorithmCore,
+        orders: Vec<OrderAny>,
+    }
+
+    impl Deref for TestExecAlgorithm {
+        type Target = DataActorCore;
+        fn deref(&self) -> &Self::Target {
+            &self.core
+        }
+    }
+
+    impl DerefMut for TestExecAlgorithm {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.core
+        }
+    }
+
+    impl DataActor for TestExecAlgorithm {}
+
+    impl ExecAlgorithm for TestExecAlgorithm {
+        fn core(&self) -> &ExecAlgorithmCore {
+            &self.core
+        }
+
+        fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+            &mut self.core
+        }
+
+        fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+            self.orders.push(order);
+            Ok(())
+        }
+    }
+
+    #[fixture]
+    fn cache() -> Rc<RefCell<Cache>> {
+        Rc::new(RefCell::new(Cache::new(None, None)))
+    }
+
+    #[fixture]
+    fn algorithm(cache: Rc<RefCell<Cache>>) -> TestExecAlgorithm {
+        let core = ExecAlgorithmCore::new(
+            ExecAlgorithmConfig::default(),
+            ExecAlgorithmId::from("TEST"),
+        );
+        let mut algorithm = TestExecAlgorithm {
+            core,
+            orders: Vec::new(),
+        };
+        algorithm
+            .register(
+                TraderId::from("TRADER-001"),
+                Rc::new(RefCell::new(TestClock::new())),
+                cache,
+            )
+            .unwrap();
+        algorithm.start().unwrap();
+        algorithm
+    }
+
+    fn primary_order(quantity: &str) -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-001");
+        OrderTestBuilder::new(OrderType::Market)
+            .trader_id(TraderId::from("TRADER-001"))
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(client_order_id)
+            .quantity(Quantity::from(quantity))
+            .exec_algorithm_id(ExecAlgorithmId::from("TEST"))
+            .exec_spawn_id(client_order_id)
+            .build()
+    }
+
+    fn submit_command(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                order.venue_order_id().unwrap_or_default(),
+                order.clone(),
+                order.exec_algorithm_id(),
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[rstest]
+    fn test_handle_submit_order_caches_primary_and_calls_on_order(
+        mut algorithm: TestExecAlgorithm,
+    ) {
+        let primary = primary_order("10");
+        algorithm.handle_command(&submit_command(&primary));
+
+        assert_eq!(algorithm.orders.len(), 1);
+        assert_eq!(
+            algorithm.orders[0].client_order_id(),
+            primary.client_order_id()
+        );
+        assert!(algorithm.cache().order_exists(&primary.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_handle_submit_order_when_not_running_is_skipped(cache: Rc<RefCell<Cache>>) {
+        let mut algorithm = TestExecAlgorithm {
+            core: ExecAlgorithmCore::new(
+                ExecAlgorithmConfig::default(),
+                ExecAlgorithmId::from("TEST"),
+            ),
+            orders: Vec::new(),
+        };
+        algorithm
+            .register(
+                TraderId::from("TRADER-001"),
+                Rc::new(RefCell::new(TestClock::new())),
+                cache,
+            )
+            .unwrap();
+
+        algorithm.handle_command(&submit_command(&primary_order("10")));
+
+        assert!(algorithm.orders.is_empty());
+    }
+
+    #[rstest]
+    fn test_spawn_market_reduces_primary_quantity(mut algorithm: TestExecAlgorithm) {
+        let mut primary = primary_order("10");
+        algorithm.handle_command(&submit_command(&primary));
+
+        let spawned = algorithm
+            .spawn_market(
+                &mut primary,
+                Quantity::from("3"),
+                TimeInForce::Gtc,
+                false,
+                None,
+                true,
+            )
+            .unwrap();
+
+        assert_eq!(spawned.client_order_id(), ClientOrderId::from("O-001-E1"));
+        assert_eq!(spawned.exec_spawn_id(), Some(primary.client_order_id()));
+        assert_eq!(spawned.quantity(), Quantity::from("3"));
+        assert!(spawned.is_secondary());
+        assert_eq!(primary.quantity(), Quantity::from("7"));
+        assert_eq!(primary.status(), OrderStatus::Initialized);
+        let cached = algorithm.cache().order(&primary.client_order_id()).cloned();
+        assert_eq!(cached.unwrap().quantity(), Quantity::from("7"));
+    }
+
+    #[rstest]
+    fn test_spawn_market_for_full_primary_quantity_returns_error(mut algorithm: TestExecAlgorithm) {
+        let mut primary = primary_order("10");
+        algorithm.handle_command(&submit_command(&primary));
+
+        let result = algorithm.spawn_market(
+            &mut primary,
+            Quantity::from("10"),
+            TimeInForce::Gtc,
+            false,
+            None,
+            true,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(primary.quantity(), Quantity::from("10"));
+    }
+
+    #[rstest]
+    fn test_spawn_market_from_other_algorithm_order_returns_error(
+        mut algorithm: TestExecAlgorithm,
+    ) {
+        let mut order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .quantity(Quantity::from("10"))
+            .build();
+
+        let result = algorithm.spawn_market(
+            &mut order,
+            Quantity::from("3"),
+            TimeInForce::Gtc,
+            false,
+            None,
+            true,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_submit_spawned_order_caches_and_sends_to_risk_engine(mut algorithm: TestExecAlgorithm) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let mut primary = primary_order("10");
+        algorithm.handle_command(&submit_command(&primary));
+        let spawned = algorithm
+            .spawn_market(
+                &mut primary,
+                Quantity::from("3"),
+                TimeInForce::Gtc,
+                false,
+                None,
+                true,
+            )
+            .unwrap();
+        let spawned_id = spawned.client_order_id();
+        algorithm.submit_order(spawned).unwrap();
+
+        let messages = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(messages.len(), 1);
+        match &messages[0] {
+            TradingCommand::SubmitOrder(command) => {
+                assert_eq!(command.client_order_id, spawned_id);
+                assert_eq!(command.client_id, ClientId::from("SIM"));
+                assert_eq!(
+                    command.exec_algorith_id,
+                    Some(ExecAlgorithmId::from("TEST"))
+                );
+            }
+            other => panic!("Unexpected command {other}"),
+        }
+        assert!(algorithm.cache().order_exists(&spawned_id));
+    }
+
+    #[rstest]
+    fn test_spawned_order_denied_restores_primary_quantity(mut algorithm: TestExecAlgorithm) {
+        let mut primary = primary_order("10");
+        algorithm.handle_command(&submit_command(&primary));
+        let spawned = algorithm
+            .spawn_market(
+                &mut primary,
+                Quantity::from("3"),
+                TimeInForce::Gtc,
+                false,
+                None,
+                true,
+            )
+            .unwrap();
+        algorithm.submit_order(spawned.clone()).unwrap();
+
+        let denied = OrderDenied::new(
+            spawned.trader_id(),
+            spawned.strategy_id(),
+            spawned.instrument_id(),
+            spawned.client_order_id(),
+            Ustr::from("Exceeded MAX_NOTIONAL_PER_ORDER"),
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        algorithm.handle_order_event(&OrderEventAny::Denied(denied));
+
+        let cached = algorithm.cache().order(&primary.client_order_id()).cloned();
+        assert_eq!(cached.unwrap().quantity(), Quantity::from("10"));
+    }
+
+    #[rstest]
+    fn test_cancel_primary_cancels_spawned_orders_and_primary(mut algorithm: TestExecAlgorithm) {
+        let command_handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(
+            MessagingSwitchboard::exec_engine_execute(),
+            command_handler.clone(),
+        );
+        let event_handler = get_message_saving_handler::<OrderEventAny>(None);
+        msgbus::register(
+            MessagingSwitchboard::exec_engine_process(),
+            event_handler.clone(),
+        );
+
+        let mut primary = primary_order("10");
+        algorithm.handle_command(&submit_command(&primary));
+        let spawned = algorithm
+            .spawn_market(
+                &mut primary,
+                Quantity::from("3"),
+                TimeInForce::Gtc,
+                false,
+                None,
+                true,
+            )
+            .unwrap();
+        let spawned_id = spawned.client_order_id();
+        algorithm.submit_order(spawned).unwrap();
+
+        let cancel = CancelOrder::new(
+            primary.trader_id(),
+            ClientId::from("SIM"),
+            primary.strategy_id(),
+            primary.instrument_id(),
+            primary.client_order_id(),
+            primary.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            UnixNanos::default(),
+        )
+        .unwrap();
+        algorithm.handle_command(&TradingCommand::CancelOrder(cancel));
+
+        let commands = get_saved_messages::<TradingCommand>(command_handler);
+        assert_eq!(commands.len(), 1);
+        match &commands[0] {
+            TradingCommand::CancelOrder(command) => {
+                assert_eq!(command.client_order_id, spawned_id);
+            }
+            other => panic!("Unexpected command {other}"),
+        }
+        let events = get_saved_messages::<OrderEventAny>(event_handler);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], OrderEventAny::Canceled(e)
+            if e.client_order_id == primary.client_order_id()));
+    }
+}
@@ -0,0 +1,274 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Scheduled slicing of primary orders, shared by the time based execution algorithms.
+
+use std::collections::VecDeque;
+
+use ant_model::{
+    enums::OrderType,
+    identifiers::ClientOrderId,
+    orders::{Order, OrderAny},
+    types::Quantity,
+};
+
+use super::ExecAlgorithm;
+
+/// A schedule of the child order quantities with which a primary order is worked.
+///
+/// Each slice is executed on consecutive timer intervals, with the final slice executed by
+/// submitting the primary order with its remaining quantity.
+#[derive(Debug, Clone)]
+pub(crate) struct SliceSchedule {
+    primary_id: ClientOrderId,
+    slices: VecDeque<Quantity>,
+}
+
+impl SliceSchedule {
+    /// Creates a new [`SliceSchedule`] which splits the `quantity` in proportion to the
+    /// `weights`.
+    ///
+    /// Slices are rounded to the precision of the `quantity`, carrying any rounding into later
+    /// slices so that the slices always sum to the `quantity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `weights` is empty, contains a negative or non-finite weight, or
+    /// does not sum to a positive value.
+    pub(crate) fn new(
+        primary_id: ClientOrderId,
+        quantity: Quantity,
+        weights: &[f64],
+    ) -> anyhow::Result<Self> {
+        if weights.is_empty() {
+            anyhow::bail!("Cannot create schedule for {primary_id}: no weights");
+        }
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            anyhow::bail!("Cannot create schedule for {primary_id}: invalid weights {weights:?}");
+        }
+
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            anyhow::bail!("Cannot create schedule for {primary_id}: weights sum to zero");
+        }
+
+        let precision = quantity.precision;
+        let mut slices = VecDeque::with_capacity(weights.len());
+        let mut allocated = Quantity::zero(precision);
+        let mut cumulative_weight = 0.0;
+
+        for (i, weight) in weights.iter().enumerate() {
+            cumulative_weight += weight;
+            let target = if i == weights.len() - 1 {
+                quantity
+            } else {
+                let value = quantity.as_f64() * cumulative_weight / total_weight;
+                Quantity::new(value.min(quantity.as_f64()), precision).max(allocated)
+            };
+            slices.push_back(target - allocated);
+            allocated = target;
+        }
+
+        Ok(Self { primary_id, slices })
+    }
+}
+
+/// Executes the next slice of the `schedule` for the `algorithm`.
+///
+/// A slice is spawned as an order of the same type as the primary order (market or limit),
+/// while the final slice submits the primary order itself. Zero quantity slices are skipped.
+///
+/// Returns `true` once the schedule is complete, which is also the case if the primary order
+/// is no longer held by the algorithm (e.g. it was canceled).
+///
+/// # Errors
+///
+/// Returns an error if the primary order is not found, has an unsupported order type, or the
+/// slice cannot be spawned or submitted.
+pub(crate) fn execute_next_slice<T: ExecAlgorithm + ?Sized>(
+    algorithm: &mut T,
+    schedule: &mut SliceSchedule,
+) -> anyhow::Result<bool> {
+    let primary_id = schedule.primary_id;
+    let Some(mut primary) = algorithm.cache().order(&primary_id).cloned() else {
+        anyhow::bail!("Cannot execute slice: primary order {primary_id} not found in the cache");
+    };
+
+    if !primary.is_active_local() {
+        log::info!("Primary order {primary_id} no longer active locally, schedule complete");
+        return Ok(true);
+    }
+
+    let Some(quantity) = schedule.slices.pop_front() else {
+        return Ok(true);
+    };
+
+    if schedule.slices.is_empty() || quantity >= primary.quantity() {
+        algorithm.submit_order(primary)?;
+        return Ok(true);
+    }
+
+    if quantity.is_zero() {
+        return Ok(false);
+    }
+
+    let time_in_force = primary.time_in_force();
+    let reduce_only = primary.is_reduce_only();
+    let spawned = match primary.order_type() {
+        OrderType::Market => algorithm.spawn_market(
+            &mut primary,
+            quantity,
+            time_in_force,
+            reduce_only,
+            None,
+            true,
+        )?,
+        OrderType::Limit => {
+            // SAFETY: Limit orders always have a price
+            let price = primary.price().unwrap();
+            let expire_time = primary.expire_time();
+            let post_only = primary.is_post_only();
+            algorithm.spawn_limit(
+                &mut primary,
+                quantity,
+                price,
+                time_in_force,
+                expire_time,
+                post_only,
+                reduce_only,
+                None,
+                None,
+                true,
+            )?
+        }
+        order_type => anyhow::bail!("Cannot execute slice for {order_type} order {primary_id}"),
+    };
+
+    algorithm.submit_order(spawned)?;
+    Ok(false)
+}
+
+/// Starts the `schedule` for the `algorithm` by executing the first slice immediately, then
+/// setting a timer (named by the primary client order ID) to execute the remaining slices
+/// every `interval_ns`.
+///
+/// Returns the schedule if it is not yet complete.
+///
+/// # Errors
+///
+/// Returns an error if the first slice cannot be executed or the timer cannot be set.
+pub(crate) fn start_schedule<T: ExecAlgorithm + ?Sized>(
+    algorithm: &mut T,
+    mut schedule: SliceSchedule,
+    interval_ns: u64,
+) -> anyhow::Result<Option<SliceSchedule>> {
+    if execute_next_slice(algorithm, &mut schedule)? {
+        return Ok(None);
+    }
+
+    let ts_now = algorithm.timestamp_ns();
+    algorithm.clock().set_timer_ns(
+        schedule.primary_id.as_str(),
+        interval_ns,
+        Some(ts_now),
+        None,
+        None,
+        None,
+        Some(false),
+    )?;
+
+    Ok(Some(schedule))
+}
+
+/// Advances the `schedule` for the `algorithm` on its timer firing, canceling the timer once
+/// the schedule is complete or fails.
+///
+/// Returns the schedule if it is not yet complete.
+///
+/// # Errors
+///
+/// Returns an error if the slice cannot be executed.
+pub(crate) fn advance_schedule<T: ExecAlgorithm + ?Sized>(
+    algorithm: &mut T,
+    mut schedule: SliceSchedule,
+) -> anyhow::Result<Option<SliceSchedule>> {
+    let result = execute_next_slice(algorithm, &mut schedule);
+    if matches!(result, Ok(false)) {
+        return Ok(Some(schedule));
+    }
+
+    algorithm.clock().cancel_timer(schedule.primary_id.as_str());
+    result.map(|_| None)
+}
+
+/// Validates that the `order` has an order type which can be sliced by a schedule.
+///
+/// # Errors
+///
+/// Returns an error if the order is not a market or limit order.
+pub(crate) fn check_sliceable(order: &OrderAny) -> anyhow::Result<()> {
+    match order.order_type() {
+        OrderType::Market | OrderType::Limit => Ok(()),
+        order_type => anyhow::bail!(
+            "Cannot slice {order_type} order {}: only market and limit orders are supported",
+            order.client_order_id()
+        ),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ant_model::{identifiers::ClientOrderId, types::Quantity};
+    use rstest::rstest;
+
+    use super::SliceSchedule;
+
+    #[rstest]
+    #[case(&[1.0, 1.0, 1.0], "10", &["3", "4", "3"])]
+    #[case(&[1.0, 1.0], "10", &["5", "5"])]
+    #[case(&[0.1, 0.2, 0.3, 0.4], "100", &["10", "20", "30", "40"])]
+    #[case(&[0.0, 1.0, 0.0], "7", &["0", "7", "0"])]
+    #[case(&[1.0, 1.0, 1.0], "1.00", &["0.33", "0.34", "0.33"])]
+    fn test_slice_schedule_splits_quantity(
+        #[case] weights: &[f64],
+        #[case] quantity: &str,
+        #[case] expected: &[&str],
+    ) {
+        let schedule = SliceSchedule::new(
+            ClientOrderId::from("O-001"),
+            Quantity::from(quantity),
+            weights,
+        )
+        .unwrap();
+
+        let expected: Vec<Quantity> = expected.iter().map(|q| Quantity::from(*q)).collect();
+        assert_eq!(Vec::from(schedule.slices), expected);
+    }
+
+    #[rstest]
+    #[case(&[])]
+    #[case(&[0.0, 0.0])]
+    #[case(&[1.0, -1.0])]
+    #[case(&[f64::NAN])]
+    fn test_slice_schedule_invalid_weights(#[case] weights: &[f64]) {
+        let result =
+            SliceSchedule::new(ClientOrderId::from("O-001"), Quantity::from("10"), weights);
+
+        assert!(result.is_err());
+    }
+}
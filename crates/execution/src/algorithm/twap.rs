@@ -0,0 +1,346 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Time-weighted average price (TWAP) execution algorithm.
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use ant_common::{
+    actor::{DataActor, DataActorCore},
+    timer::TimeEvent,
+};
+use ant_core::datetime::secs_to_nanos;
+use ant_model::{
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::{Order, OrderAny},
+};
+
+use super::{
+    ExecAlgorithm, ExecAlgorithmConfig, ExecAlgorithmCore, order_param,
+    schedule::{SliceSchedule, advance_schedule, check_sliceable, start_schedule},
+};
+
+/// Execution algorithm which works a primary order in equal slices over a time horizon.
+///
+/// Orders are configured with the execution algorithm parameters:
+/// - `horizon_secs`: the time horizon over which the order is executed.
+/// - `interval_secs`: the interval between slices.
+///
+/// The order quantity is split into `horizon_secs / interval_secs` (rounded down) slices.
+/// The first slice is executed on receipt of the order, and the final slice submits the
+/// primary order with its remaining quantity. Market and limit primary orders are supported,
+/// with limit slices placed at the primary order price.
+#[derive(Debug)]
+pub struct TwapExecAlgorithm {
+    core: ExecAlgorithmCore,
+    schedules: HashMap<ClientOrderId, SliceSchedule>,
+}
+
+impl TwapExecAlgorithm {
+    /// Creates a new [`TwapExecAlgorithm`] instance.
+    ///
+    /// The algorithm ID defaults to `TWAP`.
+    #[must_use]
+    pub fn new(config: ExecAlgorithmConfig) -> Self {
+        Self {
+            core: ExecAlgorithmCore::new(config, ExecAlgorithmId::from("TWAP")),
+            schedules: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the primary order with the `client_order_id` is being worked.
+    #[must_use]
+    pub fn is_scheduled(&self, client_order_id: &ClientOrderId) -> bool {
+        self.schedules.contains_key(client_order_id)
+    }
+}
+
+impl Deref for TwapExecAlgorithm {
+    type Target = DataActorCore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for TwapExecAlgorithm {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl DataActor for TwapExecAlgorithm {
+    fn on_start(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn on_stop(&mut self) -> anyhow::Result<()> {
+        for primary_id in self.schedules.keys() {
+            log::warn!("Stopped working primary order {primary_id} before completion");
+        }
+        self.schedules.clear();
+        self.clock().cancel_timers();
+        Ok(())
+    }
+
+    fn on_reset(&mut self) -> anyhow::Result<()> {
+        self.schedules.clear();
+        self.core.reset();
+        Ok(())
+    }
+
+    fn on_time_event(&mut self, event: &TimeEvent) -> anyhow::Result<()> {
+        let primary_id = ClientOrderId::from(event.name.as_str());
+        let Some(schedule) = self.schedules.remove(&primary_id) else {
+            log::warn!("No schedule for timer {}", event.name);
+            self.clock().cancel_timer(event.name.as_str());
+            return Ok(());
+        };
+
+        if let Some(schedule) = advance_schedule(self, schedule)? {
+            self.schedules.insert(primary_id, schedule);
+        }
+        Ok(())
+    }
+}
+
+impl ExecAlgorithm for TwapExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        check_sliceable(&order)?;
+
+        let client_order_id = order.client_order_id();
+        let horizon_secs: f64 = order_param(&order, "horizon_secs")?.ok_or_else(|| {
+            anyhow::anyhow!("Missing exec algorithm parameter 'horizon_secs' for {client_order_id}")
+        })?;
+        let interval_secs: f64 = order_param(&order, "interval_secs")?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Missing exec algorithm parameter 'interval_secs' for {client_order_id}"
+            )
+        })?;
+
+        if !(interval_secs > 0.0 && interval_secs <= horizon_secs && horizon_secs.is_finite()) {
+            anyhow::bail!(
+                "Invalid TWAP parameters for {client_order_id}: interval_secs={interval_secs} \
+                must be positive and not greater than horizon_secs={horizon_secs}"
+            );
+        }
+
+        let num_slices = (horizon_secs / interval_secs).floor() as usize;
+        let schedule =
+            SliceSchedule::new(client_order_id, order.quantity(), &vec![1.0; num_slices])?;
+
+        if let Some(schedule) = start_schedule(self, schedule, secs_to_nanos(interval_secs))? {
+            self.schedules.insert(client_order_id, schedule);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use ant_common::{
+        actor::DataActor,
+        cache::Cache,
+        clock::{Clock, TestClock},
+        component::Component,
+        messages::execution::{SubmitOrder, TradingCommand},
+        msgbus::{
+            self,
+            stubs::{get_message_saving_handler, get_saved_messages},
+            switchboard::MessagingSwitchboard,
+        },
+    };
+    use ant_core::{UUID4, UnixNanos, datetime::secs_to_nanos};
+    use ant_model::{
+        enums::OrderType,
+        identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, TraderId},
+        orders::{Order, OrderAny, OrderTestBuilder},
+        types::Quantity,
+    };
+    use indexmap::IndexMap;
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::TwapExecAlgorithm;
+    use crate::algorithm::{ExecAlgorithm, ExecAlgorithmConfig};
+
+    #[fixture]
+    fn clock() -> Rc<RefCell<TestClock>> {
+        Rc::new(RefCell::new(TestClock::new()))
+    }
+
+    #[fixture]
+    fn algorithm(clock: Rc<RefCell<TestClock>>) -> TwapExecAlgorithm {
+        create_algorithm(clock)
+    }
+
+    // The algorithm fixture receives its own clock, so tests advancing time share one explicitly
+    fn create_algorithm(clock: Rc<RefCell<TestClock>>) -> TwapExecAlgorithm {
+        let mut algorithm = TwapExecAlgorithm::new(ExecAlgorithmConfig::default());
+        algorithm
+            .register(
+                TraderId::from("TRADER-001"),
+                clock,
+                Rc::new(RefCell::new(Cache::new(None, None))),
+            )
+            .unwrap();
+        algorithm.start().unwrap();
+        algorithm
+    }
+
+    fn primary_order(quantity: &str, params: &[(&str, &str)]) -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-001");
+        let params: IndexMap<Ustr, Ustr> = params
+            .iter()
+            .map(|(key, value)| (Ustr::from(key), Ustr::from(value)))
+            .collect();
+        OrderTestBuilder::new(OrderType::Market)
+            .trader_id(TraderId::from("TRADER-001"))
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(client_order_id)
+            .quantity(Quantity::from(quantity))
+            .exec_algorithm_id(ExecAlgorithmId::from("TWAP"))
+            .exec_algorithm_params(params)
+            .exec_spawn_id(client_order_id)
+            .build()
+    }
+
+    fn submit_command(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                order.venue_order_id().unwrap_or_default(),
+                order.clone(),
+                order.exec_algorithm_id(),
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submitted_orders(commands: &[TradingCommand]) -> Vec<(String, Quantity)> {
+        commands
+            .iter()
+            .map(|command| match command {
+                TradingCommand::SubmitOrder(command) => (
+                    command.client_order_id.to_string(),
+                    command.order.quantity(),
+                ),
+                other => panic!("Unexpected command {other}"),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_twap_executes_slices_on_interval(clock: Rc<RefCell<TestClock>>) {
+        let mut algorithm = create_algorithm(clock.clone());
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let primary = primary_order("10", &[("horizon_secs", "3"), ("interval_secs", "1")]);
+        algorithm.handle_command(&submit_command(&primary));
+
+        assert!(algorithm.is_scheduled(&primary.client_order_id()));
+        assert_eq!(clock.borrow().timer_count(), 1);
+
+        let events = clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(secs_to_nanos(2.0)), true);
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            algorithm.on_time_event(event).unwrap();
+        }
+
+        let commands = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(
+            submitted_orders(&commands),
+            vec![
+                ("O-001-E1".to_string(), Quantity::from("3")),
+                ("O-001-E2".to_string(), Quantity::from("4")),
+                ("O-001".to_string(), Quantity::from("3")),
+            ]
+        );
+        assert!(!algorithm.is_scheduled(&primary.client_order_id()));
+        assert_eq!(clock.borrow().timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_twap_single_slice_submits_primary(clock: Rc<RefCell<TestClock>>) {
+        let mut algorithm = create_algorithm(clock.clone());
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let primary = primary_order("10", &[("horizon_secs", "1"), ("interval_secs", "1")]);
+        algorithm.handle_command(&submit_command(&primary));
+
+        let commands = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(
+            submitted_orders(&commands),
+            vec![("O-001".to_string(), Quantity::from("10"))]
+        );
+        assert!(!algorithm.is_scheduled(&primary.client_order_id()));
+        assert_eq!(clock.borrow().timer_count(), 0);
+    }
+
+    #[rstest]
+    #[case(&[("horizon_secs", "3")])]
+    #[case(&[("interval_secs", "1")])]
+    #[case(&[("horizon_secs", "1"), ("interval_secs", "3")])]
+    #[case(&[("horizon_secs", "3"), ("interval_secs", "0")])]
+    #[case(&[("horizon_secs", "three"), ("interval_secs", "1")])]
+    fn test_twap_invalid_params_returns_error(
+        mut algorithm: TwapExecAlgorithm,
+        #[case] params: &[(&str, &str)],
+    ) {
+        let primary = primary_order("10", params);
+
+        assert!(algorithm.on_order(primary.clone()).is_err());
+        assert!(!algorithm.is_scheduled(&primary.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_twap_stop_cancels_schedules(clock: Rc<RefCell<TestClock>>) {
+        let mut algorithm = create_algorithm(clock.clone());
+        let primary = primary_order("10", &[("horizon_secs", "3"), ("interval_secs", "1")]);
+        algorithm.handle_command(&submit_command(&primary));
+
+        algorithm.stop().unwrap();
+
+        assert!(!algorithm.is_scheduled(&primary.client_order_id()));
+        assert_eq!(clock.borrow().timer_count(), 0);
+    }
+}
@@ -0,0 +1,374 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Volume-weighted average price (VWAP) execution algorithm.
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use ant_common::{
+    actor::{DataActor, DataActorCore},
+    timer::TimeEvent,
+};
+use ant_core::datetime::secs_to_nanos;
+use ant_model::{
+    identifiers::{ClientOrderId, ExecAlgorithmId},
+    orders::{Order, OrderAny},
+};
+
+use super::{
+    ExecAlgorithm, ExecAlgorithmConfig, ExecAlgorithmCore, order_param,
+    schedule::{SliceSchedule, advance_schedule, check_sliceable, start_schedule},
+};
+
+/// Execution algorithm which works a primary order over a time horizon in slices sized in
+/// proportion to an expected volume profile.
+///
+/// The volume profile is a sequence of relative volume weights, one for each equal interval of
+/// the horizon (e.g. the historical share of daily volume traded in each half hour bucket).
+/// Orders are configured with the execution algorithm parameters:
+/// - `horizon_secs`: the time horizon over which the order is executed.
+/// - `volume_profile` (optional): comma separated weights overriding the default profile of
+///   the algorithm.
+///
+/// The first slice is executed on receipt of the order, and the final slice submits the
+/// primary order with its remaining quantity. Market and limit primary orders are supported,
+/// with limit slices placed at the primary order price.
+#[derive(Debug)]
+pub struct VwapExecAlgorithm {
+    core: ExecAlgorithmCore,
+    volume_profile: Vec<f64>,
+    schedules: HashMap<ClientOrderId, SliceSchedule>,
+}
+
+impl VwapExecAlgorithm {
+    /// Creates a new [`VwapExecAlgorithm`] instance.
+    ///
+    /// The `volume_profile` is used for orders which do not specify their own profile, and may
+    /// be empty if every order specifies one. The algorithm ID defaults to `VWAP`.
+    #[must_use]
+    pub fn new(config: ExecAlgorithmConfig, volume_profile: Vec<f64>) -> Self {
+        Self {
+            core: ExecAlgorithmCore::new(config, ExecAlgorithmId::from("VWAP")),
+            volume_profile,
+            schedules: HashMap::new(),
+        }
+    }
+
+    /// Returns the default volume profile of the algorithm.
+    #[must_use]
+    pub fn volume_profile(&self) -> &[f64] {
+        &self.volume_profile
+    }
+
+    /// Returns whether the primary order with the `client_order_id` is being worked.
+    #[must_use]
+    pub fn is_scheduled(&self, client_order_id: &ClientOrderId) -> bool {
+        self.schedules.contains_key(client_order_id)
+    }
+}
+
+/// Parses a comma separated volume profile.
+fn parse_volume_profile(value: &str) -> anyhow::Result<Vec<f64>> {
+    value
+        .split(',')
+        .map(|weight| {
+            weight
+                .trim()
+                .parse::<f64>()
+                .map_err(|e| anyhow::anyhow!("Invalid volume profile weight '{weight}': {e}"))
+        })
+        .collect()
+}
+
+impl Deref for VwapExecAlgorithm {
+    type Target = DataActorCore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.core
+    }
+}
+
+impl DerefMut for VwapExecAlgorithm {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.core
+    }
+}
+
+impl DataActor for VwapExecAlgorithm {
+    fn on_start(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn on_stop(&mut self) -> anyhow::Result<()> {
+        for primary_id in self.schedules.keys() {
+            log::warn!("Stopped working primary order {primary_id} before completion");
+        }
+        self.schedules.clear();
+        self.clock().cancel_timers();
+        Ok(())
+    }
+
+    fn on_reset(&mut self) -> anyhow::Result<()> {
+        self.schedules.clear();
+        self.core.reset();
+        Ok(())
+    }
+
+    fn on_time_event(&mut self, event: &TimeEvent) -> anyhow::Result<()> {
+        let primary_id = ClientOrderId::from(event.name.as_str());
+        let Some(schedule) = self.schedules.remove(&primary_id) else {
+            log::warn!("No schedule for timer {}", event.name);
+            self.clock().cancel_timer(event.name.as_str());
+            return Ok(());
+        };
+
+        if let Some(schedule) = advance_schedule(self, schedule)? {
+            self.schedules.insert(primary_id, schedule);
+        }
+        Ok(())
+    }
+}
+
+impl ExecAlgorithm for VwapExecAlgorithm {
+    fn core(&self) -> &ExecAlgorithmCore {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+        &mut self.core
+    }
+
+    fn on_order(&mut self, order: OrderAny) -> anyhow::Result<()> {
+        check_sliceable(&order)?;
+
+        let client_order_id = order.client_order_id();
+        let horizon_secs: f64 = order_param(&order, "horizon_secs")?.ok_or_else(|| {
+            anyhow::anyhow!("Missing exec algorithm parameter 'horizon_secs' for {client_order_id}")
+        })?;
+        if !(horizon_secs > 0.0 && horizon_secs.is_finite()) {
+            anyhow::bail!(
+                "Invalid VWAP parameters for {client_order_id}: horizon_secs={horizon_secs} \
+                must be positive"
+            );
+        }
+
+        let volume_profile = match order_param::<String>(&order, "volume_profile")? {
+            Some(value) => parse_volume_profile(&value)?,
+            None => self.volume_profile.clone(),
+        };
+        if volume_profile.is_empty() {
+            anyhow::bail!("No volume profile for VWAP order {client_order_id}");
+        }
+
+        let interval_secs = horizon_secs / volume_profile.len() as f64;
+        let schedule = SliceSchedule::new(client_order_id, order.quantity(), &volume_profile)?;
+
+        if let Some(schedule) = start_schedule(self, schedule, secs_to_nanos(interval_secs))? {
+            self.schedules.insert(client_order_id, schedule);
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use ant_common::{
+        actor::DataActor,
+        cache::Cache,
+        clock::{Clock, TestClock},
+        component::Component,
+        messages::execution::{SubmitOrder, TradingCommand},
+        msgbus::{
+            self,
+            stubs::{get_message_saving_handler, get_saved_messages},
+            switchboard::MessagingSwitchboard,
+        },
+    };
+    use ant_core::{UUID4, UnixNanos, datetime::secs_to_nanos};
+    use ant_model::{
+        enums::OrderType,
+        identifiers::{ClientId, ClientOrderId, ExecAlgorithmId, InstrumentId, TraderId},
+        orders::{Order, OrderAny, OrderTestBuilder},
+        types::{Price, Quantity},
+    };
+    use indexmap::IndexMap;
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{VwapExecAlgorithm, parse_volume_profile};
+    use crate::algorithm::{ExecAlgorithm, ExecAlgorithmConfig};
+
+    #[fixture]
+    fn clock() -> Rc<RefCell<TestClock>> {
+        Rc::new(RefCell::new(TestClock::new()))
+    }
+
+    #[fixture]
+    fn algorithm(clock: Rc<RefCell<TestClock>>) -> VwapExecAlgorithm {
+        create_algorithm(clock)
+    }
+
+    // The algorithm fixture receives its own clock, so tests advancing time share one explicitly
+    fn create_algorithm(clock: Rc<RefCell<TestClock>>) -> VwapExecAlgorithm {
+        let mut algorithm =
+            VwapExecAlgorithm::new(ExecAlgorithmConfig::default(), vec![1.0, 2.0, 1.0]);
+        algorithm
+            .register(
+                TraderId::from("TRADER-001"),
+                clock,
+                Rc::new(RefCell::new(Cache::new(None, None))),
+            )
+            .unwrap();
+        algorithm.start().unwrap();
+        algorithm
+    }
+
+    fn primary_order(quantity: &str, params: &[(&str, &str)]) -> OrderAny {
+        let client_order_id = ClientOrderId::from("O-001");
+        let params: IndexMap<Ustr, Ustr> = params
+            .iter()
+            .map(|(key, value)| (Ustr::from(key), Ustr::from(value)))
+            .collect();
+        OrderTestBuilder::new(OrderType::Limit)
+            .trader_id(TraderId::from("TRADER-001"))
+            .instrument_id(InstrumentId::from("AUD/USD.SIM"))
+            .client_order_id(client_order_id)
+            .quantity(Quantity::from(quantity))
+            .price(Price::from("0.70000"))
+            .exec_algorithm_id(ExecAlgorithmId::from("VWAP"))
+            .exec_algorithm_params(params)
+            .exec_spawn_id(client_order_id)
+            .build()
+    }
+
+    fn submit_command(order: &OrderAny) -> TradingCommand {
+        TradingCommand::SubmitOrder(
+            SubmitOrder::new(
+                order.trader_id(),
+                ClientId::from("SIM"),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                order.venue_order_id().unwrap_or_default(),
+                order.clone(),
+                order.exec_algorithm_id(),
+                None,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn submitted_orders(commands: &[TradingCommand]) -> Vec<(String, Quantity)> {
+        commands
+            .iter()
+            .map(|command| match command {
+                TradingCommand::SubmitOrder(command) => (
+                    command.client_order_id.to_string(),
+                    command.order.quantity(),
+                ),
+                other => panic!("Unexpected command {other}"),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_vwap_slices_follow_default_volume_profile(clock: Rc<RefCell<TestClock>>) {
+        let mut algorithm = create_algorithm(clock.clone());
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let primary = primary_order("8", &[("horizon_secs", "30")]);
+        algorithm.handle_command(&submit_command(&primary));
+
+        let events = clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(secs_to_nanos(20.0)), true);
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            algorithm.on_time_event(event).unwrap();
+        }
+
+        let commands = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(
+            submitted_orders(&commands),
+            vec![
+                ("O-001-E1".to_string(), Quantity::from("2")),
+                ("O-001-E2".to_string(), Quantity::from("4")),
+                ("O-001".to_string(), Quantity::from("2")),
+            ]
+        );
+        for command in &commands {
+            if let TradingCommand::SubmitOrder(command) = command {
+                assert_eq!(command.order.order_type(), OrderType::Limit);
+                assert_eq!(command.order.price(), Some(Price::from("0.70000")));
+            }
+        }
+        assert!(!algorithm.is_scheduled(&primary.client_order_id()));
+        assert_eq!(clock.borrow().timer_count(), 0);
+    }
+
+    #[rstest]
+    fn test_vwap_order_volume_profile_overrides_default(clock: Rc<RefCell<TestClock>>) {
+        let mut algorithm = create_algorithm(clock.clone());
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let primary = primary_order("8", &[("horizon_secs", "10"), ("volume_profile", "3,1")]);
+        algorithm.handle_command(&submit_command(&primary));
+
+        assert!(algorithm.is_scheduled(&primary.client_order_id()));
+        let events = clock
+            .borrow_mut()
+            .advance_time(UnixNanos::from(secs_to_nanos(5.0)), true);
+        assert_eq!(events.len(), 1);
+        algorithm.on_time_event(&events[0]).unwrap();
+
+        let commands = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(
+            submitted_orders(&commands),
+            vec![
+                ("O-001-E1".to_string(), Quantity::from("6")),
+                ("O-001".to_string(), Quantity::from("2")),
+            ]
+        );
+        assert!(!algorithm.is_scheduled(&primary.client_order_id()));
+    }
+
+    #[rstest]
+    fn test_vwap_missing_horizon_returns_error(mut algorithm: VwapExecAlgorithm) {
+        let primary = primary_order("8", &[]);
+
+        assert!(algorithm.on_order(primary).is_err());
+    }
+
+    #[rstest]
+    #[case("1,2,1", Some(vec![1.0, 2.0, 1.0]))]
+    #[case(" 0.25, 0.75 ", Some(vec![0.25, 0.75]))]
+    #[case("1,x", None)]
+    #[case("", None)]
+    fn test_parse_volume_profile(#[case] value: &str, #[case] expected: Option<Vec<f64>>) {
+        assert_eq!(parse_volume_profile(value).ok(), expected);
+    }
+}
@@ -121,6 +121,13 @@ pub trait ExecutionClient {
     ///
     /// Returns an error if the query fails.
     fn query_order(&self, cmd: &QueryOrder) -> anyhow::Result<()>;
+
+    /// Returns this client as a [`LiveExecutionClient`] if it supports venue reports.
+    ///
+    /// Used by live nodes to request reports for execution reconciliation.
+    fn as_live(&self) -> Option<&dyn LiveExecutionClient> {
+        None
+    }
 }
 
 pub trait LiveExecutionClient: ExecutionClient {
@@ -50,7 +50,7 @@ use ant_model::{
     enums::{ContingencyType, OmsType, OrderSide, PositionSide},
     events::{
         OrderDenied, OrderEvent, OrderEventAny, OrderFilled, PositionChanged, PositionClosed,
-        PositionOpened,
+        PositionEvent, PositionOpened,
     },
     identifiers::{ClientId, InstrumentId, PositionId, StrategyId, Venue},
     instruments::{Instrument, InstrumentEnum},
@@ -77,7 +77,7 @@ pub struct ExecutionEngine {
     oms_overrides: HashMap<StrategyId, OmsType>,
     external_order_claims: HashMap<InstrumentId, StrategyId>,
     external_clients: HashSet<ClientId>,
-    pos_id_generator: PositionIdGenerator,
+    pos_id_generator: RefCell<PositionIdGenerator>,
     config: ExecutionEngineConfig,
 }
 
@@ -111,7 +111,7 @@ impl ExecutionEngine {
                 .unwrap_or_default()
                 .into_iter()
                 .collect(),
-            pos_id_generator: PositionIdGenerator::new(trader_id, clock),
+            pos_id_generator: RefCell::new(PositionIdGenerator::new(trader_id, clock)),
             config: config.unwrap_or_default(),
         }
     }
@@ -119,7 +119,7 @@ impl ExecutionEngine {
     #[must_use]
     /// Returns the position ID count for the specified strategy.
     pub fn position_id_count(&self, strategy_id: StrategyId) -> usize {
-        self.pos_id_generator.count(strategy_id)
+        self.pos_id_generator.borrow().count(strategy_id)
     }
 
     #[must_use]
@@ -152,6 +152,12 @@ impl ExecutionEngine {
         self.external_order_claims.keys().copied().collect()
     }
 
+    #[must_use]
+    /// Returns the strategy which has claimed external orders for the given instrument (if any).
+    pub fn get_external_order_claim(&self, instrument_id: &InstrumentId) -> Option<StrategyId> {
+        self.external_order_claims.get(instrument_id).copied()
+    }
+
     // -- REGISTRATION ----------------------------------------------------------------------------
 
     /// Registers a new execution client.
@@ -203,10 +209,47 @@ impl ExecutionEngine {
         Ok(())
     }
 
-    // TODO: Implement `Strategy`
-    // pub fn register_external_order_claims(&mut self, strategy: Strategy) -> anyhow::Result<()> {
-    //     todo!();
-    // }
+    /// Registers an OMS type override for the given strategy.
+    ///
+    /// Positions for fills belonging to the strategy will be handled according to the
+    /// overriding OMS type rather than the venue OMS type.
+    pub fn register_oms_type(&mut self, strategy_id: StrategyId, oms_type: OmsType) {
+        self.oms_overrides.insert(strategy_id, oms_type);
+        log::info!("Registered OMS {oms_type:?} for {strategy_id}");
+    }
+
+    /// Registers external order claims for the given strategy.
+    ///
+    /// External orders for the claimed instruments will be assigned to the strategy
+    /// during reconciliation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the instruments already has an external order claim.
+    pub fn register_external_order_claims(
+        &mut self,
+        strategy_id: StrategyId,
+        instrument_ids: &HashSet<InstrumentId>,
+    ) -> anyhow::Result<()> {
+        // Validate all claims before registering any
+        for instrument_id in instrument_ids {
+            if let Some(existing) = self.external_order_claims.get(instrument_id) {
+                anyhow::bail!(
+                    "External order claim for {instrument_id} already exists for {existing}"
+                );
+            }
+        }
+
+        for instrument_id in instrument_ids {
+            self.external_order_claims.insert(*instrument_id, strategy_id);
+        }
+
+        if !instrument_ids.is_empty() {
+            log::info!("Registered external order claims for {strategy_id}: {instrument_ids:?}");
+        }
+
+        Ok(())
+    }
 
     /// # Errors
     ///
@@ -272,7 +315,7 @@ impl ExecutionEngine {
     }
 
     /// Processes an order event, updating internal state and routing as needed.
-    pub fn process(&mut self, event: &OrderEventAny) {
+    pub fn process(&self, event: &OrderEventAny) {
         self.handle_event(event);
     }
 
@@ -551,7 +594,7 @@ impl ExecutionEngine {
 
     // -- EVENT HANDLERS --------------------------------------------------------------------------
 
-    fn handle_event(&mut self, event: &OrderEventAny) {
+    fn handle_event(&self, event: &OrderEventAny) {
         if self.config.debug {
             log::debug!("{RECV}{EVT} {event:?}");
         }
@@ -642,7 +685,7 @@ impl ExecutionEngine {
         OmsType::Netting // Default fallback
     }
 
-    fn determine_position_id(&mut self, fill: OrderFilled, oms_type: OmsType) -> PositionId {
+    fn determine_position_id(&self, fill: OrderFilled, oms_type: OmsType) -> PositionId {
         match oms_type {
             OmsType::Hedging => self.determine_hedging_position_id(fill),
             OmsType::Netting => self.determine_netting_position_id(fill),
@@ -650,7 +693,7 @@ impl ExecutionEngine {
         }
     }
 
-    fn determine_hedging_position_id(&mut self, fill: OrderFilled) -> PositionId {
+    fn determine_hedging_position_id(&self, fill: OrderFilled) -> PositionId {
         // Check if position ID already exists
         if let Some(position_id) = fill.position_id {
             if self.config.debug {
@@ -685,7 +728,7 @@ impl ExecutionEngine {
         }
 
         // Generate new position ID
-        let position_id = self.pos_id_generator.generate(fill.strategy_id, false);
+        let position_id = self.pos_id_generator.borrow_mut().generate(fill.strategy_id, false);
         if self.config.debug {
             log::debug!("Generated {} for {}", position_id, fill.client_order_id());
         }
@@ -716,14 +759,14 @@ impl ExecutionEngine {
         }
 
         let topic = switchboard::get_event_orders_topic(event.strategy_id());
-        msgbus::publish(topic, order);
+        msgbus::publish(topic, &event);
 
         if self.config.snapshot_orders {
             self.create_order_state_snapshot(order);
         }
     }
 
-    fn handle_order_fill(&mut self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
+    fn handle_order_fill(&self, order: &OrderAny, fill: OrderFilled, oms_type: OmsType) {
         let instrument =
             if let Some(instrument) = self.cache.borrow().instrument(&fill.instrument_id) {
                 instrument.clone()
@@ -790,7 +833,7 @@ impl ExecutionEngine {
     ///
     /// This function mirrors the Python `_handle_position_update` method.
     fn handle_position_update(
-        &mut self,
+        &self,
         instrument: InstrumentEnum,
         fill: OrderFilled,
         oms_type: OmsType,
@@ -860,7 +903,7 @@ impl ExecutionEngine {
         let ts_init = self.clock.borrow().timestamp_ns();
         let event = PositionOpened::create(&position, &fill, UUID4::new(), ts_init);
         let topic = switchboard::get_event_positions_topic(event.strategy_id);
-        msgbus::publish(topic, &event);
+        msgbus::publish(topic, &PositionEvent::PositionOpened(event));
 
         Ok(position)
     }
@@ -894,10 +937,10 @@ impl ExecutionEngine {
 
         if is_closed {
             let event = PositionClosed::create(position, &fill, UUID4::new(), ts_init);
-            msgbus::publish(topic, &event);
+            msgbus::publish(topic, &PositionEvent::PositionClosed(event));
         } else {
             let event = PositionChanged::create(position, &fill, UUID4::new(), ts_init);
-            msgbus::publish(topic, &event);
+            msgbus::publish(topic, &PositionEvent::PositionChanged(event));
         }
     }
 
@@ -906,7 +949,7 @@ impl ExecutionEngine {
     }
 
     fn flip_position(
-        &mut self,
+        &self,
         instrument: InstrumentEnum,
         position: &mut Position,
         fill: OrderFilled,
@@ -976,7 +1019,7 @@ impl ExecutionEngine {
             && position_id.is_virtual()
         {
             // Generate new position ID for flipped virtual position (Hedging OMS only)
-            Some(self.pos_id_generator.generate(fill.strategy_id, true))
+            Some(self.pos_id_generator.borrow_mut().generate(fill.strategy_id, true))
         } else {
             // Default: use the same position ID as the fill (Python behavior)
             fill.position_id
@@ -1019,7 +1062,7 @@ impl ExecutionEngine {
 
     // -- INTERNAL --------------------------------------------------------------------------------
 
-    fn set_position_id_counts(&mut self) {
+    fn set_position_id_counts(&self) {
         // For the internal position ID generator
         let cache = self.cache.borrow();
         let positions = cache.positions(None, None, None, None);
@@ -1031,10 +1074,10 @@ impl ExecutionEngine {
             *counts.entry(position.strategy_id).or_insert(0) += 1;
         }
 
-        self.pos_id_generator.reset();
+        self.pos_id_generator.borrow_mut().reset();
 
         for (strategy_id, count) in counts {
-            self.pos_id_generator.set_count(count, strategy_id);
+            self.pos_id_generator.borrow_mut().set_count(count, strategy_id);
             log::info!("Set PositionId count for {strategy_id} to {count}");
         }
     }
@@ -1151,7 +1194,7 @@ impl ExecutionEngine {
         }
 
         let topic = switchboard::get_event_orders_topic(order.strategy_id());
-        msgbus::publish(topic, &denied);
+        msgbus::publish(topic, &OrderEventAny::Denied(denied));
 
         if self.config.snapshot_orders {
             self.create_order_state_snapshot(&order);
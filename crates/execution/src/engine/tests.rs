@@ -1049,7 +1049,7 @@ fn test_when_applying_event_to_order_with_invalid_state_trigger_logs(
 
 #[rstest]
 fn test_order_filled_event_when_order_not_found_in_cache_logs(
-    mut execution_engine: ExecutionEngine,
+    execution_engine: ExecutionEngine,
 ) {
     // Arrange
     let trader_id = TraderId::from("TEST-TRADER");
@@ -4401,7 +4401,7 @@ fn test_flip_position_when_netting_oms(mut execution_engine: ExecutionEngine) {
 
 //CAN CHECK THIS TEST
 #[rstest]
-fn test_handle_updated_order_event(mut execution_engine: ExecutionEngine) {
+fn test_handle_updated_order_event(execution_engine: ExecutionEngine) {
     // Arrange
 
     let trader_id = TraderId::from("TEST-TRADER");
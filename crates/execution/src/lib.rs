@@ -20,6 +20,7 @@
 //! execution venue integration, and advanced order type emulation:
 //!
 //! - **Execution engine**: Central orchestration of order routing and position management.
+//! - **Execution algorithms**: TWAP, VWAP and iceberg algorithms which work orders by spawning child orders.
 //! - **Order matching engine**: High-fidelity market simulation for backtesting and paper trading.
 //! - **Order emulator**: Advanced order types not natively supported by venues (trailing stops, contingent orders).
 //! - **Execution clients**: Abstract interfaces for connecting to trading venues and brokers.
@@ -60,6 +61,7 @@
 #![deny(clippy::missing_panics_doc)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+pub mod algorithm;
 pub mod client;
 pub mod engine;
 pub mod matching_core;
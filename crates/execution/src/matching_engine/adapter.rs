@@ -32,7 +32,7 @@ use crate::{
         TriggerStopOrderHandlerAny,
     },
     matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel},
+    models::{fee::FeeModelAny, fill::FillModelAny},
 };
 
 #[derive(Debug)]
@@ -45,7 +45,7 @@ impl OrderEngineAdapter {
     pub fn new(
         instrument: InstrumentEnum,
         raw_id: u32,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
         book_type: BookType,
         oms_type: OmsType,
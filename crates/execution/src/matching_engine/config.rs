@@ -13,6 +13,38 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+/// Configuration for simulated daily price limit bands (limit-up/limit-down).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceLimitConfig {
+    /// The band as a fraction of the reference price (e.g. 0.10 for +/-10%).
+    pub limit_pct: f64,
+    /// If orders should not fill while the market is locked at the limit price.
+    pub limit_locked_no_fill: bool,
+}
+
+impl PriceLimitConfig {
+    /// Creates a new [`PriceLimitConfig`] instance.
+    #[must_use]
+    pub const fn new(limit_pct: f64, limit_locked_no_fill: bool) -> Self {
+        Self {
+            limit_pct,
+            limit_locked_no_fill,
+        }
+    }
+}
+
+/// The behavior when an aggressive order exhausts the simulated L1 book volume.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LiquidityExhaustedBehavior {
+    /// Fill the remaining quantity one price tick through the last fill price.
+    #[default]
+    NextTick,
+    /// Fill the remaining quantity at the last fill price.
+    LastPrice,
+    /// Cancel the remaining quantity (IOC semantics).
+    CancelRemainder,
+}
+
 /// Configuration for `OrderMatchingEngine` instances.
 #[derive(Debug, Clone)]
 pub struct OrderMatchingEngineConfig {
@@ -23,10 +55,19 @@ pub struct OrderMatchingEngineConfig {
     pub use_position_ids: bool,
     pub use_random_ids: bool,
     pub use_reduce_only: bool,
+    /// The daily price limit band, derived from the previous trading day close or settlement.
+    pub price_limit: Option<PriceLimitConfig>,
+    /// If quantity bought on a trading day cannot be sold until the next trading day (T+1).
+    pub use_t_plus_one: bool,
+    /// The offset (seconds) added to UTC timestamps to determine the trading day.
+    pub trading_day_offset_secs: i64,
+    /// The behavior when an aggressive order exhausts the simulated L1 book volume.
+    pub liquidity_exhausted_behavior: LiquidityExhaustedBehavior,
 }
 
 impl OrderMatchingEngineConfig {
     /// Creates a new default [`OrderMatchingEngineConfig`] instance.
+    #[allow(clippy::too_many_arguments)]
     #[must_use]
     pub const fn new(
         bar_execution: bool,
@@ -36,6 +77,10 @@ impl OrderMatchingEngineConfig {
         use_position_ids: bool,
         use_random_ids: bool,
         use_reduce_only: bool,
+        price_limit: Option<PriceLimitConfig>,
+        use_t_plus_one: bool,
+        trading_day_offset_secs: i64,
+        liquidity_exhausted_behavior: LiquidityExhaustedBehavior,
     ) -> Self {
         Self {
             bar_execution,
@@ -45,6 +90,10 @@ impl OrderMatchingEngineConfig {
             use_position_ids,
             use_random_ids,
             use_reduce_only,
+            price_limit,
+            use_t_plus_one,
+            trading_day_offset_secs,
+            liquidity_exhausted_behavior,
         }
     }
 }
@@ -61,6 +110,10 @@ impl Default for OrderMatchingEngineConfig {
             use_position_ids: false,
             use_random_ids: false,
             use_reduce_only: false,
+            price_limit: None,
+            use_t_plus_one: false,
+            trading_day_offset_secs: 0,
+            liquidity_exhausted_behavior: LiquidityExhaustedBehavior::NextTick,
         }
     }
 }
@@ -36,11 +36,14 @@ use ant_common::{
 };
 use ant_core::{UUID4, UnixNanos};
 use ant_model::{
-    data::{Bar, BarType, OrderBookDelta, OrderBookDeltas, QuoteTick, TradeTick, order::BookOrder},
+    data::{
+        Bar, BarType, InstrumentClose, OrderBookDelta, OrderBookDeltas, OrderBookDepth10,
+        QuoteTick, TradeTick, order::BookOrder,
+    },
     enums::{
         AccountType, AggregationSource, AggressorSide, BarAggregation, BookType, ContingencyType,
-        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide, OrderSideSpecified,
-        OrderStatus, OrderType, PriceType, TimeInForce,
+        InstrumentCloseType, LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OrderSide,
+        OrderSideSpecified, OrderStatus, OrderType, PriceType, TimeInForce,
     },
     events::{
         OrderAccepted, OrderCancelRejected, OrderCanceled, OrderEventAny, OrderExpired,
@@ -60,10 +63,14 @@ use ustr::Ustr;
 
 use crate::{
     matching_core::OrderMatchingCore,
-    matching_engine::{config::OrderMatchingEngineConfig, ids_generator::IdsGenerator},
+    matching_engine::{
+        config::{LiquidityExhaustedBehavior, OrderMatchingEngineConfig},
+        ids_generator::IdsGenerator,
+        rules::{PriceLimitBand, TradingDayCalendar, trading_day},
+    },
     models::{
         fee::{FeeModel, FeeModelAny},
-        fill::FillModel,
+        fill::{FillModel, FillModelAny},
     },
     trailing::trailing_stop_calculate,
 };
@@ -90,7 +97,7 @@ pub struct OrderMatchingEngine {
     cache: Rc<RefCell<Cache>>,
     book: OrderBook,
     pub core: OrderMatchingCore,
-    fill_model: FillModel,
+    fill_model: FillModelAny,
     fee_model: FeeModelAny,
     target_bid: Option<Price>,
     target_ask: Option<Price>,
@@ -102,6 +109,11 @@ pub struct OrderMatchingEngine {
     account_ids: HashMap<TraderId, AccountId>,
     cached_filled_qty: HashMap<ClientOrderId, Quantity>,
     ids_generator: IdsGenerator,
+    trading_day: Option<i64>,
+    session_last_px: Option<Price>,
+    price_limit_band: Option<PriceLimitBand>,
+    trading_calendar: Option<Rc<dyn TradingDayCalendar>>,
+    bought_today: Option<Quantity>,
 }
 
 impl Debug for OrderMatchingEngine {
@@ -119,7 +131,7 @@ impl OrderMatchingEngine {
     pub fn new(
         instrument: InstrumentEnum,
         raw_id: u32,
-        fill_model: FillModel,
+        fill_model: FillModelAny,
         fee_model: FeeModelAny,
         book_type: BookType,
         oms_type: OmsType,
@@ -170,6 +182,11 @@ impl OrderMatchingEngine {
             account_ids: HashMap::new(),
             cached_filled_qty: HashMap::new(),
             ids_generator,
+            trading_day: None,
+            session_last_px: None,
+            price_limit_band: None,
+            trading_calendar: None,
+            bought_today: None,
         }
     }
 
@@ -189,12 +206,16 @@ impl OrderMatchingEngine {
         self.target_ask = None;
         self.target_last = None;
         self.ids_generator.reset();
+        self.trading_day = None;
+        self.session_last_px = None;
+        self.price_limit_band = None;
+        self.bought_today = None;
 
         log::info!("Reset {}", self.instrument.id());
     }
 
     /// Sets the fill model for the matching engine.
-    pub const fn set_fill_model(&mut self, fill_model: FillModel) {
+    pub fn set_fill_model(&mut self, fill_model: FillModelAny) {
         self.fill_model = fill_model;
     }
 
@@ -244,15 +265,148 @@ impl OrderMatchingEngine {
         self.core.order_exists(client_order_id)
     }
 
+    #[must_use]
+    /// Returns the daily price limit band for the current trading day (if established).
+    pub const fn price_limit_band(&self) -> Option<PriceLimitBand> {
+        self.price_limit_band
+    }
+
+    /// Sets the reference price (previous close or settlement) for the daily price limit band.
+    ///
+    /// Use this to seed the band for the first trading day of a backtest, later bands are derived
+    /// from the last price of each trading day.
+    pub fn set_price_limit_reference(&mut self, reference: Price) {
+        let Some(price_limit) = self.config.price_limit else {
+            log::warn!(
+                "Ignoring price limit reference for {}, price limits not configured",
+                self.instrument.id()
+            );
+            return;
+        };
+
+        match PriceLimitBand::from_reference(
+            reference,
+            price_limit.limit_pct,
+            self.instrument.price_increment(),
+        ) {
+            Ok(band) => {
+                log::debug!(
+                    "Price limits for {} set to [{}, {}] from reference {reference}",
+                    self.instrument.id(),
+                    band.lower,
+                    band.upper
+                );
+                self.price_limit_band = Some(band);
+            }
+            Err(e) => log::error!("Cannot set price limits for {}: {e}", self.instrument.id()),
+        }
+    }
+
+    /// Sets the exchange trading calendar used to resolve the trading day of timestamps.
+    ///
+    /// Without a calendar the trading day is derived from the configured fixed offset.
+    pub fn set_trading_calendar(&mut self, calendar: Rc<dyn TradingDayCalendar>) {
+        self.trading_calendar = Some(calendar);
+    }
+
+    /// Returns the quantity of the instrument which may be sold today under the T+1 rule, or
+    /// `None` if there are no long holdings (selling is then a short sale, not subject to T+1).
+    ///
+    /// The T+1 rule applies to the holdings of the instrument as a whole, so the long quantity
+    /// is summed over all open positions regardless of the OMS type.
+    fn sellable_quantity(&self) -> Option<Quantity> {
+        let precision = self.instrument.size_precision();
+        let zero = Quantity::zero(precision);
+
+        let held = self
+            .cache
+            .borrow()
+            .positions_open(None, Some(&self.instrument.id()), None, None)
+            .iter()
+            .filter(|position| position.is_long())
+            .fold(zero, |held, position| held + position.quantity);
+        if held.is_zero() {
+            return None;
+        }
+
+        let mut unavailable = self.bought_today.unwrap_or(zero);
+
+        // Quantity reserved by open sell orders is not available
+        for order in self.core.get_orders_ask() {
+            let order = OrderAny::from(order.clone());
+            unavailable += order.leaves_qty();
+        }
+
+        if held > unavailable {
+            Some(held - unavailable)
+        } else {
+            Some(zero)
+        }
+    }
+
+    /// Returns whether the market is locked at the limit price for an order on the given side.
+    ///
+    /// The market is locked limit-up when bids are queued at the upper limit, and locked
+    /// limit-down when offers are queued at the lower limit.
+    fn is_limit_locked(&self, side: OrderSide) -> bool {
+        if !self
+            .config
+            .price_limit
+            .is_some_and(|price_limit| price_limit.limit_locked_no_fill)
+        {
+            return false;
+        }
+        let Some(band) = self.price_limit_band else {
+            return false;
+        };
+
+        match side {
+            OrderSide::Buy => self.core.bid.is_some_and(|bid| bid >= band.upper),
+            OrderSide::Sell => self.core.ask.is_some_and(|ask| ask <= band.lower),
+            OrderSide::NoOrderSide => false,
+        }
+    }
+
+    /// Rolls the trading day state forward when `ts` falls on a new trading day.
+    ///
+    /// The last price of the previous trading day becomes the reference for the new daily price
+    /// limit band, and quantity bought on the previous day becomes sellable.
+    fn update_trading_day(&mut self, ts: UnixNanos) {
+        if self.config.price_limit.is_none() && !self.config.use_t_plus_one {
+            return;
+        }
+
+        let day = match &self.trading_calendar {
+            Some(calendar) => calendar.trading_day(ts),
+            None => trading_day(ts, self.config.trading_day_offset_secs),
+        };
+        match self.trading_day {
+            Some(current) if current >= day => return,
+            Some(_) => {
+                if let Some(reference) = self.session_last_px.take()
+                    && self.config.price_limit.is_some()
+                {
+                    self.set_price_limit_reference(reference);
+                }
+                self.bought_today = None;
+            }
+            None => {}
+        }
+        self.trading_day = Some(day);
+    }
+
     // -- DATA PROCESSING -------------------------------------------------------------------------
 
     /// Process the venues market for the given order book delta.
     pub fn process_order_book_delta(&mut self, delta: &OrderBookDelta) {
         log::debug!("Processing {delta}");
 
+        self.update_trading_day(delta.ts_init);
+
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
             self.book.apply_delta(delta);
         }
+        self.fill_model.process_order_book_delta(delta);
 
         self.iterate(delta.ts_init);
     }
@@ -260,19 +414,39 @@ impl OrderMatchingEngine {
     pub fn process_order_book_deltas(&mut self, deltas: &OrderBookDeltas) {
         log::debug!("Processing {deltas}");
 
+        self.update_trading_day(deltas.ts_init);
+
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
             self.book.apply_deltas(deltas);
         }
+        for delta in &deltas.deltas {
+            self.fill_model.process_order_book_delta(delta);
+        }
 
         self.iterate(deltas.ts_init);
     }
 
+    /// Process the venues market for the given order book depth snapshot.
+    pub fn process_order_book_depth10(&mut self, depth: &OrderBookDepth10) {
+        log::debug!("Processing {depth}");
+
+        self.update_trading_day(depth.ts_init);
+
+        if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
+            self.book.apply_depth(depth);
+        }
+
+        self.iterate(depth.ts_init);
+    }
+
     /// # Panics
     ///
     /// Panics if updating the order book with the quote tick fails.
     pub fn process_quote_tick(&mut self, quote: &QuoteTick) {
         log::debug!("Processing {quote}");
 
+        self.update_trading_day(quote.ts_init);
+
         if self.book_type == BookType::L1_MBP {
             self.book.update_quote_tick(quote).unwrap();
         }
@@ -286,6 +460,12 @@ impl OrderMatchingEngine {
     pub fn process_bar(&mut self, bar: &Bar) {
         log::debug!("Processing {bar}");
 
+        self.update_trading_day(bar.ts_init);
+        if bar.bar_type.spec().price_type == PriceType::Last {
+            self.session_last_px = Some(bar.close);
+        }
+        self.fill_model.process_bar(bar);
+
         // Check if configured for bar execution can only process an L1 book with bars
         if !self.config.bar_execution || self.book_type != BookType::L1_MBP {
             return;
@@ -477,14 +657,29 @@ impl OrderMatchingEngine {
     pub fn process_trade_tick(&mut self, trade: &TradeTick) {
         log::debug!("Processing {trade}");
 
+        self.update_trading_day(trade.ts_init);
+        self.session_last_px = Some(trade.price);
+
         if self.book_type == BookType::L1_MBP {
             self.book.update_trade_tick(trade).unwrap();
         }
+        self.fill_model.process_trade_tick(trade);
         self.core.set_last_raw(trade.price);
 
         self.iterate(trade.ts_init);
     }
 
+    /// Process the instrument close, where an end of session close (or settlement) price
+    /// becomes the reference for the next trading day's price limit band.
+    pub fn process_instrument_close(&mut self, close: &InstrumentClose) {
+        log::debug!("Processing {close}");
+
+        self.update_trading_day(close.ts_init);
+        if close.close_type == InstrumentCloseType::EndOfSession {
+            self.session_last_px = Some(close.close_price);
+        }
+    }
+
     pub fn process_status(&mut self, action: MarketStatusAction) {
         log::debug!("Processing {action}");
 
@@ -517,6 +712,9 @@ impl OrderMatchingEngine {
     /// Panics if the instrument activation timestamp is missing.
     #[allow(clippy::needless_return)]
     pub fn process_order(&mut self, order: &mut OrderAny, account_id: AccountId) {
+        let ts_now = self.clock.borrow().timestamp_ns();
+        self.update_trading_day(ts_now);
+
         // Enter the scope where you will borrow a cache
         {
             let cache_borrow = self.cache.as_ref().borrow();
@@ -667,6 +865,24 @@ impl OrderMatchingEngine {
                 return;
             }
 
+            // Check price is within the daily price limits
+            if let Some(band) = self.price_limit_band
+                && let Some(price) = order.price()
+                && !band.contains(price)
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "Order price {price} outside daily price limits [{}, {}] for {}",
+                        band.lower,
+                        band.upper,
+                        self.instrument.id()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
             // Get position if exists
             let position: Option<&Position> = cache_borrow
                 .position_for_order(&order.client_order_id())
@@ -699,6 +915,24 @@ impl OrderMatchingEngine {
                 return;
             }
 
+            // Check not selling quantity bought today under the T+1 rule
+            if self.config.use_t_plus_one
+                && order.order_side() == OrderSide::Sell
+                && let Some(sellable) = self.sellable_quantity()
+                && order.quantity() > sellable
+            {
+                self.generate_order_rejected(
+                    order,
+                    format!(
+                        "T+1 rule: order quantity {} exceeds sellable quantity {sellable} for {}",
+                        order.quantity(),
+                        self.instrument.id()
+                    )
+                    .into(),
+                );
+                return;
+            }
+
             // Check reduce-only instruction
             if self.config.use_reduce_only
                 && order.is_reduce_only()
@@ -737,6 +971,28 @@ impl OrderMatchingEngine {
     }
 
     pub fn process_modify(&mut self, command: &ModifyOrder, account_id: AccountId) {
+        if let Some(band) = self.price_limit_band
+            && let Some(price) = command.price
+            && !band.contains(price)
+        {
+            self.generate_order_modify_rejected(
+                command.trader_id,
+                command.strategy_id,
+                command.instrument_id,
+                command.client_order_id,
+                Ustr::from(
+                    format!(
+                        "Modified price {price} outside daily price limits [{}, {}] for {}",
+                        band.lower, band.upper, command.instrument_id
+                    )
+                    .as_str(),
+                ),
+                Some(command.venue_order_id),
+                Some(account_id),
+            );
+            return;
+        }
+
         if let Some(order) = self.core.get_order(command.client_order_id) {
             self.update_order(
                 &mut order.to_any(),
@@ -829,6 +1085,14 @@ impl OrderMatchingEngine {
             return;
         }
 
+        if self.is_limit_locked(order.order_side()) {
+            self.generate_order_rejected(
+                order,
+                format!("Market for {} locked at price limit", order.instrument_id()).into(),
+            );
+            return;
+        }
+
         self.fill_market_order(order);
     }
 
@@ -865,6 +1129,7 @@ impl OrderMatchingEngine {
         if self
             .core
             .is_limit_matched(order.order_side_specified(), limit_px)
+            && !self.is_limit_locked(order.order_side())
         {
             // Filling as liquidity taker
             if order.liquidity_side().is_some()
@@ -971,6 +1236,7 @@ impl OrderMatchingEngine {
                 order.set_liquidity_side(LiquiditySide::Taker);
                 self.fill_limit_order(order);
             }
+            return;
         }
 
         // order is not matched but is valid and we accept it
@@ -1417,20 +1683,28 @@ impl OrderMatchingEngine {
                     return;
                 }
 
+                if self.is_limit_locked(order.order_side()) {
+                    log::debug!(
+                        "Not filling {} as market locked at price limit",
+                        order.client_order_id()
+                    );
+                    return;
+                }
+
                 if order
                     .liquidity_side()
                     .is_some_and(|liquidity_side| liquidity_side == LiquiditySide::Maker)
                 {
                     if order.order_side() == OrderSide::Buy
                         && self.core.bid.is_some_and(|bid| bid == order_price)
-                        && !self.fill_model.is_limit_filled()
+                        && !self.fill_model.is_limit_filled(order)
                     {
                         // no filled
                         return;
                     }
                     if order.order_side() == OrderSide::Sell
                         && self.core.ask.is_some_and(|ask| ask == order_price)
-                        && !self.fill_model.is_limit_filled()
+                        && !self.fill_model.is_limit_filled(order)
                     {
                         // no filled
                         return;
@@ -1507,6 +1781,8 @@ impl OrderMatchingEngine {
         }
 
         let mut initial_market_to_limit_fill = false;
+        let mut last_fill_px: Option<Price> = None;
+        let mut is_volume_capped = false;
         for &(mut fill_px, ref fill_qty) in &fills {
             // Validate price precision
             assert!(
@@ -1535,12 +1811,29 @@ impl OrderMatchingEngine {
                 initial_market_to_limit_fill = true;
             }
 
+            // Cap the fill to the quantity permitted by the fill model
+            let capped_qty = self.fill_model.max_fill_quantity(order, *fill_qty);
+            if capped_qty < *fill_qty {
+                is_volume_capped = true;
+                if capped_qty.is_zero() {
+                    break;
+                }
+            }
+            let fill_qty = &capped_qty;
+
             if self.book_type == BookType::L1_MBP && self.fill_model.is_slipped() {
                 fill_px = match order.order_side().as_specified() {
                     OrderSideSpecified::Buy => fill_px.add(self.instrument.price_increment()),
                     OrderSideSpecified::Sell => fill_px.sub(self.instrument.price_increment()),
                 }
             }
+            fill_px = self.fill_model.adjust_fill_price(
+                order,
+                &self.instrument,
+                fill_px,
+                *fill_qty,
+                liquidity_side,
+            );
 
             // Check reduce only order
             if self.config.use_reduce_only
@@ -1578,11 +1871,24 @@ impl OrderMatchingEngine {
                 venue_position_id,
                 position.clone(),
             );
+            last_fill_px = Some(fill_px);
 
             if order.order_type() == OrderType::MarketToLimit && initial_market_to_limit_fill {
                 // filled initial level
                 return;
             }
+
+            if is_volume_capped {
+                break;
+            }
+        }
+
+        if is_volume_capped {
+            // Fill model capacity reached, orders which are not resting cancel the remainder
+            if order.is_open() && !self.core.order_exists(order.client_order_id()) {
+                self.cancel_order(order, None);
+            }
+            return;
         }
 
         if order.time_in_force() == TimeInForce::Ioc && order.is_open() {
@@ -1592,7 +1898,6 @@ impl OrderMatchingEngine {
         }
 
         if order.is_open()
-            && self.book_type == BookType::L1_MBP
             && matches!(
                 order.order_type(),
                 OrderType::Market
@@ -1601,10 +1906,45 @@ impl OrderMatchingEngine {
                     | OrderType::TrailingStopMarket
             )
         {
-            // Exhausted simulated book volume (continue aggressive filling into next level)
-            // This is a very basic implementation of slipping by a single tick, in the future
-            // we will implement more detailed fill modeling.
-            todo!("Exhausted simulated book volume")
+            // Exhausted simulated book volume, the fills have walked every available level
+            // (only the top level for an L1 book) so the last fill price is the deepest level
+            let Some(last_px) = last_fill_px else {
+                return;
+            };
+
+            let fill_px = match self.config.liquidity_exhausted_behavior {
+                LiquidityExhaustedBehavior::CancelRemainder => {
+                    self.cancel_order(order, None);
+                    return;
+                }
+                LiquidityExhaustedBehavior::LastPrice => last_px,
+                // Continue aggressive filling beyond the deepest level by slipping a single tick
+                LiquidityExhaustedBehavior::NextTick => match order.order_side().as_specified() {
+                    OrderSideSpecified::Buy => last_px.add(self.instrument.price_increment()),
+                    OrderSideSpecified::Sell => last_px.sub(self.instrument.price_increment()),
+                },
+            };
+
+            let leaves_qty = order.leaves_qty();
+            let fill_qty = self.fill_model.max_fill_quantity(order, leaves_qty);
+            if !fill_qty.is_zero() {
+                self.fill_order(
+                    order,
+                    fill_px,
+                    fill_qty,
+                    liquidity_side,
+                    venue_position_id,
+                    position,
+                );
+            }
+
+            // Fill model capacity reached, orders which are not resting cancel the remainder
+            if fill_qty < leaves_qty
+                && order.is_open()
+                && !self.core.order_exists(order.client_order_id())
+            {
+                self.cancel_order(order, None);
+            }
         }
     }
 
@@ -1617,6 +1957,26 @@ impl OrderMatchingEngine {
         venue_position_id: Option<PositionId>,
         position: Option<Position>,
     ) {
+        // calculate commission
+        let ts_now = self.clock.borrow().timestamp_ns();
+        let commission = match self.fee_model.get_commission_with_position(
+            order,
+            last_qty,
+            last_px,
+            &self.instrument,
+            position.as_ref(),
+            ts_now,
+        ) {
+            Ok(commission) => commission,
+            Err(e) => {
+                log::error!(
+                    "Cannot fill order {}: commission calculation failed: {e}",
+                    order.client_order_id()
+                );
+                return;
+            }
+        };
+
         match self.cached_filled_qty.get(&order.client_order_id()) {
             Some(filled_qty) => {
                 let leaves_qty = order.quantity() - *filled_qty;
@@ -1632,11 +1992,12 @@ impl OrderMatchingEngine {
             }
         }
 
-        // calculate commission
-        let commission = self
-            .fee_model
-            .get_commission(order, last_qty, last_px, &self.instrument)
-            .unwrap();
+        if self.config.use_t_plus_one && order.order_side() == OrderSide::Buy {
+            self.bought_today = Some(
+                self.bought_today
+                    .map_or(last_qty, |bought| bought + last_qty),
+            );
+        }
 
         let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
         self.generate_order_filled(
@@ -1650,6 +2011,10 @@ impl OrderMatchingEngine {
             liquidity_side,
         );
 
+        if order.is_closed() {
+            self.fill_model.on_order_closed(order.client_order_id());
+        }
+
         if order.is_passive() && order.is_closed() {
             // Check if order exists in OrderMatching core, and delete it if it does
             if self.core.order_exists(order.client_order_id()) {
@@ -2069,6 +2434,7 @@ impl OrderMatchingEngine {
         if order.status() != OrderStatus::Accepted {
             let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
             self.generate_order_accepted(order, venue_order_id);
+            self.fill_model.on_order_accepted(order, &self.book);
 
             if matches!(
                 order.order_type(),
@@ -2092,6 +2458,7 @@ impl OrderMatchingEngine {
         }
 
         self.generate_order_expired(&order.to_any());
+        self.fill_model.on_order_closed(order.client_order_id());
     }
 
     fn cancel_order(&mut self, order: &OrderAny, cancel_contingencies: Option<bool>) {
@@ -2114,6 +2481,7 @@ impl OrderMatchingEngine {
 
         let venue_order_id = self.ids_generator.get_venue_order_id(order).unwrap();
         self.generate_order_canceled(order, venue_order_id);
+        self.fill_model.on_order_closed(order.client_order_id());
 
         if self.config.support_contingent_orders
             && order.contingency_type().is_some()
@@ -2186,8 +2554,65 @@ impl OrderMatchingEngine {
         }
     }
 
+    /// Triggers the given stop or if-touched `order` once its trigger condition has been met.
+    ///
+    /// Market variants are filled as market orders, whereas limit variants generate an
+    /// `OrderTriggered` event (once) and are then filled if their limit price is marketable.
+    /// When the market is exactly at the trigger price the fill model decides whether the
+    /// stop is filled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is not a stop or if-touched order type.
     pub fn trigger_stop_order(&mut self, order: &mut OrderAny) {
-        todo!("trigger_stop_order")
+        // The matching core holds its own copy of the order, whereas the execution engine
+        // applies the triggered event to the cached order
+        let status = self
+            .cache
+            .borrow()
+            .order(&order.client_order_id())
+            .map_or(order.status(), OrderAny::status);
+        let is_triggered = matches!(
+            status,
+            OrderStatus::Triggered | OrderStatus::PartiallyFilled
+        );
+
+        if !is_triggered && let Some(trigger_price) = order.trigger_price() {
+            let at_trigger = match order.order_side_specified() {
+                OrderSideSpecified::Buy => self.core.ask == Some(trigger_price),
+                OrderSideSpecified::Sell => self.core.bid == Some(trigger_price),
+            };
+            if at_trigger && !self.fill_model.is_stop_filled() {
+                return;
+            }
+        }
+
+        match order.order_type() {
+            OrderType::StopMarket | OrderType::MarketIfTouched | OrderType::TrailingStopMarket => {
+                self.fill_market_order(order);
+            }
+            OrderType::StopLimit | OrderType::LimitIfTouched | OrderType::TrailingStopLimit => {
+                if !is_triggered {
+                    self.generate_order_triggered(order);
+                }
+
+                // Check for immediate fill
+                let limit_px = order
+                    .price()
+                    .expect("Limit order variant must have a price");
+                if self
+                    .core
+                    .is_limit_matched(order.order_side_specified(), limit_px)
+                {
+                    order.set_liquidity_side(LiquiditySide::Taker);
+                    self.fill_limit_order(order);
+                }
+            }
+            _ => panic!(
+                "Invalid order type {} for trigger_stop_order",
+                order.order_type()
+            ),
+        }
     }
 
     fn update_contingent_order(&mut self, order: &OrderAny) {
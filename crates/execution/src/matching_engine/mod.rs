@@ -19,6 +19,7 @@ pub mod adapter;
 pub mod config;
 pub mod engine;
 pub mod ids_generator;
+pub mod rules;
 
 #[cfg(test)]
 mod tests;
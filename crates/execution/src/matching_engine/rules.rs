@@ -0,0 +1,145 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Market rules for simulated venues with daily price limits and trading day settlement.
+
+use std::fmt::Debug;
+
+use ant_core::UnixNanos;
+use ant_model::types::Price;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+const NANOSECONDS_IN_DAY: i64 = 86_400 * 1_000_000_000;
+
+/// Returns the trading day number (days since the UNIX epoch) for the given timestamp.
+///
+/// The `offset_secs` is added to the UTC timestamp before truncating to a day. A venue whose
+/// trading day rolls at midnight China Standard Time uses `8 * 3600`, while China futures, whose
+/// night session belongs to the next trading day, use `16 * 3600` (rolling at 16:00 CST).
+#[must_use]
+pub fn trading_day(ts: UnixNanos, offset_secs: i64) -> i64 {
+    (ts.as_u64() as i64 + offset_secs * 1_000_000_000).div_euclid(NANOSECONDS_IN_DAY)
+}
+
+/// Resolves the trading day a timestamp belongs to from an exchange trading calendar.
+///
+/// Unlike a fixed [`trading_day`] offset, a calendar attributes night sessions before weekends
+/// and holidays to the next actual trading day.
+pub trait TradingDayCalendar: Debug {
+    /// Returns the trading day number (days since the UNIX epoch) for the given timestamp.
+    fn trading_day(&self, ts: UnixNanos) -> i64;
+}
+
+/// Represents the daily price limit band for an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceLimitBand {
+    /// The limit-down price.
+    pub lower: Price,
+    /// The limit-up price.
+    pub upper: Price,
+}
+
+impl PriceLimitBand {
+    /// Creates a new [`PriceLimitBand`] from the `reference` price (previous close or
+    /// settlement) and `limit_pct`, rounding each limit half-up to the `price_increment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `limit_pct` is not a valid fraction in the range [0, 1].
+    pub fn from_reference(
+        reference: Price,
+        limit_pct: f64,
+        price_increment: Price,
+    ) -> anyhow::Result<Self> {
+        if !(0.0..=1.0).contains(&limit_pct) {
+            anyhow::bail!("Invalid price limit pct {limit_pct}, expected fraction in [0, 1]");
+        }
+        let pct = Decimal::try_from(limit_pct)?;
+        let reference_dec = reference.as_decimal();
+        let increment = price_increment.as_decimal();
+        let round_to_tick = |value: Decimal| {
+            (value / increment).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                * increment
+        };
+
+        Ok(Self {
+            lower: Price::from_decimal(
+                round_to_tick(reference_dec * (Decimal::ONE - pct)),
+                reference.precision,
+            )?,
+            upper: Price::from_decimal(
+                round_to_tick(reference_dec * (Decimal::ONE + pct)),
+                reference.precision,
+            )?,
+        })
+    }
+
+    /// Returns whether the given `price` is within the band (inclusive).
+    #[must_use]
+    pub fn contains(&self, price: Price) -> bool {
+        price >= self.lower && price <= self.upper
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("10.00", 0.10, "0.01", "9.00", "11.00")]
+    #[case("10.05", 0.10, "0.01", "9.05", "11.06")]
+    #[case("25.33", 0.20, "0.01", "20.26", "30.40")]
+    #[case("3512", 0.07, "1", "3266", "3758")]
+    fn test_price_limit_band_from_reference(
+        #[case] reference: &str,
+        #[case] limit_pct: f64,
+        #[case] increment: &str,
+        #[case] lower: &str,
+        #[case] upper: &str,
+    ) {
+        let band = PriceLimitBand::from_reference(
+            Price::from(reference),
+            limit_pct,
+            Price::from(increment),
+        )
+        .unwrap();
+
+        assert_eq!(band.lower, Price::from(lower));
+        assert_eq!(band.upper, Price::from(upper));
+        assert!(band.contains(Price::from(reference)));
+    }
+
+    #[rstest]
+    fn test_price_limit_band_invalid_pct() {
+        let result = PriceLimitBand::from_reference(Price::from("10.00"), 1.5, Price::from("0.01"));
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_trading_day_rolls_at_offset() {
+        // 2024-01-02 15:59:59 UTC and 16:00:00 UTC (midnight CST)
+        let before = UnixNanos::from(1_704_211_199_000_000_000);
+        let after = UnixNanos::from(1_704_211_200_000_000_000);
+
+        assert_eq!(trading_day(before, 8 * 3600), trading_day(before, 0));
+        assert_eq!(trading_day(after, 8 * 3600), trading_day(after, 0) + 1);
+    }
+}
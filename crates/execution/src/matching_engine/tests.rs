@@ -29,10 +29,10 @@ use ant_common::{
 };
 use ant_core::{UUID4, UnixNanos};
 use ant_model::{
-    data::{BookOrder, TradeTick, stubs::OrderBookDeltaTestBuilder},
+    data::{Bar, BarType, BookOrder, QuoteTick, TradeTick, stubs::OrderBookDeltaTestBuilder},
     enums::{
         AccountType, AggressorSide, BookAction, BookType, ContingencyType, LiquiditySide, OmsType,
-        OrderSide, OrderType, TimeInForce, TrailingOffsetType,
+        OrderSide, OrderStatus, OrderType, TimeInForce, TrailingOffsetType,
     },
     events::{
         OrderEventAny, OrderEventType, OrderFilled, OrderRejected,
@@ -50,6 +50,7 @@ use ant_model::{
         Order, OrderAny, OrderTestBuilder,
         stubs::{TestOrderEventStubs, TestOrderStubs},
     },
+    position::Position,
     types::{Price, Quantity},
 };
 use rstest::{fixture, rstest};
@@ -57,8 +58,18 @@ use rust_decimal_macros::dec;
 use ustr::Ustr;
 
 use crate::{
-    matching_engine::{config::OrderMatchingEngineConfig, engine::OrderMatchingEngine},
-    models::{fee::FeeModelAny, fill::FillModel},
+    matching_engine::{
+        config::{LiquidityExhaustedBehavior, OrderMatchingEngineConfig, PriceLimitConfig},
+        engine::OrderMatchingEngine,
+        rules::{PriceLimitBand, TradingDayCalendar},
+    },
+    models::{
+        fee::FeeModelAny,
+        fill::{
+            FillModelAny, MarketImpactFillModel, ProbabilisticFillModel,
+            VolumeParticipationFillModel,
+        },
+    },
 };
 
 #[fixture]
@@ -157,6 +168,10 @@ fn engine_config() -> OrderMatchingEngineConfig {
         use_position_ids: false,
         use_random_ids: false,
         use_reduce_only: true,
+        price_limit: None,
+        use_t_plus_one: false,
+        trading_day_offset_secs: 0,
+        liquidity_exhausted_behavior: LiquidityExhaustedBehavior::NextTick,
     }
 }
 // -- HELPERS ---------------------------------------------------------------------------
@@ -174,7 +189,7 @@ fn get_order_matching_engine(
     OrderMatchingEngine::new(
         instrument,
         1,
-        FillModel::default(),
+        FillModelAny::default(),
         FeeModelAny::default(),
         BookType::L1_MBP,
         OmsType::Netting,
@@ -198,7 +213,7 @@ fn get_order_matching_engine_l2(
     OrderMatchingEngine::new(
         instrument,
         1,
-        FillModel::default(),
+        FillModelAny::default(),
         FeeModelAny::default(),
         BookType::L2_MBP,
         OmsType::Netting,
@@ -2700,3 +2715,804 @@ fn test_updating_of_contingent_orders(
     assert_eq!(updated.client_order_id, client_order_id_contingent);
     assert_eq!(updated.quantity, Quantity::from("2.000"));
 }
+
+// -- PRICE LIMITS AND T+1 -------------------------------------------------------------------
+
+const ONE_DAY_NS: u64 = 86_400_000_000_000;
+
+fn equity_quote(instrument_id: InstrumentId, bid: &str, ask: &str, ts: u64) -> QuoteTick {
+    QuoteTick::new(
+        instrument_id,
+        Price::from(bid),
+        Price::from(ask),
+        Quantity::from("1000"),
+        Quantity::from("1000"),
+        UnixNanos::from(ts),
+        UnixNanos::from(ts),
+    )
+}
+
+fn equity_order(
+    instrument_id: InstrumentId,
+    order_type: OrderType,
+    side: OrderSide,
+    quantity: &str,
+    price: Option<&str>,
+    client_order_id: &str,
+) -> OrderAny {
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(instrument_id)
+        .side(side)
+        .quantity(Quantity::from(quantity))
+        .client_order_id(ClientOrderId::from(client_order_id))
+        .submit(true);
+    if let Some(price) = price {
+        builder.price(Price::from(price));
+    }
+    builder.build()
+}
+
+fn price_limit_config(limit_locked_no_fill: bool) -> OrderMatchingEngineConfig {
+    OrderMatchingEngineConfig {
+        price_limit: Some(PriceLimitConfig::new(0.10, limit_locked_no_fill)),
+        use_t_plus_one: true,
+        ..Default::default()
+    }
+}
+
+#[rstest]
+fn test_process_order_outside_price_limits_rejected(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        None,
+        None,
+        Some(price_limit_config(false)),
+        None,
+    );
+    engine.set_price_limit_reference(Price::from("100.00"));
+    let mut order = equity_order(
+        instrument.id(),
+        OrderType::Limit,
+        OrderSide::Buy,
+        "100",
+        Some("110.01"),
+        "O-1",
+    );
+
+    engine.process_order(&mut order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[0].message().unwrap(),
+        Ustr::from("Order price 110.01 outside daily price limits [90.00, 110.00] for AAPL.XNAS")
+    );
+}
+
+#[rstest]
+fn test_price_limits_roll_from_previous_day_last_price(equity_aapl: Equity) {
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        None,
+        None,
+        Some(price_limit_config(false)),
+        None,
+    );
+    engine.set_price_limit_reference(Price::from("100.00"));
+
+    engine.process_trade_tick(&TradeTick::new(
+        instrument.id(),
+        Price::from("105.00"),
+        Quantity::from("100"),
+        AggressorSide::Buyer,
+        TradeId::new("1"),
+        UnixNanos::from(1),
+        UnixNanos::from(1),
+    ));
+    assert_eq!(
+        engine.price_limit_band().unwrap().upper,
+        Price::from("110.00")
+    );
+
+    engine.process_quote_tick(&equity_quote(
+        instrument.id(),
+        "105.00",
+        "105.01",
+        ONE_DAY_NS,
+    ));
+
+    assert_eq!(
+        engine.price_limit_band(),
+        Some(PriceLimitBand {
+            lower: Price::from("94.50"),
+            upper: Price::from("115.50"),
+        })
+    );
+}
+
+#[rstest]
+fn test_market_order_when_limit_locked_rejected(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        None,
+        None,
+        Some(price_limit_config(true)),
+        None,
+    );
+    engine.set_price_limit_reference(Price::from("100.00"));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "110.00", "110.00", 1));
+    let mut order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "100",
+        None,
+        "O-1",
+    );
+
+    engine.process_order(&mut order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[0].message().unwrap(),
+        Ustr::from("Market for AAPL.XNAS locked at price limit")
+    );
+}
+
+#[rstest]
+fn test_t_plus_one_rejects_selling_quantity_bought_today(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        Some(cache.clone()),
+        None,
+        Some(price_limit_config(false)),
+        None,
+    );
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+
+    // Buy 100 today
+    let mut buy_order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "100",
+        None,
+        "O-1",
+    );
+    engine.process_order(&mut buy_order, account_id);
+
+    // Position of 200 held, of which 100 was bought today
+    let position_id = PositionId::new(format!(
+        "{}-{}",
+        buy_order.instrument_id(),
+        buy_order.strategy_id()
+    ));
+    let fill = TestOrderEventStubs::filled(
+        &buy_order,
+        &instrument,
+        None,
+        Some(position_id),
+        Some(Price::from("100.01")),
+        Some(Quantity::from("200")),
+        None,
+        None,
+        None,
+        Some(account_id),
+    );
+    let OrderEventAny::Filled(fill) = fill else {
+        panic!("Expected fill");
+    };
+    cache
+        .borrow_mut()
+        .add_position(Position::new(&instrument, fill), OmsType::Netting)
+        .unwrap();
+
+    let mut sell_too_many = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Sell,
+        "150",
+        None,
+        "O-2",
+    );
+    engine.process_order(&mut sell_too_many, account_id);
+    let mut sell_sellable = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Sell,
+        "100",
+        None,
+        "O-3",
+    );
+    engine.process_order(&mut sell_sellable, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 3);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Filled);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[1].message().unwrap(),
+        Ustr::from("T+1 rule: order quantity 150 exceeds sellable quantity 100 for AAPL.XNAS")
+    );
+    assert_eq!(saved_messages[2].event_type(), OrderEventType::Filled);
+}
+
+fn add_long_position(
+    cache: &Rc<RefCell<Cache>>,
+    instrument: &InstrumentEnum,
+    order: &OrderAny,
+    position_id: PositionId,
+    quantity: &str,
+    oms_type: OmsType,
+    account_id: AccountId,
+) {
+    let fill = TestOrderEventStubs::filled(
+        order,
+        instrument,
+        None,
+        Some(position_id),
+        Some(Price::from("100.01")),
+        Some(Quantity::from(quantity)),
+        None,
+        None,
+        None,
+        Some(account_id),
+    );
+    let OrderEventAny::Filled(fill) = fill else {
+        panic!("Expected fill");
+    };
+    cache
+        .borrow_mut()
+        .add_position(Position::new(instrument, fill), oms_type)
+        .unwrap();
+}
+
+#[rstest]
+fn test_t_plus_one_applies_to_hedging_positions(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = OrderMatchingEngine::new(
+        instrument.clone(),
+        1,
+        FillModelAny::default(),
+        FeeModelAny::default(),
+        BookType::L1_MBP,
+        OmsType::Hedging,
+        AccountType::Margin,
+        Rc::new(RefCell::new(TestClock::new())),
+        cache.clone(),
+        price_limit_config(false),
+    );
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+
+    let mut buy_order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "100",
+        None,
+        "O-1",
+    );
+    engine.process_order(&mut buy_order, account_id);
+    add_long_position(
+        &cache,
+        &instrument,
+        &buy_order,
+        PositionId::new("P-1"),
+        "200",
+        OmsType::Hedging,
+        account_id,
+    );
+
+    let mut sell_too_many = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Sell,
+        "150",
+        None,
+        "O-2",
+    );
+    engine.process_order(&mut sell_too_many, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Filled);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[1].message().unwrap(),
+        Ustr::from("T+1 rule: order quantity 150 exceeds sellable quantity 100 for AAPL.XNAS")
+    );
+}
+
+/// A calendar attributing every timestamp to the same trading day, as for a night session
+/// held before a weekend.
+#[derive(Debug)]
+struct SingleTradingDayCalendar;
+
+impl TradingDayCalendar for SingleTradingDayCalendar {
+    fn trading_day(&self, _ts: UnixNanos) -> i64 {
+        0
+    }
+}
+
+#[rstest]
+fn test_t_plus_one_uses_trading_calendar_for_day_roll(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine = get_order_matching_engine(
+        instrument.clone(),
+        Some(cache.clone()),
+        None,
+        Some(price_limit_config(false)),
+        None,
+    );
+    engine.set_trading_calendar(Rc::new(SingleTradingDayCalendar));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+
+    let mut buy_order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "100",
+        None,
+        "O-1",
+    );
+    engine.process_order(&mut buy_order, account_id);
+    let position_id = PositionId::new(format!(
+        "{}-{}",
+        buy_order.instrument_id(),
+        buy_order.strategy_id()
+    ));
+    add_long_position(
+        &cache,
+        &instrument,
+        &buy_order,
+        position_id,
+        "100",
+        OmsType::Netting,
+        account_id,
+    );
+
+    // A calendar day later, but still the same trading day
+    engine.process_quote_tick(&equity_quote(
+        instrument.id(),
+        "100.00",
+        "100.01",
+        ONE_DAY_NS,
+    ));
+    let mut sell_order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Sell,
+        "100",
+        None,
+        "O-2",
+    );
+    engine.process_order(&mut sell_order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Rejected);
+    assert_eq!(
+        saved_messages[1].message().unwrap(),
+        Ustr::from("T+1 rule: order quantity 100 exceeds sellable quantity 0 for AAPL.XNAS")
+    );
+}
+
+// -- STOP TRIGGERING AND EXHAUSTED LIQUIDITY -------------------------------------------------
+
+fn no_slippage_fill_model(prob_fill_on_stop: f64) -> FillModelAny {
+    FillModelAny::Probabilistic(
+        ProbabilisticFillModel::new(1.0, prob_fill_on_stop, 0.0, None).unwrap(),
+    )
+}
+
+fn equity_stop_order(
+    instrument_id: InstrumentId,
+    order_type: OrderType,
+    trigger_price: &str,
+    price: Option<&str>,
+) -> OrderAny {
+    let mut builder = OrderTestBuilder::new(order_type);
+    builder
+        .instrument_id(instrument_id)
+        .side(OrderSide::Buy)
+        .quantity(Quantity::from("100"))
+        .trigger_price(Price::from(trigger_price))
+        .client_order_id(ClientOrderId::from("O-1"))
+        .submit(true);
+    if let Some(price) = price {
+        builder.price(Price::from(price));
+    }
+    builder.build()
+}
+
+#[rstest]
+#[case(
+    LiquidityExhaustedBehavior::NextTick,
+    OrderEventType::Filled,
+    Some("100.02")
+)]
+#[case(
+    LiquidityExhaustedBehavior::LastPrice,
+    OrderEventType::Filled,
+    Some("100.01")
+)]
+#[case(
+    LiquidityExhaustedBehavior::CancelRemainder,
+    OrderEventType::Canceled,
+    None
+)]
+fn test_market_order_when_liquidity_exhausted(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+    #[case] behavior: LiquidityExhaustedBehavior,
+    #[case] expected_event_type: OrderEventType,
+    #[case] expected_last_px: Option<&str>,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let config = OrderMatchingEngineConfig {
+        liquidity_exhausted_behavior: behavior,
+        ..Default::default()
+    };
+    let mut engine = get_order_matching_engine(instrument.clone(), None, None, Some(config), None);
+    engine.set_fill_model(no_slippage_fill_model(1.0));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+    let mut order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "1500",
+        None,
+        "O-1",
+    );
+
+    engine.process_order(&mut order, account_id);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let OrderEventAny::Filled(first_fill) = &saved_messages[0] else {
+        panic!("Expected OrderFilled event in first message");
+    };
+    assert_eq!(first_fill.last_qty, Quantity::from("1000"));
+    assert_eq!(first_fill.last_px, Price::from("100.01"));
+    assert_eq!(saved_messages[1].event_type(), expected_event_type);
+    if let Some(expected_last_px) = expected_last_px {
+        let OrderEventAny::Filled(second_fill) = &saved_messages[1] else {
+            panic!("Expected OrderFilled event in second message");
+        };
+        assert_eq!(second_fill.last_qty, Quantity::from("500"));
+        assert_eq!(second_fill.last_px, Price::from(expected_last_px));
+    }
+}
+
+#[rstest]
+fn test_market_order_when_liquidity_exhausted_walks_book_levels(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine_l2(instrument.clone(), None, None, None, None);
+    engine.set_fill_model(no_slippage_fill_model(1.0));
+    for (order_id, price) in [(1, "100.01"), (2, "100.02"), (3, "100.03")] {
+        let delta = OrderBookDeltaTestBuilder::new(instrument.id())
+            .book_action(BookAction::Add)
+            .book_order(BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from("100"),
+                order_id,
+            ))
+            .build();
+        engine.process_order_book_delta(&delta);
+    }
+    let mut order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "500",
+        None,
+        "O-1",
+    );
+
+    engine.process_order(&mut order, account_id);
+
+    let fills: Vec<(Price, Quantity)> = get_order_event_handler_messages(order_event_handler)
+        .iter()
+        .map(|event| {
+            let OrderEventAny::Filled(fill) = event else {
+                panic!("Expected OrderFilled event, was {event:?}");
+            };
+            (fill.last_px, fill.last_qty)
+        })
+        .collect();
+    assert_eq!(
+        fills,
+        vec![
+            (Price::from("100.01"), Quantity::from("100")),
+            (Price::from("100.02"), Quantity::from("100")),
+            (Price::from("100.03"), Quantity::from("100")),
+            (Price::from("100.04"), Quantity::from("200")),
+        ]
+    );
+    assert!(order.is_closed());
+}
+
+#[rstest]
+#[case(OrderType::StopMarket)]
+#[case(OrderType::MarketIfTouched)]
+fn test_trigger_stop_order_market_variants_filled(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+    #[case] order_type: OrderType,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine(instrument.clone(), None, None, None, None);
+    engine.set_fill_model(no_slippage_fill_model(1.0));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+    // Buy MIT triggers at or below the trigger price, buy STOP at or above
+    let trigger_price = match order_type {
+        OrderType::MarketIfTouched => "99.95",
+        _ => "100.05",
+    };
+    let mut order = equity_stop_order(instrument.id(), order_type, trigger_price, None);
+    engine.process_order(&mut order, account_id);
+
+    let (bid, ask) = match order_type {
+        OrderType::MarketIfTouched => ("99.93", "99.94"),
+        _ => ("100.05", "100.06"),
+    };
+    engine.process_quote_tick(&equity_quote(instrument.id(), bid, ask, 1));
+    engine.trigger_stop_order(&mut order);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+    let OrderEventAny::Filled(fill) = &saved_messages[1] else {
+        panic!("Expected OrderFilled event in second message");
+    };
+    assert_eq!(fill.last_px, Price::from(ask));
+    assert_eq!(fill.liquidity_side, LiquiditySide::Taker);
+    assert!(order.is_closed());
+}
+
+#[rstest]
+fn test_trigger_stop_order_stop_limit_triggers_once_then_fills(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let cache = Rc::new(RefCell::new(Cache::default()));
+    let mut engine =
+        get_order_matching_engine(instrument.clone(), Some(cache.clone()), None, None, None);
+    engine.set_fill_model(no_slippage_fill_model(1.0));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+    let mut order = equity_stop_order(
+        instrument.id(),
+        OrderType::StopLimit,
+        "100.05",
+        Some("100.04"),
+    );
+    engine.process_order(&mut order, account_id);
+
+    // Triggered but limit price not marketable
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.05", "100.06", 1));
+    engine.trigger_stop_order(&mut order);
+
+    // Apply the triggered event to the cached order as the execution engine would
+    let mut cached_order = order.clone();
+    for event in get_order_event_handler_messages(order_event_handler.clone()) {
+        if event.event_type() == OrderEventType::Triggered {
+            cached_order.apply(event).unwrap();
+        }
+    }
+    assert_eq!(cached_order.status(), OrderStatus::Triggered);
+    cache
+        .borrow_mut()
+        .add_order(cached_order, None, None, false)
+        .unwrap();
+
+    // Triggering again is a no-op
+    engine.trigger_stop_order(&mut order);
+
+    // Market moves through the limit price
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.03", "100.04", 2));
+    engine.trigger_stop_order(&mut order);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    let event_types: Vec<OrderEventType> = saved_messages
+        .iter()
+        .map(OrderEventAny::event_type)
+        .collect();
+    assert_eq!(
+        event_types,
+        vec![
+            OrderEventType::Accepted,
+            OrderEventType::Triggered,
+            OrderEventType::Filled,
+        ]
+    );
+}
+
+#[rstest]
+fn test_trigger_stop_order_at_trigger_price_uses_fill_model(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine(instrument.clone(), None, None, None, None);
+    engine.set_fill_model(no_slippage_fill_model(0.0));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.00", "100.01", 0));
+    let mut order = equity_stop_order(instrument.id(), OrderType::StopMarket, "100.05", None);
+    engine.process_order(&mut order, account_id);
+
+    engine.process_quote_tick(&equity_quote(instrument.id(), "100.04", "100.05", 1));
+    engine.trigger_stop_order(&mut order);
+
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    assert_eq!(saved_messages[0].event_type(), OrderEventType::Accepted);
+}
+
+// -- FILL MODELS -----------------------------------------------------------------------------
+
+#[rstest]
+fn test_market_order_capped_by_volume_participation_fill_model(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let config = OrderMatchingEngineConfig {
+        bar_execution: true,
+        ..Default::default()
+    };
+    let mut engine = get_order_matching_engine(instrument.clone(), None, None, Some(config), None);
+    engine.set_fill_model(FillModelAny::VolumeParticipation(
+        VolumeParticipationFillModel::new(0.25).unwrap(),
+    ));
+    engine.process_bar(&Bar::new(
+        BarType::from("AAPL.XNAS-1-MINUTE-LAST-EXTERNAL"),
+        Price::from("100.00"),
+        Price::from("100.00"),
+        Price::from("100.00"),
+        Price::from("100.00"),
+        Quantity::from("1000"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    ));
+    let mut order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "1000",
+        None,
+        "O-1",
+    );
+
+    engine.process_order(&mut order, account_id);
+
+    // Only a quarter of the bar volume may be filled, the remainder is canceled
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 2);
+    let OrderEventAny::Filled(fill) = &saved_messages[0] else {
+        panic!("Expected OrderFilled event in first message");
+    };
+    assert_eq!(fill.last_qty, Quantity::from("250"));
+    assert_eq!(fill.last_px, Price::from("100.00"));
+    assert_eq!(saved_messages[1].event_type(), OrderEventType::Canceled);
+}
+
+#[rstest]
+fn test_market_order_with_market_impact_fill_model(
+    order_event_handler: ShareableMessageHandler,
+    account_id: AccountId,
+    equity_aapl: Equity,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_process(),
+        order_event_handler.clone(),
+    );
+    let instrument = InstrumentEnum::Equity(equity_aapl);
+    let mut engine = get_order_matching_engine(instrument.clone(), None, None, None, None);
+    engine.set_fill_model(FillModelAny::MarketImpact(
+        MarketImpactFillModel::new(1.0, 0.02, 100_000.0).unwrap(),
+    ));
+    engine.process_quote_tick(&equity_quote(instrument.id(), "99.99", "100.00", 0));
+    let mut order = equity_order(
+        instrument.id(),
+        OrderType::Market,
+        OrderSide::Buy,
+        "1000",
+        None,
+        "O-1",
+    );
+
+    engine.process_order(&mut order, account_id);
+
+    // Impact of 1.0 * 0.02 * sqrt(1000 / 100000) = 0.2%
+    let saved_messages = get_order_event_handler_messages(order_event_handler);
+    assert_eq!(saved_messages.len(), 1);
+    let OrderEventAny::Filled(fill) = &saved_messages[0] else {
+        panic!("Expected OrderFilled event");
+    };
+    assert_eq!(fill.last_px, Price::from("100.20"));
+}
@@ -13,13 +13,21 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::HashMap;
+
+use ant_core::UnixNanos;
 use ant_model::{
-    enums::LiquiditySide,
+    enums::{LiquiditySide, OrderSide},
+    identifiers::InstrumentId,
     instruments::{Instrument, InstrumentEnum},
     orders::{Order, OrderAny},
+    position::Position,
     types::{Money, Price, Quantity},
 };
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use ustr::Ustr;
+
+use crate::matching_engine::rules::trading_day;
 
 pub trait FeeModel {
     /// Calculates commission for a fill.
@@ -34,12 +42,46 @@ pub trait FeeModel {
         fill_px: Price,
         instrument: &InstrumentEnum,
     ) -> anyhow::Result<Money>;
+
+    /// Calculates commission for a fill against the current `position` for the instrument.
+    ///
+    /// Models which charge differently for opening and closing fills override this, the
+    /// default ignores the position and delegates to [`FeeModel::get_commission`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if commission calculation fails.
+    fn get_commission_with_position(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentEnum,
+        _position: Option<&Position>,
+        _ts_fill: UnixNanos,
+    ) -> anyhow::Result<Money> {
+        self.get_commission(order, fill_quantity, fill_px, instrument)
+    }
+
+    /// Checks that commissions can be calculated for fills of `instrument`.
+    ///
+    /// Called when an instrument is added to a venue, so that missing configuration is reported
+    /// up front rather than on the first fill. The default accepts every instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the model has no fee rate for `instrument`.
+    fn check_instrument(&self, _instrument: &InstrumentEnum) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum FeeModelAny {
     Fixed(FixedFeeModel),
     MakerTaker(MakerTakerFeeModel),
+    ChinaFutures(ChinaFuturesFeeModel),
+    AShare(AShareFeeModel),
 }
 
 impl FeeModel for FeeModelAny {
@@ -55,6 +97,64 @@ impl FeeModel for FeeModelAny {
             Self::MakerTaker(model) => {
                 model.get_commission(order, fill_quantity, fill_px, instrument)
             }
+            Self::ChinaFutures(model) => {
+                model.get_commission(order, fill_quantity, fill_px, instrument)
+            }
+            Self::AShare(model) => model.get_commission(order, fill_quantity, fill_px, instrument),
+        }
+    }
+
+    fn get_commission_with_position(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentEnum,
+        position: Option<&Position>,
+        ts_fill: UnixNanos,
+    ) -> anyhow::Result<Money> {
+        match self {
+            Self::Fixed(model) => model.get_commission_with_position(
+                order,
+                fill_quantity,
+                fill_px,
+                instrument,
+                position,
+                ts_fill,
+            ),
+            Self::MakerTaker(model) => model.get_commission_with_position(
+                order,
+                fill_quantity,
+                fill_px,
+                instrument,
+                position,
+                ts_fill,
+            ),
+            Self::ChinaFutures(model) => model.get_commission_with_position(
+                order,
+                fill_quantity,
+                fill_px,
+                instrument,
+                position,
+                ts_fill,
+            ),
+            Self::AShare(model) => model.get_commission_with_position(
+                order,
+                fill_quantity,
+                fill_px,
+                instrument,
+                position,
+                ts_fill,
+            ),
+        }
+    }
+
+    fn check_instrument(&self, instrument: &InstrumentEnum) -> anyhow::Result<()> {
+        match self {
+            Self::Fixed(model) => model.check_instrument(instrument),
+            Self::MakerTaker(model) => model.check_instrument(instrument),
+            Self::ChinaFutures(model) => model.check_instrument(instrument),
+            Self::AShare(model) => model.check_instrument(instrument),
         }
     }
 }
@@ -132,21 +232,522 @@ impl FeeModel for MakerTakerFeeModel {
     }
 }
 
+/// Returns the product code for `instrument`, used to look up product level fee rates.
+///
+/// This is the leading alphabetic part of the underlying (or symbol if no underlying), in lower
+/// case, e.g. `rb` for `rb2405.SHFE` or `sr` for `SR405.CZCE`.
+fn product_code(instrument: &InstrumentEnum) -> Ustr {
+    let underlying = instrument.underlying();
+    let symbol = instrument.symbol();
+    let base = underlying
+        .as_ref()
+        .filter(|u| !u.is_empty())
+        .map_or(symbol.as_str(), |u| u.as_str());
+    let code: String = base
+        .chars()
+        .take_while(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    Ustr::from(&code)
+}
+
+/// Represents fee rates resolved per instrument, then per product, then a default.
+#[derive(Debug, Clone)]
+pub struct FeeRateTable<R> {
+    default: Option<R>,
+    products: HashMap<Ustr, R>,
+    instruments: HashMap<InstrumentId, R>,
+}
+
+impl<R> FeeRateTable<R> {
+    /// Creates a new [`FeeRateTable`] instance with an optional `default` rate.
+    #[must_use]
+    pub fn new(default: Option<R>) -> Self {
+        Self {
+            default,
+            products: HashMap::new(),
+            instruments: HashMap::new(),
+        }
+    }
+
+    /// Sets the rate for all instruments of the given `product` code (case-insensitive).
+    pub fn set_product_rate(&mut self, product: &str, rate: R) {
+        self.products
+            .insert(Ustr::from(&product.to_ascii_lowercase()), rate);
+    }
+
+    /// Sets the rate for the given `instrument_id`, taking precedence over product rates.
+    pub fn set_instrument_rate(&mut self, instrument_id: InstrumentId, rate: R) {
+        self.instruments.insert(instrument_id, rate);
+    }
+
+    /// Returns the rate for `instrument`, or `None` if no rate applies.
+    #[must_use]
+    pub fn resolve(&self, instrument: &InstrumentEnum) -> Option<&R> {
+        self.instruments
+            .get(&instrument.id())
+            .or_else(|| self.products.get(&product_code(instrument)))
+            .or(self.default.as_ref())
+    }
+
+    fn resolve_or_err(&self, instrument: &InstrumentEnum) -> anyhow::Result<&R> {
+        self.resolve(instrument)
+            .ok_or_else(|| anyhow::anyhow!("No fee rate configured for {}", instrument.id()))
+    }
+}
+
+impl<R> Default for FeeRateTable<R> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+/// Represents how a China futures fee rate is applied to a fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuturesFeeBasis {
+    /// A fixed fee per lot (contract).
+    PerLot,
+    /// A rate applied to the turnover (notional value) of the fill.
+    Turnover,
+}
+
+/// Represents the fee rates for a China futures product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuturesFeeRate {
+    /// How the rates are applied.
+    pub basis: FuturesFeeBasis,
+    /// The rate for opening fills.
+    pub open: Decimal,
+    /// The rate for fills closing a position opened on a previous trading day.
+    pub close_yesterday: Decimal,
+    /// The rate for fills closing a position opened on the same trading day.
+    pub close_today: Decimal,
+}
+
+impl FuturesFeeRate {
+    /// Creates a new [`FuturesFeeRate`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any rate is negative.
+    pub fn new(
+        basis: FuturesFeeBasis,
+        open: Decimal,
+        close_yesterday: Decimal,
+        close_today: Decimal,
+    ) -> anyhow::Result<Self> {
+        if open.is_sign_negative()
+            || close_yesterday.is_sign_negative()
+            || close_today.is_sign_negative()
+        {
+            anyhow::bail!("Fee rates must be greater than or equal to zero")
+        }
+        Ok(Self {
+            basis,
+            open,
+            close_yesterday,
+            close_today,
+        })
+    }
+
+    fn fee(&self, rate: Decimal, quantity: Decimal, notional: Decimal) -> Decimal {
+        match self.basis {
+            FuturesFeeBasis::PerLot => rate * quantity,
+            FuturesFeeBasis::Turnover => rate * notional,
+        }
+    }
+}
+
+/// Provides a fee model for China futures exchanges (SHFE, INE, DCE, CZCE, GFEX and CFFEX).
+///
+/// Fees are charged per lot or on turnover, with separate rates for opening, closing yesterday's
+/// positions and closing today's positions. A closing fill is treated as close-today when the
+/// position was opened on the same trading day as the fill, where trading days are determined
+/// with `trading_day_offset_secs` (as for the matching engine) and weekends roll forward to
+/// Monday so that Friday night sessions belong to the following Monday.
+///
+/// Without a position (see [`FeeModel::get_commission`]) fills are charged at the open rate.
+#[derive(Debug, Clone)]
+pub struct ChinaFuturesFeeModel {
+    rates: FeeRateTable<FuturesFeeRate>,
+    trading_day_offset_secs: i64,
+}
+
+impl ChinaFuturesFeeModel {
+    /// Creates a new [`ChinaFuturesFeeModel`] instance.
+    #[must_use]
+    pub const fn new(rates: FeeRateTable<FuturesFeeRate>, trading_day_offset_secs: i64) -> Self {
+        Self {
+            rates,
+            trading_day_offset_secs,
+        }
+    }
+
+    /// Returns the fee rate table.
+    #[must_use]
+    pub const fn rates(&self) -> &FeeRateTable<FuturesFeeRate> {
+        &self.rates
+    }
+
+    fn trading_day(&self, ts: UnixNanos) -> i64 {
+        let day = trading_day(ts, self.trading_day_offset_secs);
+        // Day 0 (1970-01-01) was a Thursday, so Saturday is 2 and Sunday is 3 (mod 7)
+        match day.rem_euclid(7) {
+            2 => day + 2,
+            3 => day + 1,
+            _ => day,
+        }
+    }
+
+    fn commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentEnum,
+        position: Option<&Position>,
+        ts_fill: UnixNanos,
+    ) -> anyhow::Result<Money> {
+        let rate = self.rates.resolve_or_err(instrument)?;
+        let notional = instrument
+            .calculate_notional_value(fill_quantity, fill_px, Some(false))
+            .as_decimal();
+        let fill_qty = fill_quantity.as_decimal();
+
+        let (close_qty, close_rate) = match position {
+            Some(position)
+                if position.is_open() && position.closing_order_side() == order.order_side() =>
+            {
+                let close_qty = fill_qty.min(position.quantity.as_decimal());
+                let close_rate =
+                    if self.trading_day(position.ts_opened) == self.trading_day(ts_fill) {
+                        rate.close_today
+                    } else {
+                        rate.close_yesterday
+                    };
+                (close_qty, close_rate)
+            }
+            _ => (Decimal::ZERO, rate.open),
+        };
+
+        // Any quantity beyond the position flips it, and is charged at the open rate
+        let open_qty = fill_qty - close_qty;
+        let mut commission = Decimal::ZERO;
+        for (qty, fee_rate) in [(close_qty, close_rate), (open_qty, rate.open)] {
+            if qty.is_zero() {
+                continue;
+            }
+            commission += rate.fee(fee_rate, qty, notional * qty / fill_qty);
+        }
+
+        Money::from_decimal(commission, instrument.quote_currency())
+    }
+}
+
+impl FeeModel for ChinaFuturesFeeModel {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentEnum,
+    ) -> anyhow::Result<Money> {
+        self.commission(
+            order,
+            fill_quantity,
+            fill_px,
+            instrument,
+            None,
+            UnixNanos::default(),
+        )
+    }
+
+    fn get_commission_with_position(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentEnum,
+        position: Option<&Position>,
+        ts_fill: UnixNanos,
+    ) -> anyhow::Result<Money> {
+        self.commission(order, fill_quantity, fill_px, instrument, position, ts_fill)
+    }
+
+    fn check_instrument(&self, instrument: &InstrumentEnum) -> anyhow::Result<()> {
+        self.rates.resolve_or_err(instrument).map(|_| ())
+    }
+}
+
+/// Represents the fee rates for China A-share trading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AShareFeeRate {
+    /// The broker commission rate on turnover (both sides).
+    pub commission_rate: Decimal,
+    /// The minimum broker commission per order.
+    pub min_commission: Decimal,
+    /// The stamp duty rate on turnover (sell side only).
+    pub stamp_duty_rate: Decimal,
+    /// The exchange transfer fee rate on turnover (both sides).
+    pub transfer_fee_rate: Decimal,
+}
+
+impl AShareFeeRate {
+    /// Creates a new [`AShareFeeRate`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any rate or the minimum commission is negative.
+    pub fn new(
+        commission_rate: Decimal,
+        min_commission: Decimal,
+        stamp_duty_rate: Decimal,
+        transfer_fee_rate: Decimal,
+    ) -> anyhow::Result<Self> {
+        if commission_rate.is_sign_negative()
+            || min_commission.is_sign_negative()
+            || stamp_duty_rate.is_sign_negative()
+            || transfer_fee_rate.is_sign_negative()
+        {
+            anyhow::bail!("Fee rates must be greater than or equal to zero")
+        }
+        Ok(Self {
+            commission_rate,
+            min_commission,
+            stamp_duty_rate,
+            transfer_fee_rate,
+        })
+    }
+}
+
+impl Default for AShareFeeRate {
+    /// Returns the typical rates: 2.5 bps commission with a 5 CNY minimum, 5 bps stamp duty
+    /// and a 0.1 bps transfer fee.
+    fn default() -> Self {
+        Self {
+            commission_rate: Decimal::new(25, 5),
+            min_commission: Decimal::from(5),
+            stamp_duty_rate: Decimal::new(5, 4),
+            transfer_fee_rate: Decimal::new(1, 5),
+        }
+    }
+}
+
+/// Provides a fee model for China A-shares (SSE, SZSE and BSE).
+///
+/// Charges a broker commission on both sides with a per-order minimum, a stamp duty on sells
+/// only, and a transfer fee on both sides. The minimum commission is applied to the order as a
+/// whole, so partial fills after the first are only charged commission above the minimum.
+#[derive(Debug, Clone)]
+pub struct AShareFeeModel {
+    rates: FeeRateTable<AShareFeeRate>,
+}
+
+impl AShareFeeModel {
+    /// Creates a new [`AShareFeeModel`] instance.
+    #[must_use]
+    pub const fn new(rates: FeeRateTable<AShareFeeRate>) -> Self {
+        Self { rates }
+    }
+
+    /// Returns the fee rate table.
+    #[must_use]
+    pub const fn rates(&self) -> &FeeRateTable<AShareFeeRate> {
+        &self.rates
+    }
+}
+
+impl Default for AShareFeeModel {
+    fn default() -> Self {
+        Self::new(FeeRateTable::new(Some(AShareFeeRate::default())))
+    }
+}
+
+impl FeeModel for AShareFeeModel {
+    fn get_commission(
+        &self,
+        order: &OrderAny,
+        fill_quantity: Quantity,
+        fill_px: Price,
+        instrument: &InstrumentEnum,
+    ) -> anyhow::Result<Money> {
+        let rate = self.rates.resolve_or_err(instrument)?;
+        let notional = instrument
+            .calculate_notional_value(fill_quantity, fill_px, Some(false))
+            .as_decimal();
+
+        let prev_notional = match order.avg_px() {
+            Some(avg_px) if order.filled_qty().is_positive() => {
+                let avg_px = Decimal::try_from(avg_px)?;
+                order.filled_qty().as_decimal() * avg_px * instrument.multiplier().as_decimal()
+            }
+            _ => Decimal::ZERO,
+        };
+        let charged = |turnover: Decimal| {
+            if turnover.is_zero() {
+                Decimal::ZERO
+            } else {
+                (turnover * rate.commission_rate).max(rate.min_commission)
+            }
+        };
+        let mut commission = charged(prev_notional + notional) - charged(prev_notional);
+
+        if order.order_side() == OrderSide::Sell {
+            commission += notional * rate.stamp_duty_rate;
+        }
+        commission += notional * rate.transfer_fee_rate;
+
+        Money::from_decimal(commission, instrument.quote_currency())
+    }
+
+    fn check_instrument(&self, instrument: &InstrumentEnum) -> anyhow::Result<()> {
+        self.rates.resolve_or_err(instrument).map(|_| ())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use ant_core::UnixNanos;
     use ant_model::{
-        enums::{LiquiditySide, OrderSide, OrderType},
-        instruments::{Instrument, InstrumentEnum, stubs::audusd_sim},
+        enums::{AssetClass, LiquiditySide, OrderSide, OrderType},
+        events::OrderEventAny,
+        identifiers::{InstrumentId, Symbol},
+        instruments::{Equity, FuturesContract, Instrument, InstrumentEnum, stubs::audusd_sim},
         orders::{
-            Order,
+            Order, OrderAny,
             builder::OrderTestBuilder,
             stubs::{TestOrderEventStubs, TestOrderStubs},
         },
+        position::Position,
         types::{Currency, Money, Price, Quantity},
     };
-    use rstest::rstest;
+    use chrono::{TimeZone, Utc};
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+    use ustr::Ustr;
+
+    use super::{
+        AShareFeeModel, ChinaFuturesFeeModel, FeeModel, FeeRateTable, FixedFeeModel,
+        FuturesFeeBasis, FuturesFeeRate, MakerTakerFeeModel,
+    };
+
+    const CHINA_FUTURES_OFFSET_SECS: i64 = 16 * 3600;
+
+    /// Returns the UNIX timestamp for the given China Standard Time (UTC+8).
+    fn cst(y: i32, m: u32, d: u32, hour: u32, min: u32) -> UnixNanos {
+        let ts = Utc.with_ymd_and_hms(y, m, d, hour, min, 0).unwrap() - chrono::Duration::hours(8);
+        UnixNanos::from(ts.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    #[fixture]
+    fn rebar() -> InstrumentEnum {
+        InstrumentEnum::FuturesContract(FuturesContract::new(
+            InstrumentId::from("rb2405.SHFE"),
+            Symbol::from("rb2405"),
+            AssetClass::Commodity,
+            Some(Ustr::from("SHFE")),
+            Ustr::from("rb"),
+            UnixNanos::default(),
+            cst(2024, 5, 15, 15, 0),
+            Currency::CNY(),
+            0,
+            Price::from("1"),
+            Quantity::from(10),
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ))
+    }
+
+    #[fixture]
+    fn pufa() -> InstrumentEnum {
+        InstrumentEnum::Equity(Equity::new(
+            InstrumentId::from("600000.SSE"),
+            Symbol::from("600000"),
+            None,
+            Currency::CNY(),
+            2,
+            Price::from("0.01"),
+            Some(Quantity::from(100)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ))
+    }
+
+    fn china_futures_model(basis: FuturesFeeBasis) -> ChinaFuturesFeeModel {
+        let rate = match basis {
+            FuturesFeeBasis::PerLot => FuturesFeeRate::new(basis, dec!(3), dec!(3), dec!(6)),
+            FuturesFeeBasis::Turnover => {
+                FuturesFeeRate::new(basis, dec!(0.0001), dec!(0.0001), dec!(0.0002))
+            }
+        };
+        let mut rates = FeeRateTable::default();
+        rates.set_product_rate("RB", rate.unwrap());
+        ChinaFuturesFeeModel::new(rates, CHINA_FUTURES_OFFSET_SECS)
+    }
 
-    use super::{FeeModel, FixedFeeModel, MakerTakerFeeModel};
+    fn accepted_order(instrument: &InstrumentEnum, side: OrderSide, quantity: u64) -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(side)
+            .quantity(Quantity::from(quantity))
+            .build();
+        TestOrderStubs::make_accepted_order(&order)
+    }
+
+    fn apply_fill(
+        order: &mut OrderAny,
+        instrument: &InstrumentEnum,
+        last_px: Price,
+        last_qty: Quantity,
+        ts_filled: UnixNanos,
+    ) -> OrderEventAny {
+        let fill = TestOrderEventStubs::filled(
+            order,
+            instrument,
+            None,
+            None,
+            Some(last_px),
+            Some(last_qty),
+            None,
+            None,
+            Some(ts_filled),
+            None,
+        );
+        order.apply(fill.clone()).unwrap();
+        fill
+    }
+
+    fn long_position(instrument: &InstrumentEnum, quantity: u64, ts_opened: UnixNanos) -> Position {
+        let mut order = accepted_order(instrument, OrderSide::Buy, quantity);
+        let fill = apply_fill(
+            &mut order,
+            instrument,
+            Price::from("3500"),
+            Quantity::from(quantity),
+            ts_opened,
+        );
+        match fill {
+            OrderEventAny::Filled(fill) => Position::new(instrument, fill),
+            _ => panic!("Expected fill event"),
+        }
+    }
 
     #[rstest]
     fn test_fixed_model_single_fill() {
@@ -263,4 +864,123 @@ mod tests {
             .unwrap();
         assert_eq!(commission.as_decimal(), expected_commission);
     }
+
+    #[rstest]
+    fn test_china_futures_per_lot_open_without_position(rebar: InstrumentEnum) {
+        let fee_model = china_futures_model(FuturesFeeBasis::PerLot);
+        let order = accepted_order(&rebar, OrderSide::Buy, 2);
+
+        let commission = fee_model
+            .get_commission(&order, Quantity::from(2), Price::from("3500"), &rebar)
+            .unwrap();
+
+        assert_eq!(commission, Money::from("6 CNY"));
+    }
+
+    #[rstest]
+    fn test_china_futures_turnover_open(rebar: InstrumentEnum) {
+        let fee_model = china_futures_model(FuturesFeeBasis::Turnover);
+        let order = accepted_order(&rebar, OrderSide::Buy, 2);
+
+        // Turnover is 2 lots * 3,500 * 10 multiplier = 70,000
+        let commission = fee_model
+            .get_commission(&order, Quantity::from(2), Price::from("3500"), &rebar)
+            .unwrap();
+
+        assert_eq!(commission, Money::from("7 CNY"));
+    }
+
+    #[rstest]
+    #[case::close_today(cst(2024, 3, 4, 9, 30), Money::from("15 CNY"))]
+    #[case::close_yesterday(cst(2024, 2, 29, 10, 0), Money::from("9 CNY"))]
+    #[case::friday_night_session_is_today(cst(2024, 3, 1, 21, 30), Money::from("15 CNY"))]
+    fn test_china_futures_close_today_and_yesterday(
+        rebar: InstrumentEnum,
+        #[case] ts_opened: UnixNanos,
+        #[case] expected: Money,
+    ) {
+        let fee_model = china_futures_model(FuturesFeeBasis::PerLot);
+        let position = long_position(&rebar, 2, ts_opened);
+        let order = accepted_order(&rebar, OrderSide::Sell, 3);
+
+        // Closes 2 lots then opens 1 lot short
+        let commission = fee_model
+            .get_commission_with_position(
+                &order,
+                Quantity::from(3),
+                Price::from("3500"),
+                &rebar,
+                Some(&position),
+                cst(2024, 3, 4, 10, 0),
+            )
+            .unwrap();
+
+        assert_eq!(commission, expected);
+    }
+
+    #[rstest]
+    fn test_china_futures_no_rate_configured(rebar: InstrumentEnum) {
+        let fee_model = ChinaFuturesFeeModel::new(FeeRateTable::default(), 0);
+        let order = accepted_order(&rebar, OrderSide::Buy, 1);
+
+        let result =
+            fee_model.get_commission(&order, Quantity::from(1), Price::from("3500"), &rebar);
+
+        assert!(result.is_err());
+        assert!(fee_model.check_instrument(&rebar).is_err());
+    }
+
+    #[rstest]
+    fn test_fee_rate_table_precedence(rebar: InstrumentEnum) {
+        let mut rates = FeeRateTable::new(Some(1));
+        assert_eq!(rates.resolve(&rebar), Some(&1));
+
+        rates.set_product_rate("rb", 2);
+        assert_eq!(rates.resolve(&rebar), Some(&2));
+
+        rates.set_instrument_rate(rebar.id(), 3);
+        assert_eq!(rates.resolve(&rebar), Some(&3));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, Money::from("5.10 CNY"))]
+    #[case(OrderSide::Sell, Money::from("10.10 CNY"))]
+    fn test_ashare_minimum_commission_and_stamp_duty(
+        pufa: InstrumentEnum,
+        #[case] side: OrderSide,
+        #[case] expected: Money,
+    ) {
+        let fee_model = AShareFeeModel::default();
+        let order = accepted_order(&pufa, side, 1_000);
+
+        let commission = fee_model
+            .get_commission(&order, Quantity::from(1_000), Price::from("10.00"), &pufa)
+            .unwrap();
+
+        assert_eq!(commission, expected);
+    }
+
+    #[rstest]
+    fn test_ashare_minimum_commission_charged_once_per_order(pufa: InstrumentEnum) {
+        let fee_model = AShareFeeModel::default();
+        let mut order = accepted_order(&pufa, OrderSide::Buy, 2_000);
+        let px = Price::from("10.00");
+
+        let first = fee_model
+            .get_commission(&order, Quantity::from(1_000), px, &pufa)
+            .unwrap();
+        apply_fill(
+            &mut order,
+            &pufa,
+            px,
+            Quantity::from(1_000),
+            UnixNanos::default(),
+        );
+        let second = fee_model
+            .get_commission(&order, Quantity::from(1_000), px, &pufa)
+            .unwrap();
+
+        assert_eq!(first, Money::from("5.10 CNY"));
+        assert_eq!(second, Money::from("0.10 CNY"));
+    }
 }
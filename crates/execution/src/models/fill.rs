@@ -13,13 +13,186 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
-use ant_core::correctness::{FAILED, check_in_range_inclusive_f64};
+use ant_core::correctness::{FAILED, check_in_range_inclusive_f64, check_predicate_true};
+use ant_model::{
+    data::{Bar, OrderBookDelta, TradeTick, order::OrderId},
+    enums::{AggressorSide, BookAction, BookType, LiquiditySide, OrderSideSpecified},
+    identifiers::ClientOrderId,
+    instruments::{Instrument, InstrumentEnum},
+    orderbook::OrderBook,
+    orders::{Order, OrderAny},
+    types::{Price, Quantity},
+};
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
+pub trait FillModel {
+    /// Returns `true` if the resting limit `order` should be filled when the market rests on
+    /// its price.
+    fn is_limit_filled(&mut self, order: &OrderAny) -> bool;
+
+    /// Returns `true` if a stop order should be filled when the market rests on its trigger price.
+    fn is_stop_filled(&mut self) -> bool;
+
+    /// Returns `true` if an order fill price should slip by one tick.
+    fn is_slipped(&mut self) -> bool;
+
+    /// Returns the fill price for a fill of `fill_qty` at `fill_px`, after any price impact.
+    ///
+    /// The default implementation returns `fill_px` unchanged.
+    fn adjust_fill_price(
+        &mut self,
+        _order: &OrderAny,
+        _instrument: &InstrumentEnum,
+        fill_px: Price,
+        _fill_qty: Quantity,
+        _liquidity_side: LiquiditySide,
+    ) -> Price {
+        fill_px
+    }
+
+    /// Returns the quantity which may be filled out of `fill_qty` available in the book.
+    ///
+    /// The default implementation returns `fill_qty` unchanged.
+    fn max_fill_quantity(&mut self, _order: &OrderAny, fill_qty: Quantity) -> Quantity {
+        fill_qty
+    }
+
+    /// Called when a passive `order` is accepted and begins resting in the `book`.
+    fn on_order_accepted(&mut self, _order: &OrderAny, _book: &OrderBook) {}
+
+    /// Called when the order with `client_order_id` is closed (filled, canceled or expired).
+    fn on_order_closed(&mut self, _client_order_id: ClientOrderId) {}
+
+    /// Processes the given order book `delta`.
+    fn process_order_book_delta(&mut self, _delta: &OrderBookDelta) {}
+
+    /// Processes the given `trade`.
+    fn process_trade_tick(&mut self, _trade: &TradeTick) {}
+
+    /// Processes the given `bar`.
+    fn process_bar(&mut self, _bar: &Bar) {}
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum FillModelAny {
+    Probabilistic(ProbabilisticFillModel),
+    QueuePosition(QueuePositionFillModel),
+    MarketImpact(MarketImpactFillModel),
+    VolumeParticipation(VolumeParticipationFillModel),
+}
+
+impl FillModel for FillModelAny {
+    fn is_limit_filled(&mut self, order: &OrderAny) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.is_limit_filled(order),
+            Self::QueuePosition(model) => model.is_limit_filled(order),
+            Self::MarketImpact(model) => model.is_limit_filled(order),
+            Self::VolumeParticipation(model) => model.is_limit_filled(order),
+        }
+    }
+
+    fn is_stop_filled(&mut self) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.is_stop_filled(),
+            Self::QueuePosition(model) => model.is_stop_filled(),
+            Self::MarketImpact(model) => model.is_stop_filled(),
+            Self::VolumeParticipation(model) => model.is_stop_filled(),
+        }
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        match self {
+            Self::Probabilistic(model) => model.is_slipped(),
+            Self::QueuePosition(model) => model.is_slipped(),
+            Self::MarketImpact(model) => model.is_slipped(),
+            Self::VolumeParticipation(model) => model.is_slipped(),
+        }
+    }
+
+    fn adjust_fill_price(
+        &mut self,
+        order: &OrderAny,
+        instrument: &InstrumentEnum,
+        fill_px: Price,
+        fill_qty: Quantity,
+        liquidity_side: LiquiditySide,
+    ) -> Price {
+        match self {
+            Self::MarketImpact(model) => {
+                model.adjust_fill_price(order, instrument, fill_px, fill_qty, liquidity_side)
+            }
+            _ => fill_px,
+        }
+    }
+
+    fn max_fill_quantity(&mut self, order: &OrderAny, fill_qty: Quantity) -> Quantity {
+        match self {
+            Self::VolumeParticipation(model) => model.max_fill_quantity(order, fill_qty),
+            _ => fill_qty,
+        }
+    }
+
+    fn on_order_accepted(&mut self, order: &OrderAny, book: &OrderBook) {
+        if let Self::QueuePosition(model) = self {
+            model.on_order_accepted(order, book);
+        }
+    }
+
+    fn on_order_closed(&mut self, client_order_id: ClientOrderId) {
+        if let Self::QueuePosition(model) = self {
+            model.on_order_closed(client_order_id);
+        }
+    }
+
+    fn process_order_book_delta(&mut self, delta: &OrderBookDelta) {
+        if let Self::QueuePosition(model) = self {
+            model.process_order_book_delta(delta);
+        }
+    }
+
+    fn process_trade_tick(&mut self, trade: &TradeTick) {
+        if let Self::QueuePosition(model) = self {
+            model.process_trade_tick(trade);
+        }
+    }
+
+    fn process_bar(&mut self, bar: &Bar) {
+        if let Self::VolumeParticipation(model) = self {
+            model.process_bar(bar);
+        }
+    }
+}
+
+impl Display for FillModelAny {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Probabilistic(model) => model.fmt(f),
+            Self::QueuePosition(model) => model.fmt(f),
+            Self::MarketImpact(model) => model.fmt(f),
+            Self::VolumeParticipation(model) => model.fmt(f),
+        }
+    }
+}
+
+impl Default for FillModelAny {
+    fn default() -> Self {
+        Self::Probabilistic(ProbabilisticFillModel::default())
+    }
+}
+
+fn event_success(rng: &mut StdRng, probability: f64) -> bool {
+    match probability {
+        0.0 => false,
+        1.0 => true,
+        _ => rng.random_bool(probability),
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct FillModel {
+pub struct ProbabilisticFillModel {
     /// The probability of limit order filling if the market rests on its price.
     prob_fill_on_limit: f64,
     /// The probability of stop orders filling if the market rests on its price.
@@ -30,8 +203,8 @@ pub struct FillModel {
     rng: StdRng,
 }
 
-impl FillModel {
-    /// Creates a new [`FillModel`] instance.
+impl ProbabilisticFillModel {
+    /// Creates a new [`ProbabilisticFillModel`] instance.
     ///
     /// # Errors
     ///
@@ -62,64 +235,402 @@ impl FillModel {
             rng,
         })
     }
+}
 
+impl FillModel for ProbabilisticFillModel {
     /// Returns `true` if a limit order should be filled based on the configured probability.
-    pub fn is_limit_filled(&mut self) -> bool {
-        self.event_success(self.prob_fill_on_limit)
+    fn is_limit_filled(&mut self, _order: &OrderAny) -> bool {
+        event_success(&mut self.rng, self.prob_fill_on_limit)
     }
 
     /// Returns `true` if a stop order should be filled based on the configured probability.
-    pub fn is_stop_filled(&mut self) -> bool {
-        self.event_success(self.prob_fill_on_stop)
+    fn is_stop_filled(&mut self) -> bool {
+        event_success(&mut self.rng, self.prob_fill_on_stop)
     }
 
     /// Returns `true` if an order should slip by one tick based on the configured probability.
-    pub fn is_slipped(&mut self) -> bool {
-        self.event_success(self.prob_slippage)
+    fn is_slipped(&mut self) -> bool {
+        event_success(&mut self.rng, self.prob_slippage)
+    }
+}
+
+impl Display for ProbabilisticFillModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ProbabilisticFillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {})",
+            self.prob_fill_on_limit, self.prob_fill_on_stop, self.prob_slippage
+        )
+    }
+}
+
+impl Default for ProbabilisticFillModel {
+    /// Creates a new default [`ProbabilisticFillModel`] instance.
+    fn default() -> Self {
+        Self::new(0.5, 0.5, 0.1, None).unwrap()
+    }
+}
+
+/// The estimated queue position of a resting limit order.
+#[derive(Debug, Clone)]
+struct QueuePosition {
+    side: OrderSideSpecified,
+    price: Price,
+    /// The estimated volume resting ahead of the order.
+    volume_ahead: f64,
+    /// The last known aggregate level size (L1/L2 books), used to infer cancellations.
+    level_size: f64,
+    /// The book orders resting ahead of the order (L3 books).
+    orders_ahead: Option<HashMap<OrderId, f64>>,
+}
+
+impl QueuePosition {
+    fn is_same_level(&self, side: OrderSideSpecified, price: Price) -> bool {
+        self.side == side && self.price == price
+    }
+
+    fn reduce(&mut self, volume: f64) {
+        self.volume_ahead = (self.volume_ahead - volume).max(0.0);
+    }
+}
+
+/// Fills resting limit orders only once the estimated volume ahead of them in the queue has
+/// been consumed.
+///
+/// The volume ahead is taken from the book level when the order is accepted. For L3 books the
+/// individual book orders ahead are tracked by order ID. For L1/L2 books trades at the level
+/// consume the queue, while decreases in the level size beyond trades are treated as
+/// cancellations and split in proportion to the volume ahead of and behind the order. A trade
+/// through the order price clears the queue.
+#[derive(Debug, Clone, Default)]
+pub struct QueuePositionFillModel {
+    positions: HashMap<ClientOrderId, QueuePosition>,
+}
+
+impl QueuePositionFillModel {
+    /// Creates a new [`QueuePositionFillModel`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the estimated volume ahead of the order with `client_order_id` (if tracked).
+    #[must_use]
+    pub fn volume_ahead(&self, client_order_id: &ClientOrderId) -> Option<f64> {
+        self.positions
+            .get(client_order_id)
+            .map(|position| position.volume_ahead)
+    }
+}
+
+impl FillModel for QueuePositionFillModel {
+    fn is_limit_filled(&mut self, order: &OrderAny) -> bool {
+        self.positions
+            .get(&order.client_order_id())
+            .is_none_or(|position| position.volume_ahead <= 0.0)
+    }
+
+    fn is_stop_filled(&mut self) -> bool {
+        true
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        false
+    }
+
+    fn on_order_accepted(&mut self, order: &OrderAny, book: &OrderBook) {
+        let client_order_id = order.client_order_id();
+        if self.positions.contains_key(&client_order_id) {
+            return;
+        }
+        let Some(price) = order.price() else {
+            return;
+        };
+
+        let side = order.order_side_specified();
+        let level = match side {
+            OrderSideSpecified::Buy => book.bids(None).find(|level| level.price.value == price),
+            OrderSideSpecified::Sell => book.asks(None).find(|level| level.price.value == price),
+        };
+        let level_size = level.map_or(0.0, |level| level.size());
+        let orders_ahead = (book.book_type == BookType::L3_MBO).then(|| {
+            level
+                .map(|level| {
+                    level
+                        .iter()
+                        .map(|book_order| (book_order.order_id, book_order.size.as_f64()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        });
+
+        self.positions.insert(
+            client_order_id,
+            QueuePosition {
+                side,
+                price,
+                volume_ahead: level_size,
+                level_size,
+                orders_ahead,
+            },
+        );
+    }
+
+    fn on_order_closed(&mut self, client_order_id: ClientOrderId) {
+        self.positions.remove(&client_order_id);
+    }
+
+    fn process_order_book_delta(&mut self, delta: &OrderBookDelta) {
+        if !matches!(
+            delta.action,
+            BookAction::Add | BookAction::Update | BookAction::Delete
+        ) {
+            return;
+        }
+
+        let side = delta.order.side.as_specified();
+        let new_size = match delta.action {
+            BookAction::Delete => 0.0,
+            _ => delta.order.size.as_f64(),
+        };
+
+        for position in self.positions.values_mut() {
+            if !position.is_same_level(side, delta.order.price) {
+                continue;
+            }
+
+            let decrease = match &mut position.orders_ahead {
+                Some(orders_ahead) => {
+                    // New orders join the back of the queue, so only orders ahead are tracked
+                    let Some(size) = orders_ahead.get_mut(&delta.order.order_id) else {
+                        continue;
+                    };
+                    let decrease = (*size - new_size).max(0.0);
+                    if delta.action == BookAction::Delete {
+                        orders_ahead.remove(&delta.order.order_id);
+                    } else {
+                        *size = new_size;
+                    }
+                    decrease
+                }
+                None => {
+                    // The cancelled orders cannot be located in an aggregated level, so the
+                    // decrease is split pro rata between the volume ahead and behind the order
+                    let decrease = (position.level_size - new_size).max(0.0);
+                    let share_ahead = if position.level_size > 0.0 {
+                        (position.volume_ahead / position.level_size).min(1.0)
+                    } else {
+                        0.0
+                    };
+                    position.level_size = new_size;
+                    decrease * share_ahead
+                }
+            };
+            position.reduce(decrease);
+        }
+    }
+
+    fn process_trade_tick(&mut self, trade: &TradeTick) {
+        let size = trade.size.as_f64();
+        for position in self.positions.values_mut() {
+            let is_trade_through = match position.side {
+                OrderSideSpecified::Buy => trade.price < position.price,
+                OrderSideSpecified::Sell => trade.price > position.price,
+            };
+            if is_trade_through {
+                position.volume_ahead = 0.0;
+                continue;
+            }
+
+            let hits_side = match trade.aggressor_side {
+                AggressorSide::Buyer => position.side == OrderSideSpecified::Sell,
+                AggressorSide::Seller => position.side == OrderSideSpecified::Buy,
+                AggressorSide::NoAggressor => true,
+            };
+            // L3 queues are consumed through the order deltas which accompany the trade
+            if !hits_side || trade.price != position.price || position.orders_ahead.is_some() {
+                continue;
+            }
+
+            position.reduce(size);
+            position.level_size = (position.level_size - size).max(0.0);
+        }
+    }
+}
+
+impl Display for QueuePositionFillModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "QueuePositionFillModel(orders: {})",
+            self.positions.len()
+        )
     }
+}
+
+/// Applies a square-root market impact to aggressive (taker) fills.
+///
+/// The impact as a fraction of price is `impact_coefficient * volatility * sqrt(Q / V)`, where
+/// `Q` is the fill quantity and `V` the average daily volume. Fill prices move against the order.
+#[derive(Debug, Clone)]
+pub struct MarketImpactFillModel {
+    /// The dimensionless impact coefficient (typically close to 1.0).
+    impact_coefficient: f64,
+    /// The daily volatility of returns (e.g. 0.02 for 2%).
+    volatility: f64,
+    /// The average daily volume (in instrument quantity units).
+    daily_volume: f64,
+}
 
-    /// Returns a simulated `OrderBook` for fill simulation.
+impl MarketImpactFillModel {
+    /// Creates a new [`MarketImpactFillModel`] instance.
     ///
-    /// This method allows custom fill models to provide their own liquidity
-    /// simulation by returning a custom `OrderBook` that represents the expected
-    /// market liquidity. The matching engine will use this simulated `OrderBook`
-    /// to determine fills.
+    /// # Errors
     ///
-    /// The default implementation returns None, which means the matching engine
-    /// will use its standard fill logic (maintaining backward compatibility).
-    pub fn get_orderbook_for_fill_simulation(
-        &self,
-        _instrument: &dyn std::any::Any, // Placeholder for instrument type
-        _order: &dyn std::any::Any,      // Placeholder for order type
-        _best_bid: f64,
-        _best_ask: f64,
-    ) -> Option<Box<dyn std::any::Any>> {
-        None // Default implementation - use existing fill logic
+    /// Returns an error if any parameter is not positive.
+    pub fn new(
+        impact_coefficient: f64,
+        volatility: f64,
+        daily_volume: f64,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            impact_coefficient > 0.0,
+            "`impact_coefficient` must be positive",
+        )?;
+        check_predicate_true(volatility > 0.0, "`volatility` must be positive")?;
+        check_predicate_true(daily_volume > 0.0, "`daily_volume` must be positive")?;
+        Ok(Self {
+            impact_coefficient,
+            volatility,
+            daily_volume,
+        })
+    }
+
+    /// Returns the price impact as a fraction of price for a fill of `quantity`.
+    #[must_use]
+    pub fn impact(&self, quantity: f64) -> f64 {
+        self.impact_coefficient * self.volatility * (quantity / self.daily_volume).sqrt()
     }
+}
 
-    fn event_success(&mut self, probability: f64) -> bool {
-        match probability {
-            0.0 => false,
-            1.0 => true,
-            _ => self.rng.random_bool(probability),
+impl FillModel for MarketImpactFillModel {
+    fn is_limit_filled(&mut self, _order: &OrderAny) -> bool {
+        true
+    }
+
+    fn is_stop_filled(&mut self) -> bool {
+        true
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        false
+    }
+
+    fn adjust_fill_price(
+        &mut self,
+        order: &OrderAny,
+        instrument: &InstrumentEnum,
+        fill_px: Price,
+        fill_qty: Quantity,
+        liquidity_side: LiquiditySide,
+    ) -> Price {
+        if liquidity_side != LiquiditySide::Taker {
+            return fill_px;
         }
+
+        let impact = self.impact(fill_qty.as_f64());
+        let adjusted = match order.order_side_specified() {
+            OrderSideSpecified::Buy => fill_px.as_f64() * (1.0 + impact),
+            OrderSideSpecified::Sell => fill_px.as_f64() * (1.0 - impact),
+        };
+        instrument.make_price(adjusted)
     }
 }
 
-impl Display for FillModel {
+impl Display for MarketImpactFillModel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FillModel(prob_fill_on_limit: {}, prob_fill_on_stop: {}, prob_slippage: {})",
-            self.prob_fill_on_limit, self.prob_fill_on_stop, self.prob_slippage
+            "MarketImpactFillModel(impact_coefficient: {}, volatility: {}, daily_volume: {})",
+            self.impact_coefficient, self.volatility, self.daily_volume
         )
     }
 }
 
-impl Default for FillModel {
-    /// Creates a new default [`FillModel`] instance.
-    fn default() -> Self {
-        Self::new(0.5, 0.5, 0.1, None).unwrap()
+/// Caps the quantity filled per bar to a fraction of the bar volume.
+///
+/// The capacity is reset on each bar and consumed by fills. Until the first bar is processed
+/// fills are not capped.
+#[derive(Debug, Clone)]
+pub struct VolumeParticipationFillModel {
+    /// The maximum fraction of bar volume which may be filled (0.0, 1.0].
+    participation_rate: f64,
+    /// The remaining fill capacity for the current bar.
+    remaining: Option<f64>,
+}
+
+impl VolumeParticipationFillModel {
+    /// Creates a new [`VolumeParticipationFillModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `participation_rate` is not in the range (0.0, 1.0].
+    pub fn new(participation_rate: f64) -> anyhow::Result<Self> {
+        check_predicate_true(
+            participation_rate > 0.0 && participation_rate <= 1.0,
+            "`participation_rate` must be in the range (0, 1]",
+        )?;
+        Ok(Self {
+            participation_rate,
+            remaining: None,
+        })
+    }
+
+    /// Returns the remaining fill capacity for the current bar (if a bar has been processed).
+    #[must_use]
+    pub const fn remaining(&self) -> Option<f64> {
+        self.remaining
+    }
+}
+
+impl FillModel for VolumeParticipationFillModel {
+    fn is_limit_filled(&mut self, _order: &OrderAny) -> bool {
+        true
+    }
+
+    fn is_stop_filled(&mut self) -> bool {
+        true
+    }
+
+    fn is_slipped(&mut self) -> bool {
+        false
+    }
+
+    fn max_fill_quantity(&mut self, _order: &OrderAny, fill_qty: Quantity) -> Quantity {
+        let Some(remaining) = self.remaining else {
+            return fill_qty;
+        };
+
+        // Round the capacity down to the fill quantity precision
+        let scale = 10f64.powi(i32::from(fill_qty.precision));
+        let capacity = (remaining * scale).floor() / scale;
+        let quantity = fill_qty.as_f64().min(capacity.max(0.0));
+        self.remaining = Some((remaining - quantity).max(0.0));
+        Quantity::new(quantity, fill_qty.precision)
+    }
+
+    fn process_bar(&mut self, bar: &Bar) {
+        self.remaining = Some(bar.volume.as_f64() * self.participation_rate);
+    }
+}
+
+impl Display for VolumeParticipationFillModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "VolumeParticipationFillModel(participation_rate: {})",
+            self.participation_rate
+        )
     }
 }
 
@@ -128,14 +639,98 @@ impl Default for FillModel {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use ant_core::UnixNanos;
+    use ant_model::{
+        data::{BarType, order::BookOrder},
+        enums::{OrderSide, OrderType},
+        identifiers::{InstrumentId, TradeId},
+        instruments::{CryptoPerpetual, stubs::crypto_perpetual_ethusdt},
+        orders::builder::OrderTestBuilder,
+    };
     use rstest::{fixture, rstest};
 
     use super::*;
 
     #[fixture]
-    fn fill_model() -> FillModel {
+    fn fill_model() -> ProbabilisticFillModel {
         let seed = 42;
-        FillModel::new(0.5, 0.5, 0.1, Some(seed)).unwrap()
+        ProbabilisticFillModel::new(0.5, 0.5, 0.1, Some(seed)).unwrap()
+    }
+
+    #[fixture]
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT-PERP.BINANCE")
+    }
+
+    fn limit_order(instrument_id: InstrumentId, side: OrderSide, price: &str) -> OrderAny {
+        OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument_id)
+            .side(side)
+            .price(Price::from(price))
+            .quantity(Quantity::from("1.000"))
+            .build()
+    }
+
+    fn book_with_bids(
+        instrument_id: InstrumentId,
+        book_type: BookType,
+        bids: &[(&str, &str, OrderId)],
+    ) -> OrderBook {
+        let mut book = OrderBook::new(instrument_id, book_type);
+        for (price, size, order_id) in bids {
+            book.add(
+                BookOrder::new(
+                    OrderSide::Buy,
+                    Price::from(*price),
+                    Quantity::from(*size),
+                    *order_id,
+                ),
+                0,
+                0,
+                UnixNanos::default(),
+            );
+        }
+        book
+    }
+
+    fn bid_delta(
+        instrument_id: InstrumentId,
+        action: BookAction,
+        price: &str,
+        size: &str,
+        order_id: OrderId,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            instrument_id,
+            action,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from(price),
+                Quantity::from(size),
+                order_id,
+            ),
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn trade(
+        instrument_id: InstrumentId,
+        price: &str,
+        size: &str,
+        aggressor_side: AggressorSide,
+    ) -> TradeTick {
+        TradeTick::new(
+            instrument_id,
+            Price::from(price),
+            Quantity::from(size),
+            aggressor_side,
+            TradeId::new("1"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
     }
 
     #[rstest]
@@ -143,7 +738,7 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_fill_on_limit' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_fill_on_limit_error() {
-        let _ = super::FillModel::new(1.1, 0.5, 0.1, None).unwrap();
+        let _ = super::ProbabilisticFillModel::new(1.1, 0.5, 0.1, None).unwrap();
     }
 
     #[rstest]
@@ -151,7 +746,7 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_fill_on_stop' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_fill_on_stop_error() {
-        let _ = super::FillModel::new(0.5, 1.1, 0.1, None).unwrap();
+        let _ = super::ProbabilisticFillModel::new(0.5, 1.1, 0.1, None).unwrap();
     }
 
     #[rstest]
@@ -159,27 +754,291 @@ mod tests {
         expected = "Condition failed: invalid f64 for 'prob_slippage' not in range [0, 1], was 1.1"
     )]
     fn test_fill_model_param_prob_slippage_error() {
-        let _ = super::FillModel::new(0.5, 0.5, 1.1, None).unwrap();
+        let _ = super::ProbabilisticFillModel::new(0.5, 0.5, 1.1, None).unwrap();
     }
 
     #[rstest]
-    fn test_fill_model_is_limit_filled(mut fill_model: FillModel) {
+    fn test_fill_model_is_limit_filled(
+        mut fill_model: ProbabilisticFillModel,
+        instrument_id: InstrumentId,
+    ) {
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
         // because of fixed seed this is deterministic
-        let result = fill_model.is_limit_filled();
+        let result = fill_model.is_limit_filled(&order);
         assert!(!result);
     }
 
     #[rstest]
-    fn test_fill_model_is_stop_filled(mut fill_model: FillModel) {
+    fn test_fill_model_is_stop_filled(mut fill_model: ProbabilisticFillModel) {
         // because of fixed seed this is deterministic
         let result = fill_model.is_stop_filled();
         assert!(!result);
     }
 
     #[rstest]
-    fn test_fill_model_is_slipped(mut fill_model: FillModel) {
+    fn test_fill_model_is_slipped(mut fill_model: ProbabilisticFillModel) {
         // because of fixed seed this is deterministic
         let result = fill_model.is_slipped();
         assert!(!result);
     }
+
+    #[rstest]
+    fn test_fill_model_any_default_is_probabilistic() {
+        let fill_model = FillModelAny::default();
+        assert!(matches!(fill_model, FillModelAny::Probabilistic(_)));
+        assert_eq!(
+            fill_model.to_string(),
+            "ProbabilisticFillModel(prob_fill_on_limit: 0.5, prob_fill_on_stop: 0.5, prob_slippage: 0.1)"
+        );
+    }
+
+    #[rstest]
+    fn test_queue_position_l2_consumed_by_trades_and_cancels(instrument_id: InstrumentId) {
+        let mut fill_model = QueuePositionFillModel::new();
+        let book = book_with_bids(instrument_id, BookType::L2_MBP, &[("100.00", "10.000", 0)]);
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
+
+        fill_model.on_order_accepted(&order, &book);
+        assert_eq!(
+            fill_model.volume_ahead(&order.client_order_id()),
+            Some(10.0)
+        );
+        assert!(!fill_model.is_limit_filled(&order));
+
+        // Trade at the level consumes the queue
+        fill_model.process_trade_tick(&trade(
+            instrument_id,
+            "100.00",
+            "4.000",
+            AggressorSide::Seller,
+        ));
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(6.0));
+
+        // Level decrease beyond the trade is a cancel ahead (the whole level is ahead)
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "5.000",
+            0,
+        ));
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(5.0));
+
+        // Level increases join behind the order
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "8.000",
+            0,
+        ));
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(5.0));
+        assert!(!fill_model.is_limit_filled(&order));
+
+        fill_model.process_trade_tick(&trade(
+            instrument_id,
+            "100.00",
+            "5.000",
+            AggressorSide::Seller,
+        ));
+        assert!(fill_model.is_limit_filled(&order));
+    }
+
+    #[rstest]
+    fn test_queue_position_l2_cancel_split_pro_rata(instrument_id: InstrumentId) {
+        let mut fill_model = QueuePositionFillModel::new();
+        let book = book_with_bids(instrument_id, BookType::L2_MBP, &[("100.00", "10.000", 0)]);
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
+        fill_model.on_order_accepted(&order, &book);
+
+        // An order joins behind ours, then is cancelled
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "20.000",
+            0,
+        ));
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "10.000",
+            0,
+        ));
+
+        // Half the level was ahead of the order, so half the cancel is attributed ahead
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(5.0));
+        assert!(!fill_model.is_limit_filled(&order));
+    }
+
+    #[rstest]
+    fn test_queue_position_l3_cancel_behind_keeps_queue(instrument_id: InstrumentId) {
+        let mut fill_model = QueuePositionFillModel::new();
+        let book = book_with_bids(instrument_id, BookType::L3_MBO, &[("100.00", "4.000", 1)]);
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
+        fill_model.on_order_accepted(&order, &book);
+
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Add,
+            "100.00",
+            "6.000",
+            2,
+        ));
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Delete,
+            "100.00",
+            "6.000",
+            2,
+        ));
+
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(4.0));
+    }
+
+    #[rstest]
+    fn test_queue_position_trade_through_clears_queue(instrument_id: InstrumentId) {
+        let mut fill_model = QueuePositionFillModel::new();
+        let book = book_with_bids(instrument_id, BookType::L2_MBP, &[("100.00", "10.000", 0)]);
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
+        fill_model.on_order_accepted(&order, &book);
+
+        fill_model.process_trade_tick(&trade(
+            instrument_id,
+            "99.99",
+            "1.000",
+            AggressorSide::Seller,
+        ));
+
+        assert!(fill_model.is_limit_filled(&order));
+    }
+
+    #[rstest]
+    fn test_queue_position_l3_tracks_orders_ahead(instrument_id: InstrumentId) {
+        let mut fill_model = QueuePositionFillModel::new();
+        let book = book_with_bids(
+            instrument_id,
+            BookType::L3_MBO,
+            &[("100.00", "3.000", 1), ("100.00", "2.000", 2)],
+        );
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
+        fill_model.on_order_accepted(&order, &book);
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(5.0));
+
+        // Orders joining after ours do not affect the queue ahead
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Add,
+            "100.00",
+            "7.000",
+            3,
+        ));
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Delete,
+            "100.00",
+            "7.000",
+            3,
+        ));
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(5.0));
+
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Delete,
+            "100.00",
+            "3.000",
+            1,
+        ));
+        fill_model.process_order_book_delta(&bid_delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "1.000",
+            2,
+        ));
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), Some(1.0));
+        assert!(!fill_model.is_limit_filled(&order));
+
+        fill_model.on_order_closed(order.client_order_id());
+        assert_eq!(fill_model.volume_ahead(&order.client_order_id()), None);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, LiquiditySide::Taker, "1002.00")]
+    #[case(OrderSide::Sell, LiquiditySide::Taker, "998.00")]
+    #[case(OrderSide::Buy, LiquiditySide::Maker, "1000.00")]
+    fn test_market_impact_adjust_fill_price(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] side: OrderSide,
+        #[case] liquidity_side: LiquiditySide,
+        #[case] expected: &str,
+    ) {
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt);
+        let mut fill_model = MarketImpactFillModel::new(1.0, 0.02, 1_000_000.0).unwrap();
+        let order = limit_order(instrument.id(), side, "1000.00");
+
+        let fill_px = fill_model.adjust_fill_price(
+            &order,
+            &instrument,
+            Price::from("1000.00"),
+            Quantity::from("10000.000"),
+            liquidity_side,
+        );
+
+        assert_eq!(fill_px, Price::from(expected));
+    }
+
+    #[rstest]
+    fn test_market_impact_invalid_params() {
+        assert!(MarketImpactFillModel::new(1.0, 0.02, 0.0).is_err());
+        assert!(MarketImpactFillModel::new(0.0, 0.02, 1.0).is_err());
+    }
+
+    #[rstest]
+    fn test_volume_participation_caps_fills_per_bar(instrument_id: InstrumentId) {
+        let mut fill_model = VolumeParticipationFillModel::new(0.25).unwrap();
+        let order = limit_order(instrument_id, OrderSide::Buy, "100.00");
+
+        // Uncapped before the first bar
+        assert_eq!(
+            fill_model.max_fill_quantity(&order, Quantity::from("500.000")),
+            Quantity::from("500.000")
+        );
+
+        let bar = Bar::new(
+            BarType::from("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL"),
+            Price::from("100.00"),
+            Price::from("101.00"),
+            Price::from("99.00"),
+            Price::from("100.50"),
+            Quantity::from("1000.000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        fill_model.process_bar(&bar);
+
+        assert_eq!(
+            fill_model.max_fill_quantity(&order, Quantity::from("200.000")),
+            Quantity::from("200.000")
+        );
+        assert_eq!(
+            fill_model.max_fill_quantity(&order, Quantity::from("200.000")),
+            Quantity::from("50.000")
+        );
+        assert!(
+            fill_model
+                .max_fill_quantity(&order, Quantity::from("1.000"))
+                .is_zero()
+        );
+
+        fill_model.process_bar(&bar);
+        assert_eq!(fill_model.remaining(), Some(250.0));
+    }
+
+    #[rstest]
+    fn test_volume_participation_invalid_rate() {
+        assert!(VolumeParticipationFillModel::new(0.0).is_err());
+        assert!(VolumeParticipationFillModel::new(1.5).is_err());
+    }
 }
@@ -24,7 +24,7 @@ use ant_common::{
     clock::Clock,
     logging::{CMD, EVT, SEND},
     messages::execution::{SubmitOrder, TradingCommand},
-    msgbus,
+    msgbus::{self, switchboard::MessagingSwitchboard},
 };
 use ant_core::UUID4;
 use ant_model::{
@@ -554,8 +554,8 @@ impl OrderManager {
     pub fn send_algo_command(&self, command: SubmitOrder, exec_algorithm_id: ExecAlgorithmId) {
         log::info!("{CMD}{SEND} {command}");
 
-        let endpoint = format!("{exec_algorithm_id}.execute");
-        msgbus::send_any(endpoint.into(), &TradingCommand::SubmitOrder(command));
+        let endpoint = MessagingSwitchboard::exec_algorithm_execute(exec_algorithm_id);
+        msgbus::send_any(endpoint, &TradingCommand::SubmitOrder(command));
     }
 
     pub fn send_risk_command(&self, command: TradingCommand) {
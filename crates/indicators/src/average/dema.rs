@@ -0,0 +1,184 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::ema::ExponentialMovingAverage,
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates a double exponential moving average (DEMA).
+///
+/// `DEMA = 2 * EMA(price) - EMA(EMA(price))`, which reduces the lag of a single EMA.
+#[derive(Debug, Clone)]
+pub struct DoubleExponentialMovingAverage {
+    /// The period for the underlying exponential moving averages.
+    pub period: usize,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    ema1: ExponentialMovingAverage,
+    ema2: ExponentialMovingAverage,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for DoubleExponentialMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl DoubleExponentialMovingAverage {
+    /// Creates a new [`DoubleExponentialMovingAverage`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            ema1: ExponentialMovingAverage::new(period, None),
+            ema2: ExponentialMovingAverage::new(period, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+}
+
+impl Indicator for DoubleExponentialMovingAverage {
+    fn name(&self) -> String {
+        stringify!(DoubleExponentialMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.ema1.reset();
+        self.ema2.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for DoubleExponentialMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.ema1.update_raw(value);
+        self.ema2.update_raw(self.ema1.value);
+
+        self.value = 2.0f64.mul_add(self.ema1.value, -self.ema2.value);
+        self.count += 1;
+        self.has_inputs = true;
+
+        if !self.initialized && self.ema2.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_dema_initialized() {
+        let dema = DoubleExponentialMovingAverage::new(10, None);
+
+        assert_eq!(dema.to_string(), "DoubleExponentialMovingAverage(10)");
+        assert!(!dema.has_inputs());
+        assert!(!dema.initialized());
+    }
+
+    #[rstest]
+    fn test_dema_first_value_equals_input() {
+        let mut dema = DoubleExponentialMovingAverage::new(3, None);
+
+        dema.update_raw(10.0);
+
+        assert_eq!(dema.value(), 10.0);
+        assert!(!dema.initialized());
+    }
+
+    #[rstest]
+    fn test_dema_update_raw() {
+        let mut dema = DoubleExponentialMovingAverage::new(3, None);
+
+        for value in [1.0, 2.0, 3.0] {
+            dema.update_raw(value);
+        }
+
+        // EMA1: 1.0 -> 1.5 -> 2.25, EMA2: 1.0 -> 1.25 -> 1.75
+        assert!(dema.initialized());
+        assert!((dema.value() - 2.75).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_dema_reset() {
+        let mut dema = DoubleExponentialMovingAverage::new(2, None);
+        dema.update_raw(1.0);
+        dema.update_raw(2.0);
+
+        dema.reset();
+
+        assert_eq!(dema.value(), 0.0);
+        assert_eq!(dema.count(), 0);
+        assert!(!dema.initialized());
+    }
+}
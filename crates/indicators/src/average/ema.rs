@@ -0,0 +1,231 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::indicator::{Indicator, MovingAverage};
+
+/// An indicator which calculates an exponential moving average (EMA).
+///
+/// The first input seeds the average, after which each input is weighted by the
+/// smoothing factor `alpha` (`2 / (period + 1)` by default).
+#[derive(Debug, Clone)]
+pub struct ExponentialMovingAverage {
+    /// The period used to derive the smoothing factor and warm-up length.
+    pub period: usize,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The smoothing factor.
+    pub alpha: f64,
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for ExponentialMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl ExponentialMovingAverage {
+    /// Creates a new [`ExponentialMovingAverage`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self::new_with_alpha(period, 2.0 / (period as f64 + 1.0), price_type)
+    }
+
+    /// Creates a new [`ExponentialMovingAverage`] instance with Wilder's smoothing
+    /// factor (`1 / period`), as used by RSI, ATR and ADX.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new_wilder(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self::new_with_alpha(period, 1.0 / period as f64, price_type)
+    }
+
+    /// Creates a new [`ExponentialMovingAverage`] instance with the given smoothing factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `period` is not positive (> 0).
+    /// - `alpha` is not in the range (0, 1].
+    #[must_use]
+    pub fn new_with_alpha(period: usize, alpha: f64, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        assert!(
+            alpha > 0.0 && alpha <= 1.0,
+            "`alpha` must be in range (0, 1], was {alpha}"
+        );
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            alpha,
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+}
+
+impl Indicator for ExponentialMovingAverage {
+    fn name(&self) -> String {
+        stringify!(ExponentialMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for ExponentialMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        if self.has_inputs {
+            self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
+        } else {
+            // First input seeds the average
+            self.has_inputs = true;
+            self.value = value;
+        }
+
+        self.count += 1;
+
+        if !self.initialized && self.count >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_quote;
+
+    #[rstest]
+    fn test_ema_initialized() {
+        let ema = ExponentialMovingAverage::new(10, None);
+
+        assert_eq!(ema.to_string(), "ExponentialMovingAverage(10)");
+        assert!((ema.alpha - 2.0 / 11.0).abs() < 1e-12);
+        assert!(!ema.has_inputs());
+        assert!(!ema.initialized());
+    }
+
+    #[rstest]
+    fn test_ema_wilder_alpha() {
+        let ema = ExponentialMovingAverage::new_wilder(14, None);
+
+        assert!((ema.alpha - 1.0 / 14.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`alpha` must be in range")]
+    fn test_ema_invalid_alpha_panics() {
+        let _ = ExponentialMovingAverage::new_with_alpha(10, 1.5, None);
+    }
+
+    #[rstest]
+    fn test_ema_update_raw() {
+        let mut ema = ExponentialMovingAverage::new(3, None);
+
+        ema.update_raw(1.0);
+        assert_eq!(ema.value(), 1.0);
+
+        ema.update_raw(2.0);
+        ema.update_raw(3.0);
+
+        // alpha = 0.5: 1.0 -> 1.5 -> 2.25
+        assert!(ema.initialized());
+        assert_eq!(ema.count(), 3);
+        assert!((ema.value() - 2.25).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_ema_handle_quote_uses_price_type() {
+        let mut ema = ExponentialMovingAverage::new(3, Some(PriceType::Ask));
+
+        ema.handle_quote(&stub_quote("100.0", "101.0"));
+
+        assert_eq!(ema.value(), 101.0);
+    }
+
+    #[rstest]
+    fn test_ema_reset() {
+        let mut ema = ExponentialMovingAverage::new(2, None);
+        ema.update_raw(1.0);
+        ema.update_raw(2.0);
+
+        ema.reset();
+
+        assert_eq!(ema.value(), 0.0);
+        assert_eq!(ema.count(), 0);
+        assert!(!ema.has_inputs());
+        assert!(!ema.initialized());
+    }
+}
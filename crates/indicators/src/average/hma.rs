@@ -0,0 +1,195 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::wma::WeightedMovingAverage,
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates a Hull moving average (HMA).
+///
+/// `HMA = WMA(2 * WMA(price, n / 2) - WMA(price, n), sqrt(n))`, which tracks price
+/// closely while remaining smooth.
+#[derive(Debug, Clone)]
+pub struct HullMovingAverage {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    ma1: WeightedMovingAverage,
+    ma2: WeightedMovingAverage,
+    ma3: WeightedMovingAverage,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for HullMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl HullMovingAverage {
+    /// Creates a new [`HullMovingAverage`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        let period_halved = (period / 2).max(1);
+        let period_sqrt = ((period as f64).sqrt() as usize).max(1);
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            ma1: WeightedMovingAverage::new(period_halved, None),
+            ma2: WeightedMovingAverage::new(period, None),
+            ma3: WeightedMovingAverage::new(period_sqrt, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+}
+
+impl Indicator for HullMovingAverage {
+    fn name(&self) -> String {
+        stringify!(HullMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.ma1.reset();
+        self.ma2.reset();
+        self.ma3.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for HullMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        self.ma1.update_raw(value);
+        self.ma2.update_raw(value);
+        self.ma3
+            .update_raw(2.0f64.mul_add(self.ma1.value, -self.ma2.value));
+
+        self.value = self.ma3.value;
+        self.count += 1;
+        self.has_inputs = true;
+
+        if !self.initialized && self.ma2.initialized() && self.ma3.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_hma_initialized() {
+        let hma = HullMovingAverage::new(10, None);
+
+        assert_eq!(hma.to_string(), "HullMovingAverage(10)");
+        assert!(!hma.has_inputs());
+        assert!(!hma.initialized());
+    }
+
+    #[rstest]
+    fn test_hma_constant_input() {
+        let mut hma = HullMovingAverage::new(4, None);
+
+        for _ in 0..4 {
+            hma.update_raw(5.0);
+        }
+
+        assert!(hma.initialized());
+        assert!((hma.value() - 5.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_hma_tracks_trend_closer_than_wma() {
+        let mut hma = HullMovingAverage::new(9, None);
+        let mut wma = WeightedMovingAverage::new(9, None);
+
+        for i in 1..=20 {
+            hma.update_raw(f64::from(i));
+            wma.update_raw(f64::from(i));
+        }
+
+        assert!(hma.value() > wma.value());
+        assert!((hma.value() - 20.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_hma_reset() {
+        let mut hma = HullMovingAverage::new(4, None);
+        hma.update_raw(1.0);
+
+        hma.reset();
+
+        assert_eq!(hma.value(), 0.0);
+        assert_eq!(hma.count(), 0);
+        assert!(!hma.has_inputs());
+    }
+}
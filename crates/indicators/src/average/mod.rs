@@ -0,0 +1,134 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Moving average indicators.
+
+pub mod dema;
+pub mod ema;
+pub mod hma;
+pub mod sma;
+pub mod vwap;
+pub mod wma;
+
+use ant_model::enums::PriceType;
+use strum::{AsRefStr, Display, EnumIter, EnumString};
+
+pub use self::{
+    dema::DoubleExponentialMovingAverage, ema::ExponentialMovingAverage, hma::HullMovingAverage,
+    sma::SimpleMovingAverage, vwap::VolumeWeightedAveragePrice, wma::WeightedMovingAverage,
+};
+use crate::indicator::MovingAverage;
+
+/// The type of moving average used by composite indicators.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum MovingAverageType {
+    /// A simple moving average.
+    #[default]
+    Simple,
+    /// An exponential moving average.
+    Exponential,
+    /// An exponential moving average using Wilder's smoothing factor (`1 / period`).
+    Wilder,
+    /// A double exponential moving average.
+    DoubleExponential,
+    /// A linearly weighted moving average.
+    Weighted,
+    /// A Hull moving average.
+    Hull,
+}
+
+/// Provides a means of creating moving averages of a given [`MovingAverageType`].
+#[derive(Debug)]
+pub struct MovingAverageFactory;
+
+impl MovingAverageFactory {
+    /// Creates a new moving average of the given type and period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn create(
+        ma_type: MovingAverageType,
+        period: usize,
+        price_type: Option<PriceType>,
+    ) -> Box<dyn MovingAverage> {
+        match ma_type {
+            MovingAverageType::Simple => Box::new(SimpleMovingAverage::new(period, price_type)),
+            MovingAverageType::Exponential => {
+                Box::new(ExponentialMovingAverage::new(period, price_type))
+            }
+            MovingAverageType::Wilder => {
+                Box::new(ExponentialMovingAverage::new_wilder(period, price_type))
+            }
+            MovingAverageType::DoubleExponential => {
+                Box::new(DoubleExponentialMovingAverage::new(period, price_type))
+            }
+            MovingAverageType::Weighted => Box::new(WeightedMovingAverage::new(period, price_type)),
+            MovingAverageType::Hull => Box::new(HullMovingAverage::new(period, price_type)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(MovingAverageType::Simple, "SimpleMovingAverage")]
+    #[case(MovingAverageType::Exponential, "ExponentialMovingAverage")]
+    #[case(MovingAverageType::Wilder, "ExponentialMovingAverage")]
+    #[case(MovingAverageType::DoubleExponential, "DoubleExponentialMovingAverage")]
+    #[case(MovingAverageType::Weighted, "WeightedMovingAverage")]
+    #[case(MovingAverageType::Hull, "HullMovingAverage")]
+    fn test_factory_create(#[case] ma_type: MovingAverageType, #[case] expected: &str) {
+        let ma = MovingAverageFactory::create(ma_type, 10, None);
+
+        assert_eq!(ma.name(), expected);
+        assert!(!ma.initialized());
+    }
+
+    #[rstest]
+    fn test_moving_average_type_from_str() {
+        assert_eq!(
+            MovingAverageType::from_str("exponential").unwrap(),
+            MovingAverageType::Exponential
+        );
+        assert_eq!(
+            MovingAverageType::from_str("DOUBLE_EXPONENTIAL").unwrap(),
+            MovingAverageType::DoubleExponential
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::indicator::{Indicator, MovingAverage};
+
+/// An indicator which calculates a simple moving average (SMA) across a rolling window.
+#[derive(Debug, Clone)]
+pub struct SimpleMovingAverage {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    /// The inputs within the rolling window.
+    pub inputs: VecDeque<f64>,
+    sum: f64,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for SimpleMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl SimpleMovingAverage {
+    /// Creates a new [`SimpleMovingAverage`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            inputs: VecDeque::with_capacity(period),
+            sum: 0.0,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+}
+
+impl Indicator for SimpleMovingAverage {
+    fn name(&self) -> String {
+        stringify!(SimpleMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.inputs.clear();
+        self.sum = 0.0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for SimpleMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period
+            && let Some(oldest) = self.inputs.pop_front()
+        {
+            self.sum -= oldest;
+        }
+
+        self.inputs.push_back(value);
+        self.sum += value;
+        self.count = self.inputs.len();
+        self.value = self.sum / self.count as f64;
+        self.has_inputs = true;
+
+        if !self.initialized && self.count >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{stub_bar, stub_quote, stub_trade};
+
+    #[rstest]
+    fn test_sma_initialized() {
+        let sma = SimpleMovingAverage::new(10, None);
+
+        assert_eq!(sma.to_string(), "SimpleMovingAverage(10)");
+        assert_eq!(sma.period, 10);
+        assert_eq!(sma.price_type, PriceType::Last);
+        assert!(!sma.has_inputs());
+        assert!(!sma.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`period` must be positive")]
+    fn test_sma_zero_period_panics() {
+        let _ = SimpleMovingAverage::new(0, None);
+    }
+
+    #[rstest]
+    fn test_sma_update_raw_rolls_window() {
+        let mut sma = SimpleMovingAverage::new(3, None);
+
+        for value in [1.0, 2.0, 3.0, 4.0, 5.0] {
+            sma.update_raw(value);
+        }
+
+        assert!(sma.initialized());
+        assert_eq!(sma.count(), 3);
+        assert_eq!(sma.value(), 4.0);
+    }
+
+    #[rstest]
+    fn test_sma_handle_data() {
+        let mut sma = SimpleMovingAverage::new(3, Some(PriceType::Mid));
+
+        sma.handle_quote(&stub_quote("100.0", "102.0"));
+        sma.handle_trade(&stub_trade("104.0", "1"));
+        sma.handle_bar(&stub_bar("110.0", "100.0", "105.0"));
+
+        assert!(sma.initialized());
+        assert_eq!(sma.value(), 103.0);
+    }
+
+    #[rstest]
+    fn test_sma_reset() {
+        let mut sma = SimpleMovingAverage::new(2, None);
+        sma.update_raw(1.0);
+        sma.update_raw(2.0);
+
+        sma.reset();
+
+        assert_eq!(sma.value(), 0.0);
+        assert_eq!(sma.count(), 0);
+        assert!(sma.inputs.is_empty());
+        assert!(!sma.has_inputs());
+        assert!(!sma.initialized());
+    }
+}
@@ -0,0 +1,175 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_core::UnixNanos;
+use ant_model::data::{Bar, TradeTick};
+
+use crate::indicator::Indicator;
+
+const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// An indicator which calculates the volume weighted average price (VWAP) for the day.
+///
+/// The calculation resets whenever an input falls on a new UTC day. Bars contribute
+/// their typical price `(high + low + close) / 3`.
+#[derive(Debug, Clone, Default)]
+pub struct VolumeWeightedAveragePrice {
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received for the current day.
+    pub count: usize,
+    price_volume: f64,
+    volume_total: f64,
+    day: Option<u64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for VolumeWeightedAveragePrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl VolumeWeightedAveragePrice {
+    /// Creates a new [`VolumeWeightedAveragePrice`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the indicator with the given raw price, volume and timestamp.
+    pub fn update_raw(&mut self, price: f64, volume: f64, timestamp: UnixNanos) {
+        let day = timestamp.as_u64() / NANOS_PER_DAY;
+
+        if self.day != Some(day) {
+            // New day, restart the calculation
+            self.day = Some(day);
+            self.count = 0;
+            self.price_volume = 0.0;
+            self.volume_total = 0.0;
+            self.value = price;
+        }
+
+        self.count += 1;
+        self.has_inputs = true;
+        self.initialized = true;
+
+        // No weighting possible without volume
+        if volume <= 0.0 {
+            return;
+        }
+
+        self.price_volume = price.mul_add(volume, self.price_volume);
+        self.volume_total += volume;
+        self.value = self.price_volume / self.volume_total;
+    }
+}
+
+impl Indicator for VolumeWeightedAveragePrice {
+    fn name(&self) -> String {
+        stringify!(VolumeWeightedAveragePrice).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64(), trade.size.as_f64(), trade.ts_event);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        let typical_price = (bar.high.as_f64() + bar.low.as_f64() + bar.close.as_f64()) / 3.0;
+        self.update_raw(typical_price, bar.volume.as_f64(), bar.ts_event);
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::{stub_bar_with_volume, stub_trade};
+
+    #[rstest]
+    fn test_vwap_initialized() {
+        let vwap = VolumeWeightedAveragePrice::new();
+
+        assert_eq!(vwap.to_string(), "VolumeWeightedAveragePrice");
+        assert!(!vwap.has_inputs());
+        assert!(!vwap.initialized());
+    }
+
+    #[rstest]
+    fn test_vwap_update_raw() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        vwap.update_raw(100.0, 1.0, UnixNanos::default());
+        vwap.update_raw(110.0, 3.0, UnixNanos::default());
+
+        assert!(vwap.initialized());
+        assert_eq!(vwap.count, 2);
+        assert!((vwap.value - 107.5).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_vwap_zero_volume_uses_price_on_first_input() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        vwap.update_raw(100.0, 0.0, UnixNanos::default());
+
+        assert_eq!(vwap.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_vwap_resets_on_new_day() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+
+        vwap.handle_trade(&stub_trade("100.0", "10"));
+        vwap.handle_bar(&stub_bar_with_volume(
+            "130.0",
+            "110.0",
+            "120.0",
+            "5",
+            UnixNanos::from(NANOS_PER_DAY),
+        ));
+
+        assert_eq!(vwap.count, 1);
+        assert!((vwap.value - 120.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_vwap_reset() {
+        let mut vwap = VolumeWeightedAveragePrice::new();
+        vwap.update_raw(100.0, 1.0, UnixNanos::default());
+
+        vwap.reset();
+
+        assert_eq!(vwap.value, 0.0);
+        assert!(!vwap.has_inputs());
+        assert!(!vwap.initialized());
+    }
+}
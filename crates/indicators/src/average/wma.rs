@@ -0,0 +1,197 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::indicator::{Indicator, MovingAverage};
+
+/// An indicator which calculates a linearly weighted moving average (WMA) across a
+/// rolling window.
+///
+/// The most recent input has weight `period`, the oldest input has weight 1.
+#[derive(Debug, Clone)]
+pub struct WeightedMovingAverage {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    /// The inputs within the rolling window.
+    pub inputs: VecDeque<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for WeightedMovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl WeightedMovingAverage {
+    /// Creates a new [`WeightedMovingAverage`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            inputs: VecDeque::with_capacity(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    fn weighted_average(&self) -> f64 {
+        // Weights are applied to the inputs received so far during warm-up
+        let (sum, weights) =
+            self.inputs
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(sum, weights), (i, input)| {
+                    let weight = (i + 1) as f64;
+                    (weight.mul_add(*input, sum), weights + weight)
+                });
+        sum / weights
+    }
+}
+
+impl Indicator for WeightedMovingAverage {
+    fn name(&self) -> String {
+        stringify!(WeightedMovingAverage).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.inputs.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl MovingAverage for WeightedMovingAverage {
+    fn value(&self) -> f64 {
+        self.value
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period {
+            self.inputs.pop_front();
+        }
+
+        self.inputs.push_back(value);
+        self.count = self.inputs.len();
+        self.value = self.weighted_average();
+        self.has_inputs = true;
+
+        if !self.initialized && self.count >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_wma_initialized() {
+        let wma = WeightedMovingAverage::new(10, None);
+
+        assert_eq!(wma.to_string(), "WeightedMovingAverage(10)");
+        assert!(!wma.has_inputs());
+        assert!(!wma.initialized());
+    }
+
+    #[rstest]
+    fn test_wma_update_raw() {
+        let mut wma = WeightedMovingAverage::new(3, None);
+
+        for value in [1.0, 2.0, 3.0] {
+            wma.update_raw(value);
+        }
+
+        // (1*1 + 2*2 + 3*3) / 6
+        assert!(wma.initialized());
+        assert!((wma.value() - 14.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_wma_rolls_window() {
+        let mut wma = WeightedMovingAverage::new(2, None);
+
+        for value in [1.0, 2.0, 4.0] {
+            wma.update_raw(value);
+        }
+
+        // (2*1 + 4*2) / 3
+        assert_eq!(wma.count(), 2);
+        assert!((wma.value() - 10.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_wma_reset() {
+        let mut wma = WeightedMovingAverage::new(2, None);
+        wma.update_raw(1.0);
+
+        wma.reset();
+
+        assert_eq!(wma.value(), 0.0);
+        assert!(wma.inputs.is_empty());
+        assert!(!wma.has_inputs());
+    }
+}
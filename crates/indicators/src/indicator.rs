@@ -0,0 +1,71 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Core traits implemented by all indicators.
+
+use std::fmt::Debug;
+
+use ant_model::data::{Bar, QuoteTick, TradeTick};
+
+/// A technical indicator which can be updated from market data.
+///
+/// Each data handler has a default no-op implementation, so an indicator only needs to
+/// implement the handlers for the data types it can be calculated from.
+pub trait Indicator {
+    /// Returns the name of the indicator.
+    fn name(&self) -> String;
+
+    /// Returns whether the indicator has received any inputs.
+    fn has_inputs(&self) -> bool;
+
+    /// Returns whether the indicator has received enough inputs to be warmed up.
+    fn initialized(&self) -> bool;
+
+    /// Updates the indicator with the given quote.
+    fn handle_quote(&mut self, _quote: &QuoteTick) {}
+
+    /// Updates the indicator with the given trade.
+    fn handle_trade(&mut self, _trade: &TradeTick) {}
+
+    /// Updates the indicator with the given bar.
+    fn handle_bar(&mut self, _bar: &Bar) {}
+
+    /// Resets the indicator to its initial state.
+    fn reset(&mut self);
+}
+
+impl Debug for dyn Indicator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Indicator({})", self.name())
+    }
+}
+
+/// A moving average indicator over a single stream of values.
+pub trait MovingAverage: Indicator {
+    /// Returns the current value of the moving average.
+    fn value(&self) -> f64;
+
+    /// Returns the number of inputs received.
+    fn count(&self) -> usize;
+
+    /// Updates the moving average with the given raw value.
+    fn update_raw(&mut self, value: f64);
+}
+
+impl Debug for dyn MovingAverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MovingAverage({})", self.name())
+    }
+}
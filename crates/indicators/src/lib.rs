@@ -0,0 +1,51 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Technical analysis indicators for [antTrader](http://anttrader.io).
+//!
+//! The `ant-indicators` crate provides the [`Indicator`](indicator::Indicator) trait along with a
+//! library of commonly used technical indicators. Indicators can be updated directly with raw
+//! values, or registered with a `DataActor` to be updated automatically from market data:
+//!
+//! - Moving averages: SMA, EMA, WMA, DEMA, HMA and VWAP.
+//! - Momentum: RSI, MACD, Bollinger Bands, Stochastics, ADX, CCI, OBV and Aroon.
+//! - Volatility: ATR, Keltner Channel and Donchian Channel.
+//!
+//! # Platform
+//!
+//! [antTrader](http://anttrader.io) is an open-source, high-performance, production-grade
+//! algorithmic trading platform, providing quantitative traders with the ability to backtest
+//! portfolios of automated trading strategies on historical data with an event-driven engine,
+//! and also deploy those same strategies live, with no code changes.
+//!
+//! # Feature flags
+//!
+//! - `stubs`: Enables type stubs for use in testing scenarios.
+
+#![warn(rustc::all)]
+#![deny(unsafe_code)]
+#![deny(nonstandard_style)]
+#![deny(missing_debug_implementations)]
+#![deny(clippy::missing_errors_doc)]
+#![deny(clippy::missing_panics_doc)]
+#![deny(rustdoc::broken_intra_doc_links)]
+
+pub mod average;
+pub mod indicator;
+pub mod momentum;
+pub mod volatility;
+
+#[cfg(any(test, feature = "stubs"))]
+pub mod stubs;
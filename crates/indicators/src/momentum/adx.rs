@@ -0,0 +1,230 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::data::Bar;
+
+use crate::{
+    average::ema::ExponentialMovingAverage,
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates the average directional index (ADX).
+///
+/// Directional movement and true range are smoothed with Wilder's method to produce the
+/// positive and negative directional indicators (`+DI` / `-DI`), and the ADX is the
+/// smoothed directional movement index (DX) derived from them. All values are in the
+/// range [0, 100].
+#[derive(Debug, Clone)]
+pub struct AverageDirectionalIndex {
+    /// The smoothing period for the indicator.
+    pub period: usize,
+    /// The current ADX value.
+    pub value: f64,
+    /// The current positive directional indicator value.
+    pub pos_di: f64,
+    /// The current negative directional indicator value.
+    pub neg_di: f64,
+    prev_high: f64,
+    prev_low: f64,
+    prev_close: f64,
+    pos_dm_ma: ExponentialMovingAverage,
+    neg_dm_ma: ExponentialMovingAverage,
+    tr_ma: ExponentialMovingAverage,
+    dx_ma: ExponentialMovingAverage,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for AverageDirectionalIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl AverageDirectionalIndex {
+    /// Creates a new [`AverageDirectionalIndex`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self {
+            period,
+            value: 0.0,
+            pos_di: 0.0,
+            neg_di: 0.0,
+            prev_high: 0.0,
+            prev_low: 0.0,
+            prev_close: 0.0,
+            pos_dm_ma: ExponentialMovingAverage::new_wilder(period, None),
+            neg_dm_ma: ExponentialMovingAverage::new_wilder(period, None),
+            tr_ma: ExponentialMovingAverage::new_wilder(period, None),
+            dx_ma: ExponentialMovingAverage::new_wilder(period, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if !self.has_inputs {
+            // First input only establishes the previous bar
+            self.prev_high = high;
+            self.prev_low = low;
+            self.prev_close = close;
+            self.has_inputs = true;
+            return;
+        }
+
+        let up_move = high - self.prev_high;
+        let down_move = self.prev_low - low;
+
+        let pos_dm = if up_move > down_move && up_move > 0.0 {
+            up_move
+        } else {
+            0.0
+        };
+        let neg_dm = if down_move > up_move && down_move > 0.0 {
+            down_move
+        } else {
+            0.0
+        };
+        let true_range = high.max(self.prev_close) - low.min(self.prev_close);
+
+        self.pos_dm_ma.update_raw(pos_dm);
+        self.neg_dm_ma.update_raw(neg_dm);
+        self.tr_ma.update_raw(true_range);
+
+        let atr = self.tr_ma.value();
+        if atr > 0.0 {
+            self.pos_di = 100.0 * self.pos_dm_ma.value() / atr;
+            self.neg_di = 100.0 * self.neg_dm_ma.value() / atr;
+        }
+
+        let di_sum = self.pos_di + self.neg_di;
+        let dx = if di_sum > 0.0 {
+            100.0 * (self.pos_di - self.neg_di).abs() / di_sum
+        } else {
+            0.0
+        };
+
+        self.dx_ma.update_raw(dx);
+        self.value = self.dx_ma.value();
+
+        self.prev_high = high;
+        self.prev_low = low;
+        self.prev_close = close;
+
+        if !self.initialized && self.dx_ma.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for AverageDirectionalIndex {
+    fn name(&self) -> String {
+        stringify!(AverageDirectionalIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.pos_di = 0.0;
+        self.neg_di = 0.0;
+        self.prev_high = 0.0;
+        self.prev_low = 0.0;
+        self.prev_close = 0.0;
+        self.pos_dm_ma.reset();
+        self.neg_dm_ma.reset();
+        self.tr_ma.reset();
+        self.dx_ma.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_adx_initialized() {
+        let adx = AverageDirectionalIndex::new(14);
+
+        assert_eq!(adx.to_string(), "AverageDirectionalIndex(14)");
+        assert!(!adx.has_inputs());
+        assert!(!adx.initialized());
+    }
+
+    #[rstest]
+    fn test_adx_strong_uptrend() {
+        let mut adx = AverageDirectionalIndex::new(3);
+
+        for i in 0..10 {
+            let base = f64::from(i);
+            adx.update_raw(base + 1.0, base, base + 0.5);
+        }
+
+        assert!(adx.initialized());
+        assert!(adx.pos_di > adx.neg_di);
+        assert_eq!(adx.neg_di, 0.0);
+        assert!((adx.value - 100.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_adx_strong_downtrend() {
+        let mut adx = AverageDirectionalIndex::new(3);
+
+        for i in (0..10).rev() {
+            let base = f64::from(i);
+            adx.update_raw(base + 1.0, base, base + 0.5);
+        }
+
+        assert!(adx.neg_di > adx.pos_di);
+        assert!((adx.value - 100.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_adx_reset() {
+        let mut adx = AverageDirectionalIndex::new(2);
+        adx.update_raw(2.0, 1.0, 1.5);
+        adx.update_raw(3.0, 2.0, 2.5);
+
+        adx.reset();
+
+        assert_eq!(adx.value, 0.0);
+        assert_eq!(adx.pos_di, 0.0);
+        assert!(!adx.has_inputs());
+        assert!(!adx.initialized());
+    }
+}
@@ -0,0 +1,205 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::data::{Bar, TradeTick};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the Aroon oscillator.
+///
+/// Aroon up/down measure how recently the highest high and lowest low occurred within
+/// the last `period + 1` inputs, in the range [0, 100]. The oscillator value is
+/// `aroon_up - aroon_down`.
+#[derive(Debug, Clone)]
+pub struct AroonOscillator {
+    /// The lookback period for the indicator.
+    pub period: usize,
+    /// The current Aroon up value.
+    pub aroon_up: f64,
+    /// The current Aroon down value.
+    pub aroon_down: f64,
+    /// The current oscillator value.
+    pub value: f64,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for AroonOscillator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl AroonOscillator {
+    /// Creates a new [`AroonOscillator`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self {
+            period,
+            aroon_up: 0.0,
+            aroon_down: 0.0,
+            value: 0.0,
+            highs: VecDeque::with_capacity(period + 1),
+            lows: VecDeque::with_capacity(period + 1),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if self.highs.len() == self.period + 1 {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+        self.has_inputs = true;
+
+        if self.highs.len() < self.period + 1 {
+            return;
+        }
+
+        // Ties resolve to the most recent occurrence
+        let last_index = self.highs.len() - 1;
+        let high_index =
+            self.highs.iter().enumerate().fold(
+                0,
+                |best, (i, v)| if *v >= self.highs[best] { i } else { best },
+            );
+        let low_index =
+            self.lows.iter().enumerate().fold(
+                0,
+                |best, (i, v)| if *v <= self.lows[best] { i } else { best },
+            );
+
+        let period = self.period as f64;
+        self.aroon_up = 100.0 * (period - (last_index - high_index) as f64) / period;
+        self.aroon_down = 100.0 * (period - (last_index - low_index) as f64) / period;
+        self.value = self.aroon_up - self.aroon_down;
+        self.initialized = true;
+    }
+}
+
+impl Indicator for AroonOscillator {
+    fn name(&self) -> String {
+        stringify!(AroonOscillator).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        let price = trade.price.as_f64();
+        self.update_raw(price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.aroon_up = 0.0;
+        self.aroon_down = 0.0;
+        self.value = 0.0;
+        self.highs.clear();
+        self.lows.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_aroon_initialized() {
+        let aroon = AroonOscillator::new(25);
+
+        assert_eq!(aroon.to_string(), "AroonOscillator(25)");
+        assert!(!aroon.has_inputs());
+        assert!(!aroon.initialized());
+    }
+
+    #[rstest]
+    fn test_aroon_requires_period_plus_one_inputs() {
+        let mut aroon = AroonOscillator::new(3);
+
+        for i in 0..3 {
+            aroon.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+        assert!(!aroon.initialized());
+
+        aroon.update_raw(4.0, 3.0);
+        assert!(aroon.initialized());
+    }
+
+    #[rstest]
+    fn test_aroon_uptrend() {
+        let mut aroon = AroonOscillator::new(3);
+
+        for i in 0..4 {
+            aroon.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+
+        assert_eq!(aroon.aroon_up, 100.0);
+        assert_eq!(aroon.aroon_down, 0.0);
+        assert_eq!(aroon.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_aroon_downtrend() {
+        let mut aroon = AroonOscillator::new(3);
+
+        for i in (0..4).rev() {
+            aroon.update_raw(f64::from(i) + 1.0, f64::from(i));
+        }
+
+        assert_eq!(aroon.aroon_up, 0.0);
+        assert_eq!(aroon.aroon_down, 100.0);
+        assert_eq!(aroon.value, -100.0);
+    }
+
+    #[rstest]
+    fn test_aroon_reset() {
+        let mut aroon = AroonOscillator::new(1);
+        aroon.update_raw(2.0, 1.0);
+        aroon.update_raw(3.0, 2.0);
+
+        aroon.reset();
+
+        assert_eq!(aroon.value, 0.0);
+        assert!(!aroon.has_inputs());
+        assert!(!aroon.initialized());
+    }
+}
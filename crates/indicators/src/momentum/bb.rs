@@ -0,0 +1,208 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates Bollinger Bands.
+///
+/// The middle band is the simple moving average of the inputs, with the upper and lower
+/// bands placed `k` (population) standard deviations above and below it.
+#[derive(Debug, Clone)]
+pub struct BollingerBands {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The standard deviation multiplier for the bands.
+    pub k: f64,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The current upper band value.
+    pub upper: f64,
+    /// The current middle band value.
+    pub middle: f64,
+    /// The current lower band value.
+    pub lower: f64,
+    /// The inputs within the rolling window.
+    pub inputs: VecDeque<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for BollingerBands {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.k)
+    }
+}
+
+impl BollingerBands {
+    /// Creates a new [`BollingerBands`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `period` is not positive (> 0).
+    /// - `k` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, k: f64, price_type: Option<PriceType>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        assert!(k > 0.0, "`k` must be positive, was {k}");
+
+        Self {
+            period,
+            k,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            inputs: VecDeque::with_capacity(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Returns the current band width relative to the middle band.
+    #[must_use]
+    pub fn bandwidth(&self) -> f64 {
+        if self.middle == 0.0 {
+            return 0.0;
+        }
+        (self.upper - self.lower) / self.middle
+    }
+
+    /// Updates the indicator with the given raw value.
+    pub fn update_raw(&mut self, value: f64) {
+        if self.inputs.len() == self.period {
+            self.inputs.pop_front();
+        }
+        self.inputs.push_back(value);
+
+        let count = self.inputs.len() as f64;
+        let mean = self.inputs.iter().sum::<f64>() / count;
+        let variance = self
+            .inputs
+            .iter()
+            .map(|input| (input - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let std = variance.sqrt();
+
+        self.middle = mean;
+        self.upper = self.k.mul_add(std, mean);
+        self.lower = self.k.mul_add(-std, mean);
+        self.has_inputs = true;
+
+        if !self.initialized && self.inputs.len() >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for BollingerBands {
+    fn name(&self) -> String {
+        stringify!(BollingerBands).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.inputs.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_bb_initialized() {
+        let bb = BollingerBands::new(20, 2.0, None);
+
+        assert_eq!(bb.to_string(), "BollingerBands(20,2)");
+        assert!(!bb.has_inputs());
+        assert!(!bb.initialized());
+    }
+
+    #[rstest]
+    fn test_bb_update_raw() {
+        let mut bb = BollingerBands::new(4, 2.0, None);
+
+        for value in [2.0, 4.0, 4.0, 6.0] {
+            bb.update_raw(value);
+        }
+
+        // Mean 4.0, population std sqrt(2)
+        let std = 2.0f64.sqrt();
+        assert!(bb.initialized());
+        assert!((bb.middle - 4.0).abs() < 1e-12);
+        assert!((bb.upper - 2.0f64.mul_add(std, 4.0)).abs() < 1e-12);
+        assert!((bb.lower - 2.0f64.mul_add(-std, 4.0)).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_bb_constant_inputs_collapse_bands() {
+        let mut bb = BollingerBands::new(3, 2.0, None);
+
+        for _ in 0..3 {
+            bb.update_raw(10.0);
+        }
+
+        assert_eq!(bb.upper, 10.0);
+        assert_eq!(bb.lower, 10.0);
+        assert_eq!(bb.bandwidth(), 0.0);
+    }
+
+    #[rstest]
+    fn test_bb_reset() {
+        let mut bb = BollingerBands::new(2, 2.0, None);
+        bb.update_raw(1.0);
+
+        bb.reset();
+
+        assert_eq!(bb.middle, 0.0);
+        assert!(bb.inputs.is_empty());
+        assert!(!bb.has_inputs());
+    }
+}
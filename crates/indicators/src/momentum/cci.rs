@@ -0,0 +1,174 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the commodity channel index (CCI).
+///
+/// `CCI = (TP - SMA(TP)) / (scalar * mean deviation)`, where `TP` is the typical price
+/// `(high + low + close) / 3`.
+#[derive(Debug, Clone)]
+pub struct CommodityChannelIndex {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The scaling constant (Lambert's constant, 0.015 by default).
+    pub scalar: f64,
+    /// The current output value.
+    pub value: f64,
+    typical_prices: VecDeque<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for CommodityChannelIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.scalar)
+    }
+}
+
+impl CommodityChannelIndex {
+    /// Creates a new [`CommodityChannelIndex`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `period` is not positive (> 0).
+    /// - `scalar` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize, scalar: Option<f64>) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        let scalar = scalar.unwrap_or(0.015);
+        assert!(scalar > 0.0, "`scalar` must be positive, was {scalar}");
+
+        Self {
+            period,
+            scalar,
+            value: 0.0,
+            typical_prices: VecDeque::with_capacity(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical_price = (high + low + close) / 3.0;
+
+        if self.typical_prices.len() == self.period {
+            self.typical_prices.pop_front();
+        }
+        self.typical_prices.push_back(typical_price);
+
+        let count = self.typical_prices.len() as f64;
+        let mean = self.typical_prices.iter().sum::<f64>() / count;
+        let mean_deviation = self
+            .typical_prices
+            .iter()
+            .map(|tp| (tp - mean).abs())
+            .sum::<f64>()
+            / count;
+
+        self.value = if mean_deviation > 0.0 {
+            (typical_price - mean) / (self.scalar * mean_deviation)
+        } else {
+            0.0
+        };
+        self.has_inputs = true;
+
+        if !self.initialized && self.typical_prices.len() >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for CommodityChannelIndex {
+    fn name(&self) -> String {
+        stringify!(CommodityChannelIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.typical_prices.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_cci_initialized() {
+        let cci = CommodityChannelIndex::new(20, None);
+
+        assert_eq!(cci.to_string(), "CommodityChannelIndex(20,0.015)");
+        assert!(!cci.has_inputs());
+        assert!(!cci.initialized());
+    }
+
+    #[rstest]
+    fn test_cci_update_raw() {
+        let mut cci = CommodityChannelIndex::new(3, None);
+
+        cci.update_raw(1.0, 1.0, 1.0);
+        cci.update_raw(2.0, 2.0, 2.0);
+        cci.update_raw(3.0, 3.0, 3.0);
+
+        // Mean 2.0, mean deviation 2/3
+        assert!(cci.initialized());
+        assert!((cci.value - 1.0 / (0.015 * 2.0 / 3.0)).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_cci_constant_prices() {
+        let mut cci = CommodityChannelIndex::new(3, None);
+
+        for _ in 0..3 {
+            cci.update_raw(2.0, 1.0, 1.5);
+        }
+
+        assert_eq!(cci.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_cci_reset() {
+        let mut cci = CommodityChannelIndex::new(2, None);
+        cci.update_raw(2.0, 1.0, 1.5);
+
+        cci.reset();
+
+        assert_eq!(cci.value, 0.0);
+        assert!(!cci.has_inputs());
+    }
+}
@@ -0,0 +1,243 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType, ema::ExponentialMovingAverage},
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates the moving average convergence divergence (MACD).
+///
+/// The MACD line is the difference between a fast and a slow moving average, the signal
+/// line is an EMA of the MACD line, and the histogram is their difference.
+#[derive(Debug)]
+pub struct MovingAverageConvergenceDivergence {
+    /// The period for the fast moving average.
+    pub fast_period: usize,
+    /// The period for the slow moving average.
+    pub slow_period: usize,
+    /// The period for the signal line EMA.
+    pub signal_period: usize,
+    /// The moving average type for the fast and slow averages.
+    pub ma_type: MovingAverageType,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The current MACD line value.
+    pub value: f64,
+    /// The current signal line value.
+    pub signal: f64,
+    /// The current histogram value (MACD line minus signal line).
+    pub histogram: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    fast_ma: Box<dyn MovingAverage>,
+    slow_ma: Box<dyn MovingAverage>,
+    signal_ma: ExponentialMovingAverage,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for MovingAverageConvergenceDivergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.fast_period,
+            self.slow_period,
+            self.signal_period,
+            self.ma_type,
+        )
+    }
+}
+
+impl MovingAverageConvergenceDivergence {
+    /// Creates a new [`MovingAverageConvergenceDivergence`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - Any period is not positive (> 0).
+    /// - `fast_period` is not less than `slow_period`.
+    #[must_use]
+    pub fn new(
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+        ma_type: Option<MovingAverageType>,
+        price_type: Option<PriceType>,
+    ) -> Self {
+        assert!(
+            fast_period > 0,
+            "`fast_period` must be positive, was {fast_period}"
+        );
+        assert!(
+            signal_period > 0,
+            "`signal_period` must be positive, was {signal_period}"
+        );
+        assert!(
+            fast_period < slow_period,
+            "`fast_period` must be less than `slow_period`, was {fast_period} >= {slow_period}"
+        );
+
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            ma_type,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            signal: 0.0,
+            histogram: 0.0,
+            count: 0,
+            fast_ma: MovingAverageFactory::create(ma_type, fast_period, None),
+            slow_ma: MovingAverageFactory::create(ma_type, slow_period, None),
+            signal_ma: ExponentialMovingAverage::new(signal_period, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw value.
+    pub fn update_raw(&mut self, value: f64) {
+        self.fast_ma.update_raw(value);
+        self.slow_ma.update_raw(value);
+
+        self.value = self.fast_ma.value() - self.slow_ma.value();
+        self.signal_ma.update_raw(self.value);
+        self.signal = self.signal_ma.value();
+        self.histogram = self.value - self.signal;
+        self.count += 1;
+        self.has_inputs = true;
+
+        if !self.initialized && self.slow_ma.initialized() && self.signal_ma.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for MovingAverageConvergenceDivergence {
+    fn name(&self) -> String {
+        stringify!(MovingAverageConvergenceDivergence).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.signal = 0.0;
+        self.histogram = 0.0;
+        self.count = 0;
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_macd_initialized() {
+        let macd = MovingAverageConvergenceDivergence::new(12, 26, 9, None, None);
+
+        assert_eq!(
+            macd.to_string(),
+            "MovingAverageConvergenceDivergence(12,26,9,EXPONENTIAL)"
+        );
+        assert!(!macd.has_inputs());
+        assert!(!macd.initialized());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`fast_period` must be less than `slow_period`")]
+    fn test_macd_invalid_periods_panics() {
+        let _ = MovingAverageConvergenceDivergence::new(26, 12, 9, None, None);
+    }
+
+    #[rstest]
+    fn test_macd_with_simple_averages() {
+        let mut macd =
+            MovingAverageConvergenceDivergence::new(2, 3, 1, Some(MovingAverageType::Simple), None);
+
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            macd.update_raw(value);
+        }
+
+        // Fast SMA(2) = 3.5, slow SMA(3) = 3.0, signal EMA(1) tracks the MACD line
+        assert!(macd.initialized());
+        assert!((macd.value - 0.5).abs() < 1e-12);
+        assert!((macd.signal - 0.5).abs() < 1e-12);
+        assert!(macd.histogram.abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_macd_rising_prices_positive() {
+        let mut macd = MovingAverageConvergenceDivergence::new(3, 6, 3, None, None);
+
+        for i in 1..=20 {
+            macd.update_raw(f64::from(i));
+        }
+
+        assert!(macd.initialized());
+        assert!(macd.value > 0.0);
+    }
+
+    #[rstest]
+    fn test_macd_reset() {
+        let mut macd = MovingAverageConvergenceDivergence::new(2, 3, 2, None, None);
+        macd.update_raw(1.0);
+        macd.update_raw(2.0);
+
+        macd.reset();
+
+        assert_eq!(macd.value, 0.0);
+        assert_eq!(macd.signal, 0.0);
+        assert_eq!(macd.count, 0);
+        assert!(!macd.has_inputs());
+    }
+}
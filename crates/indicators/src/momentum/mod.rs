@@ -0,0 +1,31 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Momentum and trend indicators.
+
+pub mod adx;
+pub mod aroon;
+pub mod bb;
+pub mod cci;
+pub mod macd;
+pub mod obv;
+pub mod rsi;
+pub mod stochastics;
+
+pub use self::{
+    adx::AverageDirectionalIndex, aroon::AroonOscillator, bb::BollingerBands,
+    cci::CommodityChannelIndex, macd::MovingAverageConvergenceDivergence, obv::OnBalanceVolume,
+    rsi::RelativeStrengthIndex, stochastics::Stochastics,
+};
@@ -0,0 +1,165 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates on-balance volume (OBV).
+///
+/// Volume is added when the close rises from the previous close and subtracted when it
+/// falls. A `period` of 0 accumulates all signed volume, otherwise the value is the sum
+/// of signed volume over the rolling window.
+#[derive(Debug, Clone)]
+pub struct OnBalanceVolume {
+    /// The rolling window period for the indicator (0 for unlimited).
+    pub period: usize,
+    /// The current output value.
+    pub value: f64,
+    signed_volumes: VecDeque<f64>,
+    prev_close: Option<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for OnBalanceVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl OnBalanceVolume {
+    /// Creates a new [`OnBalanceVolume`] instance.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            value: 0.0,
+            signed_volumes: VecDeque::with_capacity(period),
+            prev_close: None,
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, close: f64, volume: f64) {
+        let signed_volume = match self.prev_close {
+            Some(prev_close) if close > prev_close => volume,
+            Some(prev_close) if close < prev_close => -volume,
+            _ => 0.0,
+        };
+        self.prev_close = Some(close);
+
+        if self.period == 0 {
+            self.value += signed_volume;
+            self.initialized = true;
+        } else {
+            if self.signed_volumes.len() == self.period {
+                self.signed_volumes.pop_front();
+            }
+            self.signed_volumes.push_back(signed_volume);
+            self.value = self.signed_volumes.iter().sum();
+
+            if !self.initialized && self.signed_volumes.len() >= self.period {
+                self.initialized = true;
+            }
+        }
+
+        self.has_inputs = true;
+    }
+}
+
+impl Indicator for OnBalanceVolume {
+    fn name(&self) -> String {
+        stringify!(OnBalanceVolume).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64(), bar.volume.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.signed_volumes.clear();
+        self.prev_close = None;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_obv_initialized() {
+        let obv = OnBalanceVolume::new(0);
+
+        assert_eq!(obv.to_string(), "OnBalanceVolume(0)");
+        assert!(!obv.has_inputs());
+        assert!(!obv.initialized());
+    }
+
+    #[rstest]
+    fn test_obv_unlimited_period() {
+        let mut obv = OnBalanceVolume::new(0);
+
+        obv.update_raw(10.0, 100.0);
+        obv.update_raw(11.0, 50.0);
+        obv.update_raw(10.5, 20.0);
+        obv.update_raw(10.5, 30.0);
+
+        assert!(obv.initialized());
+        assert_eq!(obv.value, 30.0);
+    }
+
+    #[rstest]
+    fn test_obv_rolling_period() {
+        let mut obv = OnBalanceVolume::new(2);
+
+        obv.update_raw(10.0, 100.0);
+        obv.update_raw(11.0, 50.0);
+        obv.update_raw(12.0, 20.0);
+
+        assert!(obv.initialized());
+        assert_eq!(obv.value, 70.0);
+    }
+
+    #[rstest]
+    fn test_obv_reset() {
+        let mut obv = OnBalanceVolume::new(0);
+        obv.update_raw(10.0, 100.0);
+        obv.update_raw(11.0, 100.0);
+
+        obv.reset();
+
+        assert_eq!(obv.value, 0.0);
+        assert!(!obv.has_inputs());
+        assert!(!obv.initialized());
+    }
+}
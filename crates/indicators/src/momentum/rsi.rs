@@ -0,0 +1,228 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::{
+    data::{Bar, QuoteTick, TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates the relative strength index (RSI).
+///
+/// The value is in the range [0, 100]. Average gains and losses are smoothed with
+/// Wilder's method by default.
+#[derive(Debug)]
+pub struct RelativeStrengthIndex {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The price type used to extract prices from quotes.
+    pub price_type: PriceType,
+    /// The moving average type used to smooth gains and losses.
+    pub ma_type: MovingAverageType,
+    /// The current output value.
+    pub value: f64,
+    /// The number of price changes received.
+    pub count: usize,
+    last_value: f64,
+    average_gain: Box<dyn MovingAverage>,
+    average_loss: Box<dyn MovingAverage>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for RelativeStrengthIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period, self.ma_type)
+    }
+}
+
+impl RelativeStrengthIndex {
+    /// Creates a new [`RelativeStrengthIndex`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(
+        period: usize,
+        ma_type: Option<MovingAverageType>,
+        price_type: Option<PriceType>,
+    ) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Wilder);
+
+        Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            ma_type,
+            value: 0.0,
+            count: 0,
+            last_value: 0.0,
+            average_gain: MovingAverageFactory::create(ma_type, period, None),
+            average_loss: MovingAverageFactory::create(ma_type, period, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw value.
+    pub fn update_raw(&mut self, value: f64) {
+        if !self.has_inputs {
+            // First input only establishes the reference price
+            self.last_value = value;
+            self.has_inputs = true;
+            return;
+        }
+
+        let change = value - self.last_value;
+        self.average_gain.update_raw(change.max(0.0));
+        self.average_loss.update_raw((-change).max(0.0));
+        self.last_value = value;
+        self.count += 1;
+
+        let gain = self.average_gain.value();
+        let loss = self.average_loss.value();
+
+        self.value = if loss == 0.0 {
+            if gain == 0.0 { 50.0 } else { 100.0 }
+        } else {
+            100.0 - 100.0 / (1.0 + gain / loss)
+        };
+
+        if !self.initialized && self.average_gain.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for RelativeStrengthIndex {
+    fn name(&self) -> String {
+        stringify!(RelativeStrengthIndex).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw(trade.price.as_f64());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.last_value = 0.0;
+        self.average_gain.reset();
+        self.average_loss.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_rsi_initialized() {
+        let rsi = RelativeStrengthIndex::new(14, None, None);
+
+        assert_eq!(rsi.to_string(), "RelativeStrengthIndex(14,WILDER)");
+        assert!(!rsi.has_inputs());
+        assert!(!rsi.initialized());
+    }
+
+    #[rstest]
+    fn test_rsi_rising_prices() {
+        let mut rsi = RelativeStrengthIndex::new(3, None, None);
+
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            rsi.update_raw(value);
+        }
+
+        assert!(rsi.initialized());
+        assert_eq!(rsi.value, 100.0);
+    }
+
+    #[rstest]
+    fn test_rsi_falling_prices() {
+        let mut rsi = RelativeStrengthIndex::new(3, None, None);
+
+        for value in [4.0, 3.0, 2.0, 1.0] {
+            rsi.update_raw(value);
+        }
+
+        assert_eq!(rsi.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_rsi_balanced_moves_with_simple_average() {
+        let mut rsi = RelativeStrengthIndex::new(2, Some(MovingAverageType::Simple), None);
+
+        for value in [1.0, 2.0, 1.0] {
+            rsi.update_raw(value);
+        }
+
+        assert!(rsi.initialized());
+        assert!((rsi.value - 50.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_rsi_flat_prices() {
+        let mut rsi = RelativeStrengthIndex::new(2, None, None);
+
+        for _ in 0..3 {
+            rsi.update_raw(1.0);
+        }
+
+        assert_eq!(rsi.value, 50.0);
+    }
+
+    #[rstest]
+    fn test_rsi_reset() {
+        let mut rsi = RelativeStrengthIndex::new(2, None, None);
+        rsi.update_raw(1.0);
+        rsi.update_raw(2.0);
+        rsi.update_raw(3.0);
+
+        rsi.reset();
+
+        assert_eq!(rsi.value, 0.0);
+        assert_eq!(rsi.count, 0);
+        assert!(!rsi.has_inputs());
+        assert!(!rsi.initialized());
+    }
+}
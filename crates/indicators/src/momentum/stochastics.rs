@@ -0,0 +1,188 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::data::Bar;
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the stochastic oscillator.
+///
+/// `%K` locates the close within the high-low range of the last `period_k` bars, and
+/// `%D` is the simple moving average of `%K` over `period_d` values. Both are in the
+/// range [0, 100].
+#[derive(Debug, Clone)]
+pub struct Stochastics {
+    /// The lookback period for `%K`.
+    pub period_k: usize,
+    /// The smoothing period for `%D`.
+    pub period_d: usize,
+    /// The current `%K` value.
+    pub value_k: f64,
+    /// The current `%D` value.
+    pub value_d: f64,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    k_values: VecDeque<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for Stochastics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({},{})", self.name(), self.period_k, self.period_d)
+    }
+}
+
+impl Stochastics {
+    /// Creates a new [`Stochastics`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period_k` or `period_d` is not positive (> 0).
+    #[must_use]
+    pub fn new(period_k: usize, period_d: usize) -> Self {
+        assert!(period_k > 0, "`period_k` must be positive, was {period_k}");
+        assert!(period_d > 0, "`period_d` must be positive, was {period_d}");
+
+        Self {
+            period_k,
+            period_d,
+            value_k: 0.0,
+            value_d: 0.0,
+            highs: VecDeque::with_capacity(period_k),
+            lows: VecDeque::with_capacity(period_k),
+            k_values: VecDeque::with_capacity(period_d),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        if self.highs.len() == self.period_k {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+
+        let highest = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        let lowest = self.lows.iter().copied().fold(f64::MAX, f64::min);
+        let range = highest - lowest;
+
+        // A flat range places the close at the midpoint
+        self.value_k = if range > 0.0 {
+            100.0 * (close - lowest) / range
+        } else {
+            50.0
+        };
+
+        if self.k_values.len() == self.period_d {
+            self.k_values.pop_front();
+        }
+        self.k_values.push_back(self.value_k);
+        self.value_d = self.k_values.iter().sum::<f64>() / self.k_values.len() as f64;
+        self.has_inputs = true;
+
+        if !self.initialized
+            && self.highs.len() >= self.period_k
+            && self.k_values.len() >= self.period_d
+        {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for Stochastics {
+    fn name(&self) -> String {
+        stringify!(Stochastics).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value_k = 0.0;
+        self.value_d = 0.0;
+        self.highs.clear();
+        self.lows.clear();
+        self.k_values.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_bar;
+
+    #[rstest]
+    fn test_stochastics_initialized() {
+        let stoch = Stochastics::new(14, 3);
+
+        assert_eq!(stoch.to_string(), "Stochastics(14,3)");
+        assert!(!stoch.has_inputs());
+        assert!(!stoch.initialized());
+    }
+
+    #[rstest]
+    fn test_stochastics_update_raw() {
+        let mut stoch = Stochastics::new(3, 2);
+
+        stoch.update_raw(2.0, 1.0, 1.5);
+        stoch.update_raw(3.0, 2.0, 2.5);
+        stoch.update_raw(4.0, 3.0, 3.5);
+
+        // Range [1.0, 4.0], close 3.5 -> 83.33, previous %K (range [1.0, 3.0]) -> 75.0
+        assert!(stoch.initialized());
+        assert!((stoch.value_k - 250.0 / 3.0).abs() < 1e-9);
+        assert!((stoch.value_d - (250.0 / 3.0 + 75.0) / 2.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_stochastics_flat_range() {
+        let mut stoch = Stochastics::new(2, 1);
+
+        stoch.handle_bar(&stub_bar("100.0", "100.0", "100.0"));
+
+        assert_eq!(stoch.value_k, 50.0);
+    }
+
+    #[rstest]
+    fn test_stochastics_reset() {
+        let mut stoch = Stochastics::new(2, 1);
+        stoch.update_raw(2.0, 1.0, 1.5);
+
+        stoch.reset();
+
+        assert_eq!(stoch.value_k, 0.0);
+        assert_eq!(stoch.value_d, 0.0);
+        assert!(!stoch.has_inputs());
+    }
+}
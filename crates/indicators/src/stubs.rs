@@ -0,0 +1,92 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Type stubs to facilitate testing.
+
+use ant_core::UnixNanos;
+use ant_model::{
+    data::{Bar, BarType, QuoteTick, TradeTick},
+    enums::AggressorSide,
+    identifiers::{InstrumentId, TradeId},
+    types::{Price, Quantity},
+};
+use rstest::fixture;
+
+#[fixture]
+pub fn instrument_id() -> InstrumentId {
+    InstrumentId::from("ETHUSDT-PERP.BINANCE")
+}
+
+#[fixture]
+pub fn bar_type() -> BarType {
+    BarType::from("ETHUSDT-PERP.BINANCE-1-MINUTE-LAST-EXTERNAL")
+}
+
+/// Returns a quote with the given bid and ask prices.
+#[must_use]
+pub fn stub_quote(bid: &str, ask: &str) -> QuoteTick {
+    QuoteTick::new(
+        instrument_id(),
+        Price::from(bid),
+        Price::from(ask),
+        Quantity::from("1.00000000"),
+        Quantity::from("1.00000000"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+/// Returns a trade with the given price and size.
+#[must_use]
+pub fn stub_trade(price: &str, size: &str) -> TradeTick {
+    TradeTick::new(
+        instrument_id(),
+        Price::from(price),
+        Quantity::from(size),
+        AggressorSide::Buyer,
+        TradeId::new("123456789"),
+        UnixNanos::default(),
+        UnixNanos::default(),
+    )
+}
+
+/// Returns a bar with the given high, low and close prices.
+///
+/// The bar opens at the close price with a volume of 100.
+#[must_use]
+pub fn stub_bar(high: &str, low: &str, close: &str) -> Bar {
+    stub_bar_with_volume(high, low, close, "100", UnixNanos::default())
+}
+
+/// Returns a bar with the given high, low and close prices, volume and timestamp.
+#[must_use]
+pub fn stub_bar_with_volume(
+    high: &str,
+    low: &str,
+    close: &str,
+    volume: &str,
+    ts_event: UnixNanos,
+) -> Bar {
+    Bar::new(
+        bar_type(),
+        Price::from(close),
+        Price::from(high),
+        Price::from(low),
+        Price::from(close),
+        Quantity::from(volume),
+        ts_event,
+        ts_event,
+    )
+}
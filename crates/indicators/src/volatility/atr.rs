@@ -0,0 +1,205 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::data::Bar;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, MovingAverage},
+};
+
+/// An indicator which calculates the average true range (ATR).
+///
+/// The true range is `max(high, prev_close) - min(low, prev_close)`, smoothed with
+/// Wilder's method by default.
+#[derive(Debug)]
+pub struct AverageTrueRange {
+    /// The smoothing period for the indicator.
+    pub period: usize,
+    /// The moving average type used to smooth the true range.
+    pub ma_type: MovingAverageType,
+    /// If the previous close should be used to calculate the true range.
+    pub use_previous: bool,
+    /// The floor (minimum) output value.
+    pub value_floor: f64,
+    /// The current output value.
+    pub value: f64,
+    /// The number of inputs received.
+    pub count: usize,
+    prev_close: Option<f64>,
+    ma: Box<dyn MovingAverage>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for AverageTrueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{},{})",
+            self.name(),
+            self.period,
+            self.ma_type,
+            self.use_previous,
+            self.value_floor,
+        )
+    }
+}
+
+impl AverageTrueRange {
+    /// Creates a new [`AverageTrueRange`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `period` is not positive (> 0).
+    /// - `value_floor` is negative.
+    #[must_use]
+    pub fn new(
+        period: usize,
+        ma_type: Option<MovingAverageType>,
+        use_previous: Option<bool>,
+        value_floor: Option<f64>,
+    ) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        let value_floor = value_floor.unwrap_or(0.0);
+        assert!(
+            value_floor >= 0.0,
+            "`value_floor` must not be negative, was {value_floor}"
+        );
+
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Wilder);
+
+        Self {
+            period,
+            ma_type,
+            use_previous: use_previous.unwrap_or(true),
+            value_floor,
+            value: 0.0,
+            count: 0,
+            prev_close: None,
+            ma: MovingAverageFactory::create(ma_type, period, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let true_range = match self.prev_close {
+            Some(prev_close) if self.use_previous => high.max(prev_close) - low.min(prev_close),
+            _ => high - low,
+        };
+
+        self.ma.update_raw(true_range);
+        self.value = self.ma.value().max(self.value_floor);
+        self.prev_close = Some(close);
+        self.count += 1;
+        self.has_inputs = true;
+
+        if !self.initialized && self.ma.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for AverageTrueRange {
+    fn name(&self) -> String {
+        stringify!(AverageTrueRange).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.prev_close = None;
+        self.ma.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_bar;
+
+    #[rstest]
+    fn test_atr_initialized() {
+        let atr = AverageTrueRange::new(14, None, None, None);
+
+        assert_eq!(atr.to_string(), "AverageTrueRange(14,WILDER,true,0)");
+        assert!(!atr.has_inputs());
+        assert!(!atr.initialized());
+    }
+
+    #[rstest]
+    fn test_atr_uses_previous_close() {
+        let mut atr = AverageTrueRange::new(2, Some(MovingAverageType::Simple), None, None);
+
+        atr.update_raw(11.0, 10.0, 10.5);
+        // Gap up: true range is from the previous close
+        atr.update_raw(13.0, 12.0, 12.5);
+
+        assert!(atr.initialized());
+        assert!((atr.value - (1.0 + 2.5) / 2.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_atr_without_previous_close() {
+        let mut atr = AverageTrueRange::new(2, Some(MovingAverageType::Simple), Some(false), None);
+
+        atr.update_raw(11.0, 10.0, 10.5);
+        atr.update_raw(13.0, 12.0, 12.5);
+
+        assert!((atr.value - 1.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_atr_value_floor() {
+        let mut atr = AverageTrueRange::new(2, None, None, Some(5.0));
+
+        atr.handle_bar(&stub_bar("11.0", "10.0", "10.5"));
+
+        assert_eq!(atr.value, 5.0);
+    }
+
+    #[rstest]
+    fn test_atr_reset() {
+        let mut atr = AverageTrueRange::new(2, None, None, None);
+        atr.update_raw(11.0, 10.0, 10.5);
+
+        atr.reset();
+
+        assert_eq!(atr.value, 0.0);
+        assert_eq!(atr.count, 0);
+        assert!(!atr.has_inputs());
+    }
+}
@@ -0,0 +1,177 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::VecDeque, fmt::Display};
+
+use ant_model::data::{Bar, QuoteTick, TradeTick};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates a Donchian Channel.
+///
+/// The upper band is the highest high and the lower band the lowest low over the
+/// rolling window, with the middle band halfway between them.
+#[derive(Debug, Clone)]
+pub struct DonchianChannel {
+    /// The rolling window period for the indicator.
+    pub period: usize,
+    /// The current upper band value.
+    pub upper: f64,
+    /// The current middle band value.
+    pub middle: f64,
+    /// The current lower band value.
+    pub lower: f64,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for DonchianChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl DonchianChannel {
+    /// Creates a new [`DonchianChannel`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is not positive (> 0).
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+
+        Self {
+            period,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            highs: VecDeque::with_capacity(period),
+            lows: VecDeque::with_capacity(period),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64) {
+        if self.highs.len() == self.period {
+            self.highs.pop_front();
+            self.lows.pop_front();
+        }
+        self.highs.push_back(high);
+        self.lows.push_back(low);
+
+        self.upper = self.highs.iter().copied().fold(f64::MIN, f64::max);
+        self.lower = self.lows.iter().copied().fold(f64::MAX, f64::min);
+        self.middle = (self.upper + self.lower) / 2.0;
+        self.has_inputs = true;
+
+        if !self.initialized && self.highs.len() >= self.period {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for DonchianChannel {
+    fn name(&self) -> String {
+        stringify!(DonchianChannel).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.ask_price.as_f64(), quote.bid_price.as_f64());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        let price = trade.price.as_f64();
+        self.update_raw(price, price);
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.highs.clear();
+        self.lows.clear();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::stub_quote;
+
+    #[rstest]
+    fn test_dc_initialized() {
+        let dc = DonchianChannel::new(20);
+
+        assert_eq!(dc.to_string(), "DonchianChannel(20)");
+        assert!(!dc.has_inputs());
+        assert!(!dc.initialized());
+    }
+
+    #[rstest]
+    fn test_dc_update_raw_rolls_window() {
+        let mut dc = DonchianChannel::new(2);
+
+        dc.update_raw(15.0, 5.0);
+        dc.update_raw(12.0, 8.0);
+        dc.update_raw(11.0, 9.0);
+
+        assert!(dc.initialized());
+        assert_eq!(dc.upper, 12.0);
+        assert_eq!(dc.lower, 8.0);
+        assert_eq!(dc.middle, 10.0);
+    }
+
+    #[rstest]
+    fn test_dc_handle_quote() {
+        let mut dc = DonchianChannel::new(1);
+
+        dc.handle_quote(&stub_quote("100.0", "102.0"));
+
+        assert_eq!(dc.upper, 102.0);
+        assert_eq!(dc.lower, 100.0);
+    }
+
+    #[rstest]
+    fn test_dc_reset() {
+        let mut dc = DonchianChannel::new(2);
+        dc.update_raw(15.0, 5.0);
+
+        dc.reset();
+
+        assert_eq!(dc.upper, 0.0);
+        assert!(!dc.has_inputs());
+    }
+}
@@ -0,0 +1,191 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use ant_model::data::Bar;
+
+use crate::{
+    average::{MovingAverageFactory, MovingAverageType},
+    indicator::{Indicator, MovingAverage},
+    volatility::atr::AverageTrueRange,
+};
+
+/// An indicator which calculates a Keltner Channel.
+///
+/// The middle band is a moving average of the typical price `(high + low + close) / 3`,
+/// with the upper and lower bands placed `k_multiplier` ATRs above and below it.
+#[derive(Debug)]
+pub struct KeltnerChannel {
+    /// The period for the middle band moving average and the ATR.
+    pub period: usize,
+    /// The ATR multiplier for the bands.
+    pub k_multiplier: f64,
+    /// The moving average type for the middle band.
+    pub ma_type: MovingAverageType,
+    /// The current upper band value.
+    pub upper: f64,
+    /// The current middle band value.
+    pub middle: f64,
+    /// The current lower band value.
+    pub lower: f64,
+    ma: Box<dyn MovingAverage>,
+    atr: AverageTrueRange,
+    has_inputs: bool,
+    initialized: bool,
+}
+
+impl Display for KeltnerChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({},{},{})",
+            self.name(),
+            self.period,
+            self.k_multiplier,
+            self.ma_type,
+        )
+    }
+}
+
+impl KeltnerChannel {
+    /// Creates a new [`KeltnerChannel`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `period` is not positive (> 0).
+    /// - `k_multiplier` is not positive (> 0).
+    #[must_use]
+    pub fn new(
+        period: usize,
+        k_multiplier: f64,
+        ma_type: Option<MovingAverageType>,
+        ma_type_atr: Option<MovingAverageType>,
+    ) -> Self {
+        assert!(period > 0, "`period` must be positive, was {period}");
+        assert!(
+            k_multiplier > 0.0,
+            "`k_multiplier` must be positive, was {k_multiplier}"
+        );
+
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
+
+        Self {
+            period,
+            k_multiplier,
+            ma_type,
+            upper: 0.0,
+            middle: 0.0,
+            lower: 0.0,
+            ma: MovingAverageFactory::create(ma_type, period, None),
+            atr: AverageTrueRange::new(period, ma_type_atr, None, None),
+            has_inputs: false,
+            initialized: false,
+        }
+    }
+
+    /// Updates the indicator with the given raw values.
+    pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
+        let typical_price = (high + low + close) / 3.0;
+
+        self.ma.update_raw(typical_price);
+        self.atr.update_raw(high, low, close);
+
+        self.middle = self.ma.value();
+        self.upper = self.k_multiplier.mul_add(self.atr.value, self.middle);
+        self.lower = self.k_multiplier.mul_add(-self.atr.value, self.middle);
+        self.has_inputs = true;
+
+        if !self.initialized && self.ma.initialized() && self.atr.initialized() {
+            self.initialized = true;
+        }
+    }
+}
+
+impl Indicator for KeltnerChannel {
+    fn name(&self) -> String {
+        stringify!(KeltnerChannel).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw(bar.high.as_f64(), bar.low.as_f64(), bar.close.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.upper = 0.0;
+        self.middle = 0.0;
+        self.lower = 0.0;
+        self.ma.reset();
+        self.atr.reset();
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_kc_initialized() {
+        let kc = KeltnerChannel::new(20, 2.0, None, None);
+
+        assert_eq!(kc.to_string(), "KeltnerChannel(20,2,EXPONENTIAL)");
+        assert!(!kc.has_inputs());
+        assert!(!kc.initialized());
+    }
+
+    #[rstest]
+    fn test_kc_update_raw() {
+        let mut kc = KeltnerChannel::new(
+            2,
+            2.0,
+            Some(MovingAverageType::Simple),
+            Some(MovingAverageType::Simple),
+        );
+
+        kc.update_raw(11.0, 9.0, 10.0);
+        kc.update_raw(12.0, 10.0, 11.0);
+
+        // Middle SMA(10.0, 11.0) = 10.5, ATR SMA(2.0, 2.0) = 2.0
+        assert!(kc.initialized());
+        assert!((kc.middle - 10.5).abs() < 1e-12);
+        assert!((kc.upper - 14.5).abs() < 1e-12);
+        assert!((kc.lower - 6.5).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_kc_reset() {
+        let mut kc = KeltnerChannel::new(2, 2.0, None, None);
+        kc.update_raw(11.0, 9.0, 10.0);
+
+        kc.reset();
+
+        assert_eq!(kc.middle, 0.0);
+        assert_eq!(kc.upper, 0.0);
+        assert!(!kc.has_inputs());
+    }
+}
@@ -13,7 +13,12 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::VecDeque, fmt::Debug, time::Duration};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    future::Future,
+    time::Duration,
+};
 
 use ahash::AHashMap;
 use bytes::Bytes;
@@ -25,7 +30,7 @@ use ant_common::{
     custom::CustomData,
     enums::SerializationEncoding,
     logging::{log_task_awaiting, log_task_started, log_task_stopped},
-    runtime::get_runtime,
+    runtime::{block_on, get_runtime},
     signal::Signal,
 };
 use ant_core::{UUID4, UnixNanos, correctness::check_slice_not_empty};
@@ -38,13 +43,14 @@ use ant_model::{
         AccountId, ClientId, ClientOrderId, ComponentId, InstrumentId, PositionId, StrategyId,
         TraderId, VenueOrderId,
     },
-    instruments::{InstrumentEnum, SyntheticInstrument},
+    instruments::{Instrument, InstrumentEnum, SyntheticInstrument},
     orderbook::OrderBook,
-    orders::OrderAny,
+    orders::{Order, OrderAny},
     position::Position,
     types::Currency,
 };
 use redis::{Pipeline, aio::ConnectionManager};
+use serde::{Serialize, de::DeserializeOwned};
 use tokio::try_join;
 use ustr::Ustr;
 
@@ -807,6 +813,92 @@ pub struct RedisCacheDatabaseAdapter {
     pub database: RedisCacheDatabase,
 }
 
+impl RedisCacheDatabaseAdapter {
+    /// Serializes the `payload` with the adapter encoding.
+    fn serialize<T: Serialize>(&self, payload: &T) -> anyhow::Result<Bytes> {
+        DatabaseQueries::serialize_payload(self.encoding, payload).map(Bytes::from)
+    }
+
+    /// Sends a command for the `op_type` on the `key` to the buffered write task.
+    fn send(
+        &self,
+        op_type: DatabaseOperation,
+        key: String,
+        payload: Option<Vec<Bytes>>,
+    ) -> anyhow::Result<()> {
+        let op = DatabaseCommand::new(op_type, key, payload);
+        self.database
+            .tx
+            .send(op)
+            .map_err(|e| anyhow::anyhow!("{FAILED_TX_CHANNEL}: {e}"))
+    }
+
+    /// Reads the values of the `key` from Redis, blocking until the read completes.
+    fn read_blocking(&self, key: &str) -> anyhow::Result<Vec<Bytes>> {
+        let con = self.database.con.clone();
+        let trader_key = self.database.trader_key.clone();
+        block_on(async move { DatabaseQueries::read(&con, &trader_key, key).await })
+    }
+
+    /// Reads the hash index with the `index_key` as a map of field to value.
+    fn read_index_hash(&self, index_key: &str) -> anyhow::Result<HashMap<String, String>> {
+        let result = self.read_blocking(index_key)?;
+        match result.first() {
+            Some(value) => Ok(serde_json::from_slice(value)?),
+            None => Ok(HashMap::new()),
+        }
+    }
+
+    /// Loads the state map stored under the `key` (empty if no state is stored).
+    fn load_state(&self, key: &str) -> anyhow::Result<AHashMap<String, Bytes>> {
+        let result = self.read_blocking(key)?;
+        let Some(payload) = result.first() else {
+            return Ok(AHashMap::new());
+        };
+
+        let state: HashMap<String, Bytes> =
+            DatabaseQueries::deserialize_payload(self.encoding, payload)?;
+        Ok(state.into_iter().collect())
+    }
+
+    /// Loads the latest snapshot stored under the `key` (if any).
+    fn load_latest_snapshot<T: DeserializeOwned>(&self, key: &str) -> anyhow::Result<Option<T>> {
+        let result = self.read_blocking(key)?;
+        result
+            .last()
+            .map(|payload| DatabaseQueries::deserialize_payload(self.encoding, payload))
+            .transpose()
+    }
+
+    /// Indexes the `position` as open or closed, removing it from the other index.
+    fn index_position_status(&self, position: &Position) -> anyhow::Result<()> {
+        let payload = vec![Bytes::from(position.id.to_string())];
+        let (add_index, remove_index) = if position.is_open() {
+            (INDEX_POSITIONS_OPEN, INDEX_POSITIONS_CLOSED)
+        } else {
+            (INDEX_POSITIONS_CLOSED, INDEX_POSITIONS_OPEN)
+        };
+        self.send(
+            DatabaseOperation::Insert,
+            add_index.to_string(),
+            Some(payload.clone()),
+        )?;
+        self.send(
+            DatabaseOperation::Delete,
+            remove_index.to_string(),
+            Some(payload),
+        )
+    }
+}
+
+fn get_order_snapshot_key(client_order_id: &ClientOrderId) -> String {
+    format!("{SNAPSHOTS}{REDIS_DELIMITER}{ORDERS}{REDIS_DELIMITER}{client_order_id}")
+}
+
+fn get_position_snapshot_key(position_id: &PositionId) -> String {
+    format!("{SNAPSHOTS}{REDIS_DELIMITER}{POSITIONS}{REDIS_DELIMITER}{position_id}")
+}
+
 #[allow(dead_code)] // Under development
 #[allow(unused)] // Under development
 #[async_trait::async_trait]
@@ -858,8 +950,22 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     }
 
     fn load(&self) -> anyhow::Result<AHashMap<String, Bytes>> {
-        // self.database.load()
-        Ok(AHashMap::new()) // TODO
+        let mut con = self.database.con.clone();
+        let trader_key = self.database.trader_key.clone();
+        let prefix = format!("{trader_key}{REDIS_DELIMITER}{GENERAL}{REDIS_DELIMITER}");
+
+        block_on(async move {
+            let keys = DatabaseQueries::scan_keys(&mut con, format!("{prefix}*")).await?;
+            let values = DatabaseQueries::read_bulk(&con, &keys).await?;
+
+            let mut general = AHashMap::new();
+            for (key, value) in keys.iter().zip(values) {
+                if let (Some(name), Some(value)) = (key.strip_prefix(&prefix), value) {
+                    general.insert(name.to_string(), value);
+                }
+            }
+            Ok::<_, anyhow::Error>(general)
+        })
     }
 
     async fn load_currencies(&self) -> anyhow::Result<AHashMap<Ustr, Currency>> {
@@ -909,11 +1015,39 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     }
 
     fn load_index_order_position(&self) -> anyhow::Result<AHashMap<ClientOrderId, Position>> {
-        todo!()
+        let index = self.read_index_hash(INDEX_ORDER_POSITION)?;
+        let con = self.database.con.clone();
+        let trader_key = self.database.trader_key.clone();
+        let encoding = self.encoding;
+
+        block_on(async move {
+            let mut positions = AHashMap::with_capacity(index.len());
+            for (client_order_id, position_id) in index {
+                let position_id = PositionId::from(position_id.as_str());
+                match DatabaseQueries::load_position(&con, &trader_key, &position_id, encoding)
+                    .await?
+                {
+                    Some(position) => {
+                        positions.insert(ClientOrderId::from(client_order_id.as_str()), position);
+                    }
+                    None => log::error!("Indexed position {position_id} not found"),
+                }
+            }
+            Ok::<_, anyhow::Error>(positions)
+        })
     }
 
     fn load_index_order_client(&self) -> anyhow::Result<AHashMap<ClientOrderId, ClientId>> {
-        todo!()
+        let index = self.read_index_hash(INDEX_ORDER_CLIENT)?;
+        Ok(index
+            .into_iter()
+            .map(|(client_order_id, client_id)| {
+                (
+                    ClientOrderId::from(client_order_id.as_str()),
+                    ClientId::from(client_id.as_str()),
+                )
+            })
+            .collect())
     }
 
     async fn load_currency(&self, code: &Ustr) -> anyhow::Result<Option<Currency>> {
@@ -986,19 +1120,21 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     }
 
     fn load_actor(&self, component_id: &ComponentId) -> anyhow::Result<AHashMap<String, Bytes>> {
-        todo!()
+        self.load_state(&format!("{ACTORS}{REDIS_DELIMITER}{component_id}"))
     }
 
     fn delete_actor(&self, component_id: &ComponentId) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{ACTORS}{REDIS_DELIMITER}{component_id}");
+        self.send(DatabaseOperation::Delete, key, None)
     }
 
     fn load_strategy(&self, strategy_id: &StrategyId) -> anyhow::Result<AHashMap<String, Bytes>> {
-        todo!()
+        self.load_state(&format!("{STRATEGIES}{REDIS_DELIMITER}{strategy_id}"))
     }
 
     fn delete_strategy(&self, component_id: &StrategyId) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{STRATEGIES}{REDIS_DELIMITER}{component_id}");
+        self.send(DatabaseOperation::Delete, key, None)
     }
 
     fn delete_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
@@ -1084,43 +1220,86 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
     }
 
     fn delete_account_event(&self, account_id: &AccountId, event_id: &str) -> anyhow::Result<()> {
-        todo!()
+        self.database.delete_account_event(account_id, event_id)
     }
 
     fn add(&self, key: String, value: Bytes) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{GENERAL}{REDIS_DELIMITER}{key}");
+        self.send(DatabaseOperation::Insert, key, Some(vec![value]))
     }
 
     fn add_currency(&self, currency: &Currency) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{CURRENCIES}{REDIS_DELIMITER}{}", currency.code);
+        let payload = self.serialize(currency)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn add_instrument(&self, instrument: &InstrumentEnum) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{INSTRUMENTS}{REDIS_DELIMITER}{}", instrument.id());
+        let payload = self.serialize(instrument)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn add_synthetic(&self, synthetic: &SyntheticInstrument) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{SYNTHETICS}{REDIS_DELIMITER}{}", synthetic.id);
+        let payload = self.serialize(synthetic)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn add_account(&self, account: &AccountAny) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{ACCOUNTS}{REDIS_DELIMITER}{}", account.id());
+        let payload = self.serialize(account)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn add_order(&self, order: &OrderAny, client_id: Option<ClientId>) -> anyhow::Result<()> {
-        todo!()
+        let client_order_id = order.client_order_id();
+        let key = format!("{ORDERS}{REDIS_DELIMITER}{client_order_id}");
+        let payload = self.serialize(order)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))?;
+
+        let order_id_bytes = Bytes::from(client_order_id.to_string());
+        self.send(
+            DatabaseOperation::Insert,
+            INDEX_ORDERS.to_string(),
+            Some(vec![order_id_bytes.clone()]),
+        )?;
+
+        if let Some(client_id) = client_id {
+            let payload = vec![order_id_bytes, Bytes::from(client_id.to_string())];
+            self.send(
+                DatabaseOperation::Insert,
+                INDEX_ORDER_CLIENT.to_string(),
+                Some(payload),
+            )?;
+        }
+
+        Ok(())
     }
 
     fn add_order_snapshot(&self, snapshot: &OrderSnapshot) -> anyhow::Result<()> {
-        todo!()
+        let key = get_order_snapshot_key(&snapshot.client_order_id);
+        let payload = self.serialize(snapshot)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn add_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{POSITIONS}{REDIS_DELIMITER}{}", position.id);
+        let payload = self.serialize(position)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))?;
+
+        self.send(
+            DatabaseOperation::Insert,
+            INDEX_POSITIONS.to_string(),
+            Some(vec![Bytes::from(position.id.to_string())]),
+        )?;
+        self.index_position_status(position)
     }
 
     fn add_position_snapshot(&self, snapshot: &PositionSnapshot) -> anyhow::Result<()> {
-        todo!()
+        let key = get_position_snapshot_key(&snapshot.position_id);
+        let payload = self.serialize(snapshot)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn add_order_book(&self, order_book: &OrderBook) -> anyhow::Result<()> {
@@ -1171,14 +1350,14 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         &self,
         client_order_id: &ClientOrderId,
     ) -> anyhow::Result<Option<OrderSnapshot>> {
-        anyhow::bail!("Loading order snapshots from Redis cache adapter not supported")
+        self.load_latest_snapshot(&get_order_snapshot_key(client_order_id))
     }
 
     fn load_position_snapshot(
         &self,
         position_id: &PositionId,
     ) -> anyhow::Result<Option<PositionSnapshot>> {
-        anyhow::bail!("Loading position snapshots from Redis cache adapter not supported")
+        self.load_latest_snapshot(&get_position_snapshot_key(position_id))
     }
 
     fn index_venue_order_id(
@@ -1186,7 +1365,8 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
     ) -> anyhow::Result<()> {
-        todo!()
+        // Venue order IDs are restored from the persisted order events on load
+        Ok(())
     }
 
     fn index_order_position(
@@ -1194,39 +1374,73 @@ impl CacheDatabaseAdapter for RedisCacheDatabaseAdapter {
         client_order_id: ClientOrderId,
         position_id: PositionId,
     ) -> anyhow::Result<()> {
-        todo!()
+        let payload = vec![
+            Bytes::from(client_order_id.to_string()),
+            Bytes::from(position_id.to_string()),
+        ];
+        self.send(
+            DatabaseOperation::Insert,
+            INDEX_ORDER_POSITION.to_string(),
+            Some(payload),
+        )
     }
 
-    fn update_actor(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_actor(
+        &self,
+        component_id: &ComponentId,
+        state: &AHashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let key = format!("{ACTORS}{REDIS_DELIMITER}{component_id}");
+        let state: HashMap<&String, &Bytes> = state.iter().collect();
+        let payload = self.serialize(&state)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
-    fn update_strategy(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_strategy(
+        &self,
+        strategy_id: &StrategyId,
+        state: &AHashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let key = format!("{STRATEGIES}{REDIS_DELIMITER}{strategy_id}");
+        let state: HashMap<&String, &Bytes> = state.iter().collect();
+        let payload = self.serialize(&state)?;
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 
     fn update_account(&self, account: &AccountAny) -> anyhow::Result<()> {
-        todo!()
+        let Some(state) = account.last_event() else {
+            anyhow::bail!("Cannot update account {}: no account state", account.id());
+        };
+        let key = format!("{ACCOUNTS}{REDIS_DELIMITER}{}", account.id());
+        let payload = self.serialize(&state)?;
+        self.send(DatabaseOperation::Update, key, Some(vec![payload]))
     }
 
     fn update_order(&self, order_event: &OrderEventAny) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{ORDERS}{REDIS_DELIMITER}{}", order_event.client_order_id());
+        let payload = self.serialize(order_event)?;
+        self.send(DatabaseOperation::Update, key, Some(vec![payload]))
     }
 
     fn update_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{POSITIONS}{REDIS_DELIMITER}{}", position.id);
+        let payload = self.serialize(position)?;
+        self.send(DatabaseOperation::Update, key, Some(vec![payload]))?;
+        self.index_position_status(position)
     }
 
     fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()> {
-        todo!()
+        self.add_order_snapshot(&OrderSnapshot::from(order.clone()))
     }
 
     fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        self.add_position_snapshot(&PositionSnapshot::from(position, None))
     }
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()> {
-        todo!()
+        let key = format!("{HEALTH}{REDIS_DELIMITER}heartbeat");
+        let payload = Bytes::from(timestamp.to_rfc3339());
+        self.send(DatabaseOperation::Insert, key, Some(vec![payload]))
     }
 }
 
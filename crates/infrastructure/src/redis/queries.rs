@@ -22,9 +22,10 @@ use futures::future::join_all;
 use ant_common::{cache::database::CacheMap, enums::SerializationEncoding};
 use ant_model::{
     accounts::AccountAny,
+    events::{AccountState, OrderEventAny},
     identifiers::{AccountId, ClientOrderId, InstrumentId, PositionId},
     instruments::{InstrumentEnum, SyntheticInstrument},
-    orders::OrderAny,
+    orders::{Order, OrderAny},
     position::Position,
     types::Currency,
 };
@@ -660,6 +661,9 @@ impl DatabaseQueries {
 
     /// Loads a single account for `trader_key` and `account_id` using the specified `encoding`.
     ///
+    /// The account list holds the account as first added, followed by each subsequent
+    /// [`AccountState`] which is applied in order.
+    ///
     /// # Errors
     ///
     /// Returns an error if the underlying read or deserialization fails.
@@ -671,19 +675,27 @@ impl DatabaseQueries {
     ) -> anyhow::Result<Option<AccountAny>> {
         let key = format!("{ACCOUNTS}{REDIS_DELIMITER}{account_id}");
         let result = Self::read(con, trader_key, &key).await?;
-        if result.is_empty() {
+        let Some((initial, states)) = result.split_first() else {
             return Ok(None);
-        }
+        };
 
-        let account: AccountAny = Self::deserialize_payload(encoding, &result[0])?;
+        let mut account: AccountAny = Self::deserialize_payload(encoding, initial)?;
+        for state in states {
+            let state: AccountState = Self::deserialize_payload(encoding, state)?;
+            account.apply(state);
+        }
         Ok(Some(account))
     }
 
     /// Loads a single order for `trader_key` and `client_order_id` using the specified `encoding`.
     ///
+    /// The order list holds the order as first added, followed by each subsequent
+    /// [`OrderEventAny`] which is applied in order.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the underlying read or deserialization fails.
+    /// Returns an error if the underlying read or deserialization fails, or an event cannot be
+    /// applied to the order.
     pub async fn load_order(
         con: &ConnectionManager,
         trader_key: &str,
@@ -692,16 +704,23 @@ impl DatabaseQueries {
     ) -> anyhow::Result<Option<OrderAny>> {
         let key = format!("{ORDERS}{REDIS_DELIMITER}{client_order_id}");
         let result = Self::read(con, trader_key, &key).await?;
-        if result.is_empty() {
+        let Some((initial, events)) = result.split_first() else {
             return Ok(None);
-        }
+        };
 
-        let order: OrderAny = Self::deserialize_payload(encoding, &result[0])?;
+        let mut order: OrderAny = Self::deserialize_payload(encoding, initial)?;
+        for event in events {
+            let event: OrderEventAny = Self::deserialize_payload(encoding, event)?;
+            order.apply(event)?;
+        }
         Ok(Some(order))
     }
 
     /// Loads a single position for `trader_key` and `position_id` using the specified `encoding`.
     ///
+    /// The position list holds the state of the position on each update, of which the latest is
+    /// loaded.
+    ///
     /// # Errors
     ///
     /// Returns an error if the underlying read or deserialization fails.
@@ -713,11 +732,11 @@ impl DatabaseQueries {
     ) -> anyhow::Result<Option<Position>> {
         let key = format!("{POSITIONS}{REDIS_DELIMITER}{position_id}");
         let result = Self::read(con, trader_key, &key).await?;
-        if result.is_empty() {
+        let Some(latest) = result.last() else {
             return Ok(None);
-        }
+        };
 
-        let position: Position = Self::deserialize_payload(encoding, &result[0])?;
+        let position: Position = Self::deserialize_payload(encoding, latest)?;
         Ok(Some(position))
     }
 
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::VecDeque, time::Duration};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::Duration,
+};
 
 use ahash::AHashMap;
 use bytes::Bytes;
@@ -51,6 +54,10 @@ use crate::sql::{
 // Task and connection names
 const CACHE_PROCESS: &str = "cache-process";
 
+// Component state kinds
+const ACTOR: &str = "ACTOR";
+const STRATEGY: &str = "STRATEGY";
+
 #[derive(Debug)]
 #[cfg_attr(
     feature = "python",
@@ -69,8 +76,10 @@ pub enum DatabaseQuery {
     Add(String, Vec<u8>),
     AddCurrency(Currency),
     AddInstrument(InstrumentEnum),
+    AddSynthetic(SyntheticInstrument),
     AddOrder(OrderAny, Option<ClientId>, bool),
     AddOrderSnapshot(OrderSnapshot),
+    AddPosition(Position),
     AddPositionSnapshot(PositionSnapshot),
     AddAccount(AccountAny, bool),
     AddSignal(Signal),
@@ -79,6 +88,9 @@ pub enum DatabaseQuery {
     AddTrade(TradeTick),
     AddBar(Bar),
     UpdateOrder(OrderEventAny),
+    UpdateComponentState(&'static str, String, HashMap<String, Vec<u8>>),
+    DeleteComponentState(&'static str, String),
+    Heartbeat(UnixNanos),
 }
 
 impl PostgresCacheDatabase {
@@ -165,6 +177,60 @@ impl PostgresCacheDatabase {
 
         log_task_stopped(CACHE_PROCESS);
     }
+
+    fn load_component_state(
+        &self,
+        kind: &'static str,
+        component_id: String,
+    ) -> anyhow::Result<AHashMap<String, Bytes>> {
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_component_state(&pool, kind, &component_id).await;
+            match result {
+                Ok(state) => {
+                    let mapping = state
+                        .into_iter()
+                        .map(|(k, v)| (k, Bytes::from(v)))
+                        .collect();
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send state for {component_id}: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load state for {component_id}: {e:?}");
+                    if let Err(e) = tx.send(AHashMap::new()) {
+                        log::error!("Failed to send empty state for {component_id}: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
+    }
+
+    fn update_component_state(
+        &self,
+        kind: &'static str,
+        component_id: String,
+        state: &AHashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        let state = state.iter().map(|(k, v)| (k.clone(), v.to_vec())).collect();
+        let query = DatabaseQuery::UpdateComponentState(kind, component_id, state);
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query update_state to database message handler: {e}")
+        })
+    }
+
+    fn delete_component_state(
+        &self,
+        kind: &'static str,
+        component_id: String,
+    ) -> anyhow::Result<()> {
+        let query = DatabaseQuery::DeleteComponentState(kind, component_id);
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query delete_state to database message handler: {e}")
+        })
+    }
 }
 
 /// Retrieves a `PostgresCacheDatabase` using default connection options.
@@ -346,7 +412,29 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     async fn load_synthetics(&self) -> anyhow::Result<AHashMap<InstrumentId, SyntheticInstrument>> {
-        todo!()
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_synthetics(&pool).await;
+            match result {
+                Ok(synthetics) => {
+                    let mapping = synthetics
+                        .into_iter()
+                        .map(|synthetic| (synthetic.id, synthetic))
+                        .collect();
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send synthetics: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load synthetics: {e:?}");
+                    if let Err(e) = tx.send(AHashMap::new()) {
+                        log::error!("Failed to send empty synthetics: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     async fn load_accounts(&self) -> anyhow::Result<AHashMap<AccountId, AccountAny>> {
@@ -402,11 +490,58 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     async fn load_positions(&self) -> anyhow::Result<AHashMap<PositionId, Position>> {
-        todo!()
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_positions(&pool).await;
+            match result {
+                Ok(positions) => {
+                    let mapping = positions
+                        .into_iter()
+                        .map(|position| (position.id, position))
+                        .collect();
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send positions: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load positions: {e:?}");
+                    if let Err(e) = tx.send(AHashMap::new()) {
+                        log::error!("Failed to send empty positions: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn load_index_order_position(&self) -> anyhow::Result<AHashMap<ClientOrderId, Position>> {
-        todo!()
+        let pool = self.pool.clone();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_positions(&pool).await;
+            match result {
+                Ok(positions) => {
+                    // Every order which filled into a position is indexed to that position
+                    let mut mapping = AHashMap::new();
+                    for position in positions {
+                        for fill in &position.events {
+                            mapping.insert(fill.client_order_id, position.clone());
+                        }
+                    }
+                    if let Err(e) = tx.send(mapping) {
+                        log::error!("Failed to send load_index_order_position result: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load positions for order position index: {e:?}");
+                    if let Err(e) = tx.send(AHashMap::new()) {
+                        log::error!("Failed to send empty load_index_order_position result: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn load_index_order_client(&self) -> anyhow::Result<AHashMap<ClientOrderId, ClientId>> {
@@ -484,7 +619,26 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         &self,
         instrument_id: &InstrumentId,
     ) -> anyhow::Result<Option<SyntheticInstrument>> {
-        todo!()
+        let pool = self.pool.clone();
+        let instrument_id = instrument_id.to_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_synthetic(&pool, &instrument_id).await;
+            match result {
+                Ok(synthetic) => {
+                    if let Err(e) = tx.send(synthetic) {
+                        log::error!("Failed to send synthetic {instrument_id}: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load synthetic {instrument_id}: {e:?}");
+                    if let Err(e) = tx.send(None) {
+                        log::error!("Failed to send None for synthetic {instrument_id}: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     async fn load_account(&self, account_id: &AccountId) -> anyhow::Result<Option<AccountAny>> {
@@ -535,23 +689,42 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     async fn load_position(&self, position_id: &PositionId) -> anyhow::Result<Option<Position>> {
-        todo!()
+        let pool = self.pool.clone();
+        let position_id = position_id.to_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+        tokio::spawn(async move {
+            let result = DatabaseQueries::load_position(&pool, &position_id).await;
+            match result {
+                Ok(position) => {
+                    if let Err(e) = tx.send(position) {
+                        log::error!("Failed to send position {position_id}: {e:?}");
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to load position {position_id}: {e:?}");
+                    if let Err(e) = tx.send(None) {
+                        log::error!("Failed to send None for position {position_id}: {e:?}");
+                    }
+                }
+            }
+        });
+        Ok(rx.recv()?)
     }
 
     fn load_actor(&self, component_id: &ComponentId) -> anyhow::Result<AHashMap<String, Bytes>> {
-        todo!()
+        self.load_component_state(ACTOR, component_id.to_string())
     }
 
     fn delete_actor(&self, component_id: &ComponentId) -> anyhow::Result<()> {
-        todo!()
+        self.delete_component_state(ACTOR, component_id.to_string())
     }
 
     fn load_strategy(&self, strategy_id: &StrategyId) -> anyhow::Result<AHashMap<String, Bytes>> {
-        todo!()
+        self.load_component_state(STRATEGY, strategy_id.to_string())
     }
 
     fn delete_strategy(&self, component_id: &StrategyId) -> anyhow::Result<()> {
-        todo!()
+        self.delete_component_state(STRATEGY, component_id.to_string())
     }
 
     fn delete_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<()> {
@@ -592,7 +765,10 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_synthetic(&self, synthetic: &SyntheticInstrument) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddSynthetic(synthetic.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query add_synthetic to database message handler: {e}")
+        })
     }
 
     fn add_account(&self, account: &AccountAny) -> anyhow::Result<()> {
@@ -619,7 +795,10 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddPosition(position.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query add_position to database message handler: {e}")
+        })
     }
 
     fn add_position_snapshot(&self, snapshot: &PositionSnapshot) -> anyhow::Result<()> {
@@ -632,7 +811,7 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn add_order_book(&self, order_book: &OrderBook) -> anyhow::Result<()> {
-        todo!()
+        anyhow::bail!("Saving order books for PostgreSQL cache adapter not supported")
     }
 
     fn add_quote(&self, quote: &QuoteTick) -> anyhow::Result<()> {
@@ -852,7 +1031,8 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         client_order_id: ClientOrderId,
         venue_order_id: VenueOrderId,
     ) -> anyhow::Result<()> {
-        todo!()
+        // Venue order IDs are restored from the persisted order events on load
+        Ok(())
     }
 
     fn index_order_position(
@@ -860,15 +1040,24 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
         client_order_id: ClientOrderId,
         position_id: PositionId,
     ) -> anyhow::Result<()> {
-        todo!()
+        // Order position index is rebuilt from the persisted position fills on load
+        Ok(())
     }
 
-    fn update_actor(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_actor(
+        &self,
+        component_id: &ComponentId,
+        state: &AHashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        self.update_component_state(ACTOR, component_id.to_string(), state)
     }
 
-    fn update_strategy(&self) -> anyhow::Result<()> {
-        todo!()
+    fn update_strategy(
+        &self,
+        strategy_id: &StrategyId,
+        state: &AHashMap<String, Bytes>,
+    ) -> anyhow::Result<()> {
+        self.update_component_state(STRATEGY, strategy_id.to_string(), state)
     }
 
     fn update_account(&self, account: &AccountAny) -> anyhow::Result<()> {
@@ -886,19 +1075,25 @@ impl CacheDatabaseAdapter for PostgresCacheDatabase {
     }
 
     fn update_position(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::AddPosition(position.clone());
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query update_position to database message handler: {e}")
+        })
     }
 
     fn snapshot_order_state(&self, order: &OrderAny) -> anyhow::Result<()> {
-        todo!()
+        self.add_order_snapshot(&OrderSnapshot::from(order.clone()))
     }
 
     fn snapshot_position_state(&self, position: &Position) -> anyhow::Result<()> {
-        todo!()
+        self.add_position_snapshot(&PositionSnapshot::from(position, None))
     }
 
     fn heartbeat(&self, timestamp: UnixNanos) -> anyhow::Result<()> {
-        todo!()
+        let query = DatabaseQuery::Heartbeat(timestamp);
+        self.tx.send(query).map_err(|e| {
+            anyhow::anyhow!("Failed to send query heartbeat to database message handler: {e}")
+        })
     }
 }
 
@@ -912,46 +1107,94 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
             }
             DatabaseQuery::AddInstrument(instrument_any) => match instrument_any {
                 InstrumentEnum::Betting(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "BETTING", Box::new(instrument)).await
+                    DatabaseQueries::add_instrument(pool, "BETTING", Box::new(instrument), None)
+                        .await
                 }
                 InstrumentEnum::BinaryOption(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "BINARY_OPTION", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "BINARY_OPTION",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::CryptoFuture(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CRYPTO_FUTURE", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CRYPTO_FUTURE",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::CryptoOption(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CRYPTO_OPTION", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CRYPTO_OPTION",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::CryptoPerpetual(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CRYPTO_PERPETUAL", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CRYPTO_PERPETUAL",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::CurrencyPair(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "CURRENCY_PAIR", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "CURRENCY_PAIR",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::Equity(equity) => {
-                    DatabaseQueries::add_instrument(pool, "EQUITY", Box::new(equity)).await
+                    DatabaseQueries::add_instrument(pool, "EQUITY", Box::new(equity), None).await
                 }
                 InstrumentEnum::FuturesContract(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "FUTURES_CONTRACT", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "FUTURES_CONTRACT",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::FuturesSpread(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "FUTURES_SPREAD", Box::new(instrument))
-                        .await
+                    let strategy_type = instrument.strategy_type;
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "FUTURES_SPREAD",
+                        Box::new(instrument),
+                        Some(strategy_type.as_str()),
+                    )
+                    .await
                 }
                 InstrumentEnum::OptionContract(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "OPTION_CONTRACT", Box::new(instrument))
-                        .await
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "OPTION_CONTRACT",
+                        Box::new(instrument),
+                        None,
+                    )
+                    .await
                 }
                 InstrumentEnum::OptionSpread(instrument) => {
-                    DatabaseQueries::add_instrument(pool, "OPTION_SPREAD", Box::new(instrument))
-                        .await
+                    let strategy_type = instrument.strategy_type;
+                    DatabaseQueries::add_instrument(
+                        pool,
+                        "OPTION_SPREAD",
+                        Box::new(instrument),
+                        Some(strategy_type.as_str()),
+                    )
+                    .await
                 }
             },
             DatabaseQuery::AddOrder(order_any, client_id, updated) => match order_any {
@@ -1034,9 +1277,15 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
                     .await
                 }
             },
+            DatabaseQuery::AddSynthetic(synthetic) => {
+                DatabaseQueries::add_synthetic(pool, &synthetic).await
+            }
             DatabaseQuery::AddOrderSnapshot(snapshot) => {
                 DatabaseQueries::add_order_snapshot(pool, snapshot).await
             }
+            DatabaseQuery::AddPosition(position) => {
+                DatabaseQueries::add_position(pool, &position).await
+            }
             DatabaseQuery::AddPositionSnapshot(snapshot) => {
                 DatabaseQueries::add_position_snapshot(pool, snapshot).await
             }
@@ -1056,6 +1305,15 @@ async fn drain_buffer(pool: &PgPool, buffer: &mut VecDeque<DatabaseQuery>) {
             DatabaseQuery::UpdateOrder(event) => {
                 DatabaseQueries::add_order_event(pool, event.into_boxed(), None).await
             }
+            DatabaseQuery::UpdateComponentState(kind, component_id, state) => {
+                DatabaseQueries::update_component_state(pool, kind, &component_id, state).await
+            }
+            DatabaseQuery::DeleteComponentState(kind, component_id) => {
+                DatabaseQueries::delete_component_state(pool, kind, &component_id).await
+            }
+            DatabaseQuery::Heartbeat(timestamp) => {
+                DatabaseQueries::heartbeat(pool, timestamp).await
+            }
         };
 
         if let Err(e) = result {
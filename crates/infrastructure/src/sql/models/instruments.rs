@@ -26,6 +26,7 @@ use ant_model::{
     instruments::{
         BettingInstrument, BinaryOption, CryptoFuture, CryptoOption, CryptoPerpetual, CurrencyPair,
         Equity, FuturesContract, FuturesSpread, InstrumentEnum, OptionContract, OptionSpread,
+        SyntheticInstrument,
     },
     types::{Currency, Money, Price, Quantity},
 };
@@ -71,6 +72,9 @@ pub struct OptionContractModel(pub OptionContract);
 #[derive(Debug)]
 pub struct OptionSpreadModel(pub OptionSpread);
 
+#[derive(Debug)]
+pub struct SyntheticInstrumentModel(pub SyntheticInstrument);
+
 impl<'r> FromRow<'r, PgRow> for InstrumentAnyModel {
     fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
         let kind = row.get::<String, _>("kind");
@@ -923,8 +927,97 @@ impl<'r> FromRow<'r, PgRow> for FuturesContractModel {
 }
 
 impl<'r> FromRow<'r, PgRow> for FuturesSpreadModel {
-    fn from_row(_row: &'r PgRow) -> Result<Self, sqlx::Error> {
-        todo!("Implement FromRow for FuturesSpread")
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::new)?;
+        let asset_class = row
+            .try_get::<AssetClassModel, _>("asset_class")
+            .map(|res| res.0)?;
+        let exchange = row
+            .try_get::<Option<String>, _>("exchange")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let underlying = row
+            .try_get::<String, _>("underlying")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let strategy_type = row
+            .try_get::<String, _>("strategy_type")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let activation_ns = row
+            .try_get::<String, _>("activation_ns")
+            .map(UnixNanos::from)?;
+        let expiration_ns = row
+            .try_get::<String, _>("expiration_ns")
+            .map(UnixNanos::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let multiplier = row
+            .try_get::<String, _>("multiplier")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<String, _>("lot_size")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = FuturesSpread::new(
+            id,
+            raw_symbol,
+            asset_class,
+            exchange,
+            underlying,
+            strategy_type,
+            activation_ns,
+            expiration_ns,
+            currency,
+            price_precision as u8,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(Self(inst))
     }
 }
 
@@ -1029,7 +1122,124 @@ impl<'r> FromRow<'r, PgRow> for OptionContractModel {
 }
 
 impl<'r> FromRow<'r, PgRow> for OptionSpreadModel {
-    fn from_row(_row: &'r PgRow) -> Result<Self, sqlx::Error> {
-        todo!("Implement FromRow for OptionSpread")
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<String, _>("id").map(InstrumentId::from)?;
+        let raw_symbol = row.try_get::<String, _>("raw_symbol").map(Symbol::new)?;
+        let asset_class = row
+            .try_get::<AssetClassModel, _>("asset_class")
+            .map(|res| res.0)?;
+        let exchange = row
+            .try_get::<Option<String>, _>("exchange")
+            .map(|res| res.map(|s| Ustr::from(s.as_str())))?;
+        let underlying = row
+            .try_get::<String, _>("underlying")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let strategy_type = row
+            .try_get::<String, _>("strategy_type")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(Currency::from)?;
+        let activation_ns = row
+            .try_get::<String, _>("activation_ns")
+            .map(UnixNanos::from)?;
+        let expiration_ns = row
+            .try_get::<String, _>("expiration_ns")
+            .map(UnixNanos::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let multiplier = row
+            .try_get::<String, _>("multiplier")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<String, _>("lot_size")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|s| Quantity::from(s.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|s| Price::from(s.as_str())));
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Some(Decimal::from_str(res.as_str()).unwrap()))?;
+        let ts_event = row.try_get::<String, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<String, _>("ts_init").map(UnixNanos::from)?;
+
+        let inst = OptionSpread::new(
+            id,
+            raw_symbol,
+            asset_class,
+            exchange,
+            underlying,
+            strategy_type,
+            activation_ns,
+            expiration_ns,
+            currency,
+            price_precision as u8,
+            price_increment,
+            multiplier,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_price,
+            min_price,
+            margin_init,
+            margin_maint,
+            maker_fee,
+            taker_fee,
+            ts_event,
+            ts_init,
+        );
+        Ok(Self(inst))
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for SyntheticInstrumentModel {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row.try_get::<&str, _>("id").map(InstrumentId::from)?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let components = row
+            .try_get::<Vec<String>, _>("components")?
+            .into_iter()
+            .map(|component| InstrumentId::from(component.as_str()))
+            .collect();
+        let formula = row.try_get::<String, _>("formula")?;
+        let ts_event = row.try_get::<&str, _>("ts_event").map(UnixNanos::from)?;
+        let ts_init = row.try_get::<&str, _>("ts_init").map(UnixNanos::from)?;
+
+        let synthetic = SyntheticInstrument::new_checked(
+            id.symbol,
+            price_precision as u8,
+            components,
+            formula,
+            ts_event,
+            ts_init,
+        )
+        .map_err(|e| {
+            sqlx::Error::Decode(format!("Invalid synthetic instrument {id}: {e}").into())
+        })?;
+        Ok(Self(synthetic))
     }
 }
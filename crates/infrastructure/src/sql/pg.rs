@@ -169,6 +169,16 @@ fn get_schema_dir() -> anyhow::Result<String> {
     })
 }
 
+/// Returns the execution rank of a schema file, types must exist before the tables using them.
+fn schema_file_rank(file_name: &str) -> usize {
+    const SCHEMA_FILE_ORDER: [&str; 4] =
+        ["types.sql", "tables.sql", "functions.sql", "partitions.sql"];
+    SCHEMA_FILE_ORDER
+        .iter()
+        .position(|name| *name == file_name)
+        .unwrap_or(SCHEMA_FILE_ORDER.len())
+}
+
 /// Initializes the Postgres database by creating schema, roles, and executing SQL files from `schema_dir`.
 ///
 /// # Errors
@@ -214,6 +224,10 @@ pub async fn init_postgres(
     let schema_dir = schema_dir.unwrap_or_else(|| get_schema_dir().unwrap());
     let mut sql_files =
         std::fs::read_dir(schema_dir)?.collect::<Result<Vec<_>, std::io::Error>>()?;
+    sql_files.sort_by_key(|file| {
+        let file_name = file.file_name().to_string_lossy().into_owned();
+        (schema_file_rank(&file_name), file_name)
+    });
     let plpgsql_regex = Regex::new(r"\$\$ LANGUAGE plpgsql(?:\s+SECURITY\s+DEFINER)?;")?;
     for file in &mut sql_files {
         let file_name = file.file_name();
@@ -17,16 +17,18 @@ use std::collections::HashMap;
 
 use ahash::AHashMap;
 use ant_common::{custom::CustomData, signal::Signal};
+use ant_core::UnixNanos;
 use ant_model::{
     accounts::{Account, AccountAny},
     data::{Bar, DataType, QuoteTick, TradeTick},
     events::{
-        AccountState, OrderEvent, OrderEventAny, OrderSnapshot,
+        AccountState, OrderEvent, OrderEventAny, OrderFilled, OrderSnapshot,
         position::snapshot::PositionSnapshot,
     },
     identifiers::{AccountId, ClientId, ClientOrderId, InstrumentId, PositionId},
-    instruments::{Instrument, InstrumentEnum},
+    instruments::{Instrument, InstrumentEnum, SyntheticInstrument},
     orders::{Order, OrderAny},
+    position::Position,
     types::{AccountBalance, Currency, MarginBalance},
 };
 use sqlx::{PgPool, Row};
@@ -44,8 +46,8 @@ use crate::sql::models::{
         CurrencyTypeModel, PriceTypeModel, TrailingOffsetTypeModel,
     },
     general::{GeneralRow, OrderEventOrderClientIdCombination},
-    instruments::InstrumentAnyModel,
-    orders::OrderEventAnyModel,
+    instruments::{InstrumentAnyModel, SyntheticInstrumentModel},
+    orders::{OrderEventAnyModel, OrderFilledModel},
     types::CurrencyModel,
 };
 
@@ -156,21 +158,22 @@ impl DatabaseQueries {
         pool: &PgPool,
         kind: &str,
         instrument: Box<dyn Instrument>,
+        strategy_type: Option<&str>,
     ) -> anyhow::Result<()> {
         sqlx::query(r#"
             INSERT INTO "instrument" (
                 id, kind, raw_symbol, base_currency, underlying, quote_currency, settlement_currency, isin, asset_class, exchange,
                 multiplier, option_kind, is_inverse, strike_price, activation_ns, expiration_ns, price_precision, size_precision,
                 price_increment, size_increment, maker_fee, taker_fee, margin_init, margin_maint, lot_size, max_quantity, min_quantity, max_notional,
-                min_notional, max_price, min_price, ts_init, ts_event, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::asset_class, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                min_notional, max_price, min_price, ts_init, ts_event, strategy_type, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::asset_class, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30, $31, $32, $33, $34, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
             ON CONFLICT (id)
             DO UPDATE
             SET
                 kind = $2, raw_symbol = $3, base_currency= $4, underlying = $5, quote_currency = $6, settlement_currency = $7, isin = $8, asset_class = $9, exchange = $10,
                  multiplier = $11, option_kind = $12, is_inverse = $13, strike_price = $14, activation_ns = $15, expiration_ns = $16 , price_precision = $17, size_precision = $18,
                  price_increment = $19, size_increment = $20, maker_fee = $21, taker_fee = $22, margin_init = $23, margin_maint = $24, lot_size = $25, max_quantity = $26,
-                 min_quantity = $27, max_notional = $28, min_notional = $29, max_price = $30, min_price = $31, ts_init = $32,  ts_event = $33, updated_at = CURRENT_TIMESTAMP
+                 min_quantity = $27, max_notional = $28, min_notional = $29, max_price = $30, min_price = $31, ts_init = $32,  ts_event = $33, strategy_type = $34, updated_at = CURRENT_TIMESTAMP
             "#)
             .bind(instrument.id().to_string())
             .bind(kind)
@@ -205,6 +208,7 @@ impl DatabaseQueries {
             .bind(instrument.min_price().map(|x| x.to_string()))
             .bind(instrument.ts_init().to_string())
             .bind(instrument.ts_event().to_string())
+            .bind(strategy_type)
             .execute(pool)
             .await
             .map(|_| ())
@@ -243,6 +247,77 @@ impl DatabaseQueries {
             .map_err(|e| anyhow::anyhow!("Failed to load instruments: {e}"))
     }
 
+    /// Inserts or updates a `SyntheticInstrument` entry via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the INSERT or UPDATE operation fails.
+    pub async fn add_synthetic(
+        pool: &PgPool,
+        synthetic: &SyntheticInstrument,
+    ) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "synthetic" (
+                id, price_precision, components, formula, ts_event, ts_init, created_at, updated_at
+            ) VALUES (
+                $1, $2, $3, $4, $5, $6, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+            )
+            ON CONFLICT (id)
+            DO UPDATE
+            SET
+                price_precision = $2, components = $3, formula = $4, ts_event = $5, ts_init = $6,
+                updated_at = CURRENT_TIMESTAMP
+        "#,
+        )
+        .bind(synthetic.id.to_string())
+        .bind(i32::from(synthetic.price_precision))
+        .bind(
+            synthetic
+                .components
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+        )
+        .bind(synthetic.formula.as_str())
+        .bind(synthetic.ts_event.to_string())
+        .bind(synthetic.ts_init.to_string())
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into synthetic table: {e}"))
+    }
+
+    /// Loads a single `SyntheticInstrument` entry by `instrument_id` via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SELECT operation fails.
+    pub async fn load_synthetic(
+        pool: &PgPool,
+        instrument_id: &InstrumentId,
+    ) -> anyhow::Result<Option<SyntheticInstrument>> {
+        sqlx::query_as::<_, SyntheticInstrumentModel>(r#"SELECT * FROM "synthetic" WHERE id = $1"#)
+            .bind(instrument_id.to_string())
+            .fetch_optional(pool)
+            .await
+            .map(|synthetic| synthetic.map(|row| row.0))
+            .map_err(|e| anyhow::anyhow!("Failed to load synthetic with id {instrument_id}: {e}"))
+    }
+
+    /// Loads all `SyntheticInstrument` entries via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SELECT operation fails.
+    pub async fn load_synthetics(pool: &PgPool) -> anyhow::Result<Vec<SyntheticInstrument>> {
+        sqlx::query_as::<_, SyntheticInstrumentModel>(r#"SELECT * FROM "synthetic""#)
+            .fetch_all(pool)
+            .await
+            .map(|rows| rows.into_iter().map(|row| row.0).collect())
+            .map_err(|e| anyhow::anyhow!("Failed to load synthetics: {e}"))
+    }
+
     /// Inserts or updates an `OrderAny` entry via the provided `pool`.
     ///
     /// # Errors
@@ -541,7 +616,7 @@ impl DatabaseQueries {
             .bind(snapshot.realized_pnl.map(|x| x.to_string()))
             .bind(snapshot.unrealized_pnl.map(|x| x.to_string()))
             .bind(snapshot.commissions.iter().map(ToString::to_string).collect::<Vec<String>>())
-            .bind(snapshot.duration_ns.map(|x| x.to_string()))
+            .bind(snapshot.duration_ns.map(|x| x as i64))
             .bind(snapshot.ts_opened.to_string())
             .bind(snapshot.ts_closed.map(|x| x.to_string()))
             .bind(snapshot.ts_init.to_string())
@@ -573,6 +648,152 @@ impl DatabaseQueries {
             .map_err(|e| anyhow::anyhow!("Failed to load position snapshot: {e}"))
     }
 
+    /// Replaces the fill history of a `Position` via the provided `pool`.
+    ///
+    /// The fills are stored in application order so the position can be rebuilt on load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL DELETE or INSERT operations fail, or if beginning the transaction fails.
+    pub async fn add_position(pool: &PgPool, position: &Position) -> anyhow::Result<()> {
+        let mut transaction = pool.begin().await?;
+
+        // Insert trader if it does not exist
+        // TODO remove this when node and trader initialization is implemented
+        sqlx::query(
+            r#"
+            INSERT INTO "trader" (id) VALUES ($1) ON CONFLICT (id) DO NOTHING
+        "#,
+        )
+        .bind(position.trader_id.to_string())
+        .execute(&mut *transaction)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into trader table: {e}"))?;
+
+        sqlx::query(r#"DELETE FROM "position_event" WHERE position_id = $1"#)
+            .bind(position.id.to_string())
+            .execute(&mut *transaction)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Failed to clear position_event table: {e}"))?;
+
+        for (sequence, fill) in position.events.iter().enumerate() {
+            sqlx::query(r#"
+                INSERT INTO "position_event" (
+                    position_id, sequence, id, trader_id, strategy_id, instrument_id, client_order_id, venue_order_id, account_id,
+                    trade_id, order_side, order_type, last_qty, last_px, currency, liquidity_side, commission, ts_event, ts_init,
+                    created_at, updated_at
+                ) VALUES (
+                    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19,
+                    CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+                )
+            "#)
+                .bind(position.id.to_string())
+                .bind(sequence as i32)
+                .bind(fill.event_id.to_string())
+                .bind(fill.trader_id.to_string())
+                .bind(fill.strategy_id.to_string())
+                .bind(fill.instrument_id.to_string())
+                .bind(fill.client_order_id.to_string())
+                .bind(fill.venue_order_id.to_string())
+                .bind(fill.account_id.to_string())
+                .bind(fill.trade_id.to_string())
+                .bind(fill.order_side.to_string())
+                .bind(fill.order_type.to_string())
+                .bind(fill.last_qty.to_string())
+                .bind(fill.last_px.to_string())
+                .bind(fill.currency.code.as_str())
+                .bind(fill.liquidity_side.to_string())
+                .bind(fill.commission.map(|x| x.to_string()))
+                .bind(fill.ts_event.to_string())
+                .bind(fill.ts_init.to_string())
+                .execute(&mut *transaction)
+                .await
+                .map(|_| ())
+                .map_err(|e| anyhow::anyhow!("Failed to insert into position_event table: {e}"))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to commit transaction: {e}"))
+    }
+
+    /// Loads the fill history for `position_id` in application order via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL SELECT or deserialization fails.
+    pub async fn load_position_events(
+        pool: &PgPool,
+        position_id: &PositionId,
+    ) -> anyhow::Result<Vec<OrderFilled>> {
+        sqlx::query_as::<_, OrderFilledModel>(
+            r#"SELECT * FROM "position_event" WHERE position_id = $1 ORDER BY sequence ASC"#,
+        )
+        .bind(position_id.to_string())
+        .fetch_all(pool)
+        .await
+        .map(|rows| rows.into_iter().map(|row| row.0).collect())
+        .map_err(|e| anyhow::anyhow!("Failed to load position events: {e}"))
+    }
+
+    /// Loads and rebuilds a complete `Position` for `position_id` via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading the fills fails, or if the position instrument cannot be found.
+    pub async fn load_position(
+        pool: &PgPool,
+        position_id: &PositionId,
+    ) -> anyhow::Result<Option<Position>> {
+        let fills = Self::load_position_events(pool, position_id).await?;
+        let Some((first, rest)) = fills.split_first() else {
+            return Ok(None);
+        };
+        let Some(instrument) = Self::load_instrument(pool, &first.instrument_id).await? else {
+            anyhow::bail!(
+                "Failed to load position {position_id}: instrument {} not found",
+                first.instrument_id
+            )
+        };
+
+        let mut position = Position::new(&instrument, *first);
+        for fill in rest {
+            position.apply(fill);
+        }
+        Ok(Some(position))
+    }
+
+    /// Loads and rebuilds all `Position` entries via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if loading the position IDs or any individual position fails.
+    pub async fn load_positions(pool: &PgPool) -> anyhow::Result<Vec<Position>> {
+        let mut positions: Vec<Position> = Vec::new();
+        let position_ids: Vec<PositionId> = sqlx::query(
+            r#"
+            SELECT DISTINCT position_id FROM "position_event"
+        "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| PositionId::from(row.get::<&str, _>(0)))
+                .collect()
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to load position ids: {e}"))?;
+        for id in position_ids {
+            if let Some(position) = Self::load_position(pool, &id).await? {
+                positions.push(position);
+            }
+        }
+        Ok(positions)
+    }
+
     /// Checks if an `OrderInitialized` event exists for the given `client_order_id` via the provided `pool`.
     ///
     /// # Errors
@@ -1256,4 +1477,128 @@ impl DatabaseQueries {
         .map(|rows| rows.into_iter().map(|row| row.0).collect())
         .map_err(|e| anyhow::anyhow!("Failed to load custom data: {e}"))
     }
+
+    /// Replaces the persisted state of the component `kind` with `component_id` via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL DELETE or INSERT operations fail, or if beginning the transaction fails.
+    pub async fn update_component_state(
+        pool: &PgPool,
+        kind: &str,
+        component_id: &str,
+        state: HashMap<String, Vec<u8>>,
+    ) -> anyhow::Result<()> {
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query(r#"DELETE FROM "component_state" WHERE kind = $1 AND component_id = $2"#)
+            .bind(kind)
+            .bind(component_id)
+            .execute(&mut *transaction)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Failed to clear component_state table: {e}"))?;
+
+        for (key, value) in state {
+            sqlx::query(
+                r#"
+                INSERT INTO "component_state" (
+                    kind, component_id, key, value, created_at, updated_at
+                ) VALUES (
+                    $1, $2, $3, $4, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP
+                )
+            "#,
+            )
+            .bind(kind)
+            .bind(component_id)
+            .bind(key)
+            .bind(value)
+            .execute(&mut *transaction)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Failed to insert into component_state table: {e}"))?;
+        }
+
+        transaction
+            .commit()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to commit transaction: {e}"))
+    }
+
+    /// Loads the persisted state of the component `kind` with `component_id` via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL SELECT operation fails.
+    pub async fn load_component_state(
+        pool: &PgPool,
+        kind: &str,
+        component_id: &str,
+    ) -> anyhow::Result<HashMap<String, Vec<u8>>> {
+        sqlx::query(
+            r#"SELECT key, value FROM "component_state" WHERE kind = $1 AND component_id = $2"#,
+        )
+        .bind(kind)
+        .bind(component_id)
+        .fetch_all(pool)
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(|row| (row.get::<String, _>(0), row.get::<Vec<u8>, _>(1)))
+                .collect()
+        })
+        .map_err(|e| anyhow::anyhow!("Failed to load component state: {e}"))
+    }
+
+    /// Deletes the persisted state of the component `kind` with `component_id` via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL DELETE operation fails.
+    pub async fn delete_component_state(
+        pool: &PgPool,
+        kind: &str,
+        component_id: &str,
+    ) -> anyhow::Result<()> {
+        sqlx::query(r#"DELETE FROM "component_state" WHERE kind = $1 AND component_id = $2"#)
+            .bind(kind)
+            .bind(component_id)
+            .execute(pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| anyhow::anyhow!("Failed to delete component state: {e}"))
+    }
+
+    /// Records a heartbeat `timestamp` in the `health` table via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the INSERT or UPDATE operation fails.
+    pub async fn heartbeat(pool: &PgPool, timestamp: UnixNanos) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO "health" (id, value, updated_at) VALUES ('heartbeat', $1, CURRENT_TIMESTAMP)
+            ON CONFLICT (id)
+            DO UPDATE SET value = $1, updated_at = CURRENT_TIMESTAMP
+        "#,
+        )
+        .bind(timestamp.to_rfc3339())
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| anyhow::anyhow!("Failed to insert into health table: {e}"))
+    }
+
+    /// Loads the last recorded heartbeat from the `health` table via the provided `pool`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SQL SELECT operation fails.
+    pub async fn load_heartbeat(pool: &PgPool) -> anyhow::Result<Option<String>> {
+        sqlx::query(r#"SELECT value FROM "health" WHERE id = 'heartbeat'"#)
+            .fetch_optional(pool)
+            .await
+            .map(|row| row.map(|row| row.get::<String, _>(0)))
+            .map_err(|e| anyhow::anyhow!("Failed to load heartbeat: {e}"))
+    }
 }
@@ -19,6 +19,7 @@
 mod serial_tests {
     use std::{collections::HashSet, time::Duration};
 
+    use ahash::AHashMap;
     use bytes::Bytes;
     use indexmap::indexmap;
     use ant_common::{
@@ -28,7 +29,7 @@ mod serial_tests {
         testing::{wait_until, wait_until_async},
     };
     use ant_core::UnixNanos;
-    use ant_infrastructure::sql::cache::get_pg_cache_database;
+    use ant_infrastructure::sql::{cache::get_pg_cache_database, queries::DatabaseQueries};
     use ant_model::{
         accounts::{AccountAny, CashAccount},
         data::{
@@ -38,14 +39,15 @@ mod serial_tests {
         enums::{CurrencyType, OrderSide, OrderStatus, OrderType},
         events::{PositionSnapshot, account::stubs::cash_account_state_million_usd},
         identifiers::{
-            AccountId, ClientId, ClientOrderId, InstrumentId, TradeId, VenueOrderId,
-            stubs::account_id,
+            AccountId, ClientId, ClientOrderId, ComponentId, InstrumentId, StrategyId, TradeId,
+            VenueOrderId, stubs::account_id,
         },
         instruments::{
-            Instrument, InstrumentEnum,
+            Instrument, InstrumentEnum, SyntheticInstrument,
             stubs::{
                 audusd_sim, binary_option, crypto_future_btcusdt, crypto_perpetual_ethusdt,
-                currency_pair_ethusdt, equity_aapl, futures_contract_es, option_contract_appl,
+                currency_pair_ethusdt, equity_aapl, futures_contract_es, futures_spread_es,
+                option_contract_appl, option_spread,
             },
         },
         orders::{Order, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
@@ -779,4 +781,241 @@ mod serial_tests {
         pg_cache.flush().unwrap();
         pg_cache.close().unwrap();
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_spread_instruments() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let futures_spread = futures_spread_es();
+        let option_spread = option_spread();
+        pg_cache.add_currency(&Currency::USD()).unwrap();
+        pg_cache
+            .add_instrument(&InstrumentEnum::FuturesSpread(futures_spread))
+            .unwrap();
+        pg_cache
+            .add_instrument(&InstrumentEnum::OptionSpread(option_spread))
+            .unwrap();
+
+        wait_until_async(
+            || async {
+                let instruments = pg_cache.load_instruments().await.unwrap();
+                instruments.len() >= 2
+            },
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let futures_spread_result = pg_cache
+            .load_instrument(&futures_spread.id())
+            .await
+            .unwrap();
+        assert_entirely_equal(
+            futures_spread_result.unwrap(),
+            InstrumentEnum::FuturesSpread(futures_spread),
+        );
+        let option_spread_result = pg_cache.load_instrument(&option_spread.id()).await.unwrap();
+        assert_entirely_equal(
+            option_spread_result.unwrap(),
+            InstrumentEnum::OptionSpread(option_spread),
+        );
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_synthetic() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let synthetic = SyntheticInstrument::default();
+        pg_cache.add_synthetic(&synthetic).unwrap();
+
+        wait_until_async(
+            || async {
+                let synthetics = pg_cache.load_synthetics().await.unwrap();
+                !synthetics.is_empty()
+            },
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let result = pg_cache
+            .load_synthetic(&synthetic.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.id, synthetic.id);
+        assert_eq!(result.price_precision, synthetic.price_precision);
+        assert_eq!(result.components, synthetic.components);
+        assert_eq!(result.formula, synthetic.formula);
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_and_update_position() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let instrument = InstrumentEnum::CurrencyPair(currency_pair_ethusdt());
+
+        // Add foreign key dependencies: instrument and currencies
+        pg_cache
+            .add_currency(&instrument.base_currency().unwrap())
+            .unwrap();
+        pg_cache.add_currency(&instrument.quote_currency()).unwrap();
+        pg_cache.add_instrument(&instrument).unwrap();
+
+        let buy_order = OrderTestBuilder::new(OrderType::Market)
+            .client_order_id(ClientOrderId::new("O-19700101-000000-001-001-1"))
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.0"))
+            .build();
+        let buy_fill = TestOrderEventStubs::filled(
+            &buy_order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("100.0")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut position = Position::new(&instrument, buy_fill.into());
+
+        pg_cache.add_position(&position).unwrap();
+        wait_until_async(
+            || async {
+                let result = pg_cache.load_position(&position.id).await.unwrap();
+                result.is_some()
+            },
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let sell_order = OrderTestBuilder::new(OrderType::Market)
+            .client_order_id(ClientOrderId::new("O-19700101-000000-001-001-2"))
+            .instrument_id(instrument.id())
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("0.5"))
+            .build();
+        let sell_fill = TestOrderEventStubs::filled(
+            &sell_order,
+            &instrument,
+            None,
+            None,
+            Some(Price::from("110.0")),
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        position.apply(&sell_fill.into());
+
+        pg_cache.update_position(&position).unwrap();
+        wait_until_async(
+            || async {
+                let result = pg_cache.load_position(&position.id).await.unwrap();
+                result.is_some_and(|position| position.events.len() == 2)
+            },
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let result = pg_cache.load_position(&position.id).await.unwrap().unwrap();
+        assert_eq!(result.side, position.side);
+        assert_eq!(result.quantity, position.quantity);
+        assert_eq!(result.avg_px_open, position.avg_px_open);
+        assert_eq!(result.realized_pnl, position.realized_pnl);
+        assert_eq!(result.events, position.events);
+
+        let positions = pg_cache.load_positions().await.unwrap();
+        assert_eq!(positions.len(), 1);
+
+        let index = pg_cache.load_index_order_position().unwrap();
+        assert_eq!(index.len(), 2);
+        assert_eq!(index[&buy_order.client_order_id()].id, position.id);
+        assert_eq!(index[&sell_order.client_order_id()].id, position.id);
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_update_and_delete_component_state() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let component_id = ComponentId::new("Actor-001");
+        let strategy_id = StrategyId::new("S-001");
+        let mut state = AHashMap::new();
+        state.insert(String::from("step"), Bytes::from("1"));
+        state.insert(String::from("signal"), Bytes::from("long"));
+
+        pg_cache.update_actor(&component_id, &state).unwrap();
+        pg_cache.update_strategy(&strategy_id, &state).unwrap();
+        wait_until(
+            || {
+                !pg_cache.load_actor(&component_id).unwrap().is_empty()
+                    && !pg_cache.load_strategy(&strategy_id).unwrap().is_empty()
+            },
+            Duration::from_secs(5),
+        );
+        assert_eq!(pg_cache.load_actor(&component_id).unwrap(), state);
+        assert_eq!(pg_cache.load_strategy(&strategy_id).unwrap(), state);
+
+        // Updating replaces the previously saved state
+        let mut updated_state = AHashMap::new();
+        updated_state.insert(String::from("step"), Bytes::from("2"));
+        pg_cache
+            .update_strategy(&strategy_id, &updated_state)
+            .unwrap();
+        wait_until(
+            || pg_cache.load_strategy(&strategy_id).unwrap().len() == 1,
+            Duration::from_secs(5),
+        );
+        assert_eq!(pg_cache.load_strategy(&strategy_id).unwrap(), updated_state);
+
+        pg_cache.delete_actor(&component_id).unwrap();
+        pg_cache.delete_strategy(&strategy_id).unwrap();
+        wait_until(
+            || {
+                pg_cache.load_actor(&component_id).unwrap().is_empty()
+                    && pg_cache.load_strategy(&strategy_id).unwrap().is_empty()
+            },
+            Duration::from_secs(5),
+        );
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_heartbeat() {
+        let mut pg_cache = get_pg_cache_database().await.unwrap();
+
+        let timestamp = UnixNanos::from(1_000_000_000);
+        pg_cache.heartbeat(timestamp).unwrap();
+        wait_until_async(
+            || async {
+                DatabaseQueries::load_heartbeat(&pg_cache.pool)
+                    .await
+                    .unwrap()
+                    .is_some()
+            },
+            Duration::from_secs(5),
+        )
+        .await;
+
+        let result = DatabaseQueries::load_heartbeat(&pg_cache.pool)
+            .await
+            .unwrap();
+        assert_eq!(result, Some(timestamp.to_rfc3339()));
+
+        pg_cache.flush().unwrap();
+        pg_cache.close().unwrap();
+    }
 }
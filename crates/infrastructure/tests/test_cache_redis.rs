@@ -17,7 +17,7 @@
 #[cfg(feature = "redis")]
 #[cfg(target_os = "linux")] // Databases only tested and supported on Linux
 mod serial_tests {
-    use std::time::Duration;
+    use std::{collections::HashMap, time::Duration};
 
     use ant_common::{
         cache::{CacheConfig, database::CacheDatabaseAdapter},
@@ -25,15 +25,28 @@ mod serial_tests {
         msgbus::database::DatabaseConfig,
         testing::wait_until_async,
     };
-    use ant_core::UUID4;
-    use ant_infrastructure::redis::cache::{RedisCacheDatabase, RedisCacheDatabaseAdapter};
+    use ant_core::{UUID4, UnixNanos};
+    use ant_infrastructure::redis::{
+        cache::{RedisCacheDatabase, RedisCacheDatabaseAdapter},
+        queries::DatabaseQueries,
+    };
     use ant_model::{
-        enums::{OrderSide, OrderType},
-        identifiers::{ClientOrderId, PositionId, TraderId},
-        instruments::stubs::crypto_perpetual_ethusdt,
-        orders::{Order, builder::OrderTestBuilder},
-        types::Quantity,
+        accounts::{AccountAny, stubs::cash_account},
+        enums::{OrderSide, OrderStatus, OrderType},
+        events::{
+            OrderEventAny,
+            account::stubs::{cash_account_state, cash_account_state_million_usd},
+        },
+        identifiers::{
+            AccountId, ClientId, ClientOrderId, ComponentId, PositionId, TraderId, VenueOrderId,
+        },
+        instruments::{Instrument, InstrumentEnum, stubs::crypto_perpetual_ethusdt},
+        orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        position::Position,
+        types::{Currency, Quantity},
     };
+    use bytes::Bytes;
+    use redis::AsyncCommands;
 
     async fn get_redis_cache_adapter()
     -> Result<RedisCacheDatabaseAdapter, Box<dyn std::error::Error>> {
@@ -545,4 +558,290 @@ mod serial_tests {
         let mut adapter = adapter;
         adapter.flush().unwrap();
     }
+
+    /// Returns the `order` after it is accepted and filled at the venue.
+    fn accept_and_fill(
+        mut order: OrderAny,
+        instrument: &InstrumentEnum,
+        position_id: PositionId,
+    ) -> (OrderAny, Vec<OrderEventAny>) {
+        let account_id = AccountId::from("BINANCE-001");
+        let submitted = TestOrderEventStubs::submitted(&order, account_id);
+        order.apply(submitted.clone()).unwrap();
+        let accepted = TestOrderEventStubs::accepted(&order, account_id, VenueOrderId::from("V-1"));
+        order.apply(accepted.clone()).unwrap();
+        let filled = TestOrderEventStubs::filled(
+            &order,
+            instrument,
+            None,
+            Some(position_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(filled.clone()).unwrap();
+        (order, vec![submitted, accepted, filled])
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_currency_and_instrument_round_trip() {
+        let adapter = get_redis_cache_adapter()
+            .await
+            .expect("Failed to create adapter");
+
+        let currency = Currency::USDT();
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt());
+        adapter.add_currency(&currency).unwrap();
+        adapter.add_instrument(&instrument).unwrap();
+
+        wait_until_async(
+            || async {
+                adapter
+                    .load_instrument(&instrument.id())
+                    .await
+                    .unwrap()
+                    .is_some()
+            },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let loaded_currency = adapter.load_currency(&currency.code).await.unwrap();
+        assert_eq!(loaded_currency, Some(currency));
+        let loaded_instrument = adapter.load_instrument(&instrument.id()).await.unwrap();
+        assert_eq!(loaded_instrument.unwrap().id(), instrument.id());
+
+        let mut adapter = adapter;
+        adapter.flush().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_and_update_order_round_trip() {
+        let adapter = get_redis_cache_adapter()
+            .await
+            .expect("Failed to create adapter");
+
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt());
+        let order = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let client_order_id = order.client_order_id();
+
+        adapter
+            .add_order(&order, Some(ClientId::from("BINANCE")))
+            .unwrap();
+        let (order, events) = accept_and_fill(order, &instrument, PositionId::new("P-001"));
+        for event in &events {
+            adapter.update_order(event).unwrap();
+        }
+        adapter.snapshot_order_state(&order).unwrap();
+
+        wait_until_async(
+            || async {
+                adapter
+                    .load_order(&client_order_id)
+                    .await
+                    .unwrap()
+                    .is_some_and(|order| order.status() == OrderStatus::Filled)
+            },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let loaded = adapter.load_order(&client_order_id).await.unwrap().unwrap();
+        assert_eq!(loaded.venue_order_id(), Some(VenueOrderId::from("V-1")));
+        assert_eq!(loaded.filled_qty(), Quantity::from("1.000"));
+        assert_eq!(loaded.events().len(), 4);
+
+        let orders = adapter.load_orders().await.unwrap();
+        assert_eq!(orders.len(), 1);
+        assert!(orders.contains_key(&client_order_id));
+
+        let clients = adapter.load_index_order_client().unwrap();
+        assert_eq!(
+            clients.get(&client_order_id),
+            Some(&ClientId::from("BINANCE"))
+        );
+
+        let snapshot = adapter.load_order_snapshot(&client_order_id).unwrap();
+        assert_eq!(snapshot.unwrap().status, OrderStatus::Filled);
+
+        let mut adapter = adapter;
+        adapter.flush().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_and_update_position_round_trip() {
+        let adapter = get_redis_cache_adapter()
+            .await
+            .expect("Failed to create adapter");
+
+        let instrument = InstrumentEnum::CryptoPerpetual(crypto_perpetual_ethusdt());
+        let position_id = PositionId::new("P-001");
+        let buy = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from("O-001"))
+            .side(OrderSide::Buy)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let (buy, _) = accept_and_fill(buy, &instrument, position_id);
+        let OrderEventAny::Filled(buy_fill) = buy.last_event().clone() else {
+            panic!("Expected a fill");
+        };
+
+        let mut position = Position::new(&instrument, buy_fill);
+        adapter.add_position(&position).unwrap();
+        adapter
+            .index_order_position(buy.client_order_id(), position_id)
+            .unwrap();
+
+        let sell = OrderTestBuilder::new(OrderType::Market)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from("O-002"))
+            .side(OrderSide::Sell)
+            .quantity(Quantity::from("1.000"))
+            .build();
+        let (sell, _) = accept_and_fill(sell, &instrument, position_id);
+        let OrderEventAny::Filled(sell_fill) = sell.last_event().clone() else {
+            panic!("Expected a fill");
+        };
+        position.apply(&sell_fill);
+        adapter.update_position(&position).unwrap();
+        adapter.snapshot_position_state(&position).unwrap();
+
+        wait_until_async(
+            || async {
+                adapter
+                    .load_position(&position_id)
+                    .await
+                    .unwrap()
+                    .is_some_and(|position| position.is_closed())
+            },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let loaded = adapter.load_position(&position_id).await.unwrap().unwrap();
+        assert_eq!(loaded.events.len(), 2);
+
+        let index = adapter.load_index_order_position().unwrap();
+        assert_eq!(index.get(&buy.client_order_id()).unwrap().id, position_id);
+
+        let snapshot = adapter.load_position_snapshot(&position_id).unwrap();
+        assert_eq!(snapshot.unwrap().position_id, position_id);
+
+        let trader_key = &adapter.database.trader_key;
+        let mut con = adapter.database.con.clone();
+        let open: bool = con
+            .sismember(format!("{trader_key}:index:positions_open"), "P-001")
+            .await
+            .unwrap();
+        let closed: bool = con
+            .sismember(format!("{trader_key}:index:positions_closed"), "P-001")
+            .await
+            .unwrap();
+        assert!(!open);
+        assert!(closed);
+
+        let mut adapter = adapter;
+        adapter.flush().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_add_and_update_account_round_trip() {
+        let adapter = get_redis_cache_adapter()
+            .await
+            .expect("Failed to create adapter");
+
+        let mut account = AccountAny::Cash(cash_account(cash_account_state()));
+        adapter.add_account(&account).unwrap();
+        account.apply(cash_account_state_million_usd(
+            "1000000 USD",
+            "0 USD",
+            "1000000 USD",
+        ));
+        adapter.update_account(&account).unwrap();
+
+        let account_id = account.id();
+        wait_until_async(
+            || async {
+                adapter
+                    .load_account(&account_id)
+                    .await
+                    .unwrap()
+                    .is_some_and(|account| account.events().len() == 2)
+            },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let loaded = adapter.load_account(&account_id).await.unwrap().unwrap();
+        assert_eq!(loaded.last_event(), account.last_event());
+        assert_eq!(loaded.balances(), account.balances());
+
+        let mut adapter = adapter;
+        adapter.flush().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_general_actor_state_and_heartbeat_round_trip() {
+        let mut adapter = get_redis_cache_adapter()
+            .await
+            .expect("Failed to create adapter");
+
+        adapter
+            .add("greeting".to_string(), Bytes::from("hello"))
+            .unwrap();
+        adapter.heartbeat(UnixNanos::from(1_000_000_000)).unwrap();
+
+        let state = HashMap::from([("count".to_string(), Bytes::from("42"))]);
+        let payload = DatabaseQueries::serialize_payload(adapter.encoding, &state).unwrap();
+        adapter
+            .database
+            .insert(
+                "actors:MyActor-001".to_string(),
+                Some(vec![Bytes::from(payload)]),
+            )
+            .unwrap();
+
+        wait_until_async(
+            || async {
+                adapter.load().unwrap().contains_key("greeting")
+                    && !adapter
+                        .load_actor(&ComponentId::from("MyActor-001"))
+                        .unwrap()
+                        .is_empty()
+            },
+            Duration::from_secs(2),
+        )
+        .await;
+
+        let general = adapter.load().unwrap();
+        assert_eq!(general.get("greeting"), Some(&Bytes::from("hello")));
+
+        let actor_state = adapter
+            .load_actor(&ComponentId::from("MyActor-001"))
+            .unwrap();
+        assert_eq!(actor_state.get("count"), Some(&Bytes::from("42")));
+        assert!(
+            adapter
+                .load_actor(&ComponentId::from("Unknown-001"))
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut con = adapter.database.con.clone();
+        let heartbeat: String = con
+            .get(format!("{}:health:heartbeat", adapter.database.trader_key))
+            .await
+            .unwrap();
+        assert_eq!(heartbeat, UnixNanos::from(1_000_000_000).to_rfc3339());
+
+        adapter.flush().unwrap();
+    }
 }
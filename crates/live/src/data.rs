@@ -29,8 +29,8 @@ use ant_core::{UUID4, UnixNanos};
 use ant_data::client::DataClient;
 use ant_model::{
     data::{
-        Bar, BarType, Data, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta, OrderBookDeltas_API,
-        OrderBookDepth10, QuoteTick, TradeTick, close::InstrumentClose,
+        Bar, BarType, Data, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta,
+        OrderBookDeltas_API, OrderBookDepth10, QuoteTick, TradeTick, close::InstrumentClose,
     },
     identifiers::{ClientId, InstrumentId, Venue},
     instruments::{Instrument, InstrumentEnum},
@@ -75,6 +75,10 @@ pub trait LiveDataClient: DataClient {
         self.send_data(Data::IndexPriceUpdate(index_price));
     }
 
+    fn send_funding_rate(&self, funding_rate: FundingRateUpdate) {
+        self.send_data(Data::FundingRateUpdate(funding_rate));
+    }
+
     fn send_instrument_close(&self, close: InstrumentClose) {
         self.send_data(Data::InstrumentClose(close));
     }
@@ -21,6 +21,7 @@
 //!
 //! - `LiveNode` High-level abstraction for live system nodes.
 //! - `LiveNodeConfig` Configuration for live node deployment.
+//! - `ExecutionReconciler` for reconciling execution state with trading venues.
 //! - `AsyncRunner` for managing system real-time data flow.
 //!
 //! # Platform
@@ -56,6 +57,7 @@
 pub mod config;
 pub mod data;
 pub mod node;
+pub mod reconciliation;
 pub mod runner;
 
 #[cfg(feature = "python")]
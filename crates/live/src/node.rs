@@ -25,7 +25,7 @@ use std::{
         Arc,
         atomic::{AtomicBool, Ordering},
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use ant_common::{
@@ -36,6 +36,7 @@ use ant_common::{
 };
 use ant_core::UUID4;
 use ant_data::client::DataClientAdapter;
+use ant_execution::client::ExecutionClient;
 use ant_model::identifiers::TraderId;
 use ant_system::{
     config::AntKernelConfig,
@@ -43,7 +44,7 @@ use ant_system::{
     kernel::AntKernel,
 };
 
-use crate::{config::LiveNodeConfig, runner::AsyncRunner};
+use crate::{config::LiveNodeConfig, reconciliation::ExecutionReconciler, runner::AsyncRunner};
 
 /// A thread-safe handle to control a `LiveNode` from other threads.
 /// This allows starting, stopping, and querying the node's state
@@ -103,7 +104,6 @@ impl LiveNodeHandle {
 ///
 /// Provides a simplified interface for running live systems
 /// with automatic client management and lifecycle handling.
-#[derive(Debug)]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "ant_trader.core.ant_pyo3.live", unsendable)
@@ -113,11 +113,24 @@ pub struct LiveNode {
     kernel: AntKernel,
     runner: AsyncRunner,
     config: LiveNodeConfig,
+    exec_clients: Vec<Rc<dyn ExecutionClient>>,
+    reconciler: ExecutionReconciler,
     is_running: bool,
     /// Handle for thread-safe control of this node.
     handle: LiveNodeHandle,
 }
 
+impl std::fmt::Debug for LiveNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(LiveNode))
+            .field("kernel", &self.kernel)
+            .field("config", &self.config)
+            .field("exec_clients", &self.exec_clients.len())
+            .field("is_running", &self.is_running)
+            .finish_non_exhaustive()
+    }
+}
+
 impl LiveNode {
     /// Returns a thread-safe handle to control this node.
     #[must_use]
@@ -161,6 +174,12 @@ impl LiveNode {
         let runner = AsyncRunner::new();
         let clock = Rc::new(RefCell::new(LiveClock::default()));
         let kernel = AntKernel::new(name, config.clone())?;
+        let reconciler = ExecutionReconciler::new(
+            kernel.trader_id(),
+            kernel.clock(),
+            kernel.cache(),
+            config.exec_engine.clone(),
+        );
 
         log::info!("LiveNode built successfully with kernel config");
 
@@ -169,6 +188,8 @@ impl LiveNode {
             kernel,
             runner,
             config,
+            exec_clients: Vec::new(),
+            reconciler,
             is_running: false,
             handle: LiveNodeHandle::new(),
         })
@@ -185,6 +206,17 @@ impl LiveNode {
         }
 
         self.kernel.start_async().await;
+
+        if self.config.exec_engine.reconciliation {
+            if let Err(e) = self.reconcile_execution_state() {
+                log::error!("Execution state could not be reconciled: {e}");
+                self.kernel.stop_async().await;
+                return Err(e);
+            }
+        } else {
+            log::warn!("Execution state reconciliation is disabled");
+        }
+
         self.is_running = true;
         self.handle.set_running(true);
 
@@ -219,6 +251,12 @@ impl LiveNode {
     pub async fn run(&mut self) -> anyhow::Result<()> {
         self.start().await?;
 
+        let open_check_interval = self
+            .config
+            .exec_engine
+            .open_check_interval_secs
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+
         tokio::select! {
             // Run on main thread
             () = self.runner.run() => {
@@ -226,8 +264,16 @@ impl LiveNode {
             }
             // Handle stop signal from handle (for Python integration)
             () = async {
+                let mut last_open_check = Instant::now();
                 while !self.handle.should_stop() {
                     tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+                    if let Some(interval) = open_check_interval
+                        && last_open_check.elapsed() >= interval
+                    {
+                        check_open_orders(&self.kernel, &self.exec_clients, &self.reconciler);
+                        last_open_check = Instant::now();
+                    }
                 }
                 log::info!("Received stop signal from handle");
             } => {
@@ -257,6 +303,53 @@ impl LiveNode {
         Ok(())
     }
 
+    /// Reconciles the execution state of the cache with each execution client's venue.
+    ///
+    /// Requests a mass status from every client which supports venue reports, and generates
+    /// any missing order events, inferred fills and external orders.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any client fails to reconcile, or if reconciliation takes longer
+    /// than the configured `timeout_reconciliation`.
+    pub fn reconcile_execution_state(&self) -> anyhow::Result<()> {
+        let lookback_mins = self
+            .config
+            .exec_engine
+            .reconciliation_lookback_mins
+            .map(u64::from);
+        let timeout = self.config.timeout_reconciliation;
+        let start = Instant::now();
+        let exec_engine = self.kernel.exec_engine();
+
+        for client in &self.exec_clients {
+            let Some(live_client) = client.as_live() else {
+                log::warn!(
+                    "Execution client {} does not support reconciliation",
+                    client.client_id()
+                );
+                continue;
+            };
+
+            match live_client.generate_mass_status(lookback_mins)? {
+                Some(mass_status) => self
+                    .reconciler
+                    .reconcile_mass_status(&mass_status, &exec_engine)?,
+                None => log::warn!("No mass status available from {}", client.client_id()),
+            }
+
+            if start.elapsed() > timeout {
+                anyhow::bail!("Timed out reconciling execution state after {timeout:?}");
+            }
+        }
+
+        log::info!(
+            "Reconciled execution state in {}ms",
+            start.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
     /// Gets the node's environment.
     #[must_use]
     pub fn environment(&self) -> Environment {
@@ -564,6 +657,7 @@ impl LiveNodeBuilder {
         }
 
         // Create and register execution clients
+        let mut exec_clients = Vec::new();
         for (name, factory) in self.exec_client_factories {
             if let Some(config) = self.exec_client_configs.remove(&name) {
                 log::info!("Creating execution client '{name}'");
@@ -573,13 +667,26 @@ impl LiveNodeBuilder {
 
                 log::info!("Registering execution client '{name}' with execution engine");
 
-                // TODO: Implement when ExecutionEngine has a register_client method
-                // kernel.exec_engine().register_client(client);
+                let client: Rc<dyn ExecutionClient> = Rc::from(client);
+                kernel
+                    .exec_engine
+                    .borrow_mut()
+                    .register_client(client.clone())?;
+                exec_clients.push(client);
+
+                log::info!("Successfully registered execution client '{name}'");
             } else {
                 log::warn!("No config found for execution client factory '{name}'");
             }
         }
 
+        let reconciler = ExecutionReconciler::new(
+            kernel.trader_id(),
+            kernel.clock(),
+            kernel.cache(),
+            self.config.exec_engine.clone(),
+        );
+
         log::info!("Built successfully");
 
         Ok(LiveNode {
@@ -587,12 +694,30 @@ impl LiveNodeBuilder {
             kernel,
             runner,
             config: self.config,
+            exec_clients,
+            reconciler,
             is_running: false,
             handle: LiveNodeHandle::new(),
         })
     }
 }
 
+/// Checks the open orders of every execution client which supports venue reports.
+fn check_open_orders(
+    kernel: &AntKernel,
+    exec_clients: &[Rc<dyn ExecutionClient>],
+    reconciler: &ExecutionReconciler,
+) {
+    let exec_engine = kernel.exec_engine();
+    for client in exec_clients {
+        if let Some(live_client) = client.as_live()
+            && let Err(e) = reconciler.check_open_orders(live_client, &exec_engine)
+        {
+            log::error!("Open order check failed for {}: {e}", client.client_id());
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
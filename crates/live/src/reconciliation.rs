@@ -0,0 +1,1210 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution state reconciliation for live nodes.
+//!
+//! Compares the order, fill and position reports of each execution client with the cache,
+//! and generates the events required for the cache to match the state at the venue.
+
+use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+use ant_common::{cache::Cache, clock::Clock, messages::execution::GenerateOrderStatusReport};
+use ant_core::UUID4;
+use ant_execution::{client::LiveExecutionClient, engine::ExecutionEngine};
+use ant_model::{
+    enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, PriceType, TimeInForce},
+    events::{
+        OrderAccepted, OrderCanceled, OrderEventAny, OrderExpired, OrderFilled, OrderInitialized,
+        OrderRejected, OrderTriggered,
+    },
+    identifiers::{
+        AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
+    },
+    instruments::{Instrument, InstrumentEnum},
+    orders::{Order, OrderAny},
+    reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+    types::{Price, Quantity},
+};
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use ustr::Ustr;
+
+use crate::config::LiveExecEngineConfig;
+
+/// The tag applied to orders generated from venue reports.
+const TAG_EXTERNAL: &str = "EXTERNAL";
+/// The tag applied to orders generated to align position discrepancies.
+const TAG_RECONCILIATION: &str = "RECONCILIATION";
+
+/// Reconciles cached execution state with the reports of live execution clients.
+///
+/// Missing `OrderAccepted`, `OrderFilled`, `OrderCanceled` (and related) events are generated
+/// and processed through the execution engine. Orders known to the venue but not to the cache
+/// are created as external orders, and fills missing from the reports are inferred from the
+/// reported filled quantity and average price.
+pub struct ExecutionReconciler {
+    trader_id: TraderId,
+    clock: Rc<RefCell<dyn Clock>>,
+    cache: Rc<RefCell<Cache>>,
+    config: LiveExecEngineConfig,
+}
+
+impl std::fmt::Debug for ExecutionReconciler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ExecutionReconciler))
+            .field("trader_id", &self.trader_id)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl ExecutionReconciler {
+    /// Creates a new [`ExecutionReconciler`] instance.
+    pub fn new(
+        trader_id: TraderId,
+        clock: Rc<RefCell<dyn Clock>>,
+        cache: Rc<RefCell<Cache>>,
+        config: LiveExecEngineConfig,
+    ) -> Self {
+        Self {
+            trader_id,
+            clock,
+            cache,
+            config,
+        }
+    }
+
+    /// Reconciles the cache with the given execution mass status.
+    ///
+    /// Every report is reconciled even when an earlier one fails, so that as much state
+    /// as possible is recovered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the order, fill or position reports could not be reconciled.
+    pub fn reconcile_mass_status(
+        &self,
+        mass_status: &ExecutionMassStatus,
+        exec_engine: &ExecutionEngine,
+    ) -> anyhow::Result<()> {
+        log::info!(
+            "Reconciling state for {} (account_id={})",
+            mass_status.client_id,
+            mass_status.account_id,
+        );
+
+        let order_reports = mass_status.order_reports();
+        let fill_reports = mass_status.fill_reports();
+        let mut failures = 0;
+
+        for (venue_order_id, report) in &order_reports {
+            let fills = fill_reports
+                .get(venue_order_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            if let Err(e) =
+                self.reconcile_order_report(report, fills, mass_status.client_id, exec_engine)
+            {
+                log::error!("Failed to reconcile order {venue_order_id}: {e}");
+                failures += 1;
+            }
+        }
+
+        // Fills for orders which are no longer reported (e.g. closed outside the lookback)
+        for (venue_order_id, fills) in &fill_reports {
+            if order_reports.contains_key(venue_order_id) {
+                continue;
+            }
+
+            let client_order_id = self
+                .cache
+                .borrow()
+                .client_order_id(venue_order_id)
+                .copied()
+                .or_else(|| fills.iter().find_map(|fill| fill.client_order_id));
+
+            match client_order_id.filter(|id| self.cache.borrow().order(id).is_some()) {
+                Some(client_order_id) => {
+                    if let Err(e) = self.reconcile_fills(&client_order_id, fills, exec_engine) {
+                        log::error!("Failed to reconcile fills for {venue_order_id}: {e}");
+                        failures += 1;
+                    }
+                }
+                None => log::warn!(
+                    "Cannot reconcile {} fill report(s) for {venue_order_id}: order not found",
+                    fills.len(),
+                ),
+            }
+        }
+
+        if self.config.filter_position_reports {
+            log::info!("Filtered position reports for {}", mass_status.client_id);
+        } else {
+            for (instrument_id, reports) in &mass_status.position_reports() {
+                if let Err(e) = self.reconcile_position_reports(
+                    instrument_id,
+                    reports,
+                    mass_status.client_id,
+                    mass_status.account_id,
+                    exec_engine,
+                ) {
+                    log::error!("Failed to reconcile position for {instrument_id}: {e}");
+                    failures += 1;
+                }
+            }
+        }
+
+        if failures > 0 {
+            anyhow::bail!(
+                "Failed to reconcile {failures} report(s) for {}",
+                mass_status.client_id
+            );
+        }
+
+        log::info!("Reconciled state for {}", mass_status.client_id);
+        Ok(())
+    }
+
+    /// Checks the open orders in the cache against the orders reported by the `client`.
+    ///
+    /// Open orders which the venue no longer reports as open are queried individually,
+    /// so that fills and cancels missed while disconnected are applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client fails to generate reports, or if any report could
+    /// not be reconciled.
+    pub fn check_open_orders(
+        &self,
+        client: &dyn LiveExecutionClient,
+        exec_engine: &ExecutionEngine,
+    ) -> anyhow::Result<()> {
+        let client_id = client.client_id();
+        let ts_init = self.clock.borrow().timestamp_ns();
+        let cmd = GenerateOrderStatusReport::new(UUID4::new(), ts_init, None, None, None);
+
+        let reports: Vec<OrderStatusReport> = client
+            .generate_order_status_reports(&cmd)?
+            .into_iter()
+            .filter(|report| !self.config.open_check_open_only || is_open(report.order_status))
+            .collect();
+        let reported: HashSet<VenueOrderId> =
+            reports.iter().map(|report| report.venue_order_id).collect();
+        let mut failures = 0;
+
+        for report in &reports {
+            // Closed orders unknown to the cache are only recovered from a mass status
+            if !is_open(report.order_status) && self.cached_client_order_id(report).is_none() {
+                continue;
+            }
+
+            if let Err(e) = self.reconcile_order_report(report, &[], client_id, exec_engine) {
+                log::error!("Failed to reconcile order {}: {e}", report.venue_order_id);
+                failures += 1;
+            }
+        }
+
+        let missing: Vec<ClientOrderId> = self
+            .cache
+            .borrow()
+            .orders_open(Some(&client.venue()), None, None, None)
+            .iter()
+            .filter(|order| {
+                order
+                    .venue_order_id()
+                    .is_none_or(|venue_order_id| !reported.contains(&venue_order_id))
+            })
+            .map(|order| order.client_order_id())
+            .collect();
+
+        for client_order_id in missing {
+            let cmd = GenerateOrderStatusReport::new(
+                UUID4::new(),
+                ts_init,
+                None,
+                Some(client_order_id),
+                None,
+            );
+
+            match client.generate_order_status_report(&cmd)? {
+                Some(report) => {
+                    if let Err(e) =
+                        self.reconcile_order_report(&report, &[], client_id, exec_engine)
+                    {
+                        log::error!("Failed to reconcile order {client_order_id}: {e}");
+                        failures += 1;
+                    }
+                }
+                None => log::warn!("Open order {client_order_id} not found at {client_id}"),
+            }
+        }
+
+        if failures > 0 {
+            anyhow::bail!("Failed to reconcile {failures} open order(s) for {client_id}");
+        }
+
+        Ok(())
+    }
+
+    /// Reconciles a single order status report, along with any fill reports for the order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The external order could not be added to the cache.
+    /// - The reported filled quantity is less than the cached filled quantity.
+    /// - A missing fill cannot be inferred from the report.
+    pub fn reconcile_order_report(
+        &self,
+        report: &OrderStatusReport,
+        fills: &[FillReport],
+        client_id: ClientId,
+        exec_engine: &ExecutionEngine,
+    ) -> anyhow::Result<()> {
+        let client_order_id = match self.cached_client_order_id(report) {
+            Some(client_order_id) => client_order_id,
+            None => match self.generate_external_order(report, client_id, exec_engine)? {
+                Some(client_order_id) => client_order_id,
+                None => return Ok(()),
+            },
+        };
+
+        let order = self.cached_order(&client_order_id)?;
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        if report.order_status == OrderStatus::Rejected {
+            if order.status() != OrderStatus::Rejected {
+                let reason = report.cancel_reason.as_deref().unwrap_or("UNKNOWN");
+                let rejected = OrderRejected::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    report.account_id,
+                    Ustr::from(reason),
+                    UUID4::new(),
+                    report.ts_last,
+                    ts_init,
+                    true,
+                    false,
+                );
+                self.process(exec_engine, OrderEventAny::Rejected(rejected));
+            }
+            return Ok(());
+        }
+
+        if matches!(
+            order.status(),
+            OrderStatus::Initialized | OrderStatus::Submitted
+        ) {
+            let accepted = OrderAccepted::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                report.venue_order_id,
+                report.account_id,
+                UUID4::new(),
+                report.ts_accepted,
+                ts_init,
+                true,
+            );
+            self.process(exec_engine, OrderEventAny::Accepted(accepted));
+        }
+
+        let order = self.cached_order(&client_order_id)?;
+        if report.order_status == OrderStatus::Triggered && order.status() == OrderStatus::Accepted
+        {
+            let triggered = OrderTriggered::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                client_order_id,
+                UUID4::new(),
+                report.ts_triggered.unwrap_or(report.ts_last),
+                ts_init,
+                true,
+                Some(report.venue_order_id),
+                Some(report.account_id),
+            );
+            self.process(exec_engine, OrderEventAny::Triggered(triggered));
+        }
+
+        self.reconcile_fills(&client_order_id, fills, exec_engine)?;
+
+        let order = self.cached_order(&client_order_id)?;
+        if report.filled_qty < order.filled_qty() {
+            anyhow::bail!(
+                "Reported filled_qty {} for {client_order_id} is less than cached filled_qty {}",
+                report.filled_qty,
+                order.filled_qty(),
+            );
+        }
+
+        if report.filled_qty > order.filled_qty() {
+            let inferred = self.generate_inferred_fill(&order, report)?;
+            self.process(exec_engine, inferred);
+        }
+
+        let order = self.cached_order(&client_order_id)?;
+        if !order.is_open() {
+            return Ok(());
+        }
+
+        match report.order_status {
+            OrderStatus::Canceled => {
+                let canceled = OrderCanceled::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    UUID4::new(),
+                    report.ts_last,
+                    ts_init,
+                    true,
+                    Some(report.venue_order_id),
+                    Some(report.account_id),
+                );
+                self.process(exec_engine, OrderEventAny::Canceled(canceled));
+            }
+            OrderStatus::Expired => {
+                let expired = OrderExpired::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    client_order_id,
+                    UUID4::new(),
+                    report.ts_last,
+                    ts_init,
+                    true,
+                    Some(report.venue_order_id),
+                    Some(report.account_id),
+                );
+                self.process(exec_engine, OrderEventAny::Expired(expired));
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn reconcile_fills(
+        &self,
+        client_order_id: &ClientOrderId,
+        fills: &[FillReport],
+        exec_engine: &ExecutionEngine,
+    ) -> anyhow::Result<()> {
+        for fill in fills {
+            let order = self.cached_order(client_order_id)?;
+            if order.trade_ids().contains(&&fill.trade_id) {
+                continue;
+            }
+
+            let instrument = self.instrument(&fill.instrument_id)?;
+            let ts_init = self.clock.borrow().timestamp_ns();
+
+            if matches!(
+                order.status(),
+                OrderStatus::Initialized | OrderStatus::Submitted
+            ) {
+                let accepted = OrderAccepted::new(
+                    order.trader_id(),
+                    order.strategy_id(),
+                    order.instrument_id(),
+                    *client_order_id,
+                    fill.venue_order_id,
+                    fill.account_id,
+                    UUID4::new(),
+                    fill.ts_event,
+                    ts_init,
+                    true,
+                );
+                self.process(exec_engine, OrderEventAny::Accepted(accepted));
+            }
+
+            let filled = OrderFilled::new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                *client_order_id,
+                fill.venue_order_id,
+                fill.account_id,
+                fill.trade_id,
+                order.order_side(),
+                order.order_type(),
+                fill.last_qty,
+                fill.last_px,
+                instrument.quote_currency(),
+                fill.liquidity_side,
+                UUID4::new(),
+                fill.ts_event,
+                ts_init,
+                true,
+                fill.venue_position_id,
+                Some(fill.commission),
+            );
+            self.process(exec_engine, OrderEventAny::Filled(filled));
+        }
+
+        Ok(())
+    }
+
+    fn reconcile_position_reports(
+        &self,
+        instrument_id: &InstrumentId,
+        reports: &[PositionStatusReport],
+        client_id: ClientId,
+        account_id: AccountId,
+        exec_engine: &ExecutionEngine,
+    ) -> anyhow::Result<()> {
+        let venue_qty: f64 = reports
+            .iter()
+            .filter_map(|report| report.signed_decimal_qty.to_f64())
+            .sum();
+        let cached_qty: f64 = self
+            .cache
+            .borrow()
+            .positions_open(None, Some(instrument_id), None, None)
+            .iter()
+            .map(|position| position.signed_qty)
+            .sum();
+
+        let instrument = self.instrument(instrument_id)?;
+        let diff = venue_qty - cached_qty;
+        let quantity = match instrument.try_make_qty(diff.abs(), None) {
+            Ok(quantity) if !quantity.is_zero() => quantity,
+            _ => return Ok(()),
+        };
+
+        if !self.config.generate_missing_orders {
+            anyhow::bail!(
+                "Reported position {venue_qty} for {instrument_id} does not match cached position {cached_qty}"
+            );
+        }
+
+        let last_px = self.reconciliation_price(instrument_id).ok_or_else(|| {
+            anyhow::anyhow!("Cannot generate reconciliation order: no price for {instrument_id}")
+        })?;
+        let order_side = if diff > 0.0 {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+
+        log::warn!(
+            "Reported position {venue_qty} for {instrument_id} does not match cached position {cached_qty}, \
+            generating {order_side} MARKET order for {quantity}"
+        );
+
+        let strategy_id = exec_engine
+            .get_external_order_claim(instrument_id)
+            .unwrap_or_else(StrategyId::external);
+        let client_order_id = ClientOrderId::new(format!("{TAG_RECONCILIATION}-{}", UUID4::new()));
+        let venue_order_id = VenueOrderId::new(client_order_id.as_str());
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        let init = OrderInitialized::new(
+            self.trader_id,
+            strategy_id,
+            *instrument_id,
+            client_order_id,
+            order_side,
+            OrderType::Market,
+            quantity,
+            TimeInForce::Gtc,
+            false,
+            false,
+            false,
+            true,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Ustr::from(TAG_RECONCILIATION)]),
+        );
+        {
+            let mut cache = self.cache.borrow_mut();
+            cache.add_order(OrderAny::from(init), None, Some(client_id), false)?;
+            cache.add_venue_order_id(&client_order_id, &venue_order_id, false)?;
+        }
+
+        let accepted = OrderAccepted::new(
+            self.trader_id,
+            strategy_id,
+            *instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            true,
+        );
+        self.process(exec_engine, OrderEventAny::Accepted(accepted));
+
+        let filled = OrderFilled::new(
+            self.trader_id,
+            strategy_id,
+            *instrument_id,
+            client_order_id,
+            venue_order_id,
+            account_id,
+            TradeId::new(UUID4::new().to_string()),
+            order_side,
+            OrderType::Market,
+            quantity,
+            last_px,
+            instrument.quote_currency(),
+            LiquiditySide::Taker,
+            UUID4::new(),
+            ts_init,
+            ts_init,
+            true,
+            None,
+            None,
+        );
+        self.process(exec_engine, OrderEventAny::Filled(filled));
+
+        Ok(())
+    }
+
+    fn generate_external_order(
+        &self,
+        report: &OrderStatusReport,
+        client_id: ClientId,
+        exec_engine: &ExecutionEngine,
+    ) -> anyhow::Result<Option<ClientOrderId>> {
+        let claim = exec_engine.get_external_order_claim(&report.instrument_id);
+        if claim.is_none() && self.config.filter_unclaimed_external_orders {
+            log::info!(
+                "Filtered unclaimed external order {} for {}",
+                report.venue_order_id,
+                report.instrument_id,
+            );
+            return Ok(None);
+        }
+
+        let strategy_id = claim.unwrap_or_else(StrategyId::external);
+        let client_order_id = report
+            .client_order_id
+            .unwrap_or_else(|| ClientOrderId::new(report.venue_order_id.as_str()));
+        let ts_init = self.clock.borrow().timestamp_ns();
+
+        let init = OrderInitialized::new(
+            self.trader_id,
+            strategy_id,
+            report.instrument_id,
+            client_order_id,
+            report.order_side,
+            report.order_type,
+            report.quantity,
+            report.time_in_force,
+            report.post_only,
+            report.reduce_only,
+            false,
+            true,
+            UUID4::new(),
+            report.ts_accepted,
+            ts_init,
+            report.price,
+            report.trigger_price,
+            report.trigger_type,
+            report.limit_offset,
+            report.trailing_offset,
+            Some(report.trailing_offset_type),
+            report.expire_time,
+            report.display_qty,
+            None,
+            None,
+            Some(report.contingency_type),
+            report.order_list_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(vec![Ustr::from(TAG_EXTERNAL)]),
+        );
+
+        let mut cache = self.cache.borrow_mut();
+        cache.add_order(OrderAny::from(init), None, Some(client_id), false)?;
+        cache.add_venue_order_id(&client_order_id, &report.venue_order_id, false)?;
+
+        log::info!("Generated external order {client_order_id} for {strategy_id}");
+        Ok(Some(client_order_id))
+    }
+
+    fn generate_inferred_fill(
+        &self,
+        order: &OrderAny,
+        report: &OrderStatusReport,
+    ) -> anyhow::Result<OrderEventAny> {
+        let instrument = self.instrument(&order.instrument_id())?;
+        let last_qty = report.filled_qty - order.filled_qty();
+        let last_px = inferred_fill_px(order, report, last_qty)?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "Cannot infer fill for {}: no average or limit price reported",
+                order.client_order_id(),
+            )
+        })?;
+        let price_precision = instrument.price_precision();
+        let last_px = Price::from_decimal(
+            last_px.round_dp(u32::from(price_precision)),
+            price_precision,
+        )?;
+
+        log::warn!(
+            "Inferred fill of {last_qty} @ {last_px} for {}",
+            order.client_order_id()
+        );
+
+        let filled = OrderFilled::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            report.venue_order_id,
+            report.account_id,
+            TradeId::new(UUID4::new().to_string()),
+            order.order_side(),
+            order.order_type(),
+            last_qty,
+            last_px,
+            instrument.quote_currency(),
+            LiquiditySide::NoLiquiditySide,
+            UUID4::new(),
+            report.ts_last,
+            self.clock.borrow().timestamp_ns(),
+            true,
+            report.venue_position_id,
+            None,
+        );
+
+        Ok(OrderEventAny::Filled(filled))
+    }
+
+    fn process(&self, exec_engine: &ExecutionEngine, event: OrderEventAny) {
+        log::info!("Reconciliation generated {event}");
+        exec_engine.process(&event);
+    }
+
+    fn cached_client_order_id(&self, report: &OrderStatusReport) -> Option<ClientOrderId> {
+        let cache = self.cache.borrow();
+        report
+            .client_order_id
+            .filter(|client_order_id| cache.order(client_order_id).is_some())
+            .or_else(|| cache.client_order_id(&report.venue_order_id).copied())
+    }
+
+    fn cached_order(&self, client_order_id: &ClientOrderId) -> anyhow::Result<OrderAny> {
+        self.cache
+            .borrow()
+            .order(client_order_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Order {client_order_id} not found in cache"))
+    }
+
+    fn instrument(&self, instrument_id: &InstrumentId) -> anyhow::Result<InstrumentEnum> {
+        self.cache
+            .borrow()
+            .instrument(instrument_id)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Instrument {instrument_id} not found in cache"))
+    }
+
+    fn reconciliation_price(&self, instrument_id: &InstrumentId) -> Option<Price> {
+        let cache = self.cache.borrow();
+        cache
+            .price(instrument_id, PriceType::Mark)
+            .or_else(|| cache.price(instrument_id, PriceType::Last))
+            .or_else(|| cache.price(instrument_id, PriceType::Mid))
+    }
+}
+
+const fn is_open(status: OrderStatus) -> bool {
+    matches!(
+        status,
+        OrderStatus::Accepted
+            | OrderStatus::Triggered
+            | OrderStatus::PendingUpdate
+            | OrderStatus::PendingCancel
+            | OrderStatus::PartiallyFilled
+    )
+}
+
+/// Returns the price of the fill missing between the cached order and the `report`.
+///
+/// The venue average price covers all fills, so the cached fills are backed out of it. The
+/// calculation is in decimal so that the order's average price after applying the inferred fill
+/// matches the venue's, rather than drifting by the binary rounding of each term.
+fn inferred_fill_px(
+    order: &OrderAny,
+    report: &OrderStatusReport,
+    last_qty: Quantity,
+) -> anyhow::Result<Option<Decimal>> {
+    let Some(report_avg_px) = report.avg_px else {
+        return Ok(report.price.map(|price| price.as_decimal()));
+    };
+
+    let report_avg_px = Decimal::try_from(report_avg_px)?;
+    let cached_avg_px = match order.avg_px() {
+        Some(avg_px) => Decimal::try_from(avg_px)?,
+        None => Decimal::ZERO,
+    };
+    let cached_notional = cached_avg_px * order.filled_qty().as_decimal();
+    let report_notional = report_avg_px * report.filled_qty.as_decimal();
+    Ok(Some(
+        (report_notional - cached_notional) / last_qty.as_decimal(),
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashSet, rc::Rc};
+
+    use ant_common::{cache::Cache, clock::TestClock};
+    use ant_core::{UUID4, UnixNanos};
+    use ant_execution::engine::ExecutionEngine;
+    use ant_model::{
+        accounts::CashAccount,
+        data::TradeTick,
+        enums::{
+            AggressorSide, LiquiditySide, OrderSide, OrderStatus, OrderType, PositionSideSpecified,
+            TimeInForce,
+        },
+        identifiers::{
+            AccountId, ClientId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, Venue,
+            VenueOrderId,
+        },
+        instruments::{CurrencyPair, stubs::audusd_sim},
+        orders::{Order, OrderAny, builder::OrderTestBuilder, stubs::TestOrderEventStubs},
+        reports::{ExecutionMassStatus, FillReport, OrderStatusReport, PositionStatusReport},
+        types::{Money, Price, Quantity},
+    };
+    use rstest::*;
+    use ustr::Ustr;
+
+    use super::*;
+
+    struct TestContext {
+        cache: Rc<RefCell<Cache>>,
+        exec_engine: ExecutionEngine,
+        reconciler: ExecutionReconciler,
+        instrument: CurrencyPair,
+    }
+
+    fn context(config: LiveExecEngineConfig) -> TestContext {
+        let clock: Rc<RefCell<dyn Clock>> = Rc::new(RefCell::new(TestClock::new()));
+        let cache = Rc::new(RefCell::new(Cache::default()));
+        let instrument = audusd_sim();
+        cache
+            .borrow_mut()
+            .add_instrument(instrument.into())
+            .unwrap();
+        cache
+            .borrow_mut()
+            .add_account(CashAccount::default().into())
+            .unwrap();
+
+        let exec_engine = ExecutionEngine::new(clock.clone(), cache.clone(), None);
+        let reconciler =
+            ExecutionReconciler::new(TraderId::from("TRADER-001"), clock, cache.clone(), config);
+
+        TestContext {
+            cache,
+            exec_engine,
+            reconciler,
+            instrument,
+        }
+    }
+
+    #[fixture]
+    fn ctx() -> TestContext {
+        context(LiveExecEngineConfig::default())
+    }
+
+    fn client_id() -> ClientId {
+        ClientId::from("SIM")
+    }
+
+    fn account_id() -> AccountId {
+        AccountId::from("SIM-001")
+    }
+
+    fn order_report(
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        order_status: OrderStatus,
+        filled_qty: &str,
+    ) -> OrderStatusReport {
+        OrderStatusReport::new(
+            account_id(),
+            instrument_id,
+            client_order_id,
+            VenueOrderId::from("V-1"),
+            OrderSide::Buy,
+            OrderType::Limit,
+            TimeInForce::Gtc,
+            order_status,
+            Quantity::from("100000"),
+            Quantity::from(filled_qty),
+            UnixNanos::from(1_000),
+            UnixNanos::from(2_000),
+            UnixNanos::from(3_000),
+            None,
+        )
+        .with_price(Price::from("1.00000"))
+    }
+
+    fn fill_report(instrument_id: InstrumentId, trade_id: &str, last_qty: &str) -> FillReport {
+        FillReport::new(
+            account_id(),
+            instrument_id,
+            VenueOrderId::from("V-1"),
+            TradeId::new(trade_id),
+            OrderSide::Buy,
+            Quantity::from(last_qty),
+            Price::from("1.00000"),
+            Money::from("2 USD"),
+            LiquiditySide::Maker,
+            None,
+            None,
+            UnixNanos::from(2_000),
+            UnixNanos::from(3_000),
+            None,
+        )
+    }
+
+    fn submitted_order(ctx: &TestContext) -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(ctx.instrument.id)
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from("100000"))
+            .build();
+        ctx.cache
+            .borrow_mut()
+            .add_order(order.clone(), None, Some(client_id()), false)
+            .unwrap();
+        ctx.exec_engine
+            .process(&TestOrderEventStubs::submitted(&order, account_id()));
+        order
+    }
+
+    fn cached_order(ctx: &TestContext, client_order_id: &str) -> OrderAny {
+        ctx.cache
+            .borrow()
+            .order(&ClientOrderId::from(client_order_id))
+            .cloned()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn test_accepted_report_accepts_submitted_order(ctx: TestContext) {
+        let order = submitted_order(&ctx);
+        let report = order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            OrderStatus::Accepted,
+            "0",
+        );
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &[], client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        let order = cached_order(&ctx, "O-1");
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.venue_order_id(), Some(VenueOrderId::from("V-1")));
+    }
+
+    #[rstest]
+    fn test_fill_reports_are_applied_once(ctx: TestContext) {
+        let order = submitted_order(&ctx);
+        let report = order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            OrderStatus::Filled,
+            "100000",
+        );
+        let fills = [
+            fill_report(ctx.instrument.id, "T-1", "40000"),
+            fill_report(ctx.instrument.id, "T-2", "60000"),
+        ];
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &fills, client_id(), &ctx.exec_engine)
+            .unwrap();
+        ctx.reconciler
+            .reconcile_order_report(&report, &fills, client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        let order = cached_order(&ctx, "O-1");
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from("100000"));
+        assert_eq!(order.trade_ids().len(), 2);
+    }
+
+    #[rstest]
+    fn test_missing_fill_is_inferred_from_average_price(ctx: TestContext) {
+        let order = submitted_order(&ctx);
+        let report = order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            OrderStatus::PartiallyFilled,
+            "100000",
+        )
+        .with_avg_px(1.00010);
+        let fills = [fill_report(ctx.instrument.id, "T-1", "50000")];
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &fills, client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        // Reported 100,000 @ 1.00010 with 50,000 @ 1.00000 known leaves 50,000 @ 1.00020
+        let order = cached_order(&ctx, "O-1");
+        assert_eq!(order.filled_qty(), Quantity::from("100000"));
+        assert_eq!(order.trade_ids().len(), 2);
+        assert_eq!(order.avg_px(), Some(1.00010));
+        let OrderEventAny::Filled(inferred) = order.last_event() else {
+            panic!("Expected inferred fill, was {}", order.last_event());
+        };
+        assert_eq!(inferred.last_qty, Quantity::from("50000"));
+        assert_eq!(inferred.last_px, Price::from("1.00020"));
+        assert!(inferred.reconciliation);
+    }
+
+    #[rstest]
+    fn test_reported_filled_qty_less_than_cached_errors(ctx: TestContext) {
+        let order = submitted_order(&ctx);
+        let fills = [fill_report(ctx.instrument.id, "T-1", "50000")];
+        let report = order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            OrderStatus::PartiallyFilled,
+            "50000",
+        );
+        ctx.reconciler
+            .reconcile_order_report(&report, &fills, client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        let report = order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            OrderStatus::Accepted,
+            "0",
+        );
+        let result =
+            ctx.reconciler
+                .reconcile_order_report(&report, &[], client_id(), &ctx.exec_engine);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Canceled)]
+    #[case(OrderStatus::Expired)]
+    #[case(OrderStatus::Rejected)]
+    fn test_closed_report_closes_order(ctx: TestContext, #[case] status: OrderStatus) {
+        let order = submitted_order(&ctx);
+        let report = order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            status,
+            "0",
+        );
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &[], client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        assert_eq!(cached_order(&ctx, "O-1").status(), status);
+    }
+
+    #[rstest]
+    fn test_unknown_order_generates_external_order(ctx: TestContext) {
+        let report = order_report(ctx.instrument.id, None, OrderStatus::Accepted, "0");
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &[], client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        let order = cached_order(&ctx, "V-1");
+        assert_eq!(order.status(), OrderStatus::Accepted);
+        assert_eq!(order.strategy_id(), StrategyId::external());
+        assert_eq!(order.tags(), Some([Ustr::from("EXTERNAL")].as_slice()));
+    }
+
+    #[rstest]
+    fn test_external_order_assigned_to_claiming_strategy(mut ctx: TestContext) {
+        let strategy_id = StrategyId::from("S-001");
+        ctx.exec_engine
+            .register_external_order_claims(strategy_id, &HashSet::from([ctx.instrument.id]))
+            .unwrap();
+        let report = order_report(ctx.instrument.id, None, OrderStatus::Accepted, "0");
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &[], client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        assert_eq!(cached_order(&ctx, "V-1").strategy_id(), strategy_id);
+    }
+
+    #[rstest]
+    fn test_unclaimed_external_order_filtered() {
+        let ctx = context(LiveExecEngineConfig {
+            filter_unclaimed_external_orders: true,
+            ..Default::default()
+        });
+        let report = order_report(ctx.instrument.id, None, OrderStatus::Accepted, "0");
+
+        ctx.reconciler
+            .reconcile_order_report(&report, &[], client_id(), &ctx.exec_engine)
+            .unwrap();
+
+        assert!(ctx.cache.borrow().orders(None, None, None, None).is_empty());
+    }
+
+    #[rstest]
+    fn test_mass_status_reconciles_orders_and_fills(ctx: TestContext) {
+        let order = submitted_order(&ctx);
+        let mut mass_status = ExecutionMassStatus::new(
+            client_id(),
+            account_id(),
+            Venue::from("SIM"),
+            UnixNanos::from(3_000),
+            None,
+        );
+        mass_status.add_order_reports(vec![order_report(
+            ctx.instrument.id,
+            Some(order.client_order_id()),
+            OrderStatus::Filled,
+            "100000",
+        )]);
+        mass_status.add_fill_reports(vec![fill_report(ctx.instrument.id, "T-1", "100000")]);
+
+        ctx.reconciler
+            .reconcile_mass_status(&mass_status, &ctx.exec_engine)
+            .unwrap();
+
+        let order = cached_order(&ctx, "O-1");
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(
+            ctx.cache
+                .borrow()
+                .positions_open(None, Some(&ctx.instrument.id), None, None)
+                .len(),
+            1
+        );
+    }
+
+    #[rstest]
+    fn test_position_mismatch_errors_without_generating_orders() {
+        let ctx = context(LiveExecEngineConfig {
+            generate_missing_orders: false,
+            ..Default::default()
+        });
+        let mut mass_status = ExecutionMassStatus::new(
+            client_id(),
+            account_id(),
+            Venue::from("SIM"),
+            UnixNanos::from(3_000),
+            None,
+        );
+        mass_status.add_position_reports(vec![PositionStatusReport::new(
+            account_id(),
+            ctx.instrument.id,
+            PositionSideSpecified::Long,
+            Quantity::from("100000"),
+            None,
+            UnixNanos::from(3_000),
+            UnixNanos::from(3_000),
+            None,
+        )]);
+
+        let result = ctx
+            .reconciler
+            .reconcile_mass_status(&mass_status, &ctx.exec_engine);
+
+        assert!(result.is_err());
+        assert!(ctx.cache.borrow().orders(None, None, None, None).is_empty());
+    }
+
+    #[rstest]
+    fn test_position_mismatch_generates_reconciliation_order(ctx: TestContext) {
+        let trade = TradeTick::new(
+            ctx.instrument.id,
+            Price::from("1.00050"),
+            Quantity::from("1000"),
+            AggressorSide::Buyer,
+            TradeId::new("T-0"),
+            UnixNanos::from(1_000),
+            UnixNanos::from(1_000),
+        );
+        ctx.cache.borrow_mut().add_trade(trade).unwrap();
+        let mut mass_status = ExecutionMassStatus::new(
+            client_id(),
+            account_id(),
+            Venue::from("SIM"),
+            UnixNanos::from(3_000),
+            Some(UUID4::new()),
+        );
+        mass_status.add_position_reports(vec![PositionStatusReport::new(
+            account_id(),
+            ctx.instrument.id,
+            PositionSideSpecified::Short,
+            Quantity::from("100000"),
+            None,
+            UnixNanos::from(3_000),
+            UnixNanos::from(3_000),
+            None,
+        )]);
+
+        ctx.reconciler
+            .reconcile_mass_status(&mass_status, &ctx.exec_engine)
+            .unwrap();
+
+        let cache = ctx.cache.borrow();
+        let orders = cache.orders(None, None, None, None);
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].order_side(), OrderSide::Sell);
+        assert_eq!(orders[0].status(), OrderStatus::Filled);
+        assert_eq!(orders[0].avg_px(), Some(1.00050));
+        let positions = cache.positions_open(None, Some(&ctx.instrument.id), None, None);
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].signed_qty, -100_000.0);
+    }
+}
@@ -23,6 +23,7 @@ pub mod deltas;
 pub mod depth;
 pub mod funding;
 pub mod greeks;
+pub mod option_chain;
 pub mod order;
 pub mod prices;
 pub mod quote;
@@ -55,6 +56,7 @@ pub use greeks::{
     BlackScholesGreeksResult, GreeksData, PortfolioGreeks, YieldCurveData, black_scholes_greeks,
     imply_vol_and_greeks,
 };
+pub use option_chain::{OptionChain, OptionChainLeg, OptionSeriesId, OptionStrike, StrikeRange};
 pub use order::{BookOrder, NULL_ORDER};
 pub use prices::{IndexPriceUpdate, MarkPriceUpdate};
 pub use quote::QuoteTick;
@@ -78,6 +80,7 @@ pub enum Data {
     Bar(Bar),
     MarkPriceUpdate(MarkPriceUpdate), // TODO: Rename to MarkPrice once Cython gone
     IndexPriceUpdate(IndexPriceUpdate), // TODO: Rename to IndexPrice once Cython gone
+    FundingRateUpdate(FundingRateUpdate),
     InstrumentClose(InstrumentClose),
 }
 
@@ -114,6 +117,7 @@ impl_try_from_data!(Trade, TradeTick);
 impl_try_from_data!(Bar, Bar);
 impl_try_from_data!(MarkPriceUpdate, MarkPriceUpdate);
 impl_try_from_data!(IndexPriceUpdate, IndexPriceUpdate);
+impl_try_from_data!(FundingRateUpdate, FundingRateUpdate);
 impl_try_from_data!(InstrumentClose, InstrumentClose);
 
 /// Converts a vector of `Data` items to a specific variant type.
@@ -138,6 +142,7 @@ impl Data {
             Self::Bar(bar) => bar.bar_type.instrument_id(),
             Self::MarkPriceUpdate(mark_price) => mark_price.instrument_id,
             Self::IndexPriceUpdate(index_price) => index_price.instrument_id,
+            Self::FundingRateUpdate(funding_rate) => funding_rate.instrument_id,
             Self::InstrumentClose(close) => close.instrument_id,
         }
     }
@@ -169,6 +174,7 @@ impl HasTsInit for Data {
             Self::Bar(b) => b.ts_init,
             Self::MarkPriceUpdate(p) => p.ts_init,
             Self::IndexPriceUpdate(p) => p.ts_init,
+            Self::FundingRateUpdate(f) => f.ts_init,
             Self::InstrumentClose(c) => c.ts_init,
         }
     }
@@ -230,6 +236,12 @@ impl From<IndexPriceUpdate> for Data {
     }
 }
 
+impl From<FundingRateUpdate> for Data {
+    fn from(value: FundingRateUpdate) -> Self {
+        Self::FundingRateUpdate(value)
+    }
+}
+
 impl From<InstrumentClose> for Data {
     fn from(value: InstrumentClose) -> Self {
         Self::InstrumentClose(value)
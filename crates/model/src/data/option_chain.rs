@@ -0,0 +1,729 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An option chain aggregate which tracks the quotes and greeks for every strike of a single
+//! option series (one underlying and expiry on one venue).
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use ahash::AHashMap;
+use ant_core::UnixNanos;
+use chrono::Datelike;
+use implied_vol::{DefaultSpecialFn, SpecialFn};
+use ustr::Ustr;
+
+use super::{GreeksData, HasTsInit, QuoteTick, greeks::imply_vol_and_greeks};
+use crate::{
+    enums::OptionKind,
+    identifiers::{InstrumentId, Venue},
+    instruments::{Instrument, InstrumentEnum},
+    types::Price,
+};
+
+const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
+const DAYS_PER_YEAR: f64 = 365.25;
+
+/// Identifies an option series by venue, underlying and expiration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OptionSeriesId {
+    /// The venue the option series is listed on.
+    pub venue: Venue,
+    /// The underlying asset code for the series.
+    pub underlying: Ustr,
+    /// UNIX timestamp (nanoseconds) for the series expiration.
+    pub expiration_ns: UnixNanos,
+}
+
+impl OptionSeriesId {
+    /// Creates a new [`OptionSeriesId`] instance.
+    #[must_use]
+    pub fn new(venue: Venue, underlying: Ustr, expiration_ns: UnixNanos) -> Self {
+        Self {
+            venue,
+            underlying,
+            expiration_ns,
+        }
+    }
+
+    /// Returns the series the given instrument belongs to, or `None` if it is not a single
+    /// leg option with an underlying, strike and expiration.
+    #[must_use]
+    pub fn from_instrument(instrument: &InstrumentEnum) -> Option<Self> {
+        if instrument.is_spread() {
+            return None;
+        }
+        instrument.option_kind()?;
+        instrument.strike_price()?;
+
+        Some(Self::new(
+            instrument.venue(),
+            instrument.underlying()?,
+            instrument.expiration_ns()?,
+        ))
+    }
+}
+
+impl Display for OptionSeriesId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}",
+            self.venue, self.underlying, self.expiration_ns
+        )
+    }
+}
+
+/// The strikes of an option series to include in an option chain.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StrikeRange {
+    /// Every listed strike.
+    #[default]
+    All,
+    /// Strikes within the inclusive range `[min, max]`.
+    Between { min: Price, max: Price },
+}
+
+impl StrikeRange {
+    /// Returns whether the given strike falls within the range.
+    #[must_use]
+    pub fn contains(&self, strike: &Price) -> bool {
+        match self {
+            Self::All => true,
+            Self::Between { min, max } => strike >= min && strike <= max,
+        }
+    }
+}
+
+/// A single call or put leg of an option chain strike.
+#[derive(Clone, Debug)]
+pub struct OptionChainLeg {
+    /// The option instrument ID for the leg.
+    pub instrument_id: InstrumentId,
+    /// The latest quote for the leg.
+    pub quote: Option<QuoteTick>,
+    /// The latest greeks for the leg.
+    pub greeks: Option<GreeksData>,
+}
+
+impl OptionChainLeg {
+    fn new(instrument_id: InstrumentId) -> Self {
+        Self {
+            instrument_id,
+            quote: None,
+            greeks: None,
+        }
+    }
+
+    /// Returns the mid price of the latest quote, if the quote has a two-sided market.
+    #[must_use]
+    pub fn mid_price(&self) -> Option<f64> {
+        let quote = self.quote.as_ref()?;
+        let ask = quote.ask_price.as_f64();
+        if ask <= 0.0 {
+            return None;
+        }
+        Some((quote.bid_price.as_f64() + ask) / 2.0)
+    }
+}
+
+/// The call and put legs listed at a single strike.
+#[derive(Clone, Debug)]
+pub struct OptionStrike {
+    /// The strike price.
+    pub strike: Price,
+    /// The call leg at the strike, if listed.
+    pub call: Option<OptionChainLeg>,
+    /// The put leg at the strike, if listed.
+    pub put: Option<OptionChainLeg>,
+}
+
+impl OptionStrike {
+    fn new(strike: Price) -> Self {
+        Self {
+            strike,
+            call: None,
+            put: None,
+        }
+    }
+
+    fn leg(&self, kind: OptionKind) -> Option<&OptionChainLeg> {
+        match kind {
+            OptionKind::Call => self.call.as_ref(),
+            OptionKind::Put => self.put.as_ref(),
+        }
+    }
+
+    fn leg_mut(&mut self, kind: OptionKind) -> Option<&mut OptionChainLeg> {
+        match kind {
+            OptionKind::Call => self.call.as_mut(),
+            OptionKind::Put => self.put.as_mut(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct LegKey {
+    strike: Price,
+    kind: OptionKind,
+    multiplier: f64,
+}
+
+/// Represents an option chain for a single option series.
+///
+/// The chain holds the call and put legs for every strike, keeps each leg's latest quote,
+/// and derives the implied forward (from put-call parity) and Black-76 greeks as quotes
+/// arrive.
+#[derive(Clone, Debug)]
+pub struct OptionChain {
+    /// The option series for the chain.
+    pub series_id: OptionSeriesId,
+    /// UNIX timestamp (nanoseconds) when the latest update occurred.
+    pub ts_event: UnixNanos,
+    /// UNIX timestamp (nanoseconds) when the latest update was initialized.
+    pub ts_init: UnixNanos,
+    strikes: BTreeMap<Price, OptionStrike>,
+    index: AHashMap<InstrumentId, LegKey>,
+    is_inverse: bool,
+    forward_price: Option<f64>,
+}
+
+impl OptionChain {
+    /// Creates a new empty [`OptionChain`] instance.
+    #[must_use]
+    pub fn new(series_id: OptionSeriesId) -> Self {
+        Self {
+            series_id,
+            ts_event: UnixNanos::default(),
+            ts_init: UnixNanos::default(),
+            strikes: BTreeMap::new(),
+            index: AHashMap::new(),
+            is_inverse: false,
+            forward_price: None,
+        }
+    }
+
+    /// Adds the given option instrument as a leg of the chain.
+    ///
+    /// Returns `false` if the instrument is not part of the chain's series or is already added.
+    pub fn add_instrument(&mut self, instrument: &InstrumentEnum) -> bool {
+        if OptionSeriesId::from_instrument(instrument) != Some(self.series_id) {
+            return false;
+        }
+
+        let instrument_id = instrument.id();
+        if self.index.contains_key(&instrument_id) {
+            return false;
+        }
+
+        // Guaranteed by the series check above
+        let kind = instrument.option_kind().unwrap();
+        let strike = instrument.strike_price().unwrap();
+
+        let entry = self
+            .strikes
+            .entry(strike)
+            .or_insert_with(|| OptionStrike::new(strike));
+        let leg = Some(OptionChainLeg::new(instrument_id));
+        match kind {
+            OptionKind::Call => entry.call = leg,
+            OptionKind::Put => entry.put = leg,
+        }
+
+        self.is_inverse = instrument.is_inverse();
+        self.index.insert(
+            instrument_id,
+            LegKey {
+                strike,
+                kind,
+                multiplier: instrument.multiplier().as_f64(),
+            },
+        );
+        true
+    }
+
+    /// Returns whether the given instrument is a leg of the chain.
+    #[must_use]
+    pub fn contains(&self, instrument_id: &InstrumentId) -> bool {
+        self.index.contains_key(instrument_id)
+    }
+
+    /// Returns the instrument IDs of every leg in the chain.
+    #[must_use]
+    pub fn instrument_ids(&self) -> Vec<InstrumentId> {
+        self.index.keys().copied().collect()
+    }
+
+    /// Returns the strikes of the chain in ascending order.
+    pub fn strikes(&self) -> impl Iterator<Item = &OptionStrike> {
+        self.strikes.values()
+    }
+
+    /// Returns the legs listed at the given strike.
+    #[must_use]
+    pub fn strike(&self, strike: &Price) -> Option<&OptionStrike> {
+        self.strikes.get(strike)
+    }
+
+    /// Returns the leg for the given instrument.
+    #[must_use]
+    pub fn leg(&self, instrument_id: &InstrumentId) -> Option<&OptionChainLeg> {
+        let key = self.index.get(instrument_id)?;
+        self.strikes.get(&key.strike)?.leg(key.kind)
+    }
+
+    /// Returns the number of strikes in the chain.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strikes.len()
+    }
+
+    /// Returns whether the chain has no strikes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strikes.is_empty()
+    }
+
+    /// Returns the forward price implied by put-call parity, if available.
+    #[must_use]
+    pub fn forward_price(&self) -> Option<f64> {
+        self.forward_price
+    }
+
+    /// Returns the strike closest to the implied forward price, if available.
+    #[must_use]
+    pub fn atm_strike(&self) -> Option<Price> {
+        let forward = self.forward_price?;
+        self.strikes
+            .keys()
+            .min_by(|a, b| {
+                (a.as_f64() - forward)
+                    .abs()
+                    .total_cmp(&(b.as_f64() - forward).abs())
+            })
+            .copied()
+    }
+
+    /// Updates the chain with the given quote and recomputes greeks.
+    ///
+    /// Greeks are recomputed for the quoted leg, or for every leg if the implied forward changed.
+    /// Returns `false` if the quote is not for a leg of the chain.
+    pub fn update_quote(&mut self, quote: &QuoteTick) -> bool {
+        let Some(key) = self.index.get(&quote.instrument_id).copied() else {
+            return false;
+        };
+
+        if let Some(leg) = self
+            .strikes
+            .get_mut(&key.strike)
+            .and_then(|s| s.leg_mut(key.kind))
+        {
+            leg.quote = Some(*quote);
+        }
+        self.ts_event = quote.ts_event;
+        self.ts_init = quote.ts_init;
+
+        let forward = self.compute_forward_price();
+        if forward != self.forward_price {
+            self.forward_price = forward;
+            let keys: Vec<LegKey> = self.index.values().copied().collect();
+            for key in keys {
+                self.refresh_greeks(key);
+            }
+        } else {
+            self.refresh_greeks(key);
+        }
+        true
+    }
+
+    /// Overrides the greeks for a leg of the chain, e.g. with greeks published by the venue.
+    ///
+    /// Returns `false` if the greeks are not for a leg of the chain.
+    pub fn update_greeks(&mut self, greeks: GreeksData) -> bool {
+        let Some(key) = self.index.get(&greeks.instrument_id).copied() else {
+            return false;
+        };
+
+        match self
+            .strikes
+            .get_mut(&key.strike)
+            .and_then(|s| s.leg_mut(key.kind))
+        {
+            Some(leg) => {
+                leg.greeks = Some(greeks);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Uses the strike with the smallest call/put premium difference, where parity is
+    // least sensitive to the quoted spreads: F = K + C - P (or K / (1 - (c - p)) when the
+    // premiums are quoted in the underlying).
+    fn compute_forward_price(&self) -> Option<f64> {
+        self.strikes
+            .values()
+            .filter_map(|s| {
+                let call = s.call.as_ref()?.mid_price()?;
+                let put = s.put.as_ref()?.mid_price()?;
+                Some((s.strike.as_f64(), call - put))
+            })
+            .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .and_then(|(strike, diff)| {
+                let forward = if self.is_inverse {
+                    strike / (1.0 - diff)
+                } else {
+                    strike + diff
+                };
+                (forward.is_finite() && forward > 0.0).then_some(forward)
+            })
+    }
+
+    fn refresh_greeks(&mut self, key: LegKey) {
+        let forward = self.forward_price;
+        let expiration_ns = self.series_id.expiration_ns;
+        let is_inverse = self.is_inverse;
+        let ts_event = self.ts_event;
+        let ts_init = self.ts_init;
+
+        let Some(leg) = self
+            .strikes
+            .get_mut(&key.strike)
+            .and_then(|s| s.leg_mut(key.kind))
+        else {
+            return;
+        };
+
+        leg.greeks = forward.and_then(|forward| {
+            compute_greeks(
+                leg,
+                key,
+                forward,
+                is_inverse,
+                expiration_ns,
+                ts_event,
+                ts_init,
+            )
+        });
+    }
+}
+
+impl HasTsInit for OptionChain {
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+}
+
+impl Display for OptionChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OptionChain(series_id={}, strikes={}, forward_price={:?}, ts_event={})",
+            self.series_id,
+            self.strikes.len(),
+            self.forward_price,
+            self.ts_event
+        )
+    }
+}
+
+// Black-76 greeks on the implied forward, with zero rates since the forward already
+// carries the cost of carry.
+fn compute_greeks(
+    leg: &OptionChainLeg,
+    key: LegKey,
+    forward: f64,
+    is_inverse: bool,
+    expiration_ns: UnixNanos,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> Option<GreeksData> {
+    let days = expiration_ns.as_u64().saturating_sub(ts_event.as_u64()) as f64 / NANOS_PER_DAY;
+    let t = days / DAYS_PER_YEAR;
+    if t <= 0.0 {
+        return None;
+    }
+
+    let mid = leg.mid_price()?;
+    let premium = if is_inverse { mid * forward } else { mid };
+    if premium <= 0.0 {
+        return None;
+    }
+
+    let is_call = key.kind == OptionKind::Call;
+    let strike = key.strike.as_f64();
+    let result = imply_vol_and_greeks(
+        forward,
+        0.0,
+        0.0,
+        is_call,
+        strike,
+        t,
+        premium,
+        key.multiplier,
+    );
+    if !result.vol.is_finite() || result.vol <= 0.0 {
+        return None;
+    }
+
+    let phi = if is_call { 1.0 } else { -1.0 };
+    let scaled_vol = result.vol * t.sqrt();
+    let d2 = (forward / strike).ln() / scaled_vol - 0.5 * scaled_vol;
+    let itm_prob = DefaultSpecialFn::norm_cdf(phi * d2);
+
+    let expiry_date = expiration_ns.to_datetime_utc();
+    let expiry =
+        expiry_date.year() * 10_000 + expiry_date.month() as i32 * 100 + expiry_date.day() as i32;
+
+    Some(GreeksData::new(
+        ts_init,
+        ts_event,
+        leg.instrument_id,
+        is_call,
+        strike,
+        expiry,
+        days.ceil() as i32,
+        t,
+        key.multiplier,
+        1.0,
+        forward,
+        0.0,
+        0.0,
+        result.vol,
+        0.0,
+        result.price,
+        result.delta,
+        result.gamma,
+        result.vega,
+        result.theta,
+        itm_prob,
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        enums::AssetClass,
+        identifiers::Symbol,
+        instruments::{OptionContract, stubs::equity_aapl},
+        types::{Currency, Quantity},
+    };
+
+    fn expiration() -> UnixNanos {
+        let dt = Utc.with_ymd_and_hms(2021, 12, 17, 0, 0, 0).unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    // Three months before expiration
+    fn ts_quote() -> UnixNanos {
+        let dt = Utc.with_ymd_and_hms(2021, 9, 17, 0, 0, 0).unwrap();
+        UnixNanos::from(dt.timestamp_nanos_opt().unwrap() as u64)
+    }
+
+    fn option(kind: OptionKind, strike: &str) -> InstrumentEnum {
+        let code = match kind {
+            OptionKind::Call => "C",
+            OptionKind::Put => "P",
+        };
+        let symbol = format!("AAPL211217{code}{strike}");
+        InstrumentEnum::OptionContract(OptionContract::new(
+            InstrumentId::from(format!("{symbol}.OPRA").as_str()),
+            Symbol::from(symbol.as_str()),
+            AssetClass::Equity,
+            None,
+            Ustr::from("AAPL"),
+            kind,
+            Price::from(format!("{strike}.00").as_str()),
+            Currency::USD(),
+            UnixNanos::default(),
+            expiration(),
+            2,
+            Price::from("0.01"),
+            Quantity::from(100),
+            Quantity::from(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        ))
+    }
+
+    fn quote(instrument: &InstrumentEnum, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            instrument.id(),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(10),
+            Quantity::from(10),
+            ts_quote(),
+            ts_quote(),
+        )
+    }
+
+    #[fixture]
+    fn series_id() -> OptionSeriesId {
+        OptionSeriesId::new(Venue::from("OPRA"), Ustr::from("AAPL"), expiration())
+    }
+
+    #[fixture]
+    fn chain(series_id: OptionSeriesId) -> OptionChain {
+        let mut chain = OptionChain::new(series_id);
+        for strike in ["140", "150", "160"] {
+            chain.add_instrument(&option(OptionKind::Call, strike));
+            chain.add_instrument(&option(OptionKind::Put, strike));
+        }
+        chain
+    }
+
+    #[rstest]
+    fn test_series_id_from_instrument(series_id: OptionSeriesId) {
+        let call = option(OptionKind::Call, "150");
+        let equity = InstrumentEnum::Equity(equity_aapl());
+
+        assert_eq!(OptionSeriesId::from_instrument(&call), Some(series_id));
+        assert_eq!(OptionSeriesId::from_instrument(&equity), None);
+    }
+
+    #[rstest]
+    fn test_strike_range_contains() {
+        let range = StrikeRange::Between {
+            min: Price::from("140.00"),
+            max: Price::from("150.00"),
+        };
+
+        assert!(StrikeRange::All.contains(&Price::from("1000.00")));
+        assert!(range.contains(&Price::from("140.00")));
+        assert!(range.contains(&Price::from("150.00")));
+        assert!(!range.contains(&Price::from("160.00")));
+    }
+
+    #[rstest]
+    fn test_add_instrument(chain: OptionChain) {
+        let mut chain = chain;
+        let strike = chain.strike(&Price::from("150.00")).unwrap();
+
+        assert_eq!(chain.len(), 3);
+        assert_eq!(chain.instrument_ids().len(), 6);
+        assert!(strike.call.is_some());
+        assert!(strike.put.is_some());
+        // Duplicate legs and equities are not added
+        assert!(!chain.add_instrument(&option(OptionKind::Call, "150")));
+        assert!(!chain.add_instrument(&InstrumentEnum::Equity(equity_aapl())));
+    }
+
+    #[rstest]
+    fn test_update_quote_for_unknown_instrument(chain: OptionChain) {
+        let mut chain = chain;
+        let other = option(OptionKind::Call, "170");
+
+        assert!(!chain.update_quote(&quote(&other, "1.00", "1.10")));
+        assert_eq!(chain.ts_event, UnixNanos::default());
+    }
+
+    #[rstest]
+    fn test_forward_price_from_put_call_parity(chain: OptionChain) {
+        let mut chain = chain;
+        let call_140 = option(OptionKind::Call, "140");
+        let put_140 = option(OptionKind::Put, "140");
+        let call_150 = option(OptionKind::Call, "150");
+        let put_150 = option(OptionKind::Put, "150");
+
+        chain.update_quote(&quote(&call_140, "13.75", "14.25"));
+        assert_eq!(chain.forward_price(), None);
+
+        chain.update_quote(&quote(&put_140, "3.75", "4.25"));
+        assert_eq!(chain.forward_price(), Some(150.0));
+
+        // The 151 forward is taken from the 150 strike where |C - P| is smallest
+        chain.update_quote(&quote(&call_150, "6.75", "7.25"));
+        chain.update_quote(&quote(&put_150, "5.75", "6.25"));
+        assert_eq!(chain.forward_price(), Some(151.0));
+        assert_eq!(chain.atm_strike(), Some(Price::from("150.00")));
+        assert_eq!(chain.ts_event, ts_quote());
+    }
+
+    #[rstest]
+    fn test_greeks_computed_on_quotes(chain: OptionChain) {
+        let mut chain = chain;
+        let call = option(OptionKind::Call, "150");
+        let put = option(OptionKind::Put, "150");
+
+        chain.update_quote(&quote(&call, "4.75", "5.25"));
+        assert!(chain.leg(&call.id()).unwrap().greeks.is_none());
+
+        // The put quote sets the forward, so both legs are recomputed
+        chain.update_quote(&quote(&put, "4.75", "5.25"));
+        let call_greeks = chain.leg(&call.id()).unwrap().greeks.clone().unwrap();
+        let put_greeks = chain.leg(&put.id()).unwrap().greeks.clone().unwrap();
+
+        assert_eq!(call_greeks.expiry, 20211217);
+        assert_eq!(call_greeks.expiry_in_days, 91);
+        assert_eq!(call_greeks.underlying_price, 150.0);
+        assert!((call_greeks.vol - put_greeks.vol).abs() < 1e-9);
+        assert!(call_greeks.vol > 0.15 && call_greeks.vol < 0.18);
+        assert!(call_greeks.delta > 0.0 && call_greeks.delta < 100.0);
+        assert!(put_greeks.delta < 0.0 && put_greeks.delta > -100.0);
+        assert!(call_greeks.itm_prob > 0.4 && call_greeks.itm_prob < 0.5);
+        assert!((call_greeks.itm_prob + put_greeks.itm_prob - 1.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_no_greeks_after_expiration(chain: OptionChain) {
+        let mut chain = chain;
+        let call = option(OptionKind::Call, "150");
+        let put = option(OptionKind::Put, "150");
+        let mut call_quote = quote(&call, "4.75", "5.25");
+        let mut put_quote = quote(&put, "4.75", "5.25");
+        call_quote.ts_event = expiration();
+        put_quote.ts_event = expiration();
+
+        chain.update_quote(&call_quote);
+        chain.update_quote(&put_quote);
+
+        assert_eq!(chain.forward_price(), Some(150.0));
+        assert!(chain.leg(&put.id()).unwrap().greeks.is_none());
+    }
+
+    #[rstest]
+    fn test_update_greeks(chain: OptionChain) {
+        let mut chain = chain;
+        let call = option(OptionKind::Call, "160");
+        let greeks = GreeksData::from_delta(call.id(), 0.25, 100.0, ts_quote());
+
+        assert!(chain.update_greeks(greeks));
+        assert_eq!(
+            chain
+                .leg(&call.id())
+                .unwrap()
+                .greeks
+                .as_ref()
+                .unwrap()
+                .delta,
+            0.25
+        );
+    }
+}
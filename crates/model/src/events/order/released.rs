@@ -191,7 +191,7 @@ impl OrderEvent for OrderReleased {
     }
 
     fn price(&self) -> Option<Price> {
-        None
+        Some(self.released_price)
     }
 
     fn last_px(&self) -> Option<Price> {
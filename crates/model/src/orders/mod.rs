@@ -176,6 +176,7 @@ impl OrderStatus {
             (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
             (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
             (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
+            (Self::Initialized, OrderEventAny::Updated(_)) => Self::Initialized,  // Exec algorithm primary orders
             (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
             (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
             (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
@@ -712,6 +713,8 @@ impl OrderCore {
         self.trade_ids.push(event.trade_id);
         self.last_trade_id = Some(event.trade_id);
         self.liquidity_side = Some(event.liquidity_side);
+        // Average over the fills prior to this one, so before the filled quantity is updated
+        self.set_avg_px(event.last_qty, event.last_px);
         self.filled_qty += event.last_qty;
         self.leaves_qty -= event.last_qty;
         self.ts_last = event.ts_event;
@@ -719,8 +722,6 @@ impl OrderCore {
             // Set ts_accepted to time of first fill if not previously set
             self.ts_accepted = Some(event.ts_event);
         }
-
-        self.set_avg_px(event.last_qty, event.last_px);
     }
 
     fn set_avg_px(&mut self, last_qty: Quantity, last_px: Price) {
@@ -834,6 +835,7 @@ mod tests {
             accepted::OrderAcceptedBuilder, canceled::OrderCanceledBuilder,
             denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
             initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            updated::OrderUpdatedBuilder,
         },
         orders::MarketOrder,
     };
@@ -949,6 +951,34 @@ mod tests {
         assert_eq!(order.commissions(), &IndexMap::new());
     }
 
+    #[rstest]
+    fn test_order_avg_px_over_partial_fills() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let submitted = OrderSubmittedBuilder::default().build().unwrap();
+        let accepted = OrderAcceptedBuilder::default().build().unwrap();
+        let fill1 = OrderFilledBuilder::default()
+            .trade_id(TradeId::from("T-1"))
+            .last_qty(Quantity::from(50_000))
+            .last_px(Price::from("1.00000"))
+            .build()
+            .unwrap();
+        let fill2 = OrderFilledBuilder::default()
+            .trade_id(TradeId::from("T-2"))
+            .last_qty(Quantity::from(50_000))
+            .last_px(Price::from("1.00020"))
+            .build()
+            .unwrap();
+
+        order.apply(OrderEventAny::Submitted(submitted)).unwrap();
+        order.apply(OrderEventAny::Accepted(accepted)).unwrap();
+        order.apply(OrderEventAny::Filled(fill1)).unwrap();
+        order.apply(OrderEventAny::Filled(fill2)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100_000));
+        assert_eq!(order.avg_px(), Some(1.00010));
+    }
+
     #[rstest]
     fn test_order_state_transition_to_canceled() {
         let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
@@ -963,6 +993,21 @@ mod tests {
         assert!(!order.is_open());
     }
 
+    #[rstest]
+    fn test_order_state_transition_updated_while_initialized() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+        let updated = OrderUpdatedBuilder::default()
+            .quantity(Quantity::from(40_000))
+            .build()
+            .unwrap();
+
+        order.apply(OrderEventAny::Updated(updated)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.quantity(), Quantity::from(40_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(40_000));
+    }
+
     #[rstest]
     fn test_order_life_cycle_to_partially_filled() {
         let init = OrderInitializedBuilder::default().build().unwrap();
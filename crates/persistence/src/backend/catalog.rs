@@ -79,8 +79,8 @@ use ant_core::{
     datetime::{iso8601_to_unix_nanos, unix_nanos_to_iso8601},
 };
 use ant_model::data::{
-    Bar, Data, HasTsInit, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta, OrderBookDepth10,
-    QuoteTick, TradeTick, close::InstrumentClose, to_variant,
+    Bar, Data, FundingRateUpdate, HasTsInit, IndexPriceUpdate, MarkPriceUpdate, OrderBookDelta,
+    OrderBookDepth10, QuoteTick, TradeTick, close::InstrumentClose, to_variant,
 };
 use ant_serialization::arrow::{DecodeDataFromRecordBatch, EncodeToRecordBatch};
 use object_store::{ObjectStore, path::Path as ObjectPath};
@@ -350,6 +350,7 @@ impl ParquetDataCatalog {
         let mut bars: Vec<Bar> = Vec::new();
         let mut mark_prices: Vec<MarkPriceUpdate> = Vec::new();
         let mut index_prices: Vec<IndexPriceUpdate> = Vec::new();
+        let mut funding_rates: Vec<FundingRateUpdate> = Vec::new();
         let mut closes: Vec<InstrumentClose> = Vec::new();
 
         for d in data.iter().cloned() {
@@ -376,6 +377,9 @@ impl ParquetDataCatalog {
                 Data::IndexPriceUpdate(p) => {
                     index_prices.push(p);
                 }
+                Data::FundingRateUpdate(f) => {
+                    funding_rates.push(f);
+                }
                 Data::InstrumentClose(c) => {
                     closes.push(c);
                 }
@@ -391,6 +395,7 @@ impl ParquetDataCatalog {
         self.write_to_parquet(bars, start, end, None)?;
         self.write_to_parquet(mark_prices, start, end, None)?;
         self.write_to_parquet(index_prices, start, end, None)?;
+        self.write_to_parquet(funding_rates, start, end, None)?;
         self.write_to_parquet(closes, start, end, None)?;
 
         Ok(())
@@ -1673,6 +1678,7 @@ impl_catalog_path_prefix!(OrderBookDepth10, "order_book_depths");
 impl_catalog_path_prefix!(Bar, "bars");
 impl_catalog_path_prefix!(IndexPriceUpdate, "index_prices");
 impl_catalog_path_prefix!(MarkPriceUpdate, "mark_prices");
+impl_catalog_path_prefix!(FundingRateUpdate, "funding_rates");
 impl_catalog_path_prefix!(InstrumentClose, "instrument_closes");
 
 /// Converts timestamps to a filename using ISO 8601 format.
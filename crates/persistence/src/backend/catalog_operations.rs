@@ -489,6 +489,16 @@ impl ParquetDataCatalog {
                             ensure_contiguous_files,
                         )?;
                     }
+                    "funding_rates" => {
+                        use ant_model::data::FundingRateUpdate;
+                        self.consolidate_data_by_period_generic::<FundingRateUpdate>(
+                            identifier,
+                            period_nanos,
+                            start,
+                            end,
+                            ensure_contiguous_files,
+                        )?;
+                    }
                     "instrument_closes" => {
                         use ant_model::data::close::InstrumentClose;
                         self.consolidate_data_by_period_generic::<InstrumentClose>(
@@ -707,6 +717,16 @@ impl ParquetDataCatalog {
                     ensure_contiguous_files,
                 )?;
             }
+            "funding_rates" => {
+                use ant_model::data::FundingRateUpdate;
+                self.consolidate_data_by_period_generic::<FundingRateUpdate>(
+                    identifier,
+                    period_nanos,
+                    start,
+                    end,
+                    ensure_contiguous_files,
+                )?;
+            }
             "instrument_closes" => {
                 use ant_model::data::close::InstrumentClose;
                 self.consolidate_data_by_period_generic::<InstrumentClose>(
@@ -18,9 +18,9 @@ use std::str::FromStr;
 use ant_core::UnixNanos;
 use ant_model::{
     data::{
-        Bar, BarSpecification, BarType, BookOrder, Data, IndexPriceUpdate, MarkPriceUpdate,
-        OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick, depth::DEPTH10_LEN,
-        is_monotonically_increasing_by_init, to_variant,
+        Bar, BarSpecification, BarType, BookOrder, Data, FundingRateUpdate, IndexPriceUpdate,
+        MarkPriceUpdate, OrderBookDelta, OrderBookDepth10, QuoteTick, TradeTick,
+        depth::DEPTH10_LEN, is_monotonically_increasing_by_init, to_variant,
     },
     enums::{AggregationSource, AggressorSide, BarAggregation, BookAction, OrderSide, PriceType},
     identifiers::{InstrumentId, TradeId},
@@ -35,6 +35,7 @@ use ant_testkit::common::get_ant_test_data_file_path;
 #[cfg(target_os = "linux")]
 use procfs::{self, process::Process};
 use rstest::rstest;
+use rust_decimal_macros::dec;
 use tempfile::TempDir;
 
 /// Memory leak test
@@ -406,6 +407,16 @@ fn create_index_price_update(ts_init: u64) -> IndexPriceUpdate {
     )
 }
 
+fn create_funding_rate_update(ts_init: u64) -> FundingRateUpdate {
+    FundingRateUpdate::new(
+        ethusdt_binance_id(),
+        dec!(0.0001),
+        Some(UnixNanos::from(ts_init + 28_800_000_000_000)),
+        UnixNanos::from(0),
+        UnixNanos::from(ts_init),
+    )
+}
+
 // ================================================================================================
 // Rust catalog tests (equivalent to PyO3 tests)
 // ================================================================================================
@@ -752,6 +763,45 @@ fn test_rust_write_index_price_updates() {
     assert!(!files.is_empty());
 }
 
+#[rstest]
+fn test_rust_write_funding_rate_updates() {
+    // Arrange
+    let (_temp_dir, mut catalog) = create_temp_catalog();
+
+    // Act
+    let funding_rates = vec![create_funding_rate_update(1), create_funding_rate_update(2)];
+    catalog
+        .write_data_enum(
+            funding_rates.iter().copied().map(Data::from).collect(),
+            None,
+            None,
+        )
+        .unwrap();
+
+    // Assert
+    let files = catalog
+        .query_files(
+            "funding_rates",
+            Some(vec!["ETH/USDT.BINANCE".to_string()]),
+            None,
+            None,
+        )
+        .unwrap();
+    assert!(!files.is_empty());
+
+    let result = catalog
+        .query_typed_data::<FundingRateUpdate>(
+            Some(vec!["ETH/USDT.BINANCE".to_string()]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(result, funding_rates);
+    assert_eq!(result[1].ts_init, UnixNanos::from(2));
+}
+
 #[rstest]
 fn test_rust_query_files() {
     // Arrange
@@ -19,9 +19,32 @@ use std::collections::HashMap;
 
 use ant_common::throttler::RateLimit;
 use ant_core::datetime::NANOSECONDS_IN_SECOND;
-use ant_model::identifiers::InstrumentId;
+use ant_model::{
+    enums::TradingState,
+    identifiers::{InstrumentId, StrategyId, Venue},
+};
 use rust_decimal::Decimal;
 
+/// A loss limit which switches the `RiskEngine` trading state when breached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LossLimit {
+    /// The maximum loss as a fraction of the reference equity (e.g. 0.05 for 5%).
+    pub max_loss: Decimal,
+    /// The trading state to switch to when the limit is breached.
+    pub trading_state: TradingState,
+}
+
+impl LossLimit {
+    /// Creates a new [`LossLimit`] instance.
+    #[must_use]
+    pub const fn new(max_loss: Decimal, trading_state: TradingState) -> Self {
+        Self {
+            max_loss,
+            trading_state,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 /// Configuration for `RiskEngineConfig` instances.
 pub struct RiskEngineConfig {
@@ -29,6 +52,22 @@ pub struct RiskEngineConfig {
     pub max_order_submit: RateLimit,
     pub max_order_modify: RateLimit,
     pub max_notional_per_order: HashMap<InstrumentId, Decimal>,
+    /// The maximum absolute net position size per instrument, across all strategies.
+    pub max_position_per_instrument: HashMap<InstrumentId, Decimal>,
+    /// The maximum absolute net position size per strategy, for each instrument it trades.
+    pub max_position_per_strategy: HashMap<StrategyId, Decimal>,
+    /// The maximum gross exposure (sum of absolute notionals) per venue and currency.
+    pub max_gross_exposure_per_venue: HashMap<Venue, Decimal>,
+    /// The maximum absolute net exposure (sum of signed notionals) per venue and currency.
+    pub max_net_exposure_per_venue: HashMap<Venue, Decimal>,
+    /// The maximum number of open orders per instrument.
+    pub max_open_orders_per_instrument: HashMap<InstrumentId, usize>,
+    /// The loss limit per venue account, relative to the equity at the start of the UTC day.
+    pub max_daily_loss: HashMap<Venue, LossLimit>,
+    /// The drawdown limit per venue account, relative to the peak equity.
+    pub max_drawdown: HashMap<Venue, LossLimit>,
+    /// The maximum deviation of an order price from the last quote mid price (e.g. 0.1 for 10%).
+    pub max_price_deviation: Option<Decimal>,
     pub debug: bool,
 }
 
@@ -40,6 +79,14 @@ impl Default for RiskEngineConfig {
             max_order_submit: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_order_modify: RateLimit::new(100, NANOSECONDS_IN_SECOND),
             max_notional_per_order: HashMap::new(),
+            max_position_per_instrument: HashMap::new(),
+            max_position_per_strategy: HashMap::new(),
+            max_gross_exposure_per_venue: HashMap::new(),
+            max_net_exposure_per_venue: HashMap::new(),
+            max_open_orders_per_instrument: HashMap::new(),
+            max_daily_loss: HashMap::new(),
+            max_drawdown: HashMap::new(),
+            max_price_deviation: None,
             debug: false,
         }
     }
@@ -20,7 +20,12 @@ pub mod config;
 #[cfg(test)]
 mod tests;
 
-use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    rc::Rc,
+};
 
 use config::RiskEngineConfig;
 use ant_common::{
@@ -31,23 +36,30 @@ use ant_common::{
     msgbus,
     throttler::Throttler,
 };
-use ant_core::UUID4;
+use ant_core::{UUID4, datetime::NANOSECONDS_IN_SECOND};
 use ant_model::{
     accounts::{Account, AccountAny},
-    enums::{InstrumentClass, OrderSide, OrderStatus, TimeInForce, TradingState},
-    events::{OrderDenied, OrderEventAny, OrderModifyRejected},
-    identifiers::InstrumentId,
+    enums::{
+        InstrumentClass, OrderSide, OrderStatus, PositionSide, PriceType, TimeInForce, TradingState,
+    },
+    events::{AccountState, OrderDenied, OrderEventAny, OrderModifyRejected, PositionEvent},
+    identifiers::{InstrumentId, StrategyId, Venue},
     instruments::{Instrument, InstrumentEnum},
     orders::{Order, OrderAny, OrderList},
     types::{Currency, Money, Price, Quantity},
 };
 use ant_portfolio::Portfolio;
-use rust_decimal::{Decimal, prelude::ToPrimitive};
+use rust_decimal::{
+    Decimal,
+    prelude::{FromPrimitive, ToPrimitive},
+};
 use ustr::Ustr;
 
 type SubmitOrderFn = Box<dyn Fn(SubmitOrder)>;
 type ModifyOrderFn = Box<dyn Fn(ModifyOrder)>;
 
+const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
 /// Central risk management engine that validates and controls trading operations.
 ///
 /// The `RiskEngine` provides comprehensive pre-trade risk checks including order validation,
@@ -63,6 +75,8 @@ pub struct RiskEngine {
     pub throttled_modify_order: Throttler<ModifyOrder, ModifyOrderFn>,
     max_notional_per_order: HashMap<InstrumentId, Decimal>,
     trading_state: TradingState,
+    equity_day_start: HashMap<(Venue, Currency), (u64, f64)>,
+    equity_peaks: HashMap<(Venue, Currency), f64>,
     config: RiskEngineConfig,
 }
 
@@ -92,8 +106,10 @@ impl RiskEngine {
             portfolio,
             throttled_submit_order,
             throttled_modify_order,
-            max_notional_per_order: HashMap::new(),
+            max_notional_per_order: config.max_notional_per_order.clone(),
             trading_state: TradingState::Active,
+            equity_day_start: HashMap::new(),
+            equity_peaks: HashMap::new(),
             config,
         }
     }
@@ -267,6 +283,16 @@ impl RiskEngine {
         self.handle_event(event);
     }
 
+    /// Processes an account state update, checking the configured loss limits.
+    pub fn process_account_state(&mut self, _event: &AccountState) {
+        self.check_loss_limits();
+    }
+
+    /// Processes a position event, checking the configured loss limits.
+    pub fn process_position_event(&mut self, _event: &PositionEvent) {
+        self.check_loss_limits();
+    }
+
     /// Sets the trading state for risk control enforcement.
     pub fn set_trading_state(&mut self, state: TradingState) {
         if state == self.trading_state {
@@ -323,6 +349,8 @@ impl RiskEngine {
             return;
         }
 
+        self.check_loss_limits();
+
         let order = &command.order;
         if let Some(position_id) = command.position_id
             && order.is_reduce_only()
@@ -377,6 +405,14 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if !self.check_orders_limits(
+            &instrument,
+            command.strategy_id,
+            std::slice::from_ref(order),
+        ) {
+            return; // Denied
+        }
+
         // Route through execution gateway for TradingState checks & throttling
         self.execution_gateway(instrument, TradingCommand::SubmitOrder(command));
     }
@@ -387,6 +423,8 @@ impl RiskEngine {
             return;
         }
 
+        self.check_loss_limits();
+
         let instrument_exists = {
             let cache = self.cache.borrow();
             cache.instrument(&command.instrument_id).cloned()
@@ -419,6 +457,14 @@ impl RiskEngine {
             return; // Denied
         }
 
+        if !self.check_orders_limits(&instrument, command.strategy_id, &command.order_list.orders) {
+            self.deny_order_list(
+                command.order_list.clone(),
+                &format!("OrderList {} DENIED", command.order_list.id),
+            );
+            return; // Denied
+        }
+
         self.execution_gateway(instrument, TradingCommand::SubmitOrderList(command));
     }
 
@@ -477,14 +523,18 @@ impl RiskEngine {
         };
 
         // Check Price
-        let mut risk_msg = self.check_price(&instrument, command.price);
+        let mut risk_msg = self
+            .check_price(&instrument, command.price)
+            .or_else(|| self.check_price_collar(&instrument, command.price));
         if let Some(risk_msg) = risk_msg {
             self.reject_modify_order(order, &risk_msg);
             return; // Denied
         }
 
         // Check Trigger
-        risk_msg = self.check_price(&instrument, command.trigger_price);
+        risk_msg = self
+            .check_price(&instrument, command.trigger_price)
+            .or_else(|| self.check_price_collar(&instrument, command.trigger_price));
         if let Some(risk_msg) = risk_msg {
             self.reject_modify_order(order, &risk_msg);
             return; // Denied
@@ -555,7 +605,9 @@ impl RiskEngine {
         // CHECK PRICE
         ////////////////////////////////////////////////////////////////////////////////
         if order.price().is_some() {
-            let risk_msg = self.check_price(&instrument, order.price());
+            let risk_msg = self
+                .check_price(&instrument, order.price())
+                .or_else(|| self.check_price_collar(&instrument, order.price()));
             if let Some(risk_msg) = risk_msg {
                 self.deny_order(order, &risk_msg);
                 return false; // Denied
@@ -566,7 +618,9 @@ impl RiskEngine {
         // CHECK TRIGGER
         ////////////////////////////////////////////////////////////////////////////////
         if order.trigger_price().is_some() {
-            let risk_msg = self.check_price(&instrument, order.trigger_price());
+            let risk_msg = self
+                .check_price(&instrument, order.trigger_price())
+                .or_else(|| self.check_price_collar(&instrument, order.trigger_price()));
             if let Some(risk_msg) = risk_msg {
                 self.deny_order(order, &risk_msg);
                 return false; // Denied
@@ -858,6 +912,205 @@ impl RiskEngine {
         true // Passed
     }
 
+    fn check_orders_limits(
+        &self,
+        instrument: &InstrumentEnum,
+        strategy_id: StrategyId,
+        orders: &[OrderAny],
+    ) -> bool {
+        let instrument_id = instrument.id();
+        let venue = instrument_id.venue;
+
+        let max_position_instrument = self
+            .config
+            .max_position_per_instrument
+            .get(&instrument_id)
+            .copied();
+        let max_position_strategy = self
+            .config
+            .max_position_per_strategy
+            .get(&strategy_id)
+            .copied();
+        let max_open_orders = self
+            .config
+            .max_open_orders_per_instrument
+            .get(&instrument_id)
+            .copied();
+        let max_gross_exposure = self
+            .config
+            .max_gross_exposure_per_venue
+            .get(&venue)
+            .copied();
+        let max_net_exposure = self.config.max_net_exposure_per_venue.get(&venue).copied();
+        let check_exposure = max_gross_exposure.is_some() || max_net_exposure.is_some();
+
+        let mut instrument_position = self.net_position(&instrument_id, None);
+        let mut strategy_position = self.net_position(&instrument_id, Some(&strategy_id));
+        let instrument_working = self.working_quantities(instrument, None);
+        let strategy_working = self.working_quantities(instrument, Some(&strategy_id));
+        let mut open_orders =
+            self.cache
+                .borrow()
+                .orders_open_count(None, Some(&instrument_id), None, None);
+        let mut exposures = if check_exposure {
+            self.venue_exposures(&venue)
+        } else {
+            HashMap::new()
+        };
+
+        for order in orders {
+            // Check MAX open orders per instrument limit
+            open_orders += 1;
+            if let Some(max_open_orders) = max_open_orders
+                && open_orders > max_open_orders
+            {
+                self.deny_order(
+                    order.clone(),
+                    &format!(
+                        "OPEN_ORDERS_EXCEEDS_MAX_PER_INSTRUMENT: max_open_orders={max_open_orders}, open_orders={open_orders}"
+                    ),
+                );
+                return false; // Denied
+            }
+
+            let price = self.order_reference_price(instrument, order);
+            let effective_quantity = match price {
+                Some(price) if order.is_quote_quantity() && !instrument.is_inverse() => {
+                    instrument.calculate_base_quantity(order.quantity(), price)
+                }
+                _ => order.quantity(),
+            };
+            let signed_qty = match order.order_side() {
+                OrderSide::Buy => effective_quantity.as_decimal(),
+                OrderSide::Sell => -effective_quantity.as_decimal(),
+                OrderSide::NoOrderSide => {
+                    panic!("invalid `OrderSide`, was {}", order.order_side());
+                }
+            };
+
+            // Working orders on the same side are assumed to fill as well
+            let (instrument_working, strategy_working) = match order.order_side() {
+                OrderSide::Buy => (instrument_working.0, strategy_working.0),
+                _ => (-instrument_working.1, -strategy_working.1),
+            };
+
+            // Check MAX position per instrument limit
+            let position_before = instrument_position;
+            instrument_position += signed_qty;
+            let projected_before = position_before + instrument_working;
+            let projected = instrument_position + instrument_working;
+            if let Some(max_position) = max_position_instrument
+                && projected.abs() > max_position
+                && projected.abs() > projected_before.abs()
+            {
+                self.deny_order(
+                    order.clone(),
+                    &format!(
+                        "POSITION_EXCEEDS_MAX_PER_INSTRUMENT: max_position={max_position}, position={projected}"
+                    ),
+                );
+                return false; // Denied
+            }
+
+            // Check MAX position per strategy limit
+            let strategy_position_before = strategy_position;
+            strategy_position += signed_qty;
+            let projected_before = strategy_position_before + strategy_working;
+            let projected = strategy_position + strategy_working;
+            if let Some(max_position) = max_position_strategy
+                && projected.abs() > max_position
+                && projected.abs() > projected_before.abs()
+            {
+                self.deny_order(
+                    order.clone(),
+                    &format!(
+                        "POSITION_EXCEEDS_MAX_PER_STRATEGY: strategy_id={strategy_id}, max_position={max_position}, position={projected}"
+                    ),
+                );
+                return false; // Denied
+            }
+
+            if !check_exposure {
+                continue;
+            }
+
+            let Some(price) = price else {
+                log::warn!("Cannot check order exposure: no prices for {instrument_id}");
+                continue;
+            };
+
+            let notional = instrument.calculate_notional_value(effective_quantity, price, None);
+            if effective_quantity.as_decimal() == Decimal::ZERO {
+                continue;
+            }
+            let unit_notional = notional.as_decimal() / effective_quantity.as_decimal();
+            let (gross, net) = exposures
+                .entry(notional.currency)
+                .or_insert((Decimal::ZERO, Decimal::ZERO));
+
+            let gross_before = *gross;
+            let net_before = *net;
+            *gross += (instrument_position.abs() - position_before.abs()) * unit_notional;
+            *net += signed_qty * unit_notional;
+
+            // Check MAX gross exposure per venue limit
+            if let Some(max_exposure) = max_gross_exposure
+                && *gross > max_exposure
+                && *gross > gross_before
+            {
+                let reason = format!(
+                    "GROSS_EXPOSURE_EXCEEDS_MAX_PER_VENUE: venue={venue}, max_exposure={max_exposure}, exposure={gross} {}",
+                    notional.currency
+                );
+                self.deny_order(order.clone(), &reason);
+                return false; // Denied
+            }
+
+            // Check MAX net exposure per venue limit
+            if let Some(max_exposure) = max_net_exposure
+                && net.abs() > max_exposure
+                && net.abs() > net_before.abs()
+            {
+                let reason = format!(
+                    "NET_EXPOSURE_EXCEEDS_MAX_PER_VENUE: venue={venue}, max_exposure={max_exposure}, exposure={net} {}",
+                    notional.currency
+                );
+                self.deny_order(order.clone(), &reason);
+                return false; // Denied
+            }
+        }
+
+        true // Passed
+    }
+
+    fn check_price_collar(
+        &self,
+        instrument: &InstrumentEnum,
+        price: Option<Price>,
+    ) -> Option<String> {
+        let max_deviation = self.config.max_price_deviation?;
+        let price_val = price?;
+        let reference = self
+            .cache
+            .borrow()
+            .price(&instrument.id(), PriceType::Mid)?;
+
+        if reference.raw <= 0 {
+            return None; // Cannot calculate a relative deviation
+        }
+
+        let deviation =
+            ((price_val.as_decimal() - reference.as_decimal()) / reference.as_decimal()).abs();
+        if deviation > max_deviation {
+            return Some(format!(
+                "PRICE_OUTSIDE_COLLAR: price={price_val}, reference={reference}, deviation={}, max_deviation={max_deviation}",
+                deviation.round_dp(6)
+            ));
+        }
+
+        None
+    }
+
     fn check_price(&self, instrument: &InstrumentEnum, price: Option<Price>) -> Option<String> {
         let price_val = price?;
 
@@ -915,6 +1168,250 @@ impl RiskEngine {
         None
     }
 
+    fn check_loss_limits(&mut self) {
+        if self.config.max_daily_loss.is_empty() && self.config.max_drawdown.is_empty() {
+            return;
+        }
+
+        let day = self.clock.borrow().timestamp_ns().as_u64() / NANOSECONDS_IN_DAY;
+        let venues: HashSet<Venue> = self
+            .config
+            .max_daily_loss
+            .keys()
+            .chain(self.config.max_drawdown.keys())
+            .copied()
+            .collect();
+
+        let mut breaches: Vec<(TradingState, String)> = Vec::new();
+        for venue in venues {
+            let balances = {
+                let cache = self.cache.borrow();
+                match cache.account_for_venue(&venue) {
+                    Some(account) => account.balances_total(),
+                    None => continue,
+                }
+            };
+            let unrealized_pnls = self.portfolio.unrealized_pnls(&venue);
+
+            for (currency, balance) in balances {
+                let equity = balance.as_f64()
+                    + unrealized_pnls
+                        .get(&currency)
+                        .map_or(0.0, |pnl| pnl.as_f64());
+
+                let day_start = self
+                    .equity_day_start
+                    .entry((venue, currency))
+                    .or_insert((day, equity));
+                if day_start.0 != day {
+                    *day_start = (day, equity);
+                }
+                let equity_day_start = day_start.1;
+
+                let peak = self.equity_peaks.entry((venue, currency)).or_insert(equity);
+                *peak = peak.max(equity);
+                let equity_peak = *peak;
+
+                if let Some(limit) = self.config.max_daily_loss.get(&venue)
+                    && let Some(loss) = loss_fraction(equity_day_start, equity)
+                    && loss > limit.max_loss
+                {
+                    breaches.push((
+                        limit.trading_state,
+                        format!(
+                            "DAILY_LOSS_LIMIT_BREACHED: venue={venue}, currency={currency}, loss={}, max_loss={}",
+                            loss.round_dp(6),
+                            limit.max_loss
+                        ),
+                    ));
+                }
+
+                if let Some(limit) = self.config.max_drawdown.get(&venue)
+                    && let Some(drawdown) = loss_fraction(equity_peak, equity)
+                    && drawdown > limit.max_loss
+                {
+                    breaches.push((
+                        limit.trading_state,
+                        format!(
+                            "MAX_DRAWDOWN_LIMIT_BREACHED: venue={venue}, currency={currency}, drawdown={}, max_drawdown={}",
+                            drawdown.round_dp(6),
+                            limit.max_loss
+                        ),
+                    ));
+                }
+            }
+        }
+
+        for (trading_state, reason) in breaches {
+            // Loss limits only ever escalate the trading state, resuming is a manual decision
+            if trading_state_severity(trading_state) > trading_state_severity(self.trading_state) {
+                log::warn!("{reason}: switching trading state to {trading_state:?}");
+                self.set_trading_state(trading_state);
+            }
+        }
+    }
+
+    fn net_position(
+        &self,
+        instrument_id: &InstrumentId,
+        strategy_id: Option<&StrategyId>,
+    ) -> Decimal {
+        let cache = self.cache.borrow();
+        cache
+            .positions_open(None, Some(instrument_id), strategy_id, None)
+            .iter()
+            .map(|position| match position.side {
+                PositionSide::Long => position.quantity.as_decimal(),
+                PositionSide::Short => -position.quantity.as_decimal(),
+                _ => Decimal::ZERO,
+            })
+            .sum()
+    }
+
+    /// Returns the open buy and sell order quantities for the `instrument`, in base units.
+    fn working_quantities(
+        &self,
+        instrument: &InstrumentEnum,
+        strategy_id: Option<&StrategyId>,
+    ) -> (Decimal, Decimal) {
+        let orders: Vec<OrderAny> = self
+            .cache
+            .borrow()
+            .orders_open(None, Some(&instrument.id()), strategy_id, None)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let mut working = (Decimal::ZERO, Decimal::ZERO);
+        for order in &orders {
+            let quantity = self.order_base_quantity(instrument, order, order.leaves_qty());
+            match order.order_side() {
+                OrderSide::Buy => working.0 += quantity,
+                OrderSide::Sell => working.1 += quantity,
+                OrderSide::NoOrderSide => {}
+            }
+        }
+        working
+    }
+
+    /// Returns the `quantity` of the `order` in base units.
+    fn order_base_quantity(
+        &self,
+        instrument: &InstrumentEnum,
+        order: &OrderAny,
+        quantity: Quantity,
+    ) -> Decimal {
+        match self.order_reference_price(instrument, order) {
+            Some(price) if order.is_quote_quantity() && !instrument.is_inverse() => instrument
+                .calculate_base_quantity(quantity, price)
+                .as_decimal(),
+            _ => quantity.as_decimal(),
+        }
+    }
+
+    /// Returns the gross and net exposures for the `venue` per currency.
+    ///
+    /// Both open positions and the leaves quantity of working orders count towards the
+    /// exposure, so that resting orders cannot be used to build up exposure past the limits.
+    fn venue_exposures(&self, venue: &Venue) -> HashMap<Currency, (Decimal, Decimal)> {
+        let mut exposures = self.venue_position_exposures(venue);
+
+        let orders: Vec<OrderAny> = self
+            .cache
+            .borrow()
+            .orders_open(Some(venue), None, None, None)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        for order in &orders {
+            let Some(instrument) = self.cache.borrow().instrument(&order.instrument_id()).cloned()
+            else {
+                log::error!(
+                    "Cannot calculate exposure: no instrument for {}",
+                    order.instrument_id()
+                );
+                continue;
+            };
+            let Some(price) = self.order_reference_price(&instrument, order) else {
+                log::warn!(
+                    "Cannot calculate exposure: no prices for {}",
+                    order.instrument_id()
+                );
+                continue;
+            };
+
+            let quantity = self.order_base_quantity(&instrument, order, order.leaves_qty());
+            let quantity = Quantity::from_decimal(quantity, instrument.size_precision())
+                .unwrap_or(order.leaves_qty());
+            let notional = instrument.calculate_notional_value(quantity, price, None);
+            let value = notional.as_decimal().abs();
+            let (gross, net) = exposures
+                .entry(notional.currency)
+                .or_insert((Decimal::ZERO, Decimal::ZERO));
+            *gross += value;
+            match order.order_side() {
+                OrderSide::Buy => *net += value,
+                OrderSide::Sell => *net -= value,
+                OrderSide::NoOrderSide => {}
+            }
+        }
+
+        exposures
+    }
+
+    /// Returns the gross and net open position exposures for the `venue` per currency.
+    fn venue_position_exposures(&self, venue: &Venue) -> HashMap<Currency, (Decimal, Decimal)> {
+        let cache = self.cache.borrow();
+        let mut exposures: HashMap<Currency, (Decimal, Decimal)> = HashMap::new();
+
+        for position in cache.positions_open(Some(venue), None, None, None) {
+            let Some(instrument) = cache.instrument(&position.instrument_id) else {
+                log::error!(
+                    "Cannot calculate exposure: no instrument for {}",
+                    position.instrument_id
+                );
+                continue;
+            };
+
+            let price = cache
+                .price(&position.instrument_id, PriceType::Mid)
+                .or_else(|| cache.price(&position.instrument_id, PriceType::Last))
+                .unwrap_or_else(|| Price::new(position.avg_px_open, instrument.price_precision()));
+
+            let notional = instrument.calculate_notional_value(position.quantity, price, None);
+            let value = notional.as_decimal().abs();
+            let (gross, net) = exposures
+                .entry(notional.currency)
+                .or_insert((Decimal::ZERO, Decimal::ZERO));
+            *gross += value;
+            match position.side {
+                PositionSide::Long => *net += value,
+                PositionSide::Short => *net -= value,
+                _ => {}
+            }
+        }
+
+        exposures
+    }
+
+    fn order_reference_price(
+        &self,
+        instrument: &InstrumentEnum,
+        order: &OrderAny,
+    ) -> Option<Price> {
+        order.price().or(order.trigger_price()).or_else(|| {
+            let cache = self.cache.borrow();
+            let price_type = match order.order_side() {
+                OrderSide::Buy => PriceType::Ask,
+                _ => PriceType::Bid,
+            };
+            cache
+                .price(&instrument.id(), price_type)
+                .or_else(|| cache.price(&instrument.id(), PriceType::Last))
+        })
+    }
+
     // -- DENIALS ---------------------------------------------------------------------------------
 
     fn deny_command(&self, command: TradingCommand, reason: &str) {
@@ -1025,6 +1522,11 @@ impl RiskEngine {
                                 instrument.id()
                             ),
                         );
+                    } else {
+                        self.throttled_submit_order.send(SubmitOrder {
+                            order,
+                            ..submit_order
+                        });
                     }
                 }
                 TradingCommand::SubmitOrderList(submit_order_list) => {
@@ -1050,6 +1552,10 @@ impl RiskEngine {
                             return;
                         }
                     }
+                    self.send_to_execution(TradingCommand::SubmitOrderList(SubmitOrderList {
+                        order_list,
+                        ..submit_order_list
+                    }));
                 }
                 _ => {}
             },
@@ -1076,5 +1582,26 @@ impl RiskEngine {
         if self.config.debug {
             log::debug!("{RECV}{EVT} {event:?}");
         }
+
+        if matches!(event, OrderEventAny::Filled(_)) {
+            self.check_loss_limits();
+        }
+    }
+}
+
+/// Returns the loss of `equity` as a fraction of the `reference` equity, if the reference is positive.
+fn loss_fraction(reference: f64, equity: f64) -> Option<Decimal> {
+    if reference <= 0.0 {
+        return None;
+    }
+    Decimal::from_f64((reference - equity) / reference)
+}
+
+/// Returns the relative restrictiveness of the `trading_state`.
+const fn trading_state_severity(trading_state: TradingState) -> u8 {
+    match trading_state {
+        TradingState::Active => 0,
+        TradingState::Reducing => 1,
+        TradingState::Halted => 2,
     }
 }
@@ -54,7 +54,7 @@ use ant_model::{
         CryptoPerpetual, CurrencyPair, Instrument, InstrumentEnum,
         stubs::{audusd_sim, crypto_perpetual_ethusdt, xbtusd_bitmex},
     },
-    orders::{Order, OrderAny, OrderList, OrderTestBuilder},
+    orders::{Order, OrderAny, OrderList, OrderTestBuilder, stubs::TestOrderStubs},
     types::{AccountBalance, Currency, Money, Price, Quantity, fixed::FIXED_PRECISION},
 };
 use ant_portfolio::Portfolio;
@@ -163,6 +163,7 @@ fn test_deny_order_exceeding_max_notional(
         max_order_submit: RateLimit::new(10, 1000),
         max_order_modify: RateLimit::new(5, 1000),
         max_notional_per_order: HashMap::new(),
+        ..RiskEngineConfig::default()
     };
 
     let mut risk_engine = get_risk_engine(
@@ -204,7 +205,10 @@ fn test_deny_order_exceeding_max_notional(
     matches!(saved_events[0], OrderEventAny::Denied(_));
 }
 
-use super::{RiskEngine, config::RiskEngineConfig};
+use super::{
+    RiskEngine,
+    config::{LossLimit, RiskEngineConfig},
+};
 
 #[fixture]
 fn process_order_event_handler() -> ShareableMessageHandler {
@@ -298,6 +302,7 @@ fn config_fixture(
         max_order_submit,
         max_order_modify,
         max_notional_per_order,
+        ..RiskEngineConfig::default()
     }
 }
 
@@ -397,6 +402,7 @@ fn get_risk_engine(
         max_order_submit: RateLimit::new(10, 1000),
         max_order_modify: RateLimit::new(5, 1000),
         max_notional_per_order: HashMap::new(),
+        ..RiskEngineConfig::default()
     });
     let clock = clock.unwrap_or(Rc::new(RefCell::new(TestClock::new())));
     let portfolio = Portfolio::new(cache.clone(), clock.clone(), None);
@@ -3545,3 +3551,394 @@ fn test_submit_order_with_gtd_expire_time_already_passed(
 
     // TODO: Change command messages to not require owned orders
 }
+
+fn get_risk_engine_audusd(config: RiskEngineConfig, instrument: &InstrumentEnum) -> RiskEngine {
+    let mut cache = Cache::default();
+    cache.add_instrument(instrument.clone()).unwrap();
+    cache
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd("1000000 USD", "0 USD", "1000000 USD"),
+        )))
+        .unwrap();
+    cache.add_quote(quote_audusd()).unwrap();
+
+    get_risk_engine(
+        Some(Rc::new(RefCell::new(cache))),
+        Some(config),
+        None,
+        false,
+    )
+}
+
+fn submit_order_audusd(risk_engine: &RiskEngine, order: OrderAny) -> SubmitOrder {
+    SubmitOrder::new(
+        trader_id(),
+        client_id_binance(),
+        strategy_id_ema_cross(),
+        order.instrument_id(),
+        order.client_order_id(),
+        venue_order_id(),
+        order,
+        None,
+        None,
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap()
+}
+
+fn limit_order_audusd(
+    instrument: &InstrumentEnum,
+    side: OrderSide,
+    price: &str,
+    qty: &str,
+) -> OrderAny {
+    OrderTestBuilder::new(OrderType::Limit)
+        .instrument_id(instrument.id())
+        .side(side)
+        .price(Price::from(price))
+        .quantity(Quantity::from(qty))
+        .build()
+}
+
+fn assert_denied_with_code(events: &[OrderEventAny], code: &str) {
+    let denied = events
+        .iter()
+        .find(|event| event.event_type() == OrderEventType::Denied)
+        .expect("expected an OrderDenied event");
+    let reason = denied.message().unwrap();
+    assert!(
+        reason.starts_with(code),
+        "expected reason starting with {code}, was {reason}"
+    );
+}
+
+#[rstest]
+fn test_deny_order_exceeding_max_position_per_instrument(instrument_audusd: InstrumentEnum) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_position_per_instrument: HashMap::from([(
+            instrument_audusd.id(),
+            Decimal::from(1_000),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "100.00000", "2000");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_denied_with_code(&saved_events, "POSITION_EXCEEDS_MAX_PER_INSTRUMENT");
+}
+
+#[rstest]
+fn test_deny_order_exceeding_max_position_per_instrument_with_working_orders(
+    instrument_audusd: InstrumentEnum,
+) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_position_per_instrument: HashMap::from([(
+            instrument_audusd.id(),
+            Decimal::from(1_000),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    // A working buy order for 800 is already resting at the venue
+    let working = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "99.00000", "800");
+    let working = TestOrderStubs::make_accepted_order(&working);
+    {
+        let mut cache = risk_engine.cache.borrow_mut();
+        cache.add_order(working.clone(), None, None, false).unwrap();
+        cache.update_order(&working).unwrap();
+    }
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "100.00000", "500");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_denied_with_code(&saved_events, "POSITION_EXCEEDS_MAX_PER_INSTRUMENT");
+}
+
+#[rstest]
+fn test_deny_order_exceeding_max_position_per_strategy(instrument_audusd: InstrumentEnum) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_position_per_strategy: HashMap::from([(strategy_id_ema_cross(), Decimal::from(1_000))]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Sell, "100.00000", "2000");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_denied_with_code(&saved_events, "POSITION_EXCEEDS_MAX_PER_STRATEGY");
+}
+
+#[rstest]
+fn test_deny_order_list_exceeding_max_open_orders_per_instrument(
+    instrument_audusd: InstrumentEnum,
+) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_open_orders_per_instrument: HashMap::from([(instrument_audusd.id(), 1)]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order_list = OrderList::new(
+        OrderListId::new("1"),
+        instrument_audusd.id(),
+        strategy_id_ema_cross(),
+        ["100.00000", "99.00000"]
+            .into_iter()
+            .map(|price| {
+                OrderTestBuilder::new(OrderType::Limit)
+                    .instrument_id(instrument_audusd.id())
+                    .strategy_id(strategy_id_ema_cross())
+                    .side(OrderSide::Buy)
+                    .price(Price::from(price))
+                    .quantity(Quantity::from("1000"))
+                    .build()
+            })
+            .collect(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    );
+    let submit_order_list = SubmitOrderList::new(
+        trader_id(),
+        client_id_binance(),
+        strategy_id_ema_cross(),
+        instrument_audusd.id(),
+        client_order_id(),
+        venue_order_id(),
+        order_list,
+        None,
+        None,
+        UUID4::new(),
+        risk_engine.clock.borrow().timestamp_ns(),
+    )
+    .unwrap();
+    risk_engine.execute(TradingCommand::SubmitOrderList(submit_order_list));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert!(!saved_events.is_empty());
+    assert_denied_with_code(&saved_events, "OPEN_ORDERS_EXCEEDS_MAX_PER_INSTRUMENT");
+}
+
+#[rstest]
+fn test_deny_order_exceeding_max_gross_exposure_per_venue(instrument_audusd: InstrumentEnum) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_gross_exposure_per_venue: HashMap::from([(
+            instrument_audusd.id().venue,
+            Decimal::from(50_000),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    // Notional 100 * 1,000 = 100,000 USD > 50,000 USD
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "100.00000", "1000");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_denied_with_code(&saved_events, "GROSS_EXPOSURE_EXCEEDS_MAX_PER_VENUE");
+}
+
+#[rstest]
+fn test_deny_order_exceeding_max_net_exposure_per_venue(instrument_audusd: InstrumentEnum) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_net_exposure_per_venue: HashMap::from([(
+            instrument_audusd.id().venue,
+            Decimal::from(50_000),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Sell, "100.00000", "1000");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_denied_with_code(&saved_events, "NET_EXPOSURE_EXCEEDS_MAX_PER_VENUE");
+}
+
+#[rstest]
+fn test_deny_order_outside_price_collar(instrument_audusd: InstrumentEnum) {
+    let process_handler = register_process_handler();
+    let config = RiskEngineConfig {
+        max_price_deviation: Some(Decimal::from_str("0.05").unwrap()),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    // Last quote mid is 100.5, so 120 deviates by ~19%
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "120.00000", "100");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_denied_with_code(&saved_events, "PRICE_OUTSIDE_COLLAR");
+}
+
+#[rstest]
+fn test_submit_order_inside_price_collar_then_sends_to_execution(
+    instrument_audusd: InstrumentEnum,
+    execute_order_event_handler: ShareableMessageHandler,
+) {
+    let process_handler = register_process_handler();
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_execute(),
+        execute_order_event_handler.clone(),
+    );
+    let config = RiskEngineConfig {
+        max_price_deviation: Some(Decimal::from_str("0.05").unwrap()),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "101.00000", "100");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    assert!(get_process_order_event_handler_messages(process_handler).is_empty());
+    assert_eq!(
+        get_execute_order_event_handler_messages(execute_order_event_handler).len(),
+        1
+    );
+}
+
+#[rstest]
+fn test_daily_loss_limit_breach_halts_trading(
+    instrument_audusd: InstrumentEnum,
+    execute_order_event_handler: ShareableMessageHandler,
+) {
+    let process_handler = register_process_handler();
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_execute(),
+        execute_order_event_handler.clone(),
+    );
+    let config = RiskEngineConfig {
+        max_daily_loss: HashMap::from([(
+            instrument_audusd.id().venue,
+            LossLimit::new(Decimal::from_str("0.05").unwrap(), TradingState::Halted),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "100.00000", "100");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+    assert_eq!(risk_engine.trading_state, TradingState::Active);
+
+    // Equity falls 10% from the start of day equity
+    risk_engine
+        .cache
+        .borrow_mut()
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd("900000 USD", "0 USD", "900000 USD"),
+        )))
+        .unwrap();
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "100.00000", "100");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    assert_eq!(risk_engine.trading_state, TradingState::Halted);
+    assert_eq!(
+        get_execute_order_event_handler_messages(execute_order_event_handler).len(),
+        1
+    );
+    let saved_events = get_process_order_event_handler_messages(process_handler);
+    assert_eq!(saved_events.len(), 1);
+    assert_eq!(
+        saved_events[0].message().unwrap(),
+        Ustr::from("TradingState::HALTED")
+    );
+}
+
+#[rstest]
+fn test_max_drawdown_limit_breach_switches_to_reducing(
+    instrument_audusd: InstrumentEnum,
+    execute_order_event_handler: ShareableMessageHandler,
+) {
+    msgbus::register(
+        MessagingSwitchboard::exec_engine_execute(),
+        execute_order_event_handler.clone(),
+    );
+    let config = RiskEngineConfig {
+        max_drawdown: HashMap::from([(
+            instrument_audusd.id().venue,
+            LossLimit::new(Decimal::from_str("0.05").unwrap(), TradingState::Reducing),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Buy, "100.00000", "100");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    // Equity falls 8% from the peak equity
+    risk_engine
+        .cache
+        .borrow_mut()
+        .add_account(AccountAny::Cash(cash_account(
+            cash_account_state_million_usd("920000 USD", "0 USD", "920000 USD"),
+        )))
+        .unwrap();
+
+    // Flat, so the order does not increase exposure and is still sent when reducing
+    let order = limit_order_audusd(&instrument_audusd, OrderSide::Sell, "100.00000", "100");
+    let submit_order = submit_order_audusd(&risk_engine, order);
+    risk_engine.execute(TradingCommand::SubmitOrder(submit_order));
+
+    assert_eq!(risk_engine.trading_state, TradingState::Reducing);
+    assert_eq!(
+        get_execute_order_event_handler_messages(execute_order_event_handler).len(),
+        2
+    );
+}
+
+#[rstest]
+fn test_account_state_loss_limit_breach_halts_trading(instrument_audusd: InstrumentEnum) {
+    let config = RiskEngineConfig {
+        max_daily_loss: HashMap::from([(
+            instrument_audusd.id().venue,
+            LossLimit::new(Decimal::from_str("0.05").unwrap(), TradingState::Halted),
+        )]),
+        ..RiskEngineConfig::default()
+    };
+    let mut risk_engine = get_risk_engine_audusd(config, &instrument_audusd);
+
+    let state = cash_account_state_million_usd("1000000 USD", "0 USD", "1000000 USD");
+    risk_engine.process_account_state(&state);
+    assert_eq!(risk_engine.trading_state, TradingState::Active);
+
+    // Equity falls 10% without any order being submitted
+    let state = cash_account_state_million_usd("900000 USD", "0 USD", "900000 USD");
+    risk_engine
+        .cache
+        .borrow_mut()
+        .add_account(AccountAny::Cash(cash_account(state.clone())))
+        .unwrap();
+    risk_engine.process_account_state(&state);
+
+    assert_eq!(risk_engine.trading_state, TradingState::Halted);
+}
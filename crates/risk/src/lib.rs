@@ -20,9 +20,9 @@
 //! trading operations remain within defined risk parameters and regulatory constraints:
 //!
 //! - **Risk engine**: Central risk management orchestration with configurable trading states.
-//! - **Order validation**: Pre-trade checks for price, quantity, notional limits, and market conditions.
+//! - **Order validation**: Pre-trade checks for price, quantity, notional limits, price collars, and market conditions.
 //! - **Position sizing**: Fixed-risk position sizing calculations with commission and exchange rate support.
-//! - **Trading controls**: Rate limiting, balance validation, and exposure management.
+//! - **Trading controls**: Rate limiting, position, open order and exposure limits, and loss limits which reduce or halt trading.
 //! - **Account protection**: Multi-currency balance checks and margin requirement validation.
 //!
 //! # Platform
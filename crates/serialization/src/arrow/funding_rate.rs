@@ -0,0 +1,319 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use arrow::{
+    array::{Array, Decimal128Array, Decimal128Builder, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use ant_model::{data::funding::FundingRateUpdate, identifiers::InstrumentId};
+use rust_decimal::Decimal;
+
+use super::{DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID, extract_column};
+use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+
+/// Decimal precision of the encoded funding rate column.
+const RATE_PRECISION: u8 = 38;
+/// Fixed decimal scale of the encoded funding rate column.
+const RATE_SCALE: i8 = 18;
+
+impl ArrowSchemaProvider for FundingRateUpdate {
+    fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
+        let fields = vec![
+            Field::new(
+                "rate",
+                DataType::Decimal128(RATE_PRECISION, RATE_SCALE),
+                false,
+            ),
+            Field::new("next_funding_ns", DataType::UInt64, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        match metadata {
+            Some(metadata) => Schema::new_with_metadata(fields, metadata),
+            None => Schema::new(fields),
+        }
+    }
+}
+
+fn parse_metadata(metadata: &HashMap<String, String>) -> Result<InstrumentId, EncodingError> {
+    let instrument_id_str = metadata
+        .get(KEY_INSTRUMENT_ID)
+        .ok_or_else(|| EncodingError::MissingMetadata(KEY_INSTRUMENT_ID))?;
+    let instrument_id = InstrumentId::from_str(instrument_id_str)
+        .map_err(|e| EncodingError::ParseError(KEY_INSTRUMENT_ID, e.to_string()))?;
+
+    Ok(instrument_id)
+}
+
+fn rate_to_raw(rate: Decimal) -> Result<i128, ArrowError> {
+    let mut rate = rate;
+    rate.rescale(RATE_SCALE as u32);
+    if rate.scale() != RATE_SCALE as u32 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Funding rate {rate} cannot be represented with scale {RATE_SCALE}"
+        )));
+    }
+    Ok(rate.mantissa())
+}
+
+fn raw_to_rate(raw: i128) -> Decimal {
+    Decimal::from_i128_with_scale(raw, RATE_SCALE as u32).normalize()
+}
+
+impl EncodeToRecordBatch for FundingRateUpdate {
+    fn encode_batch(
+        metadata: &HashMap<String, String>,
+        data: &[Self],
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut rate_builder = Decimal128Builder::with_capacity(data.len())
+            .with_precision_and_scale(RATE_PRECISION, RATE_SCALE)?;
+        let mut next_funding_ns_builder = UInt64Array::builder(data.len());
+        let mut ts_event_builder = UInt64Array::builder(data.len());
+        let mut ts_init_builder = UInt64Array::builder(data.len());
+
+        for update in data {
+            rate_builder.append_value(rate_to_raw(update.rate)?);
+            next_funding_ns_builder.append_option(update.next_funding_ns.map(|ts| ts.as_u64()));
+            ts_event_builder.append_value(update.ts_event.as_u64());
+            ts_init_builder.append_value(update.ts_init.as_u64());
+        }
+
+        RecordBatch::try_new(
+            Self::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(rate_builder.finish()),
+                Arc::new(next_funding_ns_builder.finish()),
+                Arc::new(ts_event_builder.finish()),
+                Arc::new(ts_init_builder.finish()),
+            ],
+        )
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(&self.instrument_id)
+    }
+}
+
+impl DecodeFromRecordBatch for FundingRateUpdate {
+    fn decode_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        let instrument_id = parse_metadata(metadata)?;
+        let cols = record_batch.columns();
+
+        let rate_values = extract_column::<Decimal128Array>(
+            cols,
+            "rate",
+            0,
+            DataType::Decimal128(RATE_PRECISION, RATE_SCALE),
+        )?;
+        let next_funding_ns_values =
+            extract_column::<UInt64Array>(cols, "next_funding_ns", 1, DataType::UInt64)?;
+        let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 2, DataType::UInt64)?;
+        let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 3, DataType::UInt64)?;
+
+        if rate_values.scale() != RATE_SCALE {
+            return Err(EncodingError::ParseError(
+                "rate",
+                format!(
+                    "expected decimal scale {RATE_SCALE}, found {}",
+                    rate_values.scale()
+                ),
+            ));
+        }
+
+        let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
+            .map(|row| {
+                let next_funding_ns = if next_funding_ns_values.is_null(row) {
+                    None
+                } else {
+                    Some(next_funding_ns_values.value(row).into())
+                };
+
+                Ok(Self {
+                    instrument_id,
+                    rate: raw_to_rate(rate_values.value(row)),
+                    next_funding_ns,
+                    ts_event: ts_event_values.value(row).into(),
+                    ts_init: ts_init_values.value(row).into(),
+                })
+            })
+            .collect();
+
+        result
+    }
+}
+
+impl DecodeDataFromRecordBatch for FundingRateUpdate {
+    fn decode_data_batch(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+    ) -> Result<Vec<Data>, EncodingError> {
+        let updates: Vec<Self> = Self::decode_batch(metadata, record_batch)?;
+        Ok(updates.into_iter().map(Data::from).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::{array::Array, record_batch::RecordBatch};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[rstest]
+    fn test_get_schema() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = FundingRateUpdate::get_metadata(&instrument_id);
+        let schema = FundingRateUpdate::get_schema(Some(metadata.clone()));
+
+        let expected_fields = vec![
+            Field::new(
+                "rate",
+                DataType::Decimal128(RATE_PRECISION, RATE_SCALE),
+                false,
+            ),
+            Field::new("next_funding_ns", DataType::UInt64, true),
+            Field::new("ts_event", DataType::UInt64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ];
+
+        let expected_schema = Schema::new_with_metadata(expected_fields, metadata);
+        assert_eq!(schema, expected_schema);
+    }
+
+    #[rstest]
+    fn test_get_schema_map() {
+        let schema_map = FundingRateUpdate::get_schema_map();
+        let mut expected_map = HashMap::new();
+
+        let decimal = format!("Decimal128({RATE_PRECISION}, {RATE_SCALE})");
+        expected_map.insert("rate".to_string(), decimal);
+        expected_map.insert("next_funding_ns".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_event".to_string(), "UInt64".to_string());
+        expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        assert_eq!(schema_map, expected_map);
+    }
+
+    #[rstest]
+    fn test_encode_batch() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = FundingRateUpdate::get_metadata(&instrument_id);
+
+        let update1 = FundingRateUpdate::new(
+            instrument_id,
+            dec!(0.0001),
+            Some(10.into()),
+            1.into(),
+            3.into(),
+        );
+        let update2 =
+            FundingRateUpdate::new(instrument_id, dec!(-0.00025), None, 2.into(), 4.into());
+
+        let data = vec![update1, update2];
+        let record_batch = FundingRateUpdate::encode_batch(&metadata, &data).unwrap();
+
+        let columns = record_batch.columns();
+        let rate_values = columns[0]
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap();
+        let next_funding_ns_values = columns[1].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_event_values = columns[2].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let ts_init_values = columns[3].as_any().downcast_ref::<UInt64Array>().unwrap();
+
+        assert_eq!(columns.len(), 4);
+        assert_eq!(rate_values.len(), 2);
+        assert_eq!(rate_values.value(0), 100_000_000_000_000);
+        assert_eq!(rate_values.value(1), -250_000_000_000_000);
+        assert_eq!(next_funding_ns_values.len(), 2);
+        assert_eq!(next_funding_ns_values.value(0), 10);
+        assert!(next_funding_ns_values.is_null(1));
+        assert_eq!(ts_event_values.value(0), 1);
+        assert_eq!(ts_event_values.value(1), 2);
+        assert_eq!(ts_init_values.value(0), 3);
+        assert_eq!(ts_init_values.value(1), 4);
+    }
+
+    #[rstest]
+    fn test_decode_batch() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = FundingRateUpdate::get_metadata(&instrument_id);
+
+        let rate = Decimal128Array::from(vec![100_000_000_000_000, -250_000_000_000_000])
+            .with_precision_and_scale(RATE_PRECISION, RATE_SCALE)
+            .unwrap();
+        let next_funding_ns = UInt64Array::from(vec![Some(10), None]);
+        let ts_event = UInt64Array::from(vec![1, 2]);
+        let ts_init = UInt64Array::from(vec![3, 4]);
+
+        let record_batch = RecordBatch::try_new(
+            FundingRateUpdate::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(rate),
+                Arc::new(next_funding_ns),
+                Arc::new(ts_event),
+                Arc::new(ts_init),
+            ],
+        )
+        .unwrap();
+
+        let decoded_data = FundingRateUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data.len(), 2);
+        assert_eq!(decoded_data[0].instrument_id, instrument_id);
+        assert_eq!(decoded_data[0].rate, dec!(0.0001));
+        assert_eq!(decoded_data[0].next_funding_ns, Some(10.into()));
+        assert_eq!(decoded_data[0].ts_event.as_u64(), 1);
+        assert_eq!(decoded_data[0].ts_init.as_u64(), 3);
+
+        assert_eq!(decoded_data[1].instrument_id, instrument_id);
+        assert_eq!(decoded_data[1].rate, dec!(-0.00025));
+        assert_eq!(decoded_data[1].next_funding_ns, None);
+        assert_eq!(decoded_data[1].ts_event.as_u64(), 2);
+        assert_eq!(decoded_data[1].ts_init.as_u64(), 4);
+    }
+
+    #[rstest]
+    fn test_encode_decode_round_trip() {
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+        let metadata = FundingRateUpdate::get_metadata(&instrument_id);
+        let data = vec![FundingRateUpdate::new(
+            instrument_id,
+            dec!(0.000123456789),
+            Some(28_800_000_000_000.into()),
+            1.into(),
+            2.into(),
+        )];
+
+        let record_batch = FundingRateUpdate::encode_batch(&metadata, &data).unwrap();
+        let decoded_data = FundingRateUpdate::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded_data, data);
+        assert_eq!(decoded_data[0].ts_init, data[0].ts_init);
+    }
+}
@@ -19,6 +19,7 @@ pub mod bar;
 pub mod close;
 pub mod delta;
 pub mod depth;
+pub mod funding_rate;
 pub mod index_price;
 pub mod mark_price;
 pub mod quote;
@@ -38,8 +39,9 @@ use arrow::{
 };
 use ant_model::{
     data::{
-        Data, IndexPriceUpdate, MarkPriceUpdate, bar::Bar, close::InstrumentClose,
-        delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
+        Data, FundingRateUpdate, IndexPriceUpdate, MarkPriceUpdate, bar::Bar,
+        close::InstrumentClose, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
+        trade::TradeTick,
     },
     types::{price::PriceRaw, quantity::QuantityRaw},
 };
@@ -386,6 +388,24 @@ pub fn index_prices_to_arrow_record_batch_bytes(
     IndexPriceUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
 }
 
+/// Converts a vector of `FundingRateUpdate` into an Arrow `RecordBatch`.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `data` is empty: `EncodingError::EmptyData`.
+/// - Encoding fails: `EncodingError::ArrowError`.
+pub fn funding_rates_to_arrow_record_batch_bytes(
+    data: Vec<FundingRateUpdate>,
+) -> Result<RecordBatch, EncodingError> {
+    if data.is_empty() {
+        return Err(EncodingError::EmptyData);
+    }
+
+    let metadata = FundingRateUpdate::chunk_metadata(&data);
+    FundingRateUpdate::encode_batch(&metadata, &data).map_err(EncodingError::ArrowError)
+}
+
 /// Converts a vector of `InstrumentClose` into an Arrow `RecordBatch`.
 ///
 /// # Errors
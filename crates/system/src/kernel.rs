@@ -34,7 +34,7 @@ use ant_common::{
         logger::{LogGuard, LoggerConfig},
         writer::FileWriterConfig,
     },
-    messages::{DataResponse, data::DataCommand},
+    messages::{DataResponse, data::DataCommand, execution::TradingCommand},
     msgbus::{
         self, MessageBus, get_message_bus,
         handler::{ShareableMessageHandler, TypedMessageHandler},
@@ -46,7 +46,10 @@ use ant_common::{
 use ant_core::{UUID4, UnixNanos};
 use ant_data::engine::DataEngine;
 use ant_execution::engine::ExecutionEngine;
-use ant_model::identifiers::TraderId;
+use ant_model::{
+    events::{AccountState, OrderEventAny, PositionEvent},
+    identifiers::TraderId,
+};
 use ant_portfolio::portfolio::Portfolio;
 use ant_risk::engine::RiskEngine;
 use ustr::Ustr;
@@ -77,9 +80,9 @@ pub struct AntKernel {
     /// The data engine instance.
     pub data_engine: Rc<RefCell<DataEngine>>,
     /// The risk engine instance.
-    pub risk_engine: RiskEngine,
+    pub risk_engine: Rc<RefCell<RiskEngine>>,
     /// The execution engine instance.
-    pub exec_engine: ExecutionEngine,
+    pub exec_engine: Rc<RefCell<ExecutionEngine>>,
     /// The trader component.
     pub trader: Trader,
     /// The UNIX timestamp (nanoseconds) when the kernel was created.
@@ -139,7 +142,10 @@ impl AntKernel {
             clock.clone(),
             cache.clone(),
         );
+        let risk_engine = Rc::new(RefCell::new(risk_engine));
+
         let exec_engine = ExecutionEngine::new(clock.clone(), cache.clone(), config.exec_engine());
+        let exec_engine = Rc::new(RefCell::new(exec_engine));
 
         let data_engine = DataEngine::new(clock.clone(), cache.clone(), config.data_engine());
         let data_engine = Rc::new(RefCell::new(data_engine));
@@ -192,6 +198,78 @@ impl AntKernel {
         )));
         msgbus::register(endpoint, handler);
 
+        // Register RiskEngine command execution
+        let risk_engine_weak = WeakCell::from(Rc::downgrade(&risk_engine));
+        let risk_engine_weak1 = risk_engine_weak.clone();
+        let endpoint = MessagingSwitchboard::risk_engine_execute();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |cmd: &TradingCommand| {
+                if let Some(engine_rc) = risk_engine_weak1.upgrade() {
+                    engine_rc.borrow_mut().execute(cmd.clone());
+                }
+            },
+        )));
+        msgbus::register(endpoint, handler);
+
+        // Register RiskEngine event processing
+        let endpoint = MessagingSwitchboard::risk_engine_process();
+        let risk_engine_weak2 = risk_engine_weak.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &OrderEventAny| {
+                if let Some(engine_rc) = risk_engine_weak2.upgrade() {
+                    engine_rc.borrow_mut().process(event.clone());
+                }
+            },
+        )));
+        msgbus::register(endpoint, handler);
+
+        // Subscribe RiskEngine to account and position events for the loss limits,
+        // after the portfolio has applied them to the cache
+        let risk_engine_weak3 = risk_engine_weak.clone();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &AccountState| {
+                if let Some(engine_rc) = risk_engine_weak3.upgrade() {
+                    engine_rc.borrow_mut().process_account_state(event);
+                }
+            },
+        )));
+        msgbus::subscribe("events.account.*".into(), handler, None);
+
+        let risk_engine_weak4 = risk_engine_weak;
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &PositionEvent| {
+                if let Some(engine_rc) = risk_engine_weak4.upgrade() {
+                    engine_rc.borrow_mut().process_position_event(event);
+                }
+            },
+        )));
+        msgbus::subscribe("events.position.*".into(), handler, None);
+
+        // Register ExecEngine command execution
+        let exec_engine_weak = WeakCell::from(Rc::downgrade(&exec_engine));
+        let exec_engine_weak1 = exec_engine_weak.clone();
+        let endpoint = MessagingSwitchboard::exec_engine_execute();
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |cmd: &TradingCommand| {
+                if let Some(engine_rc) = exec_engine_weak1.upgrade() {
+                    engine_rc.borrow().execute(cmd);
+                }
+            },
+        )));
+        msgbus::register(endpoint, handler);
+
+        // Register ExecEngine event processing
+        let endpoint = MessagingSwitchboard::exec_engine_process();
+        let exec_engine_weak2 = exec_engine_weak;
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &OrderEventAny| {
+                if let Some(engine_rc) = exec_engine_weak2.upgrade() {
+                    engine_rc.borrow().process(event);
+                }
+            },
+        )));
+        msgbus::register(endpoint, handler);
+
         let trader = Trader::new(
             config.trader_id(),
             instance_id,
@@ -366,14 +444,14 @@ impl AntKernel {
 
     /// Returns the kernel's risk engine.
     #[must_use]
-    pub const fn risk_engine(&self) -> &RiskEngine {
-        &self.risk_engine
+    pub fn risk_engine(&self) -> Ref<'_, RiskEngine> {
+        self.risk_engine.borrow()
     }
 
     /// Returns the kernel's execution engine.
     #[must_use]
-    pub const fn exec_engine(&self) -> &ExecutionEngine {
-        &self.exec_engine
+    pub fn exec_engine(&self) -> Ref<'_, ExecutionEngine> {
+        self.exec_engine.borrow()
     }
 
     /// Returns the kernel's trader.
@@ -407,6 +485,45 @@ impl AntKernel {
         log::info!("Started");
     }
 
+    /// Starts the Ant system kernel without connecting clients.
+    ///
+    /// Intended for the backtest environment where all clients are simulated and
+    /// therefore always connected.
+    pub fn start(&mut self) {
+        log::info!("Starting");
+        self.start_engines();
+
+        log::info!("Initializing trader");
+        if let Err(e) = self.trader.initialize() {
+            log::error!("Error initializing trader: {e:?}");
+            return;
+        }
+
+        if let Err(e) = self.trader.start() {
+            log::error!("Error starting trader: {e:?}");
+        }
+
+        self.ts_started = Some(self.clock.borrow().timestamp_ns());
+        log::info!("Started");
+    }
+
+    /// Stops the Ant system kernel without disconnecting clients.
+    ///
+    /// Intended for the backtest environment, see [`AntKernel::start`].
+    pub fn stop(&mut self) {
+        log::info!("Stopping");
+
+        if let Err(e) = self.trader.stop() {
+            log::error!("Error stopping trader: {e:?}");
+        }
+
+        self.stop_engines();
+        self.cancel_timers();
+
+        self.ts_shutdown = Some(self.clock.borrow().timestamp_ns());
+        log::info!("Stopped");
+    }
+
     /// Stops the Ant system kernel.
     pub async fn stop_async(&mut self) {
         log::info!("Stopping");
@@ -22,7 +22,7 @@
 use std::{cell::RefCell, collections::HashMap, fmt::Debug, rc::Rc};
 
 use ant_common::{
-    actor::DataActor,
+    actor::{DataActor, registry::try_get_actor_unchecked},
     cache::Cache,
     clock::{Clock, TestClock},
     component::{
@@ -30,9 +30,21 @@ use ant_common::{
         stop_component,
     },
     enums::{ComponentState, ComponentTrigger, Environment},
+    messages::execution::TradingCommand,
+    msgbus::{
+        self,
+        handler::{ShareableMessageHandler, TypedMessageHandler},
+        switchboard::{MessagingSwitchboard, get_event_orders_topic, get_event_positions_topic},
+    },
 };
 use ant_core::{UUID4, UnixNanos};
-use ant_model::identifiers::{ActorId, ComponentId, ExecAlgorithmId, StrategyId, TraderId};
+use ant_execution::algorithm::ExecAlgorithm;
+use ant_model::{
+    events::{OrderEventAny, PositionEvent},
+    identifiers::{ActorId, ComponentId, ExecAlgorithmId, StrategyId, TraderId},
+};
+use ant_trading::strategy::Strategy;
+use ustr::Ustr;
 
 /// Central orchestrator for managing trading components.
 ///
@@ -54,10 +66,10 @@ pub struct Trader {
     cache: Rc<RefCell<Cache>>,
     /// Registered actor IDs (actors stored in global registry).
     actor_ids: Vec<ActorId>,
-    /// Registered strategies by strategy ID.
-    strategies: HashMap<StrategyId, Box<dyn Component>>,
-    /// Registered execution algorithms by algorithm ID.
-    exec_algorithms: HashMap<ExecAlgorithmId, Box<dyn Component>>,
+    /// Registered strategy IDs (strategies stored in global registry).
+    strategy_ids: Vec<StrategyId>,
+    /// Registered execution algorithm IDs (algorithms stored in global registry).
+    exec_algorithm_ids: Vec<ExecAlgorithmId>,
     /// Component clocks for individual components.
     clocks: HashMap<ComponentId, Rc<RefCell<dyn Clock>>>, // TODO: TBD global clock?
     /// Timestamp when the trader was created.
@@ -94,8 +106,8 @@ impl Trader {
             clock,
             cache,
             actor_ids: Vec::new(),
-            strategies: HashMap::new(),
-            exec_algorithms: HashMap::new(),
+            strategy_ids: Vec::new(),
+            exec_algorithm_ids: Vec::new(),
             clocks: HashMap::new(),
             ts_created,
             ts_started: None,
@@ -153,20 +165,20 @@ impl Trader {
 
     /// Returns the number of registered strategies.
     #[must_use]
-    pub fn strategy_count(&self) -> usize {
-        self.strategies.len()
+    pub const fn strategy_count(&self) -> usize {
+        self.strategy_ids.len()
     }
 
     /// Returns the number of registered execution algorithms.
     #[must_use]
-    pub fn exec_algorithm_count(&self) -> usize {
-        self.exec_algorithms.len()
+    pub const fn exec_algorithm_count(&self) -> usize {
+        self.exec_algorithm_ids.len()
     }
 
     /// Returns the total number of registered components.
     #[must_use]
     pub fn component_count(&self) -> usize {
-        self.actor_ids.len() + self.strategies.len() + self.exec_algorithms.len()
+        self.actor_ids.len() + self.strategy_ids.len() + self.exec_algorithm_ids.len()
     }
 
     /// Returns a list of all registered actor IDs.
@@ -178,13 +190,22 @@ impl Trader {
     /// Returns a list of all registered strategy IDs.
     #[must_use]
     pub fn strategy_ids(&self) -> Vec<StrategyId> {
-        self.strategies.keys().copied().collect()
+        self.strategy_ids.clone()
     }
 
     /// Returns a list of all registered execution algorithm IDs.
     #[must_use]
     pub fn exec_algorithm_ids(&self) -> Vec<ExecAlgorithmId> {
-        self.exec_algorithms.keys().copied().collect()
+        self.exec_algorithm_ids.clone()
+    }
+
+    /// Returns the clocks for all registered components.
+    ///
+    /// In a backtest environment these are the individual test clocks which must be
+    /// advanced by the engine alongside the kernel clock.
+    #[must_use]
+    pub fn get_component_clocks(&self) -> Vec<Rc<RefCell<dyn Clock>>> {
+        self.clocks.values().cloned().collect()
     }
 
     /// Creates a clock for a component.
@@ -310,65 +331,114 @@ impl Trader {
 
     /// Adds a strategy to the trader.
     ///
+    /// The strategy is registered in the global component and actor registries, and
+    /// subscribed to the order and position events published for its strategy ID.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The trader is not in a valid state for adding components
-    /// - A strategy with the same ID is already registered
-    pub fn add_strategy(&mut self, mut strategy: Box<dyn Component>) -> anyhow::Result<()> {
+    /// - The trader is not in a valid state for adding components.
+    /// - A strategy with the same ID is already registered.
+    pub fn add_strategy<T>(&mut self, strategy: T) -> anyhow::Result<()>
+    where
+        T: Strategy + Debug + 'static,
+    {
         self.validate_component_registration()?;
 
-        let strategy_id = StrategyId::from(strategy.component_id().inner().as_str());
+        let strategy_id = strategy.strategy_id();
 
         // Check for duplicate registration
-        if self.strategies.contains_key(&strategy_id) {
+        if self.strategy_ids.contains(&strategy_id) {
             anyhow::bail!("Strategy '{strategy_id}' is already registered");
         }
 
         let clock = self.create_component_clock();
-        let component_id = strategy.component_id();
+        let component_id = ComponentId::new(strategy_id.inner().as_str());
         self.clocks.insert(component_id, clock.clone());
 
-        strategy.register(self.trader_id, clock, self.cache.clone())?;
+        let mut strategy_mut = strategy;
+        strategy_mut.register(self.trader_id, clock, self.cache.clone())?;
+
+        Self::subscribe_strategy_events::<T>(strategy_id, strategy_mut.actor_id().inner());
+
+        let mem_addr = strategy_mut.mem_address();
+        register_component_actor(strategy_mut);
+        self.strategy_ids.push(strategy_id);
 
-        self.strategies.insert(strategy_id, strategy);
         log::info!(
-            "Registered strategy '{strategy_id}' with trader {}",
+            "Registered strategy '{strategy_id}' at mem_addr {mem_addr} with trader {}",
             self.trader_id
         );
 
         Ok(())
     }
 
+    /// Subscribes the strategy to its order and position event topics.
+    fn subscribe_strategy_events<T>(strategy_id: StrategyId, actor_id: Ustr)
+    where
+        T: Strategy + Debug + 'static,
+    {
+        let order_handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &OrderEventAny| {
+                if let Some(strategy) = try_get_actor_unchecked::<T>(&actor_id) {
+                    strategy.handle_order_event(event);
+                } else {
+                    log::error!("Strategy {actor_id} not found for order event handling");
+                }
+            },
+        )));
+        msgbus::subscribe_topic(get_event_orders_topic(strategy_id), order_handler, None);
+
+        let position_handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |event: &PositionEvent| {
+                if let Some(strategy) = try_get_actor_unchecked::<T>(&actor_id) {
+                    strategy.handle_position_event(event);
+                } else {
+                    log::error!("Strategy {actor_id} not found for position event handling");
+                }
+            },
+        )));
+        msgbus::subscribe_topic(get_event_positions_topic(strategy_id), position_handler, None);
+    }
+
     /// Adds an execution algorithm to the trader.
     ///
+    /// The algorithm is registered to receive the commands sent to its execution endpoint
+    /// (`{exec_algorithm_id}.execute`).
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The trader is not in a valid state for adding components
     /// - An execution algorithm with the same ID is already registered
-    pub fn add_exec_algorithm(
-        &mut self,
-        mut exec_algorithm: Box<dyn Component>,
-    ) -> anyhow::Result<()> {
+    pub fn add_exec_algorithm<T>(&mut self, exec_algorithm: T) -> anyhow::Result<()>
+    where
+        T: ExecAlgorithm + Debug + 'static,
+    {
         self.validate_component_registration()?;
 
-        let exec_algorithm_id =
-            ExecAlgorithmId::from(exec_algorithm.component_id().inner().as_str());
+        let exec_algorithm_id = exec_algorithm.exec_algorithm_id();
 
         // Check for duplicate registration
-        if self.exec_algorithms.contains_key(&exec_algorithm_id) {
+        if self.exec_algorithm_ids.contains(&exec_algorithm_id) {
             anyhow::bail!("Execution algorithm '{exec_algorithm_id}' is already registered");
         }
 
         let clock = self.create_component_clock();
-        let component_id = exec_algorithm.component_id();
+        let component_id = ComponentId::new(exec_algorithm_id.inner().as_str());
         self.clocks.insert(component_id, clock.clone());
 
-        exec_algorithm.register(self.trader_id, clock, self.cache.clone())?;
+        let mut exec_algorithm_mut = exec_algorithm;
+        exec_algorithm_mut.register(self.trader_id, clock, self.cache.clone())?;
+
+        Self::register_exec_algorithm_endpoint::<T>(
+            exec_algorithm_id,
+            exec_algorithm_mut.actor_id().inner(),
+        );
+
+        register_component_actor(exec_algorithm_mut);
+        self.exec_algorithm_ids.push(exec_algorithm_id);
 
-        self.exec_algorithms
-            .insert(exec_algorithm_id, exec_algorithm);
         log::info!(
             "Registered execution algorithm '{exec_algorithm_id}' with trader {}",
             self.trader_id
@@ -377,6 +447,26 @@ impl Trader {
         Ok(())
     }
 
+    /// Registers the execution algorithm to handle the commands sent to its endpoint.
+    fn register_exec_algorithm_endpoint<T>(exec_algorithm_id: ExecAlgorithmId, actor_id: Ustr)
+    where
+        T: ExecAlgorithm + Debug + 'static,
+    {
+        let handler = ShareableMessageHandler(Rc::new(TypedMessageHandler::from(
+            move |command: &TradingCommand| {
+                if let Some(exec_algorithm) = try_get_actor_unchecked::<T>(&actor_id) {
+                    exec_algorithm.handle_command(command);
+                } else {
+                    log::error!("Execution algorithm {actor_id} not found for command handling");
+                }
+            },
+        )));
+        msgbus::register(
+            MessagingSwitchboard::exec_algorithm_execute(exec_algorithm_id),
+            handler,
+        );
+    }
+
     /// Validates that the trader is in a valid state for component registration.
     fn validate_component_registration(&self) -> anyhow::Result<()> {
         match self.state {
@@ -405,14 +495,14 @@ impl Trader {
             start_component(&actor_id.inner())?;
         }
 
-        for strategy_id in &mut self.strategies.keys() {
+        for strategy_id in &self.strategy_ids {
             log::debug!("Starting strategy '{strategy_id}'");
-            // strategy.start()?; // TODO: TBD
+            start_component(&strategy_id.inner())?;
         }
 
-        for exec_algorithm_id in &mut self.exec_algorithms.keys() {
+        for exec_algorithm_id in &self.exec_algorithm_ids {
             log::debug!("Starting execution algorithm '{exec_algorithm_id}'");
-            // exec_algorithm.start()?;  // TODO: TBD
+            start_component(&exec_algorithm_id.inner())?;
         }
 
         Ok(())
@@ -429,14 +519,14 @@ impl Trader {
             stop_component(&actor_id.inner())?;
         }
 
-        for exec_algorithm_id in &mut self.exec_algorithms.keys() {
+        for exec_algorithm_id in &self.exec_algorithm_ids {
             log::debug!("Stopping execution algorithm '{exec_algorithm_id}'");
-            // exec_algorithm.stop()?;  // TODO: TBD
+            stop_component(&exec_algorithm_id.inner())?;
         }
 
-        for strategy_id in &mut self.strategies.keys() {
+        for strategy_id in &self.strategy_ids {
             log::debug!("Stopping strategy '{strategy_id}'");
-            // strategy.stop()?;  // TODO: TBD
+            stop_component(&strategy_id.inner())?;
         }
 
         Ok(())
@@ -454,14 +544,14 @@ impl Trader {
             reset_component(&actor_id.inner())?;
         }
 
-        for strategy_id in &mut self.strategies.keys() {
+        for strategy_id in &self.strategy_ids {
             log::debug!("Resetting strategy '{strategy_id}'");
-            // strategy.reset()?;  // TODO: TBD
+            reset_component(&strategy_id.inner())?;
         }
 
-        for exec_algorithm_id in &mut self.exec_algorithms.keys() {
+        for exec_algorithm_id in &self.exec_algorithm_ids {
             log::debug!("Resetting execution algorithm '{exec_algorithm_id}'");
-            // exec_algorithm.reset()?;  // TODO: TBD
+            reset_component(&exec_algorithm_id.inner())?;
         }
 
         Ok(())
@@ -479,19 +569,19 @@ impl Trader {
             dispose_component(&actor_id.inner())?;
         }
 
-        for strategy_id in &mut self.strategies.keys() {
+        for strategy_id in &self.strategy_ids {
             log::debug!("Disposing strategy '{strategy_id}'");
-            // strategy.dispose()?;  // TODO: TBD
+            dispose_component(&strategy_id.inner())?;
         }
 
-        for exec_algorithm_id in &mut self.exec_algorithms.keys() {
+        for exec_algorithm_id in &self.exec_algorithm_ids {
             log::debug!("Disposing execution algorithm '{exec_algorithm_id}'");
-            // exec_algorithm.dispose()?;  // TODO: TBD
+            dispose_component(&exec_algorithm_id.inner())?;
         }
 
         self.actor_ids.clear();
-        self.strategies.clear();
-        self.exec_algorithms.clear();
+        self.strategy_ids.clear();
+        self.exec_algorithm_ids.clear();
         self.clocks.clear();
 
         Ok(())
@@ -610,10 +700,17 @@ mod tests {
     };
     use ant_core::UUID4;
     use ant_data::engine::{DataEngine, config::DataEngineConfig};
-    use ant_execution::engine::{ExecutionEngine, config::ExecutionEngineConfig};
-    use ant_model::identifiers::{ActorId, ComponentId, TraderId};
+    use ant_execution::{
+        algorithm::{ExecAlgorithmConfig, ExecAlgorithmCore},
+        engine::{ExecutionEngine, config::ExecutionEngineConfig},
+    };
+    use ant_model::{
+        identifiers::{ActorId, ComponentId, TraderId},
+        orders::OrderAny,
+    };
     use ant_portfolio::portfolio::Portfolio;
     use ant_risk::engine::{RiskEngine, config::RiskEngineConfig};
+    use ant_trading::strategy::{StrategyConfig, StrategyCore};
     use rstest::rstest;
 
     use super::*;
@@ -647,48 +744,87 @@ mod tests {
         }
     }
 
-    // Mock component for testing
+    // Minimal Strategy implementation for testing
+    #[derive(Debug)]
+    struct TestStrategy {
+        core: StrategyCore,
+    }
+
+    impl TestStrategy {
+        fn new(config: StrategyConfig) -> Self {
+            Self {
+                core: StrategyCore::new(config),
+            }
+        }
+    }
+
+    impl Deref for TestStrategy {
+        type Target = DataActorCore;
+        fn deref(&self) -> &Self::Target {
+            &self.core
+        }
+    }
+
+    impl DerefMut for TestStrategy {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.core
+        }
+    }
+
+    impl DataActor for TestStrategy {}
+
+    impl Strategy for TestStrategy {
+        fn core(&self) -> &StrategyCore {
+            &self.core
+        }
+
+        fn core_mut(&mut self) -> &mut StrategyCore {
+            &mut self.core
+        }
+    }
+
+    // Minimal ExecAlgorithm implementation for testing
     #[derive(Debug)]
-    struct MockComponent {
-        id: ComponentId,
-        state: ComponentState,
+    struct TestExecAlgorithm {
+        core: ExecAlgorithmCore,
     }
 
-    impl MockComponent {
+    impl TestExecAlgorithm {
         fn new(id: &str) -> Self {
             Self {
-                id: ComponentId::from(id),
-                state: ComponentState::PreInitialized,
+                core: ExecAlgorithmCore::new(
+                    ExecAlgorithmConfig::default(),
+                    ExecAlgorithmId::from(id),
+                ),
             }
         }
     }
 
-    impl Component for MockComponent {
-        fn component_id(&self) -> ComponentId {
-            self.id
+    impl Deref for TestExecAlgorithm {
+        type Target = DataActorCore;
+        fn deref(&self) -> &Self::Target {
+            &self.core
         }
+    }
 
-        fn state(&self) -> ComponentState {
-            self.state
+    impl DerefMut for TestExecAlgorithm {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.core
         }
+    }
 
-        fn transition_state(&mut self, trigger: ComponentTrigger) -> anyhow::Result<()> {
-            self.state = self.state.transition(&trigger)?;
-            log::info!("{}", self.state.variant_name());
-            Ok(())
+    impl DataActor for TestExecAlgorithm {}
+
+    impl ExecAlgorithm for TestExecAlgorithm {
+        fn core(&self) -> &ExecAlgorithmCore {
+            &self.core
         }
 
-        fn register(
-            &mut self,
-            _trader_id: TraderId,
-            _clock: Rc<RefCell<dyn Clock>>,
-            _cache: Rc<RefCell<Cache>>,
-        ) -> anyhow::Result<()> {
-            // Mock implementation
-            Ok(())
+        fn core_mut(&mut self) -> &mut ExecAlgorithmCore {
+            &mut self.core
         }
 
-        fn on_start(&mut self) -> anyhow::Result<()> {
+        fn on_order(&mut self, _order: OrderAny) -> anyhow::Result<()> {
             Ok(())
         }
     }
@@ -857,16 +993,47 @@ mod tests {
 
         let mut trader = Trader::new(trader_id, instance_id, Environment::Backtest, clock, cache);
 
-        let strategy = Box::new(MockComponent::new("Test-Strategy"));
-        let strategy_id = StrategyId::from(strategy.component_id().inner().as_str());
+        let strategy = TestStrategy::new(StrategyConfig {
+            order_id_tag: Some("001".to_string()),
+            ..Default::default()
+        });
+        let strategy_id = strategy.strategy_id();
 
         let result = trader.add_strategy(strategy);
         assert!(result.is_ok());
         assert_eq!(trader.strategy_count(), 1);
         assert_eq!(trader.component_count(), 1);
+        assert_eq!(strategy_id, StrategyId::from("Strategy-001"));
         assert!(trader.strategy_ids().contains(&strategy_id));
     }
 
+    #[rstest]
+    fn test_add_duplicate_strategy_fails() {
+        let (_msgbus, cache, _portfolio, _data_engine, _risk_engine, _exec_engine, clock) =
+            create_trader_components();
+        let trader_id = TraderId::default();
+        let instance_id = UUID4::new();
+
+        let mut trader = Trader::new(trader_id, instance_id, Environment::Backtest, clock, cache);
+
+        let config = StrategyConfig {
+            order_id_tag: Some("001".to_string()),
+            ..Default::default()
+        };
+
+        assert!(trader.add_strategy(TestStrategy::new(config.clone())).is_ok());
+
+        let result = trader.add_strategy(TestStrategy::new(config));
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("already registered")
+        );
+        assert_eq!(trader.strategy_count(), 1);
+    }
+
     #[rstest]
     fn test_add_exec_algorithm_success() {
         let (_msgbus, cache, _portfolio, _data_engine, _risk_engine, _exec_engine, clock) =
@@ -876,9 +1043,8 @@ mod tests {
 
         let mut trader = Trader::new(trader_id, instance_id, Environment::Backtest, clock, cache);
 
-        let exec_algorithm = Box::new(MockComponent::new("TestExecAlgorithm"));
-        let exec_algorithm_id =
-            ExecAlgorithmId::from(exec_algorithm.component_id().inner().as_str());
+        let exec_algorithm = TestExecAlgorithm::new("TestExecAlgorithm");
+        let exec_algorithm_id = exec_algorithm.exec_algorithm_id();
 
         let result = trader.add_exec_algorithm(exec_algorithm);
         assert!(result.is_ok());
@@ -887,6 +1053,32 @@ mod tests {
         assert!(trader.exec_algorithm_ids().contains(&exec_algorithm_id));
     }
 
+    #[rstest]
+    fn test_add_duplicate_exec_algorithm_fails() {
+        let (_msgbus, cache, _portfolio, _data_engine, _risk_engine, _exec_engine, clock) =
+            create_trader_components();
+        let trader_id = TraderId::default();
+        let instance_id = UUID4::new();
+
+        let mut trader = Trader::new(trader_id, instance_id, Environment::Backtest, clock, cache);
+
+        assert!(
+            trader
+                .add_exec_algorithm(TestExecAlgorithm::new("TWAP"))
+                .is_ok()
+        );
+
+        let result = trader.add_exec_algorithm(TestExecAlgorithm::new("TWAP"));
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("already registered")
+        );
+        assert_eq!(trader.exec_algorithm_count(), 1);
+    }
+
     #[rstest]
     fn test_component_lifecycle() {
         let (_msgbus, cache, _portfolio, _data_engine, _risk_engine, _exec_engine, clock) =
@@ -898,8 +1090,8 @@ mod tests {
 
         // Add components
         let actor = TestDataActor::new(DataActorConfig::default());
-        let strategy = Box::new(MockComponent::new("Test-Strategy"));
-        let exec_algorithm = Box::new(MockComponent::new("TestExecAlgorithm"));
+        let strategy = TestStrategy::new(StrategyConfig::default());
+        let exec_algorithm = TestExecAlgorithm::new("TestExecAlgorithm");
 
         assert!(trader.add_actor(actor).is_ok());
         assert!(trader.add_strategy(strategy).is_ok());
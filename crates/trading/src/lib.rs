@@ -17,7 +17,8 @@
 //!
 //! The `` crate provides core trading capabilities including:
 //!
-//! - **Forex sessions**: Market session time calculations and timezone handling.
+//! - **Market sessions**: Forex session times and exchange trading calendars with holidays.
+//! - **Strategies**: The native Rust `Strategy` trait with order management helpers.
 //!
 //! # Platform
 //!
@@ -48,6 +49,7 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 pub mod sessions;
+pub mod strategy;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -0,0 +1,476 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Provides exchange trading calendars.
+//!
+//! A [`TradingCalendar`] combines the intraday [`SessionWindow`]s of an exchange schedule with a
+//! [`HolidayCalendar`] to determine whether the market is open, the next open and close times,
+//! and which trading day a timestamp belongs to.
+//!
+//! Night sessions are held on the evening before the trading day they belong to (following the
+//! previous trading day), and are skipped when a holiday falls between the two trading days.
+
+use std::{collections::BTreeSet, fs, path::Path, str::FromStr};
+
+use ant_core::UnixNanos;
+use ant_execution::matching_engine::rules::TradingDayCalendar;
+use chrono::{DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use strum::{Display, EnumIter, EnumString, FromRepr};
+
+/// The maximum number of days searched for the next or previous trading day.
+const MAX_SEARCH_DAYS: u32 = 366;
+
+/// Represents the phase of an exchange trading session.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, FromRepr, EnumIter, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum SessionPhase {
+    /// The opening call auction.
+    OpeningAuction,
+    /// Continuous trading.
+    Continuous,
+    /// The closing call auction.
+    ClosingAuction,
+}
+
+/// Represents an intraday session window in exchange local time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SessionWindow {
+    /// The session phase during the window.
+    pub phase: SessionPhase,
+    /// The local start time of the window (inclusive).
+    pub start: NaiveTime,
+    /// The local end time of the window (exclusive), may be after midnight for night sessions.
+    pub end: NaiveTime,
+    /// If the window is held on the evening before the trading day (night session).
+    pub is_night: bool,
+}
+
+impl SessionWindow {
+    /// Creates a new [`SessionWindow`] instance.
+    #[must_use]
+    pub const fn new(
+        phase: SessionPhase,
+        start: NaiveTime,
+        end: NaiveTime,
+        is_night: bool,
+    ) -> Self {
+        Self {
+            phase,
+            start,
+            end,
+            is_night,
+        }
+    }
+}
+
+/// Represents a set of exchange holidays (non-trading weekdays).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl HolidayCalendar {
+    /// Creates a new [`HolidayCalendar`] instance.
+    #[must_use]
+    pub fn new(holidays: impl IntoIterator<Item = NaiveDate>) -> Self {
+        Self {
+            holidays: holidays.into_iter().collect(),
+        }
+    }
+
+    /// Loads a holiday calendar from a file with one ISO 8601 date (`YYYY-MM-DD`) per line.
+    ///
+    /// Blank lines and `#` comments are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a line is not a valid date.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path.as_ref()).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to read holiday calendar {}: {e}",
+                path.as_ref().display()
+            )
+        })?;
+        content.parse()
+    }
+
+    /// Returns whether the given `date` is a holiday.
+    #[must_use]
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    /// Returns an iterator over the holidays in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &NaiveDate> {
+        self.holidays.iter()
+    }
+}
+
+impl FromStr for HolidayCalendar {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut holidays = BTreeSet::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let date = NaiveDate::parse_from_str(line, "%Y-%m-%d")
+                .map_err(|e| anyhow::anyhow!("Invalid holiday '{line}' on line {}: {e}", i + 1))?;
+            holidays.insert(date);
+        }
+        Ok(Self { holidays })
+    }
+}
+
+/// Represents an exchange trading calendar.
+#[derive(Clone, Debug)]
+pub struct TradingCalendar {
+    timezone: Tz,
+    windows: Vec<SessionWindow>,
+    holidays: HolidayCalendar,
+}
+
+impl TradingCalendar {
+    /// Creates a new [`TradingCalendar`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `windows` is empty.
+    /// - A day session window does not end after it starts.
+    pub fn new(
+        timezone: Tz,
+        windows: Vec<SessionWindow>,
+        holidays: HolidayCalendar,
+    ) -> anyhow::Result<Self> {
+        if windows.is_empty() {
+            anyhow::bail!("Trading calendar must have at least one session window");
+        }
+        if let Some(window) = windows.iter().find(|w| !w.is_night && w.end <= w.start) {
+            anyhow::bail!(
+                "Invalid day session window {}-{}, end must be after start",
+                window.start,
+                window.end
+            );
+        }
+
+        Ok(Self {
+            timezone,
+            windows,
+            holidays,
+        })
+    }
+
+    /// Returns the timezone of the exchange.
+    #[must_use]
+    pub const fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Returns the holiday calendar.
+    #[must_use]
+    pub const fn holidays(&self) -> &HolidayCalendar {
+        &self.holidays
+    }
+
+    /// Returns whether the given `date` is a trading day (a weekday which is not a holiday).
+    #[must_use]
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        date.weekday().number_from_monday() <= 5 && !self.holidays.is_holiday(date)
+    }
+
+    /// Returns the next trading day strictly after `date`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no trading day is found within a year.
+    #[must_use]
+    pub fn next_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        date.iter_days()
+            .skip(1)
+            .take(MAX_SEARCH_DAYS as usize)
+            .find(|d| self.is_trading_day(*d))
+            .expect("No trading day found within a year")
+    }
+
+    /// Returns the previous trading day strictly before `date`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no trading day is found within a year.
+    #[must_use]
+    pub fn prev_trading_day(&self, date: NaiveDate) -> NaiveDate {
+        date.iter_days()
+            .rev()
+            .skip(1)
+            .take(MAX_SEARCH_DAYS as usize)
+            .find(|d| self.is_trading_day(*d))
+            .expect("No trading day found within a year")
+    }
+
+    /// Returns whether the night session for `trading_day` is held.
+    ///
+    /// The night session is held on the evening of the previous trading day, unless a holiday
+    /// falls between the two trading days (e.g. before the Spring Festival break).
+    fn has_night_session(&self, trading_day: NaiveDate) -> bool {
+        let prev = self.prev_trading_day(trading_day);
+        prev.iter_days()
+            .skip(1)
+            .take_while(|d| *d < trading_day)
+            .all(|d| d.weekday().number_from_monday() > 5)
+    }
+
+    fn to_utc(&self, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+        let local = date.and_time(time);
+        match self.timezone.from_local_datetime(&local) {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt.with_timezone(&Utc),
+            // Local time falls in a DST gap, use the first valid time after it
+            LocalResult::None => self
+                .timezone
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+                .expect("Local time should be valid after DST gap")
+                .with_timezone(&Utc),
+        }
+    }
+
+    /// Returns the session windows for `trading_day` as `(phase, start, end)` in UTC, ordered
+    /// by start time.
+    ///
+    /// Returns an empty vector if `trading_day` is not a trading day.
+    #[must_use]
+    pub fn sessions(
+        &self,
+        trading_day: NaiveDate,
+    ) -> Vec<(SessionPhase, DateTime<Utc>, DateTime<Utc>)> {
+        if !self.is_trading_day(trading_day) {
+            return Vec::new();
+        }
+
+        let night_date =
+            if self.windows.iter().any(|w| w.is_night) && self.has_night_session(trading_day) {
+                Some(self.prev_trading_day(trading_day))
+            } else {
+                None
+            };
+
+        let mut sessions: Vec<(SessionPhase, DateTime<Utc>, DateTime<Utc>)> = self
+            .windows
+            .iter()
+            .filter_map(|window| {
+                let date = if window.is_night {
+                    night_date?
+                } else {
+                    trading_day
+                };
+                let start = self.to_utc(date, window.start);
+                let end_date = if window.end <= window.start {
+                    date.succ_opt()?
+                } else {
+                    date
+                };
+                Some((window.phase, start, self.to_utc(end_date, window.end)))
+            })
+            .collect();
+        sessions.sort_by_key(|(_, start, _)| *start);
+        sessions
+    }
+
+    /// Returns the open intervals for `trading_day`, merging adjacent session windows.
+    fn open_intervals(&self, trading_day: NaiveDate) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+        for (_, start, end) in self.sessions(trading_day) {
+            match intervals.last_mut() {
+                Some(last) if last.1 >= start => last.1 = last.1.max(end),
+                _ => intervals.push((start, end)),
+            }
+        }
+        intervals
+    }
+
+    /// Returns the trading day which `time` is attributed to.
+    ///
+    /// This is the first trading day which closes at or after `time`, so night sessions and
+    /// timestamps after the close (or on non-trading days) belong to the next trading day.
+    #[must_use]
+    pub fn trading_day(&self, time: DateTime<Utc>) -> NaiveDate {
+        let local_date = time.with_timezone(&self.timezone).date_naive();
+        let mut trading_day = self.prev_trading_day(local_date);
+        loop {
+            if let Some((_, _, close)) = self.sessions(trading_day).last()
+                && time <= *close
+            {
+                return trading_day;
+            }
+            trading_day = self.next_trading_day(trading_day);
+        }
+    }
+
+    /// Returns the session phase at `time`, or `None` if the market is closed.
+    #[must_use]
+    pub fn phase(&self, time: DateTime<Utc>) -> Option<SessionPhase> {
+        self.sessions(self.trading_day(time))
+            .into_iter()
+            .find(|(_, start, end)| *start <= time && time < *end)
+            .map(|(phase, _, _)| phase)
+    }
+
+    /// Returns whether the market is open at `time`.
+    #[must_use]
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        self.phase(time).is_some()
+    }
+
+    /// Returns the next time strictly after `time` at which the market opens.
+    #[must_use]
+    pub fn next_open(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let mut trading_day = self.trading_day(time);
+        loop {
+            if let Some((start, _)) = self
+                .open_intervals(trading_day)
+                .into_iter()
+                .find(|(start, _)| *start > time)
+            {
+                return start;
+            }
+            trading_day = self.next_trading_day(trading_day);
+        }
+    }
+
+    /// Returns the next time strictly after `time` at which the market closes.
+    #[must_use]
+    pub fn next_close(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let mut trading_day = self.trading_day(time);
+        loop {
+            if let Some((_, end)) = self
+                .open_intervals(trading_day)
+                .into_iter()
+                .find(|(_, end)| *end > time)
+            {
+                return end;
+            }
+            trading_day = self.next_trading_day(trading_day);
+        }
+    }
+}
+
+impl TradingDayCalendar for TradingCalendar {
+    fn trading_day(&self, ts: UnixNanos) -> i64 {
+        let trading_day = Self::trading_day(self, DateTime::<Utc>::from(ts));
+        i64::from(trading_day.to_epoch_days())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono_tz::Europe::London;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn time(hour: u32, min: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+    }
+
+    fn london(y: i32, m: u32, d: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        London
+            .with_ymd_and_hms(y, m, d, hour, min, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[fixture]
+    fn calendar() -> TradingCalendar {
+        TradingCalendar::new(
+            London,
+            vec![
+                SessionWindow::new(SessionPhase::OpeningAuction, time(7, 50), time(8, 0), false),
+                SessionWindow::new(SessionPhase::Continuous, time(8, 0), time(16, 30), false),
+            ],
+            "2024-12-25\n2024-12-26\n".parse().unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_holiday_calendar_from_str() {
+        let holidays: HolidayCalendar = "# Spring Festival\n2024-02-09\n\n2024-02-12 # Monday\n"
+            .parse()
+            .unwrap();
+
+        assert!(holidays.is_holiday(NaiveDate::from_ymd_opt(2024, 2, 9).unwrap()));
+        assert!(holidays.is_holiday(NaiveDate::from_ymd_opt(2024, 2, 12).unwrap()));
+        assert_eq!(holidays.iter().count(), 2);
+    }
+
+    #[rstest]
+    fn test_holiday_calendar_from_str_invalid() {
+        let result = "2024-02-09\n2024-13-01\n".parse::<HolidayCalendar>();
+
+        assert!(result.unwrap_err().to_string().contains("line 2"));
+    }
+
+    #[rstest]
+    fn test_calendar_requires_windows() {
+        let result = TradingCalendar::new(London, vec![], HolidayCalendar::default());
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(london(2024, 12, 23, 7, 55), Some(SessionPhase::OpeningAuction))]
+    #[case(london(2024, 12, 23, 12, 0), Some(SessionPhase::Continuous))]
+    #[case(london(2024, 12, 23, 16, 30), None)]
+    #[case(london(2024, 12, 25, 12, 0), None)] // Holiday
+    #[case(london(2024, 12, 28, 12, 0), None)] // Saturday
+    fn test_phase(
+        calendar: TradingCalendar,
+        #[case] time: DateTime<Utc>,
+        #[case] expected: Option<SessionPhase>,
+    ) {
+        assert_eq!(calendar.phase(time), expected);
+        assert_eq!(calendar.is_open(time), expected.is_some());
+    }
+
+    #[rstest]
+    fn test_trading_day_after_close_rolls_over_holidays(calendar: TradingCalendar) {
+        let result = calendar.trading_day(london(2024, 12, 24, 17, 0));
+
+        assert_eq!(result, NaiveDate::from_ymd_opt(2024, 12, 27).unwrap());
+    }
+
+    #[rstest]
+    fn test_next_open_and_close(calendar: TradingCalendar) {
+        let now = london(2024, 12, 24, 17, 0);
+
+        assert_eq!(calendar.next_open(now), london(2024, 12, 27, 7, 50));
+        assert_eq!(calendar.next_close(now), london(2024, 12, 27, 16, 30));
+    }
+
+    #[rstest]
+    fn test_next_close_merges_adjacent_windows(calendar: TradingCalendar) {
+        let result = calendar.next_close(london(2024, 12, 23, 7, 55));
+
+        assert_eq!(result, london(2024, 12, 23, 16, 30));
+    }
+}
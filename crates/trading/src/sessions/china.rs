@@ -0,0 +1,262 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trading schedules for the China futures and equity exchanges.
+//!
+//! All session times are in China Standard Time (Asia/Shanghai), which has no daylight saving.
+
+use chrono::NaiveTime;
+use chrono_tz::{Asia::Shanghai, Tz};
+use strum::{Display, EnumIter, EnumString, FromRepr};
+
+use crate::sessions::calendar::{HolidayCalendar, SessionPhase, SessionWindow, TradingCalendar};
+
+/// The timezone of the China exchanges.
+pub const CHINA_TIMEZONE: Tz = Shanghai;
+
+/// Represents a trading session schedule on the China exchanges.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, FromRepr, EnumIter, EnumString, Display)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChinaTradingSchedule {
+    /// SSE and SZSE stocks and funds, with opening and closing call auctions.
+    Equity,
+    /// Commodity futures without a night session.
+    CommodityDay,
+    /// Commodity futures with a night session until 23:00 (e.g. SHFE rebar, DCE iron ore).
+    CommodityNight2300,
+    /// Commodity futures with a night session until 01:00 (e.g. SHFE copper, aluminium).
+    CommodityNight0100,
+    /// Commodity futures with a night session until 02:30 (e.g. SHFE gold, INE crude oil).
+    CommodityNight0230,
+    /// CFFEX equity index futures.
+    CffexEquityIndex,
+    /// CFFEX treasury bond futures.
+    CffexTreasury,
+}
+
+const fn hm(hour: u32, min: u32) -> NaiveTime {
+    match NaiveTime::from_hms_opt(hour, min, 0) {
+        Some(time) => time,
+        None => panic!("Invalid session time"),
+    }
+}
+
+const fn window(phase: SessionPhase, start: NaiveTime, end: NaiveTime) -> SessionWindow {
+    SessionWindow::new(phase, start, end, false)
+}
+
+const fn night(phase: SessionPhase, start: NaiveTime, end: NaiveTime) -> SessionWindow {
+    SessionWindow::new(phase, start, end, true)
+}
+
+const COMMODITY_DAY_CONTINUOUS: [SessionWindow; 3] = [
+    window(SessionPhase::Continuous, hm(9, 0), hm(10, 15)),
+    window(SessionPhase::Continuous, hm(10, 30), hm(11, 30)),
+    window(SessionPhase::Continuous, hm(13, 30), hm(15, 0)),
+];
+
+impl ChinaTradingSchedule {
+    /// Returns the session windows for the schedule.
+    ///
+    /// Products with a night session hold their opening call auction before the night session.
+    #[must_use]
+    pub fn windows(&self) -> Vec<SessionWindow> {
+        use SessionPhase::{ClosingAuction, Continuous, OpeningAuction};
+
+        match self {
+            Self::Equity => vec![
+                window(OpeningAuction, hm(9, 15), hm(9, 25)),
+                window(Continuous, hm(9, 30), hm(11, 30)),
+                window(Continuous, hm(13, 0), hm(14, 57)),
+                window(ClosingAuction, hm(14, 57), hm(15, 0)),
+            ],
+            Self::CommodityDay => {
+                let mut windows = vec![window(OpeningAuction, hm(8, 55), hm(9, 0))];
+                windows.extend(COMMODITY_DAY_CONTINUOUS);
+                windows
+            }
+            Self::CommodityNight2300 | Self::CommodityNight0100 | Self::CommodityNight0230 => {
+                let night_close = match self {
+                    Self::CommodityNight0100 => hm(1, 0),
+                    Self::CommodityNight0230 => hm(2, 30),
+                    _ => hm(23, 0),
+                };
+                let mut windows = vec![
+                    night(OpeningAuction, hm(20, 55), hm(21, 0)),
+                    night(Continuous, hm(21, 0), night_close),
+                ];
+                windows.extend(COMMODITY_DAY_CONTINUOUS);
+                windows
+            }
+            Self::CffexEquityIndex => vec![
+                window(OpeningAuction, hm(9, 25), hm(9, 30)),
+                window(Continuous, hm(9, 30), hm(11, 30)),
+                window(Continuous, hm(13, 0), hm(15, 0)),
+            ],
+            Self::CffexTreasury => vec![
+                window(OpeningAuction, hm(9, 10), hm(9, 15)),
+                window(Continuous, hm(9, 15), hm(11, 30)),
+                window(Continuous, hm(13, 0), hm(15, 15)),
+            ],
+        }
+    }
+
+    /// Returns whether the schedule has a night session.
+    #[must_use]
+    pub fn has_night_session(&self) -> bool {
+        matches!(
+            self,
+            Self::CommodityNight2300 | Self::CommodityNight0100 | Self::CommodityNight0230
+        )
+    }
+
+    /// Creates a [`TradingCalendar`] for the schedule with the given exchange `holidays`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule windows are invalid (which is a bug).
+    #[must_use]
+    pub fn calendar(&self, holidays: HolidayCalendar) -> TradingCalendar {
+        TradingCalendar::new(CHINA_TIMEZONE, self.windows(), holidays)
+            .expect("China trading schedule windows are valid")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ant_core::UnixNanos;
+    use ant_execution::matching_engine::rules::TradingDayCalendar;
+    use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    fn cst(y: i32, m: u32, d: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        CHINA_TIMEZONE
+            .with_ymd_and_hms(y, m, d, hour, min, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// Spring Festival 2024, trading resumed on Monday 2024-02-19.
+    #[fixture]
+    fn holidays() -> HolidayCalendar {
+        "2024-02-09\n2024-02-12\n2024-02-13\n2024-02-14\n2024-02-15\n2024-02-16\n"
+            .parse()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case(cst(2024, 3, 4, 9, 20), Some(SessionPhase::OpeningAuction))]
+    #[case(cst(2024, 3, 4, 9, 27), None)]
+    #[case(cst(2024, 3, 4, 10, 0), Some(SessionPhase::Continuous))]
+    #[case(cst(2024, 3, 4, 12, 0), None)] // Lunch break
+    #[case(cst(2024, 3, 4, 14, 58), Some(SessionPhase::ClosingAuction))]
+    #[case(cst(2024, 3, 4, 15, 0), None)]
+    fn test_equity_phase(
+        holidays: HolidayCalendar,
+        #[case] time: DateTime<Utc>,
+        #[case] expected: Option<SessionPhase>,
+    ) {
+        let calendar = ChinaTradingSchedule::Equity.calendar(holidays);
+
+        assert_eq!(calendar.phase(time), expected);
+    }
+
+    #[rstest]
+    fn test_commodity_day_break(holidays: HolidayCalendar) {
+        let calendar = ChinaTradingSchedule::CommodityDay.calendar(holidays);
+
+        assert!(!calendar.is_open(cst(2024, 3, 4, 10, 20)));
+        assert_eq!(
+            calendar.next_open(cst(2024, 3, 4, 10, 20)),
+            cst(2024, 3, 4, 10, 30)
+        );
+    }
+
+    #[rstest]
+    fn test_friday_night_session_belongs_to_monday(holidays: HolidayCalendar) {
+        let calendar = ChinaTradingSchedule::CommodityNight0230.calendar(holidays);
+        let friday_night = cst(2024, 3, 1, 22, 0);
+        let saturday_morning = cst(2024, 3, 2, 1, 0);
+
+        assert!(calendar.is_open(friday_night));
+        assert!(calendar.is_open(saturday_morning));
+        assert_eq!(calendar.trading_day(friday_night), date(2024, 3, 4));
+        assert_eq!(calendar.trading_day(saturday_morning), date(2024, 3, 4));
+        assert_eq!(calendar.next_close(friday_night), cst(2024, 3, 2, 2, 30));
+        assert_eq!(
+            TradingDayCalendar::trading_day(&calendar, UnixNanos::from(friday_night)),
+            i64::from(date(2024, 3, 4).to_epoch_days())
+        );
+    }
+
+    #[rstest]
+    fn test_day_session_belongs_to_same_day(holidays: HolidayCalendar) {
+        let calendar = ChinaTradingSchedule::CommodityNight2300.calendar(holidays);
+
+        assert_eq!(
+            calendar.trading_day(cst(2024, 3, 1, 14, 0)),
+            date(2024, 3, 1)
+        );
+        assert!(!calendar.is_open(cst(2024, 3, 1, 23, 30)));
+        assert_eq!(
+            calendar.next_open(cst(2024, 3, 1, 15, 0)),
+            cst(2024, 3, 1, 20, 55)
+        );
+    }
+
+    #[rstest]
+    fn test_no_night_session_before_holiday(holidays: HolidayCalendar) {
+        let calendar = ChinaTradingSchedule::CommodityNight0100.calendar(holidays);
+        let thursday_night = cst(2024, 2, 8, 22, 0);
+
+        assert!(!calendar.is_open(thursday_night));
+        assert_eq!(calendar.trading_day(thursday_night), date(2024, 2, 19));
+        assert_eq!(calendar.next_open(thursday_night), cst(2024, 2, 19, 9, 0));
+        // Night session resumes on the evening of the first trading day after the holiday
+        assert!(calendar.is_open(cst(2024, 2, 19, 22, 0)));
+    }
+
+    #[rstest]
+    fn test_night_session_held_before_first_day_after_weekend(holidays: HolidayCalendar) {
+        let calendar = ChinaTradingSchedule::CommodityNight0100.calendar(holidays);
+        let sessions = calendar.sessions(date(2024, 3, 4));
+
+        assert_eq!(sessions.len(), 5);
+        assert_eq!(sessions[0].1, cst(2024, 3, 1, 20, 55));
+        assert_eq!(sessions[1].2, cst(2024, 3, 2, 1, 0));
+    }
+
+    #[rstest]
+    fn test_cffex_treasury_close(holidays: HolidayCalendar) {
+        let calendar = ChinaTradingSchedule::CffexTreasury.calendar(holidays);
+
+        assert!(calendar.is_open(cst(2024, 3, 4, 15, 10)));
+        assert_eq!(
+            calendar.next_close(cst(2024, 3, 4, 13, 0)),
+            cst(2024, 3, 4, 15, 15)
+        );
+        assert!(!ChinaTradingSchedule::CffexTreasury.has_night_session());
+    }
+}
@@ -0,0 +1,34 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Market session utilities.
+//!
+//! - [`forex`]: Forex session times (Sydney, Tokyo, London, New York).
+//! - [`calendar`]: Exchange trading calendars with intraday session windows, holidays and
+//!   trading day attribution.
+//! - [`china`]: Trading schedules for the China futures and equity exchanges.
+
+pub mod calendar;
+pub mod china;
+pub mod forex;
+
+// Re-exports
+pub use crate::sessions::{
+    calendar::{HolidayCalendar, SessionPhase, SessionWindow, TradingCalendar},
+    china::ChinaTradingSchedule,
+    forex::{
+        ForexSession, fx_local_from_utc, fx_next_end, fx_next_start, fx_prev_end, fx_prev_start,
+    },
+};
@@ -0,0 +1,60 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use ant_model::{
+    enums::OmsType,
+    identifiers::{InstrumentId, StrategyId},
+};
+
+/// Configuration for [`Strategy`](super::Strategy) based components.
+#[derive(Debug, Clone)]
+pub struct StrategyConfig {
+    /// The custom identifier for the strategy.
+    ///
+    /// If `None` then an identifier is derived from the `order_id_tag`.
+    pub strategy_id: Option<StrategyId>,
+    /// The unique order ID tag for the strategy (must be unique across all strategies
+    /// running for the trader).
+    pub order_id_tag: Option<String>,
+    /// The order management system type for the strategy.
+    ///
+    /// If `None` then the venue OMS type is used to determine position IDs.
+    pub oms_type: Option<OmsType>,
+    /// The instruments for which external orders are claimed by the strategy.
+    pub external_order_claims: Option<Vec<InstrumentId>>,
+    /// If UUID4s should be used for client order ID values.
+    pub use_uuid_client_order_ids: bool,
+    /// If hyphens should be used in generated client order ID values.
+    pub use_hyphens_in_client_order_ids: bool,
+    /// If events should be logged.
+    pub log_events: bool,
+    /// If commands should be logged.
+    pub log_commands: bool,
+}
+
+impl Default for StrategyConfig {
+    fn default() -> Self {
+        Self {
+            strategy_id: None,
+            order_id_tag: None,
+            oms_type: None,
+            external_order_claims: None,
+            use_uuid_client_order_ids: false,
+            use_hyphens_in_client_order_ids: true,
+            log_events: true,
+            log_commands: true,
+        }
+    }
+}
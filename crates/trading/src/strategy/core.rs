@@ -0,0 +1,138 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashSet,
+    ops::{Deref, DerefMut},
+};
+
+use ant_common::{
+    actor::{DataActorCore, data_actor::DataActorConfig},
+    factories::OrderFactory,
+};
+use ant_core::time::get_atomic_clock_static;
+use ant_model::{
+    enums::OmsType,
+    identifiers::{ActorId, InstrumentId, StrategyId},
+};
+
+use super::config::StrategyConfig;
+
+/// The core state shared by all [`Strategy`](super::Strategy) implementations.
+///
+/// Wraps a [`DataActorCore`] (which it dereferences to) and adds the order management
+/// state required by a strategy, such as the [`OrderFactory`].
+#[derive(Debug)]
+pub struct StrategyCore {
+    /// The underlying data actor core.
+    pub actor: DataActorCore,
+    /// The strategy configuration.
+    pub config: StrategyConfig,
+    strategy_id: StrategyId,
+    order_factory: Option<OrderFactory>, // Created once registered with a trader
+}
+
+impl StrategyCore {
+    /// Creates a new [`StrategyCore`] instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the derived strategy ID is invalid.
+    #[must_use]
+    pub fn new(config: StrategyConfig) -> Self {
+        let strategy_id = config.strategy_id.unwrap_or_else(|| {
+            let tag = config.order_id_tag.as_deref().unwrap_or("000");
+            StrategyId::from(format!("Strategy-{tag}").as_str())
+        });
+
+        let actor_config = DataActorConfig {
+            actor_id: Some(ActorId::from(strategy_id.inner().as_str())),
+            log_events: config.log_events,
+            log_commands: config.log_commands,
+        };
+
+        Self {
+            actor: DataActorCore::new(actor_config),
+            config,
+            strategy_id,
+            order_factory: None,
+        }
+    }
+
+    /// Returns the strategy ID.
+    #[must_use]
+    pub const fn strategy_id(&self) -> StrategyId {
+        self.strategy_id
+    }
+
+    /// Returns the OMS type override for the strategy (if configured).
+    #[must_use]
+    pub const fn oms_type(&self) -> Option<OmsType> {
+        self.config.oms_type
+    }
+
+    /// Returns the instruments for which the strategy claims external orders.
+    #[must_use]
+    pub fn external_order_claims(&self) -> HashSet<InstrumentId> {
+        self.config
+            .external_order_claims
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns the order factory for the strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy has not been registered with a trader.
+    pub fn order_factory(&mut self) -> &mut OrderFactory {
+        if self.order_factory.is_none() {
+            let trader_id = self.actor.trader_id().unwrap_or_else(|| {
+                panic!(
+                    "Strategy {} must be registered before accessing the order factory",
+                    self.strategy_id
+                )
+            });
+            self.order_factory = Some(OrderFactory::new(
+                trader_id,
+                self.strategy_id,
+                None,
+                None,
+                get_atomic_clock_static(),
+                self.config.use_uuid_client_order_ids,
+                self.config.use_hyphens_in_client_order_ids,
+            ));
+        }
+
+        // SAFETY: Factory was created above if it did not already exist
+        self.order_factory.as_mut().unwrap()
+    }
+}
+
+impl Deref for StrategyCore {
+    type Target = DataActorCore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.actor
+    }
+}
+
+impl DerefMut for StrategyCore {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.actor
+    }
+}
@@ -0,0 +1,1119 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 ANT Systems Pty Ltd. All rights reserved.
+//
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Native Rust trading strategies.
+//!
+//! A [`Strategy`] is a [`DataActor`] which additionally manages orders and positions.
+//! Strategies create orders with their [`OrderFactory`], submit them through the risk
+//! engine, and receive the resulting order and position events through dedicated callbacks.
+
+pub mod config;
+pub mod core;
+
+use ant_common::{
+    actor::{DataActor, DataActorCore},
+    factories::OrderFactory,
+    logging::{CMD, EVT, RECV, SEND},
+    messages::execution::{
+        CancelAllOrders, CancelOrder, ModifyOrder, SubmitOrder, SubmitOrderList, TradingCommand,
+    },
+    msgbus::{self, switchboard::MessagingSwitchboard},
+};
+use ant_core::UUID4;
+use ant_model::{
+    enums::{OrderSide, PositionSide, TimeInForce},
+    events::{
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated,
+        OrderEventAny, OrderExpired, OrderFilled, OrderInitialized, OrderModifyRejected,
+        OrderPendingCancel, OrderPendingUpdate, OrderRejected, OrderReleased, OrderSubmitted,
+        OrderTriggered, OrderUpdated, PositionChanged, PositionClosed, PositionEvent,
+        PositionOpened,
+    },
+    identifiers::{ClientId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId},
+    orders::{Order, OrderAny, OrderList},
+    position::Position,
+    types::{Price, Quantity},
+};
+use ustr::Ustr;
+
+pub use self::{config::StrategyConfig, core::StrategyCore};
+
+/// Core trait for implementing native Rust trading strategies.
+///
+/// Implementors hold a [`StrategyCore`] and dereference to its [`DataActorCore`], so all
+/// data subscription and request functionality of a [`DataActor`] is available. Order and
+/// position events for the strategy are dispatched to the `on_order_*` and `on_position_*`
+/// callbacks while the strategy is running.
+pub trait Strategy: DataActor {
+    /// Returns a reference to the strategy core.
+    fn core(&self) -> &StrategyCore;
+
+    /// Returns a mutable reference to the strategy core.
+    fn core_mut(&mut self) -> &mut StrategyCore;
+
+    /// Returns the strategy ID.
+    fn strategy_id(&self) -> StrategyId {
+        self.core().strategy_id()
+    }
+
+    /// Returns the order factory for the strategy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy has not been registered with a trader.
+    fn order_factory(&mut self) -> &mut OrderFactory {
+        self.core_mut().order_factory()
+    }
+
+    // -- ORDER EVENT CALLBACKS -------------------------------------------------------------------
+
+    /// Actions to be performed when receiving any order event.
+    ///
+    /// Called after the specific order event callback.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_event(&mut self, event: &OrderEventAny) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is initialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_initialized(&mut self, event: &OrderInitialized) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is denied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_denied(&mut self, event: &OrderDenied) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is emulated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_emulated(&mut self, event: &OrderEmulated) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is released from emulation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_released(&mut self, event: &OrderReleased) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is submitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_submitted(&mut self, event: &OrderSubmitted) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_accepted(&mut self, event: &OrderAccepted) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_rejected(&mut self, event: &OrderRejected) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is canceled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_canceled(&mut self, event: &OrderCanceled) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_expired(&mut self, event: &OrderExpired) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is triggered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_triggered(&mut self, event: &OrderTriggered) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is pending update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_pending_update(&mut self, event: &OrderPendingUpdate) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is pending cancel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_pending_cancel(&mut self, event: &OrderPendingCancel) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order modification is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_modify_rejected(&mut self, event: &OrderModifyRejected) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order cancellation is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_cancel_rejected(&mut self, event: &OrderCancelRejected) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_updated(&mut self, event: &OrderUpdated) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when an order is filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_order_filled(&mut self, event: &OrderFilled) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    // -- POSITION EVENT CALLBACKS ----------------------------------------------------------------
+
+    /// Actions to be performed when receiving any position event.
+    ///
+    /// Called after the specific position event callback.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_position_event(&mut self, event: &PositionEvent) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when a position is opened.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_position_opened(&mut self, event: &PositionOpened) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when a position is changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_position_changed(&mut self, event: &PositionChanged) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Actions to be performed when a position is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if handling the event fails.
+    #[allow(unused_variables)]
+    fn on_position_closed(&mut self, event: &PositionClosed) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    // -- EVENT HANDLERS --------------------------------------------------------------------------
+
+    /// Handles a received order event, dispatching to the relevant callbacks.
+    fn handle_order_event(&mut self, event: &OrderEventAny) {
+        if self.core().config.log_events {
+            log::info!("{RECV}{EVT} {event:?}");
+        }
+
+        if self.not_running() {
+            log::warn!("Received order event when not running - skipping {event:?}");
+            return;
+        }
+
+        let result = match event {
+            OrderEventAny::Initialized(e) => self.on_order_initialized(e),
+            OrderEventAny::Denied(e) => self.on_order_denied(e),
+            OrderEventAny::Emulated(e) => self.on_order_emulated(e),
+            OrderEventAny::Released(e) => self.on_order_released(e),
+            OrderEventAny::Submitted(e) => self.on_order_submitted(e),
+            OrderEventAny::Accepted(e) => self.on_order_accepted(e),
+            OrderEventAny::Rejected(e) => self.on_order_rejected(e),
+            OrderEventAny::Canceled(e) => self.on_order_canceled(e),
+            OrderEventAny::Expired(e) => self.on_order_expired(e),
+            OrderEventAny::Triggered(e) => self.on_order_triggered(e),
+            OrderEventAny::PendingUpdate(e) => self.on_order_pending_update(e),
+            OrderEventAny::PendingCancel(e) => self.on_order_pending_cancel(e),
+            OrderEventAny::ModifyRejected(e) => self.on_order_modify_rejected(e),
+            OrderEventAny::CancelRejected(e) => self.on_order_cancel_rejected(e),
+            OrderEventAny::Updated(e) => self.on_order_updated(e),
+            OrderEventAny::Filled(e) => self.on_order_filled(e),
+        };
+
+        if let Err(e) = result.and_then(|()| self.on_order_event(event)) {
+            log::error!("{e}");
+        }
+    }
+
+    /// Handles a received position event, dispatching to the relevant callbacks.
+    fn handle_position_event(&mut self, event: &PositionEvent) {
+        if self.core().config.log_events {
+            log::info!("{RECV}{EVT} {event:?}");
+        }
+
+        if self.not_running() {
+            log::warn!("Received position event when not running - skipping {event:?}");
+            return;
+        }
+
+        let result = match event {
+            PositionEvent::PositionOpened(e) => self.on_position_opened(e),
+            PositionEvent::PositionChanged(e) => self.on_position_changed(e),
+            PositionEvent::PositionClosed(e) => self.on_position_closed(e),
+        };
+
+        if let Err(e) = result.and_then(|()| self.on_position_event(event)) {
+            log::error!("{e}");
+        }
+    }
+
+    // -- TRADING COMMANDS ------------------------------------------------------------------------
+
+    /// Submits the `order` for execution through the risk engine.
+    ///
+    /// Orders with an execution algorithm ID are sent to that execution algorithm instead.
+    /// If `client_id` is `None` then the order is routed by the venue of its instrument.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is not registered, the order does not belong to
+    /// the strategy, or the order cannot be added to the cache.
+    fn submit_order(
+        &mut self,
+        order: OrderAny,
+        position_id: Option<PositionId>,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let trader_id = registered_trader_id(self)?;
+        let strategy_id = self.strategy_id();
+        check_order_strategy(&order, strategy_id)?;
+
+        let instrument_id = order.instrument_id();
+        let client_id = client_id.unwrap_or_else(|| default_client_id(&instrument_id));
+
+        self.cache_rc()
+            .borrow_mut()
+            .add_order(order.clone(), position_id, Some(client_id), false)?;
+
+        let command = SubmitOrder::new(
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            order.clone(),
+            order.exec_algorithm_id(),
+            position_id,
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        match order.exec_algorithm_id() {
+            Some(exec_algorithm_id) => {
+                send_algo_command(
+                    self,
+                    exec_algorithm_id,
+                    TradingCommand::SubmitOrder(command),
+                );
+            }
+            None => send_risk_command(self, TradingCommand::SubmitOrder(command)),
+        }
+        Ok(())
+    }
+
+    /// Submits the `order_list` for execution through the risk engine.
+    ///
+    /// If the first order has an execution algorithm ID then the list is sent to that
+    /// execution algorithm instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is not registered, any order does not belong to
+    /// the strategy, the order list was already submitted, or an order cannot be added
+    /// to the cache.
+    fn submit_order_list(
+        &mut self,
+        order_list: OrderList,
+        position_id: Option<PositionId>,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let trader_id = registered_trader_id(self)?;
+        let strategy_id = self.strategy_id();
+
+        if self.cache().order_list_exists(&order_list.id) {
+            anyhow::bail!("Order list {} was already submitted", order_list.id);
+        }
+
+        for order in &order_list.orders {
+            check_order_strategy(order, strategy_id)?;
+        }
+
+        let instrument_id = order_list.instrument_id;
+        let client_id = client_id.unwrap_or_else(|| default_client_id(&instrument_id));
+
+        {
+            let cache_rc = self.cache_rc();
+            let mut cache = cache_rc.borrow_mut();
+            for order in &order_list.orders {
+                cache.add_order(order.clone(), position_id, Some(client_id), false)?;
+            }
+        }
+
+        let first_order = &order_list.orders[0];
+        let command = SubmitOrderList::new(
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            first_order.client_order_id(),
+            first_order.venue_order_id().unwrap_or_default(),
+            order_list.clone(),
+            first_order.exec_algorithm_id(),
+            position_id,
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        match first_order.exec_algorithm_id() {
+            Some(exec_algorithm_id) => send_algo_command(
+                self,
+                exec_algorithm_id,
+                TradingCommand::SubmitOrderList(command),
+            ),
+            None => send_risk_command(self, TradingCommand::SubmitOrderList(command)),
+        }
+        Ok(())
+    }
+
+    /// Modifies the `order` with the given optional parameters.
+    ///
+    /// At least one value must differ from the original order for the command to be valid.
+    /// The command is skipped with a warning if the order is already closed or pending
+    /// cancel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is not registered or no modification is requested.
+    fn modify_order(
+        &mut self,
+        order: &OrderAny,
+        quantity: Option<Quantity>,
+        price: Option<Price>,
+        trigger_price: Option<Price>,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let trader_id = registered_trader_id(self)?;
+
+        if order.is_closed() || order.is_pending_cancel() {
+            log::warn!("Cannot modify order: state is {}, {order:?}", order.status());
+            return Ok(());
+        }
+
+        let quantity_changed = quantity.is_some_and(|q| q != order.quantity());
+        let price_changed = price.is_some_and(|p| Some(p) != order.price());
+        let trigger_price_changed =
+            trigger_price.is_some_and(|p| Some(p) != order.trigger_price());
+
+        if !quantity_changed && !price_changed && !trigger_price_changed {
+            anyhow::bail!(
+                "Cannot create command ModifyOrder: quantity, price and trigger were either None \
+                or the same as existing values"
+            );
+        }
+
+        let instrument_id = order.instrument_id();
+        let client_id = client_id.unwrap_or_else(|| default_client_id(&instrument_id));
+        let command = ModifyOrder::new(
+            trader_id,
+            client_id,
+            self.strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            quantity,
+            price,
+            trigger_price,
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        send_risk_command(self, TradingCommand::ModifyOrder(command));
+        Ok(())
+    }
+
+    /// Cancels the `order`.
+    ///
+    /// Primary orders of an execution algorithm are canceled through that algorithm, which
+    /// also cancels any orders spawned from them. The command is skipped with a warning if the
+    /// order is already closed or pending cancel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is not registered.
+    fn cancel_order(
+        &mut self,
+        order: &OrderAny,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let trader_id = registered_trader_id(self)?;
+
+        if order.is_closed() || order.is_pending_cancel() {
+            log::warn!("Cannot cancel order: state is {}, {order:?}", order.status());
+            return Ok(());
+        }
+
+        let instrument_id = order.instrument_id();
+        let client_id = client_id.unwrap_or_else(|| default_client_id(&instrument_id));
+        let command = CancelOrder::new(
+            trader_id,
+            client_id,
+            self.strategy_id(),
+            instrument_id,
+            order.client_order_id(),
+            order.venue_order_id().unwrap_or_default(),
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        match order.exec_algorithm_id() {
+            Some(exec_algorithm_id) if order.is_primary() => {
+                send_algo_command(
+                    self,
+                    exec_algorithm_id,
+                    TradingCommand::CancelOrder(command),
+                );
+            }
+            _ => send_exec_command(self, TradingCommand::CancelOrder(command)),
+        }
+        Ok(())
+    }
+
+    /// Cancels all open orders of the strategy for the `instrument_id`.
+    ///
+    /// If `order_side` is `None` then orders on both sides are canceled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is not registered.
+    fn cancel_all_orders(
+        &mut self,
+        instrument_id: InstrumentId,
+        order_side: Option<OrderSide>,
+        client_id: Option<ClientId>,
+    ) -> anyhow::Result<()> {
+        let trader_id = registered_trader_id(self)?;
+        let strategy_id = self.strategy_id();
+
+        let open_count = {
+            let cache = self.cache();
+            cache
+                .orders_open(None, Some(&instrument_id), Some(&strategy_id), order_side)
+                .len()
+                + cache
+                    .orders_inflight(None, Some(&instrument_id), Some(&strategy_id), order_side)
+                    .len()
+        };
+
+        if open_count == 0 {
+            log::info!("No open or inflight orders to cancel for {instrument_id}");
+            return Ok(());
+        }
+
+        let client_id = client_id.unwrap_or_else(|| default_client_id(&instrument_id));
+        let command = CancelAllOrders::new(
+            trader_id,
+            client_id,
+            strategy_id,
+            instrument_id,
+            order_side.unwrap_or(OrderSide::NoOrderSide),
+            UUID4::new(),
+            self.timestamp_ns(),
+        )?;
+
+        send_exec_command(self, TradingCommand::CancelAllOrders(command));
+        Ok(())
+    }
+
+    /// Closes the `position` with a reduce-only market order for its full quantity.
+    ///
+    /// The command is skipped with a warning if the position is already closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy is not registered or the closing order cannot be
+    /// submitted.
+    fn close_position(
+        &mut self,
+        position: &Position,
+        client_id: Option<ClientId>,
+        tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<()> {
+        if position.is_closed() {
+            log::warn!("Cannot close position (already closed): {}", position.id);
+            return Ok(());
+        }
+
+        let order = self.order_factory().market(
+            position.instrument_id,
+            position.closing_order_side(),
+            position.quantity,
+            Some(TimeInForce::Gtc),
+            Some(true),
+            None,
+            None,
+            None,
+            tags,
+        );
+
+        self.submit_order(order, Some(position.id), client_id)
+    }
+
+    /// Closes all open positions of the strategy, optionally filtered by `instrument_id`
+    /// and `position_side`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any closing order cannot be submitted.
+    fn close_all_positions(
+        &mut self,
+        instrument_id: Option<InstrumentId>,
+        position_side: Option<PositionSide>,
+        client_id: Option<ClientId>,
+        tags: Option<Vec<Ustr>>,
+    ) -> anyhow::Result<()> {
+        let strategy_id = self.strategy_id();
+        let positions: Vec<Position> = self
+            .cache()
+            .positions_open(None, instrument_id.as_ref(), Some(&strategy_id), position_side)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        if positions.is_empty() {
+            log::info!("No open positions to close");
+            return Ok(());
+        }
+
+        for position in &positions {
+            self.close_position(position, client_id, tags.clone())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn registered_trader_id(core: &DataActorCore) -> anyhow::Result<TraderId> {
+    core.trader_id()
+        .ok_or_else(|| anyhow::anyhow!("Strategy {} is not registered", core.actor_id))
+}
+
+fn check_order_strategy(order: &OrderAny, strategy_id: StrategyId) -> anyhow::Result<()> {
+    if order.strategy_id() != strategy_id {
+        anyhow::bail!(
+            "Order {} belongs to strategy {}, not {strategy_id}",
+            order.client_order_id(),
+            order.strategy_id(),
+        );
+    }
+    Ok(())
+}
+
+fn default_client_id(instrument_id: &InstrumentId) -> ClientId {
+    ClientId::from(instrument_id.venue.as_str())
+}
+
+fn send_risk_command<T: Strategy + ?Sized>(strategy: &T, command: TradingCommand) {
+    if strategy.core().config.log_commands {
+        log::info!("{CMD}{SEND} {command}");
+    }
+    msgbus::send_any(MessagingSwitchboard::risk_engine_execute(), &command);
+}
+
+fn send_algo_command<T: Strategy + ?Sized>(
+    strategy: &T,
+    exec_algorithm_id: ExecAlgorithmId,
+    command: TradingCommand,
+) {
+    if strategy.core().config.log_commands {
+        log::info!("{CMD}{SEND} {command}");
+    }
+    msgbus::send_any(
+        MessagingSwitchboard::exec_algorithm_execute(exec_algorithm_id),
+        &command,
+    );
+}
+
+fn send_exec_command<T: Strategy + ?Sized>(strategy: &T, command: TradingCommand) {
+    if strategy.core().config.log_commands {
+        log::info!("{CMD}{SEND} {command}");
+    }
+    msgbus::send_any(MessagingSwitchboard::exec_engine_execute(), &command);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::RefCell,
+        ops::{Deref, DerefMut},
+        rc::Rc,
+    };
+
+    use ant_common::{
+        actor::{DataActor, DataActorCore},
+        cache::Cache,
+        clock::TestClock,
+        component::Component,
+        messages::execution::TradingCommand,
+        msgbus::{
+            self,
+            stubs::{get_message_saving_handler, get_saved_messages},
+            switchboard::MessagingSwitchboard,
+        },
+    };
+    use ant_model::{
+        enums::{OrderSide, OrderStatus},
+        events::{OrderDenied, OrderEventAny, OrderFilled},
+        identifiers::{ActorId, InstrumentId, StrategyId, TraderId},
+        orders::Order,
+        types::{Price, Quantity},
+    };
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
+
+    use super::{Strategy, StrategyConfig, StrategyCore};
+
+    #[derive(Debug)]
+    struct TestStrategy {
+        core: StrategyCore,
+        denied: Vec<OrderDenied>,
+        filled: Vec<OrderFilled>,
+        event_count: usize,
+    }
+
+    impl TestStrategy {
+        fn new(config: StrategyConfig) -> Self {
+            Self {
+                core: StrategyCore::new(config),
+                denied: Vec::new(),
+                filled: Vec::new(),
+                event_count: 0,
+            }
+        }
+    }
+
+    impl Deref for TestStrategy {
+        type Target = DataActorCore;
+        fn deref(&self) -> &Self::Target {
+            &self.core
+        }
+    }
+
+    impl DerefMut for TestStrategy {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.core
+        }
+    }
+
+    impl DataActor for TestStrategy {
+        fn on_start(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Strategy for TestStrategy {
+        fn core(&self) -> &StrategyCore {
+            &self.core
+        }
+
+        fn core_mut(&mut self) -> &mut StrategyCore {
+            &mut self.core
+        }
+
+        fn on_order_denied(&mut self, event: &OrderDenied) -> anyhow::Result<()> {
+            self.denied.push(*event);
+            Ok(())
+        }
+
+        fn on_order_filled(&mut self, event: &OrderFilled) -> anyhow::Result<()> {
+            self.filled.push(*event);
+            Ok(())
+        }
+
+        fn on_order_event(&mut self, _event: &OrderEventAny) -> anyhow::Result<()> {
+            self.event_count += 1;
+            Ok(())
+        }
+    }
+
+    #[fixture]
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("BTCUSDT.BINANCE")
+    }
+
+    #[fixture]
+    fn cache() -> Rc<RefCell<Cache>> {
+        Rc::new(RefCell::new(Cache::new(None, None)))
+    }
+
+    #[fixture]
+    fn strategy(cache: Rc<RefCell<Cache>>) -> TestStrategy {
+        let config = StrategyConfig {
+            order_id_tag: Some("001".to_string()),
+            ..Default::default()
+        };
+        let mut strategy = TestStrategy::new(config);
+        strategy
+            .register(
+                TraderId::from("TRADER-001"),
+                Rc::new(RefCell::new(TestClock::new())),
+                cache,
+            )
+            .unwrap();
+        strategy
+    }
+
+    #[rstest]
+    fn test_strategy_id_derived_from_order_id_tag() {
+        let config = StrategyConfig {
+            order_id_tag: Some("002".to_string()),
+            ..Default::default()
+        };
+        let strategy = TestStrategy::new(config);
+
+        assert_eq!(strategy.strategy_id(), StrategyId::from("Strategy-002"));
+        assert_eq!(strategy.actor_id(), ActorId::from("Strategy-002"));
+    }
+
+    #[rstest]
+    fn test_strategy_id_from_config() {
+        let config = StrategyConfig {
+            strategy_id: Some(StrategyId::from("EMA-CROSS")),
+            ..Default::default()
+        };
+        let strategy = TestStrategy::new(config);
+
+        assert_eq!(strategy.strategy_id(), StrategyId::from("EMA-CROSS"));
+    }
+
+    #[rstest]
+    fn test_order_factory_creates_orders_for_strategy(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let order = strategy.order_factory().market(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(order.strategy_id(), StrategyId::from("Strategy-001"));
+        assert_eq!(order.trader_id(), TraderId::from("TRADER-001"));
+    }
+
+    #[rstest]
+    fn test_submit_order_caches_order_and_sends_to_risk_engine(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let order = strategy.order_factory().market(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let client_order_id = order.client_order_id();
+        strategy.submit_order(order, None, None).unwrap();
+
+        let messages = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(messages.len(), 1);
+        match &messages[0] {
+            TradingCommand::SubmitOrder(command) => {
+                assert_eq!(command.client_order_id, client_order_id);
+                assert_eq!(command.client_id.as_str(), "BINANCE");
+            }
+            other => panic!("Unexpected command {other}"),
+        }
+        assert!(strategy.cache().order_exists(&client_order_id));
+    }
+
+    #[rstest]
+    fn test_submit_order_for_other_strategy_returns_error(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let mut other = TestStrategy::new(StrategyConfig {
+            order_id_tag: Some("999".to_string()),
+            ..Default::default()
+        });
+        other
+            .register(
+                TraderId::from("TRADER-001"),
+                Rc::new(RefCell::new(TestClock::new())),
+                Rc::new(RefCell::new(Cache::new(None, None))),
+            )
+            .unwrap();
+        let order = other.order_factory().market(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert!(strategy.submit_order(order, None, None).is_err());
+    }
+
+    #[rstest]
+    fn test_submit_order_list_sends_to_risk_engine(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        let order_list = strategy.order_factory().bracket(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            None,
+            Price::from("49000.00"),
+            None,
+            Price::from("52000.00"),
+            None,
+            None,
+        );
+        strategy.submit_order_list(order_list, None, None).unwrap();
+
+        let messages = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0], TradingCommand::SubmitOrderList(_)));
+        assert_eq!(
+            strategy
+                .cache()
+                .orders(None, Some(&instrument_id), None, None)
+                .len(),
+            3
+        );
+    }
+
+    #[rstest]
+    fn test_modify_order_without_changes_returns_error(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let order = strategy.order_factory().limit(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            Price::from("50000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let result = strategy.modify_order(
+            &order,
+            Some(Quantity::from("1.0")),
+            Some(Price::from("50000.00")),
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_cancel_order_sends_to_exec_engine(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::exec_engine_execute(), handler.clone());
+
+        let order = strategy.order_factory().limit(
+            instrument_id,
+            OrderSide::Buy,
+            Quantity::from("1.0"),
+            Price::from("50000.00"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        strategy.cancel_order(&order, None).unwrap();
+
+        let messages = get_saved_messages::<TradingCommand>(handler);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(messages[0], TradingCommand::CancelOrder(_)));
+    }
+
+    #[rstest]
+    fn test_cancel_all_orders_with_no_open_orders_sends_nothing(
+        mut strategy: TestStrategy,
+        instrument_id: InstrumentId,
+    ) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::exec_engine_execute(), handler.clone());
+
+        strategy.cancel_all_orders(instrument_id, None, None).unwrap();
+
+        assert!(get_saved_messages::<TradingCommand>(handler).is_empty());
+    }
+
+    #[rstest]
+    fn test_handle_order_event_when_not_running_is_skipped(mut strategy: TestStrategy) {
+        strategy.handle_order_event(&OrderEventAny::Denied(OrderDenied::default()));
+
+        assert!(strategy.denied.is_empty());
+        assert_eq!(strategy.event_count, 0);
+    }
+
+    #[rstest]
+    fn test_handle_order_event_dispatches_to_callbacks(mut strategy: TestStrategy) {
+        strategy.start().unwrap();
+
+        strategy.handle_order_event(&OrderEventAny::Denied(OrderDenied::default()));
+        strategy.handle_order_event(&OrderEventAny::Filled(OrderFilled::default()));
+
+        assert_eq!(strategy.denied.len(), 1);
+        assert_eq!(strategy.filled.len(), 1);
+        assert_eq!(strategy.event_count, 2);
+    }
+
+    #[rstest]
+    fn test_close_all_positions_with_no_positions_is_noop(mut strategy: TestStrategy) {
+        let handler = get_message_saving_handler::<TradingCommand>(None);
+        msgbus::register(MessagingSwitchboard::risk_engine_execute(), handler.clone());
+
+        strategy
+            .close_all_positions(None, None, None, Some(vec![Ustr::from("EXIT")]))
+            .unwrap();
+
+        assert!(get_saved_messages::<TradingCommand>(handler).is_empty());
+    }
+}
@@ -0,0 +1,11 @@
+-- Truncates every table in the public schema, used by `PostgresCacheDatabase::flush`
+CREATE OR REPLACE FUNCTION truncate_all_tables() RETURNS void AS $$
+DECLARE
+    table_name TEXT;
+BEGIN
+    FOR table_name IN (SELECT tablename FROM pg_tables WHERE schemaname = 'public')
+    LOOP
+        EXECUTE 'TRUNCATE TABLE ' || quote_ident(table_name) || ' CASCADE';
+    END LOOP;
+END;
+$$ LANGUAGE plpgsql;
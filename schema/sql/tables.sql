@@ -0,0 +1,331 @@
+-- Cache database tables, every statement is idempotent so `ant database init` can be re-run
+
+CREATE TABLE IF NOT EXISTS "general" (
+    id TEXT PRIMARY KEY NOT NULL,
+    value BYTEA NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "trader" (
+    id TEXT PRIMARY KEY NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "account" (
+    id TEXT PRIMARY KEY NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "client" (
+    id TEXT PRIMARY KEY NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "currency" (
+    id TEXT PRIMARY KEY NOT NULL,
+    precision INTEGER,
+    iso4217 INTEGER,
+    name TEXT,
+    currency_type CURRENCY_TYPE
+);
+
+CREATE TABLE IF NOT EXISTS "instrument" (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT,
+    raw_symbol TEXT NOT NULL,
+    base_currency TEXT REFERENCES currency(id),
+    underlying TEXT,
+    quote_currency TEXT REFERENCES currency(id),
+    settlement_currency TEXT REFERENCES currency(id),
+    isin TEXT,
+    asset_class ASSET_CLASS,
+    exchange TEXT,
+    multiplier TEXT,
+    option_kind TEXT,
+    strategy_type TEXT,
+    is_inverse BOOLEAN DEFAULT FALSE,
+    strike_price TEXT,
+    activation_ns TEXT,
+    expiration_ns TEXT,
+    price_precision INTEGER NOT NULL,
+    size_precision INTEGER,
+    price_increment TEXT NOT NULL,
+    size_increment TEXT,
+    maker_fee TEXT NULL,
+    taker_fee TEXT NULL,
+    margin_init TEXT NOT NULL,
+    margin_maint TEXT NOT NULL,
+    lot_size TEXT,
+    max_quantity TEXT,
+    min_quantity TEXT,
+    max_notional TEXT,
+    min_notional TEXT,
+    max_price TEXT,
+    min_price TEXT,
+    ts_init TEXT NOT NULL,
+    ts_event TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "synthetic" (
+    id TEXT PRIMARY KEY NOT NULL,
+    price_precision INTEGER NOT NULL,
+    components TEXT[] NOT NULL,
+    formula TEXT NOT NULL,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "order_event" (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL,
+    client_order_id TEXT DEFAULT NULL,
+    order_type TEXT DEFAULT NULL,
+    order_side TEXT DEFAULT NULL,
+    trader_id TEXT REFERENCES trader(id) ON DELETE CASCADE,
+    client_id TEXT REFERENCES client(id) ON DELETE CASCADE,
+    reason TEXT,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    trade_id TEXT,
+    currency TEXT,
+    quantity TEXT,
+    time_in_force TEXT,
+    liquidity_side TEXT,
+    post_only BOOLEAN DEFAULT FALSE,
+    reduce_only BOOLEAN DEFAULT FALSE,
+    quote_quantity BOOLEAN DEFAULT FALSE,
+    reconciliation BOOLEAN DEFAULT FALSE,
+    price TEXT,
+    last_px TEXT,
+    last_qty TEXT,
+    trigger_price TEXT,
+    trigger_type TEXT,
+    limit_offset TEXT,
+    trailing_offset TEXT,
+    trailing_offset_type TRAILING_OFFSET_TYPE,
+    expire_time TEXT,
+    display_qty TEXT,
+    emulation_trigger TEXT,
+    trigger_instrument_id TEXT,
+    contingency_type TEXT,
+    order_list_id TEXT,
+    linked_order_ids TEXT[],
+    parent_order_id TEXT,
+    exec_algorithm_id TEXT,
+    exec_algorithm_params JSONB,
+    exec_spawn_id TEXT,
+    tags JSONB,
+    venue_order_id TEXT,
+    account_id TEXT,
+    position_id TEXT,
+    commission TEXT,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "order" (
+    id TEXT PRIMARY KEY NOT NULL,
+    trader_id TEXT REFERENCES trader(id) ON DELETE CASCADE,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    client_order_id TEXT NOT NULL,
+    venue_order_id TEXT,
+    position_id TEXT,
+    account_id TEXT,
+    last_trade_id TEXT,
+    order_type TEXT NOT NULL,
+    order_side TEXT NOT NULL,
+    quantity TEXT NOT NULL,
+    price TEXT,
+    trigger_price TEXT,
+    trigger_type TEXT,
+    limit_offset TEXT,
+    trailing_offset TEXT,
+    trailing_offset_type TRAILING_OFFSET_TYPE,
+    time_in_force TEXT NOT NULL,
+    expire_time TEXT,
+    filled_qty TEXT NOT NULL,
+    liquidity_side TEXT,
+    avg_px DOUBLE PRECISION,
+    slippage DOUBLE PRECISION,
+    commissions TEXT[],
+    status TEXT NOT NULL,
+    is_post_only BOOLEAN DEFAULT FALSE,
+    is_reduce_only BOOLEAN DEFAULT FALSE,
+    is_quote_quantity BOOLEAN DEFAULT FALSE,
+    display_qty TEXT,
+    emulation_trigger TEXT,
+    trigger_instrument_id TEXT,
+    contingency_type TEXT,
+    order_list_id TEXT,
+    linked_order_ids TEXT[],
+    parent_order_id TEXT,
+    exec_algorithm_id TEXT,
+    exec_algorithm_params JSONB,
+    exec_spawn_id TEXT,
+    tags TEXT[],
+    init_id TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    ts_last TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "position" (
+    id TEXT PRIMARY KEY NOT NULL,
+    trader_id TEXT REFERENCES trader(id) ON DELETE CASCADE,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    account_id TEXT NOT NULL,
+    opening_order_id TEXT NOT NULL,
+    closing_order_id TEXT,
+    entry TEXT NOT NULL,
+    side TEXT NOT NULL,
+    signed_qty DOUBLE PRECISION NOT NULL,
+    quantity TEXT NOT NULL,
+    peak_qty TEXT NOT NULL,
+    quote_currency TEXT NOT NULL,
+    base_currency TEXT,
+    settlement_currency TEXT NOT NULL,
+    avg_px_open DOUBLE PRECISION NOT NULL,
+    avg_px_close DOUBLE PRECISION,
+    realized_return DOUBLE PRECISION,
+    realized_pnl TEXT,
+    unrealized_pnl TEXT,
+    commissions TEXT[],
+    duration_ns BIGINT,
+    ts_opened TEXT NOT NULL,
+    ts_closed TEXT,
+    ts_init TEXT NOT NULL,
+    ts_last TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "position_event" (
+    position_id TEXT NOT NULL,
+    sequence INTEGER NOT NULL,
+    id TEXT NOT NULL,
+    trader_id TEXT REFERENCES trader(id) ON DELETE CASCADE,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    client_order_id TEXT NOT NULL,
+    venue_order_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    trade_id TEXT NOT NULL,
+    order_side TEXT NOT NULL,
+    order_type TEXT NOT NULL,
+    last_qty TEXT NOT NULL,
+    last_px TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    liquidity_side TEXT NOT NULL,
+    commission TEXT,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    PRIMARY KEY (position_id, sequence)
+);
+
+CREATE TABLE IF NOT EXISTS "account_event" (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL,
+    account_id TEXT REFERENCES account(id) ON DELETE CASCADE,
+    base_currency TEXT,
+    balances JSONB,
+    margins JSONB,
+    is_reported BOOLEAN DEFAULT FALSE,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "component_state" (
+    kind TEXT NOT NULL,
+    component_id TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value BYTEA NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    PRIMARY KEY (kind, component_id, key)
+);
+
+CREATE TABLE IF NOT EXISTS "health" (
+    id TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "trade" (
+    id BIGSERIAL PRIMARY KEY,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    price TEXT NOT NULL,
+    quantity TEXT NOT NULL,
+    aggressor_side AGGRESSOR_SIDE,
+    venue_trade_id TEXT,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "quote" (
+    id BIGSERIAL PRIMARY KEY,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    bid_price TEXT NOT NULL,
+    ask_price TEXT NOT NULL,
+    bid_size TEXT NOT NULL,
+    ask_size TEXT NOT NULL,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "bar" (
+    id BIGSERIAL PRIMARY KEY,
+    instrument_id TEXT REFERENCES instrument(id) ON DELETE CASCADE,
+    step INTEGER NOT NULL,
+    bar_aggregation BAR_AGGREGATION NOT NULL,
+    price_type PRICE_TYPE NOT NULL,
+    aggregation_source AGGREGATION_SOURCE NOT NULL,
+    open TEXT NOT NULL,
+    high TEXT NOT NULL,
+    low TEXT NOT NULL,
+    close TEXT NOT NULL,
+    volume TEXT NOT NULL,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "signal" (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    value TEXT NOT NULL,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS "custom" (
+    id BIGSERIAL PRIMARY KEY,
+    data_type TEXT NOT NULL,
+    metadata JSONB,
+    value BYTEA NOT NULL,
+    ts_event TEXT NOT NULL,
+    ts_init TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS order_event_client_order_id_idx ON "order_event" (client_order_id);
+CREATE INDEX IF NOT EXISTS account_event_account_id_idx ON "account_event" (account_id);
+CREATE INDEX IF NOT EXISTS trade_instrument_id_idx ON "trade" (instrument_id);
+CREATE INDEX IF NOT EXISTS quote_instrument_id_idx ON "quote" (instrument_id);
+CREATE INDEX IF NOT EXISTS bar_instrument_id_idx ON "bar" (instrument_id);
@@ -0,0 +1,12 @@
+-- Enum types used by the cache database tables
+
+CREATE TYPE ASSET_CLASS AS ENUM ('FX', 'EQUITY', 'COMMODITY', 'DEBT', 'INDEX', 'CRYPTOCURRENCY', 'ALTERNATIVE');
+CREATE TYPE CURRENCY_TYPE AS ENUM ('CRYPTO', 'FIAT', 'COMMODITY_BACKED');
+CREATE TYPE TRAILING_OFFSET_TYPE AS ENUM ('NO_TRAILING_OFFSET', 'PRICE', 'BASIS_POINTS', 'TICKS', 'PRICE_TIER');
+CREATE TYPE AGGRESSOR_SIDE AS ENUM ('NO_AGGRESSOR', 'BUYER', 'SELLER');
+CREATE TYPE AGGREGATION_SOURCE AS ENUM ('EXTERNAL', 'INTERNAL');
+CREATE TYPE BAR_AGGREGATION AS ENUM (
+    'TICK', 'TICK_IMBALANCE', 'TICK_RUNS', 'VOLUME', 'VOLUME_IMBALANCE', 'VOLUME_RUNS', 'VALUE', 'VALUE_IMBALANCE',
+    'VALUE_RUNS', 'TIME', 'SECOND', 'MINUTE', 'HOUR', 'DAY', 'WEEK', 'MONTH', 'YEAR'
+);
+CREATE TYPE PRICE_TYPE AS ENUM ('BID', 'ASK', 'MID', 'LAST', 'MARK');